@@ -58,9 +58,33 @@ QUERY setMemoryContentKey(memory_id: String, content_key: String) =>
   memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
   updated <- memory::UPDATE({ content_key: content_key })
   RETURN updated
+// Retag a memory (e.g. drop `incomplete_thought` once a FastThink commit
+// supersedes it). searchByContextTag matches the whole string.
+QUERY setMemoryContextTags(memory_id: String, context_tags: String) =>
+  memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
+  updated <- memory::UPDATE({ context_tags: context_tags })
+  RETURN updated
+// Rewrite a memory's JSON metadata (merged in Rust, e.g. file/document
+// references attached by add_memory).
+QUERY setMemoryMetadata(memory_id: String, metadata: String) =>
+  memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
+  updated <- memory::UPDATE({ metadata: metadata })
+  RETURN updated
 QUERY getMemory(memory_id: String) =>
   memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
   RETURN memory
+// Access tracking: the count is read-then-written from Rust (HQL has no
+// increment), throttled per memory.
+QUERY recordMemoryAccess(memory_id: String, last_accessed_at: String, access_count: I64) =>
+  memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
+  updated <- memory::UPDATE({ last_accessed_at: last_accessed_at, access_count: access_count })
+  RETURN updated
+// Certainty decay anchor: stamped when a memory is stated again (a deduped
+// re-add), never by a search hit.
+QUERY reinforceMemory(memory_id: String, reinforced_at: String) =>
+  memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
+  updated <- memory::UPDATE({ reinforced_at: reinforced_at })
+  RETURN updated
 QUERY getRecentMemories(limit: I64) =>
   memories <- N<Memory>::RANGE(0, limit)
   RETURN memories
@@ -91,6 +115,11 @@ QUERY deleteMemoryEmbedding(memory_id: ID) =>
 QUERY getMemoryEmbedding(memory_id: ID) =>
   embedding <- N<Memory>(memory_id)::Out<HAS_EMBEDDING>::FIRST
   RETURN embedding
+QUERY getMemoryEmbeddingsBatch(memory_ids: [String]) =>
+  memories <- N<Memory>::WHERE(_::{memory_id}::IS_IN(memory_ids))
+  embedding_edges <- memories::OutE<HAS_EMBEDDING>
+  embeddings <- memories::Out<HAS_EMBEDDING>
+  RETURN memories, embedding_edges, embeddings
 QUERY addMemoryRelation(source_id: String, target_id: String, relation_type: String, strength: I64, created_at: String, metadata: String) =>
   source <- N<Memory>::WHERE(_::{memory_id}::EQ(source_id))::FIRST
   target <- N<Memory>::WHERE(_::{memory_id}::EQ(target_id))::FIRST
@@ -110,6 +139,20 @@ QUERY addMemoryCausation(from_id: String, to_id: String, strength: I64, reasonin
   to_memory <- N<Memory>::WHERE(_::{memory_id}::EQ(to_id))::FIRST
   causation <- AddE<BECAUSE>({ strength: strength, reasoning_id: reasoning_id })::From(from_memory)::To(to_memory)
   RETURN causation
+// Relation rebuild (rebuild_relations): retire one inferred edge between
+// two memories. CONTRADICTS has no drop — it carries dispute state.
+QUERY dropMemoryImplication(from_id: String, to_id: String) =>
+  from_memory <- N<Memory>::WHERE(_::{memory_id}::EQ(from_id))::FIRST
+  DROP from_memory::OutE<IMPLIES>::WHERE(_::ToN::{memory_id}::EQ(to_id))
+  RETURN "dropped"
+QUERY dropMemoryCausation(from_id: String, to_id: String) =>
+  from_memory <- N<Memory>::WHERE(_::{memory_id}::EQ(from_id))::FIRST
+  DROP from_memory::OutE<BECAUSE>::WHERE(_::ToN::{memory_id}::EQ(to_id))
+  RETURN "dropped"
+QUERY dropMemoryRelation(from_id: String, to_id: String) =>
+  from_memory <- N<Memory>::WHERE(_::{memory_id}::EQ(from_id))::FIRST
+  DROP from_memory::OutE<MEMORY_RELATION>::WHERE(_::ToN::{memory_id}::EQ(to_id))
+  RETURN "dropped"
 QUERY addMemoryContradiction(from_id: String, to_id: String, resolution: String, resolved: I64, resolution_strategy: String) =>
   from_memory <- N<Memory>::WHERE(_::{memory_id}::EQ(from_id))::FIRST
   to_memory <- N<Memory>::WHERE(_::{memory_id}::EQ(to_id))::FIRST
@@ -125,6 +168,12 @@ QUERY getMemoryContradictionsFull(memory_id: String) =>
   out_targets <- memory::Out<CONTRADICTS>
   RETURN out_edges, out_targets
 
+QUERY getMemoryContradictionsBatch(memory_ids: [String]) =>
+  memories <- N<Memory>::WHERE(_::{memory_id}::IS_IN(memory_ids))
+  contradiction_edges <- memories::OutE<CONTRADICTS>
+  targets <- memories::Out<CONTRADICTS>
+  RETURN memories, contradiction_edges, targets
+
 QUERY resolveMemoryContradictions(memory_id: String, strategy: String) =>
   memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
   edges <- memory::OutE<CONTRADICTS>::WHERE(_::{resolved}::EQ(0))
@@ -139,6 +188,19 @@ QUERY getSupersededMemories(memory_id: String) =>
   memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
   superseded <- memory::Out<SUPERSEDES>
   RETURN superseded
+QUERY linkExtractedFrom(atom_id: String, source_id: String, created_at: String) =>
+  atom <- N<Memory>::WHERE(_::{memory_id}::EQ(atom_id))::FIRST
+  source <- N<Memory>::WHERE(_::{memory_id}::EQ(source_id))::FIRST
+  link <- AddE<EXTRACTED_FROM>({ created_at: created_at })::From(atom)::To(source)
+  RETURN link
+QUERY getMemorySourcesBatch(memory_ids: [String]) =>
+  memories <- N<Memory>::WHERE(_::{memory_id}::IS_IN(memory_ids))
+  source_edges <- memories::OutE<EXTRACTED_FROM>
+  sources <- memories::Out<EXTRACTED_FROM>
+  RETURN memories, source_edges, sources
+QUERY getMemoriesBatch(memory_ids: [String]) =>
+  memories <- N<Memory>::WHERE(_::{memory_id}::IS_IN(memory_ids))
+  RETURN memories
 QUERY getSupersededBatch(memory_ids: [String]) =>
   memories <- N<Memory>::WHERE(_::{memory_id}::IS_IN(memory_ids))
   superseded_edges <- memories::InE<SUPERSEDES>
@@ -163,7 +225,7 @@ QUERY getMemoryIncomingRelations(memory_id: String) =>
 QUERY addReasoningRelation(relation_id: String, from_memory_id: String, to_memory_id: String, relation_type: String, strength: I64, confidence: I64, explanation: String, created_by: String, created_at: String) =>
   from_mem <- N<Memory>::WHERE(_::{memory_id}::EQ(from_memory_id))::FIRST
   to_mem <- N<Memory>::WHERE(_::{memory_id}::EQ(to_memory_id))::FIRST
-  relation <- AddE<MEMORY_RELATION>({ relation_type: relation_type, strength: strength, created_at: created_at, metadata: "" })::From(from_mem)::To(to_mem)
+  relation <- AddE<MEMORY_RELATION>({ relation_type: relation_type, strength: strength, created_at: created_at, metadata: "", created_by: created_by })::From(from_mem)::To(to_mem)
   RETURN relation
 QUERY addMemoryEmbedding(memory_id: ID, vector_data: [F64], embedding_model: String, created_at: Date) =>
   embedding <- AddV<MemoryEmbedding>(vector_data, { created_at: created_at })
@@ -184,6 +246,11 @@ QUERY getEntity(entity_id: String) =>
 QUERY getEntityByName(name: String) =>
   entity <- N<Entity>::WHERE(_::{name}::EQ(name))::FIRST
   RETURN entity
+// Typed entity identity (`entity_identity = name_and_type`): same name,
+// different type is a different entity.
+QUERY getEntityByNameAndType(name: String, entity_type: String) =>
+  entity <- N<Entity>::WHERE(AND(_::{name}::EQ(name), _::{entity_type}::EQ(entity_type)))::FIRST
+  RETURN entity
 QUERY createEntity(entity_id: String, name: String, entity_type: String, properties: String, aliases: String) =>
   entity <- AddN<Entity>({
     entity_id: entity_id,
@@ -193,6 +260,17 @@ QUERY createEntity(entity_id: String, name: String, entity_type: String, propert
     aliases: aliases
   })
   RETURN entity
+// Every memory linked to an entity, either link kind (get_entity).
+// The knower buckets pair up through the link edges' from_node/to_node.
+QUERY getEntityMemories(entity_id: String) =>
+  entity <- N<Entity>::WHERE(_::{entity_id}::EQ(entity_id))::FIRST
+  extracted <- entity::In<EXTRACTED_ENTITY>
+  mentioned <- entity::In<MENTIONS>
+  extracted_links <- extracted::InE<HAS_MEMORY>
+  mentioned_links <- mentioned::InE<HAS_MEMORY>
+  extracted_knowers <- extracted::In<HAS_MEMORY>
+  mentioned_knowers <- mentioned::In<HAS_MEMORY>
+  RETURN extracted, mentioned, extracted_links, mentioned_links, extracted_knowers, mentioned_knowers
 QUERY linkExtractedEntity(memory_id: String, entity_id: String, confidence: I64, method: String) =>
   memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
   entity <- N<Entity>::WHERE(_::{entity_id}::EQ(entity_id))::FIRST
@@ -498,6 +576,18 @@ QUERY getUserMemories(user_id: String, limit: I64) =>
   memories <- user::Out<HAS_MEMORY>::RANGE(0, limit)
   RETURN memories
 
+QUERY getUserMemoriesPage(user_id: String, start: I64, end: I64) =>
+  user <- N<User>::WHERE(_::{user_id}::EQ(user_id))::FIRST
+  memories <- user::Out<HAS_MEMORY>::RANGE(start, end)
+  RETURN memories
+
+// Newest first by created_at, for browsing and recency scans that must not
+// stop at the oldest rows.
+QUERY getUserMemoriesNewest(user_id: String, start: I64, end: I64) =>
+  user <- N<User>::WHERE(_::{user_id}::EQ(user_id))::FIRST
+  memories <- user::Out<HAS_MEMORY>::ORDER<Desc>(_::{created_at})::RANGE(start, end)
+  RETURN memories
+
 QUERY getMemoryEntities(memory_id: String) =>
   memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
   entities <- memory::Out<EXTRACTED_ENTITY>
@@ -513,6 +603,12 @@ QUERY getMemoriesByEntity(entity_id: String, exclude_memory_id: String, limit: I
   memories <- entity::In<EXTRACTED_ENTITY>::WHERE(_::{memory_id}::NEQ(exclude_memory_id))::RANGE(0, limit)
   RETURN memories
 
+QUERY getMemoryConceptsBatch(memory_ids: [String]) =>
+  memories <- N<Memory>::WHERE(_::{memory_id}::IS_IN(memory_ids))
+  instance_edges <- memories::OutE<INSTANCE_OF>
+  concepts <- memories::Out<INSTANCE_OF>
+  RETURN memories, instance_edges, concepts
+
 QUERY getMemoryConcepts(memory_id: String) =>
   memory <- N<Memory>::WHERE(_::{memory_id}::EQ(memory_id))::FIRST
   instance_of <- memory::Out<INSTANCE_OF>
@@ -813,6 +909,48 @@ QUERY markNoticeDelivered(notice_id: String) =>
   notices <- N<MemoryNotice>::WHERE(_::{notice_id}::EQ(notice_id))
   updated <- notices::UPDATE({ delivered: 1 })
   RETURN updated
+QUERY addImportCheckpoint(import_id: String, user_id: String, total: I64, next_index: I64, updated_at: String) =>
+  checkpoint <- AddN<ImportCheckpoint>({ import_id: import_id, user_id: user_id, total: total, next_index: next_index, updated_at: updated_at })
+  RETURN checkpoint
+QUERY getImportCheckpoint(import_id: String) =>
+  checkpoint <- N<ImportCheckpoint>::WHERE(_::{import_id}::EQ(import_id))::FIRST
+  RETURN checkpoint
+QUERY updateImportCheckpoint(import_id: String, next_index: I64, updated_at: String) =>
+  checkpoint <- N<ImportCheckpoint>::WHERE(_::{import_id}::EQ(import_id))::FIRST
+  updated <- checkpoint::UPDATE({ next_index: next_index, updated_at: updated_at })
+  RETURN updated
+QUERY deleteImportCheckpoint(import_id: String) =>
+  DROP N<ImportCheckpoint>::WHERE(_::{import_id}::EQ(import_id))
+  RETURN "ok"
+QUERY addUserConfig(user_id: String, overrides: String, updated_at: String) =>
+  user_config <- AddN<UserConfig>({ user_id: user_id, overrides: overrides, updated_at: updated_at })
+  RETURN user_config
+QUERY getUserConfig(user_id: String) =>
+  user_config <- N<UserConfig>::WHERE(_::{user_id}::EQ(user_id))::FIRST
+  RETURN user_config
+QUERY updateUserConfig(user_id: String, overrides: String, updated_at: String) =>
+  user_config <- N<UserConfig>::WHERE(_::{user_id}::EQ(user_id))::FIRST
+  updated <- user_config::UPDATE({ overrides: overrides, updated_at: updated_at })
+  RETURN updated
+QUERY dropUserConfig(user_id: String) =>
+  DROP N<UserConfig>::WHERE(_::{user_id}::EQ(user_id))
+  RETURN "ok"
+QUERY addEntityAttributes(entity_id: String, user_id: String, attributes: String, updated_at: String) =>
+  entity_attributes <- AddN<EntityAttributes>({ entity_id: entity_id, user_id: user_id, attributes: attributes, updated_at: updated_at })
+  RETURN entity_attributes
+QUERY getEntityAttributes(entity_id: String, user_id: String) =>
+  entity_attributes <- N<EntityAttributes>::WHERE(AND(_::{entity_id}::EQ(entity_id), _::{user_id}::EQ(user_id)))::FIRST
+  RETURN entity_attributes
+QUERY updateEntityAttributes(entity_id: String, user_id: String, attributes: String, updated_at: String) =>
+  entity_attributes <- N<EntityAttributes>::WHERE(AND(_::{entity_id}::EQ(entity_id), _::{user_id}::EQ(user_id)))::FIRST
+  updated <- entity_attributes::UPDATE({ attributes: attributes, updated_at: updated_at })
+  RETURN updated
+QUERY getUserEntityAttributes(user_id: String) =>
+  entity_attributes <- N<EntityAttributes>::WHERE(_::{user_id}::EQ(user_id))
+  RETURN entity_attributes
+QUERY dropUserEntityAttributes(user_id: String) =>
+  DROP N<EntityAttributes>::WHERE(_::{user_id}::EQ(user_id))
+  RETURN "ok"
 // --- Clotho category dictionary queries — Moira #33 (additive) ---
 QUERY addCategory(category_id: String, name: String, kind: String, description: String, created_at: String) =>
   category <- AddN<Category>({ category_id: category_id, name: name, kind: kind, description: description, created_at: created_at })
@@ -863,6 +1001,30 @@ QUERY dropMemoryCascadeByInternalId(memory_internal_id: ID) =>
   DROP N<Memory>(memory_internal_id)
   RETURN "deleted"
 
+// purge_user: an entity is shared graph state — it is only dropped once no
+// memory (of any user) still extracts or mentions it.
+QUERY countEntityMemoryLinks(entity_id: String) =>
+  entity <- N<Entity>::WHERE(_::{entity_id}::EQ(entity_id))::FIRST
+  extracted <- entity::In<EXTRACTED_ENTITY>::COUNT
+  mentioned <- entity::In<MENTIONS>::COUNT
+  RETURN extracted, mentioned
+
+QUERY dropEntityCascadeByInternalId(entity_internal_id: ID) =>
+  DROP N<Entity>(entity_internal_id)::Out<ENTITY_HAS_EMBEDDING>
+  DROP N<Entity>(entity_internal_id)
+  RETURN "deleted"
+
+// purge_user: drop one knower's link; the memory itself is dropped only
+// once getMemoryUserCount reads 0.
+QUERY unlinkUserFromMemory(user_id: String, memory_id: String) =>
+  user <- N<User>::WHERE(_::{user_id}::EQ(user_id))::FIRST
+  DROP user::OutE<HAS_MEMORY>::WHERE(_::ToN::{memory_id}::EQ(memory_id))
+  RETURN "unlinked"
+
+QUERY dropUserByUserId(user_id: String) =>
+  DROP N<User>::WHERE(_::{user_id}::EQ(user_id))
+  RETURN "deleted"
+
 QUERY getCategoryAliases(category_id: String) =>
   category <- N<Category>::WHERE(_::{category_id}::EQ(category_id))::FIRST
   aliases_out <- category::Out<ALIAS_OF>
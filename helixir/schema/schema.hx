@@ -44,7 +44,10 @@ N::Memory {
   is_deleted: I64 DEFAULT 0,
   deleted_at: String DEFAULT "",
   deleted_by: String DEFAULT "",
-  user_count: I64 DEFAULT 1
+  user_count: I64 DEFAULT 1,
+  access_count: I64 DEFAULT 0,
+  last_accessed_at: String DEFAULT "",
+  reinforced_at: String DEFAULT ""
 }
 N::Entity {
   entity_id: String,
@@ -202,7 +205,8 @@ E::MEMORY_RELATION {
     relation_type: String,
     strength: I64,
     created_at: String,
-    metadata: String
+    metadata: String,
+    created_by: String DEFAULT ""
   }
 }
 E::IMPLIES {
@@ -230,6 +234,13 @@ E::CONTRADICTS {
     resolution_strategy: String
   }
 }
+E::EXTRACTED_FROM {
+  From: Memory,
+  To: Memory,
+  Properties: {
+    created_at: String
+  }
+}
 E::SUPERSEDES {
   From: Memory,
   To: Memory,
@@ -331,6 +342,31 @@ N::MemoryNotice {
   delivered: I64 DEFAULT 0
 }
 
+// Progress marker of a batch import: the next message index still to run.
+N::ImportCheckpoint {
+  import_id: String,
+  user_id: String DEFAULT "",
+  total: I64 DEFAULT 0,
+  next_index: I64 DEFAULT 0,
+  updated_at: String DEFAULT "{{timestamp}}"
+}
+// Per-user config overrides (set_user_config): `overrides` is the JSON of
+// UserConfigOverrides, merged over the global config for that user.
+N::UserConfig {
+  user_id: String,
+  overrides: String DEFAULT "{}",
+  updated_at: String DEFAULT "{{timestamp}}"
+}
+
+// Attributes one user set on a shared entity (set_entity_attribute), as a
+// JSON object. The entity's own `properties` stay the writer's extraction.
+N::EntityAttributes {
+  entity_id: String,
+  user_id: String,
+  attributes: String DEFAULT "{}",
+  updated_at: String DEFAULT "{{timestamp}}"
+}
+
 // --- Clotho category dictionary (controlled vocabulary) — Moira #33 ---
 N::Category {
   category_id: String,
@@ -15,6 +15,7 @@ use tracing::{debug, info, warn};
 use crate::llm::providers::base::LlmProvider;
 use crate::toolkit::tooling_manager::ToolingManager;
 use crate::toolkit::tooling_manager::types::{Clarification, ToolingError};
+use crate::utils::cosine_similarity as cosine;
 
 // Dominance margin (grow-pass: tag only categories within this much of the best
 // match — keeps the top domain(s), drops the noise-floor smear that wove
@@ -424,47 +425,10 @@ impl<'a> Clotho<'a> {
     }
 }
 
-/// Cosine similarity of two equal-length vectors; `0.0` on length mismatch or a
-/// zero-norm vector.
-fn cosine(a: &[f32], b: &[f32]) -> f64 {
-    if a.len() != b.len() || a.is_empty() {
-        return 0.0;
-    }
-    let (mut dot, mut na, mut nb) = (0f64, 0f64, 0f64);
-    for (x, y) in a.iter().zip(b.iter()) {
-        let (x, y) = (*x as f64, *y as f64);
-        dot += x * y;
-        na += x * x;
-        nb += y * y;
-    }
-    if na == 0.0 || nb == 0.0 {
-        return 0.0;
-    }
-    dot / (na.sqrt() * nb.sqrt())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn cosine_identical_is_one() {
-        let v = [0.3f32, 0.4, 0.5];
-        assert!((cosine(&v, &v) - 1.0).abs() < 1e-6);
-    }
-
-    #[test]
-    fn cosine_orthogonal_is_zero() {
-        assert!(cosine(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-9);
-    }
-
-    #[test]
-    fn cosine_degenerate_is_zero() {
-        assert_eq!(cosine(&[1.0, 2.0], &[1.0]), 0.0); // length mismatch
-        assert_eq!(cosine(&[0.0, 0.0], &[1.0, 1.0]), 0.0); // zero-norm vector
-        assert_eq!(cosine(&[], &[]), 0.0); // empty
-    }
-
     #[test]
     fn ancestors_walk_the_seed_hierarchy() {
         assert_eq!(dictionary::ancestors("agriculture"), vec!["raw material"]);
@@ -103,6 +103,94 @@ impl Default for RetryConfig {
     }
 }
 
+/// HelixDB connection pool (`HELIXIR_DB_POOL_SIZE`,
+/// `HELIXIR_DB_POOL_TIMEOUT_MS`): how many queries may be in flight at
+/// once, and how long a query waits for a free connection before failing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DbPoolConfig {
+    pub size: usize,
+    pub acquire_timeout_ms: u64,
+}
+impl Default for DbPoolConfig {
+    fn default() -> Self {
+        Self {
+            size: 16,
+            acquire_timeout_ms: 10_000,
+        }
+    }
+}
+
+/// Rolling summaries (`summarize_recent`): which memories one summary
+/// consolidates and what happens to them afterwards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SummaryConfig {
+    /// How far back a summary reaches (`HELIXIR_SUMMARY_WINDOW_HOURS`).
+    pub window_hours: u64,
+    /// Newest memories one summary covers at most.
+    pub max_sources: usize,
+    /// Fewer sources than this and no summary is written.
+    pub min_sources: usize,
+    /// Supersede the sources by the summary (`HELIXIR_SUMMARY_ARCHIVE`):
+    /// they stay reachable but rank below it, and later runs skip them.
+    pub archive_sources: bool,
+    /// Score multiplier for summaries in `recent` searches; 1.0 disables.
+    pub recent_boost: f64,
+    /// Strength of each source's SUPPORTS edge to its summary.
+    pub support_strength: u32,
+}
+impl Default for SummaryConfig {
+    fn default() -> Self {
+        Self {
+            window_hours: 24,
+            max_sources: 50,
+            min_sources: 3,
+            archive_sources: false,
+            recent_boost: 1.25,
+            support_strength: 60,
+        }
+    }
+}
+
+/// Stale-memory review (`find_stale`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StaleConfig {
+    /// Age after which present-tense or versioned wording is suspect
+    /// (`HELIXIR_STALE_AFTER_DAYS`).
+    pub min_age_days: u64,
+    /// Candidates one review returns at most.
+    pub max_results: usize,
+}
+impl Default for StaleConfig {
+    fn default() -> Self {
+        Self {
+            min_age_days: 30,
+            max_results: 50,
+        }
+    }
+}
+
+/// Topic map (`cluster_memories`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClusterConfig {
+    /// Largest cluster count the silhouette search tries when no `k` is
+    /// given (`HELIXIR_CLUSTER_MAX_K`).
+    pub max_k: usize,
+    /// Newest memories one run clusters at most.
+    pub max_memories: usize,
+}
+impl Default for ClusterConfig {
+    fn default() -> Self {
+        Self {
+            max_k: 10,
+            max_memories: 500,
+        }
+    }
+}
+
 /// Per-family structural edge weights for graph ranking.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(default)]
@@ -222,6 +310,28 @@ pub struct RetrievalConfig {
     /// reachable (and honestly flagged `superseded` in metadata). 1.0
     /// disables the demotion.
     pub superseded_penalty: f64,
+    /// Max chars of the original message attached as `source_excerpt` to
+    /// search rows extracted from a stored raw source (`store_source`).
+    /// 0 disables the lookup.
+    pub source_excerpt_chars: usize,
+    /// Minimum seconds between two access-stat writes for one memory; hits
+    /// in between are counted in process and flushed together, at the
+    /// memory's next hit or the tracker's next sweep.
+    pub access_throttle_secs: u64,
+    /// Map applied to search scores before `min_score` and the recall
+    /// floors; the raw score stays in `metadata.raw_score`.
+    pub score_normalization: ScoreNormalization,
+    /// Raw score the sigmoid maps to 0.5: a borderline match for the
+    /// embedding model in use.
+    pub score_sigmoid_midpoint: f64,
+    pub score_sigmoid_steepness: f64,
+    /// Score multiplier for rows of the ontology type a search names in
+    /// `boost_concept`. 1.0 disables it.
+    pub concept_boost: f64,
+    /// Seeds `search_reasoning_chain` expands at once
+    /// (`HELIXIR_CHAIN_SEED_CONCURRENCY`). Each expansion's queries still
+    /// wait for the shared DB pool; 1 expands one seed at a time.
+    pub chain_seed_concurrency: usize,
     pub search_modes: SearchModesConfig,
 }
 impl Default for RetrievalConfig {
@@ -241,6 +351,13 @@ impl Default for RetrievalConfig {
             flashback_max: 3,
             rerank_max_rows: 128,
             superseded_penalty: 0.6,
+            source_excerpt_chars: 240,
+            access_throttle_secs: 300,
+            score_normalization: ScoreNormalization::None,
+            score_sigmoid_midpoint: 0.5,
+            score_sigmoid_steepness: 10.0,
+            concept_boost: 1.5,
+            chain_seed_concurrency: 4,
             search_modes: SearchModesConfig::default(),
         }
     }
@@ -284,6 +401,120 @@ impl SearchModesConfig {
             SearchMode::Full => &self.full,
         }
     }
+
+    /// Result cap of a search in `mode` that names no `limit`, so a `full`
+    /// recall can't come back with thousands of rows. Env:
+    /// `HELIXIR_SEARCH_LIMIT_RECENT` / `_CONTEXTUAL` / `_DEEP` / `_FULL`.
+    #[must_use]
+    pub fn limit_for(&self, mode: crate::core::search_modes::SearchMode) -> usize {
+        self.for_mode(mode).max_results
+    }
+}
+
+/// The recall breadth behind each search mode name. Since #31 no preset
+/// hard-excludes old facts; the window is the mode's freshness horizon — the
+/// decay scale of the temporal ranking component — so a short `recent`
+/// window leans on the last few hours and a long `deep` one still credits a
+/// quarter-old fact. A mode left unset, and `full` always, decays over the
+/// global `search_thresholds.default_temporal_days`, the scale every mode
+/// used before the windows existed, so ranking only moves when an operator
+/// sets one.
+/// Env: `HELIXIR_WINDOW_RECENT_HOURS` / `_CONTEXTUAL_DAYS` / `_DEEP_DAYS`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchWindowsConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recent_hours: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contextual_days: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deep_days: Option<f64>,
+}
+impl SearchWindowsConfig {
+    /// The mode's window in days; `None` for `full` and for a mode with no
+    /// window set.
+    #[must_use]
+    pub fn horizon_days(&self, mode: crate::core::search_modes::SearchMode) -> Option<f64> {
+        use crate::core::search_modes::SearchMode;
+        match mode {
+            SearchMode::Recent => self.recent_hours.map(|h| h / 24.0),
+            SearchMode::Contextual => self.contextual_days,
+            SearchMode::Deep => self.deep_days,
+            SearchMode::Full => None,
+        }
+    }
+}
+
+/// One user's overrides, stored in HelixDB (`set_user_config`) and laid
+/// over the global config for that user's requests (see
+/// [`HelixirConfig::for_user`]). Precedence, highest first: the call's own
+/// argument, this override, the global config (file, then env), the
+/// built-in default. Unset fields follow the global config.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UserConfigOverrides {
+    /// Mode of a search that names none (`default_search_mode`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_search_mode: Option<String>,
+    /// Result cap of a search that names no `limit`, in every mode
+    /// (each mode's `retrieval.search_modes.<mode>.max_results`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_limit: Option<usize>,
+    /// `windows.recent_hours`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recent_hours: Option<f64>,
+    /// `windows.contextual_days`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contextual_days: Option<f64>,
+    /// `windows.deep_days`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deep_days: Option<f64>,
+}
+
+impl UserConfigOverrides {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Why these values cannot be stored, if they cannot.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(mode) = &self.default_search_mode
+            && !["recent", "contextual", "deep", "full"].contains(&mode.as_str())
+        {
+            return Err(format!(
+                "default_search_mode must be recent, contextual, deep or full, got '{mode}'"
+            ));
+        }
+        if self.search_limit == Some(0) {
+            return Err("search_limit must be at least 1".to_string());
+        }
+        for (key, value) in [
+            ("recent_hours", self.recent_hours),
+            ("contextual_days", self.contextual_days),
+            ("deep_days", self.deep_days),
+        ] {
+            if value.is_some_and(|v| !v.is_finite() || v <= 0.0) {
+                return Err(format!("{key} must be a positive number"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Overwrite the fields `patch` sets; the rest stay.
+    pub fn merge(&mut self, patch: Self) {
+        let Self {
+            default_search_mode,
+            search_limit,
+            recent_hours,
+            contextual_days,
+            deep_days,
+        } = patch;
+        self.default_search_mode = default_search_mode.or(self.default_search_mode.take());
+        self.search_limit = search_limit.or(self.search_limit);
+        self.recent_hours = recent_hours.or(self.recent_hours);
+        self.contextual_days = contextual_days.or(self.contextual_days);
+        self.deep_days = deep_days.or(self.deep_days);
+    }
 }
 
 /// Clotho (the Spinner) policy.
@@ -471,6 +702,169 @@ pub struct MoiraConfig {
     pub daemon: MoiraDaemonConfig,
 }
 
+/// What `add_memory` does when extraction yields no atomic facts
+/// (`HELIXIR_ON_EMPTY_EXTRACTION`). Every outcome is flagged
+/// `no_facts_extracted` so the agent never mistakes it for a normal save.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmptyExtractionPolicy {
+    /// Keep the whole message as one fallback memory (the historic behavior).
+    #[default]
+    StoreRaw,
+    /// Store nothing; the agent is told so and can rephrase.
+    Skip,
+    /// Fail the write.
+    Error,
+}
+
+impl EmptyExtractionPolicy {
+    /// Lenient parse; `None` on an unrecognized value so the caller keeps
+    /// whatever was configured before.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "store_raw" | "raw" => Some(Self::StoreRaw),
+            "skip" => Some(Self::Skip),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// What `add_memory` does when embedding the extracted facts fails
+/// (`HELIXIR_ON_EMBED_FAIL`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnEmbedFail {
+    /// Fail the write; nothing is stored (the historic behavior).
+    #[default]
+    Fail,
+    /// Store the facts and their graph links without a vector, flagged
+    /// `needs_embedding` in metadata for `reindex_embeddings` to fill in.
+    Defer,
+}
+
+impl OnEmbedFail {
+    /// Lenient parse; `None` on an unrecognized value.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "fail" | "error" => Some(Self::Fail),
+            "defer" => Some(Self::Defer),
+            _ => None,
+        }
+    }
+}
+
+/// What a FastThink session's `thinking_timeout` counts from
+/// (`HELIXIR_THINK_TIMEOUT_MODE`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThinkTimeoutMode {
+    /// From `think_start`: a session gets a fixed budget however busy it is.
+    #[default]
+    Absolute,
+    /// From the last mutation (thought, link, entity, concept, note): an
+    /// active session keeps going, an abandoned one still times out.
+    Idle,
+}
+
+impl ThinkTimeoutMode {
+    /// Lenient parse; `None` on an unrecognized value.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "absolute" => Some(Self::Absolute),
+            "idle" | "sliding" => Some(Self::Idle),
+            _ => None,
+        }
+    }
+}
+
+/// What makes two entities the same one (`HELIXIR_ENTITY_IDENTITY`), in the
+/// main graph and in FastThink scratch sessions alike.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityIdentity {
+    /// The case-insensitive name: "Python" is one entity whatever its type.
+    #[default]
+    Name,
+    /// Name and type: "Python" the technology and "Python" the animal stay
+    /// distinct.
+    NameAndType,
+}
+
+impl EntityIdentity {
+    /// Lenient parse; `None` on an unrecognized value.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "name" => Some(Self::Name),
+            "name_and_type" | "typed" => Some(Self::NameAndType),
+            _ => None,
+        }
+    }
+}
+
+/// Write-time duplicate check (`HELIXIR_DEDUP_ON_ADD`): what `add` does
+/// with a fact whose closest existing memory is at least
+/// `dedup_on_add_threshold` similar. The write-time complement to
+/// `consolidate`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupOnAddPolicy {
+    /// No extra check; only the decision engine's own gates apply.
+    #[default]
+    Off,
+    /// Store as usual, but report the near-duplicate with a suggestion to
+    /// update it instead.
+    Warn,
+    /// Store nothing for that fact and return the existing memory as a
+    /// duplicate. Charter-protected types are only warned about, never
+    /// skipped: a near-identical preference may be a change of mind.
+    Skip,
+}
+
+impl DedupOnAddPolicy {
+    /// Lenient parse; `None` on an unrecognized value so the caller keeps
+    /// whatever was configured before.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "off" | "" => Some(Self::Off),
+            "warn" => Some(Self::Warn),
+            "skip" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+
+/// How `search` maps raw scores onto `[0, 1]` before any threshold applies
+/// (`HELIXIR_SCORE_NORMALIZATION`). Raw similarity depends on the embedding
+/// model and metric, so a `min_score` tuned for one model means nothing for
+/// another; a normalized score does not. Both maps keep the ranking.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreNormalization {
+    /// Scores are returned as ranked (the historic behavior).
+    #[default]
+    None,
+    /// Min-max over the candidate set: the best row scores 1, the worst 0.
+    /// Relative, so a threshold keeps "the top part of this result set".
+    MinMax,
+    /// `1 / (1 + e^(-steepness * (raw - midpoint)))` with the calibrated
+    /// `score_sigmoid_midpoint` / `score_sigmoid_steepness`. Absolute, so a
+    /// weak result set stays weak.
+    Sigmoid,
+}
+
+impl ScoreNormalization {
+    /// Lenient parse; `None` on an unrecognized value.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "none" | "off" | "" => Some(Self::None),
+            "minmax" | "min_max" => Some(Self::MinMax),
+            "sigmoid" => Some(Self::Sigmoid),
+            _ => None,
+        }
+    }
+}
+
 /// Write-path (add pipeline) policy values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -512,6 +906,23 @@ pub struct WriteConfig {
     /// charter_deferred CONTRADICTS edge, and let the agent settle it with
     /// resolve_contradiction (retract = the supersede happens then).
     pub charter_blocking: bool,
+    pub on_empty_extraction: EmptyExtractionPolicy,
+    pub on_embed_fail: OnEmbedFail,
+    pub dedup_on_add: DedupOnAddPolicy,
+    /// Cosine similarity at which `dedup_on_add` treats an existing memory
+    /// as a duplicate (`HELIXIR_DEDUP_ON_ADD_THRESHOLD`).
+    pub dedup_on_add_threshold: f64,
+    /// Extra attempts per message in a batch import before the batch stops
+    /// at that message and hands back a resume token
+    /// (`HELIXIR_IMPORT_MAX_RETRIES`).
+    pub import_max_retries: u32,
+    /// Backoff before the first retry; doubles on each further attempt.
+    pub import_retry_backoff_ms: u64,
+    /// An `add` identical to one still running (same user, agent, tags and
+    /// message) waits for it and returns its result; a finished result is
+    /// handed out for this many seconds more. 0 runs every add
+    /// (`HELIXIR_CONCURRENT_ADD_WINDOW_SECS`).
+    pub concurrent_add_window_secs: u64,
 }
 impl Default for WriteConfig {
     fn default() -> Self {
@@ -536,6 +947,13 @@ impl Default for WriteConfig {
             nli_route: true,
             nli_route_min_prob: 0.85,
             charter_blocking: true,
+            on_empty_extraction: EmptyExtractionPolicy::StoreRaw,
+            on_embed_fail: OnEmbedFail::Fail,
+            dedup_on_add: DedupOnAddPolicy::Off,
+            dedup_on_add_threshold: 0.92,
+            import_max_retries: 2,
+            import_retry_backoff_ms: 500,
+            concurrent_add_window_secs: 10,
         }
     }
 }
@@ -635,6 +1053,50 @@ impl Default for GatewayConfig {
     }
 }
 
+/// Outbound post-commit webhook (see `helixir_client::webhook`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookConfig {
+    /// Endpoint POSTed `{event, memory_id, user_id, timestamp}` for every
+    /// stored or committed memory (`HELIXIR_WEBHOOK_URL`). `None` disables it.
+    pub url: Option<String>,
+    /// Extra attempts per delivery before it is logged and dropped.
+    pub max_retries: u32,
+    /// Backoff before the first retry; doubles on each further attempt.
+    pub retry_backoff_ms: u64,
+    pub timeout_secs: u64,
+}
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            max_retries: 3,
+            retry_backoff_ms: 500,
+            timeout_secs: 10,
+        }
+    }
+}
+
+/// Custom LLM prompt templates (see `llm::prompt_template`). Unset fields
+/// keep the built-in prompts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PromptConfig {
+    /// Fact-extraction prompt file; must contain `{text}`
+    /// (`HELIXIR_EXTRACTION_PROMPT_PATH`).
+    pub extraction_path: Option<String>,
+    /// Relation-inference prompt file; `{text}` receives the new memories
+    /// and their candidates (`HELIXIR_RELATION_PROMPT_PATH`).
+    pub relation_path: Option<String>,
+    /// Language of the cognitive protocol, server instructions and concept
+    /// keywords (`HELIXIR_LANG`, e.g. `ru`); unset is English. See
+    /// `core::locale`.
+    pub lang: Option<String>,
+    /// Where translations live, one subdirectory per language
+    /// (`HELIXIR_LOCALE_DIR`). Default: ~/.helixir/locales.
+    pub locale_dir: Option<String>,
+}
+
 /// LLM/embedding runtime knobs that were previously hardcoded at provider
 /// construction (ollama request timeout, embedding cache sizing).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -646,6 +1108,16 @@ pub struct LlmRuntimeConfig {
     pub embedding_cache_size: usize,
     /// Embedding cache entry TTL (seconds).
     pub embedding_cache_ttl_secs: u64,
+    /// Run one tiny embedding and one trivial LLM call in
+    /// `HelixirClient::initialize` so the first real request is not cold
+    /// (`HELIXIR_WARM_UP`). Off by default: it costs startup time and a
+    /// paid call.
+    pub warm_up: bool,
+    /// List prices `estimate_add` costs an extraction with
+    /// (`[[llm_runtime.pricing]]` in `helixir.toml`). Setting it replaces
+    /// the shipped table; a model missing from it is estimated as `null`.
+    /// Local Ollama models are free whatever the table says.
+    pub pricing: Vec<ModelPrice>,
 }
 impl Default for LlmRuntimeConfig {
     fn default() -> Self {
@@ -653,10 +1125,41 @@ impl Default for LlmRuntimeConfig {
             request_timeout_secs: crate::DEFAULT_LLM_REQUEST_TIMEOUT_SECS,
             embedding_cache_size: crate::DEFAULT_CACHE_SIZE,
             embedding_cache_ttl_secs: crate::DEFAULT_CACHE_TTL,
+            warm_up: false,
+            pricing: default_model_prices(),
         }
     }
 }
 
+/// One model's price, USD per million tokens (`llm_runtime.pricing`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub provider: String,
+    pub model: String,
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+}
+
+/// Public list prices of the models Helixir ships with, at the time of
+/// writing.
+fn default_model_prices() -> Vec<ModelPrice> {
+    [
+        ("cerebras", "gpt-oss-120b", 0.35, 0.75),
+        ("cerebras", "llama3.1-8b", 0.10, 0.10),
+        ("cerebras", "llama-3.3-70b", 0.85, 1.20),
+        ("deepseek", "deepseek-chat", 0.27, 1.10),
+        ("deepseek", "deepseek-v4-flash", 0.27, 1.10),
+    ]
+    .into_iter()
+    .map(|(provider, model, input, output)| ModelPrice {
+        provider: provider.to_string(),
+        model: model.to_string(),
+        input_per_mtok: input,
+        output_per_mtok: output,
+    })
+    .collect()
+}
+
 /// FastThink (think_* tools) session limits. Defaults match the MCP preset
 /// (`FastThinkLimits::mcp`) — the profile the live server runs with.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -667,6 +1170,9 @@ pub struct FastThinkConfig {
     pub max_concepts: usize,
     pub max_depth: usize,
     pub thinking_timeout_secs: u64,
+    /// Whether `thinking_timeout_secs` runs from `think_start` (`absolute`,
+    /// default) or from the session's last mutation (`idle`).
+    pub timeout_mode: ThinkTimeoutMode,
     pub session_ttl_secs: u64,
     pub max_recall_results: usize,
     /// Fast-commit ceiling: conclusions up to this many chars skip the
@@ -681,6 +1187,17 @@ pub struct FastThinkConfig {
     /// Strength of the SUPPORTS provenance edge written from each recalled
     /// evidence memory to the committed conclusion.
     pub commit_support_strength: u32,
+    /// Default for think_commit's `auto_classify`: one LLM call types the
+    /// conclusion into the ontology (INSTANCE_OF) so search_by_concept finds
+    /// it. Off by default — it adds an LLM round-trip to every commit.
+    pub commit_auto_classify: bool,
+    /// Default for think_commit's `reconcile_counts`
+    /// (`HELIXIR_COMMIT_RECONCILE_COUNTS`): wait for main memory's entity
+    /// extraction on the conclusion and report the entities and concepts
+    /// the stored graph links to it, instead of the session's scratch
+    /// counts (usually zero — scratch extraction is manual). Off by default:
+    /// it puts that extraction call back on the commit's critical path.
+    pub commit_reconcile_counts: bool,
     /// Score floor for think_recall: rows below this combined score never
     /// enter the session, even inside the top-K. Measured on the live store
     /// (#81): seeds sit at 0.68–0.99, the graph-expansion tail flattens at
@@ -700,6 +1217,53 @@ pub struct FastThinkConfig {
     /// the agent can always add a synthesis thought and conclude — recalled
     /// evidence must never trap the session at the cap.
     pub conclude_reserve: usize,
+    /// Convergence hint: when the last `convergence_window` agent thoughts
+    /// all carry certainty >= `convergence_min_certainty` with variance <=
+    /// `convergence_max_variance`, think_add returns `should_conclude: true`.
+    /// A nudge only — nothing is refused. 0 disables.
+    pub convergence_window: usize,
+    pub convergence_min_certainty: f32,
+    pub convergence_max_variance: f32,
+    /// think_recall returns a preview of each recalled thought cut to this
+    /// many characters, so the agent sees what came back without a
+    /// think_status round trip. 0 leaves previews out.
+    pub recall_preview_chars: usize,
+    /// Gateway: key each connection's sessions under its own prefix so
+    /// generic ids ("plan", "s1") from different agents never collide
+    /// (`HELIXIR_SESSION_NAMESPACE`). Callers keep their short ids;
+    /// think_list shows a connection only its own sessions. Off by default:
+    /// a namespaced session is reachable only from the connection that
+    /// opened it, so a reconnect (or a blue/green hand-off) cannot pick it
+    /// up again.
+    pub namespace_sessions: bool,
+    /// think_recall links each recalled thought to the most similar
+    /// non-recall thought already in the session (SUPPORTS, or CONTRADICTS
+    /// when exactly one side is negated), so recalled evidence lands next
+    /// to the hypothesis it bears on (`HELIXIR_AUTO_LINK_RECALLS`). Off by
+    /// default: it embeds the session's thoughts on every recall.
+    pub auto_link_recalls: bool,
+    /// Cosine floor (-1..1) for an auto-link; below it the recall stays a
+    /// plain child of its parent.
+    pub auto_link_min_similarity: f32,
+    /// think_discard refuses a session that reached a conclusion unless the
+    /// call passes `force: true` — discard-instead-of-commit would otherwise
+    /// lose the reasoned result silently. false restores the old behavior.
+    pub confirm_discard_concluded: bool,
+    /// think_conclude refuses a session with fewer reasoning steps than
+    /// this (thoughts other than the root and conclusions), so the agent
+    /// cannot skip straight from question to answer
+    /// (`HELIXIR_MIN_THOUGHTS_BEFORE_CONCLUDE`). 0, the default, allows it.
+    pub min_thoughts_before_conclude: usize,
+    /// The think tools accept raw graph indices (`parent_idx`,
+    /// `supporting_idx`, `relation_to`) next to the stable thought ids
+    /// (`parent_id`, `supporting_ids`, `relation_to_id`). false makes the
+    /// ids mandatory (`HELIXIR_ACCEPT_THOUGHT_IDX=0`); responses carry both
+    /// either way.
+    pub accept_thought_idx: bool,
+    /// Directory of reasoning templates for `think_template`
+    /// (`HELIXIR_THINK_TEMPLATES_DIR`), read at startup and on reload;
+    /// `None` offers none.
+    pub templates_dir: Option<String>,
 }
 impl Default for FastThinkConfig {
     fn default() -> Self {
@@ -709,16 +1273,30 @@ impl Default for FastThinkConfig {
             max_concepts: 40,
             max_depth: 12,
             thinking_timeout_secs: 90,
+            timeout_mode: ThinkTimeoutMode::Absolute,
             session_ttl_secs: 600,
             max_recall_results: 8,
             commit_extract_over_chars: 900,
             commit_certainty: 75,
             commit_importance: 60,
             commit_support_strength: 60,
+            commit_auto_classify: false,
+            commit_reconcile_counts: false,
             recall_min_score: 0.6,
             recall_fallback_min_score: 0.45,
             recall_fallback_max: 3,
             conclude_reserve: 2,
+            convergence_window: 3,
+            convergence_min_certainty: 0.8,
+            convergence_max_variance: 0.01,
+            recall_preview_chars: 160,
+            namespace_sessions: false,
+            auto_link_recalls: false,
+            auto_link_min_similarity: 0.75,
+            confirm_discard_concluded: true,
+            min_thoughts_before_conclude: 0,
+            accept_thought_idx: true,
+            templates_dir: None,
         }
     }
 }
@@ -847,26 +1425,70 @@ pub struct HelixirConfig {
     pub embedding_fallback_enabled: bool,
     pub embedding_fallback_url: String,
     pub embedding_fallback_model: String,
+    /// Length every embedding must have (`HELIXIR_EMBEDDING_DIM`); unset
+    /// adopts the first vector's length. A mismatch fails the call instead
+    /// of storing a vector the index cannot compare.
+    pub embedding_dim: Option<usize>,
+    /// Texts per embedding request when many are embedded at once
+    /// (`HELIXIR_EMBEDDING_BATCH_SIZE`), e.g. the atoms of one `add`.
+    pub embedding_batch_size: usize,
 
     pub default_certainty: u8,
     pub default_importance: u8,
 
-    pub default_search_limit: usize,
     pub default_search_mode: String,
+    /// Half-life in days of a memory's ranking weight in search, counted
+    /// from its last write or re-add — not from search hits
+    /// (`HELIXIR_CONFIDENCE_DECAY_DAYS`).
+    /// Pinned memories are exempt. `None` (default) disables decay.
+    pub confidence_decay: Option<f64>,
     /// #64: when a personal-scope recall returns fewer than this many hits and
     /// the collective tier is enabled, search_memory appends a hint nudging the
     /// agent to retry with scope=collective. 0 disables the hint.
     pub recall_thin_hint_threshold: usize,
+    /// What a blank (empty/whitespace) query means to search_memory and
+    /// think_recall. `false` (default): the call is rejected as
+    /// `invalid_params` — a blank query is almost always a client bug, and
+    /// embedding "" only returns noise. `true`: treat it as "browse recent"
+    /// and return the user's newest memories, unranked.
+    pub empty_query_browse: bool,
+    /// Machine output mode: tool results drop human prose that has a stable
+    /// code beside it (`message` next to `message_code`, ...) and strip
+    /// emoji from the prose that remains. For agents that forward tool JSON
+    /// into their own reasoning. Structured fields are unchanged.
+    pub machine_output: bool,
+    /// Cap in bytes on a tool result's text (`HELIXIR_MAX_RESPONSE_BYTES`).
+    /// A larger result loses trailing elements of its biggest array and is
+    /// marked `truncated: true, omitted: N`. `None` (default) sends results
+    /// whole.
+    #[serde(default)]
+    pub max_response_bytes: Option<usize>,
+    /// Tool whitelist (`HELIXIR_ENABLED_TOOLS`, comma-separated): when set,
+    /// only these MCP tools are listed and callable. `None` exposes all.
+    /// Read when the server is built; a SIGHUP reload does not change it.
+    #[serde(default)]
+    pub enabled_tools: Option<Vec<String>>,
     pub vector_search_enabled: bool,
     pub graph_search_enabled: bool,
     pub bm25_search_enabled: bool,
 
     pub search_thresholds: SearchThresholds,
+    #[serde(default)]
+    pub windows: SearchWindowsConfig,
 
     pub max_facts_per_call: usize,
 
     /// Entity-resolution LRU cache capacity (EntityManager).
     pub entity_cache_size: usize,
+    /// Whether an entity's type is part of its identity. Switching to
+    /// `name_and_type` does not split entities already merged by name.
+    #[serde(default)]
+    pub entity_identity: EntityIdentity,
+    /// Entity types extraction keeps (`person`, `organization`, `location`,
+    /// `concept`, `object`, `action`, `event`, `technology`, `other`);
+    /// the rest are discarded. Empty keeps every type.
+    #[serde(default)]
+    pub entity_types: Vec<String>,
     /// Max memories pulled as context when reconstructing reasoning chains.
     pub reasoning_context_limit: usize,
 
@@ -875,6 +1497,14 @@ pub struct HelixirConfig {
     #[serde(default)]
     pub retry: RetryConfig,
     #[serde(default)]
+    pub db_pool: DbPoolConfig,
+    #[serde(default)]
+    pub summary: SummaryConfig,
+    #[serde(default)]
+    pub stale: StaleConfig,
+    #[serde(default)]
+    pub cluster: ClusterConfig,
+    #[serde(default)]
     pub retrieval: RetrievalConfig,
     #[serde(default)]
     pub moira: MoiraConfig,
@@ -890,6 +1520,10 @@ pub struct HelixirConfig {
     #[serde(default)]
     pub gateway: GatewayConfig,
     #[serde(default)]
+    pub webhook: WebhookConfig,
+    #[serde(default)]
+    pub prompts: PromptConfig,
+    #[serde(default)]
     pub llm_runtime: LlmRuntimeConfig,
     #[serde(default)]
     pub fast_think: FastThinkConfig,
@@ -927,24 +1561,37 @@ impl HelixirConfig {
             embedding_fallback_enabled: true,
             embedding_fallback_url: crate::DEFAULT_OLLAMA_URL.to_string(),
             embedding_fallback_model: crate::DEFAULT_EMBEDDING_MODEL.to_string(),
+            embedding_dim: None,
+            embedding_batch_size: crate::DEFAULT_EMBEDDING_BATCH_SIZE,
 
             default_certainty: 80,
             default_importance: 50,
 
-            default_search_limit: 10,
             default_search_mode: "recent".to_string(),
+            confidence_decay: None,
             recall_thin_hint_threshold: 3,
+            empty_query_browse: false,
+            machine_output: false,
+            max_response_bytes: None,
+            enabled_tools: None,
             vector_search_enabled: true,
             graph_search_enabled: true,
             bm25_search_enabled: true,
 
             search_thresholds: SearchThresholds::default(),
+            windows: SearchWindowsConfig::default(),
 
             max_facts_per_call: 15,
             entity_cache_size: 1000,
+            entity_identity: EntityIdentity::Name,
+            entity_types: Vec::new(),
             reasoning_context_limit: 500,
 
             retry: RetryConfig::default(),
+            db_pool: DbPoolConfig::default(),
+            summary: SummaryConfig::default(),
+            stale: StaleConfig::default(),
+            cluster: ClusterConfig::default(),
             retrieval: RetrievalConfig::default(),
             moira: MoiraConfig::default(),
             watchdog: WatchdogConfig::default(),
@@ -953,6 +1600,8 @@ impl HelixirConfig {
             chunking: ChunkingConfig::default(),
             swarm: SwarmConfig::default(),
             gateway: GatewayConfig::default(),
+            webhook: WebhookConfig::default(),
+            prompts: PromptConfig::default(),
             llm_runtime: LlmRuntimeConfig::default(),
             fast_think: FastThinkConfig::default(),
         }
@@ -962,6 +1611,30 @@ impl HelixirConfig {
         format!("http://{}:{}", self.host, self.port)
     }
 
+    /// This config with `user`'s overrides applied.
+    #[must_use]
+    pub fn for_user(&self, user: &UserConfigOverrides) -> Self {
+        let mut config = self.clone();
+        if let Some(mode) = &user.default_search_mode {
+            config.default_search_mode = mode.clone();
+        }
+        if let Some(n) = user.search_limit {
+            let modes = &mut config.retrieval.search_modes;
+            for preset in [
+                &mut modes.recent,
+                &mut modes.contextual,
+                &mut modes.deep,
+                &mut modes.full,
+            ] {
+                preset.max_results = n;
+            }
+        }
+        config.windows.recent_hours = user.recent_hours.or(config.windows.recent_hours);
+        config.windows.contextual_days = user.contextual_days.or(config.windows.contextual_days);
+        config.windows.deep_days = user.deep_days.or(config.windows.deep_days);
+        config
+    }
+
     /// The public entry point. Layered: built-in defaults → `helixir.toml`
     /// (if present) → `HELIX_*`/`HELIXIR_*` env (env wins). All existing callers
     /// (MCP server, gateway, CLI, client) reach the layered config through this.
@@ -1034,6 +1707,24 @@ impl HelixirConfig {
         if let Ok(token) = std::env::var("HELIXIR_GATEWAY_TOKEN") {
             self.gateway.auth_token = (!token.is_empty()).then_some(token);
         }
+        if let Ok(url) = std::env::var("HELIXIR_WEBHOOK_URL") {
+            self.webhook.url = (!url.trim().is_empty()).then_some(url);
+        }
+        if let Ok(path) = std::env::var("HELIXIR_EXTRACTION_PROMPT_PATH") {
+            self.prompts.extraction_path = (!path.trim().is_empty()).then_some(path);
+        }
+        if let Ok(path) = std::env::var("HELIXIR_RELATION_PROMPT_PATH") {
+            self.prompts.relation_path = (!path.trim().is_empty()).then_some(path);
+        }
+        if let Ok(lang) = std::env::var("HELIXIR_LANG") {
+            self.prompts.lang = (!lang.trim().is_empty()).then_some(lang);
+        }
+        if let Ok(path) = std::env::var("HELIXIR_LOCALE_DIR") {
+            self.prompts.locale_dir = (!path.trim().is_empty()).then_some(path);
+        }
+        if let Ok(path) = std::env::var("HELIXIR_THINK_TEMPLATES_DIR") {
+            self.fast_think.templates_dir = (!path.trim().is_empty()).then_some(path);
+        }
         if let Ok(provider) = std::env::var("HELIX_LLM_PROVIDER") {
             self.llm_provider = provider;
         }
@@ -1074,12 +1765,223 @@ impl HelixirConfig {
         if let Ok(key) = std::env::var("HELIX_EMBEDDING_API_KEY") {
             self.embedding_api_key = Some(key);
         }
+        if let Some(dim) = std::env::var("HELIXIR_EMBEDDING_DIM")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .filter(|d| *d > 0)
+        {
+            self.embedding_dim = Some(dim);
+        }
+        if let Some(n) = std::env::var("HELIXIR_EMBEDDING_BATCH_SIZE")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .filter(|n| *n > 0)
+        {
+            self.embedding_batch_size = n;
+        }
+        if let Ok(v) = std::env::var("HELIXIR_CONFIDENCE_DECAY_DAYS") {
+            self.confidence_decay = v.trim().parse::<f64>().ok().filter(|d| *d > 0.0);
+        }
         if let Some(n) = std::env::var("HELIX_MAX_FACTS_PER_CALL")
             .ok()
             .and_then(|v| v.parse::<usize>().ok())
         {
             self.max_facts_per_call = n;
         }
+        // Non-positive or unparsable windows are ignored: a zero decay scale
+        // would divide the freshness term by zero.
+        let window = |key: &str| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| *v > 0.0)
+        };
+        if let Some(h) = window("HELIXIR_WINDOW_RECENT_HOURS") {
+            self.windows.recent_hours = Some(h);
+        }
+        if let Some(d) = window("HELIXIR_WINDOW_CONTEXTUAL_DAYS") {
+            self.windows.contextual_days = Some(d);
+        }
+        if let Some(d) = window("HELIXIR_WINDOW_DEEP_DAYS") {
+            self.windows.deep_days = Some(d);
+        }
+        let cap = |key: &str| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .filter(|v| *v > 0)
+        };
+        if let Some(n) = cap("HELIXIR_SEARCH_LIMIT_RECENT") {
+            self.retrieval.search_modes.recent.max_results = n;
+        }
+        if let Some(n) = cap("HELIXIR_SEARCH_LIMIT_CONTEXTUAL") {
+            self.retrieval.search_modes.contextual.max_results = n;
+        }
+        if let Some(n) = cap("HELIXIR_SEARCH_LIMIT_DEEP") {
+            self.retrieval.search_modes.deep.max_results = n;
+        }
+        if let Some(n) = cap("HELIXIR_SEARCH_LIMIT_FULL") {
+            self.retrieval.search_modes.full.max_results = n;
+        }
+        if let Some(p) = std::env::var("HELIXIR_ON_EMPTY_EXTRACTION")
+            .ok()
+            .and_then(|v| EmptyExtractionPolicy::parse(&v))
+        {
+            self.write.on_empty_extraction = p;
+        }
+        if let Some(p) = std::env::var("HELIXIR_ON_EMBED_FAIL")
+            .ok()
+            .and_then(|v| OnEmbedFail::parse(&v))
+        {
+            self.write.on_embed_fail = p;
+        }
+        if let Some(m) = std::env::var("HELIXIR_THINK_TIMEOUT_MODE")
+            .ok()
+            .and_then(|v| ThinkTimeoutMode::parse(&v))
+        {
+            self.fast_think.timeout_mode = m;
+        }
+        if let Some(n) = std::env::var("HELIXIR_SCORE_NORMALIZATION")
+            .ok()
+            .and_then(|v| ScoreNormalization::parse(&v))
+        {
+            self.retrieval.score_normalization = n;
+        }
+        if let Some(m) = std::env::var("HELIXIR_SCORE_SIGMOID_MIDPOINT")
+            .ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+        {
+            self.retrieval.score_sigmoid_midpoint = m;
+        }
+        if let Some(k) = std::env::var("HELIXIR_SCORE_SIGMOID_STEEPNESS")
+            .ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|k| *k > 0.0)
+        {
+            self.retrieval.score_sigmoid_steepness = k;
+        }
+        if let Some(b) = std::env::var("HELIXIR_CONCEPT_BOOST")
+            .ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|b| *b > 0.0)
+        {
+            self.retrieval.concept_boost = b;
+        }
+        if let Some(n) = cap("HELIXIR_CHAIN_SEED_CONCURRENCY") {
+            self.retrieval.chain_seed_concurrency = n;
+        }
+        if let Some(n) = cap("HELIXIR_CLUSTER_MAX_K") {
+            self.cluster.max_k = n;
+        }
+        if let Some(i) = std::env::var("HELIXIR_ENTITY_IDENTITY")
+            .ok()
+            .and_then(|v| EntityIdentity::parse(&v))
+        {
+            self.entity_identity = i;
+        }
+        if let Ok(v) = std::env::var("HELIXIR_ENTITY_TYPES") {
+            self.entity_types = v
+                .split(',')
+                .map(|t| t.trim().to_lowercase())
+                .filter(|t| !t.is_empty())
+                .collect();
+        }
+        if let Some(p) = std::env::var("HELIXIR_DEDUP_ON_ADD")
+            .ok()
+            .and_then(|v| DedupOnAddPolicy::parse(&v))
+        {
+            self.write.dedup_on_add = p;
+        }
+        if let Some(t) = std::env::var("HELIXIR_DEDUP_ON_ADD_THRESHOLD")
+            .ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|t| (0.0..=1.0).contains(t))
+        {
+            self.write.dedup_on_add_threshold = t;
+        }
+        if let Some(n) = std::env::var("HELIXIR_CONCURRENT_ADD_WINDOW_SECS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+        {
+            self.write.concurrent_add_window_secs = n;
+        }
+        if let Some(n) = std::env::var("HELIXIR_IMPORT_MAX_RETRIES")
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+        {
+            self.write.import_max_retries = n;
+        }
+        if let Ok(v) = std::env::var("HELIXIR_EMPTY_QUERY") {
+            match v.trim().to_lowercase().as_str() {
+                "browse" => self.empty_query_browse = true,
+                "reject" => self.empty_query_browse = false,
+                _ => {}
+            }
+        }
+        if let Ok(v) = std::env::var("HELIXIR_MACHINE_OUTPUT") {
+            self.machine_output = v == "1" || v.eq_ignore_ascii_case("true");
+        }
+        if let Some(n) = std::env::var("HELIXIR_DB_POOL_SIZE")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .filter(|n| *n > 0)
+        {
+            self.db_pool.size = n;
+        }
+        if let Some(ms) = std::env::var("HELIXIR_DB_POOL_TIMEOUT_MS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+        {
+            self.db_pool.acquire_timeout_ms = ms;
+        }
+        if let Some(hours) = std::env::var("HELIXIR_SUMMARY_WINDOW_HOURS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .filter(|h| *h > 0)
+        {
+            self.summary.window_hours = hours;
+        }
+        if let Ok(v) = std::env::var("HELIXIR_WARM_UP") {
+            self.llm_runtime.warm_up = v == "1" || v.eq_ignore_ascii_case("true");
+        }
+        if let Ok(v) = std::env::var("HELIXIR_SUMMARY_ARCHIVE") {
+            self.summary.archive_sources = v == "1" || v.eq_ignore_ascii_case("true");
+        }
+        if let Some(days) = std::env::var("HELIXIR_STALE_AFTER_DAYS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+        {
+            self.stale.min_age_days = days;
+        }
+        if let Ok(v) = std::env::var("HELIXIR_MAX_RESPONSE_BYTES") {
+            self.max_response_bytes = v.trim().parse::<usize>().ok().filter(|n| *n > 0);
+        }
+        if let Ok(v) = std::env::var("HELIXIR_SESSION_NAMESPACE") {
+            self.fast_think.namespace_sessions = v == "1" || v.eq_ignore_ascii_case("true");
+        }
+        if let Ok(v) = std::env::var("HELIXIR_AUTO_LINK_RECALLS") {
+            self.fast_think.auto_link_recalls = v == "1" || v.eq_ignore_ascii_case("true");
+        }
+        if let Some(n) = std::env::var("HELIXIR_MIN_THOUGHTS_BEFORE_CONCLUDE")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+        {
+            self.fast_think.min_thoughts_before_conclude = n;
+        }
+        if let Ok(v) = std::env::var("HELIXIR_ACCEPT_THOUGHT_IDX") {
+            self.fast_think.accept_thought_idx = !(v == "0" || v.eq_ignore_ascii_case("false"));
+        }
+        if let Ok(v) = std::env::var("HELIXIR_COMMIT_RECONCILE_COUNTS") {
+            self.fast_think.commit_reconcile_counts = v == "1" || v.eq_ignore_ascii_case("true");
+        }
+        if let Ok(v) = std::env::var("HELIXIR_ENABLED_TOOLS") {
+            let tools: Vec<String> = v
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+            self.enabled_tools = (!tools.is_empty()).then_some(tools);
+        }
     }
 }
 
@@ -1091,7 +1993,10 @@ impl Default for HelixirConfig {
 
 #[cfg(test)]
 mod tests {
-    use super::{HelixirConfig, MemoryMode};
+    use super::{
+        DedupOnAddPolicy, EmptyExtractionPolicy, EntityIdentity, HelixirConfig, MemoryMode,
+        OnEmbedFail, ScoreNormalization, ThinkTimeoutMode, UserConfigOverrides,
+    };
 
     #[test]
     fn test_from_env_reads_llm_base_url() {
@@ -1193,6 +2098,436 @@ mod tests {
         assert!(c.gateway.auth_token.is_none());
     }
 
+    #[test]
+    fn search_windows_default_and_env_override() {
+        use crate::core::search_modes::SearchMode;
+        // Unset, every mode falls back to the global decay scale.
+        let w = HelixirConfig::default().windows;
+        for mode in [
+            SearchMode::Recent,
+            SearchMode::Contextual,
+            SearchMode::Deep,
+            SearchMode::Full,
+        ] {
+            assert_eq!(w.horizon_days(mode), None);
+        }
+
+        temp_env::with_vars(
+            [
+                ("HELIXIR_WINDOW_RECENT_HOURS", Some("12")),
+                ("HELIXIR_WINDOW_CONTEXTUAL_DAYS", Some("7")),
+                ("HELIXIR_WINDOW_DEEP_DAYS", Some("0")),
+            ],
+            || {
+                let w = HelixirConfig::from_env().windows;
+                assert_eq!(w.horizon_days(SearchMode::Recent), Some(0.5));
+                assert_eq!(w.contextual_days, Some(7.0));
+                assert_eq!(w.deep_days, None, "non-positive window is ignored");
+            },
+        );
+    }
+
+    #[test]
+    fn empty_extraction_policy_defaults_to_store_raw_and_env_selects() {
+        assert_eq!(
+            HelixirConfig::default().write.on_empty_extraction,
+            EmptyExtractionPolicy::StoreRaw
+        );
+        temp_env::with_var("HELIXIR_ON_EMPTY_EXTRACTION", Some("skip"), || {
+            assert_eq!(
+                HelixirConfig::from_env().write.on_empty_extraction,
+                EmptyExtractionPolicy::Skip
+            );
+        });
+        temp_env::with_var("HELIXIR_ON_EMPTY_EXTRACTION", Some("bogus"), || {
+            assert_eq!(
+                HelixirConfig::from_env().write.on_empty_extraction,
+                EmptyExtractionPolicy::StoreRaw
+            );
+        });
+    }
+
+    #[test]
+    fn embed_failure_fails_the_write_unless_env_defers() {
+        assert_eq!(
+            HelixirConfig::default().write.on_embed_fail,
+            OnEmbedFail::Fail
+        );
+        temp_env::with_var("HELIXIR_ON_EMBED_FAIL", Some(" Defer "), || {
+            assert_eq!(
+                HelixirConfig::from_env().write.on_embed_fail,
+                OnEmbedFail::Defer
+            );
+        });
+        temp_env::with_var("HELIXIR_ON_EMBED_FAIL", Some("bogus"), || {
+            assert_eq!(
+                HelixirConfig::from_env().write.on_embed_fail,
+                OnEmbedFail::Fail
+            );
+        });
+    }
+
+    #[test]
+    fn think_timeout_is_absolute_by_default_and_env_selects_idle() {
+        assert_eq!(
+            HelixirConfig::default().fast_think.timeout_mode,
+            ThinkTimeoutMode::Absolute
+        );
+        temp_env::with_var("HELIXIR_THINK_TIMEOUT_MODE", Some("Idle"), || {
+            assert_eq!(
+                HelixirConfig::from_env().fast_think.timeout_mode,
+                ThinkTimeoutMode::Idle
+            );
+        });
+        temp_env::with_var("HELIXIR_THINK_TIMEOUT_MODE", Some("forever"), || {
+            assert_eq!(
+                HelixirConfig::from_env().fast_think.timeout_mode,
+                ThinkTimeoutMode::Absolute
+            );
+        });
+    }
+
+    #[test]
+    fn score_normalization_is_off_by_default_and_env_selects_a_map() {
+        let retrieval = HelixirConfig::default().retrieval;
+        assert_eq!(retrieval.score_normalization, ScoreNormalization::None);
+        temp_env::with_vars(
+            [
+                ("HELIXIR_SCORE_NORMALIZATION", Some("Sigmoid")),
+                ("HELIXIR_SCORE_SIGMOID_MIDPOINT", Some("0.3")),
+                ("HELIXIR_SCORE_SIGMOID_STEEPNESS", Some("0")),
+            ],
+            || {
+                let retrieval = HelixirConfig::from_env().retrieval;
+                assert_eq!(retrieval.score_normalization, ScoreNormalization::Sigmoid);
+                assert_eq!(retrieval.score_sigmoid_midpoint, 0.3);
+                assert_eq!(retrieval.score_sigmoid_steepness, 10.0);
+            },
+        );
+        temp_env::with_var("HELIXIR_SCORE_NORMALIZATION", Some("zscore"), || {
+            assert_eq!(
+                HelixirConfig::from_env().retrieval.score_normalization,
+                ScoreNormalization::None
+            );
+        });
+    }
+
+    #[test]
+    fn entity_identity_is_name_only_by_default_and_env_adds_the_type() {
+        assert_eq!(
+            HelixirConfig::default().entity_identity,
+            EntityIdentity::Name
+        );
+        temp_env::with_var("HELIXIR_ENTITY_IDENTITY", Some("name_and_type"), || {
+            assert_eq!(
+                HelixirConfig::from_env().entity_identity,
+                EntityIdentity::NameAndType
+            );
+        });
+        temp_env::with_var("HELIXIR_ENTITY_IDENTITY", Some("fuzzy"), || {
+            assert_eq!(
+                HelixirConfig::from_env().entity_identity,
+                EntityIdentity::Name
+            );
+        });
+    }
+
+    #[test]
+    fn chain_seed_concurrency_env_override_ignores_zero() {
+        assert_eq!(HelixirConfig::default().retrieval.chain_seed_concurrency, 4);
+        temp_env::with_var("HELIXIR_CHAIN_SEED_CONCURRENCY", Some("8"), || {
+            assert_eq!(
+                HelixirConfig::from_env().retrieval.chain_seed_concurrency,
+                8
+            );
+        });
+        temp_env::with_var("HELIXIR_CHAIN_SEED_CONCURRENCY", Some("0"), || {
+            assert_eq!(
+                HelixirConfig::from_env().retrieval.chain_seed_concurrency,
+                4
+            );
+        });
+    }
+
+    #[test]
+    fn cluster_max_k_reads_from_env() {
+        assert_eq!(HelixirConfig::default().cluster.max_k, 10);
+        temp_env::with_var("HELIXIR_CLUSTER_MAX_K", Some("4"), || {
+            assert_eq!(HelixirConfig::from_env().cluster.max_k, 4);
+        });
+    }
+
+    #[test]
+    fn concept_boost_defaults_to_one_and_a_half_and_env_overrides() {
+        assert_eq!(HelixirConfig::default().retrieval.concept_boost, 1.5);
+        temp_env::with_var("HELIXIR_CONCEPT_BOOST", Some("3"), || {
+            assert_eq!(HelixirConfig::from_env().retrieval.concept_boost, 3.0);
+        });
+        temp_env::with_var("HELIXIR_CONCEPT_BOOST", Some("-1"), || {
+            assert_eq!(HelixirConfig::from_env().retrieval.concept_boost, 1.5);
+        });
+    }
+
+    #[test]
+    fn entity_types_keep_everything_by_default_and_env_lists_them() {
+        assert!(HelixirConfig::default().entity_types.is_empty());
+        temp_env::with_var(
+            "HELIXIR_ENTITY_TYPES",
+            Some(" Person, technology,,"),
+            || {
+                assert_eq!(
+                    HelixirConfig::from_env().entity_types,
+                    ["person", "technology"]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn dedup_on_add_is_off_by_default_and_env_selects() {
+        let write = HelixirConfig::default().write;
+        assert_eq!(write.dedup_on_add, DedupOnAddPolicy::Off);
+        assert_eq!(write.dedup_on_add_threshold, 0.92);
+        temp_env::with_vars(
+            [
+                ("HELIXIR_DEDUP_ON_ADD", Some(" Skip ")),
+                ("HELIXIR_DEDUP_ON_ADD_THRESHOLD", Some("0.95")),
+            ],
+            || {
+                let write = HelixirConfig::from_env().write;
+                assert_eq!(write.dedup_on_add, DedupOnAddPolicy::Skip);
+                assert_eq!(write.dedup_on_add_threshold, 0.95);
+            },
+        );
+        temp_env::with_vars(
+            [
+                ("HELIXIR_DEDUP_ON_ADD", Some("bogus")),
+                ("HELIXIR_DEDUP_ON_ADD_THRESHOLD", Some("7")),
+            ],
+            || {
+                let write = HelixirConfig::from_env().write;
+                assert_eq!(write.dedup_on_add, DedupOnAddPolicy::Off);
+                assert_eq!(write.dedup_on_add_threshold, 0.92);
+            },
+        );
+    }
+
+    #[test]
+    fn concurrent_add_window_reads_from_env() {
+        assert_eq!(
+            HelixirConfig::default().write.concurrent_add_window_secs,
+            10
+        );
+        temp_env::with_var("HELIXIR_CONCURRENT_ADD_WINDOW_SECS", Some(" 0 "), || {
+            assert_eq!(
+                HelixirConfig::from_env().write.concurrent_add_window_secs,
+                0
+            );
+        });
+        temp_env::with_var("HELIXIR_CONCURRENT_ADD_WINDOW_SECS", Some("soon"), || {
+            assert_eq!(
+                HelixirConfig::from_env().write.concurrent_add_window_secs,
+                10
+            );
+        });
+    }
+
+    #[test]
+    fn enabled_tools_parse_from_a_comma_list() {
+        assert!(HelixirConfig::default().enabled_tools.is_none());
+        temp_env::with_var(
+            "HELIXIR_ENABLED_TOOLS",
+            Some(" search_memory, add_memory ,,"),
+            || {
+                assert_eq!(
+                    HelixirConfig::from_env().enabled_tools,
+                    Some(vec!["search_memory".to_string(), "add_memory".to_string()])
+                );
+            },
+        );
+        temp_env::with_var("HELIXIR_ENABLED_TOOLS", Some(" "), || {
+            assert!(HelixirConfig::from_env().enabled_tools.is_none());
+        });
+    }
+
+    #[test]
+    fn search_limits_default_per_mode_and_env_override() {
+        use crate::core::search_modes::SearchMode;
+        let l = HelixirConfig::default().retrieval.search_modes;
+        assert_eq!(l.limit_for(SearchMode::Recent), 10);
+        assert_eq!(l.limit_for(SearchMode::Contextual), 20);
+        assert_eq!(l.limit_for(SearchMode::Deep), 50);
+        assert_eq!(l.limit_for(SearchMode::Full), 100);
+
+        temp_env::with_vars(
+            [
+                ("HELIXIR_SEARCH_LIMIT_FULL", Some("200")),
+                ("HELIXIR_SEARCH_LIMIT_DEEP", Some("0")),
+            ],
+            || {
+                let l = HelixirConfig::from_env().retrieval.search_modes;
+                assert_eq!(l.full.max_results, 200);
+                assert_eq!(l.deep.max_results, 50, "zero cap is ignored");
+            },
+        );
+    }
+
+    #[test]
+    fn empty_query_policy_defaults_to_reject_and_env_selects() {
+        assert!(!HelixirConfig::default().empty_query_browse);
+        temp_env::with_var("HELIXIR_EMPTY_QUERY", Some("browse"), || {
+            assert!(HelixirConfig::from_env().empty_query_browse);
+        });
+        temp_env::with_var("HELIXIR_EMPTY_QUERY", Some("sometimes"), || {
+            assert!(!HelixirConfig::from_env().empty_query_browse);
+        });
+    }
+
+    #[test]
+    fn machine_output_is_off_by_default_and_env_enables() {
+        assert!(!HelixirConfig::default().machine_output);
+        temp_env::with_var("HELIXIR_MACHINE_OUTPUT", Some("true"), || {
+            assert!(HelixirConfig::from_env().machine_output);
+        });
+        temp_env::with_var("HELIXIR_MACHINE_OUTPUT", Some("0"), || {
+            assert!(!HelixirConfig::from_env().machine_output);
+        });
+    }
+
+    #[test]
+    fn response_cap_is_off_by_default_and_zero_disables_it() {
+        assert_eq!(HelixirConfig::default().max_response_bytes, None);
+        temp_env::with_var("HELIXIR_MAX_RESPONSE_BYTES", Some("65536"), || {
+            assert_eq!(HelixirConfig::from_env().max_response_bytes, Some(65536));
+        });
+        for off in ["0", "lots"] {
+            temp_env::with_var("HELIXIR_MAX_RESPONSE_BYTES", Some(off), || {
+                assert_eq!(HelixirConfig::from_env().max_response_bytes, None);
+            });
+        }
+    }
+
+    #[test]
+    fn thought_idx_is_accepted_by_default_and_env_can_refuse_it() {
+        assert!(HelixirConfig::default().fast_think.accept_thought_idx);
+        temp_env::with_var("HELIXIR_ACCEPT_THOUGHT_IDX", Some("false"), || {
+            assert!(!HelixirConfig::from_env().fast_think.accept_thought_idx);
+        });
+        temp_env::with_var("HELIXIR_ACCEPT_THOUGHT_IDX", Some("yes"), || {
+            assert!(HelixirConfig::from_env().fast_think.accept_thought_idx);
+        });
+    }
+
+    #[test]
+    fn summary_window_and_archiving_come_from_env() {
+        let defaults = HelixirConfig::default().summary;
+        assert_eq!(
+            (defaults.window_hours, defaults.archive_sources),
+            (24, false)
+        );
+        temp_env::with_vars(
+            [
+                ("HELIXIR_SUMMARY_WINDOW_HOURS", Some("168")),
+                ("HELIXIR_SUMMARY_ARCHIVE", Some("true")),
+            ],
+            || {
+                let summary = HelixirConfig::from_env().summary;
+                assert_eq!((summary.window_hours, summary.archive_sources), (168, true));
+            },
+        );
+    }
+
+    #[test]
+    fn stale_age_comes_from_env() {
+        assert_eq!(HelixirConfig::default().stale.min_age_days, 30);
+        temp_env::with_var("HELIXIR_STALE_AFTER_DAYS", Some("90"), || {
+            assert_eq!(HelixirConfig::from_env().stale.min_age_days, 90);
+        });
+        temp_env::with_var("HELIXIR_STALE_AFTER_DAYS", Some("soon"), || {
+            assert_eq!(HelixirConfig::from_env().stale.min_age_days, 30);
+        });
+    }
+
+    #[test]
+    fn user_overrides_win_over_the_global_config() {
+        let mut global = HelixirConfig::default();
+        global.windows.deep_days = Some(60.0);
+        let mut user = UserConfigOverrides {
+            recent_hours: Some(8.0),
+            ..UserConfigOverrides::default()
+        };
+        user.merge(UserConfigOverrides {
+            search_limit: Some(5),
+            ..UserConfigOverrides::default()
+        });
+        assert_eq!(user.recent_hours, Some(8.0), "merge keeps unset fields");
+
+        let effective = global.for_user(&user);
+        assert_eq!(effective.windows.recent_hours, Some(8.0));
+        assert_eq!(
+            effective.windows.deep_days,
+            Some(60.0),
+            "global value stays"
+        );
+        assert_eq!(
+            effective
+                .retrieval
+                .search_modes
+                .limit_for(crate::core::search_modes::SearchMode::Full),
+            5
+        );
+        assert_eq!(effective.default_search_mode, global.default_search_mode);
+
+        assert!(user.validate().is_ok());
+        for bad in [
+            r#"{"recent_hours": 0}"#,
+            r#"{"search_limit": 0}"#,
+            r#"{"default_search_mode": "everything"}"#,
+        ] {
+            let parsed: UserConfigOverrides = serde_json::from_str(bad).unwrap();
+            assert!(parsed.validate().is_err(), "{bad}");
+        }
+        assert!(serde_json::from_str::<UserConfigOverrides>(r#"{"llm_model": "x"}"#).is_err());
+    }
+
+    #[test]
+    fn min_thoughts_before_conclude_comes_from_env() {
+        assert_eq!(
+            HelixirConfig::default()
+                .fast_think
+                .min_thoughts_before_conclude,
+            0
+        );
+        temp_env::with_var("HELIXIR_MIN_THOUGHTS_BEFORE_CONCLUDE", Some("3"), || {
+            let fast_think = HelixirConfig::from_env().fast_think;
+            assert_eq!(fast_think.min_thoughts_before_conclude, 3);
+            assert_eq!(
+                crate::toolkit::fast_think::FastThinkLimits::from_config(&fast_think)
+                    .min_thoughts_before_conclude,
+                3
+            );
+        });
+    }
+
+    #[test]
+    fn db_pool_size_comes_from_env_and_ignores_zero() {
+        assert_eq!(HelixirConfig::default().db_pool.size, 16);
+        temp_env::with_var("HELIXIR_DB_POOL_SIZE", Some("4"), || {
+            assert_eq!(HelixirConfig::from_env().db_pool.size, 4);
+        });
+        temp_env::with_var("HELIXIR_DB_POOL_SIZE", Some("0"), || {
+            assert_eq!(HelixirConfig::from_env().db_pool.size, 16);
+        });
+    }
+
+    #[test]
+    fn commit_count_reconciliation_is_off_by_default_and_env_enables_it() {
+        assert!(!HelixirConfig::default().fast_think.commit_reconcile_counts);
+        temp_env::with_var("HELIXIR_COMMIT_RECONCILE_COUNTS", Some("1"), || {
+            assert!(HelixirConfig::from_env().fast_think.commit_reconcile_counts);
+        });
+    }
+
     #[test]
     fn gateway_auth_can_be_enabled_in_partial_config() {
         let cfg: HelixirConfig = toml::from_str(
@@ -1207,6 +2542,97 @@ mod tests {
         assert_eq!(cfg.gateway.auth_token.as_deref(), Some("test-token"));
     }
 
+    #[test]
+    fn a_pricing_table_in_toml_replaces_the_shipped_prices() {
+        assert!(!HelixirConfig::default().llm_runtime.pricing.is_empty());
+        let cfg: HelixirConfig = toml::from_str(
+            r#"
+                [[llm_runtime.pricing]]
+                provider = "openai"
+                model = "gpt-4o-mini"
+                input_per_mtok = 0.15
+                output_per_mtok = 0.6
+            "#,
+        )
+        .expect("pricing table parses");
+        assert_eq!(cfg.llm_runtime.pricing.len(), 1);
+        assert_eq!(cfg.llm_runtime.pricing[0].model, "gpt-4o-mini");
+        assert!(!cfg.llm_runtime.warm_up);
+    }
+
+    #[test]
+    fn webhook_url_env_enables_delivery_and_empty_value_disables_it() {
+        temp_env::with_var("HELIXIR_WEBHOOK_URL", Some("https://hooks.local/x"), || {
+            let cfg = HelixirConfig::from_env();
+            assert_eq!(cfg.webhook.url.as_deref(), Some("https://hooks.local/x"));
+            assert_eq!(cfg.webhook.max_retries, 3);
+        });
+        temp_env::with_var("HELIXIR_WEBHOOK_URL", Some(" "), || {
+            assert!(HelixirConfig::from_env().webhook.url.is_none());
+        });
+    }
+
+    #[test]
+    fn prompt_paths_come_from_env() {
+        temp_env::with_vars(
+            [
+                (
+                    "HELIXIR_EXTRACTION_PROMPT_PATH",
+                    Some("/etc/helixir/legal.txt"),
+                ),
+                ("HELIXIR_RELATION_PROMPT_PATH", Some("")),
+                (
+                    "HELIXIR_THINK_TEMPLATES_DIR",
+                    Some("/etc/helixir/templates"),
+                ),
+                ("HELIXIR_LANG", Some("ru")),
+                ("HELIXIR_LOCALE_DIR", Some(" ")),
+            ],
+            || {
+                let cfg = HelixirConfig::from_env();
+                assert_eq!(
+                    cfg.fast_think.templates_dir.as_deref(),
+                    Some("/etc/helixir/templates")
+                );
+                assert_eq!(
+                    cfg.prompts.extraction_path.as_deref(),
+                    Some("/etc/helixir/legal.txt")
+                );
+                assert!(cfg.prompts.relation_path.is_none());
+                assert_eq!(cfg.prompts.lang.as_deref(), Some("ru"));
+                assert!(cfg.prompts.locale_dir.is_none());
+            },
+        );
+    }
+
+    #[test]
+    fn embedding_batch_size_env_override_ignores_zero() {
+        assert_eq!(
+            HelixirConfig::default().embedding_batch_size,
+            crate::DEFAULT_EMBEDDING_BATCH_SIZE
+        );
+        temp_env::with_var("HELIXIR_EMBEDDING_BATCH_SIZE", Some("16"), || {
+            assert_eq!(HelixirConfig::from_env().embedding_batch_size, 16);
+        });
+        temp_env::with_var("HELIXIR_EMBEDDING_BATCH_SIZE", Some("0"), || {
+            assert_eq!(
+                HelixirConfig::from_env().embedding_batch_size,
+                crate::DEFAULT_EMBEDDING_BATCH_SIZE
+            );
+        });
+    }
+
+    #[test]
+    fn warm_up_is_off_unless_env_enables_it() {
+        assert!(!HelixirConfig::default().llm_runtime.warm_up);
+        temp_env::with_var("HELIXIR_WARM_UP", Some("true"), || {
+            assert!(HelixirConfig::from_env().llm_runtime.warm_up);
+        });
+        temp_env::with_var("HELIXIR_WARM_UP", Some("0"), || {
+            assert!(!HelixirConfig::from_env().llm_runtime.warm_up);
+        });
+    }
+
     #[test]
     fn gateway_token_env_enables_auth_and_empty_value_disables_it() {
         temp_env::with_var("HELIXIR_GATEWAY_TOKEN", Some("env-token"), || {
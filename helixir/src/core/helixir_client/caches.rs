@@ -0,0 +1,101 @@
+//! Process-local cache inspection and flushing: the embedding cache and the
+//! vector-search result cache. Flushing is for operators — after switching
+//! the embedding model, or to give the memory back.
+
+use super::client::HelixirClient;
+use super::types::CacheReport;
+
+/// A cache `clear_cache` can target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheKind {
+    Embedding,
+    Search,
+    All,
+}
+
+impl CacheKind {
+    pub const NAMES: [&'static str; 3] = ["embedding", "search", "all"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "embedding" => Some(Self::Embedding),
+            "search" => Some(Self::Search),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+
+    fn covers(self, other: Self) -> bool {
+        self == Self::All || self == other
+    }
+}
+
+impl HelixirClient {
+    /// Hit/miss counters and occupancy of each cache, since start or the
+    /// last clear.
+    pub fn cache_stats(&self) -> Vec<CacheReport> {
+        let embedding = self.embedder().cache_stats();
+        let search = self.tooling().search_engine.cache_stats();
+        vec![
+            CacheReport {
+                cache: "embedding".to_string(),
+                hits: embedding.hits,
+                misses: embedding.misses,
+                size: embedding.size,
+                hit_rate: embedding.hit_rate,
+            },
+            CacheReport {
+                cache: "search".to_string(),
+                hits: search.hits,
+                misses: search.misses,
+                size: search.size,
+                hit_rate: search.hit_rate,
+            },
+        ]
+    }
+
+    /// Empty the caches `which` names; returns `(cache, entries removed)`.
+    pub fn clear_cache(&self, which: CacheKind) -> Vec<(String, usize)> {
+        let mut cleared = Vec::new();
+        if which.covers(CacheKind::Embedding) {
+            cleared.push(("embedding".to_string(), self.embedder().clear_cache()));
+        }
+        if which.covers(CacheKind::Search) {
+            cleared.push((
+                "search".to_string(),
+                self.tooling().search_engine.clear_cache(),
+            ));
+        }
+        cleared
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::HelixirConfig;
+
+    #[tokio::test]
+    async fn clearing_the_embedding_cache_empties_it() {
+        let client = HelixirClient::new(HelixirConfig {
+            embedding_provider: "hash".to_string(),
+            ..HelixirConfig::default()
+        })
+        .unwrap();
+        client.embedder().generate("hello", true).await.unwrap();
+        client.embedder().generate("hello", true).await.unwrap();
+        let stats = client.cache_stats();
+        assert_eq!(stats[0].cache, "embedding");
+        assert_eq!((stats[0].hits, stats[0].size), (1, 1));
+
+        assert_eq!(
+            client.clear_cache(CacheKind::Search),
+            vec![("search".to_string(), 0)]
+        );
+        assert_eq!(client.cache_stats()[0].size, 1);
+        let cleared = client.clear_cache(CacheKind::from_name(" ALL ").unwrap());
+        assert_eq!(cleared[0], ("embedding".to_string(), 1));
+        assert_eq!(client.cache_stats()[0].size, 0);
+        assert_eq!(CacheKind::from_name("idempotency"), None);
+    }
+}
@@ -3,10 +3,14 @@
 //! Feature methods (memory/graph/concept) live in sibling modules as
 //! additional `impl HelixirClient` blocks.
 
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 
-use tracing::info;
+use lru::LruCache;
+use parking_lot::Mutex;
+use tracing::{info, warn};
 
 use crate::core::config::HelixirConfig;
 use crate::db::HelixClient;
@@ -24,14 +28,24 @@ pub struct HelixirClient {
     pub(super) llm_provider: Arc<dyn LlmProvider>,
     pub(super) tooling_manager: Arc<ToolingManager>,
     pub(super) is_initialized: Arc<AtomicBool>,
+    /// Effective configs of the most recently active users, resolved from
+    /// overrides already read from HelixDB, so a repeat request is a lookup;
+    /// `set_user_config` replaces the entry it writes. Bounded by
+    /// [`USER_CONFIG_CACHE_SIZE`]: an evicted user's overrides are read
+    /// again on their next request.
+    pub(super) user_configs: Mutex<LruCache<String, Arc<HelixirConfig>>>,
 }
 
+/// Users whose effective config [`HelixirClient`] keeps resolved.
+pub(super) const USER_CONFIG_CACHE_SIZE: usize = 1024;
+
 impl HelixirClient {
     pub fn new(config: HelixirConfig) -> Result<Self, HelixirClientError> {
         let db = Arc::new(
             HelixClient::new(&config.host, config.port)
                 .map_err(|e| HelixirClientError::Database(e.to_string()))?
-                .with_retry(config.retry.clone()),
+                .with_retry(config.retry.clone())
+                .with_pool(&config.db_pool),
         );
 
         let embedder = Arc::new(EmbeddingGenerator::new(crate::llm::EmbeddingConfig {
@@ -45,6 +59,8 @@ impl HelixirClient {
             fallback_enabled: config.embedding_fallback_enabled,
             fallback_url: config.embedding_fallback_url.clone(),
             fallback_model: config.embedding_fallback_model.clone(),
+            expected_dim: config.embedding_dim,
+            batch_size: config.embedding_batch_size,
         }));
 
         let primary_llm: Arc<dyn LlmProvider> = LlmProviderFactory::create(
@@ -66,12 +82,29 @@ impl HelixirClient {
         let llm_provider: Arc<dyn LlmProvider> =
             LlmProviderFactory::create_chained(primary_llm, &config);
 
-        let tooling_manager = Arc::new(ToolingManager::new(
-            Arc::clone(&db),
-            Arc::clone(&embedder),
-            Arc::clone(&llm_provider),
-            &config,
-        ));
+        Self::with_backends(config, db, embedder, llm_provider)
+    }
+
+    /// Assemble a client over backends the caller already built — e.g. a
+    /// [`HelixClient::with_store`] over an [`crate::db::InMemoryStore`], a
+    /// `hash` embedder and a scripted [`LlmProvider`], which runs the whole
+    /// memory and FastThink pipeline in process. Fails on a configured
+    /// prompt template that does not load.
+    pub fn with_backends(
+        config: HelixirConfig,
+        db: Arc<HelixClient>,
+        embedder: Arc<EmbeddingGenerator>,
+        llm_provider: Arc<dyn LlmProvider>,
+    ) -> Result<Self, HelixirClientError> {
+        let tooling_manager = Arc::new(
+            ToolingManager::new(
+                Arc::clone(&db),
+                Arc::clone(&embedder),
+                Arc::clone(&llm_provider),
+                &config,
+            )
+            .map_err(HelixirClientError::from_tooling)?,
+        );
 
         info!("HelixirClient created with ToolingManager");
 
@@ -82,6 +115,9 @@ impl HelixirClient {
             llm_provider,
             tooling_manager,
             is_initialized: Arc::new(AtomicBool::new(false)),
+            user_configs: Mutex::new(LruCache::new(
+                NonZeroUsize::new(USER_CONFIG_CACHE_SIZE).unwrap(),
+            )),
         })
     }
 
@@ -105,10 +141,39 @@ impl HelixirClient {
             .await
             .map_err(|e| HelixirClientError::Tooling(e.to_string()))?;
 
+        if self.config.llm_runtime.warm_up {
+            self.warm_up().await;
+        }
+
         self.is_initialized.store(true, Ordering::Relaxed);
         Ok(())
     }
 
+    /// `llm_runtime.warm_up`: one tiny embedding and one trivial LLM call,
+    /// so connections and lazily loaded models are ready before the first
+    /// request. Best-effort — a failure is logged and startup goes on; the
+    /// first real call then reports it.
+    async fn warm_up(&self) {
+        let started = Instant::now();
+        match self.embedder.generate("warm-up", false).await {
+            Ok(_) => info!(
+                "Warm-up: embedding ready in {} ms",
+                started.elapsed().as_millis()
+            ),
+            Err(e) => warn!("Warm-up: embedding failed: {e}"),
+        }
+
+        let started = Instant::now();
+        match self
+            .llm_provider
+            .generate("Reply with OK.", "ping", None)
+            .await
+        {
+            Ok(_) => info!("Warm-up: LLM ready in {} ms", started.elapsed().as_millis()),
+            Err(e) => warn!("Warm-up: LLM call failed: {e}"),
+        }
+    }
+
     pub async fn close(&self) -> Result<(), HelixirClientError> {
         if !self.is_initialized.load(Ordering::Relaxed) {
             return Ok(());
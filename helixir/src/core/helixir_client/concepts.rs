@@ -1,11 +1,98 @@
-//! Concept-driven search methods: `search_by_concept`, `search_reasoning_chain`.
+//! Concept-driven search methods: `search_by_concept`, `search_reasoning_chain`,
+//! and `get_goals`, which walks the chain from each `goal`-typed memory.
+
+use std::collections::{HashMap, HashSet};
 
 use super::client::HelixirClient;
 use super::error::HelixirClientError;
 use super::types::{
-    ChainNode, ConnectMemoriesResult, ConnectionEdge, ConnectionNode, ReasoningChain,
-    ReasoningChainResult, SearchResult,
+    ChainNode, ConnectMemoriesResult, ConnectionEdge, ConnectionNode, GoalProgress, GoalStatus,
+    ReasoningChain, ReasoningChainResult, SearchResult,
 };
+use crate::toolkit::tooling_manager::MemoryListFilter;
+
+/// Goals listed per call when a `topic` filters them; without one the
+/// listing stops at `limit`.
+const MAX_GOALS_SCANNED: usize = 200;
+
+/// Hops walked from each goal when looking for outcomes.
+const GOAL_CHAIN_DEPTH: usize = 3;
+
+const COMPLETED_WORDS: &[&str] = &[
+    "completed",
+    "complete",
+    "achieved",
+    "accomplished",
+    "finished",
+    "done",
+    "shipped",
+    "reached",
+    "succeeded",
+];
+const FAILED_WORDS: &[&str] = &[
+    "failed",
+    "abandoned",
+    "gave up",
+    "given up",
+    "cancelled",
+    "canceled",
+    "dropped",
+    "missed",
+];
+
+fn mentions_any(text: &str, words: &[&str]) -> bool {
+    let text = text.to_lowercase();
+    words.iter().any(|w| {
+        text.match_indices(w).any(|(at, _)| {
+            let before = text[..at].chars().next_back();
+            let after = text[at + w.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+    })
+}
+
+/// Keyword heuristic over the linked memories. A completion outweighs a
+/// failure: a goal that failed once and was then met reads as done.
+fn infer_goal_progress(outcomes: &[ChainNode]) -> GoalProgress {
+    if outcomes.is_empty() {
+        GoalProgress::Open
+    } else if outcomes
+        .iter()
+        .any(|n| mentions_any(&n.content, COMPLETED_WORDS))
+    {
+        GoalProgress::Completed
+    } else if outcomes
+        .iter()
+        .any(|n| mentions_any(&n.content, FAILED_WORDS))
+    {
+        GoalProgress::Failed
+    } else {
+        GoalProgress::InProgress
+    }
+}
+
+/// A `list_memories` row as the goal half of a [`GoalStatus`]; listing
+/// does no scoring, so `score` stays 0 unless a topic ranks it.
+fn goal_row(row: &serde_json::Value) -> Option<SearchResult> {
+    Some(SearchResult {
+        id: row["memory_id"].as_str()?.to_string(),
+        content: row["content"].as_str().unwrap_or_default().to_string(),
+        score: 0.0,
+        metadata: HashMap::from([("memory_type".to_string(), row["memory_type"].clone())]),
+        created_at: row["created_at"].as_str().unwrap_or_default().to_string(),
+        hops: 0,
+    })
+}
+
+/// Unfinished goals first.
+fn progress_rank(progress: GoalProgress) -> u8 {
+    match progress {
+        GoalProgress::InProgress => 0,
+        GoalProgress::Open => 1,
+        GoalProgress::Failed => 2,
+        GoalProgress::Completed => 3,
+    }
+}
 
 impl HelixirClient {
     pub async fn search_by_concept(
@@ -40,6 +127,7 @@ impl HelixirClient {
                 score: r.score as f32,
                 metadata: r.metadata,
                 created_at: r.created_at,
+                hops: r.hops,
             })
             .collect())
     }
@@ -76,6 +164,7 @@ impl HelixirClient {
                     score: tc.seed.score as f32,
                     metadata: tc.seed.metadata,
                     created_at: tc.seed.created_at,
+                    hops: tc.seed.hops,
                 },
                 nodes: tc
                     .nodes
@@ -89,6 +178,7 @@ impl HelixirClient {
                     .collect(),
                 chain_type: tc.chain_type,
                 reasoning_trail: tc.reasoning_trail,
+                truncated: tc.truncated,
             })
             .collect();
 
@@ -97,9 +187,81 @@ impl HelixirClient {
             chains,
             total_memories: result.total_memories,
             deepest_chain: result.deepest_chain,
+            truncated: result.truncated,
         })
     }
 
+    /// The user's `goal` memories — listed by ontology type, newest first,
+    /// so a goal is found however it is worded — each with the memories its
+    /// reasoning chain reaches from the goal itself and a keyword-inferred
+    /// status. A `topic` keeps only the goals a `full`-mode recall of it
+    /// reaches, ranked by that recall. Unfinished goals come first.
+    pub async fn get_goals(
+        &self,
+        user_id: &str,
+        topic: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<Vec<GoalStatus>, HelixirClientError> {
+        let limit = limit.unwrap_or(10).max(1);
+        let topic = topic.map(str::trim).filter(|t| !t.is_empty());
+        let goal_type = MemoryListFilter {
+            memory_type: Some("goal".to_string()),
+            ..MemoryListFilter::default()
+        };
+        let scan = if topic.is_some() {
+            MAX_GOALS_SCANNED
+        } else {
+            limit
+        };
+        let page = self.list_memories(user_id, &goal_type, 0, scan).await?;
+        let mut goals: Vec<SearchResult> = page.memories.iter().filter_map(goal_row).collect();
+
+        if let Some(topic) = topic {
+            let hits = self
+                .search_by_concept(topic, user_id, None, None, Some("full"), Some(scan))
+                .await?;
+            let scores: HashMap<&str, f32> =
+                hits.iter().map(|h| (h.id.as_str(), h.score)).collect();
+            goals.retain_mut(|g| match scores.get(g.id.as_str()) {
+                Some(&score) => {
+                    g.score = score;
+                    true
+                }
+                None => false,
+            });
+            goals.sort_by(|a, b| b.score.total_cmp(&a.score));
+        }
+        goals.truncate(limit);
+
+        let mut statuses = Vec::with_capacity(goals.len());
+        for goal in goals {
+            let mut related_outcomes: Vec<ChainNode> = self
+                .tooling_manager
+                .chain_from_memory(&goal.id, &goal.content, "both", GOAL_CHAIN_DEPTH)
+                .await
+                .map_err(HelixirClientError::from_tooling)?
+                .into_iter()
+                .filter(|n| n.memory_id != goal.id)
+                .map(|n| ChainNode {
+                    memory_id: n.memory_id,
+                    content: n.content,
+                    relation: n.relation,
+                    depth: n.depth,
+                })
+                .collect();
+            let mut seen = HashSet::new();
+            related_outcomes.retain(|n| seen.insert(n.memory_id.clone()));
+            statuses.push(GoalStatus {
+                inferred_status: infer_goal_progress(&related_outcomes),
+                goal,
+                related_outcomes,
+            });
+        }
+        // Stable: within a status, goals keep the listing (or topic) order.
+        statuses.sort_by_key(|s| progress_rank(s.inferred_status));
+        Ok(statuses)
+    }
+
     /// "How is A related to B?" — bidirectional path discovery between two
     /// anchor queries (elder-brain primitive).
     pub async fn connect_memories(
@@ -170,3 +332,42 @@ impl HelixirClient {
             .map_err(|e| HelixirClientError::Tooling(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(content: &str) -> ChainNode {
+        ChainNode {
+            memory_id: format!("mem_{}", content.len()),
+            content: content.to_string(),
+            relation: "IMPLIES".to_string(),
+            depth: 1,
+        }
+    }
+
+    #[test]
+    fn goal_progress_reads_outcome_words() {
+        assert_eq!(infer_goal_progress(&[]), GoalProgress::Open);
+        assert_eq!(
+            infer_goal_progress(&[node("Started the Rust book, chapter 3")]),
+            GoalProgress::InProgress
+        );
+        assert_eq!(
+            infer_goal_progress(&[node("The marathon attempt failed at km 30")]),
+            GoalProgress::Failed
+        );
+        // Whole words only: "undone" is not "done".
+        assert_eq!(
+            infer_goal_progress(&[node("The migration was undone")]),
+            GoalProgress::InProgress
+        );
+        assert_eq!(
+            infer_goal_progress(&[
+                node("First attempt failed"),
+                node("Finally completed the marathon in May")
+            ]),
+            GoalProgress::Completed
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Entity reads and attribute edits. Entities are shared graph nodes, so a
+//! user sees (and may annotate) only those a memory they hold references,
+//! and the attributes they set are theirs alone.
+
+use std::collections::HashMap;
+
+use super::client::HelixirClient;
+use super::error::HelixirClientError;
+use super::types::EntityDetail;
+use crate::toolkit::tooling_manager::EntityRecord;
+
+/// `attributes` holds every `wanted` pair. Values compare as trimmed,
+/// case-insensitive text, so `"CTO"` matches a stored `"cto"` and a stored
+/// number matches its decimal form.
+pub(super) fn attributes_match(
+    attributes: &HashMap<String, serde_json::Value>,
+    wanted: &HashMap<String, String>,
+) -> bool {
+    wanted.iter().all(|(key, want)| {
+        attributes.get(key).is_some_and(|have| {
+            let have = match have {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            have.trim().eq_ignore_ascii_case(want.trim())
+        })
+    })
+}
+
+/// Shape a stored record for `user_id`, with `own` (their attributes on
+/// it) over the entity's stored properties. `None` when they hold none of
+/// the linked memories (the entity reads as not found rather than leaking).
+fn entity_detail(
+    record: EntityRecord,
+    own: HashMap<String, serde_json::Value>,
+    user_id: &str,
+) -> Option<EntityDetail> {
+    let memory_ids: Vec<String> = record
+        .memories
+        .into_iter()
+        .filter(|(_, knowers)| knowers.iter().any(|k| k == user_id))
+        .map(|(id, _)| id)
+        .collect();
+    if memory_ids.is_empty() {
+        return None;
+    }
+    let entity = record.entity;
+    let mut attributes = entity.properties;
+    attributes.extend(own);
+    Some(EntityDetail {
+        entity_id: entity.entity_id,
+        name: entity.name,
+        entity_type: entity.entity_type.to_string(),
+        attributes,
+        aliases: entity.aliases,
+        memory_ids,
+    })
+}
+
+impl HelixirClient {
+    /// One entity by name, with its attributes and the caller's memories
+    /// that reference it. `Ok(None)` when no entity has that name or none
+    /// of `user_id`'s memories reference it.
+    pub async fn get_entity(
+        &self,
+        user_id: &str,
+        entity_name: &str,
+    ) -> Result<Option<EntityDetail>, HelixirClientError> {
+        if entity_name.trim().is_empty() || user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "get_entity: user_id and entity_name must be non-empty".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+
+        let Some(record) = self
+            .tooling_manager
+            .get_entity_record(entity_name)
+            .await
+            .map_err(HelixirClientError::from_tooling)?
+        else {
+            return Ok(None);
+        };
+        let own = self
+            .tooling_manager
+            .user_entity_attributes(user_id)
+            .await
+            .remove(&record.entity.entity_id)
+            .unwrap_or_default();
+        Ok(entity_detail(record, own, user_id))
+    }
+
+    /// Set attribute `key` to `value` on the entity named `entity_name`; an
+    /// empty `value` removes the attribute. The entity must be referenced
+    /// by a memory `user_id` holds, and the attribute is visible to them
+    /// only. Returns the entity as updated.
+    pub async fn set_entity_attribute(
+        &self,
+        user_id: &str,
+        entity_name: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<EntityDetail, HelixirClientError> {
+        if key.trim().is_empty() || entity_name.trim().is_empty() || user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "set_entity_attribute: user_id, entity_name and key must be non-empty".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+        let unknown = || {
+            HelixirClientError::Config(format!(
+                "set_entity_attribute: no entity named '{entity_name}' in {user_id}'s memories"
+            ))
+        };
+        let record = self
+            .tooling_manager
+            .get_entity_record(entity_name)
+            .await
+            .map_err(HelixirClientError::from_tooling)?
+            .filter(|record| {
+                record
+                    .memories
+                    .iter()
+                    .any(|(_, knowers)| knowers.iter().any(|k| k == user_id))
+            })
+            .ok_or_else(unknown)?;
+
+        let value = Some(value.trim())
+            .filter(|v| !v.is_empty())
+            .map(|v| serde_json::Value::String(v.to_string()));
+        let own = self
+            .tooling_manager
+            .set_entity_attribute(&record.entity.entity_id, user_id, key, value)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        entity_detail(record, own, user_id).ok_or_else(unknown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attribute_filter_matches_all_pairs_loosely() {
+        let attrs: HashMap<String, serde_json::Value> = serde_json::from_value(
+            serde_json::json!({"role": "CTO", "team": "platform", "level": 7}),
+        )
+        .unwrap();
+        let want = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        assert!(attributes_match(&attrs, &want(&[])));
+        assert!(attributes_match(&attrs, &want(&[("role", " cto ")])));
+        assert!(attributes_match(
+            &attrs,
+            &want(&[("role", "CTO"), ("level", "7")])
+        ));
+        assert!(!attributes_match(
+            &attrs,
+            &want(&[("role", "CTO"), ("team", "infra")])
+        ));
+        assert!(!attributes_match(&attrs, &want(&[("location", "Berlin")])));
+    }
+}
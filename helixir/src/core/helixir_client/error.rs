@@ -17,3 +17,29 @@ pub enum HelixirClientError {
     #[error("Operation failed: {0}")]
     Operation(String),
 }
+
+impl HelixirClientError {
+    /// Stable snake_case name of the variant, reported as `data.error` on
+    /// MCP errors.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Config(_) => "config",
+            Self::Database(_) => "database",
+            Self::Llm(_) => "llm",
+            Self::Embedding(_) => "embedding",
+            Self::Tooling(_) => "tooling",
+            Self::NotInitialized => "not_initialized",
+            Self::Operation(_) => "operation",
+        }
+    }
+
+    /// Tooling failures keep their embedding cause visible (dimension
+    /// mismatch, provider down) instead of flattening into `Tooling`.
+    pub(super) fn from_tooling(err: crate::toolkit::tooling_manager::ToolingError) -> Self {
+        match err {
+            crate::toolkit::tooling_manager::ToolingError::Embedding(msg) => Self::Embedding(msg),
+            crate::toolkit::tooling_manager::ToolingError::Config(msg) => Self::Config(msg),
+            other => Self::Tooling(other.to_string()),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 
 use super::client::HelixirClient;
 use super::error::HelixirClientError;
-use super::types::{GraphEdge, GraphNode, GraphResult};
+use super::types::{GraphEdge, GraphNode, GraphResult, GraphWarning};
 
 impl HelixirClient {
     pub async fn get_graph(
@@ -15,14 +15,15 @@ impl HelixirClient {
     ) -> Result<GraphResult, HelixirClientError> {
         self.ensure_initialized().await?;
 
-        let (nodes, edges) = self
+        let graph = self
             .tooling_manager
             .get_memory_graph(user_id, memory_id, depth.unwrap_or(2))
             .await
             .map_err(|e| HelixirClientError::Tooling(e.to_string()))?;
 
         Ok(GraphResult {
-            nodes: nodes
+            nodes: graph
+                .nodes
                 .into_iter()
                 .map(|n| GraphNode {
                     id: n
@@ -43,7 +44,8 @@ impl HelixirClient {
                     metadata: HashMap::new(),
                 })
                 .collect(),
-            edges: edges
+            edges: graph
+                .edges
                 .into_iter()
                 .map(|e| GraphEdge {
                     source: e
@@ -64,6 +66,11 @@ impl HelixirClient {
                     weight: e.get("weight").and_then(|v| v.as_f64()).unwrap_or(1.0) as f32,
                 })
                 .collect(),
+            warnings: graph
+                .warnings
+                .into_iter()
+                .map(|(node_id, reason)| GraphWarning { node_id, reason })
+                .collect(),
         })
     }
 }
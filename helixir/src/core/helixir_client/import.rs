@@ -0,0 +1,192 @@
+//! Checkpointed batch add `import_messages` on [`HelixirClient`].
+
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use super::client::HelixirClient;
+use super::error::HelixirClientError;
+use super::types::{ImportFailure, ImportReport};
+use crate::toolkit::tooling_manager::import::{ResumeToken, import_fingerprint};
+
+/// Per-call knobs of [`HelixirClient::import_messages`].
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    /// Token from a previous call of the same batch.
+    pub resume_token: Option<String>,
+    /// Messages to run in this call; the rest is left for the next one.
+    pub max_items: usize,
+    /// Drop the batch's checkpoint and start again from message 0.
+    pub restart: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            resume_token: None,
+            max_items: 50,
+            restart: false,
+        }
+    }
+}
+
+impl HelixirClient {
+    /// Add `messages` one by one through the normal add pipeline, writing a
+    /// checkpoint after each so an interrupted batch resumes where it
+    /// stopped (see [`crate::toolkit::tooling_manager::import`]). The batch
+    /// resumes from the furthest of the stored checkpoint and the token.
+    /// A message that still fails after `write.import_max_retries` retries
+    /// stops the call with `failed` set; everything before it stays in.
+    pub async fn import_messages(
+        &self,
+        messages: &[String],
+        user_id: &str,
+        agent_id: Option<&str>,
+        opts: ImportOptions,
+    ) -> Result<ImportReport, HelixirClientError> {
+        if user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "import_messages: user_id must be non-empty".to_string(),
+            ));
+        }
+        let import_id = import_fingerprint(user_id, messages);
+        let total = messages.len();
+        let token = match opts
+            .resume_token
+            .as_deref()
+            .filter(|t| !t.trim().is_empty())
+        {
+            None => None,
+            Some(raw) => {
+                let token = ResumeToken::parse(raw).ok_or_else(|| {
+                    HelixirClientError::Config(format!(
+                        "import_messages: malformed resume_token '{raw}'"
+                    ))
+                })?;
+                if token.import_id != import_id {
+                    return Err(HelixirClientError::Config(
+                        "import_messages: resume_token belongs to a different batch — resend the exact messages (same order, same user_id) it was issued for".to_string(),
+                    ));
+                }
+                if token.next_index > total {
+                    return Err(HelixirClientError::Config(format!(
+                        "import_messages: resume_token points past the batch ({} > {total})",
+                        token.next_index
+                    )));
+                }
+                Some(token)
+            }
+        };
+        self.ensure_initialized().await?;
+
+        let tooling = &self.tooling_manager;
+        let tooling_err = |e: crate::toolkit::tooling_manager::ToolingError| {
+            HelixirClientError::Tooling(e.to_string())
+        };
+        if opts.restart {
+            tooling
+                .delete_import_checkpoint(&import_id)
+                .await
+                .map_err(tooling_err)?;
+        }
+        let checkpoint = if opts.restart {
+            None
+        } else {
+            tooling
+                .get_import_checkpoint(&import_id)
+                .await
+                .map_err(tooling_err)?
+        };
+        let mut checkpoint_exists = checkpoint.is_some();
+        let stored = checkpoint
+            .map(|c| (c.next_index.max(0) as usize).min(total))
+            .unwrap_or(0);
+        let start = stored.max(token.map_or(0, |t| t.next_index));
+        let end = total.min(start.saturating_add(opts.max_items.max(1)));
+        if start > 0 {
+            info!("import {import_id}: resuming at {start}/{total}");
+        }
+
+        let write = &self.config.write;
+        let mut report = ImportReport {
+            import_id: import_id.clone(),
+            total,
+            start_index: start,
+            next_index: start,
+            ..Default::default()
+        };
+        for (index, message) in messages.iter().enumerate().take(end).skip(start) {
+            if message.trim().is_empty() {
+                report.skipped_blank += 1;
+            } else {
+                let mut attempt = 0;
+                let outcome = loop {
+                    match self.add(message, user_id, agent_id, None).await {
+                        Ok(r) => break Ok(r),
+                        // Invalid input fails the same way on every attempt.
+                        Err(e @ HelixirClientError::Config(_)) => break Err(e),
+                        Err(e) if attempt >= write.import_max_retries => break Err(e),
+                        Err(e) => {
+                            let backoff = write.import_retry_backoff_ms << attempt.min(6);
+                            warn!(
+                                "import {import_id}: message {index} failed ({e}), retry in {backoff}ms"
+                            );
+                            attempt += 1;
+                            tokio::time::sleep(Duration::from_millis(backoff)).await;
+                        }
+                    }
+                };
+                report.retries += attempt;
+                match outcome {
+                    Ok(added) => {
+                        report.memories_added += added.memories_added;
+                        report.memory_ids.extend(added.memory_ids);
+                        report.deduped += added.deduped.len();
+                    }
+                    Err(e) => {
+                        report.failed = Some(ImportFailure {
+                            index,
+                            error: e.to_string(),
+                        });
+                        break;
+                    }
+                }
+            }
+            report.processed += 1;
+            report.next_index = index + 1;
+            // The token carries the same progress, so a lost checkpoint write
+            // only matters if the caller also loses the response.
+            match tooling
+                .save_import_checkpoint(
+                    &import_id,
+                    user_id,
+                    total,
+                    report.next_index,
+                    checkpoint_exists,
+                )
+                .await
+            {
+                Ok(()) => checkpoint_exists = true,
+                Err(e) => warn!("import {import_id}: checkpoint write failed: {e}"),
+            }
+        }
+
+        report.done = report.next_index >= total;
+        if report.done {
+            if checkpoint_exists {
+                if let Err(e) = tooling.delete_import_checkpoint(&import_id).await {
+                    warn!("import {import_id}: checkpoint cleanup failed: {e}");
+                }
+            }
+        } else {
+            report.resume_token = Some(
+                ResumeToken {
+                    import_id,
+                    next_index: report.next_index,
+                }
+                .encode(),
+            );
+        }
+        Ok(report)
+    }
+}
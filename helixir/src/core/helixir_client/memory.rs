@@ -1,15 +1,31 @@
 //! Memory CRUD methods on [`HelixirClient`]: `add`, `add_with_tags`,
-//! `search`, `update`, `delete`.
+//! `search`, `get_memory`, `update`, `delete`.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use super::client::HelixirClient;
+use super::entities::attributes_match;
 use super::error::HelixirClientError;
-use super::types::{AddMemoryResult, SearchResult, UpdateResult};
+use super::types::{
+    AddEstimate, AddMemoryResult, AppendResult, ClusterResult, ConclusionSource, CorrectionResult,
+    EvidenceLink, ListPage, MemoryDetail, MemoryEvidence, ModeComparison, PurgeReport,
+    RebuildReport, ReindexReport, RelatedMemory, RelationSummary, RememberAdvice, SearchResult,
+    SearchWindowReport, SimilarHit, StaleMemory, SummaryResult, UpdateResult,
+};
+use super::webhook::WebhookEvent;
+use crate::core::SearchSort;
+use crate::core::config::{HelixirConfig, ScoreNormalization};
+use crate::llm::pricing::{approx_tokens, pricing_for};
+use crate::toolkit::mind_toolbox::memory::MemoryReference;
+use crate::toolkit::mind_toolbox::memory::importance;
+use crate::toolkit::tooling_manager::rebuild::RebuildOptions;
+use crate::toolkit::tooling_manager::{
+    AccessStats, DecayInputs, MemoryListFilter, MemoryRecord, SHARED_RESULT_KEY,
+};
 
 /// Client-facing search knobs (#9). Every field is optional — unset means
 /// "the configured default" (mode from `default_search_mode`, personal
-/// scope, configured limit, no time window).
+/// scope, the mode's `max_results` cap, no time window).
 #[derive(Debug, Clone, Default)]
 pub struct SearchParams {
     pub limit: Option<usize>,
@@ -18,6 +34,232 @@ pub struct SearchParams {
     pub graph_depth: Option<usize>,
     pub scope: Option<String>,
     pub window: crate::core::TimeWindow,
+    /// Blend BM25 keyword hits with vector hits (reciprocal rank fusion);
+    /// results then carry `vector_rrf` / `bm25_rrf` sub-scores. `None`
+    /// follows the retrieval profile.
+    pub hybrid: Option<bool>,
+    /// Row order: `relevance` (default), `recently_accessed`,
+    /// `frequently_used`, `newest` or `oldest` (see
+    /// [`crate::core::SearchSort`]).
+    pub sort: Option<String>,
+    /// Keep only rows linked to an entity carrying every one of these
+    /// attributes (see `set_entity_attribute`). Empty means no filter.
+    pub entity_attributes: HashMap<String, String>,
+    /// Rank only these memories (at most
+    /// [`MAX_WITHIN_IDS`](crate::toolkit::tooling_manager::MAX_WITHIN_IDS)),
+    /// e.g. an earlier search's hits against a new query. Mode, scope, time
+    /// window, graph depth and hybrid do not apply; an empty set finds
+    /// nothing.
+    pub within_ids: Option<Vec<String>>,
+    /// Drop rows scoring below this, compared against the NORMALIZED score
+    /// (`retrieval.score_normalization`) after decay, so one threshold holds
+    /// across embedding models. `None` keeps every row.
+    pub min_score: Option<f32>,
+    /// Rank rows of this ontology type (`preference`, `goal`, ...) higher by
+    /// `retrieval.concept_boost`; every row then reports the factor it got
+    /// in `metadata.concept_boost`. `None` applies no boost.
+    pub boost_concept: Option<String>,
+}
+
+/// Modes [`HelixirClient::compare_search_modes`] runs, narrowest first.
+const COMPARED_MODES: [&str; 4] = ["recent", "contextual", "deep", "full"];
+
+/// Rows each mode may return in [`HelixirClient::compare_search_modes`], so
+/// one comparison costs at most four searches of this size.
+pub const COMPARE_MODES_MAX_LIMIT: usize = 25;
+
+/// Search rows fetched per requested row when filtering by entity
+/// attributes or boosting a concept, since both run after ranking.
+const RERANK_OVERFETCH: usize = 3;
+
+/// SUPPORTS hops [`HelixirClient::why_memory`] follows below the memory.
+pub const WHY_MAX_DEPTH: usize = 3;
+
+/// Stable re-sort of `results` by usage, most recent / most used first; rows
+/// never accessed keep their relevance order at the end. The stats are
+/// copied into each row's metadata.
+fn sort_by_access(
+    results: &mut [SearchResult],
+    stats: &HashMap<String, AccessStats>,
+    sort: SearchSort,
+) {
+    let stat = |id: &str| stats.get(id).cloned().unwrap_or_default();
+    for r in results.iter_mut() {
+        let s = stat(&r.id);
+        r.metadata.insert(
+            "access_count".to_string(),
+            serde_json::json!(s.access_count),
+        );
+        r.metadata.insert(
+            "last_accessed_at".to_string(),
+            serde_json::json!(s.last_accessed_at.map(|t| t.to_rfc3339())),
+        );
+    }
+    match sort {
+        SearchSort::Relevance | SearchSort::Newest | SearchSort::Oldest => {}
+        SearchSort::RecentlyAccessed => {
+            results.sort_by_key(|r| std::cmp::Reverse(stat(&r.id).last_accessed_at))
+        }
+        SearchSort::FrequentlyUsed => {
+            results.sort_by_key(|r| std::cmp::Reverse(stat(&r.id).access_count))
+        }
+    }
+}
+
+/// Map the rows' scores onto `[0, 1]` per `method`, keeping the raw score in
+/// `metadata.raw_score`. Both maps are monotonic, so the order is unchanged.
+/// Min-max over rows that all score the same has no spread to stretch; their
+/// raw scores are clamped instead.
+fn normalize_scores(
+    results: &mut [SearchResult],
+    method: ScoreNormalization,
+    midpoint: f64,
+    steepness: f64,
+) {
+    if method == ScoreNormalization::None {
+        return;
+    }
+    let (lo, hi) = results
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), r| {
+            (lo.min(r.score), hi.max(r.score))
+        });
+    let map = |raw: f32| -> f32 {
+        match method {
+            ScoreNormalization::None => raw,
+            ScoreNormalization::MinMax if hi - lo > f32::EPSILON => (raw - lo) / (hi - lo),
+            ScoreNormalization::MinMax => raw.clamp(0.0, 1.0),
+            ScoreNormalization::Sigmoid => {
+                (1.0 / (1.0 + (-steepness * (raw as f64 - midpoint)).exp())) as f32
+            }
+        }
+    };
+    for r in results.iter_mut() {
+        r.metadata
+            .insert("raw_score".to_string(), serde_json::json!(r.score));
+        r.score = map(r.score);
+    }
+}
+
+/// Scale each row's score by its certainty decay and re-rank by the
+/// decayed score. The effective certainty and the factor go into the row's
+/// metadata; rows without inputs are left as they are.
+fn apply_confidence_decay(
+    results: &mut [SearchResult],
+    inputs: &HashMap<String, DecayInputs>,
+    half_life_days: f64,
+    now: chrono::DateTime<chrono::Utc>,
+) {
+    for r in results.iter_mut() {
+        let Some(input) = inputs.get(&r.id) else {
+            continue;
+        };
+        let factor = input.factor(half_life_days, now);
+        r.score *= factor as f32;
+        r.metadata.insert(
+            "decay_factor".to_string(),
+            serde_json::json!((factor * 1000.0).round() / 1000.0),
+        );
+        r.metadata.insert(
+            "decayed_certainty".to_string(),
+            serde_json::json!((input.certainty as f64 * factor * 10.0).round() / 10.0),
+        );
+    }
+    results.sort_by(|a, b| b.score.total_cmp(&a.score));
+}
+
+/// Multiply the score of every row in `matching` by `boost`, capped at 1.0
+/// so a boosted score stays on the normalized scale, and re-rank; each row
+/// records the factor it got (1.0 or `boost`) in `metadata.concept_boost`.
+fn apply_concept_boost(results: &mut [SearchResult], matching: &HashSet<String>, boost: f64) {
+    for r in results.iter_mut() {
+        let factor = if matching.contains(&r.id) { boost } else { 1.0 };
+        r.score = (r.score * factor as f32).min(1.0);
+        r.metadata
+            .insert("concept_boost".to_string(), serde_json::json!(factor));
+    }
+    results.sort_by(|a, b| b.score.total_cmp(&a.score));
+}
+
+/// Stable chronological re-sort of `results` by `created_at`. Rows whose
+/// timestamp does not parse keep their relevance order at the end.
+fn sort_by_time(results: &mut [SearchResult], sort: SearchSort) {
+    let at = |r: &SearchResult| {
+        chrono::DateTime::parse_from_rfc3339(&r.created_at)
+            .ok()
+            .map(|t| t.timestamp_millis())
+    };
+    results.sort_by_key(|r| match (at(r), sort) {
+        (None, _) => (1, 0),
+        (Some(t), SearchSort::Oldest) => (0, t),
+        (Some(t), _) => (0, -t),
+    });
+}
+
+/// Shape a stored record for the caller. `None` when `user_id` holds no
+/// link to the memory (it reads as not found rather than leaking).
+fn memory_detail(record: MemoryRecord, access: AccessStats, user_id: &str) -> Option<MemoryDetail> {
+    let node = &record.node;
+    let text = |key: &str| node[key].as_str().unwrap_or_default().to_string();
+    let flag = |key: &str| node[key].as_i64().unwrap_or(0) != 0;
+    let memory_id = text("memory_id");
+    let owner = text("user_id");
+    let mut users = record.users;
+    if !owner.is_empty() && !users.contains(&owner) {
+        users.insert(0, owner);
+    }
+    if !users.iter().any(|u| u == user_id) {
+        return None;
+    }
+
+    let mut relations = RelationSummary::default();
+    for (kind, from, to) in record.relations {
+        let (other, direction) = if from == memory_id {
+            (to, "out")
+        } else {
+            (from, "in")
+        };
+        *relations.by_kind.entry(kind.clone()).or_default() += 1;
+        relations.related.push(RelatedMemory {
+            memory_id: other,
+            kind,
+            direction: direction.to_string(),
+        });
+    }
+    relations.total = relations.related.len();
+
+    let metadata = match node["metadata"].as_str() {
+        Some(raw) => serde_json::from_str(raw).unwrap_or_else(|_| serde_json::json!(raw)),
+        None => node["metadata"].clone(),
+    };
+    Some(MemoryDetail {
+        content: text("content"),
+        memory_type: text("memory_type"),
+        certainty: node["certainty"].as_i64().unwrap_or_default(),
+        importance: node["importance"].as_i64().unwrap_or_default(),
+        context_tags: text("context_tags")
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect(),
+        source: text("source"),
+        metadata,
+        created_at: text("created_at"),
+        updated_at: text("updated_at"),
+        valid_from: text("valid_from"),
+        valid_until: Some(text("valid_until")).filter(|s| !s.is_empty()),
+        immutable: flag("immutable"),
+        verified: flag("verified"),
+        is_deleted: flag("is_deleted"),
+        access_count: access.access_count,
+        last_accessed_at: access.last_accessed_at.map(|t| t.to_rfc3339()),
+        users,
+        concepts: record.concepts,
+        entities: record.entities,
+        relations,
+        memory_id,
+    })
 }
 
 impl HelixirClient {
@@ -40,6 +282,23 @@ impl HelixirClient {
         agent_id: Option<&str>,
         metadata: Option<HashMap<String, serde_json::Value>>,
         context_tags: Option<&str>,
+    ) -> Result<AddMemoryResult, HelixirClientError> {
+        let result = self
+            .add_unannounced(message, user_id, agent_id, metadata, context_tags)
+            .await?;
+        self.announce_added(&result, user_id);
+        Ok(result)
+    }
+
+    /// [`Self::add_with_tags`] without the `add` webhook — for FastThink,
+    /// which announces what it writes as a `commit`.
+    pub(crate) async fn add_unannounced(
+        &self,
+        message: &str,
+        user_id: &str,
+        agent_id: Option<&str>,
+        metadata: Option<HashMap<String, serde_json::Value>>,
+        context_tags: Option<&str>,
     ) -> Result<AddMemoryResult, HelixirClientError> {
         self.ensure_initialized().await?;
 
@@ -47,9 +306,10 @@ impl HelixirClient {
             .tooling_manager
             .add_memory(message, user_id, agent_id, metadata, context_tags)
             .await
-            .map_err(|e| HelixirClientError::Tooling(e.to_string()))?;
+            .map_err(HelixirClientError::from_tooling)?;
 
         Ok(AddMemoryResult {
+            duplicate: result.is_duplicate(),
             memories_added: result.added.len(),
             memory_ids: result.added,
             deduped: result.deduped,
@@ -58,9 +318,48 @@ impl HelixirClient {
             relations_created: result.reasoning_relations_created,
             stats: result.metadata,
             needs_clarification: result.needs_clarification,
+            no_facts_extracted: result.no_facts_extracted,
+            duplicates: result.duplicates,
+            needs_embedding: result.needs_embedding,
         })
     }
 
+    /// Add memory and keep the original message as a retrievable source node
+    /// the extracted facts link back to (`store_source` on `add_memory`).
+    /// Search rows extracted from it carry `source_id` + `source_excerpt`.
+    pub async fn add_with_source(
+        &self,
+        message: &str,
+        user_id: &str,
+        agent_id: Option<&str>,
+        context_tags: Option<&str>,
+    ) -> Result<AddMemoryResult, HelixirClientError> {
+        self.ensure_initialized().await?;
+
+        let result = self
+            .tooling_manager
+            .add_memory_with_source(message, user_id, agent_id, context_tags)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+
+        let result = AddMemoryResult {
+            duplicate: result.is_duplicate(),
+            memories_added: result.added.len(),
+            memory_ids: result.added,
+            deduped: result.deduped,
+            chunks_created: result.chunks_created,
+            entities_extracted: result.entities_extracted,
+            relations_created: result.reasoning_relations_created,
+            stats: result.metadata,
+            needs_clarification: result.needs_clarification,
+            no_facts_extracted: result.no_facts_extracted,
+            duplicates: result.duplicates,
+            needs_embedding: result.needs_embedding,
+        };
+        self.announce_added(&result, user_id);
+        Ok(result)
+    }
+
     /// Store atoms the caller has ALREADY structured (FastThink commit) —
     /// the same pipeline as `add_with_tags` minus the extraction LLM call.
     /// Dedup, the charter and typed-edge enrichment all still apply.
@@ -70,6 +369,22 @@ impl HelixirClient {
         user_id: &str,
         agent_id: Option<&str>,
         context_tags: Option<&str>,
+    ) -> Result<AddMemoryResult, HelixirClientError> {
+        let result = self
+            .add_prepared_unannounced(memories, user_id, agent_id, context_tags)
+            .await?;
+        self.announce_added(&result, user_id);
+        Ok(result)
+    }
+
+    /// [`Self::add_prepared`] without the `add` webhook (see
+    /// [`Self::add_unannounced`]).
+    pub(crate) async fn add_prepared_unannounced(
+        &self,
+        memories: Vec<crate::llm::extractor::ExtractedMemory>,
+        user_id: &str,
+        agent_id: Option<&str>,
+        context_tags: Option<&str>,
     ) -> Result<AddMemoryResult, HelixirClientError> {
         self.ensure_initialized().await?;
 
@@ -80,6 +395,7 @@ impl HelixirClient {
             .map_err(|e| HelixirClientError::Tooling(e.to_string()))?;
 
         Ok(AddMemoryResult {
+            duplicate: result.is_duplicate(),
             memories_added: result.added.len(),
             memory_ids: result.added,
             deduped: result.deduped,
@@ -88,9 +404,20 @@ impl HelixirClient {
             relations_created: result.reasoning_relations_created,
             stats: result.metadata,
             needs_clarification: result.needs_clarification,
+            no_facts_extracted: result.no_facts_extracted,
+            duplicates: result.duplicates,
+            needs_embedding: result.needs_embedding,
         })
     }
 
+    /// Send the `add` webhook for what `result` stored. A result shared
+    /// from an identical add in flight was announced by that add already.
+    fn announce_added(&self, result: &AddMemoryResult, user_id: &str) {
+        if !result.stats.contains_key(SHARED_RESULT_KEY) {
+            self.notify_webhook(WebhookEvent::Add, &result.memory_ids, user_id);
+        }
+    }
+
     /// Ingest buffer (#25): persist the raw input and return a `pending_id`
     /// immediately. A background worker drains the queue serially. Use
     /// [`Self::add_status`] to poll for the result.
@@ -171,35 +498,428 @@ impl HelixirClient {
             .map_err(|e| HelixirClientError::Tooling(e.to_string()))
     }
 
+    /// Result cap a search in `mode` gets when the caller names no `limit`
+    /// (the mode's `max_results`; unknown modes read as `recent`).
+    pub fn default_limit_for(&self, mode: &str) -> usize {
+        self.config
+            .retrieval
+            .search_modes
+            .limit_for(crate::core::search_modes::SearchMode::parse_mode(mode))
+    }
+
     /// Search the memory. Every unset [`SearchParams`] field means "the
-    /// configured default". #87: an active `window` bounds recall by EVENT
+    /// configured default" — `user_id`'s overrides over the global config
+    /// (see [`Self::effective_config`]). #87: an active `window` bounds recall by EVENT
     /// time; out-of-window rows reachable through the graph come back
     /// flagged as flashbacks (`metadata.flashback` + `event_date`).
+    ///
+    /// Every returned row counts as an access (best-effort, throttled); an
+    /// access `sort` re-orders the rows by those stats and reports them in
+    /// `metadata.access_count` / `last_accessed_at`.
+    ///
+    /// An `entity_attributes` filter or a `boost_concept` over-fetches: the
+    /// filter drops rows with no matching entity, the boost re-ranks before
+    /// `min_score` applies, and the rows are trimmed back to the limit.
     pub async fn search(
         &self,
         query: &str,
         user_id: &str,
         params: SearchParams,
+    ) -> Result<Vec<SearchResult>, HelixirClientError> {
+        let config = self.effective_config(user_id).await;
+        self.search_with(query, user_id, params, &config).await
+    }
+
+    /// [`Self::search`] under a `config` the caller already resolved with
+    /// [`Self::effective_config`], so a request that also reads the user's
+    /// settings looks them up once.
+    pub async fn search_with(
+        &self,
+        query: &str,
+        user_id: &str,
+        params: SearchParams,
+        config: &HelixirConfig,
     ) -> Result<Vec<SearchResult>, HelixirClientError> {
         self.ensure_initialized().await?;
 
+        let boost_concept = match params.boost_concept.as_deref().map(str::trim) {
+            Some(t) if !t.is_empty() => {
+                let t = t.to_lowercase();
+                if !crate::toolkit::tooling_manager::helpers::MEMORY_TYPES.contains(&t.as_str()) {
+                    return Err(HelixirClientError::Config(format!(
+                        "search: unknown boost_concept '{t}'"
+                    )));
+                }
+                Some(t)
+            }
+            _ => None,
+        };
         let mode = params
             .search_mode
             .as_deref()
-            .unwrap_or(&self.config.default_search_mode);
+            .unwrap_or(&config.default_search_mode);
+        let limit = params.limit.unwrap_or_else(|| {
+            config
+                .retrieval
+                .search_modes
+                .limit_for(crate::core::search_modes::SearchMode::parse_mode(mode))
+        });
+        let fetch = if params.entity_attributes.is_empty() && boost_concept.is_none() {
+            limit
+        } else {
+            limit.saturating_mul(RERANK_OVERFETCH)
+        };
+        let results = match &params.within_ids {
+            Some(ids) => {
+                let max = crate::toolkit::tooling_manager::MAX_WITHIN_IDS;
+                if ids.len() > max {
+                    return Err(HelixirClientError::Config(format!(
+                        "search: within_ids holds {} ids; at most {max} are ranked per call",
+                        ids.len()
+                    )));
+                }
+                self.tooling_manager
+                    .search_within(query, user_id, ids, fetch)
+                    .await
+            }
+            None => {
+                self.tooling_manager
+                    .search_memory(
+                        query,
+                        user_id,
+                        crate::toolkit::tooling_manager::MemorySearchOptions {
+                            limit: Some(fetch),
+                            mode: mode.to_string(),
+                            temporal_days: params.temporal_days,
+                            graph_depth: params.graph_depth,
+                            scope: params.scope.unwrap_or_else(|| "personal".to_string()),
+                            window: params.window,
+                            hybrid: params.hybrid,
+                            windows: Some(config.windows.clone()),
+                        },
+                    )
+                    .await
+            }
+        }
+        .map_err(HelixirClientError::from_tooling)?;
+
+        let mut results: Vec<SearchResult> = results
+            .into_iter()
+            .map(|r| SearchResult {
+                id: r.memory_id,
+                content: r.content,
+                score: r.score as f32,
+                metadata: r.metadata,
+                created_at: r.created_at,
+                hops: r.hops,
+            })
+            .collect();
+        let retrieval = &config.retrieval;
+        normalize_scores(
+            &mut results,
+            retrieval.score_normalization,
+            retrieval.score_sigmoid_midpoint,
+            retrieval.score_sigmoid_steepness,
+        );
+
+        if !params.entity_attributes.is_empty() {
+            let own = self.tooling_manager.user_entity_attributes(user_id).await;
+            // A boost may still lift a row from below the limit.
+            let keep = if boost_concept.is_some() {
+                fetch
+            } else {
+                limit
+            };
+            let ids: Vec<String> = results.iter().map(|r| r.id.clone()).collect();
+            let entities = self
+                .tooling_manager
+                .memories_entity_attributes(&ids, &own)
+                .await;
+            let mut kept = Vec::with_capacity(keep);
+            for r in results {
+                if kept.len() == keep {
+                    break;
+                }
+                if entities.get(&r.id).is_some_and(|attrs| {
+                    attrs
+                        .iter()
+                        .any(|attrs| attributes_match(attrs, &params.entity_attributes))
+                }) {
+                    kept.push(r);
+                }
+            }
+            results = kept;
+        }
+
+        if let Some(half_life) = config.confidence_decay {
+            let ids: Vec<String> = results.iter().map(|r| r.id.clone()).collect();
+            let inputs = self.tooling_manager.decay_inputs(&ids).await;
+            apply_confidence_decay(&mut results, &inputs, half_life, chrono::Utc::now());
+        }
+        if let Some(concept) = &boost_concept {
+            let ids: Vec<String> = results.iter().map(|r| r.id.clone()).collect();
+            let matching = self.tooling_manager.memories_of_type(&ids, concept).await;
+            apply_concept_boost(&mut results, &matching, retrieval.concept_boost);
+        }
+        if let Some(min) = params.min_score {
+            results.retain(|r| r.score >= min);
+        }
+        results.truncate(limit);
+
+        let sort = params
+            .sort
+            .as_deref()
+            .map(SearchSort::parse)
+            .unwrap_or_default();
+        match sort {
+            SearchSort::Relevance => {}
+            SearchSort::Newest | SearchSort::Oldest => sort_by_time(&mut results, sort),
+            SearchSort::RecentlyAccessed | SearchSort::FrequentlyUsed => {
+                let ids: Vec<String> = results.iter().map(|r| r.id.clone()).collect();
+                let stats = self.tooling_manager.access_stats(&ids).await;
+                sort_by_access(&mut results, &stats, sort);
+            }
+        }
+        let ids: Vec<String> = results.iter().map(|r| r.id.clone()).collect();
+        self.tooling_manager.record_access(&ids);
+        Ok(results)
+    }
+
+    /// The event-time window a search with these arguments filters by — the
+    /// same resolution the search itself applies — and how many of the
+    /// user's memories it covers. Lets a caller tell "nothing stored" from
+    /// "nothing in the window" when a search comes back empty. `config` is
+    /// the user's [`Self::effective_config`]; `None` for `mode` means their
+    /// default mode. The counts cost a pass over the user's memories when a
+    /// window is active, so they are taken only with `count`.
+    pub async fn search_window(
+        &self,
+        user_id: &str,
+        config: &HelixirConfig,
+        mode: Option<&str>,
+        temporal_days: Option<f64>,
+        window: crate::core::TimeWindow,
+        count: bool,
+    ) -> Result<SearchWindowReport, HelixirClientError> {
+        self.ensure_initialized().await?;
+        let mode = mode.unwrap_or(&config.default_search_mode);
+        let mode_days = config
+            .retrieval
+            .search_modes
+            .for_mode(crate::core::search_modes::SearchMode::parse_mode(mode))
+            .temporal_days;
+        let (window, source) = crate::core::time_window::effective_window(
+            window,
+            temporal_days.or(mode_days),
+            chrono::Utc::now(),
+        );
+        let counts = if count {
+            Some(
+                self.tooling_manager
+                    .window_counts(user_id, &window)
+                    .await
+                    .map_err(HelixirClientError::from_tooling)?,
+            )
+        } else {
+            None
+        };
+        Ok(SearchWindowReport {
+            from: window.from.map(|t| t.to_rfc3339()),
+            to: window.to.map(|t| t.to_rfc3339()),
+            source,
+            total_in_window: counts.map(|c| c.total_in_window),
+            total_overall: counts.map(|c| c.total_overall),
+        })
+    }
+
+    /// Run `query` once per search mode, concurrently, and report how many
+    /// rows each finds and its best one — "recent finds nothing, deep finds
+    /// 12". `limit` (default 10) is per mode and capped at
+    /// [`COMPARE_MODES_MAX_LIMIT`]. Personal scope; the searches are not
+    /// counted as accesses.
+    pub async fn compare_search_modes(
+        &self,
+        query: &str,
+        user_id: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<ModeComparison>, HelixirClientError> {
+        if query.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "compare_search_modes: query is empty".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+        let limit = limit.unwrap_or(10).clamp(1, COMPARE_MODES_MAX_LIMIT);
+
+        let runs = COMPARED_MODES.into_iter().map(|mode| async move {
+            let opts = crate::toolkit::tooling_manager::MemorySearchOptions {
+                limit: Some(limit),
+                ..crate::toolkit::tooling_manager::MemorySearchOptions::new(mode)
+            };
+            let mut comparison = ModeComparison {
+                mode,
+                description: crate::core::search_modes::SearchMode::parse_mode(mode).description(),
+                result_count: 0,
+                capped: false,
+                top: None,
+                error: None,
+            };
+            match self
+                .tooling_manager
+                .search_memory(query, user_id, opts)
+                .await
+            {
+                Ok(hits) => {
+                    comparison.result_count = hits.len();
+                    comparison.capped = hits.len() >= limit;
+                    comparison.top = hits.into_iter().next().map(|r| SearchResult {
+                        id: r.memory_id,
+                        content: r.content,
+                        score: r.score as f32,
+                        metadata: r.metadata,
+                        created_at: r.created_at,
+                        hops: r.hops,
+                    });
+                }
+                Err(e) => comparison.error = Some(e.to_string()),
+            }
+            comparison
+        });
+        Ok(futures::future::join_all(runs).await)
+    }
+
+    /// Whether `message` is worth an `add`: the importance heuristics of
+    /// [`crate::toolkit::mind_toolbox::memory::importance`], then (with a
+    /// `user_id`) one search for the closest stored memory. A near-verbatim
+    /// match flips the advice to "don't add"; a merely similar one points
+    /// at `update_memory`. The lookup is not counted as an access, and its
+    /// failure only drops the similarity part of the advice.
+    pub async fn should_remember(
+        &self,
+        message: &str,
+        user_id: Option<&str>,
+    ) -> Result<RememberAdvice, HelixirClientError> {
+        let assessment = importance::assess(message);
+        let mut advice = RememberAdvice {
+            recommend: assessment.recommend,
+            importance: assessment.importance,
+            reason: assessment.reason,
+            signals: assessment.signals,
+            similar: None,
+            similarity_checked: false,
+            similarity_error: None,
+        };
+        let Some(user_id) = user_id.filter(|u| !u.trim().is_empty()) else {
+            return Ok(advice);
+        };
+        if !advice.recommend {
+            return Ok(advice);
+        }
+        self.ensure_initialized().await?;
+
+        let thresholds = &self.config.search_thresholds;
+        let opts = crate::toolkit::tooling_manager::MemorySearchOptions {
+            limit: Some(3),
+            ..crate::toolkit::tooling_manager::MemorySearchOptions::new("full")
+        };
+        advice.similarity_checked = true;
+        match self
+            .tooling_manager
+            .search_memory(message, user_id, opts)
+            .await
+        {
+            Ok(hits) => {
+                let closest = hits
+                    .into_iter()
+                    .map(|r| {
+                        let similarity = r
+                            .metadata
+                            .get("cosine")
+                            .and_then(serde_json::Value::as_f64)
+                            .unwrap_or(r.score);
+                        (r, similarity)
+                    })
+                    .max_by(|a, b| a.1.total_cmp(&b.1))
+                    .filter(|(_, s)| *s >= thresholds.similarity_threshold);
+                if let Some((hit, similarity)) = closest {
+                    let duplicate = similarity >= thresholds.exact_duplicate_score;
+                    if duplicate {
+                        advice.recommend = false;
+                        advice.reason = format!(
+                            "already stored as {} (similarity {similarity:.2}); an add would be deduped",
+                            hit.memory_id
+                        );
+                        advice.signals.push("duplicate");
+                    } else {
+                        advice.reason = format!(
+                            "{}; a similar memory exists ({}, similarity {similarity:.2}) — prefer update_memory if this revises it",
+                            advice.reason, hit.memory_id
+                        );
+                        advice.signals.push("similar_exists");
+                    }
+                    advice.similar = Some(SimilarHit {
+                        memory_id: hit.memory_id,
+                        content: hit.content,
+                        similarity,
+                        duplicate,
+                    });
+                }
+            }
+            Err(e) => advice.similarity_error = Some(e.to_string()),
+        }
+        Ok(advice)
+    }
+
+    /// Offline cost estimate for `add(message)`: approximate tokens of the
+    /// extraction call, expected chunk count and a list-price cost for the
+    /// configured model from `llm_runtime.pricing` (see
+    /// [`crate::llm::pricing`]). Touches neither the
+    /// LLM nor HelixDB.
+    pub fn estimate_add(&self, message: &str) -> AddEstimate {
+        let tooling = &self.tooling_manager;
+        let system_prompt = tooling.extractor.build_system_prompt(true, true);
+        let message_tokens = approx_tokens(message);
+        let input_tokens = approx_tokens(&system_prompt) + message_tokens;
+        // The extraction JSON restates the facts plus entities/relations —
+        // about 1.5x the message, capped near the 15-fact ceiling.
+        let expected_output_tokens = (message_tokens * 3 / 2 + 100).min(4000);
+        let expected_chunks = if tooling.chunking_manager.should_chunk(message) {
+            tooling.chunking_manager.split_text(message).len()
+        } else {
+            0
+        };
+        let estimated_cost_usd = pricing_for(
+            &self.config.llm_runtime.pricing,
+            &self.config.llm_provider,
+            &self.config.llm_model,
+        )
+        .map(|p| p.cost_usd(input_tokens, expected_output_tokens));
+
+        AddEstimate {
+            message_chars: message.chars().count(),
+            input_tokens,
+            expected_output_tokens,
+            expected_chunks,
+            provider: self.config.llm_provider.clone(),
+            model: self.config.llm_model.clone(),
+            estimated_cost_usd,
+        }
+    }
+
+    /// The user's newest memories, unranked — "browse recent" for a blank
+    /// query (see `HelixirConfig::empty_query_browse`).
+    pub async fn recent(
+        &self,
+        user_id: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<SearchResult>, HelixirClientError> {
+        self.ensure_initialized().await?;
+
         let results = self
             .tooling_manager
-            .search_memory(
-                query,
+            .recent_memories(
                 user_id,
-                crate::toolkit::tooling_manager::MemorySearchOptions {
-                    limit: params.limit,
-                    mode: mode.to_string(),
-                    temporal_days: params.temporal_days,
-                    graph_depth: params.graph_depth,
-                    scope: params.scope.unwrap_or_else(|| "personal".to_string()),
-                    window: params.window,
-                },
+                limit.unwrap_or(self.config.retrieval.search_modes.recent.max_results),
             )
             .await
             .map_err(|e| HelixirClientError::Tooling(e.to_string()))?;
@@ -212,10 +932,121 @@ impl HelixirClient {
                 score: r.score as f32,
                 metadata: r.metadata,
                 created_at: r.created_at,
+                hops: r.hops,
             })
             .collect())
     }
 
+    /// One memory by id: full content, tags, metadata, concept type,
+    /// timestamps, usage and a summary of its relations. `Ok(None)` when no
+    /// memory has that id or `user_id` does not hold it.
+    pub async fn get_memory(
+        &self,
+        memory_id: &str,
+        user_id: &str,
+    ) -> Result<Option<MemoryDetail>, HelixirClientError> {
+        if memory_id.trim().is_empty() || user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "get_memory: memory_id and user_id must be non-empty".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+
+        let Some(record) = self
+            .tooling_manager
+            .get_memory_record(memory_id)
+            .await
+            .map_err(|e| HelixirClientError::Tooling(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+        let access = self
+            .tooling_manager
+            .access_stats(&[memory_id.to_string()])
+            .await
+            .remove(memory_id)
+            .unwrap_or_default();
+        Ok(memory_detail(record, access, user_id))
+    }
+
+    /// The memories `memory_id` was concluded from: its incoming SUPPORTS
+    /// edges, with those `think_commit` drew from the session's recalled
+    /// evidence first. `None` when `user_id` does not hold the memory.
+    pub async fn get_conclusion_sources(
+        &self,
+        memory_id: &str,
+        user_id: &str,
+    ) -> Result<Option<Vec<ConclusionSource>>, HelixirClientError> {
+        if self.get_memory(memory_id, user_id).await?.is_none() {
+            return Ok(None);
+        }
+        let sources = self
+            .tooling_manager
+            .supporting_sources(memory_id)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        Ok(Some(
+            sources
+                .into_iter()
+                .map(|s| ConclusionSource {
+                    memory_id: s.memory_id,
+                    content: s.content,
+                    strength: s.strength,
+                    from_commit: s.from_commit,
+                })
+                .collect(),
+        ))
+    }
+
+    /// How `memory_id` was derived: its SUPPORTS sources, then theirs, up to
+    /// [`WHY_MAX_DEPTH`] hops, breadth first. A memory reached twice is
+    /// listed once, at its first depth. Comes with the memory itself;
+    /// `None` when `user_id` does not hold it.
+    pub async fn why_memory(
+        &self,
+        memory_id: &str,
+        user_id: &str,
+    ) -> Result<Option<MemoryEvidence>, HelixirClientError> {
+        let Some(memory) = self.get_memory(memory_id, user_id).await? else {
+            return Ok(None);
+        };
+        let mut seen: HashSet<String> = HashSet::from([memory_id.to_string()]);
+        let mut frontier = vec![memory_id.to_string()];
+        let mut chain = Vec::new();
+        for depth in 1..=WHY_MAX_DEPTH {
+            let mut next = Vec::new();
+            for supported in &frontier {
+                let sources = self
+                    .tooling_manager
+                    .supporting_sources(supported)
+                    .await
+                    .map_err(HelixirClientError::from_tooling)?;
+                for s in sources {
+                    if !seen.insert(s.memory_id.clone()) {
+                        continue;
+                    }
+                    next.push(s.memory_id.clone());
+                    chain.push(EvidenceLink {
+                        memory_id: s.memory_id,
+                        content: s.content,
+                        supports: supported.clone(),
+                        depth,
+                        strength: s.strength,
+                        from_commit: s.from_commit,
+                    });
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        Ok(Some(MemoryEvidence {
+            memory,
+            evidence: chain,
+        }))
+    }
+
     pub async fn update(
         &self,
         memory_id: &str,
@@ -237,6 +1068,245 @@ impl HelixirClient {
         })
     }
 
+    /// Add `additional_content` to the end of a memory `user_id` holds,
+    /// keeping what is there. The combined text is re-embedded and
+    /// re-extracted; new entities are linked and reasoning edges re-judged.
+    pub async fn append(
+        &self,
+        memory_id: &str,
+        additional_content: &str,
+        user_id: &str,
+    ) -> Result<AppendResult, HelixirClientError> {
+        if additional_content.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "append: additional_content must be non-empty".to_string(),
+            ));
+        }
+        if self.get_memory(memory_id, user_id).await?.is_none() {
+            return Err(HelixirClientError::Config(format!(
+                "append: memory {memory_id} not found for user {user_id}"
+            )));
+        }
+
+        let outcome = self
+            .tooling_manager
+            .append_memory(memory_id, additional_content, user_id)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        Ok(AppendResult {
+            memory_id: memory_id.to_string(),
+            content: outcome.content,
+            new_facts_added: outcome.new_facts_added,
+            entities_linked: outcome.entities_linked,
+            relations_added: outcome.relations_added,
+        })
+    }
+
+    /// Replace a wrong memory `user_id` holds with `corrected_content`: a
+    /// new memory that supersedes it as a correction, so default search
+    /// stops returning the old one while `get_memory` still reads it.
+    pub async fn correct_memory(
+        &self,
+        memory_id: &str,
+        corrected_content: &str,
+        user_id: &str,
+    ) -> Result<CorrectionResult, HelixirClientError> {
+        if corrected_content.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "correct_memory: corrected_content must be non-empty".to_string(),
+            ));
+        }
+        if self.get_memory(memory_id, user_id).await?.is_none() {
+            return Err(HelixirClientError::Config(format!(
+                "correct_memory: memory {memory_id} not found for user {user_id}"
+            )));
+        }
+
+        let outcome = self
+            .tooling_manager
+            .correct_memory(memory_id, corrected_content, user_id)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        self.notify_webhook(
+            WebhookEvent::Add,
+            std::slice::from_ref(&outcome.memory_id),
+            user_id,
+        );
+        Ok(CorrectionResult {
+            memory_id: outcome.memory_id,
+            corrected_id: outcome.corrected_id,
+            previous_content: outcome.previous_content,
+            content: outcome.content,
+        })
+    }
+
+    /// Fold `user_id`'s recent memories into one stored summary (see
+    /// [`crate::toolkit::tooling_manager::ToolingManager::summarize_recent`]).
+    /// `None`s fall back to the `summary` config.
+    pub async fn summarize_recent(
+        &self,
+        user_id: &str,
+        window_hours: Option<u64>,
+        archive_sources: Option<bool>,
+    ) -> Result<SummaryResult, HelixirClientError> {
+        if user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "summarize_recent: user_id must be non-empty".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+
+        let outcome = self
+            .tooling_manager
+            .summarize_recent(user_id, window_hours, archive_sources)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        if let Some(id) = &outcome.summary_id {
+            self.notify_webhook(WebhookEvent::Add, std::slice::from_ref(id), user_id);
+        }
+        Ok(SummaryResult {
+            summary_id: outcome.summary_id,
+            content: outcome.content,
+            source_ids: outcome.source_ids,
+            window_hours: outcome.window_hours,
+            sources_linked: outcome.sources_linked,
+            archived: outcome.archived,
+        })
+    }
+
+    /// Browse `user_id`'s memories by `filters`, newest first, `limit` rows
+    /// after `offset` — no query, no embedding (see
+    /// [`crate::toolkit::tooling_manager::listing`]). Feed `next_cursor`
+    /// back as `offset` for the next page.
+    pub async fn list_memories(
+        &self,
+        user_id: &str,
+        filters: &MemoryListFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<ListPage, HelixirClientError> {
+        if user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "list_memories: user_id must be non-empty".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+
+        let page = self
+            .tooling_manager
+            .list_memories(user_id, filters, offset, limit)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        Ok(ListPage {
+            memories: page.memories,
+            next_cursor: page.next_offset,
+            total: page.total_rows,
+            positions: page.positions,
+        })
+    }
+
+    /// Memories of `user_id` that likely need updating (see
+    /// [`crate::toolkit::tooling_manager::ToolingManager::find_stale`]).
+    /// `None`s fall back to the `stale` config.
+    pub async fn find_stale(
+        &self,
+        user_id: &str,
+        min_age_days: Option<u64>,
+        limit: Option<usize>,
+    ) -> Result<Vec<StaleMemory>, HelixirClientError> {
+        if user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "find_stale: user_id must be non-empty".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+
+        let candidates = self
+            .tooling_manager
+            .find_stale(user_id, min_age_days, limit)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        Ok(candidates
+            .into_iter()
+            .map(|c| StaleMemory {
+                memory_id: c.memory_id,
+                content: c.content,
+                created_at: c.created_at,
+                age_days: c.age_days,
+                reasons: c.reasons,
+                markers: c.markers,
+                contradicted_by: c.contradicted_by,
+            })
+            .collect())
+    }
+
+    /// Group `user_id`'s memories into topics (see
+    /// [`crate::toolkit::tooling_manager::ToolingManager::cluster_memories`]).
+    /// `k: None` picks the cluster count by silhouette; `label` asks the LLM
+    /// to name each cluster.
+    pub async fn cluster_memories(
+        &self,
+        user_id: &str,
+        k: Option<usize>,
+        label: bool,
+    ) -> Result<ClusterResult, HelixirClientError> {
+        if user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "cluster_memories: user_id must be non-empty".to_string(),
+            ));
+        }
+        if k == Some(0) {
+            return Err(HelixirClientError::Config(
+                "cluster_memories: k must be at least 1".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+
+        let outcome = self
+            .tooling_manager
+            .cluster_memories(user_id, k, label)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        Ok(ClusterResult {
+            user_id: user_id.to_string(),
+            k: outcome.k,
+            auto_k: outcome.auto_k,
+            silhouette: outcome.silhouette,
+            memories_clustered: outcome.memories_clustered,
+            clusters: outcome.clusters,
+        })
+    }
+
+    /// Drop one context tag from a memory (see
+    /// [`crate::toolkit::tooling_manager::ToolingManager::remove_context_tag`]).
+    pub async fn remove_context_tag(
+        &self,
+        memory_id: &str,
+        tag: &str,
+    ) -> Result<bool, HelixirClientError> {
+        self.ensure_initialized().await?;
+
+        self.tooling_manager
+            .remove_context_tag(memory_id, tag)
+            .await
+            .map_err(|e| HelixirClientError::Tooling(e.to_string()))
+    }
+
+    /// Attach file/document references to freshly written memories (see
+    /// [`crate::toolkit::tooling_manager::ToolingManager::attach_references`]).
+    pub async fn attach_references(
+        &self,
+        memory_ids: &[String],
+        references: &[MemoryReference],
+    ) -> Result<usize, HelixirClientError> {
+        self.ensure_initialized().await?;
+
+        self.tooling_manager
+            .attach_references(memory_ids, references)
+            .await
+            .map_err(|e| HelixirClientError::Tooling(e.to_string()))
+    }
+
     pub async fn delete(&self, memory_id: &str) -> Result<bool, HelixirClientError> {
         self.ensure_initialized().await?;
 
@@ -245,4 +1315,292 @@ impl HelixirClient {
             .await
             .map_err(|e| HelixirClientError::Tooling(e.to_string()))
     }
+
+    /// Erase everything the graph holds for `user_id` (see
+    /// [`crate::toolkit::tooling_manager::ToolingManager::purge_user`]).
+    /// Irreversible, so `confirm_token` must repeat the user_id verbatim.
+    pub async fn purge_user(
+        &self,
+        user_id: &str,
+        confirm_token: &str,
+    ) -> Result<PurgeReport, HelixirClientError> {
+        if user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "purge_user: user_id must be non-empty".to_string(),
+            ));
+        }
+        if confirm_token != user_id {
+            return Err(HelixirClientError::Config(format!(
+                "purge_user: confirm_token must equal the user_id ('{user_id}') to confirm the purge"
+            )));
+        }
+        self.ensure_initialized().await?;
+
+        let stats = self
+            .tooling_manager
+            .purge_user(user_id)
+            .await
+            .map_err(|e| HelixirClientError::Tooling(e.to_string()))?;
+        self.user_configs.lock().pop(user_id);
+
+        Ok(PurgeReport {
+            user_id: user_id.to_string(),
+            memories: stats.memories,
+            memories_unlinked: stats.memories_unlinked,
+            entities: stats.entities,
+            relations: stats.relations,
+            embeddings: stats.embeddings,
+            chunks: stats.chunks,
+            user_node_removed: stats.user_node_removed,
+            sessions_discarded: 0,
+        })
+    }
+
+    /// Re-run relation inference over stored memories and reconcile their
+    /// reasoning edges (see
+    /// [`crate::toolkit::tooling_manager::ToolingManager::rebuild_relations`]).
+    /// `user_id: None` covers every user. One call handles one batch; feed
+    /// `next_cursor` back through `opts.cursor` until it comes back `None`.
+    pub async fn rebuild_relations(
+        &self,
+        user_id: Option<&str>,
+        opts: RebuildOptions,
+    ) -> Result<RebuildReport, HelixirClientError> {
+        if user_id.is_some_and(|u| u.trim().is_empty()) {
+            return Err(HelixirClientError::Config(
+                "rebuild_relations: user_id must be non-empty when given".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+
+        let stats = self
+            .tooling_manager
+            .rebuild_relations(user_id, &opts)
+            .await
+            .map_err(|e| HelixirClientError::Tooling(e.to_string()))?;
+
+        Ok(RebuildReport {
+            user_id: user_id.map(str::to_string),
+            dry_run: opts.dry_run,
+            prune: opts.prune,
+            memories_scanned: stats.memories_scanned,
+            relations_added: stats.relations_added,
+            relations_stale: stats.relations_stale,
+            relations_removed: stats.relations_removed,
+            relations_unchanged: stats.relations_unchanged,
+            failures: stats.failures,
+            next_cursor: stats.next_cursor,
+        })
+    }
+
+    /// Embed memories stored without a vector during an embedding outage
+    /// (`HELIXIR_ON_EMBED_FAIL=defer`), at most `limit` per call, resuming
+    /// at `cursor` (see
+    /// [`crate::toolkit::tooling_manager::ToolingManager::reindex_embeddings`]).
+    /// `user_id: None` covers every user.
+    pub async fn reindex_embeddings(
+        &self,
+        user_id: Option<&str>,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<ReindexReport, HelixirClientError> {
+        if user_id.is_some_and(|u| u.trim().is_empty()) {
+            return Err(HelixirClientError::Config(
+                "reindex_embeddings: user_id must be non-empty when given".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+
+        let stats = self
+            .tooling_manager
+            .reindex_embeddings(user_id, limit, cursor)
+            .await
+            .map_err(|e| HelixirClientError::Tooling(e.to_string()))?;
+
+        Ok(ReindexReport {
+            user_id: user_id.map(str::to_string),
+            memories_scanned: stats.memories_scanned,
+            reindexed: stats.reindexed.len(),
+            memory_ids: stats.reindexed,
+            failures: stats.failures,
+            next_cursor: stats.next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn record() -> MemoryRecord {
+        MemoryRecord {
+            node: json!({
+                "memory_id": "mem_a",
+                "user_id": "alice",
+                "content": "Deploys run on Fridays.",
+                "memory_type": "fact",
+                "certainty": 80,
+                "importance": 50,
+                "context_tags": "ops, deploy",
+                "metadata": "{\"origin\":\"chat\"}",
+                "valid_until": "",
+                "verified": 1,
+            }),
+            users: vec!["bob".to_string()],
+            concepts: vec!["Fact".to_string()],
+            entities: vec![],
+            relations: vec![
+                ("IMPLIES".into(), "mem_a".into(), "mem_b".into()),
+                ("IMPLIES".into(), "mem_c".into(), "mem_a".into()),
+                ("CONTRADICTS".into(), "mem_d".into(), "mem_a".into()),
+            ],
+        }
+    }
+
+    #[test]
+    fn normalized_scores_keep_the_order_and_the_raw_score() {
+        let rows = |scores: &[f32]| -> Vec<SearchResult> {
+            scores
+                .iter()
+                .map(|&score| SearchResult {
+                    id: format!("m{score}"),
+                    content: String::new(),
+                    score,
+                    metadata: HashMap::new(),
+                    created_at: String::new(),
+                    hops: 0,
+                })
+                .collect()
+        };
+        let scores = |rows: &[SearchResult]| rows.iter().map(|r| r.score).collect::<Vec<_>>();
+
+        let mut plain = rows(&[0.8, 0.4]);
+        normalize_scores(&mut plain, ScoreNormalization::None, 0.5, 10.0);
+        assert_eq!(scores(&plain), [0.8, 0.4]);
+        assert!(plain[0].metadata.is_empty());
+
+        let mut minmax = rows(&[0.9, 0.6, 0.3]);
+        normalize_scores(&mut minmax, ScoreNormalization::MinMax, 0.5, 10.0);
+        let s = scores(&minmax);
+        assert_eq!((s[0], s[2]), (1.0, 0.0));
+        assert!((s[1] - 0.5).abs() < 1e-6, "{s:?}");
+        assert_eq!(minmax[1].metadata["raw_score"], json!(0.6_f32));
+        let mut flat = rows(&[1.7]);
+        normalize_scores(&mut flat, ScoreNormalization::MinMax, 0.5, 10.0);
+        assert_eq!(scores(&flat), [1.0]);
+
+        let mut sigmoid = rows(&[0.9, 0.5, 0.1]);
+        normalize_scores(&mut sigmoid, ScoreNormalization::Sigmoid, 0.5, 10.0);
+        let s = scores(&sigmoid);
+        assert!(s[0] > 0.98 && s[2] < 0.02, "{s:?}");
+        assert!((s[1] - 0.5).abs() < 1e-6, "{s:?}");
+    }
+
+    #[test]
+    fn a_concept_boost_lifts_matching_rows_and_reports_every_factor() {
+        let row = |id: &str, score: f32| SearchResult {
+            id: id.to_string(),
+            content: String::new(),
+            score,
+            metadata: HashMap::new(),
+            created_at: String::new(),
+            hops: 0,
+        };
+        let mut results = vec![
+            row("fact", 0.8),
+            row("pref", 0.6),
+            row("other", 0.7),
+            row("top_pref", 0.9),
+        ];
+        let matching = HashSet::from(["pref".to_string(), "top_pref".to_string()]);
+        apply_concept_boost(&mut results, &matching, 1.5);
+
+        let ids: Vec<_> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["top_pref", "pref", "fact", "other"]);
+        assert_eq!(results[0].score, 1.0, "clamped");
+        assert!((results[1].score - 0.9).abs() < 1e-6);
+        assert_eq!(results[1].metadata["concept_boost"], json!(1.5));
+        assert_eq!(results[2].metadata["concept_boost"], json!(1.0));
+    }
+
+    #[test]
+    fn decay_reranks_stale_rows_below_fresh_ones() {
+        let now = chrono::Utc::now();
+        let row = |id: &str, score: f32| SearchResult {
+            id: id.to_string(),
+            content: String::new(),
+            score,
+            metadata: HashMap::new(),
+            created_at: String::new(),
+            hops: 0,
+        };
+        let inputs = |days: i64, pinned: bool| DecayInputs {
+            certainty: 90,
+            reinforced_at: Some(now - chrono::Duration::days(days)),
+            pinned,
+        };
+        let stats: HashMap<String, DecayInputs> = [
+            ("stale".to_string(), inputs(60, false)),
+            ("fresh".to_string(), inputs(0, false)),
+            ("pinned".to_string(), inputs(600, true)),
+        ]
+        .into();
+        let mut rows = vec![
+            row("stale", 0.9),
+            row("fresh", 0.6),
+            row("pinned", 0.5),
+            row("unknown", 0.4),
+        ];
+        apply_confidence_decay(&mut rows, &stats, 30.0, now);
+        let order: Vec<&str> = rows.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(order, ["fresh", "pinned", "unknown", "stale"]);
+        assert_eq!(rows[3].metadata["decay_factor"], json!(0.25));
+        assert_eq!(rows[3].metadata["decayed_certainty"], json!(22.5));
+        assert_eq!(rows[1].metadata["decayed_certainty"], json!(90.0));
+        assert!(!rows[2].metadata.contains_key("decay_factor"));
+    }
+
+    #[test]
+    fn time_sorts_order_by_created_at_and_park_unparsable_rows() {
+        let row = |id: &str, at: &str| SearchResult {
+            id: id.to_string(),
+            content: String::new(),
+            score: 0.0,
+            metadata: HashMap::new(),
+            created_at: at.to_string(),
+            hops: 0,
+        };
+        let mut rows = vec![
+            row("mid", "2026-03-01T00:00:00Z"),
+            row("bad", ""),
+            row("old", "2025-12-31T23:00:00+00:00"),
+            row("new", "2026-05-01T08:30:00+02:00"),
+        ];
+        let ids = |rows: &[SearchResult]| rows.iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+
+        sort_by_time(&mut rows, SearchSort::Newest);
+        assert_eq!(ids(&rows), vec!["new", "mid", "old", "bad"]);
+        sort_by_time(&mut rows, SearchSort::Oldest);
+        assert_eq!(ids(&rows), vec!["old", "mid", "new", "bad"]);
+    }
+
+    #[test]
+    fn memory_detail_shapes_the_record_and_scopes_to_holders() {
+        let d = memory_detail(record(), AccessStats::default(), "alice").unwrap();
+        assert_eq!(d.memory_id, "mem_a");
+        assert_eq!(d.context_tags, vec!["ops", "deploy"]);
+        assert_eq!(d.metadata, json!({ "origin": "chat" }));
+        assert_eq!(d.valid_until, None);
+        assert!(d.verified && !d.immutable);
+        assert_eq!(d.users, vec!["alice", "bob"]);
+        assert_eq!(d.relations.total, 3);
+        assert_eq!(d.relations.by_kind["IMPLIES"], 2);
+        assert_eq!(d.relations.related[1].memory_id, "mem_c");
+        assert_eq!(d.relations.related[1].direction, "in");
+
+        // A linked user sees it; anyone else gets not-found.
+        assert!(memory_detail(record(), AccessStats::default(), "bob").is_some());
+        assert!(memory_detail(record(), AccessStats::default(), "eve").is_none());
+    }
 }
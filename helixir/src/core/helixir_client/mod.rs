@@ -4,28 +4,47 @@
 //! - [`error`]   — [`HelixirClientError`] (one variant per failure boundary).
 //! - [`types`]   — public DTOs returned by client methods.
 //! - [`client`]  — [`HelixirClient`] struct, constructor, lifecycle, accessors.
-//! - [`memory`]  — `add` / `search` / `update` / `delete` methods.
+//! - [`memory`]  — `add` / `search` / `get_memory` / `update` / `delete` methods.
 //! - [`graph`]   — `get_graph`.
-//! - [`concepts`] — `search_by_concept` / `search_reasoning_chain`.
+//! - [`concepts`] — `search_by_concept` / `search_reasoning_chain` / `get_goals`.
+//! - [`entities`] — `get_entity` / `set_entity_attribute`.
+//! - [`import`]  — `import_messages` (checkpointed batch add).
+//! - [`webhook`] — `notify_webhook` (outbound post-commit announcements).
+//! - [`caches`]  — `cache_stats` / `clear_cache`.
+//! - [`user_config`] — `user_config` / `set_user_config` / `effective_config`.
 //!
-//! Every method on `HelixirClient` lives in one of the four feature modules
-//! (`memory`, `graph`, `concepts`) as `impl HelixirClient { ... }`; the public
+//! Every method on `HelixirClient` lives in one of the feature modules
+//! (`memory`, `graph`, `concepts`, `entities`, `import`) as `impl HelixirClient { ... }`; the public
 //! API surface is identical to the pre-split file.
 
+mod caches;
 mod client;
 mod concepts;
+mod entities;
 mod error;
 mod graph;
+mod import;
 mod memory;
 mod types;
+mod user_config;
+mod webhook;
 
+pub use caches::CacheKind;
 pub use client::HelixirClient;
 pub use error::HelixirClientError;
+pub use import::ImportOptions;
 pub use memory::SearchParams;
 pub use types::{
-    AddMemoryResult, ChainNode, GraphEdge, GraphNode, GraphResult, ReasoningChain,
-    ReasoningChainResult, SearchResult, UpdateResult,
+    AddEstimate, AddMemoryResult, AppendResult, CacheReport, ChainNode, ClusterResult,
+    ConclusionSource, CorrectionResult, EntityDetail, EvidenceLink, GoalProgress, GoalStatus,
+    GraphEdge, GraphNode, GraphResult, GraphWarning, ImportFailure, ImportReport, ListPage,
+    MemoryDetail, MemoryEvidence, ModeComparison, PurgeReport, ReasoningChain,
+    ReasoningChainResult, RebuildReport, ReindexReport, RelatedMemory, RelationSummary,
+    RememberAdvice, SearchResult, SearchWindowReport, SimilarHit, StaleMemory, SummaryResult,
+    UpdateResult,
 };
+pub(crate) use webhook::Webhook;
+pub use webhook::WebhookEvent;
 
 #[cfg(test)]
 mod tests {
@@ -61,4 +80,27 @@ mod tests {
         assert_eq!(client.config().host, "localhost");
         assert_eq!(client.config().port, 6969);
     }
+
+    #[tokio::test]
+    async fn test_purge_user_requires_matching_confirm_token() {
+        // Rejected before any DB access: the client is never initialized.
+        let client = HelixirClient::new(HelixirConfig::default()).unwrap();
+        let err = client.purge_user("alice", "bob").await.unwrap_err();
+        assert!(matches!(err, HelixirClientError::Config(_)));
+        let err = client.purge_user("  ", "  ").await.unwrap_err();
+        assert!(matches!(err, HelixirClientError::Config(_)));
+    }
+
+    #[test]
+    fn test_estimate_add_is_offline_and_grows_with_input() {
+        let client = HelixirClient::new(HelixirConfig::default()).unwrap();
+        let short = client.estimate_add("I prefer dark themes.");
+        let long = client.estimate_add(&"The deploy pipeline runs nightly. ".repeat(200));
+
+        assert!(short.input_tokens > 0 && short.expected_chunks == 0);
+        assert!(long.input_tokens > short.input_tokens);
+        assert!(long.expected_chunks > 1);
+        // The shipped default model is in the pricing table.
+        assert!(short.estimated_cost_usd.unwrap() < long.estimated_cost_usd.unwrap());
+    }
 }
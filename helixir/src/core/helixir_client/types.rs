@@ -26,6 +26,23 @@ pub struct AddMemoryResult {
     /// ask the human or apply a learned rule.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub needs_clarification: Vec<crate::toolkit::tooling_manager::types::Clarification>,
+    /// The LLM found no atomic facts in the message. Paired with
+    /// `memories_added: 1` the message was stored verbatim as a fallback;
+    /// with 0, nothing was stored (`HELIXIR_ON_EMPTY_EXTRACTION=skip`).
+    #[serde(default)]
+    pub no_facts_extracted: bool,
+    /// Every fact was already stored (`HELIXIR_DEDUP_ON_ADD=skip`); the
+    /// existing ids are in `deduped`.
+    #[serde(default)]
+    pub duplicate: bool,
+    /// Near-duplicates the write-time check found, skipped or warned about.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<crate::toolkit::tooling_manager::types::DuplicateHit>,
+    /// Memories stored without an embedding (`HELIXIR_ON_EMBED_FAIL=defer`
+    /// during an embedding outage). Vector search misses them until
+    /// `reindex_embeddings` runs; tag, graph and keyword lookups still work.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub needs_embedding: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +52,121 @@ pub struct SearchResult {
     pub score: f32,
     pub metadata: HashMap<String, serde_json::Value>,
     pub created_at: String,
+    /// Relation hops from the direct match that led here; 0 for a direct
+    /// semantic match.
+    #[serde(default)]
+    pub hops: usize,
+}
+
+/// Outcome of [`super::HelixirClient::append`]: the original content is
+/// kept and `additional_content` follows it on a new line.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppendResult {
+    pub memory_id: String,
+    pub content: String,
+    pub new_facts_added: usize,
+    pub entities_linked: usize,
+    pub relations_added: usize,
+}
+
+/// Outcome of [`super::HelixirClient::correct_memory`]: the new memory
+/// and the one it now supersedes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrectionResult {
+    pub memory_id: String,
+    pub corrected_id: String,
+    pub previous_content: String,
+    pub content: String,
+}
+
+/// Outcome of [`super::HelixirClient::summarize_recent`]. `summary_id` is
+/// `None` when the window held too few memories to summarize.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryResult {
+    pub summary_id: Option<String>,
+    pub content: String,
+    pub source_ids: Vec<String>,
+    pub window_hours: u64,
+    pub sources_linked: usize,
+    pub archived: usize,
+}
+
+/// A memory [`super::HelixirClient::find_stale`] suggests reviewing, with
+/// why: time-bound wording past the age floor, newer contradictions, or
+/// both.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaleMemory {
+    pub memory_id: String,
+    pub content: String,
+    pub created_at: String,
+    pub age_days: u64,
+    pub reasons: Vec<String>,
+    pub markers: Vec<String>,
+    pub contradicted_by: Vec<String>,
+}
+
+/// One page of [`super::HelixirClient::list_memories`], newest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListPage {
+    /// Stored memory nodes, as `getUserMemoriesNewest` returns them.
+    pub memories: Vec<serde_json::Value>,
+    /// Pass back as `offset` for the next page; `None` on the last one.
+    pub next_cursor: Option<usize>,
+    /// Memories the user holds before filters — an upper bound on the
+    /// matches across all pages.
+    pub total: usize,
+    /// Cursor of each memory: passing `positions[i]` as `offset` lists
+    /// `memories[i]` first.
+    #[serde(default)]
+    pub positions: Vec<usize>,
+}
+
+impl ListPage {
+    /// Keep the first `keep` memories; `next_cursor` then resumes at the
+    /// first one dropped.
+    pub fn truncate(&mut self, keep: usize) {
+        if keep < self.memories.len() {
+            self.next_cursor = self.positions.get(keep).copied().or(self.next_cursor);
+            self.memories.truncate(keep);
+            self.positions.truncate(keep);
+        }
+    }
+}
+
+/// A memory a committed conclusion rests on, from
+/// [`super::HelixirClient::get_conclusion_sources`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConclusionSource {
+    pub memory_id: String,
+    pub content: String,
+    /// SUPPORTS edge strength, 0-100.
+    pub strength: i64,
+    /// Linked by `think_commit` from the session's recalled evidence, as
+    /// opposed to a SUPPORTS edge drawn at write time.
+    pub from_commit: bool,
+}
+
+/// What [`super::HelixirClient::why_memory`] found: the memory asked
+/// about and its evidence chain, nearest links first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryEvidence {
+    pub memory: MemoryDetail,
+    pub evidence: Vec<EvidenceLink>,
+}
+
+/// One link of the evidence chain from
+/// [`super::HelixirClient::why_memory`]: `memory_id` SUPPORTS `supports`,
+/// `depth` hops below the memory asked about.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvidenceLink {
+    pub memory_id: String,
+    pub content: String,
+    pub supports: String,
+    pub depth: usize,
+    /// SUPPORTS edge strength, 0-100.
+    pub strength: i64,
+    /// Linked by `think_commit` from the session's recalled evidence.
+    pub from_commit: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,10 +176,259 @@ pub struct UpdateResult {
     pub new_content: String,
 }
 
+/// What [`super::HelixirClient::estimate_add`] expects one `add` to cost,
+/// computed offline (no LLM call). Covers the extraction call only — dedup
+/// decisions on near-duplicates add a few small calls on top.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddEstimate {
+    pub message_chars: usize,
+    /// Extraction system prompt + the message.
+    pub input_tokens: usize,
+    pub expected_output_tokens: usize,
+    /// 0 when the message is below the chunking threshold.
+    pub expected_chunks: usize,
+    pub provider: String,
+    pub model: String,
+    /// `None` when the configured model is not in the pricing table.
+    pub estimated_cost_usd: Option<f64>,
+}
+
+/// [`super::HelixirClient::should_remember`] advice: the prompt's importance
+/// filter applied server-side, plus the closest existing memory when a user
+/// was given.
+#[derive(Debug, Clone, Serialize)]
+pub struct RememberAdvice {
+    pub recommend: bool,
+    pub importance: crate::toolkit::mind_toolbox::memory::importance::Importance,
+    pub reason: String,
+    pub signals: Vec<&'static str>,
+    /// Closest stored memory at or above the dedup similarity threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similar: Option<SimilarHit>,
+    /// Whether the existing-memory check ran (a user was given and the
+    /// message was not rejected on text alone).
+    pub similarity_checked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similarity_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarHit {
+    pub memory_id: String,
+    pub content: String,
+    pub similarity: f64,
+    /// At or above `exact_duplicate_score`: an add would be deduped.
+    pub duplicate: bool,
+}
+
+/// One search mode's answer in
+/// [`super::HelixirClient::compare_search_modes`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ModeComparison {
+    pub mode: &'static str,
+    /// What the mode covers, e.g. "Balanced search (30 days) + moderate graph".
+    pub description: &'static str,
+    pub result_count: usize,
+    /// `result_count` reached the per-mode limit: more rows may exist.
+    pub capped: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<SearchResult>,
+    /// The mode's search failed; the other modes are still reported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The window a search resolved to and how much of the user's memory it
+/// covers, from [`super::HelixirClient::search_window`]. `total_in_window`
+/// well below `total_overall` means a wider mode or window would find more;
+/// both at zero means nothing is stored.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchWindowReport {
+    /// Inclusive event-time bounds; `None` is open on that side.
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub source: crate::core::time_window::WindowSource,
+    /// `None` unless the counts were asked for.
+    pub total_in_window: Option<usize>,
+    pub total_overall: Option<usize>,
+}
+
+/// One memory as [`super::HelixirClient::get_memory`] reads it back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryDetail {
+    pub memory_id: String,
+    pub content: String,
+    /// Ontology type the memory was stored as (`fact`, `preference`, ...).
+    pub memory_type: String,
+    pub certainty: i64,
+    pub importance: i64,
+    pub context_tags: Vec<String>,
+    pub source: String,
+    /// The stored metadata object; a string that is not JSON comes back
+    /// as-is.
+    pub metadata: serde_json::Value,
+    pub created_at: String,
+    pub updated_at: String,
+    pub valid_from: String,
+    pub valid_until: Option<String>,
+    pub immutable: bool,
+    pub verified: bool,
+    pub is_deleted: bool,
+    pub access_count: u64,
+    pub last_accessed_at: Option<String>,
+    /// Users holding it; more than one for a shared content-keyed memory.
+    pub users: Vec<String>,
+    pub concepts: Vec<String>,
+    pub entities: Vec<String>,
+    pub relations: RelationSummary,
+}
+
+/// One entity as [`super::HelixirClient::get_entity`] reads it back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityDetail {
+    pub entity_id: String,
+    pub name: String,
+    pub entity_type: String,
+    /// User-set attributes (`set_entity_attribute`), e.g. `{"role": "CTO"}`.
+    pub attributes: HashMap<String, serde_json::Value>,
+    pub aliases: Vec<String>,
+    /// The caller's memories that extract or mention it.
+    pub memory_ids: Vec<String>,
+}
+
+/// Memory-to-memory edges around one memory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelationSummary {
+    pub total: usize,
+    /// Edge count per kind (`IMPLIES`, `BECAUSE`, `CONTRADICTS`,
+    /// `MEMORY_RELATION`).
+    pub by_kind: std::collections::BTreeMap<String, usize>,
+    pub related: Vec<RelatedMemory>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelatedMemory {
+    pub memory_id: String,
+    pub kind: String,
+    /// `out` when this memory is the edge's source, `in` otherwise.
+    pub direction: String,
+}
+
+/// One cache's counters in [`super::HelixirClient::cache_stats`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheReport {
+    pub cache: String,
+    pub hits: u64,
+    pub misses: u64,
+    pub size: usize,
+    pub hit_rate: f64,
+}
+
+/// Counts of everything [`super::HelixirClient::purge_user`] removed.
+/// `sessions_discarded` is filled in by the caller that owns the FastThink
+/// sessions (the MCP server); the client itself holds none.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PurgeReport {
+    pub user_id: String,
+    pub memories: usize,
+    pub memories_unlinked: usize,
+    pub entities: usize,
+    pub relations: usize,
+    pub embeddings: usize,
+    pub chunks: usize,
+    pub user_node_removed: bool,
+    pub sessions_discarded: usize,
+}
+
+/// Outcome of one [`super::HelixirClient::rebuild_relations`] batch.
+/// `next_cursor` is `None` once the whole scope has been re-judged; pass it
+/// back to continue otherwise.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RebuildReport {
+    pub user_id: Option<String>,
+    pub dry_run: bool,
+    pub prune: bool,
+    pub memories_scanned: usize,
+    pub relations_added: usize,
+    pub relations_stale: usize,
+    pub relations_removed: usize,
+    pub relations_unchanged: usize,
+    pub failures: usize,
+    pub next_cursor: Option<String>,
+}
+
+/// A topic map from [`super::HelixirClient::cluster_memories`]: clusters
+/// largest first, each led by its most central memory. `silhouette` (-1..1,
+/// higher is cleaner) is `None` for a single cluster.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClusterResult {
+    pub user_id: String,
+    pub k: usize,
+    pub auto_k: bool,
+    pub silhouette: Option<f64>,
+    pub memories_clustered: usize,
+    pub clusters: Vec<crate::toolkit::tooling_manager::types::MemoryCluster>,
+}
+
+/// Outcome of one [`super::HelixirClient::reindex_embeddings`] call.
+/// `next_cursor` is set when the limit stopped the pass early; pass it back
+/// to continue.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReindexReport {
+    pub user_id: Option<String>,
+    pub memories_scanned: usize,
+    pub reindexed: usize,
+    pub memory_ids: Vec<String>,
+    pub failures: usize,
+    pub next_cursor: Option<String>,
+}
+
+/// Outcome of one [`super::HelixirClient::import_messages`] call.
+/// `resume_token` is `None` once every message is in; pass it back (with
+/// the same messages) to continue otherwise.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub import_id: String,
+    pub total: usize,
+    /// Index this call started at (> 0 when it resumed).
+    pub start_index: usize,
+    /// First index not yet imported; `total` when done.
+    pub next_index: usize,
+    /// Messages handled by this call, blank ones included.
+    pub processed: usize,
+    pub skipped_blank: usize,
+    pub memories_added: usize,
+    pub memory_ids: Vec<String>,
+    /// Existing memories messages deduped to.
+    pub deduped: usize,
+    /// Retries spent on messages that then succeeded or failed for good.
+    pub retries: u32,
+    /// The message the batch stopped at after exhausting its retries.
+    pub failed: Option<ImportFailure>,
+    pub resume_token: Option<String>,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportFailure {
+    pub index: usize,
+    pub error: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GraphResult {
     pub nodes: Vec<GraphNode>,
     pub edges: Vec<GraphEdge>,
+    /// Nodes left out because they failed to load, with the reason; empty
+    /// for a clean traversal.
+    #[serde(default)]
+    pub warnings: Vec<GraphWarning>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphWarning {
+    pub node_id: String,
+    pub reason: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,6 +437,9 @@ pub struct ReasoningChainResult {
     pub chains: Vec<ReasoningChain>,
     pub total_memories: usize,
     pub deepest_chain: usize,
+    /// At least one chain hit the per-chain node cap; its nodes are a prefix
+    /// of the full walk.
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,6 +448,7 @@ pub struct ReasoningChain {
     pub nodes: Vec<ChainNode>,
     pub chain_type: String,
     pub reasoning_trail: String,
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +459,27 @@ pub struct ChainNode {
     pub depth: usize,
 }
 
+/// What the linked memories say about a goal (`get_goals`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalProgress {
+    /// Nothing is linked to the goal yet.
+    Open,
+    /// Linked memories exist but none reads as an outcome.
+    InProgress,
+    Completed,
+    Failed,
+}
+
+/// One goal-typed memory with the reasoning-chain neighbours that speak
+/// to its progress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalStatus {
+    pub goal: SearchResult,
+    pub related_outcomes: Vec<ChainNode>,
+    pub inferred_status: GoalProgress,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GraphNode {
     pub id: String,
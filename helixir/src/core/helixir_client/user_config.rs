@@ -0,0 +1,83 @@
+//! Per-user config overrides on [`HelixirClient`]: read, patch, and the
+//! effective config a user's requests run with.
+
+use std::sync::Arc;
+
+use tracing::warn;
+
+use super::client::HelixirClient;
+use super::error::HelixirClientError;
+use crate::core::config::{HelixirConfig, UserConfigOverrides};
+
+impl HelixirClient {
+    /// `user_id`'s stored overrides; empty when none are set.
+    pub async fn user_config(
+        &self,
+        user_id: &str,
+    ) -> Result<UserConfigOverrides, HelixirClientError> {
+        if user_id.trim().is_empty() {
+            return Err(HelixirClientError::Config(
+                "user_config: user_id must be non-empty".to_string(),
+            ));
+        }
+        self.ensure_initialized().await?;
+        self.tooling_manager
+            .get_user_config(user_id)
+            .await
+            .map_err(HelixirClientError::from_tooling)
+    }
+
+    /// Lay `patch` over `user_id`'s stored overrides (over none with
+    /// `reset`) and store the result, which is returned. Values are checked
+    /// before anything is written.
+    pub async fn set_user_config(
+        &self,
+        user_id: &str,
+        patch: UserConfigOverrides,
+        reset: bool,
+    ) -> Result<UserConfigOverrides, HelixirClientError> {
+        patch
+            .validate()
+            .map_err(|e| HelixirClientError::Config(format!("set_user_config: {e}")))?;
+        let mut overrides = if reset {
+            UserConfigOverrides::default()
+        } else {
+            self.user_config(user_id).await?
+        };
+        overrides.merge(patch);
+        self.tooling_manager
+            .save_user_config(user_id, &overrides)
+            .await
+            .map_err(HelixirClientError::from_tooling)?;
+        self.user_configs.lock().put(
+            user_id.to_string(),
+            Arc::new(self.config.for_user(&overrides)),
+        );
+        Ok(overrides)
+    }
+
+    /// The global config with `user_id`'s overrides applied. It is resolved
+    /// once per process and kept until `set_user_config` replaces it or the
+    /// user drops out of the bounded cache; resolve this once per request
+    /// and hand it down. Best-effort: overrides that cannot be read are
+    /// logged and the global config is used (and not cached), so a user's
+    /// request never fails on its settings lookup.
+    pub async fn effective_config(&self, user_id: &str) -> Arc<HelixirConfig> {
+        if let Some(config) = self.user_configs.lock().get(user_id) {
+            return Arc::clone(config);
+        }
+        match self.user_config(user_id).await {
+            Ok(overrides) => {
+                let config = Arc::new(self.config.for_user(&overrides));
+                self.user_configs
+                    .lock()
+                    .put(user_id.to_string(), Arc::clone(&config));
+                config
+            }
+            Err(e) => {
+                warn!("user config of {user_id} unavailable, using the global config: {e}");
+                Arc::new(self.config.clone())
+            }
+        }
+    }
+}
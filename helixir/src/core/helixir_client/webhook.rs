@@ -0,0 +1,186 @@
+//! Outbound post-commit webhook: when `webhook.url` (`HELIXIR_WEBHOOK_URL`)
+//! is set, every memory the client's add paths or the ingest worker store,
+//! every one a FastThink commit writes — and every one `uncommit` deletes —
+//! is announced with a POST of `{event, memory_id, user_id, timestamp}`, so
+//! downstream indexing or notifications need not poll. The announcements
+//! come from where the write happens, so library callers get them too.
+//!
+//! The endpoint lives on the [`ToolingManager`], which the ingest worker
+//! shares. Delivery runs on a spawned task and never touches the caller's
+//! result: a failed POST is retried with doubling backoff, then logged and
+//! dropped.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use super::client::HelixirClient;
+use crate::core::config::WebhookConfig;
+use crate::toolkit::tooling_manager::ToolingManager;
+
+/// What happened to the memory being announced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// Stored by an add (sync, buffered or imported), `summarize_recent`,
+    /// `correct_memory` or a promoted FastThink note.
+    Add,
+    /// Written by a FastThink commit (including a partial or timeout save).
+    Commit,
+    /// Deleted by `think_uncommit` taking a retained commit back.
+    Delete,
+}
+
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    event: WebhookEvent,
+    memory_id: &'a str,
+    user_id: &'a str,
+    timestamp: String,
+}
+
+/// Configured endpoint plus the HTTP client that delivers to it.
+pub(crate) struct Webhook {
+    url: String,
+    http: reqwest::Client,
+    max_retries: u32,
+    backoff_ms: u64,
+}
+
+impl Webhook {
+    /// `None` when no URL is configured.
+    pub(crate) fn from_config(config: &WebhookConfig) -> Option<Arc<Self>> {
+        let url = config
+            .url
+            .as_deref()
+            .map(str::trim)
+            .filter(|u| !u.is_empty())?;
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs.max(1)))
+            .build()
+            .unwrap_or_default();
+        Some(Arc::new(Self {
+            url: url.to_string(),
+            http,
+            max_retries: config.max_retries,
+            backoff_ms: config.retry_backoff_ms,
+        }))
+    }
+
+    async fn deliver(&self, body: &WebhookPayload<'_>) {
+        let mut attempt = 0;
+        loop {
+            let outcome = self
+                .http
+                .post(&self.url)
+                .json(body)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status);
+            match outcome {
+                Ok(_) => {
+                    debug!("webhook: {:?} {} delivered", body.event, body.memory_id);
+                    return;
+                }
+                Err(e) if attempt >= self.max_retries => {
+                    warn!(
+                        "webhook: giving up on {:?} {} after {} attempts: {e}",
+                        body.event,
+                        body.memory_id,
+                        attempt + 1
+                    );
+                    return;
+                }
+                Err(e) => {
+                    let backoff = self.backoff_ms << attempt.min(6);
+                    debug!(
+                        "webhook: {} failed ({e}), retry in {backoff}ms",
+                        body.memory_id
+                    );
+                    attempt += 1;
+                    tokio::time::sleep(Duration::from_millis(backoff)).await;
+                }
+            }
+        }
+    }
+}
+
+impl ToolingManager {
+    /// Announce `memory_ids` to the configured webhook. Returns at once;
+    /// a no-op without a URL or ids.
+    pub(crate) fn notify_webhook(&self, event: WebhookEvent, memory_ids: &[String], user_id: &str) {
+        let Some(webhook) = self.webhook.clone() else {
+            return;
+        };
+        let ids: Vec<String> = memory_ids
+            .iter()
+            .filter(|id| !id.is_empty())
+            .cloned()
+            .collect();
+        if ids.is_empty() {
+            return;
+        }
+        let user_id = user_id.to_string();
+        tokio::spawn(async move {
+            for memory_id in &ids {
+                let body = WebhookPayload {
+                    event,
+                    memory_id,
+                    user_id: &user_id,
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                };
+                webhook.deliver(&body).await;
+            }
+        });
+    }
+}
+
+impl HelixirClient {
+    /// Announce `memory_ids` to the configured webhook. Returns at once;
+    /// a no-op without a URL or ids.
+    pub fn notify_webhook(&self, event: WebhookEvent, memory_ids: &[String], user_id: &str) {
+        self.tooling_manager
+            .notify_webhook(event, memory_ids, user_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_shape_and_disabled_without_url() {
+        let body = WebhookPayload {
+            event: WebhookEvent::Commit,
+            memory_id: "mem_1",
+            user_id: "alice",
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            serde_json::json!({
+                "event": "commit",
+                "memory_id": "mem_1",
+                "user_id": "alice",
+                "timestamp": "2026-01-01T00:00:00+00:00",
+            })
+        );
+
+        assert!(Webhook::from_config(&WebhookConfig::default()).is_none());
+        let blank = WebhookConfig {
+            url: Some("  ".to_string()),
+            ..WebhookConfig::default()
+        };
+        assert!(Webhook::from_config(&blank).is_none());
+        let set = WebhookConfig {
+            url: Some("http://127.0.0.1:9/hook".to_string()),
+            ..WebhookConfig::default()
+        };
+        assert_eq!(
+            Webhook::from_config(&set).unwrap().url,
+            "http://127.0.0.1:9/hook"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Output localization (`HELIXIR_LANG`): the cognitive protocol, the server
+//! instructions and the concept keywords in the agent's language.
+//!
+//! English is bundled; other languages are loaded from
+//! `<locale_dir>/<lang>/` (`HELIXIR_LOCALE_DIR`, default
+//! `~/.helixir/locales`):
+//!
+//! - `cognitive_protocol.md` — replaces the `tool_selection_guide` prompt,
+//!   tool keyword triggers included;
+//! - `server_instructions.md` — replaces the MCP `instructions`;
+//! - `concept_keywords.toml` — `Preference = ["нравится", "люблю"]`, one
+//!   list per concept type, added to the English keywords (memories are
+//!   often stored in English whatever the conversation language).
+//!
+//! A region tag falls back to its language (`pt_BR.UTF-8` tries `pt-br`,
+//! then `pt`) and any file the translation lacks falls back to English with
+//! a warning, so a partial translation still serves.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use tracing::{info, warn};
+
+use crate::core::config::PromptConfig;
+
+pub const DEFAULT_LANG: &str = "en";
+
+#[derive(Debug, Clone, Default)]
+pub struct Locale {
+    /// Requested language tag, normalized; `en` when unset.
+    lang: String,
+    cognitive_protocol: Option<String>,
+    server_instructions: Option<String>,
+    /// Extra keywords per lowercased concept type name.
+    concept_keywords: HashMap<String, Vec<String>>,
+}
+
+/// `pt_BR.UTF-8` → `pt-br`.
+fn normalize(lang: &str) -> String {
+    let tag = lang.trim().split('.').next().unwrap_or_default();
+    tag.replace('_', "-").to_lowercase()
+}
+
+/// The directories to look in, most specific first.
+fn candidates(root: &Path, lang: &str) -> Vec<PathBuf> {
+    let mut dirs = vec![root.join(lang)];
+    if let Some((primary, _)) = lang.split_once('-') {
+        dirs.push(root.join(primary));
+    }
+    dirs
+}
+
+fn read_first(dirs: &[PathBuf], file: &str) -> Option<String> {
+    dirs.iter().find_map(|dir| {
+        let path = dir.join(file);
+        let text = std::fs::read_to_string(&path).ok()?;
+        (!text.trim().is_empty()).then_some(text)
+    })
+}
+
+fn default_locale_dir() -> PathBuf {
+    std::env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".helixir/locales")
+}
+
+impl Locale {
+    pub fn english() -> Self {
+        Self {
+            lang: DEFAULT_LANG.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Resolve `prompts.lang` against `prompts.locale_dir`. Never fails:
+    /// whatever cannot be read stays English.
+    pub fn load(prompts: &PromptConfig) -> Self {
+        let lang = prompts.lang.as_deref().map(normalize).unwrap_or_default();
+        if lang.is_empty() || lang == DEFAULT_LANG || lang.starts_with("en-") {
+            return Self::english();
+        }
+        let root = prompts
+            .locale_dir
+            .as_deref()
+            .map(PathBuf::from)
+            .unwrap_or_else(default_locale_dir);
+        Self::from_dir(&lang, &root)
+    }
+
+    fn from_dir(lang: &str, root: &Path) -> Self {
+        let dirs = candidates(root, lang);
+        let cognitive_protocol = read_first(&dirs, "cognitive_protocol.md");
+        let server_instructions = read_first(&dirs, "server_instructions.md");
+        let concept_keywords = match read_first(&dirs, "concept_keywords.toml") {
+            Some(raw) => match toml::from_str::<HashMap<String, Vec<String>>>(&raw) {
+                Ok(table) => table
+                    .into_iter()
+                    .map(|(concept, words)| {
+                        let words = words
+                            .into_iter()
+                            .map(|w| w.trim().to_lowercase())
+                            .filter(|w| !w.is_empty())
+                            .collect();
+                        (concept.trim().to_lowercase(), words)
+                    })
+                    .collect(),
+                Err(e) => {
+                    warn!("Locale '{lang}': concept_keywords.toml does not parse: {e}");
+                    HashMap::new()
+                }
+            },
+            None => HashMap::new(),
+        };
+
+        let missing: Vec<&str> = [
+            ("cognitive_protocol.md", cognitive_protocol.is_none()),
+            ("server_instructions.md", server_instructions.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(file, missing)| missing.then_some(file))
+        .collect();
+        if !missing.is_empty() {
+            warn!(
+                "Locale '{lang}': no {} under {}; using English",
+                missing.join(" or "),
+                root.display()
+            );
+        }
+        info!(
+            "Locale '{lang}' loaded ({} concept keyword list(s))",
+            concept_keywords.len()
+        );
+        Self {
+            lang: lang.to_string(),
+            cognitive_protocol,
+            server_instructions,
+            concept_keywords,
+        }
+    }
+
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// Translated protocol, `None` when English applies.
+    pub fn cognitive_protocol(&self) -> Option<&str> {
+        self.cognitive_protocol.as_deref()
+    }
+
+    /// Translated instructions, `None` when English applies.
+    pub fn server_instructions(&self) -> Option<&str> {
+        self.server_instructions.as_deref()
+    }
+
+    pub fn concept_keywords(&self) -> &HashMap<String, Vec<String>> {
+        &self.concept_keywords
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_region_falls_back_to_its_language_and_missing_files_to_english() {
+        let root = std::env::temp_dir().join(format!("helixir-locales-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("ru")).unwrap();
+        std::fs::write(root.join("ru/cognitive_protocol.md"), "ПРОТОКОЛ ПАМЯТИ").unwrap();
+        std::fs::write(
+            root.join("ru/concept_keywords.toml"),
+            "Preference = [\"Нравится\", \" люблю \"]\n",
+        )
+        .unwrap();
+
+        let prompts = PromptConfig {
+            lang: Some("ru_RU.UTF-8".to_string()),
+            locale_dir: Some(root.display().to_string()),
+            ..PromptConfig::default()
+        };
+        let locale = Locale::load(&prompts);
+        assert_eq!(locale.lang(), "ru-ru");
+        assert_eq!(locale.cognitive_protocol(), Some("ПРОТОКОЛ ПАМЯТИ"));
+        assert_eq!(locale.server_instructions(), None);
+        assert_eq!(
+            locale.concept_keywords()["preference"],
+            ["нравится", "люблю"]
+        );
+
+        let english = Locale::load(&PromptConfig {
+            lang: Some("en_US".to_string()),
+            ..prompts
+        });
+        assert_eq!(english.lang(), "en");
+        assert!(english.cognitive_protocol().is_none());
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
@@ -5,6 +5,7 @@ pub mod error;
 pub mod events;
 pub mod helixir_client;
 pub mod levels;
+pub mod locale;
 pub mod retrieval_profile;
 pub mod search_modes;
 pub mod time_window;
@@ -12,8 +13,9 @@ pub mod time_window;
 pub use config::HelixirConfig;
 pub use error::{HelixirError, Result};
 pub use helixir_client::HelixirClient;
+pub use locale::Locale;
 pub use retrieval_profile::RetrievalProfile;
-pub use search_modes::{SearchMode, SearchModeDefaults, estimate_token_cost};
+pub use search_modes::{SearchMode, SearchModeDefaults, SearchSort, estimate_token_cost};
 pub use time_window::TimeWindow;
 
 pub use levels::{
@@ -1,5 +1,32 @@
 use serde::{Deserialize, Serialize};
 
+/// Order of the rows a client search returns. `Relevance` keeps the
+/// retrieval ranking; the access orders re-sort the same rows by usage and
+/// `Newest` / `Oldest` by `created_at` (stable, so relevance breaks ties).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum SearchSort {
+    #[default]
+    Relevance,
+    RecentlyAccessed,
+    FrequentlyUsed,
+    Newest,
+    Oldest,
+}
+
+impl SearchSort {
+    /// Unknown values read as `Relevance`.
+    #[must_use]
+    pub fn parse(s: &str) -> Self {
+        match s.trim().to_lowercase().as_str() {
+            "recently_accessed" => Self::RecentlyAccessed,
+            "frequently_used" => Self::FrequentlyUsed,
+            "newest" => Self::Newest,
+            "oldest" => Self::Oldest,
+            _ => Self::Relevance,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum SearchMode {
     #[default]
@@ -59,6 +59,34 @@ impl TimeWindow {
     }
 }
 
+/// Where the window a search filters by came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowSource {
+    /// `time_from` / `time_to`.
+    Explicit,
+    /// `temporal_days`, the caller's or the mode's configured default.
+    TemporalDays,
+    /// Nothing bounds the search; mode windows only set freshness decay.
+    None,
+}
+
+/// The window a search filters seeds by: an active `window` wins, else
+/// `temporal_days` as a one-sided "last N days", else no window at all.
+pub fn effective_window(
+    window: TimeWindow,
+    temporal_days: Option<f64>,
+    now: DateTime<Utc>,
+) -> (TimeWindow, WindowSource) {
+    if window.is_active() {
+        return (window, WindowSource::Explicit);
+    }
+    match temporal_days {
+        Some(days) => (TimeWindow::last_days(days, now), WindowSource::TemporalDays),
+        None => (TimeWindow::default(), WindowSource::None),
+    }
+}
+
 /// Parse a user-supplied window bound: full RFC3339 (`2026-06-20T14:00:00Z`)
 /// or a bare date (`2026-06-20`). A bare date expands to the START of the
 /// day for `from` and the END of the day for `to`, so `time_from="2026-06-01",
@@ -129,6 +157,26 @@ mod tests {
         assert_eq!(w.to, None);
     }
 
+    #[test]
+    fn an_explicit_window_wins_over_temporal_days() {
+        let now = t("2026-07-05T00:00:00Z");
+        let june = TimeWindow {
+            from: Some(t("2026-06-01T00:00:00Z")),
+            to: None,
+        };
+        assert_eq!(
+            effective_window(june, Some(2.0), now),
+            (june, WindowSource::Explicit)
+        );
+        let (w, source) = effective_window(TimeWindow::default(), Some(2.0), now);
+        assert_eq!(source, WindowSource::TemporalDays);
+        assert_eq!(w.from, Some(t("2026-07-03T00:00:00Z")));
+        assert_eq!(
+            effective_window(TimeWindow::default(), None, now),
+            (TimeWindow::default(), WindowSource::None)
+        );
+    }
+
     #[test]
     fn malformed_timestamp_never_hides_a_memory() {
         let w = TimeWindow::last_days(1.0, Utc::now());
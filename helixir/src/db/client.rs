@@ -1,11 +1,14 @@
-use helix_rs::{HelixDB, HelixDBClient, HelixError};
+use helix_rs::{HelixDBClient, HelixError};
 use serde::{Serialize, de::DeserializeOwned};
+use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, info};
 
-use crate::core::config::RetryConfig;
+use super::pool::{ConnectionPool, PoolHealth, PooledConnection};
+use super::store::MemoryStore;
+use crate::core::config::{DbPoolConfig, RetryConfig};
 
 #[derive(Debug, Error)]
 pub enum HelixClientError {
@@ -21,16 +24,29 @@ pub enum HelixClientError {
     NotConnected,
     #[error("Retry exhausted after {0} attempts: {1}")]
     RetryExhausted(u32, String),
+    #[error("Connection pool exhausted: all {0} HelixDB connections busy for {1} ms")]
+    PoolExhausted(usize, u64),
+}
+
+fn is_decode(e: &HelixError) -> bool {
+    matches!(e, HelixError::ReqwestError(r) if r.is_decode())
 }
 
 pub struct HelixClient {
-    inner: HelixDB,
+    pool: ConnectionPool,
+
+    endpoint: String,
+
+    port: u16,
 
     is_connected: AtomicBool,
 
     base_url: String,
 
     retry: RetryConfig,
+
+    /// Replaces `inner` for every query when set (see [`Self::with_store`]).
+    store: Option<Arc<dyn MemoryStore>>,
 }
 
 impl HelixClient {
@@ -38,18 +54,94 @@ impl HelixClient {
         let endpoint = format!("http://{}", host);
         let base_url = format!("http://{}:{}", host, port);
 
-        let inner = <HelixDB as HelixDBClient>::new(Some(&endpoint), Some(port), None);
+        let pool_config = DbPoolConfig::default();
+        let pool = ConnectionPool::new(
+            &endpoint,
+            port,
+            pool_config.size,
+            Duration::from_millis(pool_config.acquire_timeout_ms),
+        );
 
         info!("HelixClient created for {}", base_url);
 
         Ok(Self {
-            inner,
+            pool,
+            endpoint,
+            port,
             is_connected: AtomicBool::new(false),
             base_url,
             retry: RetryConfig::default(),
+            store: None,
         })
     }
 
+    /// A client whose queries all go to `store` instead of a HelixDB server,
+    /// e.g. an [`super::InMemoryStore`] in tests. Retries are off: a store
+    /// in process has no transient failures to wait out.
+    pub fn with_store(store: Arc<dyn MemoryStore>) -> Self {
+        Self {
+            pool: ConnectionPool::new("http://localhost", 0, 1, Duration::from_secs(1)),
+            endpoint: "http://localhost".to_string(),
+            port: 0,
+            is_connected: AtomicBool::new(false),
+            base_url: "memory://".to_string(),
+            retry: RetryConfig {
+                max: 1,
+                ..RetryConfig::default()
+            },
+            store: Some(store),
+        }
+    }
+
+    /// A pooled connection, or `None` when queries go to the store.
+    async fn checkout(&self) -> Result<Option<PooledConnection<'_>>, HelixClientError> {
+        if self.store.is_some() {
+            return Ok(None);
+        }
+        self.pool.checkout().await.map(Some)
+    }
+
+    /// Run one query on the store or the checked-out HelixDB connection;
+    /// errors come back as their message text. A transport failure rebuilds
+    /// the connection before the error is returned.
+    async fn dispatch<T, P>(
+        &self,
+        conn: Option<&PooledConnection<'_>>,
+        query_name: &str,
+        params: &P,
+    ) -> Result<T, String>
+    where
+        T: DeserializeOwned,
+        P: Serialize + Sync,
+    {
+        let Some(store) = &self.store else {
+            let conn = conn.ok_or_else(|| HelixClientError::NotConnected.to_string())?;
+            return match HelixDBClient::query::<P, T>(&conn.client(), query_name, params).await {
+                Ok(result) => {
+                    conn.succeeded();
+                    Ok(result)
+                }
+                // A body that does not decode came over a working connection.
+                Err(e @ HelixError::ReqwestError(_)) if !is_decode(&e) => {
+                    let message = e.to_string();
+                    conn.reconnect(&message);
+                    Err(message)
+                }
+                Err(e) => {
+                    // The server answered: the connection itself is fine.
+                    conn.succeeded();
+                    Err(e.to_string())
+                }
+            };
+        };
+        let params = serde_json::to_value(params).map_err(|e| e.to_string())?;
+        let value = store.query(query_name, params).await.map_err(|e| match e {
+            HelixClientError::Query(msg) => msg,
+            other => other.to_string(),
+        })?;
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    }
+
     /// Override the query retry policy (defaults to [`RetryConfig::default`]).
     #[must_use]
     pub fn with_retry(mut self, retry: RetryConfig) -> Self {
@@ -57,6 +149,18 @@ impl HelixClient {
         self
     }
 
+    /// Resize the connection pool (defaults to [`DbPoolConfig::default`]).
+    #[must_use]
+    pub fn with_pool(mut self, pool: &DbPoolConfig) -> Self {
+        self.pool = ConnectionPool::new(
+            &self.endpoint,
+            self.port,
+            pool.size,
+            Duration::from_millis(pool.acquire_timeout_ms),
+        );
+        self
+    }
+
     pub fn from_env() -> Result<Self, HelixClientError> {
         let host = std::env::var("HELIX_HOST").unwrap_or_else(|_| "localhost".to_string());
         let port: u16 = std::env::var("HELIX_PORT")
@@ -93,7 +197,20 @@ impl HelixClient {
         for attempt in 1..=max_retries {
             debug!("Executing query: {} (attempt {})", query_name, attempt);
 
-            match self.inner.query::<P, T>(query_name, params).await {
+            // A fresh checkout per attempt: a retry after a transport
+            // failure lands on a healthy (or rebuilt) connection, and no
+            // slot is held through the backoff sleep. A pool that stays busy
+            // past its timeout is backed off and retried like any other
+            // transient failure.
+            let outcome = match self.checkout().await {
+                Ok(conn) => {
+                    self.dispatch::<T, P>(conn.as_ref(), query_name, params)
+                        .await
+                }
+                Err(e @ HelixClientError::PoolExhausted(..)) => Err(e.to_string()),
+                Err(e) => return Err(e),
+            };
+            match outcome {
                 Ok(result) => {
                     if !self.is_connected.load(Ordering::Relaxed) {
                         self.is_connected.store(true, Ordering::Relaxed);
@@ -101,9 +218,7 @@ impl HelixClient {
                     debug!("Query {} succeeded", query_name);
                     return Ok(result);
                 }
-                Err(e) => {
-                    let err_str = e.to_string();
-
+                Err(err_str) => {
                     if err_str.contains("not found") || err_str.contains("No value") {
                         debug!("Query {} returned not found (expected)", query_name);
                         return Err(HelixClientError::Query(err_str));
@@ -112,12 +227,12 @@ impl HelixClient {
                     if attempt == 1 {
                         debug!(
                             "Query {} failed (attempt {}), retrying: {}",
-                            query_name, attempt, e
+                            query_name, attempt, err_str
                         );
                     } else {
                         debug!(
                             "Query {} failed (final attempt {}): {}",
-                            query_name, attempt, e
+                            query_name, attempt, err_str
                         );
                     }
                     last_error = Some(err_str);
@@ -147,10 +262,10 @@ impl HelixClient {
         T: DeserializeOwned,
         P: Serialize + Sync,
     {
-        self.inner
-            .query::<P, T>(query_name, params)
+        let conn = self.checkout().await?;
+        self.dispatch(conn.as_ref(), query_name, params)
             .await
-            .map_err(|e| HelixClientError::Query(e.to_string()))
+            .map_err(HelixClientError::Query)
     }
 
     pub async fn health_check(&self) -> Result<(), HelixClientError> {
@@ -183,8 +298,9 @@ impl HelixClient {
         &self.base_url
     }
 
-    pub fn inner(&self) -> &HelixDB {
-        &self.inner
+    /// Connection pool counters (idle/active/failed slots, reconnects).
+    pub fn pool_health(&self) -> PoolHealth {
+        self.pool.health()
     }
 }
 
@@ -198,6 +314,37 @@ mod tests {
         assert!(client.is_ok());
     }
 
+    #[tokio::test]
+    async fn a_busy_pool_is_retried_without_holding_a_slot() {
+        let client = HelixClient::new("127.0.0.1", 1)
+            .unwrap()
+            .with_pool(&DbPoolConfig {
+                size: 1,
+                acquire_timeout_ms: 20,
+            })
+            .with_retry(RetryConfig {
+                max: 3,
+                initial_delay_ms: 30,
+                max_delay_ms: 30,
+                backoff_factor: 1,
+            });
+        let held = client.checkout().await.unwrap();
+        let release = async {
+            tokio::time::sleep(Duration::from_millis(35)).await;
+            drop(held);
+        };
+        let params = serde_json::json!({});
+        let (result, ()) = tokio::join!(
+            client.execute_query::<serde_json::Value, _>("getMemory", &params),
+            release
+        );
+        // The first attempt timed out on the pool, a later one got the slot
+        // (and failed on the closed port).
+        let err = result.unwrap_err().to_string();
+        assert!(!err.contains("pool exhausted"), "{err}");
+        assert_eq!(client.pool_health().idle, 1);
+    }
+
     #[test]
     fn test_client_from_env() {
         temp_env::with_vars(
@@ -0,0 +1,609 @@
+//! Evaluation of parsed queries over an in-process graph of nodes, edges
+//! and vectors.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde_json::{Map, Value, json};
+
+use super::hql::{Arg, Cond, Dir, Expr, Op, Props, Query, Source, Step, Stmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Node,
+    Edge,
+    Vector,
+}
+
+#[derive(Debug, Clone)]
+struct Element {
+    id: String,
+    kind: Kind,
+    label: String,
+    props: Map<String, Value>,
+    /// Endpoints of an edge; empty otherwise.
+    from: String,
+    to: String,
+    /// Payload of a vector; empty otherwise.
+    data: Vec<f64>,
+}
+
+#[derive(Debug, Clone)]
+struct Hit {
+    id: String,
+    /// Similarity of a `SearchV` / `SearchBM25` row.
+    score: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+enum Val {
+    Set {
+        hits: Vec<Hit>,
+        /// Narrowed by `FIRST`: serialises as one object.
+        single: bool,
+        /// The `N<L>` / `E<L>` a set came from, for the upserts.
+        label: Option<String>,
+    },
+    Count(usize),
+}
+
+impl Val {
+    fn set(hits: Vec<Hit>) -> Self {
+        Self::Set {
+            hits,
+            single: false,
+            label: None,
+        }
+    }
+
+    fn one(id: String) -> Self {
+        Self::Set {
+            hits: vec![Hit { id, score: None }],
+            single: true,
+            label: None,
+        }
+    }
+
+    fn hits(&self) -> Result<&[Hit], String> {
+        match self {
+            Self::Set { hits, .. } => Ok(hits),
+            Self::Count(_) => Err("traversal over a COUNT".to_string()),
+        }
+    }
+}
+
+fn no_value() -> String {
+    "GRAPH_ERROR: No value found".to_string()
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Graph {
+    elements: Vec<Element>,
+    next_id: u64,
+}
+
+struct Scope<'a> {
+    params: &'a Value,
+    vars: HashMap<String, Val>,
+    defaults: &'a HashMap<String, Map<String, Value>>,
+}
+
+impl Scope<'_> {
+    fn value(&self, arg: &Arg) -> Value {
+        match arg {
+            Arg::Lit(v) => v.clone(),
+            Arg::Name(n) => self.params.get(n).cloned().unwrap_or(Value::Null),
+        }
+    }
+
+    fn int(&self, arg: &Arg) -> usize {
+        self.value(arg).as_i64().unwrap_or_default().max(0) as usize
+    }
+
+    /// The element id an endpoint or `N<L>(id)` argument names: the first
+    /// hit of a binding, else the parameter's string value.
+    fn id(&self, arg: &Arg) -> Result<String, String> {
+        if let Arg::Name(n) = arg
+            && let Some(val) = self.vars.get(n)
+        {
+            return val
+                .hits()?
+                .first()
+                .map(|h| h.id.clone())
+                .ok_or_else(no_value);
+        }
+        self.value(arg)
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(no_value)
+    }
+
+    fn props(&self, props: &Props) -> Map<String, Value> {
+        props
+            .iter()
+            .map(|(k, a)| (k.clone(), self.value(a)))
+            .collect()
+    }
+
+    fn vector(&self, arg: &Arg) -> Vec<f64> {
+        self.value(arg)
+            .as_array()
+            .map(|a| a.iter().filter_map(Value::as_f64).collect())
+            .unwrap_or_default()
+    }
+}
+
+fn cosine(a: &[f64], b: &[f64]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let na = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let nb = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if na == 0.0 || nb == 0.0 {
+        0.0
+    } else {
+        dot / (na * nb)
+    }
+}
+
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+}
+
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => x.partial_cmp(&y),
+        _ => match (a.as_str(), b.as_str()) {
+            (Some(x), Some(y)) => Some(x.cmp(y)),
+            _ => (a == b).then_some(Ordering::Equal),
+        },
+    }
+}
+
+impl Graph {
+    fn fresh_id(&mut self) -> String {
+        self.next_id += 1;
+        // Time-ordered like HelixDB's v7 ids: later rows sort after earlier.
+        format!("00000000-0000-7000-8000-{:012x}", self.next_id)
+    }
+
+    fn get(&self, id: &str) -> Option<&Element> {
+        self.elements.iter().find(|e| e.id == id)
+    }
+
+    fn insert(
+        &mut self,
+        kind: Kind,
+        label: &str,
+        props: Map<String, Value>,
+        defaults: &HashMap<String, Map<String, Value>>,
+    ) -> Element {
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut all: Map<String, Value> = defaults
+            .get(label)
+            .into_iter()
+            .flatten()
+            .map(|(k, v)| {
+                let v = match v.as_str() {
+                    Some("{{timestamp}}" | "NOW") => json!(now),
+                    _ => v.clone(),
+                };
+                (k.clone(), v)
+            })
+            .collect();
+        all.extend(props);
+        Element {
+            id: self.fresh_id(),
+            kind,
+            label: label.to_string(),
+            props: all,
+            from: String::new(),
+            to: String::new(),
+            data: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, element: Element) -> Val {
+        let id = element.id.clone();
+        self.elements.push(element);
+        Val::one(id)
+    }
+
+    fn json(&self, hit: &Hit) -> Value {
+        let Some(e) = self.get(&hit.id) else {
+            return Value::Null;
+        };
+        let mut o = e.props.clone();
+        o.insert("id".into(), json!(e.id));
+        o.insert("label".into(), json!(e.label));
+        match e.kind {
+            Kind::Node => {}
+            Kind::Edge => {
+                o.insert("from_node".into(), json!(e.from));
+                o.insert("to_node".into(), json!(e.to));
+            }
+            Kind::Vector => {
+                o.insert("data".into(), json!(e.data));
+            }
+        }
+        if let Some(score) = hit.score {
+            o.insert("score".into(), json!(score));
+        }
+        Value::Object(o)
+    }
+
+    pub(super) fn run(
+        &mut self,
+        query: &Query,
+        params: &Value,
+        defaults: &HashMap<String, Map<String, Value>>,
+    ) -> Result<Value, String> {
+        let mut scope = Scope {
+            params,
+            vars: HashMap::new(),
+            defaults,
+        };
+        for stmt in &query.body {
+            match stmt {
+                Stmt::Bind(name, expr) => {
+                    let val = self.eval(expr, &scope)?;
+                    scope.vars.insert(name.clone(), val);
+                }
+                Stmt::Drop(expr) => {
+                    let val = self.eval(expr, &scope)?;
+                    self.drop_all(val.hits()?);
+                }
+            }
+        }
+        let mut out = Map::new();
+        for name in &query.returns {
+            let val = scope
+                .vars
+                .get(name)
+                .ok_or_else(|| format!("RETURN of unbound '{name}'"))?;
+            let json = match val {
+                Val::Count(n) => json!(n),
+                Val::Set {
+                    hits, single: true, ..
+                } => hits.first().map_or(Value::Null, |h| self.json(h)),
+                Val::Set { hits, .. } => hits.iter().map(|h| self.json(h)).collect(),
+            };
+            out.insert(name.clone(), json);
+        }
+        Ok(Value::Object(out))
+    }
+
+    fn drop_all(&mut self, hits: &[Hit]) {
+        let doomed: Vec<&str> = hits.iter().map(|h| h.id.as_str()).collect();
+        self.elements.retain(|e| {
+            !doomed.contains(&e.id.as_str())
+                && !(e.kind == Kind::Edge
+                    && (doomed.contains(&e.from.as_str()) || doomed.contains(&e.to.as_str())))
+        });
+    }
+
+    fn of_label(&self, kind: Kind, label: &str) -> Vec<Hit> {
+        self.elements
+            .iter()
+            .filter(|e| e.kind == kind && e.label == label)
+            .map(|e| Hit {
+                id: e.id.clone(),
+                score: None,
+            })
+            .collect()
+    }
+
+    fn source(&mut self, source: &Source, scope: &Scope) -> Result<Val, String> {
+        Ok(match source {
+            Source::AddN(label, props) => {
+                let node = self.insert(Kind::Node, label, scope.props(props), scope.defaults);
+                self.push(node)
+            }
+            Source::AddE {
+                label,
+                props,
+                from,
+                to,
+            } => {
+                let (from, to) = (scope.id(from)?, scope.id(to)?);
+                let mut edge = self.insert(Kind::Edge, label, scope.props(props), scope.defaults);
+                edge.from = from;
+                edge.to = to;
+                self.push(edge)
+            }
+            Source::AddV(label, vector, props) => {
+                let mut v = self.insert(Kind::Vector, label, scope.props(props), scope.defaults);
+                v.data = scope.vector(vector);
+                self.push(v)
+            }
+            Source::SearchV(label, vector, k) => {
+                let query = scope.vector(vector);
+                let mut hits: Vec<Hit> = self
+                    .elements
+                    .iter()
+                    .filter(|e| e.kind == Kind::Vector && e.label == *label)
+                    .map(|e| Hit {
+                        id: e.id.clone(),
+                        score: Some(cosine(&query, &e.data)),
+                    })
+                    .collect();
+                hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+                hits.truncate(scope.int(k));
+                Val::set(hits)
+            }
+            Source::SearchBm25(label, text, k) => {
+                let text = scope.value(text);
+                let terms: Vec<String> = words(text.as_str().unwrap_or_default()).collect();
+                let mut hits: Vec<Hit> = self
+                    .elements
+                    .iter()
+                    .filter(|e| e.kind == Kind::Node && e.label == *label)
+                    .filter_map(|e| {
+                        let tf = e
+                            .props
+                            .values()
+                            .filter_map(Value::as_str)
+                            .flat_map(words)
+                            .filter(|w| terms.contains(w))
+                            .count();
+                        (tf > 0).then(|| Hit {
+                            id: e.id.clone(),
+                            score: Some(tf as f64),
+                        })
+                    })
+                    .collect();
+                hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+                hits.truncate(scope.int(k));
+                Val::set(hits)
+            }
+            Source::Nodes(label, None) => Val::Set {
+                hits: self.of_label(Kind::Node, label),
+                single: false,
+                label: Some(label.clone()),
+            },
+            Source::Vectors(label, None) => Val::set(self.of_label(Kind::Vector, label)),
+            Source::Nodes(_, Some(id)) | Source::Vectors(_, Some(id)) => {
+                let id = scope.id(id)?;
+                self.get(&id).ok_or_else(no_value)?;
+                Val::one(id)
+            }
+            Source::Edges(label) => Val::Set {
+                hits: self.of_label(Kind::Edge, label),
+                single: false,
+                label: Some(label.clone()),
+            },
+            Source::Var(name) => scope
+                .vars
+                .get(name)
+                .cloned()
+                .ok_or_else(|| format!("unbound '{name}'"))?,
+        })
+    }
+
+    fn eval(&mut self, expr: &Expr, scope: &Scope) -> Result<Val, String> {
+        let mut val = self.source(&expr.source, scope)?;
+        for step in &expr.steps {
+            val = self.step(step, val, scope)?;
+        }
+        Ok(val)
+    }
+
+    fn matches(&self, e: &Element, cond: &Cond, scope: &Scope) -> bool {
+        match cond {
+            Cond::And(parts) => parts.iter().all(|c| self.matches(e, c, scope)),
+            Cond::Or(parts) => parts.iter().any(|c| self.matches(e, c, scope)),
+            Cond::Cmp {
+                via,
+                field,
+                op,
+                arg,
+            } => {
+                let target = match via {
+                    None => Some(e),
+                    Some(Dir::Out) => self.get(&e.to),
+                    Some(Dir::In) => self.get(&e.from),
+                };
+                let Some(target) = target else {
+                    return false;
+                };
+                let actual = match field.as_str() {
+                    "id" => json!(target.id),
+                    _ => target.props.get(field).cloned().unwrap_or(Value::Null),
+                };
+                let wanted = scope.value(arg);
+                let ord = compare(&actual, &wanted);
+                match op {
+                    Op::Eq => ord == Some(Ordering::Equal),
+                    Op::Neq => ord != Some(Ordering::Equal),
+                    Op::Gt => ord == Some(Ordering::Greater),
+                    Op::Gte => matches!(ord, Some(Ordering::Greater | Ordering::Equal)),
+                    Op::Lt => ord == Some(Ordering::Less),
+                    Op::Lte => matches!(ord, Some(Ordering::Less | Ordering::Equal)),
+                    Op::IsIn => wanted.as_array().is_some_and(|a| {
+                        a.iter()
+                            .any(|w| compare(&actual, w) == Some(Ordering::Equal))
+                    }),
+                    Op::Contains => match (actual.as_str(), wanted.as_str()) {
+                        (Some(a), Some(w)) => a.contains(w),
+                        _ => false,
+                    },
+                }
+            }
+        }
+    }
+
+    fn step(&mut self, step: &Step, val: Val, scope: &Scope) -> Result<Val, String> {
+        let ids: Vec<String> = val.hits()?.iter().map(|h| h.id.clone()).collect();
+        let out = |elements: Vec<&Element>| {
+            Val::set(
+                elements
+                    .into_iter()
+                    .map(|e| Hit {
+                        id: e.id.clone(),
+                        score: None,
+                    })
+                    .collect(),
+            )
+        };
+        Ok(match step {
+            Step::Walk(dir, label) | Step::WalkE(dir, label) => {
+                let edges_only = matches!(step, Step::WalkE(..));
+                let mut reached = Vec::new();
+                for id in &ids {
+                    for e in self.elements.iter().filter(|e| {
+                        e.kind == Kind::Edge
+                            && e.label == *label
+                            && match dir {
+                                Dir::Out => e.from == *id,
+                                Dir::In => e.to == *id,
+                            }
+                    }) {
+                        if edges_only {
+                            reached.push(e);
+                        } else if let Some(n) = self.get(match dir {
+                            Dir::Out => &e.to,
+                            Dir::In => &e.from,
+                        }) {
+                            reached.push(n);
+                        }
+                    }
+                }
+                out(reached)
+            }
+            Step::Endpoint(dir) => {
+                let reached = ids
+                    .iter()
+                    .filter_map(|id| self.get(id))
+                    .filter_map(|e| {
+                        self.get(match dir {
+                            Dir::Out => &e.to,
+                            Dir::In => &e.from,
+                        })
+                    })
+                    .collect();
+                out(reached)
+            }
+            Step::Where(cond) => {
+                let Val::Set { hits, label, .. } = val else {
+                    unreachable!("hits() rejected COUNT above");
+                };
+                let hits = hits
+                    .into_iter()
+                    .filter(|h| {
+                        self.get(&h.id)
+                            .is_some_and(|e| self.matches(e, cond, scope))
+                    })
+                    .collect();
+                Val::Set {
+                    hits,
+                    single: false,
+                    label,
+                }
+            }
+            Step::Order(field, desc) => {
+                let Val::Set {
+                    mut hits, label, ..
+                } = val
+                else {
+                    unreachable!("hits() rejected COUNT above");
+                };
+                let key = |h: &Hit| {
+                    self.get(&h.id)
+                        .and_then(|e| e.props.get(field))
+                        .cloned()
+                        .unwrap_or(Value::Null)
+                };
+                hits.sort_by(|a, b| {
+                    let ord = compare(&key(a), &key(b)).unwrap_or(Ordering::Equal);
+                    if *desc { ord.reverse() } else { ord }
+                });
+                Val::Set {
+                    hits,
+                    single: false,
+                    label,
+                }
+            }
+            Step::First => {
+                let first = ids.first().cloned().ok_or_else(no_value)?;
+                let Val::Set { hits, label, .. } = val else {
+                    unreachable!("hits() rejected COUNT above");
+                };
+                Val::Set {
+                    hits: hits.into_iter().take(1).collect(),
+                    single: true,
+                    label: label.or_else(|| self.get(&first).map(|e| e.label.clone())),
+                }
+            }
+            Step::Range(start, end) => {
+                let (start, end) = (scope.int(start), scope.int(end));
+                let Val::Set { hits, label, .. } = val else {
+                    unreachable!("hits() rejected COUNT above");
+                };
+                Val::Set {
+                    hits: hits
+                        .into_iter()
+                        .skip(start)
+                        .take(end.saturating_sub(start))
+                        .collect(),
+                    single: false,
+                    label,
+                }
+            }
+            Step::Count => Val::Count(ids.len()),
+            Step::Update(props) => {
+                let props = scope.props(props);
+                for e in self.elements.iter_mut().filter(|e| ids.contains(&e.id)) {
+                    e.props.extend(props.clone());
+                }
+                val
+            }
+            Step::UpsertN(props) => {
+                let props = scope.props(props);
+                match ids.first() {
+                    Some(id) => {
+                        if let Some(e) = self.elements.iter_mut().find(|e| e.id == *id) {
+                            e.props.extend(props);
+                        }
+                        Val::one(id.clone())
+                    }
+                    None => {
+                        let Val::Set {
+                            label: Some(label), ..
+                        } = &val
+                        else {
+                            return Err("UpsertN needs an N<Label> source".to_string());
+                        };
+                        let node = self.insert(Kind::Node, label, props, scope.defaults);
+                        self.push(node)
+                    }
+                }
+            }
+            Step::UpsertE { props, from, to } => {
+                let (from, to) = (scope.id(from)?, scope.id(to)?);
+                let props = scope.props(props);
+                let existing = self
+                    .elements
+                    .iter_mut()
+                    .find(|e| ids.contains(&e.id) && e.from == from && e.to == to);
+                match existing {
+                    Some(e) => {
+                        e.props.extend(props);
+                        Val::one(e.id.clone())
+                    }
+                    None => {
+                        let Val::Set {
+                            label: Some(label), ..
+                        } = &val
+                        else {
+                            return Err("UpsertE needs an E<Label> source".to_string());
+                        };
+                        let mut edge = self.insert(Kind::Edge, label, props, scope.defaults);
+                        edge.from = from;
+                        edge.to = to;
+                        self.push(edge)
+                    }
+                }
+            }
+        })
+    }
+}
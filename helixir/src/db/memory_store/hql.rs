@@ -0,0 +1,574 @@
+//! Parser for the subset of HelixQL that `schema/queries.hx` uses, plus the
+//! property defaults declared in `schema/schema.hx`.
+//!
+//! Grammar covered: `x <- <source>(::<step>)*`, `DROP <expr>` and
+//! `RETURN a, b` / `RETURN "literal"`. Sources are `AddN`/`AddE`/`AddV`,
+//! `SearchV`/`SearchBM25`, `N<L>`/`V<L>`/`E<L>` with an optional id, and
+//! earlier bindings. Steps are traversals (`Out`/`In`/`OutE`/`InE`/`ToN`/
+//! `FromN`), `WHERE`, `ORDER`, `FIRST`, `RANGE`, `COUNT`, `UPDATE` and the
+//! two upserts.
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value, json};
+
+#[derive(Debug, Clone)]
+pub(super) enum Arg {
+    /// A bare identifier: a query parameter or an earlier binding.
+    Name(String),
+    Lit(Value),
+}
+
+pub(super) type Props = Vec<(String, Arg)>;
+
+#[derive(Debug, Clone)]
+pub(super) enum Source {
+    AddN(String, Props),
+    AddE {
+        label: String,
+        props: Props,
+        from: Arg,
+        to: Arg,
+    },
+    AddV(String, Arg, Props),
+    SearchV(String, Arg, Arg),
+    SearchBm25(String, Arg, Arg),
+    Nodes(String, Option<Arg>),
+    Vectors(String, Option<Arg>),
+    Edges(String),
+    Var(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Dir {
+    Out,
+    In,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Step {
+    Walk(Dir, String),
+    WalkE(Dir, String),
+    /// `ToN` (`Out`) or `FromN` (`In`) from an edge to its endpoint.
+    Endpoint(Dir),
+    Where(Cond),
+    /// `ORDER<Asc|Desc>(_::{field})`; `true` is descending.
+    Order(String, bool),
+    First,
+    Range(Arg, Arg),
+    Count,
+    Update(Props),
+    UpsertN(Props),
+    UpsertE {
+        props: Props,
+        from: Arg,
+        to: Arg,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Op {
+    Eq,
+    Neq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    IsIn,
+    Contains,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Cond {
+    /// `_::{field}::OP(arg)`, optionally through an edge endpoint first.
+    Cmp {
+        via: Option<Dir>,
+        field: String,
+        op: Op,
+        arg: Arg,
+    },
+    And(Vec<Cond>),
+    Or(Vec<Cond>),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Expr {
+    pub source: Source,
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Stmt {
+    Bind(String, Expr),
+    Drop(Expr),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Query {
+    pub body: Vec<Stmt>,
+    /// Bindings to return; empty for `RETURN "literal"`.
+    pub returns: Vec<String>,
+}
+
+/// Every `QUERY` in `source`, by name. A query that fails to parse is an
+/// error naming it, so an unsupported construct is caught by the parse
+/// test rather than at the first call.
+pub(super) fn parse_queries(source: &str) -> Result<HashMap<String, Query>, String> {
+    let mut out = HashMap::new();
+    for chunk in strip_comments(source).split("\nQUERY ").skip(1) {
+        let (header, body) = chunk
+            .split_once("=>")
+            .ok_or_else(|| format!("query without '=>': {}", first_line(chunk)))?;
+        let name = header
+            .split('(')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        let query = parse_body(body).map_err(|e| format!("{name}: {e}"))?;
+        out.insert(name, query);
+    }
+    Ok(out)
+}
+
+fn first_line(s: &str) -> &str {
+    s.lines().next().unwrap_or_default()
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut out = String::from("\n");
+    for line in source.lines() {
+        let mut in_str = false;
+        let mut cut = line.len();
+        let bytes = line.as_bytes();
+        for i in 0..bytes.len() {
+            match bytes[i] {
+                b'"' => in_str = !in_str,
+                b'/' if !in_str && bytes.get(i + 1) == Some(&b'/') => {
+                    cut = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        out.push_str(&line[..cut]);
+        out.push('\n');
+    }
+    out
+}
+
+/// Split a body into statements: a line at bracket depth zero opens one.
+fn statements(body: &str) -> Vec<String> {
+    let mut stmts: Vec<String> = Vec::new();
+    let mut depth = 0i32;
+    for line in body.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if depth == 0 || stmts.is_empty() {
+            stmts.push(line.to_string());
+        } else if let Some(last) = stmts.last_mut() {
+            last.push(' ');
+            last.push_str(line);
+        }
+        let mut in_str = false;
+        for c in line.chars() {
+            match c {
+                '"' => in_str = !in_str,
+                '(' | '{' | '[' if !in_str => depth += 1,
+                ')' | '}' | ']' if !in_str => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+    stmts
+}
+
+fn parse_body(body: &str) -> Result<Query, String> {
+    let mut query = Query {
+        body: Vec::new(),
+        returns: Vec::new(),
+    };
+    for stmt in statements(body) {
+        if let Some(rest) = stmt.strip_prefix("RETURN ") {
+            let rest = rest.trim();
+            if !rest.starts_with('"') {
+                query.returns = rest.split(',').map(|s| s.trim().to_string()).collect();
+            }
+        } else if let Some(rest) = stmt.strip_prefix("DROP ") {
+            query
+                .body
+                .push(Stmt::Drop(Cursor::new(rest).expr_to_end()?));
+        } else if let Some((name, rest)) = stmt.split_once("<-") {
+            let expr = Cursor::new(rest).expr_to_end()?;
+            query.body.push(Stmt::Bind(name.trim().to_string(), expr));
+        } else {
+            return Err(format!("unrecognised statement '{stmt}'"));
+        }
+    }
+    Ok(query)
+}
+
+struct Cursor<'a> {
+    s: &'a str,
+    at: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(s: &'a str) -> Self {
+        Self { s, at: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.s[self.at..]
+    }
+
+    fn ws(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.at = self.s.len() - trimmed.len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.ws();
+        if self.rest().starts_with(token) {
+            self.at += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(format!("expected '{token}' at '{}'", self.rest()))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        self.ws();
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(format!("expected identifier at '{}'", self.rest()));
+        }
+        let id = self.rest()[..len].to_string();
+        self.at += len;
+        Ok(id)
+    }
+
+    /// `<Label>`
+    fn label(&mut self) -> Result<String, String> {
+        self.expect("<")?;
+        let label = self.ident()?;
+        self.expect(">")?;
+        Ok(label)
+    }
+
+    fn arg(&mut self) -> Result<Arg, String> {
+        self.ws();
+        let rest = self.rest();
+        if let Some(s) = rest.strip_prefix('"') {
+            let end = s.find('"').ok_or("unterminated string")?;
+            self.at += end + 2;
+            return Ok(Arg::Lit(json!(&s[..end])));
+        }
+        if rest.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+            let len = rest[1..]
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .map_or(rest.len(), |n| n + 1);
+            let raw = &rest[..len];
+            self.at += len;
+            let value = raw
+                .parse::<i64>()
+                .map(Value::from)
+                .or_else(|_| raw.parse::<f64>().map(Value::from))
+                .map_err(|_| format!("bad number '{raw}'"))?;
+            return Ok(Arg::Lit(value));
+        }
+        let id = self.ident()?;
+        Ok(match id.as_str() {
+            "true" => Arg::Lit(json!(true)),
+            "false" => Arg::Lit(json!(false)),
+            _ => Arg::Name(id),
+        })
+    }
+
+    /// `{ key: arg, ... }`
+    fn props(&mut self) -> Result<Props, String> {
+        self.expect("{")?;
+        let mut props = Vec::new();
+        while !self.eat("}") {
+            let key = self.ident()?;
+            self.expect(":")?;
+            props.push((key, self.arg()?));
+            self.eat(",");
+        }
+        Ok(props)
+    }
+
+    /// `::From(a)::To(b)`
+    fn endpoints(&mut self) -> Result<(Arg, Arg), String> {
+        self.expect("::From(")?;
+        let from = self.arg()?;
+        self.expect(")")?;
+        self.expect("::To(")?;
+        let to = self.arg()?;
+        self.expect(")")?;
+        Ok((from, to))
+    }
+
+    fn optional_id(&mut self) -> Result<Option<Arg>, String> {
+        if self.eat("(") {
+            let id = self.arg()?;
+            self.expect(")")?;
+            Ok(Some(id))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn source(&mut self) -> Result<Source, String> {
+        let head = self.ident()?;
+        Ok(match head.as_str() {
+            "AddN" => {
+                let label = self.label()?;
+                self.expect("(")?;
+                let props = self.props()?;
+                self.expect(")")?;
+                Source::AddN(label, props)
+            }
+            "AddE" => {
+                let label = self.label()?;
+                let props = if self.eat("(") {
+                    let props = self.props()?;
+                    self.expect(")")?;
+                    props
+                } else {
+                    Vec::new()
+                };
+                let (from, to) = self.endpoints()?;
+                Source::AddE {
+                    label,
+                    props,
+                    from,
+                    to,
+                }
+            }
+            "AddV" => {
+                let label = self.label()?;
+                self.expect("(")?;
+                let vector = self.arg()?;
+                let props = if self.eat(",") {
+                    self.props()?
+                } else {
+                    Vec::new()
+                };
+                self.expect(")")?;
+                Source::AddV(label, vector, props)
+            }
+            "SearchV" | "SearchBM25" => {
+                let label = self.label()?;
+                self.expect("(")?;
+                let needle = self.arg()?;
+                self.expect(",")?;
+                let k = self.arg()?;
+                self.expect(")")?;
+                if head == "SearchV" {
+                    Source::SearchV(label, needle, k)
+                } else {
+                    Source::SearchBm25(label, needle, k)
+                }
+            }
+            "N" => {
+                let label = self.label()?;
+                Source::Nodes(label, self.optional_id()?)
+            }
+            "V" => {
+                let label = self.label()?;
+                Source::Vectors(label, self.optional_id()?)
+            }
+            "E" => Source::Edges(self.label()?),
+            _ => Source::Var(head),
+        })
+    }
+
+    fn cond(&mut self) -> Result<Cond, String> {
+        for (token, and) in [("AND(", true), ("OR(", false)] {
+            if self.eat(token) {
+                let mut parts = vec![self.cond()?];
+                while self.eat(",") {
+                    parts.push(self.cond()?);
+                }
+                self.expect(")")?;
+                return Ok(if and {
+                    Cond::And(parts)
+                } else {
+                    Cond::Or(parts)
+                });
+            }
+        }
+        self.expect("_")?;
+        let via = if self.eat("::ToN") {
+            Some(Dir::Out)
+        } else if self.eat("::FromN") {
+            Some(Dir::In)
+        } else {
+            None
+        };
+        self.expect("::{")?;
+        let field = self.ident()?;
+        self.expect("}")?;
+        self.expect("::")?;
+        let op = match self.ident()?.as_str() {
+            "EQ" => Op::Eq,
+            "NEQ" => Op::Neq,
+            "GT" => Op::Gt,
+            "GTE" => Op::Gte,
+            "LT" => Op::Lt,
+            "LTE" => Op::Lte,
+            "IS_IN" => Op::IsIn,
+            "CONTAINS" => Op::Contains,
+            other => return Err(format!("unsupported operator {other}")),
+        };
+        self.expect("(")?;
+        let arg = self.arg()?;
+        self.expect(")")?;
+        Ok(Cond::Cmp {
+            via,
+            field,
+            op,
+            arg,
+        })
+    }
+
+    fn step(&mut self) -> Result<Step, String> {
+        let name = self.ident()?;
+        Ok(match name.as_str() {
+            "Out" => Step::Walk(Dir::Out, self.label()?),
+            "In" => Step::Walk(Dir::In, self.label()?),
+            "OutE" => Step::WalkE(Dir::Out, self.label()?),
+            "InE" => Step::WalkE(Dir::In, self.label()?),
+            "ToN" => Step::Endpoint(Dir::Out),
+            "FromN" => Step::Endpoint(Dir::In),
+            "FIRST" => Step::First,
+            "COUNT" => Step::Count,
+            "WHERE" => {
+                self.expect("(")?;
+                let cond = self.cond()?;
+                self.expect(")")?;
+                Step::Where(cond)
+            }
+            "ORDER" => {
+                let desc = match self.label()?.as_str() {
+                    "Asc" => false,
+                    "Desc" => true,
+                    other => return Err(format!("unsupported order {other}")),
+                };
+                self.expect("(")?;
+                self.expect("_")?;
+                self.expect("::{")?;
+                let field = self.ident()?;
+                self.expect("}")?;
+                self.expect(")")?;
+                Step::Order(field, desc)
+            }
+            "RANGE" => {
+                self.expect("(")?;
+                let start = self.arg()?;
+                self.expect(",")?;
+                let end = self.arg()?;
+                self.expect(")")?;
+                Step::Range(start, end)
+            }
+            "UPDATE" | "UpsertN" => {
+                self.expect("(")?;
+                let props = self.props()?;
+                self.expect(")")?;
+                if name == "UPDATE" {
+                    Step::Update(props)
+                } else {
+                    Step::UpsertN(props)
+                }
+            }
+            "UpsertE" => {
+                self.expect("(")?;
+                let props = self.props()?;
+                self.expect(")")?;
+                let (from, to) = self.endpoints()?;
+                Step::UpsertE { props, from, to }
+            }
+            other => return Err(format!("unsupported step ::{other}")),
+        })
+    }
+
+    fn expr_to_end(&mut self) -> Result<Expr, String> {
+        let source = self.source()?;
+        let mut steps = Vec::new();
+        while self.eat("::") {
+            steps.push(self.step()?);
+        }
+        self.ws();
+        if !self.rest().is_empty() {
+            return Err(format!("trailing input '{}'", self.rest()));
+        }
+        Ok(Expr { source, steps })
+    }
+}
+
+/// Declared `DEFAULT`s per node, edge or vector label. `{{timestamp}}` and
+/// `NOW` stay as markers; the store fills them in at insert time.
+pub(super) fn parse_defaults(schema: &str) -> HashMap<String, Map<String, Value>> {
+    let mut out: HashMap<String, Map<String, Value>> = HashMap::new();
+    let mut label = None;
+    for line in strip_comments(schema).lines().map(str::trim) {
+        if let Some(header) = ["N::", "E::", "V::"]
+            .iter()
+            .find_map(|p| line.strip_prefix(p))
+        {
+            label = header.split([' ', '{']).next().map(str::to_string);
+            continue;
+        }
+        let (Some(label), Some((decl, default))) = (&label, line.split_once(" DEFAULT ")) else {
+            continue;
+        };
+        let field = decl
+            .trim_start_matches("INDEX ")
+            .split(':')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        let raw = default.trim().trim_end_matches(',').trim();
+        let value = match Cursor::new(raw).arg() {
+            Ok(Arg::Lit(v)) => v,
+            _ => json!(raw),
+        };
+        out.entry(label.clone())
+            .or_default()
+            .insert(field.to_string(), value);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_shipped_query_and_default_parses() {
+        let queries = parse_queries(include_str!("../../../schema/queries.hx")).unwrap();
+        assert!(queries.len() > 100);
+        let add = &queries["addMemoryEmbedding"];
+        assert_eq!(add.returns, vec!["embedding"]);
+        assert!(matches!(
+            &add.body[1],
+            Stmt::Bind(_, Expr { source: Source::AddE { label, .. }, .. }) if label == "HAS_EMBEDDING"
+        ));
+        assert!(queries["deleteImportCheckpoint"].returns.is_empty());
+
+        let defaults = parse_defaults(include_str!("../../../schema/schema.hx"));
+        assert_eq!(defaults["Memory"]["certainty"], json!(100));
+        assert_eq!(defaults["Memory"]["created_at"], json!("{{timestamp}}"));
+        assert_eq!(defaults["MemoryNotice"]["delivered"], json!(0));
+    }
+}
@@ -0,0 +1,75 @@
+//! [`InMemoryStore`]: a process-local [`MemoryStore`] for tests, so the
+//! memory pipeline and FastThink run end to end without a HelixDB server.
+//!
+//! Rather than hand-mocking each query, the store parses the real
+//! `schema/queries.hx` (and the `DEFAULT`s of `schema/schema.hx`) at first
+//! use and interprets the HelixQL subset those files use over plain tables
+//! (see [`hql`] for the grammar). A query added to the schema is therefore
+//! served without touching this module, and a construct the interpreter
+//! does not know fails the parse test instead of passing on wrong data.
+//!
+//! Semantics follow HelixDB where the pipeline depends on them: `::FIRST`
+//! on nothing fails with `No value found`, ids are time-ordered, results
+//! come back as `{<RETURN name>: ...}`, and an unknown query name fails
+//! with `not found` like an undeployed endpoint. `SearchV` is exact cosine
+//! over every stored vector; `SearchBM25` ranks by plain term frequency.
+
+mod eval;
+mod hql;
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use serde_json::{Map, Value};
+
+use super::client::HelixClientError;
+use super::store::MemoryStore;
+
+struct Schema {
+    queries: HashMap<String, hql::Query>,
+    defaults: HashMap<String, Map<String, Value>>,
+}
+
+fn schema() -> &'static Schema {
+    static SCHEMA: OnceLock<Schema> = OnceLock::new();
+    SCHEMA.get_or_init(|| Schema {
+        // The parse test guarantees this succeeds for the shipped file.
+        queries: hql::parse_queries(include_str!("../../../schema/queries.hx")).unwrap_or_default(),
+        defaults: hql::parse_defaults(include_str!("../../../schema/schema.hx")),
+    })
+}
+
+/// See the module docs. Cheap to create; use one per test.
+#[derive(Debug, Default)]
+pub struct InMemoryStore {
+    graph: Mutex<eval::Graph>,
+    calls: Mutex<Vec<String>>,
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Names of the queries run so far, oldest first.
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().clone()
+    }
+}
+
+#[async_trait]
+impl MemoryStore for InMemoryStore {
+    async fn query(&self, name: &str, params: Value) -> Result<Value, HelixClientError> {
+        self.calls.lock().push(name.to_string());
+        let schema = schema();
+        let query = schema.queries.get(name).ok_or_else(|| {
+            HelixClientError::Query(format!("query '{name}' not found in schema/queries.hx"))
+        })?;
+        self.graph
+            .lock()
+            .run(query, &params, &schema.defaults)
+            .map_err(HelixClientError::Query)
+    }
+}
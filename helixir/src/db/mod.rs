@@ -1,3 +1,11 @@
 mod client;
+#[cfg(any(test, feature = "in-memory-store"))]
+mod memory_store;
+mod pool;
+mod store;
 
 pub use client::{HelixClient, HelixClientError};
+#[cfg(any(test, feature = "in-memory-store"))]
+pub use memory_store::InMemoryStore;
+pub use pool::PoolHealth;
+pub use store::MemoryStore;
@@ -0,0 +1,209 @@
+//! HelixDB connection pool (`HELIXIR_DB_POOL_SIZE`): a fixed set of HTTP
+//! clients ("slots") behind a semaphore, so at most `size` queries are in
+//! flight and a long-running server never marches on with a dead client.
+//!
+//! Slots connect lazily, on their first checkout: building an HTTP client
+//! loads the TLS roots, and most processes never run `size` queries at once.
+//!
+//! A slot whose query fails at the transport level (refused, reset, timed
+//! out — not a HelixQL error) is rebuilt on the spot with a fresh client and
+//! counted as failed until a query on it succeeds again; the query's own
+//! retry/backoff ([`crate::core::config::RetryConfig`]) then runs on a
+//! healthy slot. A checkout that cannot get a slot within `acquire_timeout`
+//! fails with [`HelixClientError::PoolExhausted`] instead of queueing
+//! forever; `execute_query` backs that off and retries like a transport
+//! failure.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use helix_rs::{HelixDB, HelixDBClient};
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tracing::warn;
+
+use super::client::HelixClientError;
+
+/// Pool counters, as `cache_stats` and `status://helixdb` report them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct PoolHealth {
+    pub size: usize,
+    /// Slots not running a query right now.
+    pub idle: usize,
+    /// Slots running a query right now.
+    pub active: usize,
+    /// Slots whose last query failed at the transport level.
+    pub failed: usize,
+    /// Slots rebuilt after a transport failure, since start.
+    pub reconnects: u64,
+}
+
+struct Slot {
+    /// `None` until first checked out.
+    client: RwLock<Option<HelixDB>>,
+    failed: AtomicBool,
+}
+
+pub(super) struct ConnectionPool {
+    endpoint: String,
+    port: u16,
+    slots: Vec<Slot>,
+    /// Indices of the slots nobody holds; a permit guarantees one is here.
+    idle: Mutex<Vec<usize>>,
+    permits: Semaphore,
+    acquire_timeout: Duration,
+    reconnects: AtomicU64,
+}
+
+/// A checked-out slot; hands it back on drop.
+pub(super) struct PooledConnection<'a> {
+    pool: &'a ConnectionPool,
+    idx: usize,
+    _permit: SemaphorePermit<'a>,
+}
+
+impl PooledConnection<'_> {
+    /// The slot's client. `HelixDB` is a cheap handle (its HTTP client is
+    /// reference-counted), so the lock is not held across the query.
+    pub(super) fn client(&self) -> HelixDB {
+        let slot = &self.pool.slots[self.idx];
+        if let Some(client) = slot.client.read().as_ref() {
+            return client.clone();
+        }
+        slot.client
+            .write()
+            .get_or_insert_with(|| self.pool.fresh_client())
+            .clone()
+    }
+
+    pub(super) fn succeeded(&self) {
+        self.pool.slots[self.idx]
+            .failed
+            .store(false, Ordering::Relaxed);
+    }
+
+    /// Rebuild the slot's client after a transport failure.
+    pub(super) fn reconnect(&self, error: &str) {
+        let slot = &self.pool.slots[self.idx];
+        if !slot.failed.swap(true, Ordering::Relaxed) {
+            warn!(
+                "HelixDB connection {} failed, reconnecting: {error}",
+                self.idx
+            );
+        }
+        *slot.client.write() = Some(self.pool.fresh_client());
+        self.pool.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        self.pool.idle.lock().push(self.idx);
+    }
+}
+
+impl ConnectionPool {
+    /// `size` is clamped to at least one slot.
+    pub(super) fn new(endpoint: &str, port: u16, size: usize, acquire_timeout: Duration) -> Self {
+        let size = size.max(1);
+        Self {
+            endpoint: endpoint.to_string(),
+            port,
+            slots: (0..size)
+                .map(|_| Slot {
+                    client: RwLock::new(None),
+                    failed: AtomicBool::new(false),
+                })
+                .collect(),
+            idle: Mutex::new((0..size).rev().collect()),
+            permits: Semaphore::new(size),
+            acquire_timeout,
+            reconnects: AtomicU64::new(0),
+        }
+    }
+
+    fn fresh_client(&self) -> HelixDB {
+        <HelixDB as HelixDBClient>::new(Some(&self.endpoint), Some(self.port), None)
+    }
+
+    /// Wait up to `acquire_timeout` for a slot, preferring a healthy one.
+    pub(super) async fn checkout(&self) -> Result<PooledConnection<'_>, HelixClientError> {
+        let permit = tokio::time::timeout(self.acquire_timeout, self.permits.acquire())
+            .await
+            .map_err(|_| {
+                HelixClientError::PoolExhausted(
+                    self.slots.len(),
+                    self.acquire_timeout.as_millis() as u64,
+                )
+            })?
+            .map_err(|_| HelixClientError::NotConnected)?;
+        let idx = {
+            let mut idle = self.idle.lock();
+            let pick = idle
+                .iter()
+                .rposition(|&i| !self.slots[i].failed.load(Ordering::Relaxed))
+                .unwrap_or(idle.len() - 1);
+            idle.swap_remove(pick)
+        };
+        Ok(PooledConnection {
+            pool: self,
+            idx,
+            _permit: permit,
+        })
+    }
+
+    pub(super) fn health(&self) -> PoolHealth {
+        let idle = self.idle.lock().len();
+        PoolHealth {
+            size: self.slots.len(),
+            idle,
+            active: self.slots.len() - idle,
+            failed: self
+                .slots
+                .iter()
+                .filter(|s| s.failed.load(Ordering::Relaxed))
+                .count(),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn a_full_pool_times_out_and_failed_slots_are_avoided() {
+        let pool = ConnectionPool::new("http://127.0.0.1", 1, 2, Duration::from_millis(20));
+        let first = pool.checkout().await.unwrap();
+        first.reconnect("connection refused");
+        let second = pool.checkout().await.unwrap();
+        assert_eq!(
+            pool.health(),
+            PoolHealth {
+                size: 2,
+                idle: 0,
+                active: 2,
+                failed: 1,
+                reconnects: 1,
+            }
+        );
+        let err = pool.checkout().await.err().expect("no slot left");
+        assert!(
+            matches!(err, HelixClientError::PoolExhausted(2, 20)),
+            "{err}"
+        );
+
+        let failed_idx = first.idx;
+        drop(first);
+        drop(second);
+        // Both are idle again; the healthy one is handed out first.
+        let next = pool.checkout().await.unwrap();
+        assert_ne!(next.idx, failed_idx);
+        next.succeeded();
+        drop(next);
+        let health = pool.health();
+        assert_eq!((health.idle, health.failed), (2, 1));
+    }
+}
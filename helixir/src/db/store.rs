@@ -0,0 +1,21 @@
+//! [`MemoryStore`]: the persistence seam under [`super::HelixClient`].
+//!
+//! Every read and write the crate makes is a named HelixQL query with JSON
+//! parameters, so the seam is one method: run query `name` with `params`.
+//! Production queries go straight to the HelixDB connection pool; a store
+//! set with [`super::HelixClient::with_store`] replaces it. The
+//! `InMemoryStore` (feature `in-memory-store`) answers the shipped queries
+//! from process memory so the client can be exercised without a live
+//! database.
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::client::HelixClientError;
+
+#[async_trait]
+pub trait MemoryStore: Send + Sync {
+    /// Run the named query. A lookup that matches nothing fails with an
+    /// error whose text contains `No value found`, as HelixDB does.
+    async fn query(&self, name: &str, params: Value) -> Result<Value, HelixClientError>;
+}
@@ -41,6 +41,8 @@ pub const DEFAULT_DEEPSEEK_MODEL: &str = "deepseek-v4-flash";
 pub const DEFAULT_HELIX_PORT: u16 = 6969;
 pub const DEFAULT_CACHE_SIZE: usize = 1000;
 pub const DEFAULT_CACHE_TTL: u64 = 300;
+/// Texts per embedding request on the batched path.
+pub const DEFAULT_EMBEDDING_BATCH_SIZE: usize = 64;
 /// Ollama HTTP request timeout (seconds). Generous by default so weak hardware
 /// running a large local model doesn't trip the client before the model replies.
 pub const DEFAULT_LLM_REQUEST_TIMEOUT_SECS: u64 = 600;
@@ -1,5 +1,10 @@
 //! Batched embedding path: cache lookup per item → batched primary call →
 //! per-item Ollama fallback.
+//!
+//! Uncached texts go to the provider `batch_size` at a time, one batch after
+//! another: N texts cost ceil(N / batch_size) OpenAI requests instead of N,
+//! and an Ollama fan-out never has more than `batch_size` requests in
+//! flight.
 
 use std::sync::atomic::Ordering;
 
@@ -51,15 +56,37 @@ impl EmbeddingGenerator {
         }
 
         info!(
-            "Batch embedding: {} total, {} cached, {} to generate",
+            "Batch embedding: {} total, {} cached, {} to generate in {} request(s) of up to {}",
             texts.len(),
             texts.len() - uncached_texts.len(),
-            uncached_texts.len()
+            uncached_texts.len(),
+            uncached_texts.len().div_ceil(self.batch_size),
+            self.batch_size
         );
 
+        let mut embeddings = Vec::with_capacity(uncached_texts.len());
+        for chunk in uncached_texts.chunks(self.batch_size) {
+            embeddings.extend(self.generate_chunk(chunk).await?);
+        }
+
+        for (idx, embedding) in uncached_indices.into_iter().zip(embeddings) {
+            if use_cache {
+                self.cache.set(texts[idx], embedding.clone());
+            }
+            results[idx] = Some(embedding);
+        }
+
+        Ok(results.into_iter().map(|r| r.unwrap()).collect())
+    }
+
+    /// One provider request (or fallback fan-out) for at most `batch_size`
+    /// texts, dimension-checked.
+    async fn generate_chunk(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
         let embeddings_result = match self.provider.as_str() {
-            "openai" => self.generate_batch_openai(&uncached_texts).await,
-            "ollama" => self.generate_batch_ollama(&uncached_texts).await,
+            "openai" => self.generate_batch_openai(texts).await,
+            "ollama" => self.generate_batch_ollama(texts).await,
+            #[cfg(any(test, feature = "in-memory-store"))]
+            "hash" => Ok(texts.iter().map(|t| self.generate_hashed(t)).collect()),
             other => return Err(EmbeddingError::NotImplemented(other.to_string())),
         };
 
@@ -71,21 +98,22 @@ impl EmbeddingGenerator {
             Err(e) => {
                 debug!("Batch primary embedding failed, trying fallback: {}", e);
                 if self.fallback_enabled && self.provider != "ollama" {
-                    self.fallback_batch_to_ollama(&uncached_texts, &e).await?
+                    self.fallback_batch_to_ollama(texts, &e).await?
                 } else {
                     return Err(e);
                 }
             }
         };
 
-        for (idx, embedding) in uncached_indices.into_iter().zip(embeddings) {
-            if use_cache {
-                self.cache.set(texts[idx], embedding.clone());
-            }
-            results[idx] = Some(embedding);
+        let model = if self.is_using_fallback() {
+            &self.fallback_model
+        } else {
+            &self.model
+        };
+        for embedding in &embeddings {
+            self.check_dimension(embedding, model)?;
         }
-
-        Ok(results.into_iter().map(|r| r.unwrap()).collect())
+        Ok(embeddings)
     }
 
     async fn generate_batch_openai(
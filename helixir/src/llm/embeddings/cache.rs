@@ -15,12 +15,15 @@ use std::collections::HashMap;
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Mutex, RwLock};
 use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
+use super::quantize::{EmbeddingQuantization, Int8Vector};
+
 pub(super) struct CacheEntry {
     pub(super) embedding: Vec<f32>,
     pub(super) created_at: Instant,
@@ -28,28 +31,71 @@ pub(super) struct CacheEntry {
     pub(super) persistent: bool,
 }
 
+/// One persisted entry. Full-precision lines carry `e`; int8 lines carry
+/// `q` instead (see [`super::quantize`]). Both forms load either way.
 #[derive(Serialize, Deserialize)]
 struct DiskLine {
     m: String,
     t: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     e: Vec<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    q: Option<Int8Vector>,
+}
+
+impl DiskLine {
+    fn new(model: &str, text: &str, embedding: &[f32], quant: EmbeddingQuantization) -> Self {
+        let (e, q) = match quant {
+            EmbeddingQuantization::None => (embedding.to_vec(), None),
+            EmbeddingQuantization::Int8 => (Vec::new(), Some(Int8Vector::quantize(embedding))),
+        };
+        Self {
+            m: model.to_string(),
+            t: text.to_string(),
+            e,
+            q,
+        }
+    }
+
+    fn embedding(self) -> Vec<f32> {
+        match self.q {
+            Some(q) => q.dequantize(),
+            None => self.e,
+        }
+    }
+}
+
+/// Lookup counters and occupancy, reset by a clear.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EmbeddingCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub size: usize,
+    pub hit_rate: f64,
 }
 
 pub(super) struct EmbeddingCache {
     cache: RwLock<HashMap<String, CacheEntry>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
     max_size: usize,
     ttl: Duration,
     /// `(model, append handle)` when disk persistence is enabled.
     disk: Option<(String, Mutex<File>)>,
+    /// Encoding for newly persisted lines.
+    quantization: EmbeddingQuantization,
 }
 
 impl EmbeddingCache {
     pub(super) fn new(max_size: usize, ttl_secs: u64) -> Self {
         Self {
             cache: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
             max_size,
             ttl: Duration::from_secs(ttl_secs),
             disk: None,
+            quantization: EmbeddingQuantization::None,
         }
     }
 
@@ -59,6 +105,7 @@ impl EmbeddingCache {
         ttl_secs: u64,
         path: &Path,
         model: &str,
+        quantization: EmbeddingQuantization,
     ) -> Self {
         let mut map = HashMap::new();
         if let Ok(file) = File::open(path) {
@@ -67,16 +114,17 @@ impl EmbeddingCache {
                 if map.len() >= max_size {
                     break;
                 }
-                let Ok(parsed) = serde_json::from_str::<DiskLine>(&line) else {
+                let Ok(mut parsed) = serde_json::from_str::<DiskLine>(&line) else {
                     continue;
                 };
                 if parsed.m != model {
                     continue;
                 }
+                let text = std::mem::take(&mut parsed.t);
                 map.insert(
-                    parsed.t,
+                    text,
                     CacheEntry {
-                        embedding: parsed.e,
+                        embedding: parsed.embedding(),
                         created_at: Instant::now(),
                         persistent: true,
                     },
@@ -111,9 +159,12 @@ impl EmbeddingCache {
 
         Self {
             cache: RwLock::new(map),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
             max_size,
             ttl: Duration::from_secs(ttl_secs),
             disk,
+            quantization,
         }
     }
 
@@ -121,9 +172,11 @@ impl EmbeddingCache {
         let cache = self.cache.read().unwrap();
         if let Some(entry) = cache.get(text) {
             if entry.persistent || entry.created_at.elapsed() < self.ttl {
+                self.hits.fetch_add(1, Ordering::Relaxed);
                 return Some(entry.embedding.clone());
             }
         }
+        self.misses.fetch_add(1, Ordering::Relaxed);
         None
     }
 
@@ -134,11 +187,12 @@ impl EmbeddingCache {
                 cache.get(text).is_some_and(|e| e.persistent)
             };
             if !already_persisted {
-                let line = serde_json::to_string(&DiskLine {
-                    m: model.clone(),
-                    t: text.to_string(),
-                    e: embedding.clone(),
-                });
+                let line = serde_json::to_string(&DiskLine::new(
+                    model,
+                    text,
+                    &embedding,
+                    self.quantization,
+                ));
                 if let (Ok(line), Ok(mut f)) = (line, file.lock()) {
                     let _ = writeln!(f, "{line}");
                 }
@@ -165,11 +219,97 @@ impl EmbeddingCache {
         );
     }
 
-    pub(super) fn clear(&self) {
-        self.cache.write().unwrap().clear();
+    /// Drop every in-process entry and reset the counters. A persistence
+    /// file is left alone: its lines are scoped to the model that wrote
+    /// them, so a model change already ignores them on the next load.
+    pub(super) fn clear(&self) -> usize {
+        let mut cache = self.cache.write().unwrap();
+        let removed = cache.len();
+        cache.clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+        removed
+    }
+
+    pub(super) fn stats(&self) -> EmbeddingCacheStats {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        EmbeddingCacheStats {
+            hits,
+            misses,
+            size: self.len(),
+            hit_rate: if total > 0 {
+                hits as f64 / total as f64
+            } else {
+                0.0
+            },
+        }
     }
 
     pub(super) fn len(&self) -> usize {
         self.cache.read().unwrap().len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int8_persisted_lines_are_smaller_and_reload_close_to_the_original() {
+        let path = std::env::temp_dir().join(format!(
+            "helixir-embed-cache-{}-{}.jsonl",
+            std::process::id(),
+            uuid::Uuid::new_v4()
+        ));
+        let embedding: Vec<f32> = (0..768)
+            .map(|i| ((i * 37 % 101) as f32 - 50.0) / 997.0)
+            .collect();
+
+        let full = DiskLine::new("m", "t", &embedding, EmbeddingQuantization::None);
+        let int8 = DiskLine::new("m", "t", &embedding, EmbeddingQuantization::Int8);
+        let (full_len, int8_len) = (
+            serde_json::to_string(&full).unwrap().len(),
+            serde_json::to_string(&int8).unwrap().len(),
+        );
+        assert!(int8_len * 2 < full_len, "{int8_len} vs {full_len}");
+
+        let cache =
+            EmbeddingCache::with_persistence(10, 60, &path, "m", EmbeddingQuantization::Int8);
+        cache.set("hello", embedding.clone());
+        drop(cache);
+
+        // A reader with quantization off still loads int8 lines.
+        let reloaded =
+            EmbeddingCache::with_persistence(10, 60, &path, "m", EmbeddingQuantization::None);
+        let back = reloaded.get("hello").expect("persisted entry reloads");
+        let max_err = embedding
+            .iter()
+            .zip(&back)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0f32, f32::max);
+        assert!(max_err <= 0.05 / 127.0 + f32::EPSILON, "{max_err}");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn stats_count_lookups_and_clear_resets_them() {
+        let cache = EmbeddingCache::new(10, 60);
+        assert!(cache.get("a").is_none());
+        cache.set("a", vec![1.0]);
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("a").is_some());
+        assert_eq!(
+            cache.stats(),
+            EmbeddingCacheStats {
+                hits: 2,
+                misses: 1,
+                size: 1,
+                hit_rate: 2.0 / 3.0,
+            }
+        );
+        assert_eq!(cache.clear(), 1);
+        assert_eq!(cache.stats(), EmbeddingCacheStats::default());
+    }
+}
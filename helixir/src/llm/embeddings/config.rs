@@ -13,7 +13,9 @@ pub(super) const DEFAULT_FALLBACK_MODEL: &str = "nomic-embed-text";
 /// in `provider`). For `ollama` it is the Ollama host (e.g.
 /// `http://localhost:11434`); for `openai`-compatible providers it is the
 /// API root (e.g. `https://openrouter.ai/api/v1` or `https://api.openai.com/v1`).
-/// An empty string means "use the provider's built-in default".
+/// An empty string means "use the provider's built-in default". The
+/// offline `hash` provider (feature `in-memory-store` only) ignores
+/// `base_url`, `model` and `api_key`.
 #[derive(Debug, Clone)]
 pub struct EmbeddingConfig {
     pub provider: String,
@@ -26,4 +28,11 @@ pub struct EmbeddingConfig {
     pub fallback_enabled: bool,
     pub fallback_url: String,
     pub fallback_model: String,
+    /// Vector length every embedding must have. `None` adopts the length
+    /// of the first vector produced.
+    pub expected_dim: Option<usize>,
+    /// Most texts `generate_batch` sends in one provider request (one
+    /// concurrent fan-out for Ollama); larger inputs go out in consecutive
+    /// batches. 0 is treated as 1.
+    pub batch_size: usize,
 }
@@ -21,4 +21,13 @@ pub enum EmbeddingError {
 
     #[error("Both primary and fallback failed: primary={0}, fallback={1}")]
     BothFailed(String, String),
+
+    #[error(
+        "Embedding dimension mismatch: expected {expected}, got {actual} from model '{model}' — check HELIXIR_EMBEDDING_DIM against the configured embedding model"
+    )]
+    DimensionMismatch {
+        expected: usize,
+        actual: usize,
+        model: String,
+    },
 }
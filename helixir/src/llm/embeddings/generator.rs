@@ -9,8 +9,10 @@ use std::time::Duration;
 use reqwest::Client;
 use tracing::info;
 
-use super::cache::EmbeddingCache;
+use super::cache::{EmbeddingCache, EmbeddingCacheStats};
 use super::config::{DEFAULT_FALLBACK_MODEL, DEFAULT_FALLBACK_URL, EmbeddingConfig};
+use super::error::EmbeddingError;
+use super::quantize::EmbeddingQuantization;
 
 pub struct EmbeddingGenerator {
     pub(super) provider: String,
@@ -25,6 +27,9 @@ pub struct EmbeddingGenerator {
     pub(super) fallback_model: String,
     pub(super) using_fallback: AtomicBool,
     pub(super) fallback_count: AtomicUsize,
+    /// Required vector length; 0 until configured or detected.
+    pub(super) expected_dim: AtomicUsize,
+    pub(super) batch_size: usize,
 }
 
 impl EmbeddingGenerator {
@@ -57,6 +62,7 @@ impl EmbeddingGenerator {
                 config.cache_ttl,
                 std::path::Path::new(path.trim()),
                 &model,
+                EmbeddingQuantization::from_env(),
             ),
             _ => EmbeddingCache::new(config.cache_size, config.cache_ttl),
         };
@@ -76,6 +82,53 @@ impl EmbeddingGenerator {
             fallback_model,
             using_fallback: AtomicBool::new(false),
             fallback_count: AtomicUsize::new(0),
+            expected_dim: AtomicUsize::new(config.expected_dim.unwrap_or(0)),
+            batch_size: config.batch_size.max(1),
+        }
+    }
+
+    /// Reject a freshly produced vector whose length differs from the
+    /// expected dimension. With none configured, the first vector seen sets
+    /// it, so a mid-run model swap (or a fallback model of another size) is
+    /// caught before its vectors reach the store or the cache.
+    pub(super) fn check_dimension(
+        &self,
+        embedding: &[f32],
+        model: &str,
+    ) -> Result<(), EmbeddingError> {
+        let actual = embedding.len();
+        let expected =
+            match self
+                .expected_dim
+                .compare_exchange(0, actual, Ordering::SeqCst, Ordering::SeqCst)
+            {
+                Ok(_) => {
+                    info!("Embedding dimension detected: {actual} (model {model})");
+                    return Ok(());
+                }
+                Err(expected) => expected,
+            };
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(EmbeddingError::DimensionMismatch {
+                expected,
+                actual,
+                model: model.to_string(),
+            })
+        }
+    }
+
+    /// Texts per provider request on the batched path.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// The dimension vectors are validated against, once known.
+    pub fn dimension(&self) -> Option<usize> {
+        match self.expected_dim.load(Ordering::SeqCst) {
+            0 => None,
+            n => Some(n),
         }
     }
 
@@ -106,9 +159,15 @@ impl EmbeddingGenerator {
         self.cache.len()
     }
 
-    pub fn clear_cache(&self) {
-        self.cache.clear();
-        info!("Embedding cache cleared");
+    /// Empty the embedding cache; returns how many entries it held.
+    pub fn clear_cache(&self) -> usize {
+        let removed = self.cache.clear();
+        info!("Embedding cache cleared ({removed} entries)");
+        removed
+    }
+
+    pub fn cache_stats(&self) -> EmbeddingCacheStats {
+        self.cache.stats()
     }
 
     pub fn reset_fallback_state(&self) {
@@ -0,0 +1,79 @@
+//! `hash` provider: an offline, deterministic embedding for the
+//! [`crate::db::InMemoryStore`] test harness, compiled only with it (feature
+//! `in-memory-store`) so no release config can select it. Each lowercased
+//! word is hashed into one of `dim` buckets with a hash-derived sign, and
+//! the vector is L2-normalised, so texts sharing words score a cosine above
+//! zero and identical texts score 1. It knows nothing about meaning.
+
+use std::hash::{Hash, Hasher};
+
+use super::generator::EmbeddingGenerator;
+
+/// Vector length when no `expected_dim` is configured.
+pub(super) const DEFAULT_HASH_DIM: usize = 256;
+
+impl EmbeddingGenerator {
+    pub(super) fn generate_hashed(&self, text: &str) -> Vec<f32> {
+        hash_embedding(text, self.dimension().unwrap_or(DEFAULT_HASH_DIM))
+    }
+}
+
+pub(super) fn hash_embedding(text: &str, dim: usize) -> Vec<f32> {
+    let mut v = vec![0.0f32; dim.max(1)];
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        // FNV-1a: stable across processes, unlike the std RandomState.
+        let mut h = Fnv1a::default();
+        word.to_lowercase().hash(&mut h);
+        let bits = h.finish();
+        let bucket = (bits % v.len() as u64) as usize;
+        v[bucket] += if bits >> 63 == 0 { 1.0 } else { -1.0 };
+    }
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        v.iter_mut().for_each(|x| *x /= norm);
+    }
+    v
+}
+
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= u64::from(*b);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::cosine_similarity as cosine;
+
+    #[test]
+    fn deterministic_normalised_and_overlap_sensitive() {
+        let a = hash_embedding("The kappa queue feeds aurora", 64);
+        assert_eq!(a, hash_embedding("the KAPPA queue feeds aurora!", 64));
+        assert_eq!(a.len(), 64);
+        assert!((cosine(&a, &a) - 1.0).abs() < 1e-5);
+
+        let near = hash_embedding("aurora reads the kappa queue", 64);
+        let far = hash_embedding("lunch menu for friday", 64);
+        assert!(cosine(&a, &near) > cosine(&a, &far));
+        assert!(hash_embedding("   ", 8).iter().all(|x| *x == 0.0));
+    }
+}
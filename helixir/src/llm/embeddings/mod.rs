@@ -1,12 +1,18 @@
 //! Embedding generation: cache-fronted HTTP client over Ollama / OpenAI-compatible
 //! providers, with optional Ollama fallback when the primary endpoint fails.
+//! The offline `hash` provider (feature `in-memory-store`) needs no endpoint
+//! at all.
 //!
 //! Layout:
 //! - [`config`]    — [`EmbeddingConfig`] + provider defaults.
 //! - [`error`]     — [`EmbeddingError`].
 //! - [`wire`]      — request/response DTOs for Ollama and OpenAI batch/single.
 //! - [`cache`]     — in-process LRU+TTL embedding cache.
+//! - [`quantize`]  — int8 scalar quantization for persisted cache lines
+//!   (HelixDB's stored vectors stay full precision).
 //! - [`generator`] — [`EmbeddingGenerator`] struct, constructor, accessors.
+//! - [`hashed`]    — the `hash` provider (deterministic feature hashing; test
+//!   harness only, feature `in-memory-store`).
 //! - [`single`]    — `generate` (one text) + provider routing + single-shot fallback.
 //! - [`batch`]     — `generate_batch` (many texts) + batched provider routing
 //!   + per-item fallback fan-out.
@@ -16,9 +22,13 @@ mod cache;
 mod config;
 mod error;
 mod generator;
+#[cfg(any(test, feature = "in-memory-store"))]
+mod hashed;
+pub mod quantize;
 mod single;
 mod wire;
 
+pub use cache::EmbeddingCacheStats;
 pub use config::EmbeddingConfig;
 pub use error::EmbeddingError;
 pub use generator::EmbeddingGenerator;
@@ -39,6 +49,8 @@ mod tests {
             fallback_enabled: false,
             fallback_url: String::new(),
             fallback_model: String::new(),
+            expected_dim: None,
+            batch_size: 64,
         }
     }
 
@@ -54,6 +66,8 @@ mod tests {
             fallback_enabled: true,
             fallback_url: "http://localhost:11434".into(),
             fallback_model: "nomic-embed-text".into(),
+            expected_dim: None,
+            batch_size: 64,
         }
     }
 
@@ -98,4 +112,108 @@ mod tests {
         let generator = EmbeddingGenerator::new(cfg);
         assert_eq!(generator.provider(), "ollama");
     }
+
+    #[test]
+    fn dimension_is_detected_then_enforced() {
+        let generator = EmbeddingGenerator::new(ollama_cfg());
+        assert_eq!(generator.dimension(), None);
+        generator.check_dimension(&[0.1; 4], "m").unwrap();
+        assert_eq!(generator.dimension(), Some(4));
+        generator.check_dimension(&[0.2; 4], "m").unwrap();
+        let err = generator.check_dimension(&[0.1; 3], "other").unwrap_err();
+        assert!(matches!(
+            err,
+            EmbeddingError::DimensionMismatch {
+                expected: 4,
+                actual: 3,
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("expected 4, got 3"));
+
+        let mut cfg = ollama_cfg();
+        cfg.expected_dim = Some(768);
+        let pinned = EmbeddingGenerator::new(cfg);
+        assert_eq!(pinned.dimension(), Some(768));
+        assert!(pinned.check_dimension(&[0.0; 4], "m").is_err());
+    }
+
+    /// A one-route OpenAI-compatible `/embeddings` stub: answers every
+    /// request with one 3-dimensional vector per input and records the
+    /// batch sizes it saw.
+    async fn embeddings_stub() -> (String, std::sync::Arc<parking_lot::Mutex<Vec<usize>>>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let seen = std::sync::Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let log = std::sync::Arc::clone(&seen);
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let log = std::sync::Arc::clone(&log);
+                tokio::spawn(async move {
+                    let mut buf = Vec::new();
+                    let mut chunk = [0u8; 4096];
+                    let body = loop {
+                        let n = socket.read(&mut chunk).await.unwrap();
+                        buf.extend_from_slice(&chunk[..n]);
+                        let text = String::from_utf8_lossy(&buf).to_string();
+                        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                            let len = head
+                                .lines()
+                                .find_map(|l| {
+                                    l.to_lowercase()
+                                        .strip_prefix("content-length:")
+                                        .map(|v| v.trim().parse::<usize>().unwrap())
+                                })
+                                .unwrap_or(0);
+                            if body.len() >= len {
+                                break body.to_string();
+                            }
+                        }
+                    };
+                    let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+                    let inputs = request["input"].as_array().unwrap().len();
+                    log.lock().push(inputs);
+                    let data: Vec<_> = (0..inputs)
+                        .map(|i| serde_json::json!({"index": i, "embedding": [i as f32, 1.0, 0.0]}))
+                        .collect();
+                    let payload = serde_json::json!({ "data": data }).to_string();
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{payload}",
+                        payload.len()
+                    );
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                });
+            }
+        });
+        (url, seen)
+    }
+
+    #[tokio::test]
+    async fn batches_are_split_by_batch_size() {
+        let (url, seen) = embeddings_stub().await;
+        let mut cfg = openai_cfg();
+        cfg.base_url = url;
+        cfg.fallback_enabled = false;
+        cfg.batch_size = 4;
+        let generator = EmbeddingGenerator::new(cfg);
+
+        let texts: Vec<String> = (0..10).map(|i| format!("chunk {i}")).collect();
+        let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+        let vectors = generator.generate_batch(&refs, true).await.unwrap();
+        assert_eq!(vectors.len(), 10);
+        // Order survives the split: chunk 5 is the second item of batch two.
+        assert_eq!(vectors[5][0], 1.0);
+        assert_eq!(*seen.lock(), vec![4, 4, 2], "10 texts, 3 round trips");
+
+        // Cached texts are not re-sent; only the new ones form batches.
+        let more: Vec<&str> = refs
+            .iter()
+            .copied()
+            .chain(["chunk 10", "chunk 11"])
+            .collect();
+        generator.generate_batch(&more, true).await.unwrap();
+        assert_eq!(*seen.lock(), vec![4, 4, 2, 2]);
+    }
 }
@@ -0,0 +1,141 @@
+//! Scalar quantization of the persisted embedding cache
+//! (`HELIXIR_EMBED_CACHE_QUANTIZATION`).
+//!
+//! `int8` maps each vector onto 255 symmetric levels scaled by its own
+//! max-abs component: one `f32` scale plus one `i8` per dimension. Cosine
+//! similarity against the original stays above 0.9999 on 768-d vectors and
+//! top-10 neighbour recall at or above 0.95 on the synthetic corpus below.
+//!
+//! Only the cache file (`HELIXIR_EMBED_CACHE_PATH`) is quantized — it is the
+//! one place Helixir owns the bytes. Vectors stored in HelixDB stay full
+//! precision: its vector index takes `[F64]` and has no compact element
+//! type, so there is nothing to write quantized and nothing for
+//! `reindex_embeddings` to migrate.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmbeddingQuantization {
+    #[default]
+    None,
+    Int8,
+}
+
+impl EmbeddingQuantization {
+    /// Parse the env value; anything unrecognised reads as `None`.
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "int8" => Self::Int8,
+            _ => Self::None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        std::env::var("HELIXIR_EMBED_CACHE_QUANTIZATION")
+            .map(|v| Self::from_name(&v))
+            .unwrap_or_default()
+    }
+}
+
+/// An int8-quantized vector: component `i` is `codes[i] as f32 * scale`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Int8Vector {
+    pub scale: f32,
+    pub codes: Vec<i8>,
+}
+
+impl Int8Vector {
+    pub fn quantize(vector: &[f32]) -> Self {
+        let max_abs = vector.iter().fold(0.0f32, |m, x| m.max(x.abs()));
+        if max_abs == 0.0 || !max_abs.is_finite() {
+            return Self {
+                scale: 0.0,
+                codes: vec![0; vector.len()],
+            };
+        }
+        let scale = max_abs / 127.0;
+        let codes = vector
+            .iter()
+            .map(|x| (x / scale).round().clamp(-127.0, 127.0) as i8)
+            .collect();
+        Self { scale, codes }
+    }
+
+    pub fn dequantize(&self) -> Vec<f32> {
+        self.codes.iter().map(|&c| c as f32 * self.scale).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::cosine_similarity as cosine;
+
+    /// Deterministic pseudo-random unit-ish vectors (no rand dependency).
+    fn vectors(seed: u64, n: usize, dim: usize) -> Vec<Vec<f32>> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                (0..dim)
+                    .map(|_| {
+                        state = state
+                            .wrapping_mul(6_364_136_223_846_793_005)
+                            .wrapping_add(1_442_695_040_888_963_407);
+                        ((state >> 33) as f32 / u32::MAX as f32) - 0.25
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn int8_round_trip_keeps_direction_and_neighbour_order() {
+        let corpus = vectors(0x9E37_79B9_7F4A_7C15, 128, 768);
+        for v in &corpus {
+            let back = Int8Vector::quantize(v).dequantize();
+            assert!(cosine(v, &back) > 0.9999);
+        }
+
+        // Recall impact: top-10 neighbours of each query against the
+        // quantized corpus vs the original one.
+        let quantized: Vec<Vec<f32>> = corpus
+            .iter()
+            .map(|v| Int8Vector::quantize(v).dequantize())
+            .collect();
+        let top = |set: &[Vec<f32>], q: &[f32]| {
+            let mut idx: Vec<usize> = (0..set.len()).collect();
+            idx.sort_by(|&a, &b| cosine(q, &set[b]).total_cmp(&cosine(q, &set[a])));
+            idx.truncate(10);
+            idx
+        };
+        let queries = vectors(0x2545_F491_4F6C_DD1D, 32, 768);
+        let mut overlap = 0;
+        for q in &queries {
+            let exact = top(&corpus, q);
+            overlap += top(&quantized, q)
+                .iter()
+                .filter(|i| exact.contains(i))
+                .count();
+        }
+        let recall = overlap as f32 / (queries.len() * 10) as f32;
+        assert!(recall >= 0.95, "recall@10 {recall}");
+    }
+
+    #[test]
+    fn zero_vectors_and_env_names_are_handled() {
+        let z = Int8Vector::quantize(&[0.0, 0.0]);
+        assert_eq!(z.dequantize(), vec![0.0, 0.0]);
+        assert_eq!(
+            EmbeddingQuantization::from_name(" INT8 "),
+            EmbeddingQuantization::Int8
+        );
+        assert_eq!(
+            EmbeddingQuantization::from_name("none"),
+            EmbeddingQuantization::None
+        );
+        assert_eq!(
+            EmbeddingQuantization::from_name("fp4"),
+            EmbeddingQuantization::None
+        );
+    }
+}
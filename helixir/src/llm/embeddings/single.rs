@@ -25,11 +25,14 @@ impl EmbeddingGenerator {
         let result = match self.provider.as_str() {
             "ollama" => self.generate_ollama(text).await,
             "openai" => self.generate_openai(text).await,
+            #[cfg(any(test, feature = "in-memory-store"))]
+            "hash" => Ok(self.generate_hashed(text)),
             other => Err(EmbeddingError::NotImplemented(other.to_string())),
         };
 
         match result {
             Ok(embedding) => {
+                self.check_dimension(&embedding, &self.model)?;
                 if use_cache {
                     self.cache.set(text, embedding.clone());
                 }
@@ -133,6 +136,7 @@ impl EmbeddingGenerator {
             .map_err(|e| EmbeddingError::BothFailed(original_error.to_string(), e.to_string()))?;
 
         let embedding = response.embedding;
+        self.check_dimension(&embedding, &self.fallback_model)?;
 
         if use_cache {
             self.cache.set(text, embedding.clone());
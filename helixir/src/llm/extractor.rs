@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
 
+use super::prompt_template::{DEFAULT_EXTRACTION_TEMPLATE, PromptTemplate};
 use super::providers::base::{LlmProvider, LlmProviderError};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -177,12 +178,24 @@ fn default_confidence() -> i32 {
 
 pub struct LlmExtractor<P: LlmProvider> {
     provider: P,
+    template: PromptTemplate,
 }
 
 impl<P: LlmProvider> LlmExtractor<P> {
     #[must_use]
     pub fn new(provider: P) -> Self {
-        Self { provider }
+        Self {
+            provider,
+            template: PromptTemplate::builtin(DEFAULT_EXTRACTION_TEMPLATE),
+        }
+    }
+
+    /// Wrap the input in `template` instead of the built-in user prompt.
+    /// The output-schema system prompt is unchanged.
+    #[must_use]
+    pub fn with_template(mut self, template: PromptTemplate) -> Self {
+        self.template = template;
+        self
     }
 
     pub async fn extract(
@@ -199,7 +212,7 @@ impl<P: LlmProvider> LlmExtractor<P> {
         );
 
         let system_prompt = self.build_system_prompt(extract_entities, extract_relations);
-        let user_prompt = format!("Extract information from this text:\n\n{}", text);
+        let user_prompt = self.template.render(text);
 
         let (response, _metadata) = self
             .provider
@@ -292,7 +305,11 @@ impl<P: LlmProvider> LlmExtractor<P> {
         }
     }
 
-    fn build_system_prompt(&self, extract_entities: bool, extract_relations: bool) -> String {
+    pub(crate) fn build_system_prompt(
+        &self,
+        extract_entities: bool,
+        extract_relations: bool,
+    ) -> String {
         let mut prompt = String::from(
             r#"You are a memory extraction system. Analyze the text and extract structured information.
 
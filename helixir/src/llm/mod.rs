@@ -6,11 +6,14 @@ pub mod extractor;
 pub mod factory;
 #[cfg(feature = "nli")]
 pub mod nli;
+pub mod pricing;
+pub mod prompt_template;
 pub mod providers;
 
 pub use decision::{LLMDecisionEngine, MemoryDecision, MemoryOperation, SimilarMemory};
 
-pub use embeddings::{EmbeddingConfig, EmbeddingGenerator};
+pub use embeddings::{EmbeddingCacheStats, EmbeddingConfig, EmbeddingGenerator};
 pub use extractor::LlmExtractor;
 pub use factory::LlmProviderFactory;
+pub use prompt_template::PromptTemplate;
 pub use providers::base::LlmProvider;
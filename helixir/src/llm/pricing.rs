@@ -0,0 +1,88 @@
+//! Offline cost estimation for LLM calls: a character-based token
+//! approximation and a lookup in the configured list-price table
+//! (`llm_runtime.pricing`, shipped with the models Helixir defaults to).
+//! Both are deliberately rough — they let a caller budget an ingestion
+//! before paying for it, they are not a bill.
+
+use crate::core::config::ModelPrice;
+
+/// USD per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+}
+
+impl ModelPricing {
+    pub fn cost_usd(&self, input_tokens: usize, output_tokens: usize) -> f64 {
+        (input_tokens as f64 * self.input_per_mtok + output_tokens as f64 * self.output_per_mtok)
+            / 1_000_000.0
+    }
+}
+
+/// Pricing for `provider`/`model` in `table` (`llm_runtime.pricing`),
+/// `None` when the model is not listed. Local Ollama models are free.
+pub fn pricing_for(table: &[ModelPrice], provider: &str, model: &str) -> Option<ModelPricing> {
+    if provider.eq_ignore_ascii_case("ollama") {
+        return Some(ModelPricing {
+            input_per_mtok: 0.0,
+            output_per_mtok: 0.0,
+        });
+    }
+    table
+        .iter()
+        .find(|p| p.provider.eq_ignore_ascii_case(provider) && p.model.eq_ignore_ascii_case(model))
+        .map(|p| ModelPricing {
+            input_per_mtok: p.input_per_mtok,
+            output_per_mtok: p.output_per_mtok,
+        })
+}
+
+/// Approximate BPE token count without a tokenizer: ~4 chars per token for
+/// ASCII text, ~2 for everything else (Cyrillic, CJK and accented text
+/// split into more tokens per character).
+pub fn approx_tokens(text: &str) -> usize {
+    let (ascii, other) = text.chars().fold((0usize, 0usize), |(a, o), c| {
+        if c.is_ascii() { (a + 1, o) } else { (a, o + 1) }
+    });
+    ascii.div_ceil(4) + other.div_ceil(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_tokens_weighs_non_ascii_heavier() {
+        assert_eq!(approx_tokens(""), 0);
+        assert_eq!(approx_tokens("abcdefgh"), 2);
+        assert_eq!(approx_tokens("привет"), 3);
+    }
+
+    #[test]
+    fn pricing_lookup_is_case_insensitive_and_ollama_is_free() {
+        let table = crate::core::config::LlmRuntimeConfig::default().pricing;
+        let p = pricing_for(&table, "Cerebras", "GPT-OSS-120B").expect("shipped default is priced");
+        assert!((p.cost_usd(1_000_000, 0) - 0.35).abs() < 1e-9);
+        assert_eq!(
+            pricing_for(&table, "ollama", "qwen2.5:7b")
+                .unwrap()
+                .cost_usd(5000, 5000),
+            0.0
+        );
+        assert!(pricing_for(&table, "cerebras", "unknown-model").is_none());
+    }
+
+    #[test]
+    fn a_configured_table_replaces_the_shipped_prices() {
+        let table = vec![ModelPrice {
+            provider: "openai".to_string(),
+            model: "gpt-4o-mini".to_string(),
+            input_per_mtok: 0.15,
+            output_per_mtok: 0.60,
+        }];
+        let p = pricing_for(&table, "openai", "gpt-4o-mini").expect("configured model");
+        assert!((p.cost_usd(0, 1_000_000) - 0.60).abs() < 1e-9);
+        assert!(pricing_for(&table, "cerebras", "gpt-oss-120b").is_none());
+    }
+}
@@ -0,0 +1,123 @@
+//! Operator-supplied LLM prompt templates (`HELIXIR_EXTRACTION_PROMPT_PATH`,
+//! `HELIXIR_RELATION_PROMPT_PATH`): domain wording — legal, medical, an
+//! in-house glossary — wrapped around the input the pipeline sends. The
+//! output-schema system prompts stay built in, so a custom template cannot
+//! break response parsing.
+//!
+//! A template must contain [`TEXT_PLACEHOLDER`]; a configured file that is
+//! missing, unreadable or lacks the placeholder is a startup error rather
+//! than a silent fall back to the built-in template.
+
+use tracing::info;
+
+/// Where the input text is spliced into a template.
+pub const TEXT_PLACEHOLDER: &str = "{text}";
+
+/// Built-in fact-extraction user prompt.
+pub const DEFAULT_EXTRACTION_TEMPLATE: &str = "Extract information from this text:\n\n{text}";
+
+/// Built-in relation-inference user prompt: the NEW/EXISTING listing as is.
+pub const DEFAULT_RELATION_TEMPLATE: &str = "{text}";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptTemplate(String);
+
+impl PromptTemplate {
+    /// Validate a template body: non-blank and carrying [`TEXT_PLACEHOLDER`].
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        if raw.trim().is_empty() {
+            return Err("template is empty".to_string());
+        }
+        if !raw.contains(TEXT_PLACEHOLDER) {
+            return Err(format!(
+                "template has no {TEXT_PLACEHOLDER} placeholder for the input text"
+            ));
+        }
+        Ok(Self(raw.to_string()))
+    }
+
+    pub fn builtin(template: &'static str) -> Self {
+        Self(template.to_string())
+    }
+
+    /// Read and validate the template at `path`.
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let raw = std::fs::read_to_string(path).map_err(|e| format!("cannot read {path}: {e}"))?;
+        Self::parse(&raw).map_err(|e| format!("{path}: {e}"))
+    }
+
+    /// The template at `path`, or `default` when no path is configured. A
+    /// configured file that does not load is an error naming `what` prompt.
+    pub fn load(path: Option<&str>, default: &'static str, what: &str) -> Result<Self, String> {
+        let Some(path) = path.map(str::trim).filter(|p| !p.is_empty()) else {
+            return Ok(Self::builtin(default));
+        };
+        let template = Self::from_file(path).map_err(|e| format!("custom {what} prompt: {e}"))?;
+        info!("Using custom {what} prompt from {path}");
+        Ok(template)
+    }
+
+    /// Every placeholder replaced by `text`.
+    pub fn render(&self, text: &str) -> String {
+        self.0.replace(TEXT_PLACEHOLDER, text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholder_is_required_and_rendered() {
+        assert!(PromptTemplate::parse("  ").is_err());
+        let err = PromptTemplate::parse("Extract legal facts.").unwrap_err();
+        assert!(err.contains("{text}"), "{err}");
+
+        let t = PromptTemplate::parse("Extract holdings and parties from:\n{text}").unwrap();
+        assert_eq!(
+            t.render("Smith v. Jones"),
+            "Extract holdings and parties from:\nSmith v. Jones"
+        );
+        assert_eq!(
+            PromptTemplate::builtin(DEFAULT_EXTRACTION_TEMPLATE).render("x"),
+            "Extract information from this text:\n\nx"
+        );
+    }
+
+    #[test]
+    fn a_configured_file_that_does_not_load_is_an_error() {
+        let default = PromptTemplate::builtin(DEFAULT_EXTRACTION_TEMPLATE);
+        assert_eq!(
+            PromptTemplate::load(None, DEFAULT_EXTRACTION_TEMPLATE, "extraction"),
+            Ok(default.clone())
+        );
+        assert_eq!(
+            PromptTemplate::load(Some("  "), DEFAULT_EXTRACTION_TEMPLATE, "extraction"),
+            Ok(default)
+        );
+        let err = PromptTemplate::load(
+            Some("/nonexistent/helixir-prompt.txt"),
+            DEFAULT_EXTRACTION_TEMPLATE,
+            "extraction",
+        )
+        .unwrap_err();
+        assert!(
+            err.starts_with("custom extraction prompt: cannot read"),
+            "{err}"
+        );
+
+        let dir = std::env::temp_dir().join(format!("helixir-prompt-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let bad = dir.join("bad.txt");
+        std::fs::write(&bad, "no placeholder here").unwrap();
+        let err =
+            PromptTemplate::load(bad.to_str(), DEFAULT_RELATION_TEMPLATE, "relation").unwrap_err();
+        assert!(err.contains("{text}"), "{err}");
+        let good = dir.join("good.txt");
+        std::fs::write(&good, "Medical note; keep dosages:\n{text}").unwrap();
+        let loaded =
+            PromptTemplate::load(good.to_str(), DEFAULT_EXTRACTION_TEMPLATE, "extraction").unwrap();
+        assert_eq!(loaded.render("5mg"), "Medical note; keep dosages:\n5mg");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
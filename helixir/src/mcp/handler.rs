@@ -4,15 +4,21 @@
 
 use rmcp::{
     ErrorData as McpError, RoleServer, ServerHandler, handler::server::wrapper::Parameters,
-    model::*, prompt, prompt_handler, prompt_router, service::RequestContext, tool_handler,
+    model::*, prompt, prompt_handler, prompt_router, service::RequestContext,
 };
 use serde_json::json;
 use tokio::sync::broadcast;
 
+use super::machine_output;
 use super::params::*;
 use super::prompts;
+use super::response_limit;
 use super::server::HelixirMcpServer;
 
+/// `config://helixir/user/{user_id}`: one user's overrides and effective
+/// config.
+const USER_CONFIG_URI_PREFIX: &str = "config://helixir/user/";
+
 impl HelixirMcpServer {
     /// Wrapper exposing the macro-generated `prompt_router()` across modules.
     /// Mirrors [`HelixirMcpServer::build_tool_router`] — `#[prompt_router]` emits
@@ -64,7 +70,8 @@ Provide a summary with:
         description = "Cognitive protocol for AI agents with persistent memory"
     )]
     async fn tool_selection_guide(&self) -> Result<GetPromptResult, McpError> {
-        let guide = prompts::get_cognitive_protocol();
+        let client = self.client();
+        let guide = prompts::get_cognitive_protocol(client.tooling().locale());
 
         let messages = vec![PromptMessage::new_text(
             PromptMessageRole::Assistant,
@@ -78,9 +85,46 @@ Provide a summary with:
     }
 }
 
-#[tool_handler]
+// Tool dispatch is written out (rather than `#[tool_handler]`) so results
+// can pass through the machine-output rewrite and the response size cap.
 #[prompt_handler]
 impl ServerHandler for HelixirMcpServer {
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        if self.disabled_tools.iter().any(|t| *t == request.name) {
+            return Err(McpError::new(
+                ErrorCode::METHOD_NOT_FOUND,
+                format!("tool '{}' is not enabled on this server", request.name),
+                None,
+            ));
+        }
+        let tcc = rmcp::handler::server::tool::ToolCallContext::new(self, request, context);
+        let mut result = self
+            .tool_router
+            .call(tcc)
+            .await
+            .map_err(Self::ensure_error_code)?;
+        let client = self.client();
+        if client.config().machine_output {
+            machine_output::normalize(&mut result);
+        }
+        if let Some(max_bytes) = client.config().max_response_bytes {
+            response_limit::cap(&mut result, max_bytes);
+        }
+        Ok(result)
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult::with_all_items(self.tool_router.list_all()))
+    }
+
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: ProtocolVersion::V_2024_11_05,
@@ -94,7 +138,9 @@ impl ServerHandler for HelixirMcpServer {
                 version: env!("CARGO_PKG_VERSION").into(),
                 ..Default::default()
             },
-            instructions: Some(prompts::get_server_instructions()),
+            instructions: Some(prompts::get_server_instructions(
+                self.client().tooling().locale(),
+            )),
         }
     }
 
@@ -148,6 +194,28 @@ impl ServerHandler for HelixirMcpServer {
         })
     }
 
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _ctx: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult {
+            resource_templates: vec![
+                RawResourceTemplate {
+                    uri_template: format!("{USER_CONFIG_URI_PREFIX}{{user_id}}"),
+                    name: "helixir-user-config".to_string(),
+                    title: None,
+                    description: Some(
+                        "One user's config overrides and the effective search settings".to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                }
+                .no_annotation(),
+            ],
+            next_cursor: None,
+        })
+    }
+
     async fn read_resource(
         &self,
         ReadResourceRequestParam { uri }: ReadResourceRequestParam,
@@ -157,6 +225,7 @@ impl ServerHandler for HelixirMcpServer {
             "config://helixir" => {
                 let client = self.client();
                 let config = client.config();
+                let decay_days = config.search_thresholds.default_temporal_days;
 
                 let content = serde_json::to_string_pretty(&json!({
                     "version": env!("CARGO_PKG_VERSION"),
@@ -170,6 +239,23 @@ impl ServerHandler for HelixirMcpServer {
                         "model": config.llm_model,
                     },
                     "retrieval_profile": crate::core::RetrievalProfile::cached().tag(),
+                    "lang": client.tooling().locale().lang(),
+                    "score_normalization": config.retrieval.score_normalization,
+                    // Active mode windows, so a prompt quoting a window can
+                    // check itself against the operator's override. An
+                    // unset mode decays over the global default.
+                    "search_windows": {
+                        "recent_hours": config.windows.recent_hours.unwrap_or(decay_days * 24.0),
+                        "contextual_days": config.windows.contextual_days.unwrap_or(decay_days),
+                        "deep_days": config.windows.deep_days.unwrap_or(decay_days),
+                        "full": "all time",
+                    },
+                    // Global values; a user's overrides (set_user_config)
+                    // apply on top and are read per user.
+                    "user_config": {
+                        "resource": format!("{USER_CONFIG_URI_PREFIX}{{user_id}}"),
+                        "precedence": super::server::USER_CONFIG_PRECEDENCE,
+                    },
                     "capabilities": {
                         "memory_management": true,
                         "hybrid_search_bm25_rrf": true,
@@ -186,25 +272,65 @@ impl ServerHandler for HelixirMcpServer {
                         "hive_stances": true,
                         "self_seed": true,
                     },
-                    "tools": [
+                    "tools": ([
                         "add_memory",
                         "get_add_status",
+                        "estimate_add",
+                        "should_remember",
+                        "compare_search_modes",
+                        "import_memories",
+                        "capabilities",
                         "search_memory",
                         "search_by_concept",
                         "search_reasoning_chain",
+                        "get_goals",
                         "connect_memories",
                         "get_memory_graph",
+                        "get_memory",
+                        "get_conclusion_sources",
+                        "why_memory",
+                        "get_entity",
+                        "set_entity_attribute",
                         "update_memory",
+                        "append_memory",
+                        "correct_memory",
+                        "summarize_recent",
+                        "find_stale",
+                        "cluster_memories",
+                        "get_user_config",
+                        "set_user_config",
                         "list_memories",
+                        "purge_user",
+                        "rebuild_relations",
+                        "reindex_embeddings",
+                        "cache_stats",
+                        "clear_cache",
+                        "self_test",
                         "think_start",
+                        "continue_incomplete",
+                        "think_import_incomplete",
+                        "think_branch",
                         "think_add",
                         "think_recall",
                         "think_conclude",
                         "think_commit",
+                        "think_uncommit",
                         "think_discard",
                         "think_status",
+                        "think_list",
+                        "think_snapshot_all",
+                        "think_restore_all",
+                        "think_note",
+                        "think_promote_note",
+                        "think_entities",
+                        "think_template",
+                        "think_adjust_certainty",
+                        "think_search",
                         "search_incomplete_thoughts",
-                    ],
+                    ]
+                    .into_iter()
+                    .filter(|t| self.tool_router.has_route(t))
+                    .collect::<Vec<_>>()),
                     "notes": {
                         "needs_clarification": "add_memory responses may carry charter escalations the agent should surface to the user",
                         "charter": "memory-charter.md — conflicts the engine never resolves silently",
@@ -245,12 +371,16 @@ impl ServerHandler for HelixirMcpServer {
             "status://helixdb" => {
                 let client = self.client();
                 let config = client.config();
+                let pool = client.db().pool_health();
 
                 let content = serde_json::to_string_pretty(&json!({
-                    "status": "connected",
+                    // Every connection's last query failed in transit: the
+                    // next ones reconnect, but the database looks down.
+                    "status": if pool.failed == pool.size { "reconnecting" } else { "connected" },
                     "host": config.host,
                     "port": config.port,
                     "instance": config.instance,
+                    "pool": pool,
                 }))
                 .unwrap_or_default();
 
@@ -258,6 +388,23 @@ impl ServerHandler for HelixirMcpServer {
                     contents: vec![ResourceContents::text(content, uri)],
                 })
             }
+            other if other.starts_with(USER_CONFIG_URI_PREFIX) => {
+                let user_id = &other[USER_CONFIG_URI_PREFIX.len()..];
+                let client = self.client();
+                let overrides = client
+                    .user_config(user_id)
+                    .await
+                    .map_err(Self::convert_error)?;
+                let content = serde_json::to_string_pretty(&Self::user_config_report(
+                    client.config(),
+                    user_id,
+                    &overrides,
+                ))
+                .unwrap_or_default();
+                Ok(ReadResourceResult {
+                    contents: vec![ResourceContents::text(content, uri)],
+                })
+            }
             _ => Err(McpError::resource_not_found(
                 format!("Unknown resource: {}", uri),
                 Some(json!({ "uri": uri })),
@@ -0,0 +1,124 @@
+//! Machine output mode (`HELIXIR_MACHINE_OUTPUT=true`): tool results shaped
+//! for programs rather than people. Every prose field that has a stable code
+//! next to it (`message` + `message_code`, `note` + `note_code`, ...) loses
+//! the prose, and emoji are stripped from whatever prose is left. Structured
+//! fields — and stored memory content — are never touched.
+
+use rmcp::model::{CallToolResult, RawContent};
+use serde_json::Value;
+
+/// Keys whose string values are prose written for a human reader. A key
+/// ending in `_warning` counts too (e.g. `edge_type_warning`).
+const PROSE_KEYS: &[&str] = &["message", "note", "hint", "warning"];
+
+fn is_prose_key(key: &str) -> bool {
+    PROSE_KEYS.contains(&key) || key.ends_with("_warning")
+}
+
+/// Rewrite every JSON text block of `result` in place. Non-JSON blocks only
+/// get their emoji stripped.
+pub(super) fn normalize(result: &mut CallToolResult) {
+    for content in &mut result.content {
+        let RawContent::Text(block) = &mut content.raw else {
+            continue;
+        };
+        match serde_json::from_str::<Value>(&block.text) {
+            Ok(mut value) => {
+                normalize_value(&mut value);
+                let pretty = block.text.contains('\n');
+                let rendered = if pretty {
+                    serde_json::to_string_pretty(&value)
+                } else {
+                    serde_json::to_string(&value)
+                };
+                if let Ok(text) = rendered {
+                    block.text = text;
+                }
+            }
+            Err(_) => block.text = strip_emoji(&block.text),
+        }
+    }
+}
+
+fn normalize_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let coded: Vec<String> = map
+                .keys()
+                .filter(|k| is_prose_key(k) && map.contains_key(&format!("{k}_code")))
+                .cloned()
+                .collect();
+            for key in coded {
+                map.remove(&key);
+            }
+            for (key, v) in map.iter_mut() {
+                match v {
+                    Value::String(s) if is_prose_key(key) => *s = strip_emoji(s),
+                    _ => normalize_value(v),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize_value),
+        _ => {}
+    }
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(c as u32,
+        0x1F000..=0x1FAFF // pictographs, emoticons, transport, flags
+        | 0x2600..=0x27BF // misc symbols + dingbats (⚠ ✅ ✓ ★)
+        | 0x2B00..=0x2BFF // arrows/stars block used as emoji (⭐ ⬆)
+        | 0xFE0F          // emoji presentation selector
+        | 0x200D          // zero-width joiner
+    )
+}
+
+/// `s` without emoji, whitespace collapsed so "⚠️ Timed out" reads
+/// "Timed out".
+pub(super) fn strip_emoji(s: &str) -> String {
+    s.chars()
+        .filter(|c| !is_emoji(*c))
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::Content;
+    use serde_json::json;
+
+    #[test]
+    fn coded_prose_is_dropped_and_the_rest_loses_emoji() {
+        let payload = json!({
+            "status": "timeout_committed",
+            "message": "⚠️ Thinking timed out.",
+            "message_code": "timeout_partial_saved",
+            "edge_type_warning": "✅ fell back to leads_to",
+            "results": [{ "content": "launch day 🚀", "note": "🔥 hot" }],
+        });
+        let mut result = CallToolResult::success(vec![Content::text(payload.to_string())]);
+        normalize(&mut result);
+
+        let text = &result.content[0].as_text().unwrap().text;
+        let v: Value = serde_json::from_str(text).unwrap();
+        assert!(v.get("message").is_none());
+        assert_eq!(v["message_code"], "timeout_partial_saved");
+        assert_eq!(v["edge_type_warning"], "fell back to leads_to");
+        // Stored content is data, not prose.
+        assert_eq!(v["results"][0]["content"], "launch day 🚀");
+        assert_eq!(v["results"][0]["note"], "hot");
+    }
+
+    #[test]
+    fn plain_text_blocks_only_lose_emoji() {
+        let mut result = CallToolResult::success(vec![Content::text("💡 Hint: retry → full")]);
+        normalize(&mut result);
+        assert_eq!(
+            result.content[0].as_text().unwrap().text,
+            "Hint: retry → full"
+        );
+    }
+}
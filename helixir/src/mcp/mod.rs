@@ -3,16 +3,21 @@
 //!
 //! Layout:
 //! - [`server`]   — `HelixirMcpServer` struct, lifecycle, error mapping, runtime entry.
-//! - [`tools`]    — tool routers grouped by domain (`memory`, `think`);
+//! - [`tools`]    — tool routers grouped by domain (`memory`, `think`,
+//!   `diagnostics`);
 //!   merged into a single `ToolRouter` in `tools::mod`.
 //! - [`handler`]  — `#[prompt_router]` block and the `ServerHandler` impl
 //!   (`get_info`, `list_resources`, `read_resource`).
+//! - [`machine_output`] — `HELIXIR_MACHINE_OUTPUT` rewrite of tool results.
+//! - [`response_limit`] — `HELIXIR_MAX_RESPONSE_BYTES` cap on tool results.
 //! - [`params`]   — typed parameter structs for every tool/prompt.
 //! - [`prompts`]  — instruction prompt text (cognitive protocol, tool guide).
 
 mod handler;
+mod machine_output;
 mod params;
 mod prompts;
+mod response_limit;
 mod server;
 mod tools;
 
@@ -25,6 +25,9 @@ pub enum SearchMode {
     Full,
 }
 impl SearchMode {
+    /// Every value, in declaration order.
+    pub const ALL: [Self; 4] = [Self::Recent, Self::Contextual, Self::Deep, Self::Full];
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Recent => "recent",
@@ -47,6 +50,9 @@ pub enum SearchScope {
     All,
 }
 impl SearchScope {
+    /// Every value, in declaration order.
+    pub const ALL: [Self; 3] = [Self::Personal, Self::Collective, Self::All];
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Personal => "personal",
@@ -56,6 +62,42 @@ impl SearchScope {
     }
 }
 
+/// Order of search_memory rows.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSort {
+    /// Retrieval ranking (default).
+    Relevance,
+    /// Most recently returned by an earlier search first.
+    RecentlyAccessed,
+    /// Most often returned by earlier searches first.
+    FrequentlyUsed,
+    /// Latest stored first.
+    Newest,
+    /// Earliest stored first.
+    Oldest,
+}
+impl SearchSort {
+    /// Every value, in declaration order.
+    pub const ALL: [Self; 5] = [
+        Self::Relevance,
+        Self::RecentlyAccessed,
+        Self::FrequentlyUsed,
+        Self::Newest,
+        Self::Oldest,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Relevance => "relevance",
+            Self::RecentlyAccessed => "recently_accessed",
+            Self::FrequentlyUsed => "frequently_used",
+            Self::Newest => "newest",
+            Self::Oldest => "oldest",
+        }
+    }
+}
+
 /// The 8-type memory ontology (used both to classify and to filter).
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
@@ -70,6 +112,18 @@ pub enum OntologyType {
     Action,
 }
 impl OntologyType {
+    /// Every value, in declaration order.
+    pub const ALL: [Self; 8] = [
+        Self::Skill,
+        Self::Preference,
+        Self::Goal,
+        Self::Fact,
+        Self::Opinion,
+        Self::Experience,
+        Self::Achievement,
+        Self::Action,
+    ];
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Skill => "skill",
@@ -98,6 +152,9 @@ pub enum ChainMode {
     Deep,
 }
 impl ChainMode {
+    /// Every value, in declaration order.
+    pub const ALL: [Self; 4] = [Self::Causal, Self::Forward, Self::Both, Self::Deep];
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Causal => "causal",
@@ -109,13 +166,46 @@ impl ChainMode {
 }
 
 /// Kind of thought node in a FastThink session.
-#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ThoughtTypeArg {
     Reasoning,
     Hypothesis,
     Observation,
     Question,
+    /// A choice made along the way (not the final conclusion).
+    Decision,
+}
+impl ThoughtTypeArg {
+    /// Every value, in declaration order.
+    pub const ALL: [Self; 5] = [
+        Self::Reasoning,
+        Self::Hypothesis,
+        Self::Observation,
+        Self::Question,
+        Self::Decision,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Reasoning => "reasoning",
+            Self::Hypothesis => "hypothesis",
+            Self::Observation => "observation",
+            Self::Question => "question",
+            Self::Decision => "decision",
+        }
+    }
+
+    pub fn thought_type(self) -> crate::toolkit::fast_think::ThoughtType {
+        use crate::toolkit::fast_think::ThoughtType;
+        match self {
+            Self::Reasoning => ThoughtType::Reasoning,
+            Self::Hypothesis => ThoughtType::Hypothesis,
+            Self::Observation => ThoughtType::Observation,
+            Self::Question => ThoughtType::Question,
+            Self::Decision => ThoughtType::Decision,
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -134,6 +224,45 @@ pub struct AddMemoryParams {
     pub user_id: String,
     #[schemars(description = "Optional agent identifier that produced this memory.")]
     pub agent_id: Option<String>,
+    #[schemars(
+        description = "Keep the full original message as a source node the extracted facts link back to (default false; long multi-fact inputs are preserved automatically). search_memory then returns metadata.source_id + source_excerpt on those facts."
+    )]
+    pub store_source: Option<bool>,
+    #[schemars(
+        description = "Files or documents this text came from, e.g. [{uri:'design_doc.md', locator:'§3', title:'Design doc'}]. Stored on every memory this call creates and returned by search_memory as metadata.references."
+    )]
+    pub references: Option<Vec<ReferenceArg>>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ShouldRememberParams {
+    #[schemars(description = "The text you are considering passing to add_memory.")]
+    pub message: String,
+    #[schemars(
+        description = "Whose memory to check for an existing similar entry. Omit to judge the text alone."
+    )]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct CompareSearchModesParams {
+    #[schemars(description = "The query to run in every search mode.")]
+    pub query: String,
+    #[schemars(description = "Whose memory to search.")]
+    pub user_id: String,
+    #[schemars(description = "Rows per mode (default 10, at most 25).")]
+    pub limit: Option<u32>,
+}
+
+/// A file/document reference supplied on `add_memory`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct ReferenceArg {
+    #[schemars(description = "Path, URL or id of the artifact.")]
+    pub uri: String,
+    #[schemars(description = "Position inside it: section, line range, page ('§3', 'L120-140').")]
+    pub locator: Option<String>,
+    #[schemars(description = "Human-readable title to cite.")]
+    pub title: Option<String>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -144,7 +273,9 @@ pub struct SearchMemoryParams {
         description = "Whose memory to search (must match the user_id used on add_memory)."
     )]
     pub user_id: String,
-    #[schemars(description = "Max results. Default depends on mode (~5–20).")]
+    #[schemars(
+        description = "Max results. Default depends on mode (recent 10 / contextual 20 / deep 50 / full 100)."
+    )]
     pub limit: Option<i32>,
     #[schemars(
         description = "Recall breadth. Default 'contextual' (~30d). If a query you expect to match returns nothing, retry with 'full'."
@@ -166,6 +297,34 @@ pub struct SearchMemoryParams {
         description = "Whose memories to include. Default 'personal'. 'collective'/'all' require the collective tier and are silently downgraded to personal otherwise."
     )]
     pub scope: Option<SearchScope>,
+    #[schemars(
+        description = "Blend exact keyword (BM25) matches with semantic matches via rank fusion. Turn on for identifiers, error codes or names; omit for the server default."
+    )]
+    pub hybrid: Option<bool>,
+    #[schemars(
+        description = "Row order. Default 'relevance'. 'recently_accessed' / 'frequently_used' re-order the same results by how recently / how often earlier searches returned them - your working set first. 'newest' / 'oldest' order them chronologically by when they were stored; mode, time window and limit still pick the rows."
+    )]
+    pub sort: Option<SearchSort>,
+    #[schemars(
+        description = "Keep only memories linked to an entity with ALL of these attributes (set via set_entity_attribute), e.g. {\"role\": \"CTO\"}. Values match case-insensitively."
+    )]
+    pub entity_attributes: Option<std::collections::HashMap<String, String>>,
+    #[schemars(
+        description = "Re-rank ONLY these memory_ids (e.g. an earlier search's hits) against this query, at most 200. mode, scope, time window, graph_depth and hybrid are ignored; ids you do not hold are dropped. An empty list returns nothing."
+    )]
+    pub within_ids: Option<Vec<String>>,
+    #[schemars(
+        description = "Drop results scoring below this (0-1). Compared against the normalized score when the server normalizes scores (see config://helixir score_normalization); each row then keeps metadata.raw_score."
+    )]
+    pub min_score: Option<f32>,
+    #[schemars(
+        description = "Rank memories of this ontology type higher without filtering the rest out (e.g. 'preference' while acting on the user's tastes). Each row then reports metadata.concept_boost, the factor its score got (1.0 = not boosted). Use search_by_concept to return ONLY that type."
+    )]
+    pub boost_concept: Option<OntologyType>,
+    #[schemars(
+        description = "Report total_in_window / total_overall in search_meta even when results come back (they always come with an empty result). Costs a pass over your memories when a time window is active."
+    )]
+    pub window_counts: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -186,6 +345,142 @@ pub struct UpdateMemoryParams {
     pub user_id: String,
 }
 
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct AppendMemoryParams {
+    #[schemars(description = "Id of the memory to extend (mem_… / raw_…).")]
+    pub memory_id: String,
+    #[schemars(
+        description = "Text to add after the existing content, e.g. 'Also, we decided to use Postgres.'"
+    )]
+    pub additional_content: String,
+    #[schemars(description = "A user holding the memory.")]
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct CorrectMemoryParams {
+    #[schemars(description = "Id of the wrong memory (mem_… / raw_…).")]
+    pub memory_id: String,
+    #[schemars(
+        description = "The correct statement, in full, e.g. 'The launch moved to March 12.'"
+    )]
+    pub corrected_content: String,
+    #[schemars(description = "A user holding the memory.")]
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct SummarizeRecentParams {
+    #[schemars(description = "User whose recent memories to summarize.")]
+    pub user_id: String,
+    #[schemars(
+        description = "How many hours back the summary reaches (default: summary.window_hours, 24)."
+    )]
+    pub window_hours: Option<u64>,
+    #[schemars(
+        description = "Supersede the summarized memories by the summary: still reachable, ranked below it, skipped by later runs (default: summary.archive_sources, false)."
+    )]
+    pub archive_sources: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct FindStaleParams {
+    #[schemars(description = "User whose memories to review.")]
+    pub user_id: String,
+    #[schemars(
+        description = "Only flag time-bound wording ('currently', 'this month', version numbers) in memories at least this many days old (default: stale.min_age_days, 30). Newer contradictions are reported at any age."
+    )]
+    pub min_age_days: Option<u64>,
+    #[schemars(description = "Max candidates (default: stale.max_results, 50).")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ClusterMemoriesParams {
+    #[schemars(description = "User whose memories to group.")]
+    pub user_id: String,
+    #[schemars(
+        description = "Number of clusters. Omit to choose it automatically (2..cluster.max_k, default 10) by silhouette score."
+    )]
+    pub k: Option<usize>,
+    #[schemars(
+        description = "Ask the LLM for a short topic label per cluster (one call each). Default: false."
+    )]
+    pub label: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct GetUserConfigParams {
+    #[schemars(description = "User whose settings to show.")]
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct SetUserConfigParams {
+    #[schemars(description = "User whose settings to change.")]
+    pub user_id: String,
+    #[schemars(description = "Mode of a search_memory call that names none.")]
+    pub default_search_mode: Option<SearchMode>,
+    #[schemars(
+        description = "Result cap of a search_memory call that names no limit, in every mode."
+    )]
+    pub search_limit: Option<usize>,
+    #[schemars(description = "Freshness window of the 'recent' mode, in hours (> 0).")]
+    pub recent_hours: Option<f64>,
+    #[schemars(description = "Freshness window of the 'contextual' mode, in days (> 0).")]
+    pub contextual_days: Option<f64>,
+    #[schemars(description = "Freshness window of the 'deep' mode, in days (> 0).")]
+    pub deep_days: Option<f64>,
+    #[schemars(
+        description = "Drop every stored override first, so only the ones in this call remain (none: back to the global config). Default false — the call changes only the keys it sets."
+    )]
+    pub reset: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct GetMemoryParams {
+    #[schemars(description = "Id of the memory to read (mem_… / raw_…).")]
+    pub memory_id: String,
+    #[schemars(description = "A user holding the memory.")]
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct GetConclusionSourcesParams {
+    #[schemars(description = "Id of the conclusion memory (the memory_id think_commit returned).")]
+    pub memory_id: String,
+    #[schemars(description = "A user holding the memory.")]
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct WhyMemoryParams {
+    #[schemars(description = "Id of the memory to explain (e.g. from a search result).")]
+    pub memory_id: String,
+    #[schemars(description = "A user holding the memory.")]
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct GetEntityParams {
+    #[schemars(description = "Exact entity name, as listed in a memory's entities.")]
+    pub entity_name: String,
+    #[schemars(description = "A user whose memories reference the entity.")]
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct SetEntityAttributeParams {
+    #[schemars(description = "Exact entity name, as listed in a memory's entities.")]
+    pub entity_name: String,
+    #[schemars(description = "A user whose memories reference the entity.")]
+    pub user_id: String,
+    #[schemars(description = "Attribute name, e.g. 'role'.")]
+    pub key: String,
+    #[schemars(description = "Attribute value, e.g. 'CTO'. Empty string removes the attribute.")]
+    pub value: String,
+}
+
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
 pub struct GetMemoryGraphParams {
     #[schemars(description = "Whose graph to read.")]
@@ -230,6 +525,18 @@ pub struct SearchReasoningChainParams {
     pub limit: Option<i32>,
 }
 
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct GetGoalsParams {
+    #[schemars(description = "Owner of the goals.")]
+    pub user_id: String,
+    #[schemars(
+        description = "Only goals a recall of this topic reaches (natural language), ranked by it. Omit for all goals, newest first."
+    )]
+    pub topic: Option<String>,
+    #[schemars(description = "Max goals. Default 10.")]
+    pub limit: Option<i32>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MemorySummaryArgs {
     #[schemars(description = "Owner of the memories to summarise.")]
@@ -245,9 +552,29 @@ pub struct StartThinkingParams {
     )]
     pub session_id: String,
     #[schemars(
-        description = "The opening thought or question to reason about (becomes the root node)."
+        description = "The opening thought or question to reason about (becomes the root node). Omit to start empty and recall first: the first think_add becomes the root, or a first think_recall roots the session at its query."
     )]
-    pub initial_thought: String,
+    pub initial_thought: Option<String>,
+    #[schemars(
+        description = "thought_type that think_add uses in this session when none is passed. Default 'reasoning'; e.g. 'observation' for a session that mostly logs findings."
+    )]
+    pub default_thought_type: Option<ThoughtTypeArg>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ContinueIncompleteParams {
+    #[schemars(
+        description = "A new session id you choose; reuse it on every think_* call until commit/discard."
+    )]
+    pub session_id: String,
+    #[schemars(
+        description = "memory_id of the [INCOMPLETE] memory to continue, as returned by search_incomplete_thoughts."
+    )]
+    pub memory_id: String,
+    #[schemars(
+        description = "A user holding the memory (the user_id given to search_incomplete_thoughts)."
+    )]
+    pub user_id: String,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -258,10 +585,30 @@ pub struct AddThoughtParams {
     pub content: String,
     #[schemars(description = "Kind of thought. Default 'reasoning'.")]
     pub thought_type: Option<ThoughtTypeArg>,
+    #[schemars(
+        description = "Stable id of the parent thought (a previous response's thought_id/root_thought_id). Preferred over parent_idx, and wins when both are given. Omit both to attach to the root."
+    )]
+    pub parent_id: Option<String>,
     #[schemars(
         description = "Index of the parent thought to attach under (from a previous response's thought_idx/root_thought_idx). Omit to attach to the root."
     )]
     pub parent_idx: Option<u32>,
+    #[schemars(
+        description = "Relationship from the parent to this thought: leads_to (default), implies, because, supports, contradicts, refines, questions, recalled. Unknown values fall back to leads_to and are reported as edge_type_warning."
+    )]
+    pub edge_type: Option<String>,
+    #[schemars(
+        description = "Strength of the parent edge, 0.0-1.0 (e.g. 0.3 for a weak implication). Default 1.0."
+    )]
+    pub edge_weight: Option<f32>,
+    #[schemars(
+        description = "Your confidence in this thought, 0.0-1.0. Default 0.5. When the last few thoughts are all confident and steady, the response carries should_conclude: true."
+    )]
+    pub certainty: Option<f32>,
+    #[schemars(
+        description = "Optional structured payload for this step, e.g. a comparison table [{option, score}] or {key: value} facts. Kept as-is in think_status / snapshots and rendered as text (table, key: value lines, list) when the thought is committed."
+    )]
+    pub data: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -270,8 +617,14 @@ pub struct ThinkRecallParams {
     pub session_id: String,
     #[schemars(description = "Query to pull matching facts from MAIN memory into the session.")]
     pub query: String,
-    #[schemars(description = "Index of the thought to attach the recalled facts under.")]
-    pub parent_idx: u32,
+    #[schemars(
+        description = "Stable id of the thought to attach the recalled facts under. Preferred over parent_idx, and wins when both are given."
+    )]
+    pub parent_id: Option<String>,
+    #[schemars(
+        description = "Index of the thought to attach the recalled facts under. Omit to attach them to the session root; the response reports the parent used."
+    )]
+    pub parent_idx: Option<u32>,
     #[schemars(
         description = "Whose main memory to recall from. Omit to use the session's default scope."
     )]
@@ -284,8 +637,24 @@ pub struct ThinkConcludeParams {
     pub session_id: String,
     #[schemars(description = "The conclusion of the reasoning (what to remember).")]
     pub conclusion: String,
+    #[schemars(
+        description = "Stable ids of the thoughts that support this conclusion. Preferred over supporting_idx, and wins when both are given."
+    )]
+    pub supporting_ids: Option<Vec<String>>,
     #[schemars(description = "Indices of the thoughts that support this conclusion.")]
     pub supporting_idx: Option<Vec<u32>>,
+    #[schemars(
+        description = "conclusion_id of an earlier conclusion in this session that this one qualifies. Preferred over relation_to, and wins when both are given."
+    )]
+    pub relation_to_id: Option<String>,
+    #[schemars(
+        description = "conclusion_idx of an earlier conclusion in this session that this one qualifies. The committed content nests it under that one as '(refined by: ...)' or '(contradicted by: ...)'."
+    )]
+    pub relation_to: Option<u32>,
+    #[schemars(
+        description = "How this conclusion relates to relation_to / relation_to_id: refines (default) or contradicts. Ignored without either."
+    )]
+    pub relation: Option<String>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -294,18 +663,236 @@ pub struct ThinkCommitParams {
     pub session_id: String,
     #[schemars(description = "Owner under whom the conclusion is stored in main memory.")]
     pub user_id: String,
+    #[schemars(
+        description = "memory_id of an [INCOMPLETE] memory (from search_incomplete_thoughts) this session continued. The conclusion then UPDATES that memory and clears its incomplete_thought tag instead of creating a duplicate. It must be a memory user_id holds (else error: memory_not_found)."
+    )]
+    pub supersede_memory_id: Option<String>,
+    #[schemars(
+        description = "Ask the LLM to classify the conclusion into an ontology type (skill, preference, goal, fact, opinion, experience, achievement, action) and link it, so search_by_concept finds it. Costs one LLM call. Default from server config (off)."
+    )]
+    pub auto_classify: Option<bool>,
+    #[schemars(
+        description = "Report entities_extracted / concepts_mapped as what main memory actually linked to the stored conclusion (waits for its entity extraction instead of running it in the background) rather than the session's scratch counts. Default from server config (off)."
+    )]
+    pub reconcile_counts: Option<bool>,
+    #[schemars(
+        description = "When the session has no conclusion yet, save its thoughts as an [INCOMPLETE] memory (like a timeout) instead of failing. Default false: the commit is rejected and the session stays open for think_conclude."
+    )]
+    pub allow_partial: Option<bool>,
+    #[schemars(
+        description = "Keep the committed session for one session TTL so think_uncommit can take the commit back (delete the memory, reopen the session). Ignored for a superseding commit. Default false."
+    )]
+    pub retain: Option<bool>,
+    #[schemars(
+        description = "Also return the committed memory's depth-1 neighbourhood in main memory under 'graph' ({nodes, edges}, as get_memory_graph) plus 'linked_to' — the existing memories the new one is connected to — so you can render where the conclusion slotted in. Default false."
+    )]
+    pub include_graph: Option<bool>,
+    #[schemars(
+        description = "Also list what structure the session committed: 'entities' [{name, entity_type}] extracted during the session and 'concepts' (mapped concept names). Default false — counts only."
+    )]
+    pub verbose: Option<bool>,
+    #[schemars(
+        description = "Commit the session's reasoning structure instead of one conclusion text: each thought becomes a memory and each typed edge (implies, because, supports, contradicts, ...) a reasoning relation that search_reasoning_chain can traverse. Not with supersede_memory_id or retain. Default false."
+    )]
+    pub as_chain: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkUncommitParams {
+    #[schemars(description = "The session_id committed with retain=true.")]
+    pub session_id: String,
+    #[schemars(
+        description = "Must be true: the committed memory is deleted. Without it the call is refused and the commit stands."
+    )]
+    pub confirm: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
 pub struct ThinkDiscardParams {
     #[schemars(description = "The session_id to discard.")]
     pub session_id: String,
+    #[schemars(
+        description = "Discard even though the session reached a conclusion that was never committed. Default false: such a discard is refused with a preview of the conclusion."
+    )]
+    pub force: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
 pub struct ThinkStatusParams {
     #[schemars(description = "The session_id to inspect.")]
     pub session_id: String,
+    #[schemars(
+        description = "Also return the full session graph (every thought, edge, entity and concept) under 'graph'. Default false — counts only. Use it to rebuild your view of a session, e.g. after a reconnect."
+    )]
+    pub verbose: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkListParams {
+    #[schemars(
+        description = "Only list sessions in this status: thinking, needs_recall, decided, timed_out, overflow, committed or discarded — e.g. 'decided' for concluded-but-uncommitted sessions. Omit for all."
+    )]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkSnapshotAllParams {}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkRestoreAllParams {
+    #[schemars(
+        description = "The 'snapshot' object think_snapshot_all returned, passed back unchanged."
+    )]
+    pub snapshot: serde_json::Value,
+    #[schemars(
+        description = "Replace the sessions already live in this process. Default false — the restore is refused while any session exists. Cannot be combined with on_conflict."
+    )]
+    pub force: Option<bool>,
+    #[schemars(
+        description = "Restore next to the live sessions instead, resolving each session_id that is already live: 'skip' (keep the live one), 'overwrite' (replace it), 'duplicate' (install the snapshot's under '<id>-copy'), 'merge' (add the snapshot's missing thoughts, edges, entity mentions, concepts and notes to the live one). Sessions whose id is not live are created either way."
+    )]
+    pub on_conflict: Option<String>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct CapabilitiesParams {}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct SelfTestParams {}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkNoteParams {
+    #[schemars(description = "The session_id from think_start.")]
+    pub session_id: String,
+    #[schemars(
+        description = "Process note to append (e.g. 'need to verify with user'). Omit to just list the session's notes."
+    )]
+    pub note: Option<String>,
+}
+
+/// Where `think_promote_note` puts a note.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NoteTarget {
+    /// A thought in the same session (kept only if the session is committed).
+    #[default]
+    Thought,
+    /// Main memory right away, through the add_memory pipeline.
+    Memory,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkPromoteNoteParams {
+    #[schemars(description = "The session_id from think_start.")]
+    pub session_id: String,
+    #[schemars(
+        description = "Position of the note in think_note's annotations list (0 = the first note)."
+    )]
+    pub note_idx: usize,
+    #[schemars(
+        description = "'thought' (default): the note becomes a thought in this session. 'memory': it is stored in main memory now (needs user_id)."
+    )]
+    pub target: Option<NoteTarget>,
+    #[schemars(description = "Owner of the stored memory. Required with target 'memory'.")]
+    pub user_id: Option<String>,
+    #[schemars(
+        description = "Kind of thought to create (target 'thought'). Default 'observation'."
+    )]
+    pub thought_type: Option<ThoughtTypeArg>,
+    #[schemars(
+        description = "Stable id of the thought to attach under (target 'thought'). Omit to attach to the root."
+    )]
+    pub parent_id: Option<String>,
+    #[schemars(description = "Index of the thought to attach under (target 'thought').")]
+    pub parent_idx: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkEntitiesParams {
+    #[schemars(description = "The session_id from think_start.")]
+    pub session_id: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct AdjustCertaintyParams {
+    #[schemars(description = "The session_id from think_start.")]
+    pub session_id: String,
+    #[schemars(
+        description = "Stable id of the thought to re-weigh (its thought_id). Preferred over thought_idx; one of the two is required."
+    )]
+    pub thought_id: Option<String>,
+    #[schemars(description = "Index of the thought to re-weigh.")]
+    pub thought_idx: Option<u32>,
+    #[schemars(
+        description = "Change to add to the thought's current certainty, e.g. 0.1 ('a bit more likely') or -0.25. The result is clamped to 0.0-1.0."
+    )]
+    pub delta: f32,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkSearchParams {
+    #[schemars(description = "The session_id from think_start.")]
+    pub session_id: String,
+    #[schemars(description = "What to look for in the session's thoughts, e.g. 'cache tradeoff'.")]
+    pub query: String,
+    #[schemars(
+        description = "'substring' (default): case-insensitive text match, in thought order, no model call. 'semantic': rank every thought by embedding similarity to the query (embeddings are cached for the session)."
+    )]
+    pub mode: Option<String>,
+    #[schemars(description = "Most matches to return. Default 10.")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkTemplateParams {
+    #[schemars(description = "The session_id from think_start.")]
+    pub session_id: String,
+    #[schemars(
+        description = "Name of a registered reasoning template, e.g. 'evaluate_library'. An unknown name is rejected with the list of available ones."
+    )]
+    pub template: String,
+    #[schemars(
+        description = "Stable id of the thought to hang the template under. Preferred over parent_idx. Omit both to attach to the root."
+    )]
+    pub parent_id: Option<String>,
+    #[schemars(description = "Index of the thought to hang the template under.")]
+    pub parent_idx: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkImportIncompleteParams {
+    #[schemars(description = "The session_id of an active session to import into.")]
+    pub session_id: String,
+    #[schemars(
+        description = "ID of an [INCOMPLETE] memory (incomplete_thought tag), e.g. from search_incomplete_thoughts."
+    )]
+    pub memory_id: String,
+    #[schemars(
+        description = "A user holding the memory (the user_id given to search_incomplete_thoughts)."
+    )]
+    pub user_id: String,
+    #[schemars(
+        description = "Stable id of the thought to attach the imported thoughts under. Preferred over parent_idx. Omit both to attach to the root."
+    )]
+    pub parent_id: Option<String>,
+    #[schemars(description = "Index of the thought to attach the imported thoughts under.")]
+    pub parent_idx: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ThinkBranchParams {
+    #[schemars(description = "The session_id of the active session to fork.")]
+    pub session_id: String,
+    #[schemars(
+        description = "session_id for the fork. Must not be live yet (think_list shows the active ones)."
+    )]
+    pub new_session_id: String,
+    #[schemars(
+        description = "Stable id of the thought to branch at; it and its chain back to the root are copied. Preferred over from_thought_idx."
+    )]
+    pub from_thought_id: Option<String>,
+    #[schemars(description = "Index of the thought to branch at.")]
+    pub from_thought_idx: Option<u32>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -318,10 +905,107 @@ pub struct SearchIncompleteThoughtsParams {
 pub struct ListMemoriesParams {
     #[schemars(description = "Whose memories to list.")]
     pub user_id: String,
-    #[schemars(description = "Max results. Default 100.")]
+    #[schemars(description = "Max results per page. Default 100.")]
     pub limit: Option<i32>,
+    #[schemars(
+        description = "Rows to skip: 0 (default) for the first page, then the next_cursor of the page before."
+    )]
+    pub offset: Option<u32>,
     #[schemars(description = "Optional: return only memories of this ontology type.")]
     pub memory_type: Option<OntologyType>,
+    #[schemars(
+        description = "Optional: return only memories carrying ALL of these context tags (case-insensitive)."
+    )]
+    pub tags: Option<Vec<String>>,
+    #[schemars(
+        description = "Optional: earliest creation time (inclusive): RFC3339 or YYYY-MM-DD."
+    )]
+    pub time_from: Option<String>,
+    #[schemars(description = "Optional: latest creation time (inclusive): RFC3339 or YYYY-MM-DD.")]
+    pub time_to: Option<String>,
+    #[schemars(
+        description = "Optional: true = only pinned memories (immutable or tagged 'pinned'), false = only unpinned ones."
+    )]
+    pub pinned: Option<bool>,
+    #[schemars(
+        description = "Optional: true = only archived memories (superseded by a newer one, e.g. by summarize_recent archive_sources), false = only current ones."
+    )]
+    pub archived: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct PurgeUserParams {
+    #[schemars(description = "Whose data to erase.")]
+    pub user_id: String,
+    #[schemars(
+        description = "Must repeat user_id exactly. A guard against purging the wrong user by accident — anything else is rejected and nothing is deleted."
+    )]
+    pub confirm_token: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct CacheStatsParams {}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ClearCacheParams {
+    #[schemars(
+        description = "Which cache to flush: embedding (vectors per text — stale after an embedding model change), search (vector-search results) or all."
+    )]
+    pub which: String,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct RebuildRelationsParams {
+    #[schemars(description = "Whose memories to re-judge. Omit to walk every user.")]
+    pub user_id: Option<String>,
+    #[schemars(
+        description = "Report what would change without touching the graph. Default false."
+    )]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "Also drop edges re-inference no longer proposes. Default false: only missing edges are added. CONTRADICTS edges are never dropped."
+    )]
+    pub prune: Option<bool>,
+    #[schemars(
+        description = "next_cursor from the previous call, to resume where it stopped. Omit to start from the beginning."
+    )]
+    pub cursor: Option<String>,
+    #[schemars(description = "Memories to re-judge in this call. Default 50, at most 200.")]
+    pub batch_size: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ReindexEmbeddingsParams {
+    #[schemars(description = "Whose memories to embed. Omit to walk every user.")]
+    pub user_id: Option<String>,
+    #[schemars(description = "Memories to embed in this call. Default 100.")]
+    pub limit: Option<usize>,
+    #[schemars(
+        description = "next_cursor from the previous call, to resume where it stopped. Omit to start from the beginning."
+    )]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ImportMemoriesParams {
+    #[schemars(description = "Whose memory the messages go into.")]
+    pub user_id: String,
+    #[schemars(
+        description = "The whole batch, in order: one natural-language message per item, each handled like an add_memory call. Resend the SAME list on every resumed call — the batch is identified by its content."
+    )]
+    pub messages: Vec<String>,
+    #[schemars(description = "Optional agent identifier that produced these memories.")]
+    pub agent_id: Option<String>,
+    #[schemars(
+        description = "resume_token from the previous call of this batch. Omit on the first call; a batch resent without it still resumes from its stored checkpoint."
+    )]
+    pub resume_token: Option<String>,
+    #[schemars(description = "Messages to process in this call. Default 50.")]
+    pub max_items: Option<usize>,
+    #[schemars(
+        description = "Discard this batch's checkpoint and start again from the first message. Default false."
+    )]
+    pub restart: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -372,8 +1056,47 @@ pub struct ConnectMemoriesParams {
     pub max_depth: Option<i32>,
 }
 
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct EstimateAddParams {
+    #[schemars(description = "The text you are about to pass to add_memory.")]
+    pub message: String,
+}
+
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
 pub struct GetAddStatusParams {
     #[schemars(description = "The pending_id returned by a buffered add_memory.")]
     pub pending_id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `ALL` + `as_str` feed the capabilities tool, so each wire string must
+    /// be exactly what the schema accepts back.
+    #[test]
+    fn every_enum_value_round_trips_through_its_wire_string() {
+        fn check<T: serde::de::DeserializeOwned>(name: &str) {
+            serde_json::from_value::<T>(serde_json::json!(name))
+                .unwrap_or_else(|e| panic!("{name:?} does not deserialize: {e}"));
+        }
+        for v in SearchMode::ALL {
+            check::<SearchMode>(v.as_str());
+        }
+        for v in SearchSort::ALL {
+            check::<SearchSort>(v.as_str());
+        }
+        for v in SearchScope::ALL {
+            check::<SearchScope>(v.as_str());
+        }
+        for v in OntologyType::ALL {
+            check::<OntologyType>(v.as_str());
+        }
+        for v in ChainMode::ALL {
+            check::<ChainMode>(v.as_str());
+        }
+        for v in ThoughtTypeArg::ALL {
+            check::<ThoughtTypeArg>(v.as_str());
+        }
+    }
+}
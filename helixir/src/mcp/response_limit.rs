@@ -0,0 +1,232 @@
+//! Response size cap (`HELIXIR_MAX_RESPONSE_BYTES`): a tool result whose
+//! text would exceed the cap loses trailing elements of its largest result
+//! array until it fits, and says so with `truncated: true, omitted: N`.
+//!
+//! Applied to the finished [`CallToolResult`] in `ServerHandler::call_tool`
+//! rather than in `result_to_json`, so results a tool assembles itself are
+//! capped too. Only array elements are dropped: scalar fields survive, so
+//! the caller can narrow the query (smaller `limit`, `search_mode`). A tool
+//! that pages trims its own page first with [`rows_within`], so its
+//! `next_cursor` resumes at the first row it left out rather than past it.
+
+use rmcp::model::{CallToolResult, Content, RawContent};
+use serde_json::{Value, json};
+
+/// Room kept for the `truncated` / `omitted` marker itself.
+const MARKER_BYTES: usize = 48;
+
+fn render(value: &Value, pretty: bool) -> String {
+    let rendered = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    rendered.unwrap_or_default()
+}
+
+/// Bytes `item` adds to a rendered array: itself, its separator and, when
+/// pretty, the extra indentation of each of its lines.
+fn element_bytes(item: &Value, pretty: bool) -> usize {
+    let rendered = render(item, pretty);
+    if pretty {
+        rendered.len() + 2 * rendered.lines().count() + 2
+    } else {
+        rendered.len() + 1
+    }
+}
+
+/// How many leading `rows` fit in `budget` bytes rendered as one JSON array.
+/// Each row is rendered once.
+pub(super) fn rows_within(rows: &[Value], budget: usize, pretty: bool) -> usize {
+    let mut used = 4;
+    rows.iter()
+        .take_while(|row| {
+            used += element_bytes(row, pretty);
+            used <= budget
+        })
+        .count()
+}
+
+/// Non-empty arrays in `value` as `(rendered size, JSON pointer)`, looking
+/// through objects (not into array elements). `""` is the value itself.
+fn arrays(value: &Value, at: String, out: &mut Vec<(usize, String)>) {
+    match value {
+        Value::Array(items) if !items.is_empty() => {
+            let size = serde_json::to_string(value).map_or(0, |s| s.len());
+            out.push((size, at));
+        }
+        Value::Object(map) => {
+            for (key, v) in map {
+                let escaped = key.replace('~', "~0").replace('/', "~1");
+                arrays(v, format!("{at}/{escaped}"), out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Drop trailing elements of the largest arrays in `value` until it renders
+/// within `budget` bytes. Returns how many were dropped; stops early when
+/// no array is left to shrink.
+///
+/// Each round renders `value` once, then pops elements off the largest
+/// array — measuring each one as it goes — until they cover the excess or
+/// the array shrinks below the runner-up. An element's indentation inside
+/// `value` is at least what [`element_bytes`] counts, so a round never
+/// drops more than needed.
+fn shrink(value: &mut Value, budget: usize, pretty: bool) -> usize {
+    let mut omitted = 0;
+    loop {
+        let len = render(value, pretty).len();
+        if len <= budget {
+            break;
+        }
+        let mut excess = len - budget;
+        let mut found = Vec::new();
+        arrays(value, String::new(), &mut found);
+        found.sort_by_key(|(size, _)| std::cmp::Reverse(*size));
+        let runner_up = found.get(1).map_or(0, |(size, _)| *size);
+        let Some((mut size, pointer)) = found.into_iter().next() else {
+            break;
+        };
+        let Some(Value::Array(items)) = value.pointer_mut(&pointer) else {
+            break;
+        };
+        while let Some(item) = items.pop() {
+            omitted += 1;
+            let saved = element_bytes(&item, pretty);
+            let compact = element_bytes(&item, false);
+            if saved >= excess {
+                break;
+            }
+            excess -= saved;
+            size = size.saturating_sub(compact);
+            if size < runner_up {
+                break;
+            }
+        }
+    }
+    omitted
+}
+
+/// Cap the text of `result` at `max_bytes`. Each JSON block is shrunk to
+/// what the other blocks leave over; an object block carries the marker
+/// itself, a top-level array gets a marker block appended after it.
+/// Non-JSON blocks are left alone.
+pub(super) fn cap(result: &mut CallToolResult, max_bytes: usize) {
+    let text_len = |c: &Content| match &c.raw {
+        RawContent::Text(block) => block.text.len(),
+        _ => 0,
+    };
+    let total: usize = result.content.iter().map(text_len).sum();
+    if total <= max_bytes {
+        return;
+    }
+    let mut others = total;
+    let mut appended = Vec::new();
+    for content in &mut result.content {
+        let own = text_len(content);
+        others -= own;
+        let RawContent::Text(block) = &mut content.raw else {
+            continue;
+        };
+        let Ok(mut value) = serde_json::from_str::<Value>(&block.text) else {
+            others += own;
+            continue;
+        };
+        let pretty = block.text.contains('\n');
+        let budget = max_bytes
+            .saturating_sub(others)
+            .saturating_sub(MARKER_BYTES);
+        let omitted = shrink(&mut value, budget, pretty);
+        if omitted > 0 {
+            match &mut value {
+                Value::Object(map) => {
+                    map.insert("truncated".into(), json!(true));
+                    map.insert("omitted".into(), json!(omitted));
+                }
+                _ => appended.push(Content::text(
+                    json!({ "truncated": true, "omitted": omitted }).to_string(),
+                )),
+            }
+            block.text = render(&value, pretty);
+        }
+        others += block.text.len();
+    }
+    result.content.extend(appended);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(result: &CallToolResult, i: usize) -> Value {
+        serde_json::from_str(&result.content[i].as_text().unwrap().text).unwrap()
+    }
+
+    #[test]
+    fn the_largest_array_is_cut_and_marked_until_the_result_fits() {
+        let payload = json!({
+            "ok": true,
+            "next_cursor": "mem_42",
+            "nodes": (0..50).map(|i| json!({ "id": format!("mem_{i}"), "content": "x".repeat(40) })).collect::<Vec<_>>(),
+            "edges": [{ "source": "mem_0", "target": "mem_1" }],
+        });
+        let mut result = CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&payload).unwrap(),
+        )]);
+        cap(&mut result, 1_000);
+
+        let body = &result.content[0].as_text().unwrap().text;
+        assert!(body.len() <= 1_000, "{}", body.len());
+        let v = text(&result, 0);
+        assert_eq!(v["truncated"], true);
+        let kept = v["nodes"].as_array().unwrap().len();
+        assert_eq!(v["omitted"], 50 - kept as u64);
+        assert_eq!(v["nodes"][0]["id"], "mem_0", "the head is kept");
+        assert_eq!(v["next_cursor"], "mem_42");
+        assert_eq!(v["edges"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn a_top_level_array_gets_a_marker_block_and_small_results_are_untouched() {
+        let hits: Vec<Value> = (0..20)
+            .map(|i| json!({ "id": format!("mem_{i}"), "content": "y".repeat(60) }))
+            .collect();
+        let mut result = CallToolResult::success(vec![
+            Content::text(serde_json::to_string(&hits).unwrap()),
+            Content::text("Tip: narrow the query"),
+        ]);
+        cap(&mut result, 600);
+
+        assert_eq!(result.content.len(), 3);
+        let kept = text(&result, 0).as_array().unwrap().len();
+        assert!(kept < 20);
+        assert_eq!(
+            result.content[1].as_text().unwrap().text,
+            "Tip: narrow the query"
+        );
+        let marker = text(&result, 2);
+        assert_eq!(marker["truncated"], true);
+        assert_eq!(marker["omitted"], 20 - kept as u64);
+
+        let mut small = CallToolResult::success(vec![Content::text(r#"{"ok":true}"#)]);
+        cap(&mut small, 600);
+        assert_eq!(small.content[0].as_text().unwrap().text, r#"{"ok":true}"#);
+    }
+
+    #[test]
+    fn rows_within_keeps_the_rows_that_fit_rendered() {
+        let rows: Vec<Value> = (0..30)
+            .map(|i| json!({ "memory_id": format!("mem_{i}"), "content": "z".repeat(50) }))
+            .collect();
+        for pretty in [true, false] {
+            let kept = rows_within(&rows, 900, pretty);
+            assert!(kept > 0 && kept < rows.len());
+            assert!(render(&json!(rows[..kept]), pretty).len() <= 900);
+            assert!(render(&json!(rows[..=kept]), pretty).len() > 900 - 16);
+        }
+        assert_eq!(rows_within(&rows, usize::MAX, true), rows.len());
+        assert_eq!(rows_within(&rows, 3, true), 0);
+    }
+}
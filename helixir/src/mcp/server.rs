@@ -20,9 +20,9 @@ use sha2::{Digest, Sha256};
 use subtle::ConstantTimeEq;
 use tracing::{info, warn};
 
-use crate::core::config::HelixirConfig;
+use crate::core::config::{HelixirConfig, UserConfigOverrides};
 use crate::core::helixir_client::{HelixirClient, HelixirClientError};
-use crate::toolkit::fast_think::{FastThinkLimits, FastThinkManager};
+use crate::toolkit::fast_think::{self, FastThinkError, FastThinkLimits, FastThinkManager};
 use crate::toolkit::tooling_manager::{ToolingManager, ingest_buffer};
 
 struct IngestWorkerRuntime {
@@ -70,7 +70,15 @@ pub struct HelixirMcpServer {
     pub(super) fast_think: Arc<FastThinkManager>,
     ingest_worker: Arc<IngestWorkerRuntime>,
     pub(super) tool_router: ToolRouter<Self>,
+    /// Tools removed by the `enabled_tools` whitelist; calling one is
+    /// method-not-found rather than the unknown-tool error.
+    pub(super) disabled_tools: Arc<[String]>,
     pub(super) prompt_router: PromptRouter<Self>,
+    /// Per-connection prefix of FastThink session keys (gateway with
+    /// `fast_think.namespace_sessions`): two connections may both use
+    /// session_id "plan" without colliding. `None` keys sessions by the
+    /// caller's id as given.
+    pub(super) session_namespace: Option<Arc<str>>,
 }
 
 impl HelixirMcpServer {
@@ -78,19 +86,47 @@ impl HelixirMcpServer {
         let client_arc = Arc::new(client);
         let fast_think = Arc::new(FastThinkManager::new(
             client_arc.clone(),
-            FastThinkLimits::from_config(&client_arc.config().fast_think),
+            FastThinkLimits::from_config(&client_arc.config().fast_think)
+                .with_entity_identity(client_arc.config().entity_identity),
         ));
         let ingest_worker = IngestWorkerRuntime::new(client_arc.tooling_arc());
+        let mut tool_router = Self::build_tool_router();
+        let disabled_tools = match client_arc.config().enabled_tools.as_deref() {
+            Some(enabled) => Self::restrict_tools(&mut tool_router, enabled),
+            None => Vec::new(),
+        };
 
         Self {
             client: Arc::new(arc_swap::ArcSwap::from(client_arc)),
             fast_think,
             ingest_worker,
-            tool_router: Self::build_tool_router(),
+            tool_router,
+            disabled_tools: disabled_tools.into(),
             prompt_router: Self::build_prompt_router(),
+            session_namespace: None,
+        }
+    }
+
+    /// A copy of this server whose FastThink sessions live under
+    /// `namespace` (see [`Self::session_namespace`]).
+    pub(super) fn with_session_namespace(&self, namespace: &str) -> Self {
+        Self {
+            session_namespace: Some(namespace.into()),
+            ..self.clone()
         }
     }
 
+    /// The manager key for a caller's `session_id`.
+    pub(super) fn session_key(&self, session_id: &str) -> String {
+        fast_think::session_key(self.session_namespace.as_deref(), session_id)
+    }
+
+    /// The caller's id for a manager key, or `None` when the key belongs to
+    /// another connection's namespace.
+    pub(super) fn caller_session_id<'a>(&self, key: &'a str) -> Option<&'a str> {
+        fast_think::caller_id(self.session_namespace.as_deref(), key)
+    }
+
     /// The current client. Load per call — never cache across an await if
     /// you want reloads to take effect promptly (holding one for a single
     /// request is exactly right).
@@ -122,7 +158,8 @@ impl HelixirMcpServer {
         ingest_worker: &IngestWorkerRuntime,
         client: Arc<HelixirClient>,
     ) {
-        let limits = FastThinkLimits::from_config(&client.config().fast_think);
+        let limits = FastThinkLimits::from_config(&client.config().fast_think)
+            .with_entity_identity(client.config().entity_identity);
         ingest_worker.update(client.tooling_arc());
         fast_think.update_runtime(Arc::clone(&client), limits);
         handle.store(client);
@@ -153,18 +190,257 @@ impl HelixirMcpServer {
         });
     }
 
+    /// Client errors, with the variant's [`HelixirClientError::code`] as
+    /// `data.error`.
     pub(super) fn convert_error(err: HelixirClientError) -> McpError {
+        let data = Some(serde_json::json!({ "error": err.code() }));
         match err {
-            HelixirClientError::Config(msg) => McpError::invalid_params(msg, None),
-            HelixirClientError::Database(msg) => McpError::internal_error(msg, None),
-            HelixirClientError::Llm(msg) => McpError::internal_error(msg, None),
-            HelixirClientError::Embedding(msg) => McpError::internal_error(msg, None),
-            HelixirClientError::Tooling(msg) => McpError::internal_error(msg, None),
+            HelixirClientError::Config(msg) => McpError::invalid_params(msg, data),
+            HelixirClientError::Database(msg) => McpError::internal_error(msg, data),
+            HelixirClientError::Llm(msg) => McpError::internal_error(msg, data),
+            HelixirClientError::Embedding(msg) => McpError::internal_error(msg, data),
+            HelixirClientError::Tooling(msg) => McpError::internal_error(msg, data),
             HelixirClientError::NotInitialized => {
-                McpError::internal_error("Client not initialized", None)
+                McpError::internal_error("Client not initialized", data)
+            }
+            HelixirClientError::Operation(msg) => McpError::internal_error(msg, data),
+        }
+    }
+
+    /// Every tool error leaves with a stable `data.error`: the code the tool
+    /// set, or else the JSON-RPC category (`invalid_params`,
+    /// `internal_error`, ...). Data that is not an object is kept under
+    /// `data.detail`.
+    pub(super) fn ensure_error_code(mut err: McpError) -> McpError {
+        use rmcp::model::ErrorCode;
+        let generic = match err.code {
+            ErrorCode::INVALID_PARAMS => "invalid_params",
+            ErrorCode::INVALID_REQUEST => "invalid_request",
+            ErrorCode::METHOD_NOT_FOUND => "method_not_found",
+            ErrorCode::RESOURCE_NOT_FOUND => "resource_not_found",
+            ErrorCode::PARSE_ERROR => "parse_error",
+            _ => "internal_error",
+        };
+        match err.data.take() {
+            Some(serde_json::Value::Object(mut map)) => {
+                map.entry("error").or_insert_with(|| generic.into());
+                err.data = Some(serde_json::Value::Object(map));
+            }
+            Some(other) => {
+                err.data = Some(serde_json::json!({ "error": generic, "detail": other }));
+            }
+            None => err.data = Some(serde_json::json!({ "error": generic })),
+        }
+        err
+    }
+
+    /// Gate for a blank `query`: Ok when the operator chose "browse recent"
+    /// (`empty_query_browse`), otherwise the caller gets `invalid_params`
+    /// rather than the noise an embedded "" would return.
+    pub(super) fn check_empty_query_allowed(
+        config: &HelixirConfig,
+        tool: &str,
+    ) -> Result<(), McpError> {
+        if config.empty_query_browse {
+            return Ok(());
+        }
+        Err(McpError::invalid_params(
+            format!(
+                "{tool}: query is empty. Pass what to look for; to see everything use list_memories."
+            ),
+            Some(serde_json::json!({ "error": "empty_query", "hint": "list_memories" })),
+        ))
+    }
+
+    /// FastThink errors for `session_id`. A missing or duplicate session is
+    /// the caller's mistake (a stale or reused id), not a server fault — it
+    /// maps to `invalid_params` with the id in `data` and a `think_list`
+    /// hint. So does committing before `think_conclude` (hint
+    /// `think_conclude`; the session survives), and concluding before
+    /// `min_thoughts_before_conclude` steps (hint `think_add`). A session
+    /// busy with a recall is `invalid_request` marked `retryable`.
+    /// Everything else stays an internal error.
+    pub(super) fn convert_think_error(err: FastThinkError, session_id: &str) -> McpError {
+        match err {
+            FastThinkError::SessionNotFound => McpError::invalid_params(
+                format!("{err}; call think_list to see the active sessions"),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "hint": "think_list",
+                })),
+            ),
+            FastThinkError::SessionAlreadyExists => McpError::invalid_params(
+                format!(
+                    "{err}: pick a new session_id, or continue this one with think_add \
+                     (think_list shows the active sessions)"
+                ),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "hint": "think_list",
+                })),
+            ),
+            FastThinkError::NoConclusion => McpError::invalid_params(
+                format!(
+                    "{err}: the session is still open — call think_conclude and commit again, \
+                     or pass allow_partial=true to save it as an [INCOMPLETE] memory"
+                ),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "hint": "think_conclude",
+                })),
+            ),
+            FastThinkError::TooFewThoughts { steps, required } => McpError::invalid_params(
+                err.to_string(),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "steps": steps,
+                    "required": required,
+                    "hint": "think_add",
+                })),
+            ),
+            FastThinkError::UnsavedConclusion(ref preview) => McpError::invalid_params(
+                format!(
+                    "{err} — discarding would lose it: call think_commit to save it, \
+                     or think_discard again with force=true"
+                ),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "conclusion_preview": preview,
+                    "hint": "think_commit",
+                })),
+            ),
+            FastThinkError::ThoughtNotFound => McpError::invalid_params(
+                format!(
+                    "{err} in this session; think_status(verbose=true) lists its thoughts \
+                     with their ids"
+                ),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "hint": "think_status",
+                })),
+            ),
+            FastThinkError::MemoryNotFound(ref memory_id) => McpError::invalid_params(
+                format!("{err}; search_incomplete_thoughts lists the ones to continue"),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "memory_id": memory_id,
+                    "error": err.code(),
+                    "hint": "search_incomplete_thoughts",
+                })),
+            ),
+            FastThinkError::NoteNotFound { note_idx, count } => McpError::invalid_params(
+                err.to_string(),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "note_idx": note_idx,
+                    // Valid indices are 0..count (none when count is 0).
+                    "valid_range": [0, count],
+                    "hint": "think_note",
+                })),
+            ),
+            // A recall holds the session: nothing failed, the same call
+            // succeeds once it returns.
+            FastThinkError::SessionBusy => McpError::invalid_request(
+                err.to_string(),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "retryable": true,
+                    "hint": "retry",
+                })),
+            ),
+            // think_uncommit refusals: the commit stands, nothing failed.
+            FastThinkError::NotRetained | FastThinkError::UndoWindowPassed => {
+                McpError::invalid_params(
+                    err.to_string(),
+                    Some(serde_json::json!({
+                        "session_id": session_id,
+                        "error": err.code(),
+                    })),
+                )
             }
-            HelixirClientError::Operation(msg) => McpError::internal_error(msg, None),
+            FastThinkError::ConfirmRequired { memories } => McpError::invalid_params(
+                err.to_string(),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "memories": memories,
+                    "hint": "think_uncommit",
+                })),
+            ),
+            FastThinkError::NotIncomplete(ref memory_id) => McpError::invalid_params(
+                format!("{err}; search_incomplete_thoughts lists the ones that are"),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "memory_id": memory_id,
+                    "error": err.code(),
+                    "hint": "search_incomplete_thoughts",
+                })),
+            ),
+            // The write failed but the session was put back: retrying the
+            // same commit is safe.
+            FastThinkError::CommitFailed(_) => McpError::internal_error(
+                format!("{err}; the session is still open — retry think_commit"),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": err.code(),
+                    "hint": "think_commit",
+                })),
+            ),
+            other => McpError::internal_error(
+                other.to_string(),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": other.code(),
+                })),
+            ),
+        }
+    }
+
+    /// Resolve one thought reference of a think tool: the stable id
+    /// (`id_field`) wins over the raw index (`idx_field`), which is refused
+    /// while `fast_think.accept_thought_idx` is off. `None` when neither is
+    /// given. An index is not checked here; the session rejects one that
+    /// names no thought.
+    pub(super) fn thought_ref(
+        &self,
+        key: &str,
+        session_id: &str,
+        id: Option<&str>,
+        idx: Option<u32>,
+        (id_field, idx_field): (&str, &str),
+    ) -> Result<Option<petgraph::stable_graph::NodeIndex>, McpError> {
+        if let Some(id) = id {
+            return self
+                .fast_think
+                .resolve_thought_id(key, id)
+                .map(Some)
+                .map_err(|e| Self::convert_think_error(e, session_id));
         }
+        let Some(idx) = idx else {
+            return Ok(None);
+        };
+        if !self.client().config().fast_think.accept_thought_idx {
+            return Err(McpError::invalid_params(
+                format!(
+                    "{idx_field} is not accepted by this server (fast_think.accept_thought_idx \
+                     is off); pass {id_field} with the thought's id instead"
+                ),
+                Some(serde_json::json!({
+                    "session_id": session_id,
+                    "error": "thought_idx_disabled",
+                    "field": idx_field,
+                })),
+            ));
+        }
+        Ok(Some(petgraph::stable_graph::NodeIndex::new(idx as usize)))
     }
 
     pub(super) fn result_to_json<T: Serialize>(result: T) -> Result<String, McpError> {
@@ -177,8 +453,38 @@ impl HelixirMcpServer {
     pub(super) fn result_to_value<T: Serialize>(result: T) -> Result<serde_json::Value, McpError> {
         serde_json::to_value(&result).map_err(|e| McpError::internal_error(e.to_string(), None))
     }
+
+    /// `user_id`'s stored overrides next to the settings they produce over
+    /// `global`, for get/set_user_config and the per-user config resource.
+    pub(super) fn user_config_report(
+        global: &HelixirConfig,
+        user_id: &str,
+        overrides: &UserConfigOverrides,
+    ) -> serde_json::Value {
+        let effective = global.for_user(overrides);
+        let modes = &effective.retrieval.search_modes;
+        serde_json::json!({
+            "user_id": user_id,
+            "overrides": overrides,
+            "effective": {
+                "default_search_mode": effective.default_search_mode,
+                "search_limits": {
+                    "recent": modes.recent.max_results,
+                    "contextual": modes.contextual.max_results,
+                    "deep": modes.deep.max_results,
+                    "full": modes.full.max_results,
+                },
+                "search_windows": effective.windows,
+            },
+            "precedence": USER_CONFIG_PRECEDENCE,
+        })
+    }
 }
 
+/// How a per-user override combines with the rest of the config.
+pub(super) const USER_CONFIG_PRECEDENCE: &str =
+    "call argument > user override > global config (config file, then env) > built-in default";
+
 /// Returns true when HelixDB's error message indicates that a user-scoped
 /// traversal failed because the User node has no outgoing edges (or doesn't
 /// exist yet). Callers should treat this as "empty result", not as a hard
@@ -354,8 +660,17 @@ pub async fn run_gateway_with_options(bind: &str, require_auth: bool) -> anyhow:
         Arc::clone(&template.fast_think),
         Arc::clone(&template.ingest_worker),
     );
+    // Each MCP session (connection) gets its own namespace when enabled.
+    let namespace_sessions = template.client().config().fast_think.namespace_sessions;
+    let connections = std::sync::atomic::AtomicU64::new(0);
     let service = StreamableHttpService::new(
-        move || Ok(template.clone()),
+        move || {
+            if !namespace_sessions {
+                return Ok(template.clone());
+            }
+            let n = connections.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok(template.with_session_namespace(&format!("conn{n}")))
+        },
         Arc::new(LocalSessionManager::default()),
         Default::default(),
     );
@@ -382,6 +697,215 @@ mod tests {
         toolkit::fast_think::{FastThinkLimits, FastThinkManager},
     };
 
+    #[test]
+    fn session_errors_are_invalid_params_with_the_session_id() {
+        use crate::toolkit::fast_think::FastThinkError;
+        use rmcp::model::ErrorCode;
+
+        for err in [
+            FastThinkError::SessionNotFound,
+            FastThinkError::SessionAlreadyExists,
+        ] {
+            let e = HelixirMcpServer::convert_think_error(err, "s-42");
+            assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+            assert!(e.message.contains("think_list"));
+            let data = e.data.expect("session errors carry data");
+            assert_eq!(data["session_id"], "s-42");
+        }
+
+        let e = HelixirMcpServer::convert_think_error(FastThinkError::NoConclusion, "s-42");
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(e.data.expect("carries data")["hint"], "think_conclude");
+
+        let e = HelixirMcpServer::convert_think_error(
+            FastThinkError::UnsavedConclusion("use redis".to_string()),
+            "s-42",
+        );
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        let data = e.data.expect("carries data");
+        assert_eq!(data["conclusion_preview"], "use redis");
+        assert_eq!(data["hint"], "think_commit");
+
+        let e = HelixirMcpServer::convert_think_error(
+            FastThinkError::TooDeep {
+                max_depth: 10,
+                parent_depth: 10,
+            },
+            "s-42",
+        );
+        assert_eq!(e.code, ErrorCode::INTERNAL_ERROR);
+
+        let e = HelixirMcpServer::convert_think_error(
+            FastThinkError::CommitFailed("connection refused".to_string()),
+            "s-42",
+        );
+        assert_eq!(e.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(e.data.expect("carries data")["error"], "commit_failed");
+    }
+
+    #[test]
+    fn every_tool_error_carries_a_stable_code() {
+        use crate::core::helixir_client::HelixirClientError;
+        use rmcp::{ErrorData as McpError, model::ErrorCode};
+
+        let e = HelixirMcpServer::convert_error(HelixirClientError::Database("down".into()));
+        assert_eq!(e.data.expect("coded")["error"], "database");
+
+        let e = HelixirMcpServer::convert_think_error(
+            crate::toolkit::fast_think::FastThinkError::TooManyThoughts,
+            "s-42",
+        );
+        assert_eq!(e.data.expect("coded")["error"], "too_many_thoughts");
+        let e = HelixirMcpServer::convert_think_error(
+            crate::toolkit::fast_think::FastThinkError::MemoryNotFound("mem_gone".into()),
+            "s-42",
+        );
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(e.data.expect("coded")["error"], "memory_not_found");
+        let e = HelixirMcpServer::convert_think_error(
+            crate::toolkit::fast_think::FastThinkError::NotIncomplete("mem_plain".into()),
+            "s-42",
+        );
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(e.data.expect("coded")["error"], "not_incomplete");
+        let e = HelixirMcpServer::convert_think_error(
+            crate::toolkit::fast_think::FastThinkError::NoteNotFound {
+                note_idx: 5,
+                count: 2,
+            },
+            "s-42",
+        );
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        let data = e.data.expect("coded");
+        assert_eq!(data["error"], "note_not_found");
+        assert_eq!(data["valid_range"], serde_json::json!([0, 2]));
+        for (err, code) in [
+            (
+                crate::toolkit::fast_think::FastThinkError::ConfirmRequired { memories: 2 },
+                "confirm_required",
+            ),
+            (
+                crate::toolkit::fast_think::FastThinkError::UndoWindowPassed,
+                "undo_window_passed",
+            ),
+            (
+                crate::toolkit::fast_think::FastThinkError::NotRetained,
+                "not_retained",
+            ),
+        ] {
+            let e = HelixirMcpServer::convert_think_error(err, "s-42");
+            assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+            assert_eq!(e.data.expect("coded")["error"], code);
+        }
+        let e = HelixirMcpServer::convert_think_error(
+            crate::toolkit::fast_think::FastThinkError::SessionBusy,
+            "s-42",
+        );
+        assert_eq!(e.code, ErrorCode::INVALID_REQUEST);
+        let data = e.data.expect("coded");
+        assert_eq!(data["error"], "session_busy");
+        assert_eq!(data["retryable"], true);
+        let e = HelixirMcpServer::convert_think_error(
+            crate::toolkit::fast_think::FastThinkError::TooFewThoughts {
+                steps: 1,
+                required: 3,
+            },
+            "s-42",
+        );
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        let data = e.data.expect("coded");
+        assert_eq!(data["error"], "too_few_thoughts");
+        assert_eq!(data["required"], 3);
+
+        let bare = HelixirMcpServer::ensure_error_code(McpError::invalid_params("bad", None));
+        assert_eq!(bare.data.expect("coded")["error"], "invalid_params");
+        let kept = HelixirMcpServer::ensure_error_code(McpError::internal_error(
+            "x",
+            Some(serde_json::json!({ "error": "commit_failed", "hint": "think_commit" })),
+        ));
+        assert_eq!(kept.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(kept.data.as_ref().unwrap()["error"], "commit_failed");
+        let wrapped = HelixirMcpServer::ensure_error_code(McpError::internal_error(
+            "x",
+            Some(serde_json::json!("raw")),
+        ));
+        assert_eq!(
+            wrapped.data,
+            Some(serde_json::json!({ "error": "internal_error", "detail": "raw" }))
+        );
+    }
+
+    #[tokio::test]
+    async fn session_namespace_prefixes_keys_and_hides_other_connections() {
+        let client = HelixirClient::new(HelixirConfig::default()).unwrap();
+        let shared = HelixirMcpServer::new(client);
+        assert_eq!(shared.session_key("plan"), "plan");
+        assert_eq!(shared.caller_session_id("conn0/plan"), Some("conn0/plan"));
+
+        let a = shared.with_session_namespace("conn0");
+        let b = shared.with_session_namespace("conn1");
+        assert_eq!(a.session_key("plan"), "conn0/plan");
+        assert_ne!(a.session_key("plan"), b.session_key("plan"));
+        assert_eq!(a.caller_session_id("conn0/plan"), Some("plan"));
+        assert_eq!(a.caller_session_id("conn1/plan"), None);
+        assert_eq!(a.caller_session_id("conn01/plan"), None);
+        assert_eq!(a.caller_session_id("plan"), None);
+    }
+
+    #[tokio::test]
+    async fn thought_refs_prefer_ids_and_raw_indices_can_be_refused() {
+        use rmcp::model::ErrorCode;
+
+        let mut config = HelixirConfig::default();
+        config.fast_think.accept_thought_idx = false;
+        let server = HelixirMcpServer::new(HelixirClient::new(config).unwrap());
+        let root = server.fast_think.start_thinking("s", "which db?").unwrap();
+        let root_id = server
+            .fast_think
+            .thought_ids("s", &[root])
+            .unwrap()
+            .remove(0);
+
+        let fields = ("parent_id", "parent_idx");
+        let found = server
+            .thought_ref("s", "s", Some(&root_id), Some(99), fields)
+            .unwrap();
+        assert_eq!(found, Some(root));
+        assert_eq!(
+            server.thought_ref("s", "s", None, None, fields).unwrap(),
+            None
+        );
+
+        let e = server
+            .thought_ref("s", "s", None, Some(root.index() as u32), fields)
+            .unwrap_err();
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(
+            e.data.expect("carries data")["error"],
+            "thought_idx_disabled"
+        );
+
+        let e = server
+            .thought_ref("s", "s", Some("no-such-thought"), None, fields)
+            .unwrap_err();
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(e.data.expect("carries data")["error"], "thought_not_found");
+    }
+
+    #[test]
+    fn blank_query_is_invalid_params_unless_browse_is_configured() {
+        use rmcp::model::ErrorCode;
+
+        let mut config = HelixirConfig::default();
+        let e = HelixirMcpServer::check_empty_query_allowed(&config, "search_memory")
+            .expect_err("reject is the default");
+        assert_eq!(e.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(e.data.expect("carries data")["error"], "empty_query");
+
+        config.empty_query_browse = true;
+        assert!(HelixirMcpServer::check_empty_query_allowed(&config, "think_recall").is_ok());
+    }
+
     #[test]
     fn empty_user_graph_error_matches_helixdb_payload() {
         let msg = r#"Query failed: Got Error from server: {"error":"Graph error: No value found","code":"GRAPH_ERROR"}"#;
@@ -0,0 +1,262 @@
+//! Deployment self-test: one scripted pass over the write, search and
+//! FastThink paths against a throwaway user, so an operator can tell which
+//! subsystem (HelixDB, LLM, embeddings) is miswired. Everything the run
+//! stores is purged before it returns.
+
+use std::time::Instant;
+
+use rmcp::{
+    ErrorData as McpError, handler::server::wrapper::Parameters, model::*, tool, tool_router,
+};
+use serde::Serialize;
+use serde_json::json;
+use tracing::{info, warn};
+
+use crate::core::helixir_client::SearchParams;
+use crate::mcp::params::*;
+use crate::mcp::server::HelixirMcpServer;
+use crate::toolkit::fast_think::{ThoughtOptions, ThoughtType};
+
+const SELF_TEST_FACT: &str = "Helixir self-test: the deployment check stores this fact.";
+const SELF_TEST_QUERY: &str = "Helixir self-test deployment check";
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum StepStatus {
+    Pass,
+    Fail,
+    /// Not run because a step it depends on failed.
+    Skip,
+}
+
+#[derive(Debug, Serialize)]
+struct StepReport {
+    step: &'static str,
+    status: StepStatus,
+    elapsed_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+}
+
+/// Step results in run order.
+#[derive(Debug, Default)]
+struct SelfTest {
+    steps: Vec<StepReport>,
+}
+
+impl SelfTest {
+    /// Record `step` from its outcome (`Ok(detail)` / `Err(error)`); returns
+    /// whether it passed.
+    fn record(
+        &mut self,
+        step: &'static str,
+        started: Instant,
+        outcome: Result<String, String>,
+    ) -> bool {
+        let passed = outcome.is_ok();
+        if let Err(e) = &outcome {
+            warn!("self_test: {step} failed: {e}");
+        }
+        self.steps.push(StepReport {
+            step,
+            status: if passed {
+                StepStatus::Pass
+            } else {
+                StepStatus::Fail
+            },
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            detail: Some(outcome.unwrap_or_else(|e| e)).filter(|d| !d.is_empty()),
+        });
+        passed
+    }
+
+    fn skip(&mut self, step: &'static str, after: &str) {
+        self.steps.push(StepReport {
+            step,
+            status: StepStatus::Skip,
+            elapsed_ms: 0,
+            detail: Some(format!("{after} failed")),
+        });
+    }
+
+    fn failed(&self) -> Vec<&'static str> {
+        self.steps
+            .iter()
+            .filter(|s| s.status == StepStatus::Fail)
+            .map(|s| s.step)
+            .collect()
+    }
+}
+
+#[tool_router(router = diagnostics_router, vis = "pub(super)")]
+impl HelixirMcpServer {
+    #[tool(
+        description = "ADMIN / DIAGNOSTIC: verify a deployment end-to-end in one call. Runs a scripted sequence as a throwaway user — add_memory (LLM extraction + embeddings + HelixDB write), search_memory for it (embeddings + vector search), think_start / think_add / think_conclude / think_commit (FastThink and its commit path) — then purges that user so no data is left behind. A step whose prerequisite failed is skipped. Takes a few seconds to a minute, depending on the LLM. Returns {passed, user_id, total_ms, failed: [step], steps: [{step, status: pass|fail|skip, elapsed_ms, detail}]}; the first failing step names the broken subsystem."
+    )]
+    async fn self_test(
+        &self,
+        Parameters(_params): Parameters<SelfTestParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let run = uuid::Uuid::new_v4().simple().to_string();
+        let user_id = format!("helixir_self_test_{run}");
+        let key = self.session_key(&format!("self_test_{run}"));
+        let client = self.client();
+        let total = Instant::now();
+        let mut report = SelfTest::default();
+        info!("self_test: starting as {user_id}");
+
+        let started = Instant::now();
+        let added = client
+            .add(SELF_TEST_FACT, &user_id, None, None)
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|r| {
+                if r.memory_ids.is_empty() && r.deduped.is_empty() {
+                    Err("nothing was stored".to_string())
+                } else {
+                    Ok([r.memory_ids, r.deduped].concat())
+                }
+            });
+        let added_ids = added.as_ref().ok().cloned().unwrap_or_default();
+        let add_ok = report.record(
+            "add_memory",
+            started,
+            added.map(|ids| format!("stored {}", ids.join(", "))),
+        );
+
+        if add_ok {
+            let started = Instant::now();
+            let found = client
+                .search(SELF_TEST_QUERY, &user_id, SearchParams::default())
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(
+                    |hits| match hits.iter().position(|h| added_ids.contains(&h.id)) {
+                        Some(rank) => Ok(format!("found at rank {} of {}", rank + 1, hits.len())),
+                        None => Err(format!("stored memory not among {} hits", hits.len())),
+                    },
+                );
+            report.record("search_memory", started, found);
+        } else {
+            report.skip("search_memory", "add_memory");
+        }
+
+        let started = Instant::now();
+        let root = self
+            .fast_think
+            .start_thinking(&key, "Is this deployment wired correctly?")
+            .map_err(|e| e.to_string());
+        let start_ok = report.record(
+            "think_start",
+            started,
+            root.as_ref().map(|_| String::new()).map_err(Clone::clone),
+        );
+        let root = root.ok();
+
+        let mut step = None;
+        if start_ok {
+            let started = Instant::now();
+            let added = self
+                .fast_think
+                .add_thought(
+                    &key,
+                    "The memory write and search steps ran.",
+                    ThoughtType::Observation,
+                    root,
+                    ThoughtOptions::default(),
+                )
+                .map_err(|e| e.to_string());
+            step = added.as_ref().ok().copied();
+            report.record("think_add", started, added.map(|_| String::new()));
+        } else {
+            report.skip("think_add", "think_start");
+        }
+
+        let mut concluded = false;
+        if let Some(step) = step {
+            let started = Instant::now();
+            let outcome = self
+                .fast_think
+                .conclude(&key, "The self-test session concluded.", &[step])
+                .map(|_| String::new())
+                .map_err(|e| e.to_string());
+            concluded = report.record("think_conclude", started, outcome);
+        } else {
+            report.skip("think_conclude", "think_add");
+        }
+
+        if concluded {
+            let started = Instant::now();
+            let outcome = self
+                .fast_think
+                .commit(&key, &user_id, None, Some(false), None, false)
+                .await
+                .map(|c| format!("stored {}", c.memory_id))
+                .map_err(|e| e.to_string());
+            report.record("think_commit", started, outcome);
+        } else {
+            report.skip("think_commit", "think_conclude");
+        }
+
+        let started = Instant::now();
+        if start_ok {
+            // A committed session is gone already; this drops one a failed
+            // step left open.
+            let _ = self.fast_think.discard(&key, true);
+        }
+        let sessions = self.fast_think.discard_user_sessions(&user_id);
+        // Only unlinks a probe fact a real user also holds; theirs stays.
+        let cleaned = client
+            .purge_user(&user_id, &user_id)
+            .await
+            .map(|p| {
+                format!(
+                    "purged {} memories, {} entities, {} sessions",
+                    p.memories, p.entities, sessions
+                )
+            })
+            .map_err(|e| format!("{e}; data may remain under {user_id}"));
+        report.record("cleanup", started, cleaned);
+
+        let failed = report.failed();
+        info!(
+            "self_test: {} ({} steps, {} failed)",
+            if failed.is_empty() {
+                "passed"
+            } else {
+                "FAILED"
+            },
+            report.steps.len(),
+            failed.len()
+        );
+        let json = Self::result_to_json(json!({
+            "passed": failed.is_empty(),
+            "user_id": user_id,
+            "total_ms": total.elapsed().as_millis() as u64,
+            "failed": failed,
+            "steps": report.steps,
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_are_listed_and_skips_are_not() {
+        let mut report = SelfTest::default();
+        assert!(!report.record("add_memory", Instant::now(), Err("db down".to_string())));
+        report.skip("search_memory", "add_memory");
+        assert!(report.record("think_start", Instant::now(), Ok(String::new())));
+
+        assert_eq!(report.failed(), ["add_memory"]);
+        let steps = serde_json::to_value(&report.steps).unwrap();
+        assert_eq!(steps[0]["status"], "fail");
+        assert_eq!(steps[0]["detail"], "db down");
+        assert_eq!(steps[1]["status"], "skip");
+        assert_eq!(steps[1]["detail"], "add_memory failed");
+        assert!(steps[2].get("detail").is_none());
+    }
+}
@@ -10,8 +10,20 @@ use rmcp::{
 use serde_json::json;
 use tracing::{debug, info, warn};
 
+use crate::core::config::UserConfigOverrides;
+use crate::core::helixir_client::{CacheKind, ImportOptions, ListPage};
 use crate::mcp::params::*;
+use crate::mcp::response_limit;
 use crate::mcp::server::{HelixirMcpServer, is_empty_user_graph_error};
+use crate::toolkit::fast_think::ThoughtEdge;
+use crate::toolkit::mind_toolbox::memory::MemoryReference;
+use crate::toolkit::mind_toolbox::reasoning::ReasoningType;
+use crate::toolkit::tooling_manager::MemoryListFilter;
+use crate::toolkit::tooling_manager::rebuild::RebuildOptions;
+
+/// Room `list_memories` keeps for its `{page: ...}` block under the
+/// response cap.
+const LIST_META_BYTES: usize = 160;
 
 #[tool_router(router = memory_router, vis = "pub(super)")]
 impl HelixirMcpServer {
@@ -22,8 +34,13 @@ impl HelixirMcpServer {
         \n- ok:true + memory_ids = stored now.\
         \n- ok:true + status:'accepted' + pending_id = buffered write still finishing; searchable within seconds; optionally confirm via get_add_status(pending_id). Still SUCCESS.\
         \n- memories_added:0 with non-empty 'deduped' = this fact was ALREADY known and got linked ('saved' = memories_added + deduped). SUCCESS, not a failure.\
+        \n- duplicate:true = the server's write-time duplicate check (HELIXIR_DEDUP_ON_ADD=skip) found every fact already stored; the existing ids are in 'deduped'. SUCCESS. 'duplicates' lists each near-match [{new_content, existing_memory_id, existing_content, similarity, action: skipped|warned, suggestion}] — for 'warned' the fact was stored anyway, consider update_memory on the existing one.\
+        \n- no_facts_extracted:true = nothing factual was found in the message: with memories_added:1 it was stored verbatim; with ok:false nothing was stored — rephrase as explicit statements and retry.\
+        \n- needs_embedding:[ids] = the embedding service failed and the server stores facts anyway (HELIXIR_ON_EMBED_FAIL=defer): saved, but semantic search misses them until reindex_embeddings runs. SUCCESS.\
         \n- Only ok:false / status:'failed' is a real failure.\
         \n- 'pending_outcomes' = results of EARLIER buffered adds, delivered opportunistically.\
+        \nstore_source:true keeps the whole original message as a source node; facts extracted from it then come back from search_memory with metadata.source_id + source_excerpt.\
+        \nreferences:[{uri, locator, title}] records the file/document the text came from on every created memory; search_memory returns them as metadata.references so you can cite \"from design_doc.md §3\".\
         \nneeds_clarification: if non-empty, the memory charter refused to silently resolve a conflict (e.g. a reversed preference). Ask the user each suggested_question (or apply a standing rule), then store the answer as a new memory. Never ignore it."
     )]
     async fn add_memory(
@@ -55,7 +72,25 @@ impl HelixirMcpServer {
         // briefly wait for THIS write to finish and return its real result, so
         // the agent gets memory_ids it can trust — never a bare "pending" it
         // misreads as failure (which made swarm agents retry or defect).
-        if crate::toolkit::tooling_manager::ingest_buffer::buffer_enabled() {
+        // store_source and references writes run inline: the queued
+        // PendingInput carries no per-write options, and the caller asked for
+        // a specific shape.
+        let store_source = params.store_source.unwrap_or(false);
+        let references: Vec<MemoryReference> = params
+            .references
+            .iter()
+            .flatten()
+            .filter(|r| !r.uri.trim().is_empty())
+            .map(|r| MemoryReference {
+                uri: r.uri.trim().to_string(),
+                locator: r.locator.clone().filter(|l| !l.trim().is_empty()),
+                title: r.title.clone().filter(|t| !t.trim().is_empty()),
+            })
+            .collect();
+        if !store_source
+            && references.is_empty()
+            && crate::toolkit::tooling_manager::ingest_buffer::buffer_enabled()
+        {
             use crate::toolkit::tooling_manager::ingest_buffer::{STATUS_DONE, STATUS_FAILED};
             let enq = self
                 .client()
@@ -113,6 +148,7 @@ impl HelixirMcpServer {
                     "message": "Saved to memory; still processing in the background and \
                                 searchable within a few seconds. This is SUCCESS — do NOT retry. \
                                 Optionally confirm later with get_add_status(pending_id).",
+                    "message_code": "accepted_processing",
                 }),
             };
             json["pending_id"] = json!(enq.pending_id);
@@ -124,16 +160,26 @@ impl HelixirMcpServer {
             )]));
         }
 
-        let result = self
-            .client()
-            .add(
-                &params.message,
-                &params.user_id,
-                params.agent_id.as_deref(),
-                None,
-            )
-            .await
-            .map_err(Self::convert_error)?;
+        let result = if store_source {
+            self.client()
+                .add_with_source(
+                    &params.message,
+                    &params.user_id,
+                    params.agent_id.as_deref(),
+                    None,
+                )
+                .await
+        } else {
+            self.client()
+                .add(
+                    &params.message,
+                    &params.user_id,
+                    params.agent_id.as_deref(),
+                    None,
+                )
+                .await
+        }
+        .map_err(Self::convert_error)?;
 
         info!(
             "Added {} memories ({} chunks)",
@@ -147,11 +193,110 @@ impl HelixirMcpServer {
         let mut json = Self::result_to_value(&result)?;
         json["ok"] = json!(true);
         json["saved"] = json!(result.memories_added + result.deduped.len());
+        // Zero extracted facts is never a silent success: either the raw
+        // message was stored as one fallback memory, or (skip policy)
+        // nothing was — then the write did NOT happen and ok says so.
+        if result.no_facts_extracted {
+            if result.memories_added == 0 && result.deduped.is_empty() {
+                json["ok"] = json!(false);
+                json["hint"] = json!(
+                    "No facts could be extracted and nothing was stored. Rephrase the message as explicit statements and call add_memory again."
+                );
+            } else {
+                json["hint"] = json!(
+                    "No facts could be extracted; the message was stored verbatim as one memory."
+                );
+            }
+            json["hint_code"] = json!("no_facts_extracted");
+        } else if !result.needs_embedding.is_empty() {
+            json["hint"] = json!(
+                "Stored without embeddings: the embedding service failed. Semantic search misses these memories until reindex_embeddings runs; tags and the graph still find them."
+            );
+            json["hint_code"] = json!("needs_embedding");
+        }
+        // References ride on the memories this call created; deduped rows may
+        // belong to another user and stay untouched.
+        if !references.is_empty() {
+            let attached = self
+                .client()
+                .attach_references(&result.memory_ids, &references)
+                .await
+                .map_err(Self::convert_error)?;
+            json["references_attached"] = json!(attached);
+        }
         Ok(CallToolResult::success(vec![Content::text(
             json.to_string(),
         )]))
     }
 
+    #[tool(
+        description = "Ask BEFORE add_memory whether a message is worth saving. Applies the importance heuristics server-side: decisions, outcomes, preferences and architecture facts rate high; hypotheses and plans medium; tool output (grep hits, compiler/lint output, traces, diffs), fragments and temporary notes low. With user_id it also looks up the closest existing memory: a near-verbatim match means don't add (it would be deduped), a similar one means prefer update_memory. No LLM call. Returns {recommend, importance: low|medium|high, reason, signals, similar?: {memory_id, content, similarity, duplicate}, similarity_checked}."
+    )]
+    async fn should_remember(
+        &self,
+        Parameters(params): Parameters<ShouldRememberParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let advice = self
+            .client()
+            .should_remember(&params.message, params.user_id.as_deref())
+            .await
+            .map_err(Self::convert_error)?;
+        let json = Self::result_to_json(&advice)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "DIAGNOSTIC: run one query in every search mode (recent ~4h, contextual ~30d, deep ~90d, full = all history) concurrently and compare — use it to pick a mode when search_memory finds less than expected ('recent finds nothing, deep finds 12'). Personal scope; not counted as accesses. Returns {query, modes: [{mode, description, result_count, capped, top?: {id, content, score, ...}, error?}]}."
+    )]
+    async fn compare_search_modes(
+        &self,
+        Parameters(params): Parameters<CompareSearchModesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let modes = self
+            .client()
+            .compare_search_modes(
+                &params.query,
+                &params.user_id,
+                params.limit.map(|l| l as usize),
+            )
+            .await
+            .map_err(Self::convert_error)?;
+        let json = Self::result_to_json(json!({ "query": params.query, "modes": modes }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Estimate what an add_memory of this message would cost BEFORE running it — no LLM call, nothing stored. Use it to budget large or batch ingestions. Token counts are a character-based approximation and the cost uses the server's list-price table (llm_runtime.pricing) for the configured model (null when the model is not in the table; local Ollama is 0). Returns {message_chars, input_tokens, expected_output_tokens, expected_chunks, provider, model, estimated_cost_usd}."
+    )]
+    async fn estimate_add(
+        &self,
+        Parameters(params): Parameters<EstimateAddParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let estimate = self.client().estimate_add(&params.message);
+        let json = Self::result_to_json(&estimate)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "List the values the enum-typed parameters accept, so a client can build pickers or validate input before calling: concept_types (search_by_concept), chain_modes (search_reasoning_chain), search_modes, search_scopes and search_sorts (search_memory), thought_types and thought_edge_types (think_add), and memory_relation_types (the reasoning edges between memories). Static; nothing is read from the store."
+    )]
+    async fn capabilities(
+        &self,
+        Parameters(_params): Parameters<CapabilitiesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let json = Self::result_to_json(json!({
+            "concept_types": OntologyType::ALL.map(OntologyType::as_str),
+            "chain_modes": ChainMode::ALL.map(ChainMode::as_str),
+            "search_modes": SearchMode::ALL.map(SearchMode::as_str),
+            "search_scopes": SearchScope::ALL.map(SearchScope::as_str),
+            "search_sorts": SearchSort::ALL.map(SearchSort::as_str),
+            "thought_types": ThoughtTypeArg::ALL.map(ThoughtTypeArg::as_str),
+            "thought_edge_types": ThoughtEdge::ALL.map(|e| e.to_string()),
+            "memory_relation_types": ReasoningType::ALL.map(|r| r.edge_name()),
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
     #[tool(
         description = "Check the status of a buffered add_memory by its pending_id. Returns {status: pending|processing|done|failed|not_found, result?, error?}. Optional — outcomes are also delivered opportunistically as pending_outcomes on your next add_memory, so polling is not required."
     )]
@@ -169,17 +314,32 @@ impl HelixirMcpServer {
     }
 
     #[tool(
-        description = "Recall memories by meaning — the DEFAULT retrieval tool (hybrid dense + keyword + graph, no LLM call). Use it to answer 'what do I know about X'. Pick a sibling instead when: you want the WHY behind something -> search_reasoning_chain; to bridge two specific concepts -> connect_memories; to filter by ontology type/tags -> search_by_concept; to dump everything for a user -> list_memories. 'mode' sets recall breadth (recent ~4h / contextual ~30d default / deep ~90d / full = whole store; use full if a query you expect to match returns empty). 'time_from'/'time_to' (RFC3339 or YYYY-MM-DD) bound recall to an explicit EVENT-time window; memories outside the window that are linked to in-window results via the graph still return as FLASHBACKS — flagged metadata.flashback=true with their event_date, capped separately so they never crowd in-window rows. 'scope' defaults to personal; collective/all need the collective tier and are downgraded to personal otherwise. Returns ranked [{memory_id, content, score, metadata}] where metadata carries provenance (origin, edge, ppr, cosine). When a result's metadata has 'collapsed', those memory_ids are the same story folded under this row (a raw source and its extracted atoms never coexist in one window) — the content is NOT lost; fetch a folded id explicitly if you need its exact wording. A result with 'superseded: true' is OUTDATED (ranked down, kept for history) — 'superseded_by' names the current version; never act on a superseded row as current truth."
+        description = "Recall memories by meaning — the DEFAULT retrieval tool (hybrid dense + keyword + graph, no LLM call). Use it to answer 'what do I know about X'. Pick a sibling instead when: you want the WHY behind something -> search_reasoning_chain; to bridge two specific concepts -> connect_memories; to filter by ontology type/tags -> search_by_concept; to dump everything for a user -> list_memories. 'mode' sets recall breadth (recent ~4h / contextual ~30d default / deep ~90d / full = whole store; use full if a query you expect to match returns empty). Without 'limit' each mode has its own cap (recent 10 / contextual 20 / deep 50 / full 100, server-configurable); the LAST content block is {search_meta: {effective_limit, limit_source: requested|mode_default, capped, window: {from, to, source: explicit|temporal_days|none}, total_in_window, total_overall}} — capped=true means more rows may exist, so raise 'limit' to see them; the totals come with an empty result (or with window_counts:true): total_in_window < total_overall means the window excluded matches, so widen 'mode'/'time_from' or drop temporal_days, while total_overall=0 means nothing is stored for this user. 'time_from'/'time_to' (RFC3339 or YYYY-MM-DD) bound recall to an explicit EVENT-time window; memories outside the window that are linked to in-window results via the graph still return as FLASHBACKS — flagged metadata.flashback=true with their event_date, capped separately so they never crowd in-window rows. 'scope' defaults to personal; collective/all need the collective tier and are downgraded to personal otherwise. Returns ranked [{memory_id, content, score, hops, metadata}] where metadata carries provenance (origin, edge, ppr, cosine). hops=0 is a direct semantic match; hops=N was reached N relation hops away by graph expansion, so trust low-hop rows first. When a result's metadata has 'collapsed', those memory_ids are the same story folded under this row (a raw source and its extracted atoms never coexist in one window) — the content is NOT lost; fetch a folded id explicitly if you need its exact wording. A result with 'superseded: true' is OUTDATED (ranked down, kept for history) — 'superseded_by' names the current version; never act on a superseded row as current truth. 'sort' re-orders the rows: relevance (default), recently_accessed or frequently_used - every row a search returns counts as an access, so these surface your working set; rows then carry metadata.access_count / last_accessed_at. newest / oldest order the same rows by when they were stored (pair with time_from/time_to to browse a period in order). 'hybrid:true' forces keyword (BM25) + vector fusion — use it for exact identifiers (error codes, function names, ids) that embed poorly; rows then carry metadata.vector_rrf / bm25_rrf (null = not found by that arm). 'source_id' + 'source_excerpt' point at the original message a fact was extracted from (stored with add_memory store_source, or long inputs). A blank query is rejected (invalid_params) unless the server is configured to treat it as 'browse recent'. 'within_ids' re-ranks only the listed memory_ids (max 200) against the query — narrow with one search, then refine with another over just those hits; rows carry metadata.origin='within_ids' and mode/scope/time window/hybrid are ignored. 'boost_concept' (an ontology type) ranks memories of that type higher while keeping the rest; rows report the factor in metadata.concept_boost."
     )]
     async fn search_memory(
         &self,
         Parameters(params): Parameters<SearchMemoryParams>,
     ) -> Result<CallToolResult, McpError> {
+        let limit = params.limit.map(|l| l as usize);
+        if params.query.trim().is_empty() {
+            Self::check_empty_query_allowed(self.client().config(), "search_memory")?;
+            let results = self
+                .client()
+                .recent(&params.user_id, limit)
+                .await
+                .map_err(Self::convert_error)?;
+            info!("Blank query: browsing {} recent memories", results.len());
+            return Ok(CallToolResult::success(vec![Content::text(
+                Self::result_to_json(&results)?,
+            )]));
+        }
+
+        // The user's overrides fill what the call leaves unset.
+        let effective = self.client().effective_config(&params.user_id).await;
         let mode = params
             .mode
             .map(|m| m.as_str().to_string())
-            .unwrap_or_else(|| self.client().config().default_search_mode.clone());
-        let limit = params.limit.map(|l| l as usize);
+            .unwrap_or_else(|| effective.default_search_mode.clone());
         // Default scope is intentionally personal (GH #40): collective memory
         // stays hidden unless explicitly requested, so weak models aren't
         // flooded with other users' facts. Not a config knob — a safety default.
@@ -216,25 +376,47 @@ impl HelixirMcpServer {
             }
         }
 
+        // Per-mode cap when the caller names none, so an unbounded `full`
+        // recall can't return the whole store.
+        let limit_source = if limit.is_some() {
+            "requested"
+        } else {
+            "mode_default"
+        };
+        let limit = limit.unwrap_or_else(|| {
+            effective
+                .retrieval
+                .search_modes
+                .limit_for(crate::core::search_modes::SearchMode::parse_mode(&mode))
+        });
+
         let query_preview: String = params.query.chars().take(50).collect();
         info!(
-            "Searching: '{}' [mode={}, limit={:?}, scope={}, window={:?}..{:?}]",
+            "Searching: '{}' [mode={}, limit={}, scope={}, window={:?}..{:?}]",
             query_preview, mode, limit, scope, window.from, window.to
         );
 
+        let within_ids = params.within_ids.is_some();
         let results = self
             .client()
-            .search(
+            .search_with(
                 &params.query,
                 &params.user_id,
                 crate::core::helixir_client::SearchParams {
-                    limit,
+                    limit: Some(limit),
                     search_mode: Some(mode.clone()),
                     temporal_days: params.temporal_days,
                     graph_depth: params.graph_depth.map(|d| d as usize),
                     scope: Some(scope.to_string()),
                     window,
+                    hybrid: params.hybrid,
+                    sort: params.sort.map(|s| s.as_str().to_string()),
+                    entity_attributes: params.entity_attributes.unwrap_or_default(),
+                    within_ids: params.within_ids,
+                    min_score: params.min_score,
+                    boost_concept: params.boost_concept.map(|c| c.as_str().to_string()),
                 },
+                &effective,
             )
             .await
             .map_err(Self::convert_error)?;
@@ -253,83 +435,307 @@ impl HelixirMcpServer {
             && results.len() < threshold
             && self.client().config().mode.collective_enabled()
         {
-            contents.push(Content::text(format!(
-                "Hint: personal scope returned {} result(s). If you expected more, retry search_memory with scope=\"collective\" to include the shared collective memory; or call list_users to check which user_id holds the knowledge.",
-                results.len()
-            )));
+            // Machine output has no prose to strip a code from here, so the
+            // hint block itself becomes the code.
+            contents.push(Content::text(if self.client().config().machine_output {
+                json!({ "hint_code": "retry_collective_scope", "returned": results.len() })
+                    .to_string()
+            } else {
+                format!(
+                    "Hint: personal scope returned {} result(s). If you expected more, retry search_memory with scope=\"collective\" to include the shared collective memory; or call list_users to check which user_id holds the knowledge.",
+                    results.len()
+                )
+            }));
         }
+        // Last block, after the optional hint, so content[0]/content[1] keep
+        // their positions. `capped` means more rows may exist past the limit.
+        let mut meta = json!({
+            "effective_limit": limit,
+            "limit_source": limit_source,
+            "capped": results.len() >= limit,
+        });
+        // The resolved window and, for an empty result or on request, how
+        // much memory it covers, so the caller can tell whether widening
+        // would help. within_ids ignores the window; a failed count only
+        // drops these fields.
+        if !within_ids {
+            let count = results.is_empty() || params.window_counts.unwrap_or(false);
+            match self
+                .client()
+                .search_window(
+                    &params.user_id,
+                    &effective,
+                    Some(&mode),
+                    params.temporal_days,
+                    window,
+                    count,
+                )
+                .await
+            {
+                Ok(report) => {
+                    meta["window"] = json!({
+                        "from": report.from,
+                        "to": report.to,
+                        "source": report.source,
+                    });
+                    if let (Some(in_window), Some(overall)) =
+                        (report.total_in_window, report.total_overall)
+                    {
+                        meta["total_in_window"] = json!(in_window);
+                        meta["total_overall"] = json!(overall);
+                    }
+                }
+                Err(e) => warn!("search_memory: window counts failed: {e}"),
+            }
+        }
+        contents.push(Content::text(json!({ "search_meta": meta }).to_string()));
         Ok(CallToolResult::success(contents))
     }
 
     #[tool(
-        description = "Dump a user's memories in bulk (newest first), with NO ranking by relevance — use it for counting, auditing, or seeing everything; for 'what's relevant to X' use search_memory instead. Optionally restrict to one ontology type via memory_type. Capped by 'limit' (default 100) and truncated on large stores, so it is not a substitute for search. Returns [{memory_id, content, memory_type, created_at, importance, certainty}]."
+        description = "ADMIN / IRREVERSIBLE: erase everything stored for one user — their memories with embeddings and chunks, every relation on those memories, entities no other memory still references, the User node, and any live FastThink session bound to them. Memories other users also hold are left to them. This is the only delete verb and exists for data-erasure requests; never use it to retire a wrong fact (update_memory / supersede does that). confirm_token must equal user_id or nothing happens. Returns counts of what was removed: {memories, memories_unlinked, entities, relations, embeddings, chunks, user_node_removed, sessions_discarded}."
+    )]
+    async fn purge_user(
+        &self,
+        Parameters(params): Parameters<PurgeUserParams>,
+    ) -> Result<CallToolResult, McpError> {
+        warn!("purge_user requested for user={}", params.user_id);
+
+        let mut report = self
+            .client()
+            .purge_user(&params.user_id, &params.confirm_token)
+            .await
+            .map_err(Self::convert_error)?;
+        report.sessions_discarded = self.fast_think.discard_user_sessions(&params.user_id);
+
+        let json = Self::result_to_json(json!({ "ok": true, "purged": report }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "ADMIN / MAINTENANCE: re-run relation inference over memories already stored and reconcile their reasoning edges (IMPLIES / BECAUSE / SUPPORTS / RELATES_TO ...) with what the current model says — use after changing the LLM or its prompts. Each memory is re-judged against its existing neighbours and entity-sharing peers (at most 32); missing edges are added, and with prune=true edges no longer inferred are dropped (CONTRADICTS never). Costs one LLM call per memory, so it works in batches (batch_size at most 200): pass next_cursor back as cursor until it is null. dry_run=true reports the diff without writing. Returns {ok, rebuild: {memories_scanned, relations_added, relations_stale, relations_removed, relations_unchanged, failures, next_cursor}}."
+    )]
+    async fn rebuild_relations(
+        &self,
+        Parameters(params): Parameters<RebuildRelationsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let defaults = RebuildOptions::default();
+        let opts = RebuildOptions {
+            dry_run: params.dry_run.unwrap_or(false),
+            prune: params.prune.unwrap_or(false),
+            cursor: params.cursor.filter(|c| !c.trim().is_empty()),
+            batch_size: params.batch_size.unwrap_or(defaults.batch_size),
+            ..defaults
+        };
+        info!(
+            "rebuild_relations user={:?} dry_run={} prune={} cursor={:?}",
+            params.user_id, opts.dry_run, opts.prune, opts.cursor
+        );
+
+        let report = self
+            .client()
+            .rebuild_relations(params.user_id.as_deref(), opts)
+            .await
+            .map_err(Self::convert_error)?;
+
+        let json = Self::result_to_json(json!({ "ok": true, "rebuild": report }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "ADMIN / MAINTENANCE: embed memories that were stored without a vector because the embedding service failed during add_memory (HELIXIR_ON_EMBED_FAIL=defer; add_memory listed them in needs_embedding). Until then vector search misses them; tag, graph and keyword lookups still find them. Embeds up to limit flagged memories and clears their flag; one that fails again stays flagged for the next pass. Batched — pass next_cursor back as cursor until it is null. Returns {ok, reindex: {memories_scanned, reindexed, memory_ids, failures, next_cursor}}."
+    )]
+    async fn reindex_embeddings(
+        &self,
+        Parameters(params): Parameters<ReindexEmbeddingsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let limit = params.limit.unwrap_or(100);
+        info!(
+            "reindex_embeddings user={:?} limit={}",
+            params.user_id, limit
+        );
+
+        let report = self
+            .client()
+            .reindex_embeddings(params.user_id.as_deref(), limit, params.cursor.as_deref())
+            .await
+            .map_err(Self::convert_error)?;
+
+        let json = Self::result_to_json(json!({ "ok": true, "reindex": report }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "ADMIN / MAINTENANCE: counters of this process's in-memory caches — the embedding cache (vector per text) and the vector-search result cache — since start or the last clear_cache — plus the HelixDB connection pool's health. Read-only. Returns {caches: [{cache, hits, misses, size, hit_rate}], db_pool: {size, idle, active, failed, reconnects}}."
+    )]
+    async fn cache_stats(
+        &self,
+        Parameters(_params): Parameters<CacheStatsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let json = Self::result_to_json(json!({
+            "caches": self.client().cache_stats(),
+            "db_pool": self.client().db().pool_health(),
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "ADMIN / MAINTENANCE: flush this process's in-memory caches — which: embedding | search | all. Use after changing the embedding model (cached vectors are stale) or to reclaim memory; nothing stored in HelixDB is touched and the caches refill on demand. With a HELIXIR_ENABLED_TOOLS whitelist it is callable only when listed there. Unknown which is rejected (invalid_params). Returns {ok, cleared: [{cache, entries_removed}]}."
+    )]
+    async fn clear_cache(
+        &self,
+        Parameters(params): Parameters<ClearCacheParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let which = CacheKind::from_name(&params.which).ok_or_else(|| {
+            McpError::invalid_params(
+                format!(
+                    "unknown cache '{}'; expected one of: {}",
+                    params.which,
+                    CacheKind::NAMES.join(", ")
+                ),
+                None,
+            )
+        })?;
+        let cleared = self.client().clear_cache(which);
+        info!("clear_cache which={:?}: {:?}", which, cleared);
+        let json = Self::result_to_json(json!({
+            "ok": true,
+            "cleared": cleared
+                .iter()
+                .map(|(cache, removed)| json!({ "cache": cache, "entries_removed": removed }))
+                .collect::<Vec<_>>(),
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Bulk-load many messages (migrations, history backfills) into one user's memory with checkpointing, so an interrupted import resumes instead of restarting or duplicating. Each message runs through the add_memory pipeline in order; progress is checkpointed after every message. One call handles at most max_items (default 50): while resume_token is non-null, call again with the SAME messages and that resume_token. If the server died mid-call and you never got a token, just resend the same batch — it resumes from the stored checkpoint. A message that keeps failing after the configured retries stops the call with ok:false and failed:{index, error}; fix or drop it, or retry with the token. Returns {ok, import: {import_id, total, start_index, next_index, processed, skipped_blank, memories_added, memory_ids, deduped, retries, failed, resume_token, done}}."
+    )]
+    async fn import_memories(
+        &self,
+        Parameters(params): Parameters<ImportMemoriesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let defaults = ImportOptions::default();
+        let opts = ImportOptions {
+            resume_token: params.resume_token,
+            max_items: params.max_items.unwrap_or(defaults.max_items),
+            restart: params.restart.unwrap_or(false),
+        };
+        info!(
+            "import_memories user={} messages={} resume={:?}",
+            params.user_id,
+            params.messages.len(),
+            opts.resume_token
+        );
+
+        let report = self
+            .client()
+            .import_messages(
+                &params.messages,
+                &params.user_id,
+                params.agent_id.as_deref(),
+                opts,
+            )
+            .await
+            .map_err(Self::convert_error)?;
+
+        let ok = report.failed.is_none();
+        let json = Self::result_to_json(json!({ "ok": ok, "import": report }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Browse a user's memories by filter, newest first, a page at a time, with NO query and NO ranking by relevance — use it for counting, auditing, management UIs or seeing everything; for 'what's relevant to X' use search_memory instead. Filters apply before paging and combine: memory_type (ontology type), tags (all of them), time_from/time_to (when stored, RFC3339 or YYYY-MM-DD), pinned, archived (superseded by a newer memory). Deleted memories are never listed. 'limit' rows per page (default 100) after 'offset'. The first content block is the page [{memory_id, content, memory_type, context_tags, created_at, importance, certainty, ...}]; the LAST is {page: {offset, next_cursor, total}} — pass next_cursor as 'offset' for the next page (null = last page); total is how many memories the user holds before filters. When the server caps response size, a page that would not fit is cut short and marked page.truncated / page.omitted; next_cursor then resumes at the first row left out, so nothing is skipped."
     )]
     async fn list_memories(
         &self,
         Parameters(params): Parameters<ListMemoriesParams>,
     ) -> Result<CallToolResult, McpError> {
-        let limit = params.limit.unwrap_or(100) as i64;
+        let limit = params.limit.unwrap_or(100).max(1) as usize;
+        let offset = params.offset.unwrap_or(0) as usize;
         info!(
-            "Listing memories for user={}, limit={}",
-            params.user_id, limit
+            "Listing memories for user={}, offset={}, limit={}",
+            params.user_id, offset, limit
         );
 
-        #[derive(serde::Deserialize)]
-        struct MemoriesResponse {
-            #[serde(default)]
-            memories: Vec<serde_json::Value>,
+        let mut window = crate::core::TimeWindow::default();
+        if let Some(ref s) = params.time_from {
+            window.from = Some(
+                crate::core::time_window::parse_time_bound(s, false)
+                    .map_err(|e| McpError::invalid_params(format!("time_from: {e}"), None))?,
+            );
+        }
+        if let Some(ref s) = params.time_to {
+            window.to = Some(
+                crate::core::time_window::parse_time_bound(s, true)
+                    .map_err(|e| McpError::invalid_params(format!("time_to: {e}"), None))?,
+            );
         }
+        let filters = MemoryListFilter {
+            tags: params.tags.unwrap_or_default(),
+            memory_type: params.memory_type.map(|t| t.as_str().to_string()),
+            window,
+            pinned: params.pinned,
+            archived: params.archived,
+        };
 
-        // HelixDB raises `Graph error: No value found` (also serialised with
-        // the code `GRAPH_ERROR`) when the user has zero outgoing
-        // `HAS_MEMORY` edges — i.e. either the user node is brand new or it
-        // doesn't exist yet. Both states are semantically equivalent to "no
-        // memories", so we map them to an empty Vec instead of bubbling an
-        // MCP error to the caller. See issue #19.
-        let result: MemoriesResponse = match self
+        // HelixDB raises `Graph error: No value found` when the user has zero
+        // outgoing `HAS_MEMORY` edges — a brand-new or unknown user. Both
+        // mean "no memories", so answer an empty page instead of an MCP
+        // error. See issue #19.
+        let mut page = match self
             .client()
-            .db()
-            .execute_query(
-                "getUserMemories",
-                &serde_json::json!({
-                    "user_id": params.user_id,
-                    "limit": limit
-                }),
-            )
+            .list_memories(&params.user_id, &filters, offset, limit)
             .await
         {
-            Ok(r) => r,
-            Err(e) => {
-                let msg = e.to_string();
-                if is_empty_user_graph_error(&msg) {
-                    debug!(
-                        "list_memories: user '{}' has no memories yet (HelixDB returned '{}')",
-                        params.user_id, msg
-                    );
-                    MemoriesResponse {
-                        memories: Vec::new(),
-                    }
-                } else {
-                    return Err(McpError::internal_error(msg, None));
+            Ok(page) => page,
+            Err(e) if is_empty_user_graph_error(&e.to_string()) => {
+                debug!(
+                    "list_memories: user '{}' has no memories yet ({e})",
+                    params.user_id
+                );
+                ListPage {
+                    memories: Vec::new(),
+                    next_cursor: None,
+                    total: 0,
+                    positions: Vec::new(),
                 }
             }
+            Err(e) => return Err(Self::convert_error(e)),
         };
 
-        let mut memories = result.memories;
-
-        if let Some(mem_type) = params.memory_type {
-            memories.retain(|m| {
-                m.get("memory_type")
-                    .and_then(|v| v.as_str())
-                    .map(|t| t == mem_type.as_str())
-                    .unwrap_or(false)
-            });
+        // Trim to the response cap here rather than in `call_tool`, so the
+        // cursor resumes at the first row left out instead of skipping it.
+        // One row is always kept so the cursor moves.
+        let mut omitted = 0;
+        if let Some(max_bytes) = self.client().config().max_response_bytes {
+            let keep = response_limit::rows_within(
+                &page.memories,
+                max_bytes.saturating_sub(LIST_META_BYTES),
+                true,
+            )
+            .max(1);
+            omitted = page.memories.len().saturating_sub(keep);
+            page.truncate(keep);
         }
 
-        info!("Listed {} memories", memories.len());
-        let json = serde_json::to_string_pretty(&memories)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+        info!("Listed {} of {} memories", page.memories.len(), page.total);
+        let rows = Self::result_to_json(&page.memories)?;
+        let mut meta = json!({
+            "page": {
+                "offset": offset,
+                "next_cursor": page.next_cursor,
+                "total": page.total,
+            }
+        });
+        if omitted > 0 {
+            meta["page"]["truncated"] = json!(true);
+            meta["page"]["omitted"] = json!(omitted);
+        }
+        Ok(CallToolResult::success(vec![
+            Content::text(rows),
+            Content::text(meta.to_string()),
+        ]))
     }
 
     #[tool(
@@ -347,6 +753,7 @@ impl HelixirMcpServer {
                 "available": false,
                 "users": [],
                 "note": "User discovery requires the collective tier; this Helixir runs in Solo mode (private memory). Set HELIXIR_MODE=collective to enable a shared roster.",
+                "note_code": "collective_tier_required",
             });
             return Ok(CallToolResult::success(vec![Content::text(
                 payload.to_string(),
@@ -398,6 +805,7 @@ impl HelixirMcpServer {
             "returned": roster.len(),
             "users": roster,
             "note": "Roster for orientation. Pick your OWN stable user_id and use it consistently. Read an identity's memories with list_memories(user_id); search across everyone with search_memory(scope='collective').",
+            "note_code": "roster_orientation",
         });
         Ok(CallToolResult::success(vec![Content::text(
             payload.to_string(),
@@ -416,6 +824,7 @@ impl HelixirMcpServer {
                 "available": false,
                 "agents": [],
                 "note": "The swarm roster requires the collective tier; this Helixir runs in Solo mode (private memory). Set mode=Collective or Insights to join a swarm.",
+                "note_code": "collective_tier_required",
             });
             return Ok(CallToolResult::success(vec![Content::text(
                 payload.to_string(),
@@ -566,6 +975,7 @@ impl HelixirMcpServer {
             "to_id": params.to_id,
             "strategy": strategy,
             "note": if resolved { "dispute retired; it will not re-surface" } else { "no open dispute found for from_id (already resolved?)" },
+            "note_code": if resolved { "dispute_retired" } else { "no_open_dispute" },
         });
         if let Some(p) = rule_proposal {
             payload["rule_proposal"] = json!({
@@ -603,7 +1013,153 @@ impl HelixirMcpServer {
     }
 
     #[tool(
-        description = "Replace the content of an EXISTING memory (you must pass its memory_id, e.g. from a search result); the embedding and graph relations are regenerated. Use to correct or refine a specific known fact. Note: this edits in place and Helixir never deletes — to retire an OUTDATED fact, prefer add_memory with the corrected statement and let the charter supersede the old one (history is preserved). Returns {updated: bool, memory_id}."
+        description = "Read ONE memory by its memory_id — no search needed when you already know the id (from a search result, a commit, a related_memories list). Returns {found: true, memory: {memory_id, content, memory_type, certainty, importance, context_tags, source, metadata, created_at, updated_at, valid_from, valid_until, immutable, verified, is_deleted, access_count, last_accessed_at, users, concepts, entities, relations: {total, by_kind, related: [{memory_id, kind, direction}]}}}. An unknown id, or one user_id does not hold, returns {found: false} rather than an error."
+    )]
+    async fn get_memory(
+        &self,
+        Parameters(params): Parameters<GetMemoryParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let detail = self
+            .client()
+            .get_memory(&params.memory_id, &params.user_id)
+            .await
+            .map_err(Self::convert_error)?;
+
+        let body = match detail {
+            Some(memory) => json!({ "found": true, "memory": memory }),
+            None => json!({
+                "found": false,
+                "memory_id": params.memory_id,
+                "message_code": "memory_not_found",
+            }),
+        };
+        let json = Self::result_to_json(body)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Trace a committed conclusion back to the evidence it rests on: the memories with a SUPPORTS edge into memory_id. think_commit links every memory the session recalled (think_recall) and concluded from, so for a FastThink conclusion these are its sources (from_commit: true, listed first); other SUPPORTS edges drawn when memories were written follow. Returns {found: true, memory_id, sources: [{memory_id, content, strength, from_commit}]}; an unknown id, or one user_id does not hold, returns {found: false}."
+    )]
+    async fn get_conclusion_sources(
+        &self,
+        Parameters(params): Parameters<GetConclusionSourcesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let sources = self
+            .client()
+            .get_conclusion_sources(&params.memory_id, &params.user_id)
+            .await
+            .map_err(Self::convert_error)?;
+
+        let body = match sources {
+            Some(sources) => json!({
+                "found": true,
+                "memory_id": params.memory_id,
+                "sources": sources,
+            }),
+            None => json!({
+                "found": false,
+                "memory_id": params.memory_id,
+                "message_code": "memory_not_found",
+            }),
+        };
+        let json = Self::result_to_json(body)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Explain WHY a memory (e.g. a search_memory hit) is believed: the evidence chain it was derived from — the memories that SUPPORT it, then the ones supporting those, up to 3 hops; think_commit's links from the session's recalls are flagged from_commit. When the memory came from a think_commit(retain=true) whose undo window is still open, session carries that FastThink session's id and reasoning graph {thoughts, edges, ...} as committed; otherwise session is null. Returns {found: true, memory_id, content, evidence: [{memory_id, content, supports, depth, strength, from_commit}], session}; evidence is empty for a memory nothing supports. An unknown id, or one user_id does not hold, returns {found: false}."
+    )]
+    async fn why_memory(
+        &self,
+        Parameters(params): Parameters<WhyMemoryParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let Some(why) = self
+            .client()
+            .why_memory(&params.memory_id, &params.user_id)
+            .await
+            .map_err(Self::convert_error)?
+        else {
+            let json = Self::result_to_json(json!({
+                "found": false,
+                "memory_id": params.memory_id,
+                "message_code": "memory_not_found",
+            }))?;
+            return Ok(CallToolResult::success(vec![Content::text(json)]));
+        };
+        // A retained session is keyed by its namespaced id; one another
+        // caller committed is not shown.
+        let session =
+            self.fast_think
+                .retained_trace(&params.memory_id)
+                .and_then(|(key, mut trace)| {
+                    trace.session_id = self.caller_session_id(&key)?.to_string();
+                    Some(trace)
+                });
+
+        let json = Self::result_to_json(json!({
+            "found": true,
+            "memory_id": params.memory_id,
+            "content": why.memory.content,
+            "evidence": why.evidence,
+            "session": session,
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Read ONE entity (person, system, product, ...) by its exact name: type, aliases, the attributes set on it with set_entity_attribute, and the ids of your memories that reference it. Returns {found: true, entity: {entity_id, name, entity_type, attributes, aliases, memory_ids}}. An unknown name, or one none of user_id's memories reference, returns {found: false}."
+    )]
+    async fn get_entity(
+        &self,
+        Parameters(params): Parameters<GetEntityParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let entity = self
+            .client()
+            .get_entity(&params.user_id, &params.entity_name)
+            .await
+            .map_err(Self::convert_error)?;
+
+        let body = match entity {
+            Some(entity) => json!({ "found": true, "entity": entity }),
+            None => json!({
+                "found": false,
+                "entity_name": params.entity_name,
+                "message_code": "entity_not_found",
+            }),
+        };
+        let json = Self::result_to_json(body)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Annotate an entity in the knowledge graph with a structured attribute, e.g. key='role' value='CTO' on a person, so the graph carries properties and not just names. An empty value removes the attribute. The entity must be referenced by a memory user_id holds (an unknown name is invalid_params); the attribute is user_id's own, other users do not see it. Attributes show up in get_entity and can filter search_memory via entity_attributes. Returns the updated entity {entity_id, name, entity_type, attributes, aliases, memory_ids}."
+    )]
+    async fn set_entity_attribute(
+        &self,
+        Parameters(params): Parameters<SetEntityAttributeParams>,
+    ) -> Result<CallToolResult, McpError> {
+        info!(
+            "Entity attribute: '{}'.{} = '{}'",
+            params.entity_name,
+            params.key,
+            params.value.chars().take(30).collect::<String>()
+        );
+        let entity = self
+            .client()
+            .set_entity_attribute(
+                &params.user_id,
+                &params.entity_name,
+                &params.key,
+                &params.value,
+            )
+            .await
+            .map_err(Self::convert_error)?;
+        let json = Self::result_to_json(&entity)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Replace the content of an EXISTING memory (you must pass its memory_id, e.g. from a search result); the embedding and graph relations are regenerated. Use to correct or refine a specific known fact. Note: this edits in place and the old wording is lost — to fix a WRONG fact, prefer correct_memory, which keeps the old memory as superseded history. Returns {updated: bool, memory_id}."
     )]
     async fn update_memory(
         &self,
@@ -629,7 +1185,165 @@ impl HelixirMcpServer {
     }
 
     #[tool(
-        description = "Return the user's knowledge graph as {nodes, edges}. Nodes are memories ({id, content, node_type}); edges are typed relations ({source, target, edge_type, weight}) where edge_type is BECAUSE/IMPLIES/SUPPORTS/CONTRADICTS. Pass memory_id to get the ego-network around one memory (radius = depth, default 2); omit it for the user's whole local graph. Use this to inspect structure — to WALK a reasoning chain use search_reasoning_chain, to find a PATH between two memories use connect_memories."
+        description = "Add to an EXISTING memory without replacing it — incremental notes ('also, we decided to use Postgres'). The original content is kept verbatim and additional_content follows it on a new line; the combined text is re-embedded and re-extracted, entities it names are linked and its reasoning edges re-judged. To rewrite a memory use update_memory; for an unrelated fact use add_memory. Returns {memory_id, content, new_facts_added, entities_linked, relations_added}; new_facts_added counts extracted facts the original did not already state. An unknown memory_id, or one user_id does not hold, is invalid_params."
+    )]
+    async fn append_memory(
+        &self,
+        Parameters(params): Parameters<AppendMemoryParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let id_preview: String = params.memory_id.chars().take(12).collect();
+        info!("Appending to memory: {}...", id_preview);
+
+        let result = self
+            .client()
+            .append(
+                &params.memory_id,
+                &params.additional_content,
+                &params.user_id,
+            )
+            .await
+            .map_err(Self::convert_error)?;
+
+        let json = Self::result_to_json(&result)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Fix a WRONG memory without losing it: corrected_content is stored as a NEW memory (same type, importance, certainty and tags) that SUPERSEDES the old one as a correction. search_memory and search_reasoning_chain stop returning the corrected memory, and the correction's rows carry metadata.corrects = [old ids] so the evolution stays visible; get_memory and list_memories(archived=true) still read the old one. Nothing is deleted. To grow a memory use append_memory; to rewrite it in place, update_memory. Returns {memory_id (the correction), corrected_id, previous_content, content}. An unknown memory_id, or one user_id does not hold, is invalid_params."
+    )]
+    async fn correct_memory(
+        &self,
+        Parameters(params): Parameters<CorrectMemoryParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let id_preview: String = params.memory_id.chars().take(12).collect();
+        info!("Correcting memory: {}...", id_preview);
+
+        let result = self
+            .client()
+            .correct_memory(
+                &params.memory_id,
+                &params.corrected_content,
+                &params.user_id,
+            )
+            .await
+            .map_err(Self::convert_error)?;
+
+        let json = Self::result_to_json(&result)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Progressive summarization for a busy user: fold the memories of the last window_hours into ONE LLM-written summary, stored with context tag 'summary' and a SUPPORTS edge from every source. search_memory(mode='recent') then ranks summaries first (metadata.summary = true). With archive_sources the summary also supersedes its sources — nothing is deleted, they just rank below it and are not summarized again. Existing summaries are never re-summarized. Returns {summary_id, content, source_ids, window_hours, sources_linked, archived}; summary_id is null when the window held fewer than summary.min_sources memories."
+    )]
+    async fn summarize_recent(
+        &self,
+        Parameters(params): Parameters<SummarizeRecentParams>,
+    ) -> Result<CallToolResult, McpError> {
+        info!(
+            "Summarizing recent memories for user={} [window_hours={:?}]",
+            params.user_id, params.window_hours
+        );
+
+        let result = self
+            .client()
+            .summarize_recent(&params.user_id, params.window_hours, params.archive_sources)
+            .await
+            .map_err(Self::convert_error)?;
+
+        let json = Self::result_to_json(&result)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Find memories that are probably outdated, for a proactive 'this may be out of date — should I update it?'. Flags (1) present-tense or time-relative wording ('currently', 'right now', 'this quarter', version numbers like v1.2) in memories older than min_age_days and (2) memories with an open contradiction from a NEWER memory. Superseded memories and summaries are skipped. Changes nothing. Returns [{memory_id, content, created_at, age_days, reasons, markers, contradicted_by}], most reasons first; confirm with the user, then call update_memory."
+    )]
+    async fn find_stale(
+        &self,
+        Parameters(params): Parameters<FindStaleParams>,
+    ) -> Result<CallToolResult, McpError> {
+        info!(
+            "Finding stale memories for user={} [min_age_days={:?}]",
+            params.user_id, params.min_age_days
+        );
+
+        let stale = self
+            .client()
+            .find_stale(&params.user_id, params.min_age_days, params.limit)
+            .await
+            .map_err(Self::convert_error)?;
+
+        let json = Self::result_to_json(&stale)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Group a user's memories into topics by embedding similarity (k-means), for an overview like 'what do I know about this user?'. Pass k for a fixed number of clusters, or omit it to pick the count by silhouette score. label=true asks the LLM for a 2-5 word name per cluster. Summaries and superseded memories are skipped; covers the newest cluster.max_memories (default 500). Changes nothing. Returns {user_id, k, auto_k, silhouette, memories_clustered, clusters: [{representative_id, representative_content, size, memory_ids, cohesion, label?}]}, largest cluster first."
+    )]
+    async fn cluster_memories(
+        &self,
+        Parameters(params): Parameters<ClusterMemoriesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        info!(
+            "Clustering memories for user={} [k={:?}, label={:?}]",
+            params.user_id, params.k, params.label
+        );
+
+        let result = self
+            .client()
+            .cluster_memories(&params.user_id, params.k, params.label.unwrap_or(false))
+            .await
+            .map_err(Self::convert_error)?;
+
+        let json = Self::result_to_json(&result)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Show a user's own settings: the overrides stored for them and the effective search settings they produce. Returns {user_id, overrides, effective: {default_search_mode, search_limits, search_windows}, precedence}; overrides is {} when the user follows the global config."
+    )]
+    async fn get_user_config(
+        &self,
+        Parameters(params): Parameters<GetUserConfigParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let overrides = self
+            .client()
+            .user_config(&params.user_id)
+            .await
+            .map_err(Self::convert_error)?;
+        let report = Self::user_config_report(self.client().config(), &params.user_id, &overrides);
+        Ok(CallToolResult::success(vec![Content::text(
+            Self::result_to_json(report)?,
+        )]))
+    }
+
+    #[tool(
+        description = "Change a user's own settings, stored in HelixDB and applied to that user's requests over the global config: default_search_mode, search_limit (the cap when a search names no limit) and the mode windows recent_hours / contextual_days / deep_days (e.g. recent_hours=8 for one user; an unset window decays over the server's global default, 30 days). Only the keys passed change; reset=true drops the stored ones first (reset alone returns the user to the global config). An explicit argument on a call still wins over these. Returns the same shape as get_user_config."
+    )]
+    async fn set_user_config(
+        &self,
+        Parameters(params): Parameters<SetUserConfigParams>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("Setting config overrides for user={}", params.user_id);
+        let patch = UserConfigOverrides {
+            default_search_mode: params.default_search_mode.map(|m| m.as_str().to_string()),
+            search_limit: params.search_limit,
+            recent_hours: params.recent_hours,
+            contextual_days: params.contextual_days,
+            deep_days: params.deep_days,
+        };
+        let overrides = self
+            .client()
+            .set_user_config(&params.user_id, patch, params.reset.unwrap_or(false))
+            .await
+            .map_err(Self::convert_error)?;
+        let report = Self::user_config_report(self.client().config(), &params.user_id, &overrides);
+        Ok(CallToolResult::success(vec![Content::text(
+            Self::result_to_json(report)?,
+        )]))
+    }
+
+    #[tool(
+        description = "Return the user's knowledge graph as {nodes, edges}. Nodes are memories ({id, content, node_type}) plus any file/document references they cite (type 'reference', linked by REFERENCES edges); edges are typed relations ({source, target, edge_type, weight}) where edge_type is BECAUSE/IMPLIES/SUPPORTS/CONTRADICTS. A node that fails to load (missing or corrupt) is skipped rather than failing the call: it is named in warnings [{node_id, reason}] and its edges are dropped. Pass memory_id to get the ego-network around one memory (radius = depth, default 2); omit it for the user's whole local graph. Use this to inspect structure — to WALK a reasoning chain use search_reasoning_chain, to find a PATH between two memories use connect_memories."
     )]
     async fn get_memory_graph(
         &self,
@@ -684,7 +1398,7 @@ impl HelixirMcpServer {
     }
 
     #[tool(
-        description = "Reconstruct chains of reasoning around a topic — the 'why / what-follows' tool, and Helixir's signature capability. It finds seed memories then walks typed reasoning edges (BECAUSE/IMPLIES/SUPPORTS/CONTRADICTS) to assemble cause->effect chains with a human-readable reasoning_trail. Use chain_mode 'causal' for 'why is X so', 'forward' for 'what does X lead to', 'both'/'deep' for full context. Can return a LARGE payload on a dense graph — keep max_depth (default 5) and limit modest. Returns {query, chains:[{seed, nodes, reasoning_trail}], total_memories, deepest_chain}."
+        description = "Reconstruct chains of reasoning around a topic — the 'why / what-follows' tool, and Helixir's signature capability. It finds seed memories then walks typed reasoning edges (BECAUSE/IMPLIES/SUPPORTS/CONTRADICTS) to assemble cause->effect chains with a human-readable reasoning_trail. Use chain_mode 'causal' for 'why is X so', 'forward' for 'what does X lead to', 'both'/'deep' for full context. Can return a LARGE payload on a dense graph — keep max_depth (default 5) and limit modest. A memory appears at most once per chain, so cycles in the graph end the walk; truncated=true means a chain hit the node cap. Returns {query, chains:[{seed, nodes, reasoning_trail, truncated}], total_memories, deepest_chain, truncated}."
     )]
     async fn search_reasoning_chain(
         &self,
@@ -713,6 +1427,31 @@ impl HelixirMcpServer {
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 
+    #[tool(
+        description = "What is the user trying to achieve, and how is it going? Lists goal-typed memories newest first (optionally only those a recall of 'topic' reaches, ranked by it) and walks each one's reasoning chain from the goal itself for linked progress. Returns [{goal: {id, content, score, ...}, related_outcomes: [{memory_id, content, relation, depth}], inferred_status: open|in_progress|completed|failed}], unfinished goals first. The status is a keyword guess over the linked memories ('completed', 'failed', ...) — read related_outcomes before telling the user a goal is done."
+    )]
+    async fn get_goals(
+        &self,
+        Parameters(params): Parameters<GetGoalsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("Goals for user={}", params.user_id);
+
+        let goals = self
+            .client()
+            .get_goals(
+                &params.user_id,
+                params.topic.as_deref(),
+                params.limit.map(|l| l as usize),
+            )
+            .await
+            .map_err(Self::convert_error)?;
+
+        info!("Found {} goals", goals.len());
+
+        let json = Self::result_to_json(&goals)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
     #[tool(
         description = "Discover how two concepts are related through the memory graph: bidirectional path search between anchors A and B. Each anchor may be a free-text query OR an exact memory_id (mem_… / raw_…) — pass an id to connect a memory you already know precisely, bypassing the search step. Returns the connecting chain with edge types (IMPLIES/BECAUSE/...) and cumulative confidence. The elder-brain primitive: sees connections that are several logical hops apart."
     )]
@@ -757,14 +1496,15 @@ impl HelixirMcpServer {
         let results = self
             .client()
             .tooling()
-            .search_by_tag("incomplete_thought", limit)
+            .search_by_tag(crate::toolkit::fast_think::INCOMPLETE_THOUGHT_TAG, limit)
             .await
             .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
         if results.is_empty() {
             let json = Self::result_to_json(json!({
                 "found": 0,
-                "message": "No incomplete thoughts found"
+                "message": "No incomplete thoughts found",
+                "message_code": "no_incomplete_thoughts"
             }))?;
             return Ok(CallToolResult::success(vec![Content::text(json)]));
         }
@@ -3,14 +3,20 @@
 //! The tool surface is split by domain so each file stays small and
 //! cohesive. Every submodule defines its own `#[tool_router(router = ...)]`
 //! block, which `rmcp` compiles into a public router constructor. We then
-//! combine those routers via the `+` operator in [`build_tool_router`].
+//! combine those routers via the `+` operator in [`build_tool_router`],
+//! and [`restrict_tools`] drops whatever `enabled_tools` leaves out.
 //!
 //! - [`memory`] — long-term memory: add / search / list / update / graph /
 //!   concept / reasoning chain / incomplete thoughts.
 //! - [`think`] — FastThink ephemeral working-memory sessions.
+//! - [`diagnostics`] — `self_test`, the one-call deployment check.
+
+use rmcp::handler::server::router::tool::ToolRouter;
+use tracing::{info, warn};
 
 use super::server::HelixirMcpServer;
 
+mod diagnostics;
 mod memory;
 mod think;
 
@@ -20,7 +26,57 @@ impl HelixirMcpServer {
     /// Each subrouter is generated by `rmcp::tool_router` and exposed with
     /// `vis = "pub(super)"` from its module. The `+` operator on
     /// `ToolRouter<Self>` merges entries into a single router.
-    pub(super) fn build_tool_router() -> rmcp::handler::server::router::tool::ToolRouter<Self> {
-        Self::memory_router() + Self::think_router()
+    pub(super) fn build_tool_router() -> ToolRouter<Self> {
+        Self::memory_router() + Self::think_router() + Self::diagnostics_router()
+    }
+
+    /// Drop every route not named in `enabled` (the `HELIXIR_ENABLED_TOOLS`
+    /// whitelist). `#[tool_router]` registers tools statically, so the
+    /// filter runs over the built router. Returns the removed names, which
+    /// `call_tool` answers with method-not-found.
+    pub(super) fn restrict_tools(router: &mut ToolRouter<Self>, enabled: &[String]) -> Vec<String> {
+        for name in enabled {
+            if !router.has_route(name) {
+                warn!("HELIXIR_ENABLED_TOOLS names unknown tool '{name}'");
+            }
+        }
+        let mut disabled: Vec<String> = router
+            .list_all()
+            .into_iter()
+            .map(|t| t.name.to_string())
+            .filter(|name| !enabled.contains(name))
+            .collect();
+        disabled.sort();
+        for name in &disabled {
+            router.remove_route(name);
+        }
+        info!(
+            "Tool whitelist: {} enabled, {} disabled",
+            router.list_all().len(),
+            disabled.len()
+        );
+        disabled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restrict_tools_keeps_only_the_whitelist() {
+        let mut router = HelixirMcpServer::build_tool_router();
+        let enabled = vec!["search_memory".to_string(), "add_memory".to_string()];
+        let disabled = HelixirMcpServer::restrict_tools(&mut router, &enabled);
+
+        let mut listed: Vec<String> = router
+            .list_all()
+            .into_iter()
+            .map(|t| t.name.to_string())
+            .collect();
+        listed.sort();
+        assert_eq!(listed, vec!["add_memory", "search_memory"]);
+        assert!(disabled.contains(&"think_start".to_string()));
+        assert!(!disabled.contains(&"add_memory".to_string()));
     }
 }
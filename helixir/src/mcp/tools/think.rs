@@ -2,7 +2,9 @@
 //!
 //! These tools never touch HelixDB directly. They drive the in-process
 //! `petgraph` scratchpad in [`FastThinkManager`]. Only `think_commit` (and
-//! the automatic timeout commit inside `think_add`) persist anything.
+//! the automatic timeout commit inside `think_add`) persist anything;
+//! `think_commit(include_graph)` also reads the stored memory's
+//! neighbourhood back through the client.
 
 use rmcp::{
     ErrorData as McpError, handler::server::wrapper::Parameters, model::*, tool, tool_router,
@@ -12,77 +14,191 @@ use tracing::{info, warn};
 
 use crate::mcp::params::*;
 use crate::mcp::server::HelixirMcpServer;
-use crate::toolkit::fast_think::{FastThinkError, ThoughtType};
+use crate::toolkit::fast_think::{
+    DEFAULT_EDGE_WEIGHT, FastThinkError, INCOMPLETE_THOUGHT_TAG, ManagerSnapshot, OnConflict,
+    SessionStatus, ThoughtEdge, ThoughtOptions, ThoughtSearchMode, ThoughtType, WeightedEdge,
+};
 
 #[tool_router(router = think_router, vis = "pub(super)")]
 impl HelixirMcpServer {
     #[tool(
-        description = "Begin a FastThink session — a reasoning scratchpad wired into long-term memory. OPEN ONE WHEN: you are weighing options, diagnosing a cause, or making a decision that rests on facts you would have to recall — i.e. whenever your next move would be search_memory followed by a judgement. Why not just think silently: think_recall lands stored facts INSIDE your reasoning tree, and think_commit persists ONE conclusion with SUPPORTS provenance edges from that evidence (fast — a few seconds), so the next agent inherits the WHY, not just the answer. For storing a plain fact, add_memory is enough. Flow: think_start -> think_add steps -> think_recall -> think_conclude -> think_commit (or think_discard). YOU choose the session_id and reuse it on every call. Returns {session_id, root_thought_idx}."
+        description = "Begin a FastThink session — a reasoning scratchpad wired into long-term memory. OPEN ONE WHEN: you are weighing options, diagnosing a cause, or making a decision that rests on facts you would have to recall — i.e. whenever your next move would be search_memory followed by a judgement. Why not just think silently: think_recall lands stored facts INSIDE your reasoning tree, and think_commit persists ONE conclusion with SUPPORTS provenance edges from that evidence (fast — a few seconds), so the next agent inherits the WHY, not just the answer. For storing a plain fact, add_memory is enough. Flow: think_start -> think_add steps -> think_recall -> think_conclude -> think_commit (or think_discard). YOU choose the session_id and reuse it on every call. default_thought_type sets what think_add records when it gets no thought_type (default 'reasoning') — e.g. 'observation' for a logging-heavy session. initial_thought may be omitted to recall first: the session starts empty (root_thought_idx and root_thought_id null) and the first think_add becomes the root, or the first think_recall roots it at its query. Returns {session_id, root_thought_idx, root_thought_id, default_thought_type}. Refer to thoughts by their *_id values (stable for the session's life) rather than *_idx (raw graph positions, kept for older clients)."
     )]
     async fn think_start(
         &self,
         Parameters(params): Parameters<StartThinkingParams>,
     ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
         info!("Starting thinking session: {}", params.session_id);
 
-        let result = self
+        let initial = params
+            .initial_thought
+            .as_deref()
+            .filter(|t| !t.trim().is_empty());
+        let root = match initial {
+            Some(thought) => self.fast_think.start_thinking(&key, thought).map(Some),
+            None => self.fast_think.start_rootless(&key).map(|()| None),
+        }
+        .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let default_type = params
+            .default_thought_type
+            .unwrap_or(ThoughtTypeArg::Reasoning);
+        if default_type != ThoughtTypeArg::Reasoning {
+            self.fast_think
+                .set_default_thought_type(&key, default_type.thought_type())
+                .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        }
+
+        let root_id = match root {
+            Some(root) => self
+                .fast_think
+                .thought_ids(&key, &[root])
+                .map_err(|e| Self::convert_think_error(e, &params.session_id))?
+                .pop(),
+            None => None,
+        };
+
+        let json = Self::result_to_json(json!({
+            "session_id": params.session_id,
+            "root_thought_idx": root.map(|r| r.index()),
+            "root_thought_id": root_id,
+            "default_thought_type": default_type.as_str(),
+            "status": "thinking"
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Resume an [INCOMPLETE] memory (from search_incomplete_thoughts) as a new FastThink session: its saved thoughts are seeded as recall thoughts under the root, so you can think_add / think_recall on top of them right away. The session remembers the memory: think_commit updates it in place and clears its incomplete_thought tag (action: updated) without passing supersede_memory_id. A memory without the incomplete_thought tag (error: not_incomplete), or an unknown memory_id or one user_id does not hold (error: memory_not_found), is rejected as invalid_params. Returns {session_id, root_thought_idx, root_thought_id, supersedes, seeded_count, thought_indices, thought_ids}."
+    )]
+    async fn continue_incomplete(
+        &self,
+        Parameters(params): Parameters<ContinueIncompleteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        info!(
+            "Continuing incomplete memory {} as session {}",
+            params.memory_id, params.session_id
+        );
+
+        let (root, seeded) = self
             .fast_think
-            .start_thinking(&params.session_id, &params.initial_thought)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            .continue_incomplete(&key, &params.memory_id, &params.user_id)
+            .await
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+
+        let mut ids = self
+            .fast_think
+            .thought_ids(&key, &[&[root][..], &seeded].concat())
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let root_id = ids.remove(0);
 
         let json = Self::result_to_json(json!({
             "session_id": params.session_id,
-            "root_thought_idx": result.index(),
+            "root_thought_idx": root.index(),
+            "root_thought_id": root_id,
+            "supersedes": params.memory_id,
+            "seeded_count": seeded.len(),
+            "thought_indices": seeded.iter().map(|n| n.index()).collect::<Vec<_>>(),
+            "thought_ids": ids,
             "status": "thinking"
         }))?;
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 
     #[tool(
-        description = "Add a thought node to an active FastThink session (from think_start). Attach it under parent_idx (a previous thought's index) to build a reasoning tree, or omit to attach to the root. thought_type defaults to 'reasoning'. Returns {thought_idx, thought_count, depth} — keep thought_idx to use as a parent for later thoughts."
+        description = "Add a thought node to an active FastThink session (from think_start). Attach it under parent_id (a previous thought's thought_id; parent_idx, its index, also works) to build a reasoning tree, or omit to attach to the root — a parent that is not in the session is rejected (invalid_params, thought_not_found). thought_type defaults to the session's default_thought_type from think_start ('reasoning' unless set); use 'decision' for a choice made along the way (committed in its own 'Decisions:' section of the conclusion — think_conclude is still required). edge_type sets the relationship to the parent in the same call (e.g. 'implies', 'because', 'contradicts'; default 'leads_to'), edge_weight how strongly it holds (0.0-1.0, default 1.0). data carries structured intermediate results (a table of options with scores, key/value facts) that survive into think_status and are rendered as text in the committed memory. certainty (0.0-1.0) records your confidence in the thought; once the last few are all confident and steady the response says should_conclude: true — a nudge to think_conclude, nothing is refused. Returns {thought_idx, thought_id, thought_count, depth, edge_type, edge_weight, should_conclude} — keep thought_id to use as a parent for later thoughts. A thought that would exceed the session's max_depth is not added; the call still succeeds with {rejected:true, reason:'too_deep', max_depth, parent_idx, parent_id, parent_depth, suggestion} — conclude, or branch from a shallower parent."
     )]
     async fn think_add(
         &self,
         Parameters(params): Parameters<AddThoughtParams>,
     ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        // Unset falls back to the session's think_start default; an unknown
+        // session keeps `reasoning` here and fails in add_thought below.
         let thought_type = match params.thought_type {
-            Some(ThoughtTypeArg::Hypothesis) => ThoughtType::Hypothesis,
-            Some(ThoughtTypeArg::Observation) => ThoughtType::Observation,
-            Some(ThoughtTypeArg::Question) => ThoughtType::Question,
-            _ => ThoughtType::Reasoning,
+            Some(arg) => arg.thought_type(),
+            None => self
+                .fast_think
+                .default_thought_type(&key)
+                .unwrap_or(ThoughtType::Reasoning),
         };
 
-        let parent = params
-            .parent_idx
-            .map(|idx| petgraph::stable_graph::NodeIndex::new(idx as usize));
+        let parent = self.thought_ref(
+            &key,
+            &params.session_id,
+            params.parent_id.as_deref(),
+            params.parent_idx,
+            ("parent_id", "parent_idx"),
+        )?;
+
+        // An unrecognised edge_type must not lose the thought: attach it with
+        // the default edge and say so, rather than failing the whole add.
+        let requested_edge = params.edge_type.as_deref().filter(|s| !s.trim().is_empty());
+        let edge_type = requested_edge.and_then(ThoughtEdge::from_name);
+        let attached_edge = WeightedEdge::with_weight(
+            edge_type.clone().unwrap_or(ThoughtEdge::LeadsTo),
+            params.edge_weight.unwrap_or(DEFAULT_EDGE_WEIGHT),
+        );
+        let edge_warning = requested_edge.filter(|_| edge_type.is_none()).map(|raw| {
+            format!(
+                "unknown edge_type '{raw}', attached with leads_to; valid: {}",
+                ThoughtEdge::ALL.map(|e| e.to_string()).join(", ")
+            )
+        });
 
         let result = self.fast_think.add_thought(
-            &params.session_id,
+            &key,
             &params.content,
             thought_type,
             parent,
-            None,
+            ThoughtOptions {
+                edge: Some(attached_edge.clone()),
+                certainty: params.certainty,
+                data: params.data,
+            },
         );
 
         match result {
             Ok(node) => {
                 let status = self
                     .fast_think
-                    .get_session_status(&params.session_id)
-                    .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+                    .get_session_status(&key)
+                    .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
 
-                let json = Self::result_to_json(json!({
+                let thought_id = self
+                    .fast_think
+                    .thought_ids(&key, &[node])
+                    .map_err(|e| Self::convert_think_error(e, &params.session_id))?
+                    .remove(0);
+
+                let mut payload = json!({
                     "thought_idx": node.index(),
+                    "thought_id": thought_id,
                     "thought_count": status.thought_count,
-                    "depth": status.current_depth
-                }))?;
+                    "depth": status.current_depth,
+                    "edge_type": attached_edge.kind.to_string(),
+                    "edge_weight": attached_edge.weight
+                });
+                if let Some(warning) = edge_warning {
+                    payload["edge_type_warning"] = json!(warning);
+                    payload["edge_type_warning_code"] = json!("unknown_edge_type");
+                }
+                payload["should_conclude"] = json!(status.should_conclude);
+                if status.should_conclude {
+                    payload["hint"] = json!(
+                        "Certainty has converged over the last few thoughts; consider think_conclude."
+                    );
+                    payload["hint_code"] = json!("certainty_converged");
+                }
+                let json = Self::result_to_json(payload)?;
                 Ok(CallToolResult::success(vec![Content::text(json)]))
             }
             Err(FastThinkError::Timeout) => {
                 warn!("FastThink timeout - committing partial results");
                 let commit_result = self
                     .fast_think
-                    .commit_partial(&params.session_id, "claude", "timeout")
+                    .commit_partial(&key, "claude", "timeout")
                     .await;
 
                 match commit_result {
@@ -91,7 +207,8 @@ impl HelixirMcpServer {
                             "status": "timeout_committed",
                             "memory_id": cr.memory_id,
                             "thoughts_saved": cr.thoughts_processed,
-                            "message": "Thinking timed out. Partial thoughts saved to memory for future research."
+                            "message": "Thinking timed out. Partial thoughts saved to memory for future research.",
+                            "message_code": "timeout_partial_saved"
                         }))?;
                         Ok(CallToolResult::success(vec![Content::text(json)]))
                     }
@@ -101,110 +218,349 @@ impl HelixirMcpServer {
                     )),
                 }
             }
-            Err(e) => Err(McpError::internal_error(e.to_string(), None)),
+            // Recoverable: the agent only has to conclude or branch higher up,
+            // so it gets guidance instead of a failed call.
+            Err(
+                e @ FastThinkError::TooDeep {
+                    max_depth,
+                    parent_depth,
+                },
+            ) => {
+                let json = Self::result_to_json(json!({
+                    "rejected": true,
+                    "reason": "too_deep",
+                    "message": e.to_string(),
+                    "message_code": e.code(),
+                    "max_depth": max_depth,
+                    "parent_idx": parent.map(|p| p.index()),
+                    "parent_id": parent
+                        .and_then(|p| self.fast_think.thought_ids(&key, &[p]).ok())
+                        .map(|mut ids| ids.remove(0)),
+                    "parent_depth": parent_depth,
+                    "suggestion": "This chain is as deep as the session allows. If it has reached an answer, call think_conclude with supporting_ids pointing at it. Otherwise start a branch: pass the parent_id of a shallower thought, or omit it to attach under the root.",
+                }))?;
+                Ok(CallToolResult::success(vec![Content::text(json)]))
+            }
+            Err(e) => Err(Self::convert_think_error(e, &params.session_id)),
         }
     }
 
     #[tool(
-        description = "Pull relevant facts from MAIN memory into the current FastThink session as child thoughts under parent_idx. READ-ONLY — it never modifies main memory. Use it to ground the session's reasoning in what is already known. Returns {recalled_count, thought_indices}."
+        description = "Pull relevant facts from MAIN memory into the current FastThink session as child thoughts under parent_id (or parent_idx; omit both to hang them under the session root). READ-ONLY — it never modifies main memory. Use it to ground the session's reasoning in what is already known. A blank query is rejected (invalid_params) unless the server is configured to browse recent memories instead. Returns {recalled_count, parent_idx, parent_id, thought_indices, thought_ids, recalled: [{idx, id, content_preview, source_memory_id, certainty}]} — previews are cut to fast_think.recall_preview_chars, so you can reason on what came back without think_status. With fast_think.auto_link_recalls on, each recalled thought is also linked to the most similar non-recall thought (supports, or contradicts when only one side is negated) and the response carries auto_links: [{from_idx, to_idx, from_id, to_id, kind, similarity}]."
     )]
     async fn think_recall(
         &self,
         Parameters(params): Parameters<ThinkRecallParams>,
     ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
         info!("Recalling from main memory: '{}'", params.query);
 
-        let parent = petgraph::stable_graph::NodeIndex::new(params.parent_idx as usize);
+        if params.query.trim().is_empty() {
+            Self::check_empty_query_allowed(self.client().config(), "think_recall")?;
+        }
+
+        let parent = self.thought_ref(
+            &key,
+            &params.session_id,
+            params.parent_id.as_deref(),
+            params.parent_idx,
+            ("parent_id", "parent_idx"),
+        )?;
         let user_id = params.user_id.as_deref().unwrap_or("default");
 
-        let results = self
+        let (parent, results, auto_links) = self
             .fast_think
-            .recall(&params.session_id, &params.query, parent, user_id)
+            .recall(&key, &params.query, parent, user_id)
             .await
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
 
         let indices: Vec<usize> = results.iter().map(|n| n.index()).collect();
+        let mut ids = self
+            .fast_think
+            .thought_ids(&key, &[&[parent][..], &results].concat())
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let parent_id = ids.remove(0);
 
         info!("Recalled {} facts", results.len());
 
-        let json = Self::result_to_json(json!({
+        let mut payload = json!({
             "recalled_count": results.len(),
-            "thought_indices": indices
-        }))?;
+            "parent_idx": parent.index(),
+            "parent_id": parent_id,
+            "thought_indices": indices,
+            "thought_ids": ids
+        });
+        let preview_chars = self.client().config().fast_think.recall_preview_chars;
+        if preview_chars > 0 {
+            let previews = self
+                .fast_think
+                .recall_previews(&key, &results, preview_chars)
+                .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+            payload["recalled"] = json!(previews);
+        }
+        if !auto_links.is_empty() {
+            payload["auto_links"] = json!(auto_links);
+        }
+        let json = Self::result_to_json(payload)?;
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 
     #[tool(
-        description = "Record the conclusion of a FastThink session — REQUIRED before think_commit. Pass supporting_idx with the thought indices the conclusion rests on. Returns {conclusion_idx, status:'decided'}."
+        description = "Record the conclusion of a FastThink session — REQUIRED before think_commit. Pass supporting_ids with the thought ids the conclusion rests on (supporting_idx, their indices, also works). A session may hold several conclusions: pass relation_to_id (an earlier conclusion_id; relation_to takes its conclusion_idx) with relation 'refines' (default) or 'contradicts' and the committed content nests this one under it as '(refined by: ...)' / '(contradicted by: ...)'; a target that is not a conclusion, or any other relation, is rejected (invalid_params), as is a supporting thought that is not in the session. A deployment can require a minimum number of reasoning steps (thoughts other than the root and conclusions; fast_think.min_thoughts_before_conclude, off by default): below it conclude is rejected (invalid_params) — add more thoughts first. Returns {conclusion_idx, conclusion_id, status:'decided', relation_to, relation_to_id, relation, confidence, rationale}: confidence (0.0-1.0) is calibrated from the supporting thoughts' certainties, blended with the strength of the recalls among or under them, lowered when nothing was recalled and for every contradicts edge touching the support; rationale lists what raised or lowered it — report that figure rather than guessing one."
     )]
     async fn think_conclude(
         &self,
         Parameters(params): Parameters<ThinkConcludeParams>,
     ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
         info!("Concluding thinking session: {}", params.session_id);
 
-        let supporting: Vec<petgraph::stable_graph::NodeIndex> = params
-            .supporting_idx
-            .unwrap_or_default()
-            .iter()
-            .map(|&idx| petgraph::stable_graph::NodeIndex::new(idx as usize))
-            .collect();
+        let mut supporting = Vec::new();
+        match &params.supporting_ids {
+            Some(ids) => {
+                for id in ids {
+                    supporting.extend(self.thought_ref(
+                        &key,
+                        &params.session_id,
+                        Some(id),
+                        None,
+                        ("supporting_ids", "supporting_idx"),
+                    )?);
+                }
+            }
+            None => {
+                for &idx in params.supporting_idx.iter().flatten() {
+                    supporting.extend(self.thought_ref(
+                        &key,
+                        &params.session_id,
+                        None,
+                        Some(idx),
+                        ("supporting_ids", "supporting_idx"),
+                    )?);
+                }
+            }
+        }
+        let prior = self.thought_ref(
+            &key,
+            &params.session_id,
+            params.relation_to_id.as_deref(),
+            params.relation_to,
+            ("relation_to_id", "relation_to"),
+        )?;
+        let relation = match prior {
+            None => None,
+            Some(prior) => {
+                let name = params.relation.as_deref().unwrap_or("refines");
+                let kind = ThoughtEdge::from_name(name).ok_or_else(|| {
+                    McpError::invalid_params(
+                        format!("unknown relation '{name}'; expected refines or contradicts"),
+                        None,
+                    )
+                })?;
+                Some((prior, kind))
+            }
+        };
+        let relation_name = relation.as_ref().map(|(_, kind)| kind.to_string());
 
         let result = self
             .fast_think
-            .conclude(&params.session_id, &params.conclusion, &supporting)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            .conclude_related(&key, &params.conclusion, &supporting, relation)
+            .map_err(|e| match e {
+                // A relation_to / relation the session cannot honour.
+                FastThinkError::InvalidState(msg) if prior.is_some() => {
+                    McpError::invalid_params(msg, None)
+                }
+                e => Self::convert_think_error(e, &params.session_id),
+            })?;
+
+        let mut ids = self
+            .fast_think
+            .thought_ids(
+                &key,
+                &[Some(result), prior]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>(),
+            )
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let conclusion_id = ids.remove(0);
+        let calibrated = self
+            .fast_think
+            .conclusion_confidence(&key, result)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
 
         let json = Self::result_to_json(json!({
             "conclusion_idx": result.index(),
-            "status": "decided"
+            "conclusion_id": conclusion_id,
+            "status": "decided",
+            "relation_to": prior.map(|p| p.index()),
+            "relation_to_id": ids.pop(),
+            "relation": relation_name,
+            "confidence": calibrated.confidence,
+            "rationale": calibrated.rationale,
         }))?;
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 
     #[tool(
-        description = "Persist a concluded FastThink session into main memory. Call think_conclude first. The conclusion is stored as-is (fast path, typically a few seconds): recalled evidence becomes SUPPORTS provenance edges and entity discovery finishes in the background — only a very long conclusion falls back to full LLM extraction. Call it ONCE at the end. Returns {memory_id, thoughts_processed, elapsed_ms}."
+        description = "Persist a concluded FastThink session into main memory. Call think_conclude first. The conclusion is stored as-is (fast path, typically a few seconds): recalled evidence becomes SUPPORTS provenance edges and entity discovery finishes in the background — only a very long conclusion falls back to full LLM extraction. Call it ONCE at the end. Continuing an [INCOMPLETE] memory from search_incomplete_thoughts? Pass its id as supersede_memory_id (a session opened with continue_incomplete does this by default): the conclusion updates that memory in place (and clears its incomplete_thought tag) instead of leaving a stale duplicate. auto_classify:true spends one LLM call typing the conclusion into the ontology (preference, goal, skill, ...) so search_by_concept can find it. Committing without a conclusion is rejected and the session stays open; allow_partial:true instead saves the thoughts as an [INCOMPLETE] memory (status: partial_committed). retain:true keeps the session for one session TTL so think_uncommit can take a bad commit back. reconcile_counts:true waits for main memory's entity extraction and reports entities_extracted/concepts_mapped as what the stored graph links to the conclusion (counts_source: graph) instead of the session's scratch counts (counts_source: session). Returns {memory_id, action: created|updated, thoughts_processed, entities_extracted, concepts_mapped, counts_source, classified_as, elapsed_ms} plus retained and undo_window_secs when retained; verbose:true adds entities [{name, entity_type}] and concepts [name] — the structure the session held (counts include pipeline extraction, these lists do not); include_graph:true adds graph {nodes, edges} (the new memory's depth-1 neighbourhood, as get_memory_graph) and linked_to [{memory_id, edge_type, direction: out|in}] — edges written later by background entity discovery may not be there yet. as_chain:true keeps the session's STRUCTURE instead of one prose memory: every reasoning/hypothesis/observation/decision/conclusion thought is stored as its own memory (recalls link to the memory they came from; the root and questions are not stored) and each typed thought edge becomes a reasoning relation — implies/leads_to -> IMPLIES, because -> BECAUSE, supports -> SUPPORTS, contradicts -> CONTRADICTS, refines -> RELATES_TO, a recall SUPPORTS its parent (strength = edge_weight) — so search_reasoning_chain can walk it later; it returns {memory_id (the first conclusion's), action: created, as_chain: true, memory_ids, conclusion_memory_ids, reused_memory_ids, relations [{from_memory_id, to_memory_id, relation}], relations_failed, thoughts_processed, elapsed_ms} and cannot be combined with supersede_memory_id, retain or a continue_incomplete session."
     )]
     async fn think_commit(
         &self,
         Parameters(params): Parameters<ThinkCommitParams>,
     ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
         info!("Committing thinking session: {}", params.session_id);
 
-        let result = self
+        if params.as_chain.unwrap_or(false) {
+            if params.supersede_memory_id.is_some() || params.retain.unwrap_or(false) {
+                return Err(McpError::invalid_params(
+                    "as_chain cannot be combined with supersede_memory_id or retain".to_string(),
+                    None,
+                ));
+            }
+            let result = self
+                .fast_think
+                .commit_chain(&key, &params.user_id)
+                .await
+                .map_err(|e| match e {
+                    FastThinkError::InvalidState(msg) => McpError::invalid_params(msg, None),
+                    e => Self::convert_think_error(e, &params.session_id),
+                })?;
+            info!(
+                "Committed as chain: {} memories, {} relations",
+                result.memory_ids.len(),
+                result.relations.len()
+            );
+            let json = Self::result_to_json(json!({
+                "memory_id": result.conclusion_memory_ids.first(),
+                "action": "created",
+                "as_chain": true,
+                "memory_ids": result.memory_ids,
+                "conclusion_memory_ids": result.conclusion_memory_ids,
+                "reused_memory_ids": result.reused_memory_ids,
+                "relations": result.relations,
+                "relations_failed": result.relations_failed,
+                "thoughts_processed": result.thoughts_processed,
+                "elapsed_ms": result.elapsed.as_millis()
+            }))?;
+            return Ok(CallToolResult::success(vec![Content::text(json)]));
+        }
+
+        let result = match self
             .fast_think
-            .commit(&params.session_id, &params.user_id)
+            .commit(
+                &key,
+                &params.user_id,
+                params.supersede_memory_id.as_deref(),
+                params.auto_classify,
+                params.reconcile_counts,
+                params.retain.unwrap_or(false),
+            )
             .await
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        {
+            Ok(result) => result,
+            Err(FastThinkError::NoConclusion) if params.allow_partial.unwrap_or(false) => {
+                warn!(
+                    "No conclusion in {} - committing partial results",
+                    params.session_id
+                );
+                let cr = self
+                    .fast_think
+                    .commit_partial(&key, &params.user_id, "no_conclusion")
+                    .await
+                    .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+                let json = Self::result_to_json(json!({
+                    "status": "partial_committed",
+                    "memory_id": cr.memory_id,
+                    "action": "created",
+                    "thoughts_saved": cr.thoughts_processed,
+                    "tag": INCOMPLETE_THOUGHT_TAG,
+                    "message": "No conclusion was reached. Thoughts saved as an [INCOMPLETE] memory; search_incomplete_thoughts finds it.",
+                    "message_code": "no_conclusion_partial_saved"
+                }))?;
+                return Ok(CallToolResult::success(vec![Content::text(json)]));
+            }
+            Err(e) => return Err(Self::convert_think_error(e, &params.session_id)),
+        };
 
         info!(
             "Committed: {} thoughts -> memory {}",
             result.thoughts_processed, result.memory_id
         );
 
-        let json = Self::result_to_json(json!({
+        let mut payload = json!({
             "memory_id": result.memory_id,
+            "action": if result.superseded { "updated" } else { "created" },
             "thoughts_processed": result.thoughts_processed,
             "entities_extracted": result.entities_extracted,
             "concepts_mapped": result.concepts_mapped,
+            "counts_source": if result.counts_reconciled { "graph" } else { "session" },
+            "classified_as": result.classified_as,
             "elapsed_ms": result.elapsed.as_millis()
-        }))?;
+        });
+        if result.retained {
+            payload["retained"] = json!(true);
+            payload["undo_window_secs"] = json!(self.client().config().fast_think.session_ttl_secs);
+        }
+        if params.verbose.unwrap_or(false) {
+            payload["entities"] = json!(result.entities);
+            payload["concepts"] = json!(result.concepts);
+        }
+        if params.include_graph.unwrap_or(false) {
+            // The memory is already stored: a failed read must not turn a
+            // successful commit into an error.
+            match self
+                .client()
+                .get_graph(&params.user_id, Some(&result.memory_id), Some(1))
+                .await
+            {
+                Ok(graph) => {
+                    let linked_to: Vec<serde_json::Value> = graph
+                        .edges
+                        .iter()
+                        .filter_map(|e| {
+                            let (other, direction) = if e.source == result.memory_id {
+                                (&e.target, "out")
+                            } else if e.target == result.memory_id {
+                                (&e.source, "in")
+                            } else {
+                                return None;
+                            };
+                            Some(json!({
+                                "memory_id": other,
+                                "edge_type": e.edge_type,
+                                "direction": direction
+                            }))
+                        })
+                        .collect();
+                    payload["linked_to"] = json!(linked_to);
+                    payload["graph"] = json!(graph);
+                }
+                Err(e) => {
+                    warn!("think_commit graph read failed: {e}");
+                    payload["graph_error"] = json!(e.to_string());
+                }
+            }
+        }
+        let json = Self::result_to_json(payload)?;
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 
     #[tool(
-        description = "Throw away a FastThink session without persisting anything (clears the scratchpad). Use when the reasoning led nowhere or shouldn't be remembered. After this the session_id no longer exists. Returns {discarded_thoughts, elapsed_ms}."
+        description = "Throw away a FastThink session without persisting anything (clears the scratchpad). Use when the reasoning led nowhere or shouldn't be remembered. A session that already reached a conclusion is refused (invalid_params, data.conclusion_preview) — think_commit it, or pass force=true if losing it is intended. After this the session_id no longer exists. Returns {discarded_thoughts, elapsed_ms}."
     )]
     async fn think_discard(
         &self,
         Parameters(params): Parameters<ThinkDiscardParams>,
     ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
         info!("Discarding thinking session: {}", params.session_id);
 
         let result = self
             .fast_think
-            .discard(&params.session_id)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            .discard(&key, params.force.unwrap_or(false))
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
 
         let json = Self::result_to_json(json!({
             "discarded_thoughts": result.thoughts_discarded,
@@ -214,23 +570,550 @@ impl HelixirMcpServer {
     }
 
     #[tool(
-        description = "Inspect a FastThink session without changing it — useful to check progress or whether a conclusion exists yet. Returns {status, thought_count, thoughts_left, depth, has_conclusion, elapsed_ms}; thoughts_left is your headroom before the session's thought cap — and think_conclude STILL works at 0 (the conclusion is the exit, not another thought). Errors if the session_id does not exist (e.g. after think_discard or think_commit)."
+        description = "Undo a think_commit made with retain=true, while its undo window (the session TTL, undo_window_secs in the commit's reply) is open: the memory the commit created is deleted and the session comes back as 'decided' with a fresh thinking clock, ready for more think_add / a new think_conclude / another think_commit. Use it right after committing a bad conclusion, before other reasoning builds on it. Requires confirm=true; without it (error: confirm_required), after the window (error: undo_window_passed), or for a commit that was not retained (error: not_retained) the call is invalid_params and the commit stands. A memory the commit was deduped to existed before and is kept. Returns {session_id, status: 'decided', deleted_memory_ids, thoughts_restored}."
+    )]
+    async fn think_uncommit(
+        &self,
+        Parameters(params): Parameters<ThinkUncommitParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        info!("Uncommitting thinking session: {}", params.session_id);
+
+        let result = self
+            .fast_think
+            .uncommit(&key, params.confirm.unwrap_or(false))
+            .await
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+
+        let json = Self::result_to_json(json!({
+            "session_id": params.session_id,
+            "status": SessionStatus::Decided.to_string(),
+            "deleted_memory_ids": result.deleted_memory_ids,
+            "thoughts_restored": result.thoughts_restored,
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "List the live FastThink sessions — use it when a think_* call says your session_id does not exist (it was committed, discarded, timed out, or never started). Pass status to narrow it down, e.g. 'timed_out' or 'decided' (concluded but not yet committed) to find sessions needing attention; no match is an empty list, an unknown status is invalid_params. Returns {count, sessions:[{session_id, status, thought_count, has_conclusion, elapsed_ms}]}, oldest first."
+    )]
+    async fn think_list(
+        &self,
+        Parameters(params): Parameters<ThinkListParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let wanted = match params.status.as_deref().filter(|s| !s.trim().is_empty()) {
+            Some(name) => Some(SessionStatus::from_name(name).ok_or_else(|| {
+                let known: Vec<String> = SessionStatus::ALL.iter().map(|s| s.to_string()).collect();
+                McpError::invalid_params(
+                    format!(
+                        "unknown status '{name}'; expected one of {}",
+                        known.join(", ")
+                    ),
+                    None,
+                )
+            })?),
+            None => None,
+        };
+        let sessions: Vec<serde_json::Value> = self
+            .fast_think
+            .list_session_info()
+            .into_iter()
+            .filter(|s| wanted.as_ref().map_or(true, |w| &s.status == w))
+            .filter_map(|s| {
+                let session_id = self.caller_session_id(&s.id)?;
+                Some(json!({
+                    "session_id": session_id,
+                    "status": s.status.to_string(),
+                    "thought_count": s.thought_count,
+                    "has_conclusion": s.has_conclusion,
+                    "elapsed_ms": s.elapsed.as_millis()
+                }))
+            })
+            .collect();
+
+        let json = Self::result_to_json(json!({
+            "count": sessions.len(),
+            "sessions": sessions
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Jot a meta-note on a FastThink session ('need to verify with user', 'revisit the cost estimate') — process notes that are NOT part of the reasoning chain. Notes never become thoughts, conclusion content, or SUPPORTS evidence, and are not committed to memory; they live as long as the session and are shown by think_status. Omit note to just list them. Adding one while a think_recall on the session is in flight fails with error session_busy (invalid_request, retryable); retry once it returns. Returns {annotation_count, annotations}."
+    )]
+    async fn think_note(
+        &self,
+        Parameters(params): Parameters<ThinkNoteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        if let Some(note) = params.note.as_deref().filter(|n| !n.trim().is_empty()) {
+            self.fast_think
+                .annotate(&key, note)
+                .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        }
+        let annotations = self
+            .fast_think
+            .annotations(&key)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+
+        let json = Self::result_to_json(json!({
+            "session_id": params.session_id,
+            "annotation_count": annotations.len(),
+            "annotations": annotations
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Keep a think_note annotation that turned out to matter — notes are otherwise dropped at commit. Name it by note_idx (its position in think_note's list). target 'thought' (default) turns it into a thought of thought_type (default 'observation') under parent_id/parent_idx or the root; target 'memory' stores it in main memory now for user_id through the add_memory pipeline. Either way the note leaves the annotation list. A note_idx past the list is invalid_params (error: note_not_found, with valid_range [0, count) — think_note lists the notes). Returns {session_id, target, thought_idx, thought_id} or {session_id, target, memory_id, memory_ids}, plus annotation_count."
+    )]
+    async fn think_promote_note(
+        &self,
+        Parameters(params): Parameters<ThinkPromoteNoteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        let target = params.target.unwrap_or_default();
+        let mut payload = match target {
+            NoteTarget::Thought => {
+                let parent = self.thought_ref(
+                    &key,
+                    &params.session_id,
+                    params.parent_id.as_deref(),
+                    params.parent_idx,
+                    ("parent_id", "parent_idx"),
+                )?;
+                let thought_type = params
+                    .thought_type
+                    .unwrap_or(ThoughtTypeArg::Observation)
+                    .thought_type();
+                let node = self
+                    .fast_think
+                    .promote_note(&key, params.note_idx, thought_type, parent)
+                    .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+                let thought_id = self
+                    .fast_think
+                    .thought_ids(&key, &[node])
+                    .map_err(|e| Self::convert_think_error(e, &params.session_id))?
+                    .remove(0);
+                json!({
+                    "session_id": params.session_id,
+                    "target": "thought",
+                    "thought_idx": node.index(),
+                    "thought_id": thought_id,
+                })
+            }
+            NoteTarget::Memory => {
+                let user_id = params
+                    .user_id
+                    .as_deref()
+                    .filter(|u| !u.trim().is_empty())
+                    .ok_or_else(|| {
+                        McpError::invalid_params("target 'memory' needs a user_id", None)
+                    })?;
+                let ids = self
+                    .fast_think
+                    .promote_note_to_memory(&key, params.note_idx, user_id)
+                    .await
+                    .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+                json!({
+                    "session_id": params.session_id,
+                    "target": "memory",
+                    "memory_id": ids[0],
+                    "memory_ids": ids,
+                })
+            }
+        };
+        payload["annotation_count"] = json!(
+            self.fast_think
+                .annotations(&key)
+                .map_err(|e| Self::convert_think_error(e, &params.session_id))?
+                .len()
+        );
+        let json = Self::result_to_json(payload)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Review the scratch knowledge graph of a FastThink session before committing: its entities (most mentioned first) and concepts, each with the thoughts it is tied to. Read-only. Returns {session_id, entity_count, concept_count, entities:[{name, entity_type, mention_count, thought_idx, thought_ids}], concepts:[{name, parent?, thought_idx, thought_ids}]}."
+    )]
+    async fn think_entities(
+        &self,
+        Parameters(params): Parameters<ThinkEntitiesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        let (entities, concepts) = self
+            .fast_think
+            .session_entities(&key)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+
+        let json = Self::result_to_json(json!({
+            "session_id": params.session_id,
+            "entity_count": entities.len(),
+            "concept_count": concepts.len(),
+            "entities": entities,
+            "concepts": concepts
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Update your confidence in a FastThink thought relatively: delta is ADDED to its current certainty (clamped to 0.0-1.0) instead of restating an absolute value — 'this got a bit more likely' is delta 0.1. Name the thought by thought_id (or thought_idx). Conclusions do not derive their certainty from their support, so nothing else changes; the new value counts toward should_conclude like one set by think_add. Returns {thought_idx, thought_id, previous, certainty, delta, should_conclude}."
+    )]
+    async fn think_adjust_certainty(
+        &self,
+        Parameters(params): Parameters<AdjustCertaintyParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        let node = self
+            .thought_ref(
+                &key,
+                &params.session_id,
+                params.thought_id.as_deref(),
+                params.thought_idx,
+                ("thought_id", "thought_idx"),
+            )?
+            .ok_or_else(|| {
+                McpError::invalid_params("pass thought_id (or thought_idx) to re-weigh", None)
+            })?;
+        let (previous, certainty) = self
+            .fast_think
+            .adjust_certainty(&key, node, params.delta)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let thought_id = self
+            .fast_think
+            .thought_ids(&key, &[node])
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?
+            .remove(0);
+        let status = self
+            .fast_think
+            .get_session_status(&key)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+
+        let json = Self::result_to_json(json!({
+            "thought_idx": node.index(),
+            "thought_id": thought_id,
+            "previous": previous,
+            "certainty": certainty,
+            "delta": params.delta,
+            "should_conclude": status.should_conclude
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Find thoughts in a FastThink session without dumping its graph — 'where did I mention the cache tradeoff?'. mode 'substring' (default) is a cheap case-insensitive match in thought order; 'semantic' ranks every thought by embedding similarity (score, best first). Read-only. Returns {session_id, mode, count, matches:[{idx, id, content, type, score?}]}; use the id as parent_id or supporting_ids."
+    )]
+    async fn think_search(
+        &self,
+        Parameters(params): Parameters<ThinkSearchParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        if params.query.trim().is_empty() {
+            return Err(McpError::invalid_params("query must not be empty", None));
+        }
+        let mode = match params.mode.as_deref() {
+            None => ThoughtSearchMode::default(),
+            Some(raw) => ThoughtSearchMode::parse(raw).ok_or_else(|| {
+                McpError::invalid_params(
+                    format!("unknown mode '{raw}': use 'substring' or 'semantic'"),
+                    None,
+                )
+            })?,
+        };
+        let matches = self
+            .fast_think
+            .search_thoughts(
+                &key,
+                params.query.trim(),
+                mode,
+                params.limit.unwrap_or(10).max(1),
+            )
+            .await
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+
+        let json = Self::result_to_json(json!({
+            "session_id": params.session_id,
+            "mode": if mode == ThoughtSearchMode::Semantic { "semantic" } else { "substring" },
+            "count": matches.len(),
+            "matches": matches
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Jump-start a FastThink session with a registered reasoning template — the recurring skeleton of a decision type (e.g. 'evaluate_library': requirements, alternatives, maintenance risk) as connected question/hypothesis thoughts. They hang under parent_id/parent_idx, or the root when omitted (a session opened without initial_thought is rooted by the template's first thought). All or nothing: a template that would pass the thought limit or max_depth adds nothing. Templates are .json/.toml files in the server's HELIXIR_THINK_TEMPLATES_DIR. Returns {session_id, template, added, thought_idx:[...], thought_ids:[...], thought_count} in template order — answer or refine them with think_add."
+    )]
+    async fn think_template(
+        &self,
+        Parameters(params): Parameters<ThinkTemplateParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        let templates = self.fast_think.templates();
+        let Some(template) = templates
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(params.template.trim()))
+        else {
+            let available: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
+            return Err(McpError::invalid_params(
+                if available.is_empty() {
+                    format!(
+                        "unknown template '{}': no reasoning templates are registered (HELIXIR_THINK_TEMPLATES_DIR)",
+                        params.template
+                    )
+                } else {
+                    format!(
+                        "unknown template '{}'; available: {}",
+                        params.template,
+                        available.join(", ")
+                    )
+                },
+                None,
+            ));
+        };
+        let parent = self.thought_ref(
+            &key,
+            &params.session_id,
+            params.parent_id.as_deref(),
+            params.parent_idx,
+            ("parent_id", "parent_idx"),
+        )?;
+
+        let nodes = self
+            .fast_think
+            .apply_template(&key, template, parent)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let thought_ids = self
+            .fast_think
+            .thought_ids(&key, &nodes)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let status = self
+            .fast_think
+            .get_session_status(&key)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+
+        let json = Self::result_to_json(json!({
+            "session_id": params.session_id,
+            "template": template.name,
+            "added": nodes.len(),
+            "thought_idx": nodes.iter().map(|n| n.index()).collect::<Vec<_>>(),
+            "thought_ids": thought_ids,
+            "thought_count": status.thought_count
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Fold an [INCOMPLETE] memory (from search_incomplete_thoughts) into an ACTIVE session instead of opening a new one with continue_incomplete: its saved thought list is parsed back into typed thoughts under parent_id/parent_idx (the root when omitted). The interrupted session's initial thought comes back as a question with the rest under it; its conclusions come back as hypotheses, so the import concludes nothing; recalls stay recalls sourced from the memory. Imported thoughts carry certainty 0.5. All or nothing: an import that would pass max_depth or the thought limit (less the recall reserve) adds nothing. The memory is left as it is — pass it as supersede_memory_id to think_commit to replace it. A memory without the incomplete_thought tag (error: not_incomplete), or an unknown memory_id or one user_id does not hold (error: memory_not_found), is rejected as invalid_params; a busy or unknown session gets the usual session errors. Returns {session_id, memory_id, imported, thought_idx:[...], thought_ids:[...], thought_count} in saved order."
+    )]
+    async fn think_import_incomplete(
+        &self,
+        Parameters(params): Parameters<ThinkImportIncompleteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        let parent = self.thought_ref(
+            &key,
+            &params.session_id,
+            params.parent_id.as_deref(),
+            params.parent_idx,
+            ("parent_id", "parent_idx"),
+        )?;
+        info!(
+            "Importing incomplete memory {} into session {}",
+            params.memory_id, params.session_id
+        );
+
+        let nodes = self
+            .fast_think
+            .import_incomplete(&key, &params.memory_id, &params.user_id, parent)
+            .await
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let thought_ids = self
+            .fast_think
+            .thought_ids(&key, &nodes)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let status = self
+            .fast_think
+            .get_session_status(&key)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+
+        let json = Self::result_to_json(json!({
+            "session_id": params.session_id,
+            "memory_id": params.memory_id,
+            "imported": nodes.len(),
+            "thought_idx": nodes.iter().map(|n| n.index()).collect::<Vec<_>>(),
+            "thought_ids": thought_ids,
+            "thought_count": status.thought_count
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Fork a FastThink session to try an alternative path without touching the original: a new session new_session_id gets copies of the thought from_thought_id/from_thought_idx and its chain back to the root (with the edges among them and the entities/concepts they mention), under new indices and ids. Continue the fork with think_add on branch_root_id; both sessions then conclude and commit (or are discarded) independently. Errors (invalid_params) if the thought does not exist or new_session_id is already live. Returns {session_id, new_session_id, copied_thoughts, branch_root_idx, branch_root_id}."
+    )]
+    async fn think_branch(
+        &self,
+        Parameters(params): Parameters<ThinkBranchParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
+        let new_key = self.session_key(&params.new_session_id);
+        let from = self
+            .thought_ref(
+                &key,
+                &params.session_id,
+                params.from_thought_id.as_deref(),
+                params.from_thought_idx,
+                ("from_thought_id", "from_thought_idx"),
+            )?
+            .ok_or_else(|| {
+                McpError::invalid_params(
+                    "pass from_thought_id (or from_thought_idx) — the thought to branch at"
+                        .to_string(),
+                    None,
+                )
+            })?;
+        info!(
+            "Branching session {} into {} at thought {}",
+            params.session_id,
+            params.new_session_id,
+            from.index()
+        );
+
+        let (tip, copied) = self
+            .fast_think
+            .branch(&key, &new_key, from)
+            .map_err(|e| match e {
+                FastThinkError::SessionAlreadyExists => {
+                    Self::convert_think_error(e, &params.new_session_id)
+                }
+                other => Self::convert_think_error(other, &params.session_id),
+            })?;
+        let tip_id = self
+            .fast_think
+            .thought_ids(&new_key, &[tip])
+            .map_err(|e| Self::convert_think_error(e, &params.new_session_id))?;
+
+        let json = Self::result_to_json(json!({
+            "session_id": params.session_id,
+            "new_session_id": params.new_session_id,
+            "copied_thoughts": copied,
+            "branch_root_idx": tip.index(),
+            "branch_root_id": tip_id.first(),
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "ADMIN / DEPLOY: export every live FastThink session of this connection (of the whole process when fast_think.namespace_sessions is off) — graph, status, owner and wall-clock started_at / last_activity — so a replacement process can take them over with think_restore_all (blue/green hand-off, no DB round trip). Read-only. Returns {ok, count, snapshot: {taken_at, sessions:[{started_at, last_activity, owner_hint, session:{session_id, status, root_idx, thoughts, edges, entities, concepts, annotations}}]}}."
+    )]
+    async fn think_snapshot_all(
+        &self,
+        Parameters(_params): Parameters<ThinkSnapshotAllParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let snapshot = self
+            .fast_think
+            .snapshot_all(self.session_namespace.as_deref());
+        info!("Snapshotted {} FastThink sessions", snapshot.sessions.len());
+        let json = Self::result_to_json(json!({
+            "ok": true,
+            "count": snapshot.sessions.len(),
+            "snapshot": snapshot
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "ADMIN / DEPLOY: install the sessions from a think_snapshot_all snapshot into this process. Thought indices and ids, timeouts and TTLs carry over; sessions run under this process's limits. Sessions are restored into this connection's own namespace. Without on_conflict the restore is refused (invalid_params) while any of this connection's sessions is live unless force=true, which REPLACES them; other connections' sessions are never touched. With on_conflict=skip|overwrite|duplicate|merge it runs against a live process: sessions with new ids are created and each id already live is resolved by the policy (merge adds the snapshot's missing thoughts, edges, entities, concepts and notes to the live session, and is refused with invalid_params when that would pass the live session's thought or depth cap). A policy only ever meets this connection's sessions. Conflict policies cover sessions only; import_memories has none and relies on the add pipeline's dedup. A malformed snapshot changes nothing. Returns {ok, restored} plus, with on_conflict, {on_conflict, created, skipped, overwritten, merged, duplicated: {snapshot_id: new_id}}."
+    )]
+    async fn think_restore_all(
+        &self,
+        Parameters(params): Parameters<ThinkRestoreAllParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let snapshot: ManagerSnapshot = serde_json::from_value(params.snapshot)
+            .map_err(|e| McpError::invalid_params(format!("snapshot: {e}"), None))?;
+        let on_conflict = params
+            .on_conflict
+            .as_deref()
+            .map(|name| {
+                OnConflict::from_name(name).ok_or_else(|| {
+                    McpError::invalid_params(
+                        format!(
+                            "on_conflict: unknown policy '{name}' (expected skip, overwrite, duplicate or merge)"
+                        ),
+                        None,
+                    )
+                })
+            })
+            .transpose()?;
+        let invalid_state = |e: FastThinkError| match e {
+            FastThinkError::InvalidState(msg) => McpError::invalid_params(msg, None),
+            other => McpError::internal_error(other.to_string(), None),
+        };
+
+        let payload = match on_conflict {
+            None => {
+                let restored = self
+                    .fast_think
+                    .restore_all(
+                        snapshot,
+                        params.force.unwrap_or(false),
+                        self.session_namespace.as_deref(),
+                    )
+                    .map_err(invalid_state)?;
+                info!("Restored {} FastThink sessions", restored);
+                json!({ "ok": true, "restored": restored })
+            }
+            Some(_) if params.force.unwrap_or(false) => {
+                return Err(McpError::invalid_params(
+                    "pass either force or on_conflict, not both".to_string(),
+                    None,
+                ));
+            }
+            Some(policy) => {
+                let report = self
+                    .fast_think
+                    .restore_with(snapshot, policy, self.session_namespace.as_deref())
+                    .map_err(invalid_state)?;
+                let restored =
+                    report.created + report.overwritten + report.merged + report.duplicated.len();
+                json!({
+                    "ok": true,
+                    "restored": restored,
+                    "on_conflict": policy.to_string(),
+                    "created": report.created,
+                    "skipped": report.skipped,
+                    "overwritten": report.overwritten,
+                    "merged": report.merged,
+                    "duplicated": report.duplicated,
+                })
+            }
+        };
+        let json = Self::result_to_json(payload)?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Inspect a FastThink session without changing it — useful to check progress or whether a conclusion exists yet. Returns {status, thought_count, thoughts_left, depth, has_conclusion, should_conclude, elapsed_ms, time_remaining_ms, ttl_remaining_ms, annotations}, plus graph {root_idx, thoughts:[{idx, id, content, thought_type, certainty, depth}], edges:[{from, to, edge_type, weight}], entities, concepts} when verbose=true; thoughts_left is your headroom before the session's thought cap — and think_conclude STILL works at 0 (the conclusion is the exit, not another thought). time_remaining_ms is the thinking time left before the session times out (counted from the start, or from your last call when the timeout is idle-based) — conclude before it reaches 0; ttl_remaining_ms is how long the session survives untouched before it is dropped. Both stop at 0. Errors (invalid_params) if the session_id does not exist (e.g. after think_discard or think_commit) — think_list shows the live ones."
     )]
     async fn think_status(
         &self,
         Parameters(params): Parameters<ThinkStatusParams>,
     ) -> Result<CallToolResult, McpError> {
+        let key = self.session_key(&params.session_id);
         let status = self
             .fast_think
-            .get_session_status(&params.session_id)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            .get_session_status(&key)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
         let max_thoughts = self
             .fast_think
-            .session_max_thoughts(&params.session_id)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            .session_max_thoughts(&key)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+        let annotations = self
+            .fast_think
+            .annotations(&key)
+            .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
 
-        let json = Self::result_to_json(json!({
-            "session_id": status.id,
+        let mut payload = json!({
+            "session_id": params.session_id,
             "status": status.status.to_string(),
             "thought_count": status.thought_count,
             // #78: headroom before the thought cap — think_conclude still
@@ -240,8 +1123,21 @@ impl HelixirMcpServer {
             "concept_count": status.concept_count,
             "current_depth": status.current_depth,
             "has_conclusion": status.has_conclusion,
-            "elapsed_ms": status.elapsed.as_millis()
-        }))?;
+            "should_conclude": status.should_conclude,
+            "elapsed_ms": status.elapsed.as_millis(),
+            "time_remaining_ms": status.time_remaining.as_millis(),
+            "ttl_remaining_ms": status.ttl_remaining.as_millis(),
+            "annotations": annotations
+        });
+        if params.verbose.unwrap_or(false) {
+            let mut graph = self
+                .fast_think
+                .snapshot(&key)
+                .map_err(|e| Self::convert_think_error(e, &params.session_id))?;
+            graph.session_id = params.session_id.clone();
+            payload["graph"] = json!(graph);
+        }
+        let json = Self::result_to_json(payload)?;
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 }
@@ -1,5 +1,7 @@
 use std::time::Duration;
 
+pub use crate::core::config::{EntityIdentity, ThinkTimeoutMode};
+
 #[derive(Debug, Clone)]
 pub struct FastThinkLimits {
     pub max_thoughts: usize,
@@ -7,6 +9,9 @@ pub struct FastThinkLimits {
     pub max_concepts: usize,
     pub max_depth: usize,
     pub thinking_timeout: Duration,
+    /// What `thinking_timeout` counts from (see
+    /// `FastThinkConfig::timeout_mode`).
+    pub timeout_mode: ThinkTimeoutMode,
     pub session_ttl: Duration,
     pub max_recall_results: usize,
     /// Score floor for recalls (see `FastThinkConfig::recall_min_score`).
@@ -18,6 +23,24 @@ pub struct FastThinkLimits {
     pub recall_fallback_max: usize,
     /// #78: recall stops this many slots short of the thought cap.
     pub conclude_reserve: usize,
+    /// Certainty-convergence hint (see `FastThinkConfig::convergence_window`);
+    /// a window of 0 disables it.
+    pub convergence_window: usize,
+    pub convergence_min_certainty: f32,
+    pub convergence_max_variance: f32,
+    /// Link recalled thoughts to their most similar reasoning thought (see
+    /// `FastThinkConfig::auto_link_recalls`).
+    pub auto_link_recalls: bool,
+    pub auto_link_min_similarity: f32,
+    /// Refuse an unforced discard of a concluded session (see
+    /// `FastThinkConfig::confirm_discard_concluded`).
+    pub confirm_discard_concluded: bool,
+    /// Whether a scratch entity's type is part of its dedup key (see
+    /// `HelixirConfig::entity_identity`).
+    pub entity_identity: EntityIdentity,
+    /// Reasoning steps a session needs before it may conclude (see
+    /// `FastThinkConfig::min_thoughts_before_conclude`); 0 disables it.
+    pub min_thoughts_before_conclude: usize,
 }
 
 impl Default for FastThinkLimits {
@@ -28,12 +51,21 @@ impl Default for FastThinkLimits {
             max_concepts: 30,
             max_depth: 10,
             thinking_timeout: Duration::from_secs(30),
+            timeout_mode: ThinkTimeoutMode::Absolute,
             session_ttl: Duration::from_secs(300),
             max_recall_results: 5,
             recall_min_score: 0.6,
             recall_fallback_min_score: 0.45,
             recall_fallback_max: 3,
             conclude_reserve: 2,
+            convergence_window: 3,
+            convergence_min_certainty: 0.8,
+            convergence_max_variance: 0.01,
+            auto_link_recalls: false,
+            auto_link_min_similarity: 0.75,
+            confirm_discard_concluded: true,
+            entity_identity: EntityIdentity::Name,
+            min_thoughts_before_conclude: 0,
         }
     }
 }
@@ -47,12 +79,21 @@ impl FastThinkLimits {
             max_concepts: c.max_concepts,
             max_depth: c.max_depth,
             thinking_timeout: Duration::from_secs(c.thinking_timeout_secs),
+            timeout_mode: c.timeout_mode,
             session_ttl: Duration::from_secs(c.session_ttl_secs),
             max_recall_results: c.max_recall_results,
             recall_min_score: c.recall_min_score,
             recall_fallback_min_score: c.recall_fallback_min_score,
             recall_fallback_max: c.recall_fallback_max,
             conclude_reserve: c.conclude_reserve,
+            convergence_window: c.convergence_window,
+            convergence_min_certainty: c.convergence_min_certainty,
+            convergence_max_variance: c.convergence_max_variance,
+            auto_link_recalls: c.auto_link_recalls,
+            auto_link_min_similarity: c.auto_link_min_similarity,
+            confirm_discard_concluded: c.confirm_discard_concluded,
+            entity_identity: EntityIdentity::Name,
+            min_thoughts_before_conclude: c.min_thoughts_before_conclude,
         }
     }
 
@@ -63,12 +104,21 @@ impl FastThinkLimits {
             max_concepts: 50,
             max_depth: 15,
             thinking_timeout: Duration::from_secs(60),
+            timeout_mode: ThinkTimeoutMode::Absolute,
             session_ttl: Duration::from_secs(600),
             max_recall_results: 10,
             recall_min_score: 0.6,
             recall_fallback_min_score: 0.45,
             recall_fallback_max: 3,
             conclude_reserve: 2,
+            convergence_window: 3,
+            convergence_min_certainty: 0.8,
+            convergence_max_variance: 0.01,
+            auto_link_recalls: false,
+            auto_link_min_similarity: 0.75,
+            confirm_discard_concluded: true,
+            entity_identity: EntityIdentity::Name,
+            min_thoughts_before_conclude: 0,
         }
     }
 
@@ -82,12 +132,21 @@ impl FastThinkLimits {
             max_concepts: 40,
             max_depth: 12,
             thinking_timeout: Duration::from_secs(90),
+            timeout_mode: ThinkTimeoutMode::Absolute,
             session_ttl: Duration::from_secs(600),
             max_recall_results: 8,
             recall_min_score: 0.6,
             recall_fallback_min_score: 0.45,
             recall_fallback_max: 3,
             conclude_reserve: 2,
+            convergence_window: 3,
+            convergence_min_certainty: 0.8,
+            convergence_max_variance: 0.01,
+            auto_link_recalls: false,
+            auto_link_min_similarity: 0.75,
+            confirm_discard_concluded: true,
+            entity_identity: EntityIdentity::Name,
+            min_thoughts_before_conclude: 0,
         }
     }
 
@@ -98,12 +157,21 @@ impl FastThinkLimits {
             max_concepts: 15,
             max_depth: 5,
             thinking_timeout: Duration::from_secs(15),
+            timeout_mode: ThinkTimeoutMode::Absolute,
             session_ttl: Duration::from_secs(120),
             max_recall_results: 3,
             recall_min_score: 0.6,
             recall_fallback_min_score: 0.45,
             recall_fallback_max: 1,
             conclude_reserve: 2,
+            convergence_window: 3,
+            convergence_min_certainty: 0.8,
+            convergence_max_variance: 0.01,
+            auto_link_recalls: false,
+            auto_link_min_similarity: 0.75,
+            confirm_discard_concluded: true,
+            entity_identity: EntityIdentity::Name,
+            min_thoughts_before_conclude: 0,
         }
     }
 
@@ -117,6 +185,16 @@ impl FastThinkLimits {
         self
     }
 
+    pub fn with_timeout_mode(mut self, mode: ThinkTimeoutMode) -> Self {
+        self.timeout_mode = mode;
+        self
+    }
+
+    pub fn with_entity_identity(mut self, identity: EntityIdentity) -> Self {
+        self.entity_identity = identity;
+        self
+    }
+
     pub fn with_max_depth(mut self, depth: usize) -> Self {
         self.max_depth = depth;
         self
@@ -137,12 +215,15 @@ mod tests {
         c.recall_min_score = 0.42;
         c.recall_fallback_min_score = 0.33;
         c.recall_fallback_max = 2;
+        c.auto_link_recalls = true;
         let limits = FastThinkLimits::from_config(&c);
         assert_eq!(limits.max_recall_results, 3);
         assert!((limits.recall_min_score - 0.42).abs() < f32::EPSILON);
         assert!((limits.recall_fallback_min_score - 0.33).abs() < f32::EPSILON);
         assert_eq!(limits.recall_fallback_max, 2);
+        assert!(limits.auto_link_recalls);
         assert_eq!(limits.conclude_reserve, 2, "#78 reserve flows from config");
+        assert_eq!(limits.convergence_window, 3);
     }
 
     /// #90: the fallback cap must stay SMALLER than the primary cap in every
@@ -1,22 +1,56 @@
 use parking_lot::RwLock;
 use petgraph::stable_graph::NodeIndex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 use super::limits::FastThinkLimits;
 use super::models::*;
-use super::session::ThinkingSession;
+use super::session::{ConclusionConfidence, ThinkingSession};
+use super::snapshot::{
+    ManagedSessionSnapshot, ManagerSnapshot, OnConflict, RestoreReport, SessionSnapshot,
+};
+use super::templates::ReasoningTemplate;
 use crate::core::HelixirClient;
+use crate::core::helixir_client::WebhookEvent;
+use crate::toolkit::tooling_manager::THINK_COMMIT_AUTHOR;
+use crate::utils::cosine_similarity;
 
 struct FastThinkRuntime {
     limits: FastThinkLimits,
     main_memory: Arc<HelixirClient>,
+    /// `fast_think.templates_dir`, read once per generation.
+    templates: Arc<[ReasoningTemplate]>,
 }
 
+impl FastThinkRuntime {
+    fn new(main_memory: Arc<HelixirClient>, limits: FastThinkLimits) -> Self {
+        let templates = match main_memory.config().fast_think.templates_dir.as_deref() {
+            Some(dir) => super::templates::load_templates(std::path::Path::new(dir)).into(),
+            None => Arc::from([]),
+        };
+        Self {
+            limits,
+            main_memory,
+            templates,
+        }
+    }
+}
+
+/// Tag carried by the [INCOMPLETE] memories `commit_partial` writes; what
+/// `search_incomplete_thoughts` looks up and a superseding commit removes.
+pub const INCOMPLETE_THOUGHT_TAG: &str = "incomplete_thought";
+
 struct ManagedSession {
     state: ThinkingSession,
     runtime: Arc<FastThinkRuntime>,
+    /// Set while a `recall` awaits main memory with the lock released;
+    /// mutating calls in that window are refused (see [`idle_session`]).
+    recalling: bool,
+    /// Embeddings `search_thoughts` has computed, keyed by thought content
+    /// (so an edited thought is re-embedded). Lives as long as the session
+    /// and is never snapshotted.
+    embeddings: HashMap<String, Vec<f32>>,
 }
 
 impl std::ops::Deref for ManagedSession {
@@ -33,8 +67,150 @@ impl std::ops::DerefMut for ManagedSession {
     }
 }
 
+/// Mutable access to a session that is not mid-recall. `recall` releases
+/// the lock around its search; a mutation landing in that gap would change
+/// the parent/limit assumptions the recall resumes with, so it is refused
+/// with `SessionBusy` instead — the caller retries once the
+/// recall returns.
+fn idle_session<'a>(
+    sessions: &'a mut HashMap<String, ManagedSession>,
+    session_id: &str,
+) -> Result<&'a mut ManagedSession, FastThinkError> {
+    let session = sessions
+        .get_mut(session_id)
+        .ok_or(FastThinkError::SessionNotFound)?;
+    if session.recalling {
+        return Err(FastThinkError::SessionBusy);
+    }
+    Ok(session)
+}
+
+/// The text of annotation `note_idx`, or `NoteNotFound` naming how many
+/// notes the session holds.
+fn note_at(session: &ThinkingSession, note_idx: usize) -> Result<String, FastThinkError> {
+    session
+        .annotations
+        .get(note_idx)
+        .cloned()
+        .ok_or(FastThinkError::NoteNotFound {
+            note_idx,
+            count: session.annotations.len(),
+        })
+}
+
+/// Content of the [INCOMPLETE] memory `memory_id` as `user_id` holds it:
+/// `MemoryNotFound` when no memory has the id or `user_id` does not hold
+/// it, `NotIncomplete` when it lacks the tag.
+async fn incomplete_content(
+    client: &HelixirClient,
+    memory_id: &str,
+    user_id: &str,
+) -> Result<String, FastThinkError> {
+    let memory = client
+        .get_memory(memory_id, user_id)
+        .await
+        .map_err(|e| FastThinkError::RecallFailed(e.to_string()))?
+        .ok_or_else(|| FastThinkError::MemoryNotFound(memory_id.to_string()))?;
+    if !memory
+        .context_tags
+        .iter()
+        .any(|t| t == INCOMPLETE_THOUGHT_TAG)
+    {
+        return Err(FastThinkError::NotIncomplete(memory_id.to_string()));
+    }
+    Ok(memory.content)
+}
+
+/// Put back a session whose commit failed to persist, so the agent can
+/// retry instead of losing every thought. Removing it up front is what keeps
+/// a second commit of the same session from racing the first; the failed
+/// attempt counts as activity for the TTL sweep.
+fn restore_uncommitted(
+    sessions: &RwLock<HashMap<String, ManagedSession>>,
+    mut session: ManagedSession,
+) {
+    session.last_activity = std::time::Instant::now();
+    let id = session.id.clone();
+    sessions.write().entry(id).or_insert(session);
+}
+
+/// A committed session kept for `uncommit` (`commit(retain)`), for the
+/// session TTL after the commit.
+struct RetainedCommit {
+    session: ManagedSession,
+    /// Memories the commit created. Ids it was deduped to existed before
+    /// and survive an undo.
+    created_ids: Vec<String>,
+    /// Whose memories they are, for the `delete` webhook an undo sends.
+    user_id: String,
+    committed_at: std::time::Instant,
+}
+
+impl RetainedCommit {
+    fn expired(&self) -> bool {
+        self.committed_at.elapsed() >= self.session.runtime.limits.session_ttl
+    }
+}
+
+/// Drop the retained commits whose undo window has passed, all but
+/// `keep`'s, so `uncommit` can still tell its caller the window closed.
+/// Nothing else empties the map, so every access prunes it.
+fn prune_retained(retained: &mut HashMap<String, RetainedCommit>, keep: Option<&str>) {
+    retained.retain(|id, r| Some(id.as_str()) == keep || !r.expired());
+}
+
+/// What [`FastThinkManager::commit`] derived from the session before
+/// writing: the conclusion text, the evidence it rests on and how to type
+/// and count it.
+struct CommitDraft {
+    conclusion_content: String,
+    supporting_ids: Vec<String>,
+    classification: Option<(&'static str, i32)>,
+    entities: Vec<CommittedEntity>,
+    concepts: Vec<String>,
+    /// Wait for entity extraction so the reported counts match the graph.
+    reconcile: bool,
+}
+
+/// Clears `recalling` when the recall finishes — including on an error
+/// return or when the caller drops the future mid-search.
+struct RecallGuard<'a> {
+    sessions: &'a RwLock<HashMap<String, ManagedSession>>,
+    session_id: &'a str,
+}
+
+/// The manager key of the caller in `namespace`'s `session_id`:
+/// `<namespace>/session_id`, or the id itself without a namespace. The
+/// inverse of [`caller_id`]; every namespaced key is built with it.
+pub fn session_key(namespace: Option<&str>, session_id: &str) -> String {
+    match namespace {
+        Some(ns) => format!("{ns}/{session_id}"),
+        None => session_id.to_string(),
+    }
+}
+
+/// A manager key as the caller in `namespace` names it: `<namespace>/id`
+/// loses its prefix, a key of another namespace is `None`. Without a
+/// namespace every key is the caller's own.
+pub fn caller_id<'a>(namespace: Option<&str>, key: &'a str) -> Option<&'a str> {
+    match namespace {
+        Some(ns) => key.strip_prefix(ns)?.strip_prefix('/'),
+        None => Some(key),
+    }
+}
+
+impl Drop for RecallGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(session) = self.sessions.write().get_mut(self.session_id) {
+            session.recalling = false;
+        }
+    }
+}
+
 pub struct FastThinkManager {
     sessions: RwLock<HashMap<String, ManagedSession>>,
+    /// Committed sessions kept for `uncommit`, by session id.
+    retained: RwLock<HashMap<String, RetainedCommit>>,
     current: arc_swap::ArcSwap<FastThinkRuntime>,
 }
 
@@ -42,10 +218,8 @@ impl FastThinkManager {
     pub fn new(main_memory: Arc<HelixirClient>, limits: FastThinkLimits) -> Self {
         Self {
             sessions: RwLock::new(HashMap::new()),
-            current: arc_swap::ArcSwap::from_pointee(FastThinkRuntime {
-                limits,
-                main_memory,
-            }),
+            retained: RwLock::new(HashMap::new()),
+            current: arc_swap::ArcSwap::from_pointee(FastThinkRuntime::new(main_memory, limits)),
         }
     }
 
@@ -55,12 +229,16 @@ impl FastThinkManager {
 
     /// Publish a new runtime generation for sessions started after a hot
     /// reload. Existing sessions retain the client and limits they began
-    /// with, so their reasoning remains internally consistent.
+    /// with, so their reasoning remains internally consistent. The
+    /// reasoning templates are re-read here and nowhere else.
     pub fn update_runtime(&self, main_memory: Arc<HelixirClient>, limits: FastThinkLimits) {
-        self.current.store(Arc::new(FastThinkRuntime {
-            limits,
-            main_memory,
-        }));
+        self.current
+            .store(Arc::new(FastThinkRuntime::new(main_memory, limits)));
+    }
+
+    /// The reasoning templates of the current generation, sorted by name.
+    pub fn templates(&self) -> Arc<[ReasoningTemplate]> {
+        Arc::clone(&self.current.load().templates)
     }
 
     pub fn start_thinking(
@@ -68,6 +246,22 @@ impl FastThinkManager {
         session_id: &str,
         initial_thought: &str,
     ) -> Result<NodeIndex, FastThinkError> {
+        self.open_session(session_id, Some(initial_thought))
+            .map(|root| root.expect("an initial thought becomes the root"))
+    }
+
+    /// Open a session with no thoughts yet ("recall, then reason"): the
+    /// first `add_thought` becomes its root, or a first `recall` roots it
+    /// at its query.
+    pub fn start_rootless(&self, session_id: &str) -> Result<(), FastThinkError> {
+        self.open_session(session_id, None).map(|_| ())
+    }
+
+    fn open_session(
+        &self,
+        session_id: &str,
+        initial_thought: Option<&str>,
+    ) -> Result<Option<NodeIndex>, FastThinkError> {
         let mut sessions = self.sessions.write();
 
         if sessions.contains_key(session_id) {
@@ -76,28 +270,149 @@ impl FastThinkManager {
 
         let runtime = self.current.load_full();
         let mut session = ThinkingSession::new(session_id);
-        let node = session.add_thought(
-            initial_thought,
+        let node = initial_thought
+            .map(|thought| {
+                session.add_thought(thought, ThoughtType::Initial, None, None, &runtime.limits)
+            })
+            .transpose()?;
+
+        info!(
+            session_id = session_id,
+            thought = initial_thought.unwrap_or("<none>"),
+            "Started thinking session"
+        );
+
+        sessions.insert(
+            session_id.to_string(),
+            ManagedSession {
+                state: session,
+                runtime,
+                recalling: false,
+                embeddings: HashMap::new(),
+            },
+        );
+        Ok(node)
+    }
+
+    /// Fork `source_id` at `from_thought` into a new session `new_id` (see
+    /// [`ThinkingSession::fork`]) to try another path; the source is left
+    /// as it is. The fork runs under the current limits with its own
+    /// timeout and TTL. Returns the fork's copy of `from_thought` and how
+    /// many thoughts were copied.
+    pub fn branch(
+        &self,
+        source_id: &str,
+        new_id: &str,
+        from_thought: NodeIndex,
+    ) -> Result<(NodeIndex, usize), FastThinkError> {
+        let mut sessions = self.sessions.write();
+        let source = sessions
+            .get(source_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+        if sessions.contains_key(new_id) {
+            return Err(FastThinkError::SessionAlreadyExists);
+        }
+        let (state, tip) = source.fork(new_id, from_thought)?;
+        let copied = state.graph.node_count();
+        let embeddings = source.embeddings.clone();
+        info!(
+            source = source_id,
+            session_id = new_id,
+            copied,
+            "Branched thinking session"
+        );
+        sessions.insert(
+            new_id.to_string(),
+            ManagedSession {
+                state,
+                runtime: self.current.load_full(),
+                recalling: false,
+                embeddings,
+            },
+        );
+        Ok((tip, copied))
+    }
+
+    /// Open `session_id` as the continuation of the [INCOMPLETE] memory
+    /// `memory_id`: its saved thoughts come back as recall thoughts under
+    /// the root, and the session remembers the memory so its commit updates
+    /// it in place (and drops the tag) instead of adding a duplicate.
+    /// A memory `user_id` does not hold is `MemoryNotFound`. Returns the
+    /// root and the seeded thought indices.
+    pub async fn continue_incomplete(
+        &self,
+        session_id: &str,
+        memory_id: &str,
+        user_id: &str,
+    ) -> Result<(NodeIndex, Vec<NodeIndex>), FastThinkError> {
+        if self.sessions.read().contains_key(session_id) {
+            return Err(FastThinkError::SessionAlreadyExists);
+        }
+        let runtime = self.current.load_full();
+        let content = incomplete_content(&runtime.main_memory, memory_id, user_id).await?;
+        self.start_continuation(session_id, memory_id, &content, runtime)
+    }
+
+    fn start_continuation(
+        &self,
+        session_id: &str,
+        memory_id: &str,
+        content: &str,
+        runtime: Arc<FastThinkRuntime>,
+    ) -> Result<(NodeIndex, Vec<NodeIndex>), FastThinkError> {
+        let mut session = ThinkingSession::new(session_id);
+        let root = session.add_thought(
+            &format!("Continue the incomplete research saved as {memory_id}"),
             ThoughtType::Initial,
             None,
             None,
             &runtime.limits,
         )?;
+        // Same headroom rule as `recall`: seeds never fill the session.
+        let ceiling = runtime
+            .limits
+            .max_thoughts
+            .saturating_sub(runtime.limits.conclude_reserve);
+        let mut seeded = Vec::new();
+        for thought in incomplete_seed_thoughts(content) {
+            if session.thought_count() >= ceiling {
+                warn!(
+                    session_id = session_id,
+                    "continue_incomplete: seeding stopped at the reserve ceiling ({ceiling} thoughts)"
+                );
+                break;
+            }
+            // Unverified work in progress, not matched evidence: neutral certainty.
+            seeded.push(session.add_recalled_thought(
+                &thought,
+                memory_id,
+                0.5,
+                root,
+                &runtime.limits,
+            )?);
+        }
+        session.continues_memory_id = Some(memory_id.to_string());
 
+        let mut sessions = self.sessions.write();
+        if sessions.contains_key(session_id) {
+            return Err(FastThinkError::SessionAlreadyExists);
+        }
         info!(
             session_id = session_id,
-            thought = initial_thought,
-            "Started thinking session"
+            memory_id = memory_id,
+            seeded = seeded.len(),
+            "Continuing incomplete thought"
         );
-
         sessions.insert(
             session_id.to_string(),
             ManagedSession {
                 state: session,
                 runtime,
+                recalling: false,
+                embeddings: HashMap::new(),
             },
         );
-        Ok(node)
+        Ok((root, seeded))
     }
 
     pub fn add_thought(
@@ -106,16 +421,29 @@ impl FastThinkManager {
         content: &str,
         thought_type: ThoughtType,
         parent: Option<NodeIndex>,
-        edge_type: Option<ThoughtEdge>,
+        options: ThoughtOptions,
     ) -> Result<NodeIndex, FastThinkError> {
+        let ThoughtOptions {
+            edge,
+            certainty,
+            data,
+        } = options;
         let mut sessions = self.sessions.write();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or(FastThinkError::SessionNotFound)?;
+        let session = idle_session(&mut sessions, session_id)?;
 
         let runtime = Arc::clone(&session.runtime);
+        let edge_type = edge.as_ref().map(|e| e.kind.clone());
         let node =
             session.add_thought(content, thought_type, parent, edge_type, &runtime.limits)?;
+        if let (Some(parent), Some(edge)) = (parent, &edge) {
+            session.set_edge_weight(parent, node, edge.weight)?;
+        }
+        if let Some(thought) = session.get_thought_mut(node) {
+            if let Some(c) = certainty {
+                thought.certainty = c.clamp(0.0, 1.0);
+            }
+            thought.data = data.filter(|d| !d.is_null());
+        }
 
         debug!(
             session_id = session_id,
@@ -127,36 +455,229 @@ impl FastThinkManager {
         Ok(node)
     }
 
-    pub async fn recall(
+    /// Stamp `template`'s thoughts into the session under `parent`, or under
+    /// the root when `None` (a rootless session is rooted by the first
+    /// top-level thought). All or nothing: a template that would pass the
+    /// thought cap or `max_depth` adds no thought. Returns the new indices
+    /// in template order.
+    pub fn apply_template(
         &self,
         session_id: &str,
-        query: &str,
-        parent_thought: NodeIndex,
+        template: &ReasoningTemplate,
+        parent: Option<NodeIndex>,
+    ) -> Result<Vec<NodeIndex>, FastThinkError> {
+        template.validate().map_err(FastThinkError::InvalidState)?;
+        let mut sessions = self.sessions.write();
+        let session = idle_session(&mut sessions, session_id)?;
+        let runtime = Arc::clone(&session.runtime);
+        let limits = &runtime.limits;
+
+        let attach = parent.or(session.root());
+        let base_depth = match attach {
+            Some(p) => {
+                session
+                    .get_thought(p)
+                    .ok_or(FastThinkError::ThoughtNotFound)?
+                    .depth
+            }
+            None => 0,
+        };
+        let levels = template.depth();
+        if base_depth + levels > limits.max_depth {
+            return Err(FastThinkError::TooDeep {
+                max_depth: limits.max_depth,
+                parent_depth: base_depth + levels - 1,
+            });
+        }
+        if session.thought_count() + template.thoughts.len() > limits.max_thoughts {
+            return Err(FastThinkError::TooManyThoughts);
+        }
+
+        let parents = template.parent_positions();
+        let mut nodes: Vec<NodeIndex> = Vec::with_capacity(template.thoughts.len());
+        let mut top = attach;
+        for (thought, parent_pos) in template.thoughts.iter().zip(parents) {
+            let thought_type =
+                ThoughtType::from_name(&thought.thought_type).unwrap_or(ThoughtType::Question);
+            let parent = match parent_pos {
+                Some(p) => Some(nodes[p]),
+                None => top,
+            };
+            let node = session.add_thought(&thought.content, thought_type, parent, None, limits)?;
+            top = top.or(Some(node));
+            nodes.push(node);
+        }
+
+        debug!(
+            session_id = session_id,
+            template = %template.name,
+            added = nodes.len(),
+            "Applied reasoning template"
+        );
+        Ok(nodes)
+    }
+
+    /// Fold the thoughts of the [INCOMPLETE] memory `memory_id` into the
+    /// live session under `parent`, or under the root when `None`, as typed
+    /// nodes instead of flat recall text (see [`incomplete_thought_nodes`]):
+    /// the interrupted session's initial thought comes back as a question
+    /// and its other thoughts hang under it. All or nothing: an import that
+    /// would pass `max_depth` or the recall reserve below the thought cap
+    /// adds nothing. The memory itself is left as it is; one `user_id`
+    /// does not hold is `MemoryNotFound`. Returns the new indices in saved
+    /// order.
+    pub async fn import_incomplete(
+        &self,
+        session_id: &str,
+        memory_id: &str,
         user_id: &str,
+        parent: Option<NodeIndex>,
     ) -> Result<Vec<NodeIndex>, FastThinkError> {
         let runtime = {
             let mut sessions = self.sessions.write();
-            let session = sessions
-                .get_mut(session_id)
-                .ok_or(FastThinkError::SessionNotFound)?;
+            Arc::clone(&idle_session(&mut sessions, session_id)?.runtime)
+        };
+        let content = incomplete_content(&runtime.main_memory, memory_id, user_id).await?;
+        let thoughts = incomplete_thought_nodes(&content);
+
+        let mut sessions = self.sessions.write();
+        let session = idle_session(&mut sessions, session_id)?;
+        let limits = &runtime.limits;
+        let attach = parent.or(session.root());
+        let base_depth = match attach {
+            Some(p) => {
+                session
+                    .get_thought(p)
+                    .ok_or(FastThinkError::ThoughtNotFound)?
+                    .depth
+            }
+            None => 0,
+        };
+        let headed = thoughts
+            .first()
+            .is_some_and(|(t, _)| *t == ThoughtType::Question)
+            && thoughts.len() > 1;
+        let levels = if headed { 2 } else { 1 };
+        if base_depth + levels > limits.max_depth {
+            return Err(FastThinkError::TooDeep {
+                max_depth: limits.max_depth,
+                parent_depth: base_depth + levels - 1,
+            });
+        }
+        // Same headroom rule as `recall`: an import never fills the session.
+        let ceiling = limits.max_thoughts.saturating_sub(limits.conclude_reserve);
+        if session.thought_count() + thoughts.len() > ceiling {
+            return Err(FastThinkError::TooManyThoughts);
+        }
+
+        let mut nodes: Vec<NodeIndex> = Vec::with_capacity(thoughts.len());
+        let mut under = attach;
+        for (i, (thought_type, text)) in thoughts.iter().enumerate() {
+            // Unverified work in progress, not matched evidence: neutral certainty.
+            let node = match (thought_type, under) {
+                (ThoughtType::Recall, Some(p)) => {
+                    session.add_recalled_thought(text, memory_id, 0.5, p, limits)?
+                }
+                _ => {
+                    let node =
+                        session.add_thought(text, thought_type.clone(), under, None, limits)?;
+                    if let Some(t) = session.get_thought_mut(node) {
+                        t.certainty = 0.5;
+                    }
+                    node
+                }
+            };
+            if i == 0 && (headed || under.is_none()) {
+                under = Some(node);
+            }
+            nodes.push(node);
+        }
+
+        info!(
+            session_id = session_id,
+            memory_id = memory_id,
+            imported = nodes.len(),
+            "Imported incomplete thought"
+        );
+        Ok(nodes)
+    }
+
+    /// Recall main-memory facts into the session under `parent_thought`, or
+    /// under the session root when `None`; a session without one (see
+    /// [`Self::start_rootless`]) is rooted at the query first, before the
+    /// search runs. Returns the parent actually used,
+    /// the new thought indices and, with `auto_link_recalls`, the edges that
+    /// tie them to the session's reasoning.
+    pub async fn recall(
+        &self,
+        session_id: &str,
+        query: &str,
+        parent_thought: Option<NodeIndex>,
+        user_id: &str,
+    ) -> Result<(NodeIndex, Vec<NodeIndex>, Vec<AutoLink>), FastThinkError> {
+        let (runtime, parent_thought) = {
+            let mut sessions = self.sessions.write();
+            let session = idle_session(&mut sessions, session_id)?;
+            let parent_thought = match parent_thought {
+                // Checked before the search runs, not when the first recall
+                // is attached.
+                Some(parent) if session.get_thought(parent).is_none() => {
+                    return Err(FastThinkError::ThoughtNotFound);
+                }
+                Some(parent) => parent,
+                None => match session.root() {
+                    Some(root) => root,
+                    None => {
+                        let question = if query.trim().is_empty() {
+                            "What do recent memories say?"
+                        } else {
+                            query
+                        };
+                        let runtime = Arc::clone(&session.runtime);
+                        session.add_thought(
+                            question,
+                            ThoughtType::Initial,
+                            None,
+                            None,
+                            &runtime.limits,
+                        )?
+                    }
+                },
+            };
             session.status = SessionStatus::NeedsRecall;
             session.owner_hint = Some(user_id.to_string());
-            Arc::clone(&session.runtime)
+            session.recalling = true;
+            (Arc::clone(&session.runtime), parent_thought)
+        };
+        let _busy = RecallGuard {
+            sessions: &self.sessions,
+            session_id,
         };
 
-        let mut memories = runtime
-            .main_memory
-            .search(
-                query,
-                user_id,
-                crate::core::helixir_client::SearchParams {
-                    limit: Some(runtime.limits.max_recall_results),
-                    search_mode: Some("contextual".to_string()),
-                    ..Default::default()
-                },
-            )
-            .await
-            .map_err(|e| FastThinkError::RecallFailed(e.to_string()))?;
+        // A blank query reaching here is the server's "browse recent" policy
+        // (the MCP layer rejects it otherwise): never embed "" — take the
+        // newest memories instead, outside the relevance belt below.
+        let browse = query.trim().is_empty();
+        let mut memories = if browse {
+            runtime
+                .main_memory
+                .recent(user_id, Some(runtime.limits.max_recall_results))
+                .await
+        } else {
+            runtime
+                .main_memory
+                .search(
+                    query,
+                    user_id,
+                    crate::core::helixir_client::SearchParams {
+                        limit: Some(runtime.limits.max_recall_results),
+                        search_mode: Some("contextual".to_string()),
+                        ..Default::default()
+                    },
+                )
+                .await
+        }
+        .map_err(|e| FastThinkError::RecallFailed(e.to_string()))?;
         // #81 belt: the limit above bounds the search, but a recall must
         // never exceed max_recall_results regardless of engine behavior —
         // every recalled row becomes a session thought AND a SUPPORTS
@@ -164,7 +685,9 @@ impl FastThinkManager {
         // context-window flood for the agent and a slow commit. The score
         // floor guards the THIN-store case where the top-K itself reaches
         // into the flat expansion tail (see recall_min_score in config).
-        memories.retain(|m| m.score >= runtime.limits.recall_min_score);
+        if !browse {
+            memories.retain(|m| m.score >= runtime.limits.recall_min_score);
+        }
         memories.truncate(runtime.limits.max_recall_results);
 
         // #90: the belt's failure mode must not be a silent zero. A strong
@@ -175,7 +698,7 @@ impl FastThinkManager {
         // fallback row is annotated as weak evidence, so the tree and the
         // SUPPORTS provenance stay honest about its quality.
         let mut weak_evidence = false;
-        if memories.is_empty() && runtime.limits.recall_fallback_max > 0 {
+        if !browse && memories.is_empty() && runtime.limits.recall_fallback_max > 0 {
             let mut wide = runtime
                 .main_memory
                 .search(
@@ -232,13 +755,18 @@ impl FastThinkManager {
                         "[weak recall, score {:.2} — below the primary evidence bar] {}",
                         memory.score, memory.content
                     )
+                } else if browse {
+                    format!("[recent memory, not matched to a query] {}", memory.content)
                 } else {
                     memory.content.clone()
                 };
+                // Browse rows carry no relevance score; recall them at neutral
+                // certainty rather than zero.
+                let score = if browse { 0.5 } else { memory.score };
                 let node = session.add_recalled_thought(
                     &content,
                     &memory.id,
-                    memory.score,
+                    score,
                     parent_thought,
                     &runtime.limits,
                 )?;
@@ -249,7 +777,122 @@ impl FastThinkManager {
             session.status = SessionStatus::Thinking;
         }
 
-        Ok(recalled_nodes)
+        // Still under the recall guard: the thoughts being compared cannot
+        // change while their embeddings are computed.
+        let auto_links = if runtime.limits.auto_link_recalls && !recalled_nodes.is_empty() {
+            self.auto_link(session_id, &runtime, &recalled_nodes).await
+        } else {
+            Vec::new()
+        };
+
+        Ok((parent_thought, recalled_nodes, auto_links))
+    }
+
+    /// Link each of `recalled` to its most similar non-recall thought (see
+    /// [`pick_auto_links`]). Best-effort: an embedding failure leaves the
+    /// recalls as plain children of their parent.
+    async fn auto_link(
+        &self,
+        session_id: &str,
+        runtime: &FastThinkRuntime,
+        recalled: &[NodeIndex],
+    ) -> Vec<AutoLink> {
+        let (targets, recalls) = {
+            let sessions = self.sessions.read();
+            let Some(session) = sessions.get(session_id) else {
+                return Vec::new();
+            };
+            let targets: Vec<(NodeIndex, String)> = session
+                .graph
+                .node_indices()
+                .filter_map(|idx| {
+                    let t = session.get_thought(idx)?;
+                    (!t.is_recall() && t.thought_type != ThoughtType::Conclusion)
+                        .then(|| (idx, t.content.clone()))
+                })
+                .collect();
+            let recalls: Vec<(NodeIndex, String, Vec<NodeIndex>)> = recalled
+                .iter()
+                .filter_map(|&idx| {
+                    let t = session.get_thought(idx)?;
+                    // Drop a "[weak recall ...]" / "[recent memory ...]" marker:
+                    // it is not part of the recalled statement.
+                    let text = t
+                        .content
+                        .strip_prefix('[')
+                        .and_then(|rest| rest.split_once("] "))
+                        .map_or(t.content.as_str(), |(_, body)| body);
+                    Some((idx, text.to_string(), session.get_chain_to_root(idx)))
+                })
+                .collect();
+            (targets, recalls)
+        };
+        if targets.is_empty() || recalls.is_empty() {
+            return Vec::new();
+        }
+
+        let texts: Vec<&str> = targets
+            .iter()
+            .map(|(_, c)| c.as_str())
+            .chain(recalls.iter().map(|(_, c, _)| c.as_str()))
+            .collect();
+        let vectors = match runtime
+            .main_memory
+            .embedder()
+            .generate_batch(&texts, true)
+            .await
+        {
+            Ok(v) if v.len() == texts.len() => v,
+            Ok(_) => return Vec::new(),
+            Err(e) => {
+                warn!(session_id = session_id, "Recall auto-link skipped: {e}");
+                return Vec::new();
+            }
+        };
+        let (target_vecs, recall_vecs) = vectors.split_at(targets.len());
+        let candidates: Vec<LinkCandidate<'_>> = targets
+            .iter()
+            .zip(target_vecs)
+            .map(|((idx, text), vector)| LinkCandidate {
+                idx: *idx,
+                text,
+                vector,
+                chain: &[],
+            })
+            .collect();
+        let sources: Vec<LinkCandidate<'_>> = recalls
+            .iter()
+            .zip(recall_vecs)
+            .map(|((idx, text, chain), vector)| LinkCandidate {
+                idx: *idx,
+                text,
+                vector,
+                chain,
+            })
+            .collect();
+        let links = pick_auto_links(
+            &sources,
+            &candidates,
+            runtime.limits.auto_link_min_similarity,
+        );
+
+        // Another recall started while these embedded: leave the recalls
+        // unlinked rather than edit a graph that recall resumes into.
+        let mut sessions = self.sessions.write();
+        let Ok(session) = idle_session(&mut sessions, session_id) else {
+            return Vec::new();
+        };
+        links
+            .into_iter()
+            .filter_map(|mut link| {
+                let (from, to) = (NodeIndex::new(link.from_idx), NodeIndex::new(link.to_idx));
+                let edge = WeightedEdge::with_weight(link.kind.clone(), link.similarity);
+                session.link_thoughts(from, to, edge).ok()?;
+                link.from_id = session.thought_id(from)?.to_string();
+                link.to_id = session.thought_id(to)?.to_string();
+                Some(link)
+            })
+            .collect()
     }
 
     pub fn conclude(
@@ -257,41 +900,86 @@ impl FastThinkManager {
         session_id: &str,
         conclusion: &str,
         supporting_thoughts: &[NodeIndex],
+    ) -> Result<NodeIndex, FastThinkError> {
+        self.conclude_related(session_id, conclusion, supporting_thoughts, None)
+    }
+
+    /// [`Self::conclude`] with the new conclusion refining or contradicting
+    /// an earlier one (see [`ThinkingSession::add_related_conclusion`]).
+    pub fn conclude_related(
+        &self,
+        session_id: &str,
+        conclusion: &str,
+        supporting_thoughts: &[NodeIndex],
+        relation: Option<(NodeIndex, ThoughtEdge)>,
     ) -> Result<NodeIndex, FastThinkError> {
         let mut sessions = self.sessions.write();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or(FastThinkError::SessionNotFound)?;
+        let session = idle_session(&mut sessions, session_id)?;
 
         let runtime = Arc::clone(&session.runtime);
-        let node = session.add_conclusion(conclusion, supporting_thoughts, &runtime.limits)?;
+        let related = relation
+            .as_ref()
+            .map(|(prior, kind)| (prior.index(), kind.to_string()));
+        let node = session.add_related_conclusion(
+            conclusion,
+            supporting_thoughts,
+            relation,
+            &runtime.limits,
+        )?;
 
         info!(
             session_id = session_id,
             supporting_count = supporting_thoughts.len(),
+            related = ?related,
             "Reached conclusion"
         );
 
         Ok(node)
     }
 
+    /// Persist the session's conclusions. With `supersede_memory_id` (an
+    /// [INCOMPLETE] memory this session continued) the conclusion REPLACES
+    /// that memory's content via `update` and drops its `incomplete_thought`
+    /// tag, instead of adding a duplicate next to it.
+    ///
+    /// `reconcile_counts` (default `fast_think.commit_reconcile_counts`)
+    /// waits for the main-memory entity extraction the fast path otherwise
+    /// runs in the background, then reports the entities and concepts the
+    /// stored graph links to the committed memory instead of the session's
+    /// scratch counts.
+    ///
+    /// `retain` keeps the committed session for one session TTL so
+    /// [`Self::uncommit`] can take the commit back. A superseding commit is
+    /// never retained: undoing it would delete the memory it rewrote.
     pub async fn commit(
         &self,
         session_id: &str,
         user_id: &str,
+        supersede_memory_id: Option<&str>,
+        auto_classify: Option<bool>,
+        reconcile_counts: Option<bool>,
+        retain: bool,
     ) -> Result<CommitResult, FastThinkError> {
+        // Check before removing: a commit without a conclusion is a caller
+        // mistake, and it must leave the session (and its thoughts) in place
+        // so the agent can still think_conclude, or retry with allow_partial.
         let session = {
             let mut sessions = self.sessions.write();
+            let existing = idle_session(&mut sessions, session_id)?;
+            if existing.get_conclusions().is_empty() {
+                return Err(FastThinkError::NoConclusion);
+            }
             sessions
                 .remove(session_id)
                 .ok_or(FastThinkError::SessionNotFound)?
         };
+        // A session from `continue_incomplete` supersedes its source unless
+        // the caller named another target.
+        let supersede_memory_id = supersede_memory_id
+            .map(str::to_string)
+            .or_else(|| session.continues_memory_id.clone());
 
-        let conclusions = session.get_conclusions();
-        if conclusions.is_empty() {
-            return Err(FastThinkError::NoConclusion);
-        }
-
+        let (entities, concepts) = session_structure(&session);
         let conclusion_content = session.build_conclusion_content();
         // Evidence = recalls the conclusion rests on; fall back to all recalls
         // only when the session graph is too flat to tell (old behaviour).
@@ -309,19 +997,57 @@ impl FastThinkManager {
         // A wall-of-text conclusion still earns full extraction: atomizing it
         // is worth the wait.
         let fast = conclusion_content.len() <= ft.commit_extract_over_chars;
+        let reconcile = reconcile_counts.unwrap_or(ft.commit_reconcile_counts);
         let (certainty, importance, support_strength) = (
             ft.commit_certainty as i32,
             ft.commit_importance as i32,
             ft.commit_support_strength as i32,
         );
 
-        let result = if fast {
-            let atoms: Vec<crate::llm::extractor::ExtractedMemory> = conclusions
-                .iter()
-                .map(|(_, t)| crate::llm::extractor::ExtractedMemory {
-                    text: t.content.clone(),
+        // Opt-in: one LLM call types the conclusion into the ontology so
+        // search_by_concept can find it. A failed call only costs the
+        // classification, never the commit.
+        let classification = if auto_classify.unwrap_or(ft.commit_auto_classify) {
+            session
+                .runtime
+                .main_memory
+                .tooling()
+                .classify_memory_type(&conclusion_content)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("commit: auto-classify failed (non-fatal): {e}");
+                    None
+                })
+        } else {
+            None
+        };
+
+        if let Some(target) = supersede_memory_id.as_deref() {
+            let draft = CommitDraft {
+                conclusion_content,
+                supporting_ids,
+                classification,
+                entities,
+                concepts,
+                reconcile,
+            };
+            let committed = self
+                .commit_superseding(&session, draft, target, user_id)
+                .await;
+            if committed.is_err() {
+                restore_uncommitted(&self.sessions, session);
+            }
+            return committed;
+        }
+
+        let written = if fast {
+            let atoms: Vec<crate::llm::extractor::ExtractedMemory> = session
+                .conclusion_atoms()
+                .into_iter()
+                .map(|text| crate::llm::extractor::ExtractedMemory {
+                    text,
                     // A committed conclusion is decided-but-derived knowledge.
-                    memory_type: "fact".to_string(),
+                    memory_type: classification.map_or("fact", |(t, _)| t).to_string(),
                     certainty,
                     importance,
                     entities: vec![],
@@ -331,16 +1057,27 @@ impl FastThinkManager {
             session
                 .runtime
                 .main_memory
-                .add_prepared(atoms, user_id, None, None)
+                .add_prepared_unannounced(atoms, user_id, None, None)
                 .await
         } else {
             session
                 .runtime
                 .main_memory
-                .add(&conclusion_content, user_id, None, None)
+                .add_unannounced(&conclusion_content, user_id, None, None, None)
                 .await
-        }
-        .map_err(|e| FastThinkError::CommitFailed(e.to_string()))?;
+        };
+        let result = match written {
+            Ok(result) => result,
+            Err(e) => {
+                restore_uncommitted(&self.sessions, session);
+                return Err(FastThinkError::CommitFailed(e.to_string()));
+            }
+        };
+        session.runtime.main_memory.notify_webhook(
+            WebhookEvent::Commit,
+            &result.memory_ids,
+            user_id,
+        );
 
         // Recalled evidence becomes SUPPORTS provenance edges (LLM-free) —
         // not "[Evidence: ...]" text glued into the content.
@@ -359,12 +1096,13 @@ impl FastThinkManager {
                     .main_memory
                     .tooling()
                     .reasoning_engine
-                    .add_relation(
+                    .add_relation_by(
                         sid,
                         mid,
                         crate::toolkit::mind_toolbox::reasoning::ReasoningType::Supports,
                         support_strength,
                         None,
+                        THINK_COMMIT_AUTHOR,
                     )
                     .await
                 {
@@ -375,8 +1113,16 @@ impl FastThinkManager {
 
         // The fast path skipped extraction, so entity discovery moves OFF the
         // critical path: one background extraction call links entities to the
-        // stored conclusion after the agent already has its ack.
-        if fast && !committed_ids.is_empty() {
+        // stored conclusion after the agent already has its ack — unless the
+        // caller wants counts that match the graph, which means waiting.
+        if fast && reconcile && !committed_ids.is_empty() {
+            session
+                .runtime
+                .main_memory
+                .tooling()
+                .extract_and_link_entities(&conclusion_content, user_id, &committed_ids)
+                .await;
+        } else if fast && !committed_ids.is_empty() {
             let client = Arc::clone(&session.runtime.main_memory);
             let text = conclusion_content.clone();
             let uid = user_id.to_string();
@@ -389,31 +1135,416 @@ impl FastThinkManager {
             });
         }
 
+        let type_links =
+            link_classification(&session.runtime.main_memory, &committed_ids, classification).await;
+
         let pipeline_entities = result.entities_extracted;
         let pipeline_relations = result.relations_created + supporting_ids.len();
+        let (entities_extracted, concepts_mapped) = if reconcile {
+            persisted_counts(&session.runtime.main_memory, &committed_ids).await
+        } else {
+            (
+                pipeline_entities + session.entity_count(),
+                pipeline_relations + session.concept_count() + type_links,
+            )
+        };
 
         info!(
             session_id = session_id,
             memory_id = ?committed_ids.first(),
             fast_path = fast,
             thoughts_processed = session.thought_count(),
-            entities_extracted = pipeline_entities + session.entity_count(),
+            entities_extracted,
             relations_created = pipeline_relations,
+            counts_reconciled = reconcile,
             elapsed_ms = session.elapsed().as_millis(),
             "Committed thinking session to main memory"
         );
 
-        Ok(CommitResult {
+        let committed = CommitResult {
             memory_id: committed_ids.first().cloned().unwrap_or_default(),
             thoughts_processed: session.thought_count(),
-            entities_extracted: pipeline_entities + session.entity_count(),
-            concepts_mapped: pipeline_relations + session.concept_count(),
+            entities_extracted,
+            concepts_mapped,
+            counts_reconciled: reconcile,
             elapsed: session.elapsed(),
-        })
-    }
-
-    pub fn discard(&self, session_id: &str) -> Result<DiscardResult, FastThinkError> {
+            superseded: false,
+            retained: retain,
+            classified_as: classification.map(|(t, _)| t.to_string()),
+            entities,
+            concepts,
+        };
+        if retain {
+            let mut retained = self.retained.write();
+            prune_retained(&mut retained, None);
+            retained.insert(
+                session_id.to_string(),
+                RetainedCommit {
+                    session,
+                    created_ids: result.memory_ids,
+                    user_id: user_id.to_string(),
+                    committed_at: std::time::Instant::now(),
+                },
+            );
+        }
+        Ok(committed)
+    }
+
+    /// Commit the session as a reasoning chain instead of one prose memory:
+    /// each thought [`ThinkingSession::chain_plan`] keeps is stored as its
+    /// own memory (a recall reuses the memory it came from) and each typed
+    /// edge between them becomes the matching reasoning relation, so
+    /// `search_reasoning_chain` can walk the session's logic later. Edges
+    /// between two recalled memories are left alone. The memories are all
+    /// or nothing: a failed write deletes the ones already stored and
+    /// reopens the session. A failed relation is logged and counted.
+    ///
+    /// A session continuing an [INCOMPLETE] memory is refused: that memory
+    /// is rewritten in place by [`Self::commit`] instead.
+    pub async fn commit_chain(
+        &self,
+        session_id: &str,
+        user_id: &str,
+    ) -> Result<ChainCommitResult, FastThinkError> {
+        let session = {
+            let mut sessions = self.sessions.write();
+            let existing = idle_session(&mut sessions, session_id)?;
+            if existing.get_conclusions().is_empty() {
+                return Err(FastThinkError::NoConclusion);
+            }
+            if let Some(target) = &existing.continues_memory_id {
+                return Err(FastThinkError::InvalidState(format!(
+                    "this session continues {target}: commit it without as_chain to update that memory"
+                )));
+            }
+            sessions
+                .remove(session_id)
+                .ok_or(FastThinkError::SessionNotFound)?
+        };
+
+        let client = Arc::clone(&session.runtime.main_memory);
+        let importance = client.tooling().config.fast_think.commit_importance as i32;
+        let (thoughts, links) = session.chain_plan();
+        let mut memory_of: HashMap<NodeIndex, String> = HashMap::new();
+        let mut created = Vec::new();
+        let mut reused = Vec::new();
+        for thought in &thoughts {
+            if let Some(id) = &thought.source_memory_id {
+                memory_of.insert(thought.idx, id.clone());
+                if !reused.contains(id) {
+                    reused.push(id.clone());
+                }
+                continue;
+            }
+            let atom = crate::llm::extractor::ExtractedMemory {
+                text: thought.text.clone(),
+                memory_type: thought.memory_type.to_string(),
+                certainty: (thought.certainty * 100.0).round() as i32,
+                importance,
+                entities: vec![],
+                context: None,
+            };
+            let stored = match client
+                .add_prepared_unannounced(vec![atom], user_id, None, None)
+                .await
+            {
+                Ok(r) if !r.memory_ids.is_empty() => {
+                    created.push(r.memory_ids[0].clone());
+                    Ok(r.memory_ids[0].clone())
+                }
+                // Deduped onto a memory already stored: link that one.
+                Ok(r) => r
+                    .deduped
+                    .first()
+                    .cloned()
+                    .ok_or_else(|| "the write stored nothing".to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            match stored {
+                Ok(id) => {
+                    memory_of.insert(thought.idx, id);
+                }
+                Err(e) => {
+                    for id in &created {
+                        if let Err(e) = client.delete(id).await {
+                            warn!("commit_chain: rolling back {id} failed: {e}");
+                        }
+                    }
+                    restore_uncommitted(&self.sessions, session);
+                    return Err(FastThinkError::CommitFailed(e));
+                }
+            }
+        }
+
+        let recalled: HashSet<NodeIndex> = thoughts
+            .iter()
+            .filter(|t| t.source_memory_id.is_some())
+            .map(|t| t.idx)
+            .collect();
+        let mut relations = Vec::new();
+        let mut relations_failed = 0;
+        for link in &links {
+            if recalled.contains(&link.from) && recalled.contains(&link.to) {
+                continue;
+            }
+            let (Some(from), Some(to)) = (memory_of.get(&link.from), memory_of.get(&link.to))
+            else {
+                continue;
+            };
+            if from == to {
+                continue;
+            }
+            match client
+                .tooling()
+                .reasoning_engine
+                .add_relation(
+                    from,
+                    to,
+                    link.relation,
+                    (link.weight * 100.0).round() as i32,
+                    None,
+                )
+                .await
+            {
+                Ok(_) => relations.push(ChainRelation {
+                    from_memory_id: from.clone(),
+                    to_memory_id: to.clone(),
+                    relation: link.relation.edge_name().to_string(),
+                }),
+                Err(e) => {
+                    relations_failed += 1;
+                    warn!(
+                        "commit_chain: {} {from} -> {to} failed: {e}",
+                        link.relation.edge_name()
+                    );
+                }
+            }
+        }
+
+        let conclusion_memory_ids: Vec<String> = session
+            .get_conclusions()
+            .into_iter()
+            .filter_map(|(idx, _)| memory_of.get(&idx).cloned())
+            .collect();
+        info!(
+            session_id = session_id,
+            memories_created = created.len(),
+            memories_reused = reused.len(),
+            relations = relations.len(),
+            relations_failed,
+            "Committed thinking session as a reasoning chain"
+        );
+        client.notify_webhook(WebhookEvent::Commit, &created, user_id);
+        Ok(ChainCommitResult {
+            conclusion_memory_ids,
+            memory_ids: created,
+            reused_memory_ids: reused,
+            relations,
+            relations_failed,
+            thoughts_processed: session.thought_count(),
+            elapsed: session.elapsed(),
+        })
+    }
+
+    /// Take back a retained commit (`commit(retain)`) within the session TTL:
+    /// delete the memories it created and put the session back, `Decided`
+    /// with a fresh thinking clock, so it can be reworked and committed
+    /// again. Refused without `confirm`, and once the window has passed.
+    /// Each deleted memory is announced with a `delete` webhook.
+    /// A failed delete keeps the commit retained with the memories still to
+    /// go, so the undo can be retried.
+    pub async fn uncommit(
+        &self,
+        session_id: &str,
+        confirm: bool,
+    ) -> Result<UncommitResult, FastThinkError> {
+        let mut retained = {
+            let mut map = self.retained.write();
+            prune_retained(&mut map, Some(session_id));
+            let Some(entry) = map.get(session_id) else {
+                return Err(FastThinkError::NotRetained);
+            };
+            if entry.expired() {
+                map.remove(session_id);
+                return Err(FastThinkError::UndoWindowPassed);
+            }
+            if !confirm {
+                return Err(FastThinkError::ConfirmRequired {
+                    memories: entry.created_ids.len(),
+                });
+            }
+            if self.sessions.read().contains_key(session_id) {
+                return Err(FastThinkError::SessionAlreadyExists);
+            }
+            map.remove(session_id)
+                .ok_or(FastThinkError::SessionNotFound)?
+        };
+
+        let client = Arc::clone(&retained.session.runtime.main_memory);
+        let mut deleted = Vec::new();
+        while let Some(id) = retained.created_ids.first().cloned() {
+            if let Err(e) = client.delete(&id).await {
+                let message = format!("uncommit: deleting {id} failed: {e}");
+                self.retained
+                    .write()
+                    .entry(session_id.to_string())
+                    .or_insert(retained);
+                return Err(FastThinkError::CommitFailed(message));
+            }
+            client.notify_webhook(
+                WebhookEvent::Delete,
+                std::slice::from_ref(&id),
+                &retained.user_id,
+            );
+            retained.created_ids.remove(0);
+            deleted.push(id);
+        }
+
+        let mut session = retained.session;
+        session.status = SessionStatus::Decided;
+        session.started_at = std::time::Instant::now();
+        session.last_activity = std::time::Instant::now();
+        let thoughts = session.thought_count();
+        self.sessions
+            .write()
+            .entry(session_id.to_string())
+            .or_insert(session);
+        info!(
+            session_id = session_id,
+            deleted = deleted.len(),
+            "Uncommitted thinking session"
+        );
+        Ok(UncommitResult {
+            deleted_memory_ids: deleted,
+            thoughts_restored: thoughts,
+        })
+    }
+
+    /// The `supersede_memory_id` branch of [`Self::commit`]: rewrite the
+    /// target in place, untag it, then wire the same SUPPORTS evidence and
+    /// background entity linking the add path gets.
+    async fn commit_superseding(
+        &self,
+        session: &ManagedSession,
+        draft: CommitDraft,
+        target: &str,
+        user_id: &str,
+    ) -> Result<CommitResult, FastThinkError> {
+        let CommitDraft {
+            conclusion_content,
+            supporting_ids,
+            classification,
+            entities,
+            concepts,
+            reconcile,
+        } = draft;
+        let conclusion_content = conclusion_content.as_str();
+        let client = Arc::clone(&session.runtime.main_memory);
+        // Only a memory the committing user holds may be rewritten.
+        if client
+            .get_memory(target, user_id)
+            .await
+            .map_err(|e| FastThinkError::CommitFailed(e.to_string()))?
+            .is_none()
+        {
+            return Err(FastThinkError::MemoryNotFound(target.to_string()));
+        }
+        client
+            .update(target, conclusion_content, user_id)
+            .await
+            .map_err(|e| FastThinkError::CommitFailed(e.to_string()))?;
+        if let Err(e) = client
+            .remove_context_tag(target, INCOMPLETE_THOUGHT_TAG)
+            .await
+        {
+            warn!("commit: could not untag superseded {target} (non-fatal): {e}");
+        }
+
+        let support_strength = client.tooling().config.fast_think.commit_support_strength as i32;
+        for sid in supporting_ids.iter().filter(|sid| sid.as_str() != target) {
+            if let Err(e) = client
+                .tooling()
+                .reasoning_engine
+                .add_relation_by(
+                    sid,
+                    target,
+                    crate::toolkit::mind_toolbox::reasoning::ReasoningType::Supports,
+                    support_strength,
+                    None,
+                    THINK_COMMIT_AUTHOR,
+                )
+                .await
+            {
+                debug!("commit: evidence SUPPORTS {sid} -> {target} failed (non-fatal): {e}");
+            }
+        }
+
+        let ids = vec![target.to_string()];
+        let type_links = link_classification(&client, &ids, classification).await;
+
+        let counts = if reconcile {
+            client
+                .tooling()
+                .extract_and_link_entities(conclusion_content, user_id, &ids)
+                .await;
+            persisted_counts(&client, &ids).await
+        } else {
+            let counts = (
+                session.entity_count(),
+                supporting_ids.len() + session.concept_count() + type_links,
+            );
+            let text = conclusion_content.to_string();
+            let uid = user_id.to_string();
+            tokio::spawn(async move {
+                client
+                    .tooling()
+                    .extract_and_link_entities(&text, &uid, &ids)
+                    .await;
+            });
+            counts
+        };
+
+        info!(
+            session_id = %session.id,
+            memory_id = target,
+            thoughts_processed = session.thought_count(),
+            elapsed_ms = session.elapsed().as_millis(),
+            "Committed thinking session as an update of an existing memory"
+        );
+        session.runtime.main_memory.notify_webhook(
+            WebhookEvent::Commit,
+            &[target.to_string()],
+            user_id,
+        );
+
+        Ok(CommitResult {
+            memory_id: target.to_string(),
+            thoughts_processed: session.thought_count(),
+            entities_extracted: counts.0,
+            concepts_mapped: counts.1,
+            counts_reconciled: reconcile,
+            elapsed: session.elapsed(),
+            superseded: true,
+            retained: false,
+            classified_as: classification.map(|(t, _)| t.to_string()),
+            entities,
+            concepts,
+        })
+    }
+
+    /// Drop the session without persisting it. A session holding a
+    /// conclusion is refused with `UnsavedConclusion` unless `force` (or the
+    /// session's limits turn the check off).
+    pub fn discard(&self, session_id: &str, force: bool) -> Result<DiscardResult, FastThinkError> {
         let mut sessions = self.sessions.write();
+        let existing = idle_session(&mut sessions, session_id)?;
+        if !force && existing.runtime.limits.confirm_discard_concluded {
+            if let Some((_, conclusion)) = existing.get_conclusions().first() {
+                return Err(FastThinkError::UnsavedConclusion(
+                    crate::utils::safe_truncate_ellipsis(&conclusion.content, 200),
+                ));
+            }
+        }
         let session = sessions
             .remove(session_id)
             .ok_or(FastThinkError::SessionNotFound)?;
@@ -431,49 +1562,104 @@ impl FastThinkManager {
         })
     }
 
+    /// Drop every session last bound to `user_id` (via recall), uncommitted.
+    /// Used by `purge_user`; sessions that never learned an owner are kept.
+    pub fn discard_user_sessions(&self, user_id: &str) -> usize {
+        let mut sessions = self.sessions.write();
+        let before = sessions.len();
+        sessions.retain(|_, s| s.owner_hint.as_deref() != Some(user_id));
+        let discarded = before - sessions.len();
+        if discarded > 0 {
+            info!(
+                user_id = user_id,
+                discarded, "Discarded thinking sessions for purged user"
+            );
+        }
+        discarded
+    }
+
     pub async fn commit_partial(
         &self,
         session_id: &str,
         user_id: &str,
         reason: &str,
+    ) -> Result<CommitResult, FastThinkError> {
+        self.persist_partial(session_id, user_id, reason, false)
+            .await
+    }
+
+    /// `commit_partial` body. `allow_busy` lets the shutdown auto-save take
+    /// a session mid-recall: the process is going away, so the recall will
+    /// never resume and its thoughts are better saved than lost.
+    async fn persist_partial(
+        &self,
+        session_id: &str,
+        user_id: &str,
+        reason: &str,
+        allow_busy: bool,
     ) -> Result<CommitResult, FastThinkError> {
         let session = {
             let mut sessions = self.sessions.write();
+            let existing = if allow_busy {
+                sessions
+                    .get_mut(session_id)
+                    .ok_or(FastThinkError::SessionNotFound)?
+            } else {
+                idle_session(&mut sessions, session_id)?
+            };
+            if existing.thought_count() == 0 {
+                return Err(FastThinkError::NoConclusion);
+            }
             sessions
                 .remove(session_id)
                 .ok_or(FastThinkError::SessionNotFound)?
         };
 
+        let (entities, concepts) = session_structure(&session);
         let thoughts: Vec<String> = session
             .graph
             .node_indices()
             .filter_map(|idx| session.graph.node_weight(idx))
-            .map(|t| format!("- [{}] {}", t.thought_type, t.content))
+            .map(|t| {
+                format!(
+                    "- [{}] {}",
+                    t.thought_type,
+                    t.rendered().replace('\n', "\n  ")
+                )
+            })
             .collect();
 
-        if thoughts.is_empty() {
-            return Err(FastThinkError::NoConclusion);
-        }
-
         let partial_content = format!(
-            "FastThink session interrupted ({})\n\nThoughts:\n{}\n\n[Action: Continue research with think_start]",
+            "FastThink session interrupted ({})\n\nThoughts:\n{}\n\n[Action: Continue research with continue_incomplete]",
             reason,
             thoughts.join("\n")
         );
 
-        // Use add_with_tags to mark as incomplete_thought - tag is inherited by all extracted facts
-        let result = session
+        // Tag as incomplete_thought - the tag is inherited by all extracted facts
+        let written = session
             .runtime
             .main_memory
-            .add_with_tags(
+            .add_unannounced(
                 &partial_content,
                 user_id,
                 None,
                 None,
-                Some("incomplete_thought"),
+                Some(INCOMPLETE_THOUGHT_TAG),
             )
-            .await
-            .map_err(|e| FastThinkError::CommitFailed(e.to_string()))?;
+            .await;
+        let result = match written {
+            Ok(result) => result,
+            Err(e) => {
+                restore_uncommitted(&self.sessions, session);
+                return Err(FastThinkError::CommitFailed(e.to_string()));
+            }
+        };
+
+        session.runtime.main_memory.notify_webhook(
+            WebhookEvent::Commit,
+            &result.memory_ids,
+            user_id,
+        );
 
         let pipeline_entities = result.entities_extracted;
         let pipeline_relations = result.relations_created;
@@ -492,7 +1678,13 @@ impl FastThinkManager {
             thoughts_processed: session.thought_count(),
             entities_extracted: pipeline_entities + session.entity_count(),
             concepts_mapped: pipeline_relations + session.concept_count(),
+            counts_reconciled: false,
             elapsed: session.elapsed(),
+            superseded: false,
+            retained: false,
+            classified_as: None,
+            entities,
+            concepts,
         })
     }
 
@@ -504,9 +1696,7 @@ impl FastThinkManager {
         entity_type: ScratchEntityType,
     ) -> Result<String, FastThinkError> {
         let mut sessions = self.sessions.write();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or(FastThinkError::SessionNotFound)?;
+        let session = idle_session(&mut sessions, session_id)?;
 
         let runtime = Arc::clone(&session.runtime);
         session.extract_entity(thought_idx, name, entity_type, &runtime.limits)
@@ -520,9 +1710,7 @@ impl FastThinkManager {
         parent_concept: Option<&str>,
     ) -> Result<String, FastThinkError> {
         let mut sessions = self.sessions.write();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or(FastThinkError::SessionNotFound)?;
+        let session = idle_session(&mut sessions, session_id)?;
 
         let runtime = Arc::clone(&session.runtime);
         session.map_to_concept(thought_idx, concept_name, parent_concept, &runtime.limits)
@@ -533,14 +1721,12 @@ impl FastThinkManager {
         session_id: &str,
         from: NodeIndex,
         to: NodeIndex,
-        edge_type: ThoughtEdge,
+        edge: WeightedEdge,
     ) -> Result<(), FastThinkError> {
         let mut sessions = self.sessions.write();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or(FastThinkError::SessionNotFound)?;
+        let session = idle_session(&mut sessions, session_id)?;
 
-        session.link_thoughts(from, to, edge_type)?;
+        session.link_thoughts(from, to, edge)?;
         Ok(())
     }
 
@@ -559,90 +1745,629 @@ impl FastThinkManager {
         Ok(session.runtime.limits.max_thoughts)
     }
 
-    pub fn get_session_status(&self, session_id: &str) -> Result<SessionInfo, FastThinkError> {
+    /// The whole session graph (see [`SessionSnapshot`]).
+    pub fn snapshot(&self, session_id: &str) -> Result<SessionSnapshot, FastThinkError> {
         let sessions = self.sessions.read();
         let session = sessions
             .get(session_id)
             .ok_or(FastThinkError::SessionNotFound)?;
+        Ok(session.snapshot())
+    }
 
-        Ok(SessionInfo {
-            id: session.id.clone(),
-            status: session.status.clone(),
-            thought_count: session.thought_count(),
-            entity_count: session.entity_count(),
-            concept_count: session.concept_count(),
-            current_depth: session.current_depth,
-            elapsed: session.elapsed(),
-            has_conclusion: !session.get_conclusions().is_empty(),
-        })
+    /// The retained commit (`commit(retain)`) that created `memory_id`, if
+    /// its undo window is still open: its session id and the session graph
+    /// as it stood when committed.
+    pub fn retained_trace(&self, memory_id: &str) -> Option<(String, SessionSnapshot)> {
+        let mut retained = self.retained.write();
+        prune_retained(&mut retained, None);
+        retained
+            .iter()
+            .find(|(_, r)| r.created_ids.iter().any(|id| id == memory_id))
+            .map(|(id, r)| (id.clone(), r.session.snapshot()))
     }
 
-    pub fn get_thought_chain(
+    /// Every live session of `namespace` (all of them without one), for
+    /// handing them to another process (see [`ManagerSnapshot`]). Sessions
+    /// carry the ids their namespace knows them by. A session mid-recall is
+    /// taken as it stands; the recall's results land only in this process.
+    pub fn snapshot_all(&self, namespace: Option<&str>) -> ManagerSnapshot {
+        let now = chrono::Utc::now();
+        let sessions = self.sessions.read();
+        let mut entries: Vec<ManagedSessionSnapshot> = sessions
+            .iter()
+            .filter_map(|(key, s)| {
+                let id = caller_id(namespace, key)?;
+                let mut entry = ManagedSessionSnapshot::of(s, now);
+                entry.session.session_id = id.to_string();
+                Some(entry)
+            })
+            .collect();
+        entries.sort_by_key(|e| e.started_at);
+        ManagerSnapshot {
+            taken_at: now,
+            sessions: entries,
+        }
+    }
+
+    /// Install every session of `snapshot` into `namespace` (see
+    /// [`restore_with`](Self::restore_with)) under the current runtime's
+    /// limits. Refused while any session of the namespace is live unless
+    /// `force`, which replaces exactly those; other namespaces are never
+    /// touched. All sessions are rebuilt before any is installed, so a bad
+    /// entry leaves the manager untouched. Returns how many sessions were
+    /// restored.
+    pub fn restore_all(
+        &self,
+        snapshot: ManagerSnapshot,
+        force: bool,
+        namespace: Option<&str>,
+    ) -> Result<usize, FastThinkError> {
+        let restored = self.rebuild_snapshot(&snapshot, namespace)?;
+
+        let mut sessions = self.sessions.write();
+        let live = sessions
+            .keys()
+            .filter(|key| caller_id(namespace, key).is_some())
+            .count();
+        if live > 0 && !force {
+            return Err(FastThinkError::InvalidState(format!(
+                "{live} session(s) already live; pass force=true to replace them"
+            )));
+        }
+        let count = restored.len();
+        info!(
+            restored = count,
+            replaced = live,
+            taken_at = %snapshot.taken_at,
+            "Restored FastThink sessions from snapshot"
+        );
+        sessions.retain(|key, _| caller_id(namespace, key).is_none());
+        sessions.extend(restored.into_iter().map(|s| (s.id.clone(), s)));
+        Ok(count)
+    }
+
+    /// Install the sessions of `snapshot` next to the live ones, resolving
+    /// each id that is already live by `on_conflict` (see [`OnConflict`]).
+    /// With a `namespace` the snapshot's ids are the caller's own, installed
+    /// as `<namespace>/<id>`, so a restore only ever meets — and merges into
+    /// or overwrites — sessions of that namespace. As with
+    /// [`restore_all`](Self::restore_all), every session is rebuilt — and
+    /// every `merge` checked against its target's recall and limits —
+    /// before any is installed, so an error leaves the manager untouched.
+    /// The report names sessions by their snapshot ids.
+    pub fn restore_with(
+        &self,
+        snapshot: ManagerSnapshot,
+        on_conflict: OnConflict,
+        namespace: Option<&str>,
+    ) -> Result<RestoreReport, FastThinkError> {
+        let restored = self.rebuild_snapshot(&snapshot, namespace)?;
+        let mut sessions = self.sessions.write();
+        if on_conflict == OnConflict::Merge {
+            for incoming in &restored {
+                let Some(live) = sessions.get(&incoming.id) else {
+                    continue;
+                };
+                let id = caller_id(namespace, &incoming.id).unwrap_or(&incoming.id);
+                if live.recalling {
+                    return Err(FastThinkError::InvalidState(format!(
+                        "session '{id}' is busy with a recall; retry the restore once it returns"
+                    )));
+                }
+                live.state
+                    .check_merge(&incoming.state, &live.runtime.limits)
+                    .map_err(|e| {
+                        FastThinkError::InvalidState(format!("merging session '{id}': {e}"))
+                    })?;
+            }
+        }
+
+        let mut report = RestoreReport::default();
+        for mut incoming in restored {
+            let key = incoming.id.clone();
+            let id = caller_id(namespace, &key).unwrap_or(&key).to_string();
+            let Some(live) = sessions.get_mut(&key) else {
+                sessions.insert(key, incoming);
+                report.created += 1;
+                continue;
+            };
+            match on_conflict {
+                OnConflict::Skip => report.skipped += 1,
+                OnConflict::Overwrite => {
+                    *live = incoming;
+                    report.overwritten += 1;
+                }
+                OnConflict::Merge => {
+                    let limits = live.runtime.limits.clone();
+                    live.state.merge_from(&incoming.state, &limits)?;
+                    report.merged += 1;
+                }
+                OnConflict::Duplicate => {
+                    let mut copy = format!("{key}-copy");
+                    while sessions.contains_key(&copy) {
+                        copy = format!(
+                            "{key}-copy-{}",
+                            &uuid::Uuid::new_v4().simple().to_string()[..8]
+                        );
+                    }
+                    incoming.state.id = copy.clone();
+                    report
+                        .duplicated
+                        .insert(id, caller_id(namespace, &copy).unwrap_or(&copy).to_string());
+                    sessions.insert(copy, incoming);
+                }
+            }
+        }
+        info!(
+            on_conflict = %on_conflict,
+            created = report.created,
+            skipped = report.skipped,
+            overwritten = report.overwritten,
+            merged = report.merged,
+            duplicated = report.duplicated.len(),
+            taken_at = %snapshot.taken_at,
+            "Restored FastThink sessions from snapshot"
+        );
+        Ok(report)
+    }
+
+    /// Every session of `snapshot`, rebuilt under the current runtime's
+    /// limits and keyed into `namespace`. A bad entry or a repeated session
+    /// id is `InvalidState`.
+    fn rebuild_snapshot(
+        &self,
+        snapshot: &ManagerSnapshot,
+        namespace: Option<&str>,
+    ) -> Result<Vec<ManagedSession>, FastThinkError> {
+        let runtime = self.current.load_full();
+        let mut seen = HashSet::with_capacity(snapshot.sessions.len());
+        let mut restored = Vec::with_capacity(snapshot.sessions.len());
+        for entry in &snapshot.sessions {
+            let mut state = entry.restore(runtime.limits.entity_identity)?;
+            state.id = session_key(namespace, &state.id);
+            if !seen.insert(state.id.clone()) {
+                return Err(FastThinkError::InvalidState(format!(
+                    "snapshot: duplicate session_id '{}'",
+                    state.id
+                )));
+            }
+            restored.push(ManagedSession {
+                state,
+                runtime: Arc::clone(&runtime),
+                recalling: false,
+                embeddings: HashMap::new(),
+            });
+        }
+        Ok(restored)
+    }
+
+    /// What `nodes` hold, content cut to `max_chars` (the think_recall
+    /// response). Indices that no longer exist are skipped.
+    pub fn recall_previews(
         &self,
         session_id: &str,
-        thought_idx: NodeIndex,
-    ) -> Result<Vec<ThoughtInfo>, FastThinkError> {
+        nodes: &[NodeIndex],
+        max_chars: usize,
+    ) -> Result<Vec<RecallPreview>, FastThinkError> {
         let sessions = self.sessions.read();
         let session = sessions
             .get(session_id)
             .ok_or(FastThinkError::SessionNotFound)?;
-
-        let chain = session.get_chain_to_root(thought_idx);
-
-        Ok(chain
+        Ok(nodes
             .iter()
             .filter_map(|&idx| {
-                session.get_thought(idx).map(|t| ThoughtInfo {
+                let t = session.get_thought(idx)?;
+                Some(RecallPreview {
+                    idx: idx.index(),
                     id: t.id.clone(),
-                    content: t.content.clone(),
-                    thought_type: t.thought_type.clone(),
+                    content_preview: crate::utils::safe_truncate_ellipsis(&t.content, max_chars),
+                    source_memory_id: t.source_memory_id.clone(),
                     certainty: t.certainty,
-                    depth: t.depth,
                 })
             })
             .collect())
     }
 
-    pub fn cleanup_stale(&self) -> usize {
-        let mut sessions = self.sessions.write();
-        let before = sessions.len();
+    /// The node of thought `thought_id` in `session_id`.
+    pub fn resolve_thought_id(
+        &self,
+        session_id: &str,
+        thought_id: &str,
+    ) -> Result<NodeIndex, FastThinkError> {
+        let sessions = self.sessions.read();
+        let session = sessions
+            .get(session_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+        session
+            .resolve_thought_id(thought_id)
+            .ok_or(FastThinkError::ThoughtNotFound)
+    }
 
-        sessions.retain(|id, session| {
-            let keep = session.last_activity.elapsed() < session.runtime.limits.session_ttl;
-            if !keep {
-                info!(session_id = id, "Cleaned up stale session");
-            }
-            keep
-        });
+    /// Stable ids of `nodes`, in order; a node that no longer exists maps to
+    /// an empty string.
+    pub fn thought_ids(
+        &self,
+        session_id: &str,
+        nodes: &[NodeIndex],
+    ) -> Result<Vec<String>, FastThinkError> {
+        let sessions = self.sessions.read();
+        let session = sessions
+            .get(session_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+        Ok(nodes
+            .iter()
+            .map(|&idx| session.thought_id(idx).unwrap_or_default().to_string())
+            .collect())
+    }
 
-        before - sessions.len()
+    /// Calibrated confidence in one of the session's conclusions (see
+    /// [`ThinkingSession::conclusion_confidence`]).
+    pub fn conclusion_confidence(
+        &self,
+        session_id: &str,
+        conclusion: NodeIndex,
+    ) -> Result<ConclusionConfidence, FastThinkError> {
+        let sessions = self.sessions.read();
+        let session = sessions
+            .get(session_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+        session.conclusion_confidence(conclusion)
     }
 
-    pub fn active_session_count(&self) -> usize {
-        self.sessions.read().len()
+    pub fn get_session_status(&self, session_id: &str) -> Result<SessionInfo, FastThinkError> {
+        let sessions = self.sessions.read();
+        let session = sessions
+            .get(session_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+
+        Ok(SessionInfo::of(session))
     }
 
-    pub fn list_sessions(&self) -> Vec<String> {
-        self.sessions.read().keys().cloned().collect()
+    /// Move a thought's certainty by `delta` (see
+    /// [`ThinkingSession::adjust_certainty`]); returns it before and after.
+    pub fn adjust_certainty(
+        &self,
+        session_id: &str,
+        thought_idx: NodeIndex,
+        delta: f32,
+    ) -> Result<(f32, f32), FastThinkError> {
+        let mut sessions = self.sessions.write();
+        let session = idle_session(&mut sessions, session_id)?;
+        let runtime = Arc::clone(&session.runtime);
+        session.adjust_certainty(thought_idx, delta, &runtime.limits)
     }
 
-    /// Shutdown auto-save: persist every still-active session as an
-    /// [INCOMPLETE] memory (via `commit_partial`) so reasoning survives the
-    /// process — one-shot MCP clients kill the server long before the
-    /// session-TTL sweeper would fire. Sessions with no recall never learned
-    /// an owner; they save under the `helixir` system user, and
-    /// `search_incomplete_thoughts` finds them regardless (tag search is
-    /// user-agnostic). Returns how many sessions were saved.
-    pub async fn save_all_interrupted(&self, reason: &str) -> usize {
-        let ids = self.list_sessions();
-        let mut saved = 0usize;
+    /// Append a session annotation (see [`ThinkingSession::annotations`]).
+    pub fn annotate(&self, session_id: &str, note: &str) -> Result<usize, FastThinkError> {
+        let mut sessions = self.sessions.write();
+        let session = idle_session(&mut sessions, session_id)?;
+        Ok(session.annotate(note))
+    }
+
+    /// Set the type `think_add` falls back to for this session.
+    pub fn set_default_thought_type(
+        &self,
+        session_id: &str,
+        thought_type: ThoughtType,
+    ) -> Result<(), FastThinkError> {
+        let mut sessions = self.sessions.write();
+        let session = idle_session(&mut sessions, session_id)?;
+        session.default_thought_type = thought_type;
+        Ok(())
+    }
+
+    pub fn default_thought_type(&self, session_id: &str) -> Result<ThoughtType, FastThinkError> {
+        let sessions = self.sessions.read();
+        let session = sessions
+            .get(session_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+        Ok(session.default_thought_type.clone())
+    }
+
+    pub fn annotations(&self, session_id: &str) -> Result<Vec<String>, FastThinkError> {
+        let sessions = self.sessions.read();
+        let session = sessions
+            .get(session_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+        Ok(session.annotations.clone())
+    }
+
+    /// Turn annotation `note_idx` (its position in [`Self::annotations`])
+    /// into a thought of `thought_type` under `parent`, like
+    /// [`Self::add_thought`]. The note leaves the annotation list only once
+    /// the thought is in.
+    pub fn promote_note(
+        &self,
+        session_id: &str,
+        note_idx: usize,
+        thought_type: ThoughtType,
+        parent: Option<NodeIndex>,
+    ) -> Result<NodeIndex, FastThinkError> {
+        let mut sessions = self.sessions.write();
+        let session = idle_session(&mut sessions, session_id)?;
+        let note = note_at(session, note_idx)?;
+
+        let runtime = Arc::clone(&session.runtime);
+        let node = session.add_thought(&note, thought_type, parent, None, &runtime.limits)?;
+        session.annotations.remove(note_idx);
+
+        info!(
+            session_id = session_id,
+            thought_idx = node.index(),
+            "Promoted a session note to a thought"
+        );
+        Ok(node)
+    }
+
+    /// Store annotation `note_idx` straight in main memory as `user_id`'s,
+    /// through the full add pipeline. Returns the stored (or deduplicated
+    /// onto) memory ids; the note leaves the list once something is stored.
+    pub async fn promote_note_to_memory(
+        &self,
+        session_id: &str,
+        note_idx: usize,
+        user_id: &str,
+    ) -> Result<Vec<String>, FastThinkError> {
+        let (note, runtime) = {
+            let mut sessions = self.sessions.write();
+            let session = idle_session(&mut sessions, session_id)?;
+            (note_at(session, note_idx)?, Arc::clone(&session.runtime))
+        };
+
+        let result = runtime
+            .main_memory
+            .add(&note, user_id, None, None)
+            .await
+            .map_err(|e| FastThinkError::CommitFailed(e.to_string()))?;
+        let ids = if result.memory_ids.is_empty() {
+            result.deduped
+        } else {
+            result.memory_ids
+        };
+        if ids.is_empty() {
+            return Err(FastThinkError::CommitFailed(
+                "the add pipeline stored nothing for this note".to_string(),
+            ));
+        }
+
+        // Other notes may have landed meanwhile: drop this one by content.
+        if let Some(session) = self.sessions.write().get_mut(session_id) {
+            if let Some(pos) = session.annotations.iter().position(|n| *n == note) {
+                session.annotations.remove(pos);
+            }
+        }
+        info!(
+            session_id = session_id,
+            memory_id = ?ids.first(),
+            "Promoted a session note to main memory"
+        );
+        Ok(ids)
+    }
+
+    /// Thoughts of the session matching `query`, at most `limit`.
+    /// [`ThoughtSearchMode::Substring`] is a case-insensitive containment
+    /// test, in thought order. [`ThoughtSearchMode::Semantic`] embeds the
+    /// query and every thought (cached per session) and ranks by cosine
+    /// similarity, best first. Read-only: it runs mid-recall too.
+    pub async fn search_thoughts(
+        &self,
+        session_id: &str,
+        query: &str,
+        mode: ThoughtSearchMode,
+        limit: usize,
+    ) -> Result<Vec<ThoughtMatch>, FastThinkError> {
+        let (thoughts, runtime, cached) = {
+            let sessions = self.sessions.read();
+            let session = sessions
+                .get(session_id)
+                .ok_or(FastThinkError::SessionNotFound)?;
+            let thoughts: Vec<ThoughtMatch> = session
+                .graph
+                .node_indices()
+                .filter_map(|idx| {
+                    let t = session.get_thought(idx)?;
+                    Some(ThoughtMatch {
+                        idx: idx.index(),
+                        id: t.id.clone(),
+                        content: t.content.clone(),
+                        thought_type: t.thought_type.to_string(),
+                        score: None,
+                    })
+                })
+                .collect();
+            (
+                thoughts,
+                Arc::clone(&session.runtime),
+                session.embeddings.clone(),
+            )
+        };
+
+        if mode == ThoughtSearchMode::Substring {
+            let needle = query.to_lowercase();
+            return Ok(thoughts
+                .into_iter()
+                .filter(|t| t.content.to_lowercase().contains(&needle))
+                .take(limit)
+                .collect());
+        }
+
+        let mut missing: Vec<&str> = Vec::new();
+        for t in &thoughts {
+            if !cached.contains_key(&t.content) && !missing.contains(&t.content.as_str()) {
+                missing.push(&t.content);
+            }
+        }
+        let texts: Vec<&str> = std::iter::once(query)
+            .chain(missing.iter().copied())
+            .collect();
+        let vectors = runtime
+            .main_memory
+            .embedder()
+            .generate_batch(&texts, true)
+            .await
+            .map_err(|e| FastThinkError::RecallFailed(format!("think_search embedding: {e}")))?;
+        if vectors.len() != texts.len() {
+            return Err(FastThinkError::RecallFailed(
+                "think_search embedding: short batch".to_string(),
+            ));
+        }
+        let (query_vec, fresh) = vectors.split_first().expect("batch holds the query");
+        let fresh: HashMap<String, Vec<f32>> = missing
+            .iter()
+            .map(|s| s.to_string())
+            .zip(fresh.iter().cloned())
+            .collect();
+
+        let mut ranked: Vec<ThoughtMatch> = thoughts
+            .into_iter()
+            .filter_map(|mut t| {
+                let vector = cached.get(&t.content).or_else(|| fresh.get(&t.content))?;
+                t.score = Some(cosine_similarity(query_vec, vector) as f32);
+                Some(t)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.score.unwrap_or(0.0).total_cmp(&a.score.unwrap_or(0.0)));
+        ranked.truncate(limit);
+
+        if let Some(session) = self.sessions.write().get_mut(session_id) {
+            session.embeddings.extend(fresh);
+        }
+        Ok(ranked)
+    }
+
+    /// The session's scratch knowledge graph: entities by mention count
+    /// (most mentioned first, then by name) and concepts by name, each with
+    /// the thoughts it is tied to.
+    pub fn session_entities(
+        &self,
+        session_id: &str,
+    ) -> Result<(Vec<SessionEntity>, Vec<SessionConcept>), FastThinkError> {
+        let sessions = self.sessions.read();
+        let session = sessions
+            .get(session_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+        let refs = |nodes: &[NodeIndex]| -> (Vec<usize>, Vec<String>) {
+            let ids = nodes
+                .iter()
+                .filter_map(|n| session.thought_id(*n))
+                .map(str::to_string)
+                .collect();
+            (nodes.iter().map(|n| n.index()).collect(), ids)
+        };
+
+        let mut entities: Vec<SessionEntity> = session
+            .entities
+            .values()
+            .map(|e| {
+                let (thought_idx, thought_ids) = refs(&e.mentions);
+                SessionEntity {
+                    name: e.name.clone(),
+                    entity_type: e.entity_type.to_string(),
+                    mention_count: e.mention_count(),
+                    thought_idx,
+                    thought_ids,
+                }
+            })
+            .collect();
+        entities.sort_by(|a, b| {
+            b.mention_count
+                .cmp(&a.mention_count)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        let mut concepts: Vec<SessionConcept> = session
+            .concepts
+            .values()
+            .map(|c| {
+                let (thought_idx, thought_ids) = refs(&c.related_thoughts);
+                SessionConcept {
+                    name: c.name.clone(),
+                    parent: c.parent.clone(),
+                    thought_idx,
+                    thought_ids,
+                }
+            })
+            .collect();
+        concepts.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok((entities, concepts))
+    }
+
+    pub fn get_thought_chain(
+        &self,
+        session_id: &str,
+        thought_idx: NodeIndex,
+    ) -> Result<Vec<ThoughtInfo>, FastThinkError> {
+        let sessions = self.sessions.read();
+        let session = sessions
+            .get(session_id)
+            .ok_or(FastThinkError::SessionNotFound)?;
+
+        let chain = session.get_chain_to_root(thought_idx);
+
+        Ok(chain
+            .iter()
+            .filter_map(|&idx| {
+                session.get_thought(idx).map(|t| ThoughtInfo {
+                    id: t.id.clone(),
+                    content: t.content.clone(),
+                    thought_type: t.thought_type.clone(),
+                    certainty: t.certainty,
+                    depth: t.depth,
+                })
+            })
+            .collect())
+    }
+
+    pub fn cleanup_stale(&self) -> usize {
+        let mut sessions = self.sessions.write();
+        let before = sessions.len();
+
+        sessions.retain(|id, session| {
+            let keep = session.last_activity.elapsed() < session.runtime.limits.session_ttl;
+            if !keep {
+                info!(session_id = id, "Cleaned up stale session");
+            }
+            keep
+        });
+        prune_retained(&mut self.retained.write(), None);
+
+        before - sessions.len()
+    }
+
+    pub fn active_session_count(&self) -> usize {
+        self.sessions.read().len()
+    }
+
+    pub fn list_sessions(&self) -> Vec<String> {
+        self.sessions.read().keys().cloned().collect()
+    }
+
+    /// Status snapshot of every live session, oldest first — what a client
+    /// holding a stale session_id needs to find the one it meant.
+    pub fn list_session_info(&self) -> Vec<SessionInfo> {
+        let sessions = self.sessions.read();
+        let mut infos: Vec<SessionInfo> = sessions.values().map(SessionInfo::of).collect();
+        infos.sort_by_key(|s| std::cmp::Reverse(s.elapsed));
+        infos
+    }
+
+    /// Shutdown auto-save: persist every still-active session as an
+    /// [INCOMPLETE] memory (via `commit_partial`) so reasoning survives the
+    /// process — one-shot MCP clients kill the server long before the
+    /// session-TTL sweeper would fire. Sessions with no recall never learned
+    /// an owner; they save under the `helixir` system user, and
+    /// `search_incomplete_thoughts` finds them regardless (tag search is
+    /// user-agnostic). Returns how many sessions were saved.
+    pub async fn save_all_interrupted(&self, reason: &str) -> usize {
+        let ids = self.list_sessions();
+        let mut saved = 0usize;
         for id in ids {
             let owner = {
                 let sessions = self.sessions.read();
                 sessions.get(&id).and_then(|s| s.owner_hint.clone())
             }
             .unwrap_or_else(|| "helixir".to_string());
-            match self.commit_partial(&id, &owner, reason).await {
+            match self.persist_partial(&id, &owner, reason, true).await {
                 Ok(_) => {
                     info!(session_id = %id, owner = %owner, "Auto-saved interrupted FastThink session");
                     saved += 1;
@@ -662,7 +2387,226 @@ pub struct CommitResult {
     pub thoughts_processed: usize,
     pub entities_extracted: usize,
     pub concepts_mapped: usize,
+    /// True when the two counts above were read back from the stored graph
+    /// (`reconcile_counts`): distinct entities and INSTANCE_OF concepts
+    /// linked to the committed memory. False: pipeline plus scratch counts.
+    pub counts_reconciled: bool,
     pub elapsed: std::time::Duration,
+    /// True when the commit updated an existing memory (`supersede_memory_id`)
+    /// rather than creating one.
+    pub superseded: bool,
+    /// True when [`FastThinkManager::uncommit`] can take the commit back
+    /// (`retain`) until the session TTL passes.
+    pub retained: bool,
+    /// Ontology type `auto_classify` assigned the conclusion, if it ran.
+    pub classified_as: Option<String>,
+    /// The session's scratch entities, by name, as they stood when the
+    /// commit took the session (`think_commit(verbose)` lists them).
+    pub entities: Vec<CommittedEntity>,
+    /// The session's mapped concept names, sorted.
+    pub concepts: Vec<String>,
+}
+
+/// What [`FastThinkManager::commit_chain`] wrote.
+#[derive(Debug, Clone)]
+pub struct ChainCommitResult {
+    /// The conclusions' memories, in conclusion order.
+    pub conclusion_memory_ids: Vec<String>,
+    /// Every memory the commit created, in thought order.
+    pub memory_ids: Vec<String>,
+    /// Recalled memories the chain links into without rewriting them.
+    pub reused_memory_ids: Vec<String>,
+    pub relations: Vec<ChainRelation>,
+    pub relations_failed: usize,
+    pub thoughts_processed: usize,
+    pub elapsed: std::time::Duration,
+}
+
+/// One reasoning relation a chain commit wrote.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ChainRelation {
+    pub from_memory_id: String,
+    pub to_memory_id: String,
+    /// `IMPLIES`, `BECAUSE`, `SUPPORTS`, `CONTRADICTS` or `RELATES_TO`.
+    pub relation: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct CommittedEntity {
+    pub name: String,
+    pub entity_type: String,
+}
+
+/// A scratch entity as [`FastThinkManager::session_entities`] lists it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct SessionEntity {
+    pub name: String,
+    pub entity_type: String,
+    pub mention_count: usize,
+    /// The thoughts that mention it, in mention order.
+    pub thought_idx: Vec<usize>,
+    pub thought_ids: Vec<String>,
+}
+
+/// A scratch concept as [`FastThinkManager::session_entities`] lists it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct SessionConcept {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    pub thought_idx: Vec<usize>,
+    pub thought_ids: Vec<String>,
+}
+
+/// How [`FastThinkManager::search_thoughts`] matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThoughtSearchMode {
+    #[default]
+    Substring,
+    Semantic,
+}
+
+impl ThoughtSearchMode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "substring" | "text" => Some(Self::Substring),
+            "semantic" | "embedding" => Some(Self::Semantic),
+            _ => None,
+        }
+    }
+}
+
+/// One [`FastThinkManager::search_thoughts`] hit.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ThoughtMatch {
+    pub idx: usize,
+    pub id: String,
+    pub content: String,
+    #[serde(rename = "type")]
+    pub thought_type: String,
+    /// Cosine similarity to the query (semantic mode only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f32>,
+}
+
+/// Scratch entities and concepts of `session`, sorted by name — captured
+/// before the session is consumed so the commit can report what it held.
+fn session_structure(session: &ThinkingSession) -> (Vec<CommittedEntity>, Vec<String>) {
+    let mut entities: Vec<CommittedEntity> = session
+        .entities
+        .values()
+        .map(|e| CommittedEntity {
+            name: e.name.clone(),
+            entity_type: e.entity_type.to_string(),
+        })
+        .collect();
+    entities.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut concepts: Vec<String> = session.concepts.values().map(|c| c.name.clone()).collect();
+    concepts.sort();
+    (entities, concepts)
+}
+
+/// The thoughts an [INCOMPLETE] memory carries, one per entry: the
+/// `- [type] text` bullets `persist_partial` writes (continuation lines
+/// rejoined), or the whole content when the memory is an extracted fact
+/// without that layout.
+fn incomplete_seed_thoughts(content: &str) -> Vec<String> {
+    let mut thoughts: Vec<String> = Vec::new();
+    let mut in_list = false;
+    for line in content.lines() {
+        if let Some(item) = line.strip_prefix("- [") {
+            thoughts.push(format!("[{item}"));
+            in_list = true;
+        } else if let (true, Some(more)) = (in_list, line.strip_prefix("  ")) {
+            if let Some(last) = thoughts.last_mut() {
+                last.push('\n');
+                last.push_str(more);
+            }
+        } else {
+            in_list = false;
+        }
+    }
+    if thoughts.is_empty() && !content.trim().is_empty() {
+        thoughts.push(content.trim().to_string());
+    }
+    thoughts
+}
+
+/// [`incomplete_seed_thoughts`] typed back from their `[type]` prefix for
+/// `import_incomplete`. The interrupted session's root (`initial`) returns
+/// as a question and its `conclusion`s as hypotheses — the importing
+/// session keeps its own root and has concluded nothing yet. Text without
+/// a known prefix is reasoning.
+fn incomplete_thought_nodes(content: &str) -> Vec<(ThoughtType, String)> {
+    incomplete_seed_thoughts(content)
+        .into_iter()
+        .map(|item| {
+            let typed = item.strip_prefix('[').and_then(|rest| {
+                let (name, text) = rest.split_once(']')?;
+                let thought_type = match ThoughtType::from_name(name)? {
+                    ThoughtType::Initial => ThoughtType::Question,
+                    ThoughtType::Conclusion => ThoughtType::Hypothesis,
+                    other => other,
+                };
+                Some((thought_type, text.trim().to_string()))
+            });
+            typed.unwrap_or((ThoughtType::Reasoning, item))
+        })
+        .filter(|(_, text)| !text.is_empty())
+        .collect()
+}
+
+/// Distinct entities and INSTANCE_OF concepts the stored graph links to
+/// `memory_ids` — what `reconcile_counts` reports in place of the
+/// session's scratch counts. A memory that cannot be read counts nothing.
+async fn persisted_counts(client: &HelixirClient, memory_ids: &[String]) -> (usize, usize) {
+    let mut entities: HashSet<String> = HashSet::new();
+    let mut concepts: HashSet<String> = HashSet::new();
+    for mid in memory_ids {
+        match client.tooling().get_memory_record(mid).await {
+            Ok(Some(record)) => {
+                entities.extend(record.entities.iter().map(|n| n.to_lowercase()));
+                concepts.extend(record.concepts.iter().map(|n| n.to_lowercase()));
+            }
+            Ok(None) => {}
+            Err(e) => warn!("commit: reading back {mid} for counts failed (non-fatal): {e}"),
+        }
+    }
+    (entities.len(), concepts.len())
+}
+
+/// INSTANCE_OF links from the committed memories to the auto-classified
+/// type's concept; returns how many were newly written.
+async fn link_classification(
+    client: &HelixirClient,
+    memory_ids: &[String],
+    classification: Option<(&'static str, i32)>,
+) -> usize {
+    let Some((memory_type, confidence)) = classification else {
+        return 0;
+    };
+    let mut linked = 0;
+    for mid in memory_ids {
+        match client
+            .tooling()
+            .ensure_type_concept(mid, memory_type, confidence)
+            .await
+        {
+            Ok(true) => linked += 1,
+            Ok(false) => {}
+            Err(e) => warn!("commit: concept link {mid} -> {memory_type} failed (non-fatal): {e}"),
+        }
+    }
+    linked
+}
+
+/// What [`FastThinkManager::uncommit`] undid.
+#[derive(Debug, Clone)]
+pub struct UncommitResult {
+    /// The committed memories now deleted; memories the commit was deduped
+    /// to are not among them.
+    pub deleted_memory_ids: Vec<String>,
+    pub thoughts_restored: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -681,6 +2625,132 @@ pub struct SessionInfo {
     pub current_depth: usize,
     pub elapsed: std::time::Duration,
     pub has_conclusion: bool,
+    /// Certainty has converged and nothing is concluded yet — a hint to
+    /// conclude (see [`ThinkingSession::certainty_converged`]).
+    pub should_conclude: bool,
+    /// Thinking time left before the session times out (see
+    /// [`ThinkingSession::time_remaining`]).
+    pub time_remaining: std::time::Duration,
+    /// Time left before the untouched session is dropped.
+    pub ttl_remaining: std::time::Duration,
+}
+
+impl SessionInfo {
+    fn of(session: &ManagedSession) -> Self {
+        let has_conclusion = !session.get_conclusions().is_empty();
+        Self {
+            id: session.id.clone(),
+            status: session.status.clone(),
+            thought_count: session.thought_count(),
+            entity_count: session.entity_count(),
+            concept_count: session.concept_count(),
+            current_depth: session.current_depth,
+            elapsed: session.elapsed(),
+            has_conclusion,
+            should_conclude: !has_conclusion
+                && session.certainty_converged(&session.runtime.limits),
+            time_remaining: session.time_remaining(&session.runtime.limits),
+            ttl_remaining: session.ttl_remaining(&session.runtime.limits),
+        }
+    }
+}
+
+/// One recalled thought as think_recall reports it.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RecallPreview {
+    pub idx: usize,
+    pub id: String,
+    pub content_preview: String,
+    pub source_memory_id: Option<String>,
+    pub certainty: f32,
+}
+
+/// An edge think_recall added from a recalled thought to the reasoning
+/// thought it bears on.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AutoLink {
+    pub from_idx: usize,
+    pub to_idx: usize,
+    /// Thought ids of the two ends; filled in once the edge is written.
+    pub from_id: String,
+    pub to_id: String,
+    #[serde(serialize_with = "edge_name")]
+    pub kind: ThoughtEdge,
+    pub similarity: f32,
+}
+
+fn edge_name<S: serde::Serializer>(kind: &ThoughtEdge, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_str(kind)
+}
+
+/// A thought with its embedding, as [`pick_auto_links`] compares them.
+/// `chain` is the path from the root (recalls only).
+struct LinkCandidate<'a> {
+    idx: NodeIndex,
+    text: &'a str,
+    vector: &'a [f32],
+    chain: &'a [NodeIndex],
+}
+
+/// Words whose presence flips a statement's polarity.
+const NEGATIONS: &[&str] = &[
+    "not",
+    "no",
+    "never",
+    "none",
+    "cannot",
+    "without",
+    "isn't",
+    "aren't",
+    "wasn't",
+    "weren't",
+    "don't",
+    "doesn't",
+    "didn't",
+    "won't",
+    "can't",
+    "shouldn't",
+];
+
+fn negated(text: &str) -> bool {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '\''))
+        .any(|w| NEGATIONS.contains(&w.to_lowercase().as_str()))
+}
+
+/// For every source, the most similar target at or above `min_similarity`
+/// that is not already on the source's own path to the root (the parent it
+/// was recalled under is linked already). Cosine says how related two
+/// thoughts are, not which way the evidence points, so the kind is a
+/// heuristic: CONTRADICTS when exactly one side is negated, else SUPPORTS.
+fn pick_auto_links(
+    sources: &[LinkCandidate<'_>],
+    targets: &[LinkCandidate<'_>],
+    min_similarity: f32,
+) -> Vec<AutoLink> {
+    sources
+        .iter()
+        .filter_map(|source| {
+            let (target, similarity) = targets
+                .iter()
+                .filter(|t| !source.chain.contains(&t.idx))
+                .map(|t| (t, cosine_similarity(source.vector, t.vector) as f32))
+                .filter(|(_, s)| *s >= min_similarity)
+                .max_by(|a, b| a.1.total_cmp(&b.1))?;
+            let kind = if negated(source.text) != negated(target.text) {
+                ThoughtEdge::Contradicts
+            } else {
+                ThoughtEdge::Supports
+            };
+            Some(AutoLink {
+                from_idx: source.idx.index(),
+                to_idx: target.idx.index(),
+                from_id: String::new(),
+                to_id: String::new(),
+                kind,
+                similarity,
+            })
+        })
+        .collect()
 }
 
 #[derive(Debug, Clone)]
@@ -696,6 +2766,7 @@ pub struct ThoughtInfo {
 mod tests {
     use super::*;
     use crate::core::config::HelixirConfig;
+    use crate::toolkit::fast_think::TemplateThought;
 
     #[test]
     fn hot_reload_pins_existing_sessions_and_updates_new_sessions() {
@@ -723,4 +2794,863 @@ mod tests {
         assert_eq!(manager.session_max_thoughts("new").unwrap(), 23);
         assert_eq!(manager.max_thoughts(), 23);
     }
+
+    #[test]
+    fn default_thought_type_is_per_session() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        manager.start_thinking("log", "watch the queue").unwrap();
+        manager.start_thinking("plain", "pick a db").unwrap();
+
+        manager
+            .set_default_thought_type("log", ThoughtType::Observation)
+            .unwrap();
+        assert_eq!(
+            manager.default_thought_type("log").unwrap(),
+            ThoughtType::Observation
+        );
+        assert_eq!(
+            manager.default_thought_type("plain").unwrap(),
+            ThoughtType::Reasoning
+        );
+        assert!(matches!(
+            manager.set_default_thought_type("gone", ThoughtType::Question),
+            Err(FastThinkError::SessionNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn expired_retained_commits_are_pruned_on_lookup() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let limits = FastThinkLimits {
+            session_ttl: std::time::Duration::ZERO,
+            ..FastThinkLimits::default()
+        };
+        let manager = FastThinkManager::new(client, limits);
+        manager.start_thinking("s", "which region").unwrap();
+        let session = manager.sessions.write().remove("s").unwrap();
+        manager.retained.write().insert(
+            "s".to_string(),
+            RetainedCommit {
+                session,
+                created_ids: vec!["mem_1".to_string()],
+                user_id: "alice".to_string(),
+                committed_at: std::time::Instant::now(),
+            },
+        );
+
+        assert!(manager.retained_trace("mem_1").is_none());
+        assert!(manager.retained.read().is_empty());
+
+        manager.start_thinking("t", "which zone").unwrap();
+        let session = manager.sessions.write().remove("t").unwrap();
+        manager.retained.write().insert(
+            "t".to_string(),
+            RetainedCommit {
+                session,
+                created_ids: vec!["mem_2".to_string()],
+                user_id: "alice".to_string(),
+                committed_at: std::time::Instant::now(),
+            },
+        );
+        assert!(matches!(
+            manager.uncommit("t", true).await,
+            Err(FastThinkError::UndoWindowPassed)
+        ));
+        assert!(matches!(
+            manager.uncommit("t", true).await,
+            Err(FastThinkError::NotRetained)
+        ));
+    }
+
+    #[test]
+    fn incomplete_seed_thoughts_splits_partial_layout() {
+        let partial = "FastThink session interrupted (timeout)\n\nThoughts:\n- [initial] pick a cache\n- [observation] hit rate is low\n  | option | score |\n- [hypothesis] TTL too short\n\n[Action: Continue research with continue_incomplete]";
+        assert_eq!(
+            incomplete_seed_thoughts(partial),
+            vec![
+                "[initial] pick a cache".to_string(),
+                "[observation] hit rate is low\n| option | score |".to_string(),
+                "[hypothesis] TTL too short".to_string(),
+            ]
+        );
+        assert_eq!(
+            incomplete_seed_thoughts("  Redis TTL looked too short  "),
+            vec!["Redis TTL looked too short".to_string()]
+        );
+        assert!(incomplete_seed_thoughts(" ").is_empty());
+    }
+
+    #[test]
+    fn incomplete_thought_nodes_keep_their_types() {
+        let partial = "FastThink session interrupted (timeout)\n\nThoughts:\n- [initial] pick a cache\n- [recall] Redis is in use\n- [conclusion] use Redis\n- [musing] odd\n\n[Action: Continue research with continue_incomplete]";
+        assert_eq!(
+            incomplete_thought_nodes(partial),
+            vec![
+                (ThoughtType::Question, "pick a cache".to_string()),
+                (ThoughtType::Recall, "Redis is in use".to_string()),
+                (ThoughtType::Hypothesis, "use Redis".to_string()),
+                (ThoughtType::Reasoning, "[musing] odd".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn continuation_seeds_recalls_and_remembers_its_source() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let runtime = manager.current.load_full();
+        let (root, seeded) = manager
+            .start_continuation(
+                "resume",
+                "mem_partial",
+                "Thoughts:\n- [reasoning] a\n- [reasoning] b",
+                Arc::clone(&runtime),
+            )
+            .expect("continuation starts");
+        assert_eq!(seeded.len(), 2);
+
+        let sessions = manager.sessions.read();
+        let session = sessions.get("resume").unwrap();
+        assert_eq!(session.root(), Some(root));
+        assert_eq!(session.continues_memory_id.as_deref(), Some("mem_partial"));
+        let seed = session.graph.node_weight(seeded[0]).unwrap();
+        assert_eq!(seed.thought_type, ThoughtType::Recall);
+        assert_eq!(seed.source_memory_id.as_deref(), Some("mem_partial"));
+        drop(sessions);
+
+        assert!(matches!(
+            manager.start_continuation("resume", "mem_partial", "x", runtime),
+            Err(FastThinkError::SessionAlreadyExists)
+        ));
+    }
+
+    #[test]
+    fn recall_previews_cut_content_and_skip_missing_thoughts() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager.start_thinking("s", "which cache").unwrap();
+        let recalled = {
+            let mut sessions = manager.sessions.write();
+            let session = sessions.get_mut("s").unwrap();
+            let runtime = Arc::clone(&session.runtime);
+            session
+                .add_recalled_thought(
+                    "Redis already runs in prod",
+                    "mem_redis",
+                    0.8,
+                    root,
+                    &runtime.limits,
+                )
+                .unwrap()
+        };
+
+        let previews = manager
+            .recall_previews("s", &[recalled, NodeIndex::new(99)], 5)
+            .unwrap();
+        assert_eq!(previews.len(), 1);
+        assert_eq!(previews[0].idx, recalled.index());
+        assert_eq!(previews[0].content_preview, "Redis...");
+        assert_eq!(previews[0].source_memory_id.as_deref(), Some("mem_redis"));
+        assert_eq!(previews[0].certainty, 0.8);
+        assert!(matches!(
+            manager.recall_previews("gone", &[recalled], 5),
+            Err(FastThinkError::SessionNotFound)
+        ));
+    }
+
+    #[test]
+    fn discard_user_sessions_drops_only_that_owner() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        for id in ["a1", "a2", "b1", "anon"] {
+            manager.start_thinking(id, "q").expect("session starts");
+        }
+        {
+            let mut sessions = manager.sessions.write();
+            for (id, owner) in [("a1", "alice"), ("a2", "alice"), ("b1", "bob")] {
+                sessions.get_mut(id).unwrap().owner_hint = Some(owner.to_string());
+            }
+        }
+
+        assert_eq!(manager.discard_user_sessions("alice"), 2);
+        let mut left = manager.list_sessions();
+        left.sort();
+        assert_eq!(left, vec!["anon".to_string(), "b1".to_string()]);
+    }
+
+    #[test]
+    fn restore_all_hands_sessions_over_and_refuses_to_clobber() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let old = FastThinkManager::with_default_limits(Arc::clone(&client));
+        for id in ["s1", "s2"] {
+            old.start_thinking(id, "q").expect("session starts");
+        }
+        let snap = old.snapshot_all(None);
+        assert_eq!(snap.sessions.len(), 2);
+
+        let new = FastThinkManager::with_default_limits(client);
+        new.start_thinking("local", "q").expect("session starts");
+        assert!(matches!(
+            new.restore_all(snap.clone(), false, None),
+            Err(FastThinkError::InvalidState(_))
+        ));
+        assert_eq!(new.list_sessions(), vec!["local".to_string()]);
+
+        assert_eq!(new.restore_all(snap, true, None).unwrap(), 2);
+        let mut ids = new.list_sessions();
+        ids.sort();
+        assert_eq!(ids, vec!["s1".to_string(), "s2".to_string()]);
+        assert_eq!(new.snapshot("s1").unwrap(), old.snapshot("s1").unwrap());
+    }
+
+    #[test]
+    fn snapshot_and_restore_all_stay_in_the_callers_namespace() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        for key in ["conn0/plan", "conn0/draft", "conn1/plan"] {
+            manager.start_thinking(key, "q").expect("session starts");
+        }
+
+        let snap = manager.snapshot_all(Some("conn0"));
+        let mut ids: Vec<&str> = snap
+            .sessions
+            .iter()
+            .map(|e| e.session.session_id.as_str())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["draft", "plan"]);
+
+        assert!(matches!(
+            manager.restore_all(snap.clone(), false, Some("conn0")),
+            Err(FastThinkError::InvalidState(_))
+        ));
+        manager.discard("conn0/draft", true).unwrap();
+        assert_eq!(manager.restore_all(snap, true, Some("conn0")).unwrap(), 2);
+        let mut keys = manager.list_sessions();
+        keys.sort();
+        assert_eq!(keys, vec!["conn0/draft", "conn0/plan", "conn1/plan"]);
+    }
+
+    #[test]
+    fn restore_with_resolves_live_ids_by_policy() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let old = FastThinkManager::with_default_limits(Arc::clone(&client));
+        let root = old.start_thinking("s1", "q").expect("session starts");
+        old.start_thinking("s2", "q").expect("session starts");
+        let new = FastThinkManager::with_default_limits(client);
+        assert_eq!(
+            new.restore_all(old.snapshot_all(None), false, None)
+                .unwrap(),
+            2
+        );
+
+        let step = |m: &FastThinkManager, content: &str| {
+            m.add_thought(
+                "s1",
+                content,
+                ThoughtType::Reasoning,
+                Some(root),
+                ThoughtOptions::default(),
+            )
+            .expect("thought added")
+        };
+        step(&new, "local step");
+        step(&old, "remote step");
+        old.annotate("s1", "check the remote step").unwrap();
+        old.start_thinking("s3", "q").expect("session starts");
+        let snap = old.snapshot_all(None);
+
+        let skipped = new
+            .restore_with(snap.clone(), OnConflict::Skip, None)
+            .unwrap();
+        assert_eq!((skipped.created, skipped.skipped), (1, 2));
+        assert_eq!(new.get_session_status("s1").unwrap().thought_count, 2);
+
+        let merged = new
+            .restore_with(snap.clone(), OnConflict::Merge, None)
+            .unwrap();
+        assert_eq!((merged.created, merged.merged), (0, 3));
+        let s1 = new.snapshot("s1").unwrap();
+        let contents: Vec<&str> = s1.thoughts.iter().map(|t| t.content.as_str()).collect();
+        assert_eq!(contents, vec!["q", "local step", "remote step"]);
+        assert_eq!(s1.edges.len(), 2);
+        assert_eq!(s1.annotations, vec!["check the remote step".to_string()]);
+        let again = new
+            .restore_with(snap.clone(), OnConflict::Merge, None)
+            .unwrap();
+        assert_eq!(
+            new.snapshot("s1").unwrap(),
+            s1,
+            "merging twice adds nothing"
+        );
+        assert_eq!(again.merged, 3);
+
+        let duplicated = new
+            .restore_with(snap.clone(), OnConflict::Duplicate, None)
+            .unwrap();
+        assert_eq!(duplicated.duplicated["s1"], "s1-copy");
+        let copy = new.snapshot("s1-copy").unwrap();
+        assert_eq!(copy.thoughts, old.snapshot("s1").unwrap().thoughts);
+        assert_eq!(new.snapshot("s1").unwrap(), s1);
+
+        let overwritten = new.restore_with(snap, OnConflict::Overwrite, None).unwrap();
+        assert_eq!(overwritten.overwritten, 3);
+        assert_eq!(new.snapshot("s1").unwrap(), old.snapshot("s1").unwrap());
+        assert_eq!(new.list_sessions().len(), 6);
+    }
+
+    #[test]
+    fn restore_with_keeps_merges_in_bounds_and_stays_in_the_namespace() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let old = FastThinkManager::with_default_limits(Arc::clone(&client));
+        let root = old.start_thinking("s1", "q").expect("session starts");
+        for content in ["a", "b"] {
+            old.add_thought(
+                "s1",
+                content,
+                ThoughtType::Reasoning,
+                Some(root),
+                ThoughtOptions::default(),
+            )
+            .expect("thought added");
+        }
+        let snap = old.snapshot_all(None);
+
+        let limits = FastThinkLimits {
+            max_thoughts: 2,
+            ..FastThinkLimits::default()
+        };
+        let new = FastThinkManager::new(client, limits);
+        new.start_thinking("s1", "q").expect("session starts");
+        let before = new.snapshot("s1").unwrap();
+        assert!(matches!(
+            new.restore_with(snap.clone(), OnConflict::Merge, None),
+            Err(FastThinkError::InvalidState(_))
+        ));
+        assert_eq!(new.snapshot("s1").unwrap(), before);
+
+        new.start_thinking("conn1/s1", "q").expect("session starts");
+        let report = new
+            .restore_with(snap, OnConflict::Duplicate, Some("conn1"))
+            .unwrap();
+        assert_eq!(report.duplicated["s1"], "s1-copy");
+        let mut ids = new.list_sessions();
+        ids.sort();
+        assert_eq!(ids, vec!["conn1/s1", "conn1/s1-copy", "s1"]);
+        assert_eq!(new.snapshot("s1").unwrap(), before);
+    }
+
+    #[test]
+    fn branch_copies_the_chain_to_root_and_leaves_the_source_alone() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager.start_thinking("s", "which queue?").unwrap();
+        let add = |content: &str, parent: NodeIndex| {
+            manager
+                .add_thought(
+                    "s",
+                    content,
+                    ThoughtType::Hypothesis,
+                    Some(parent),
+                    ThoughtOptions::default(),
+                )
+                .unwrap()
+        };
+        let kafka = add("kafka handles the fan-out", root);
+        let partitions = add("partition by tenant", kafka);
+        let sqs = add("sqs is simpler", root);
+        manager
+            .extract_entity("s", kafka, "Kafka", ScratchEntityType::Technology)
+            .unwrap();
+        manager
+            .extract_entity("s", sqs, "SQS", ScratchEntityType::Technology)
+            .unwrap();
+        let before = manager.snapshot("s").unwrap();
+
+        let (tip, copied) = manager.branch("s", "fork", partitions).unwrap();
+        assert_eq!(copied, 3);
+        let fork = manager.snapshot("fork").unwrap();
+        let contents: Vec<&str> = fork.thoughts.iter().map(|t| t.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec![
+                "which queue?",
+                "kafka handles the fan-out",
+                "partition by tenant"
+            ]
+        );
+        assert_eq!(fork.root_idx, Some(0));
+        assert_eq!(tip.index(), 2);
+        assert_eq!(fork.edges.len(), 2);
+        let entities: Vec<&str> = fork.entities.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(entities, vec!["Kafka"]);
+        assert_ne!(
+            fork.thoughts[2].id, before.thoughts[2].id,
+            "fresh thought ids"
+        );
+
+        manager
+            .add_thought(
+                "fork",
+                "use a stream per tenant",
+                ThoughtType::Reasoning,
+                Some(tip),
+                ThoughtOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(manager.snapshot("s").unwrap(), before);
+
+        assert!(matches!(
+            manager.branch("s", "fork", root),
+            Err(FastThinkError::SessionAlreadyExists)
+        ));
+        assert!(matches!(
+            manager.branch("s", "other", NodeIndex::new(99)),
+            Err(FastThinkError::ThoughtNotFound)
+        ));
+        assert!(matches!(
+            manager.branch("missing", "other", root),
+            Err(FastThinkError::SessionNotFound)
+        ));
+    }
+
+    #[test]
+    fn mutations_are_refused_while_a_recall_is_in_flight() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager.start_thinking("s", "q").expect("session starts");
+        manager.sessions.write().get_mut("s").unwrap().recalling = true;
+
+        let guard = RecallGuard {
+            sessions: &manager.sessions,
+            session_id: "s",
+        };
+        let busy = manager.add_thought(
+            "s",
+            "x",
+            ThoughtType::Reasoning,
+            Some(root),
+            ThoughtOptions::default(),
+        );
+        assert!(matches!(busy, Err(FastThinkError::SessionBusy)));
+        assert!(manager.conclude("s", "done", &[root]).is_err());
+        assert!(manager.discard("s", true).is_err());
+        assert!(manager.annotate("s", "later").is_err());
+        assert!(
+            manager
+                .set_default_thought_type("s", ThoughtType::Observation)
+                .is_err()
+        );
+        // Reads still work mid-recall.
+        assert_eq!(manager.get_session_status("s").unwrap().thought_count, 1);
+
+        drop(guard);
+        manager
+            .add_thought(
+                "s",
+                "x",
+                ThoughtType::Reasoning,
+                Some(root),
+                ThoughtOptions::default(),
+            )
+            .expect("idle again once the recall returns");
+    }
+
+    #[tokio::test]
+    async fn commit_without_conclusion_keeps_the_session() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager
+            .start_thinking("s", "which cache?")
+            .expect("session starts");
+        manager
+            .add_thought(
+                "s",
+                "redis fits the access pattern",
+                ThoughtType::Hypothesis,
+                Some(root),
+                ThoughtOptions::default(),
+            )
+            .expect("thought added");
+
+        let err = manager
+            .commit("s", "alice", None, None, None, false)
+            .await
+            .expect_err("no conclusion yet");
+        assert!(matches!(err, FastThinkError::NoConclusion));
+        assert_eq!(manager.list_sessions(), vec!["s".to_string()]);
+        assert_eq!(manager.get_session_status("s").unwrap().thought_count, 2);
+    }
+
+    #[tokio::test]
+    async fn a_commit_that_fails_to_persist_keeps_the_session_for_a_retry() {
+        // No HelixDB is listening, so every write fails.
+        let client = Arc::new(
+            HelixirClient::new(HelixirConfig {
+                port: 1,
+                ..HelixirConfig::default()
+            })
+            .expect("test client constructs"),
+        );
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager
+            .start_thinking("s", "which cache?")
+            .expect("session starts");
+        manager
+            .conclude("s", "use redis for the session cache", &[root])
+            .expect("concluded");
+
+        for supersede in [None, Some("mem_old")] {
+            let err = manager
+                .commit("s", "alice", supersede, Some(false), None, false)
+                .await
+                .expect_err("nothing can be written");
+            assert!(matches!(err, FastThinkError::CommitFailed(_)), "{err:?}");
+            assert_eq!(manager.list_sessions(), vec!["s".to_string()]);
+            assert_eq!(manager.get_session_status("s").unwrap().thought_count, 2);
+        }
+
+        let err = manager
+            .commit_partial("s", "alice", "retry later")
+            .await
+            .expect_err("nothing can be written");
+        assert!(matches!(err, FastThinkError::CommitFailed(_)), "{err:?}");
+        assert_eq!(manager.list_sessions(), vec!["s".to_string()]);
+    }
+
+    #[test]
+    fn a_promoted_note_becomes_a_thought_and_leaves_the_notes() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager.start_thinking("s", "pick a retry policy").unwrap();
+        manager.annotate("s", "ask ops about the SLA").unwrap();
+        manager
+            .annotate("s", "the gateway already retries twice")
+            .unwrap();
+
+        let err = manager
+            .promote_note("s", 2, ThoughtType::Observation, None)
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                FastThinkError::NoteNotFound {
+                    note_idx: 2,
+                    count: 2
+                }
+            ),
+            "{err:?}"
+        );
+
+        let node = manager
+            .promote_note("s", 1, ThoughtType::Observation, Some(root))
+            .unwrap();
+        let chain = manager.get_thought_chain("s", node).unwrap();
+        assert_eq!(chain.len(), 2, "attached under the root");
+        let thought = chain
+            .iter()
+            .find(|t| t.content == "the gateway already retries twice")
+            .expect("the note is a thought now");
+        assert_eq!(thought.thought_type, ThoughtType::Observation);
+        assert_eq!(
+            manager.annotations("s").unwrap(),
+            vec!["ask ops about the SLA".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn substring_search_matches_case_insensitively_in_thought_order() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager
+            .start_thinking("s", "How should the API cache responses?")
+            .expect("session starts");
+        for thought in [
+            "The Cache tradeoff: staleness against load",
+            "Rate limits apply per tenant",
+            "A write-through cache keeps reads fresh",
+        ] {
+            manager
+                .add_thought(
+                    "s",
+                    thought,
+                    ThoughtType::Reasoning,
+                    Some(root),
+                    ThoughtOptions::default(),
+                )
+                .unwrap();
+        }
+
+        let hits = manager
+            .search_thoughts("s", "CACHE", ThoughtSearchMode::Substring, 10)
+            .await
+            .unwrap();
+        let idx: Vec<usize> = hits.iter().map(|h| h.idx).collect();
+        assert_eq!(idx, [0, 1, 3]);
+        assert_eq!(hits[1].thought_type, "reasoning");
+        assert!(hits.iter().all(|h| h.score.is_none()));
+
+        let capped = manager
+            .search_thoughts("s", "cache", ThoughtSearchMode::Substring, 1)
+            .await
+            .unwrap();
+        assert_eq!(capped.len(), 1);
+        assert!(matches!(
+            manager
+                .search_thoughts("nope", "cache", ThoughtSearchMode::Substring, 10)
+                .await,
+            Err(FastThinkError::SessionNotFound)
+        ));
+        assert_eq!(
+            ThoughtSearchMode::parse(" Semantic"),
+            Some(ThoughtSearchMode::Semantic)
+        );
+        assert_eq!(ThoughtSearchMode::parse("fuzzy"), None);
+    }
+
+    #[test]
+    fn session_entities_rank_by_mentions_and_carry_their_thoughts() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager
+            .start_thinking("s", "which database for billing?")
+            .expect("session starts");
+        let step = manager
+            .add_thought(
+                "s",
+                "postgres handles the ledger; redis only caches",
+                ThoughtType::Reasoning,
+                Some(root),
+                ThoughtOptions::default(),
+            )
+            .expect("thought added");
+        for (thought, name) in [(root, "Postgres"), (step, "postgres"), (step, "Redis")] {
+            manager
+                .extract_entity("s", thought, name, ScratchEntityType::Technology)
+                .unwrap();
+        }
+        manager
+            .map_to_concept("s", step, "storage", Some("architecture"))
+            .unwrap();
+
+        let (entities, concepts) = manager.session_entities("s").unwrap();
+        let names: Vec<(&str, usize)> = entities
+            .iter()
+            .map(|e| (e.name.as_str(), e.mention_count))
+            .collect();
+        assert_eq!(names, [("Postgres", 2), ("Redis", 1)]);
+        assert_eq!(entities[0].thought_idx, [root.index(), step.index()]);
+        assert_eq!(
+            entities[0].thought_ids[1],
+            manager.thought_ids("s", &[step]).unwrap()[0]
+        );
+        assert_eq!(concepts.len(), 1);
+        assert_eq!(concepts[0].parent.as_deref(), Some("architecture"));
+        assert_eq!(concepts[0].thought_idx, [step.index()]);
+
+        assert!(matches!(
+            manager.session_entities("nope"),
+            Err(FastThinkError::SessionNotFound)
+        ));
+    }
+
+    #[test]
+    fn a_rootless_session_is_rooted_by_its_first_thought() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        manager.start_rootless("s").expect("session starts");
+        assert!(matches!(
+            manager.start_rootless("s"),
+            Err(FastThinkError::SessionAlreadyExists)
+        ));
+        assert_eq!(manager.get_session_status("s").unwrap().thought_count, 0);
+        assert_eq!(manager.snapshot("s").unwrap().root_idx, None);
+
+        let first = manager
+            .add_thought(
+                "s",
+                "the queue backs up every night",
+                ThoughtType::Observation,
+                None,
+                ThoughtOptions::default(),
+            )
+            .unwrap();
+        let next = manager
+            .add_thought(
+                "s",
+                "the nightly export holds the lock",
+                ThoughtType::Hypothesis,
+                Some(first),
+                ThoughtOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(manager.snapshot("s").unwrap().root_idx, Some(first.index()));
+        let chain = manager.get_thought_chain("s", next).unwrap();
+        assert_eq!(chain.len(), 2);
+    }
+
+    #[test]
+    fn a_template_lands_as_a_tree_or_not_at_all() {
+        let template = ReasoningTemplate {
+            name: "evaluate_library".to_string(),
+            description: None,
+            thoughts: vec![
+                TemplateThought {
+                    reference: Some("need".to_string()),
+                    content: "What must the library do?".to_string(),
+                    thought_type: "question".to_string(),
+                    parent_ref: None,
+                },
+                TemplateThought {
+                    reference: None,
+                    content: "It is actively maintained".to_string(),
+                    thought_type: "hypothesis".to_string(),
+                    parent_ref: Some("need".to_string()),
+                },
+                TemplateThought {
+                    reference: None,
+                    content: "What does it cost to leave?".to_string(),
+                    thought_type: "question".to_string(),
+                    parent_ref: None,
+                },
+            ],
+        };
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager =
+            FastThinkManager::new(client, FastThinkLimits::default().with_max_thoughts(4));
+        let root = manager.start_thinking("s", "pick an HTTP client").unwrap();
+        let nodes = manager.apply_template("s", &template, None).unwrap();
+        assert_eq!(nodes.len(), 3);
+        let chain = manager.get_thought_chain("s", nodes[1]).unwrap();
+        let path: Vec<_> = chain.iter().map(|t| t.depth).collect();
+        assert_eq!(path.len(), 3, "root -> need -> hypothesis: {path:?}");
+        assert_eq!(chain.last().unwrap().thought_type, ThoughtType::Hypothesis);
+        assert_eq!(manager.get_thought_chain("s", nodes[2]).unwrap().len(), 2);
+        assert_eq!(manager.snapshot("s").unwrap().root_idx, Some(root.index()));
+
+        // One slot left: the three-thought template does not fit and adds nothing.
+        assert!(matches!(
+            manager.apply_template("s", &template, Some(nodes[2])),
+            Err(FastThinkError::TooManyThoughts)
+        ));
+        assert_eq!(manager.get_session_status("s").unwrap().thought_count, 4);
+
+        manager.start_rootless("r").unwrap();
+        let rooted = manager.apply_template("r", &template, None).unwrap();
+        assert_eq!(
+            manager.snapshot("r").unwrap().root_idx,
+            Some(rooted[0].index())
+        );
+    }
+
+    #[test]
+    fn templates_are_read_once_per_runtime_generation() {
+        let dir =
+            std::env::temp_dir().join(format!("helixir-runtime-templates-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let write = |name: &str| {
+            std::fs::write(
+                dir.join(format!("{name}.json")),
+                r#"{"thoughts": [{"content": "What is the goal?"}]}"#,
+            )
+            .unwrap();
+        };
+        write("first");
+        let mut config = HelixirConfig::default();
+        config.fast_think.templates_dir = Some(dir.display().to_string());
+        let client = Arc::new(HelixirClient::new(config).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+        let names = |m: &FastThinkManager| {
+            m.templates()
+                .iter()
+                .map(|t| t.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&manager), ["first"]);
+
+        // A new file waits for the next generation.
+        write("second");
+        assert_eq!(names(&manager), ["first"]);
+        manager.update_runtime(client, FastThinkLimits::default());
+        assert_eq!(names(&manager), ["first", "second"]);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn auto_links_pick_the_closest_off_path_thought_and_flag_negation() {
+        let chain = [NodeIndex::new(0), NodeIndex::new(1)];
+        let source = |idx, text, vector| LinkCandidate {
+            idx: NodeIndex::new(idx),
+            text,
+            vector,
+            chain: &chain,
+        };
+        let target = |idx, text, vector| LinkCandidate {
+            idx: NodeIndex::new(idx),
+            text,
+            vector,
+            chain: &[],
+        };
+        let targets = [
+            // The recall's own parent: closest, but already linked.
+            target(1, "the cache is the bottleneck", &[1.0, 0.0][..]),
+            target(2, "the cache is the bottleneck under load", &[0.9, 0.1][..]),
+            target(3, "the disk is slow", &[0.0, 1.0][..]),
+        ];
+        let sources = [
+            source(4, "cache misses dominate latency", &[1.0, 0.0][..]),
+            source(5, "the cache is not involved", &[0.95, 0.05][..]),
+            source(6, "unrelated", &[-1.0, 0.0][..]),
+        ];
+
+        let links = pick_auto_links(&sources, &targets, 0.75);
+        assert_eq!(links.len(), 2, "no target is similar enough to idx 6");
+        assert_eq!((links[0].from_idx, links[0].to_idx), (4, 2));
+        assert_eq!(links[0].kind, ThoughtEdge::Supports);
+        assert_eq!((links[1].from_idx, links[1].to_idx), (5, 2));
+        assert_eq!(links[1].kind, ThoughtEdge::Contradicts);
+        assert_eq!(
+            serde_json::to_value(&links[1]).unwrap()["kind"],
+            serde_json::json!("contradicts")
+        );
+    }
+
+    #[test]
+    fn discard_of_a_concluded_session_needs_force() {
+        let client =
+            Arc::new(HelixirClient::new(HelixirConfig::default()).expect("test client constructs"));
+        let manager = FastThinkManager::with_default_limits(client);
+        let root = manager.start_thinking("s", "which cache?").unwrap();
+        manager.conclude("s", "use redis", &[root]).unwrap();
+
+        let err = manager
+            .discard("s", false)
+            .expect_err("conclusion not committed");
+        assert!(matches!(err, FastThinkError::UnsavedConclusion(ref p) if p == "use redis"));
+        assert_eq!(manager.list_sessions(), vec!["s".to_string()]);
+
+        assert_eq!(manager.discard("s", true).unwrap().thoughts_discarded, 2);
+
+        // Without a conclusion nothing is at stake.
+        manager.start_thinking("t", "open question").unwrap();
+        assert!(manager.discard("t", false).is_ok());
+    }
 }
@@ -2,8 +2,12 @@ mod limits;
 mod manager;
 mod models;
 mod session;
+mod snapshot;
+mod templates;
 
 pub use limits::*;
 pub use manager::*;
 pub use models::*;
 pub use session::*;
+pub use snapshot::*;
+pub use templates::*;
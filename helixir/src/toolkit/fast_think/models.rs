@@ -2,6 +2,8 @@ use petgraph::stable_graph::NodeIndex;
 use std::collections::HashMap;
 use std::time::Instant;
 
+use crate::toolkit::mind_toolbox::reasoning::ReasoningType;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ThoughtType {
     Initial,
@@ -11,6 +13,9 @@ pub enum ThoughtType {
     Conclusion,
     Question,
     Observation,
+    /// A committed choice made along the way — distinct from the session's
+    /// final conclusion, and committed in its own section of it.
+    Decision,
 }
 
 impl std::fmt::Display for ThoughtType {
@@ -23,10 +28,31 @@ impl std::fmt::Display for ThoughtType {
             ThoughtType::Conclusion => write!(f, "conclusion"),
             ThoughtType::Question => write!(f, "question"),
             ThoughtType::Observation => write!(f, "observation"),
+            ThoughtType::Decision => write!(f, "decision"),
         }
     }
 }
 
+impl ThoughtType {
+    /// Every variant, in declaration order.
+    pub const ALL: [ThoughtType; 8] = [
+        ThoughtType::Initial,
+        ThoughtType::Reasoning,
+        ThoughtType::Recall,
+        ThoughtType::Hypothesis,
+        ThoughtType::Conclusion,
+        ThoughtType::Question,
+        ThoughtType::Observation,
+        ThoughtType::Decision,
+    ];
+
+    /// Parse the `Display` name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized = name.trim().to_lowercase();
+        Self::ALL.into_iter().find(|v| v.to_string() == normalized)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Thought {
     pub id: String,
@@ -36,6 +62,9 @@ pub struct Thought {
     pub timestamp: Instant,
     pub depth: usize,
     pub source_memory_id: Option<String>,
+    /// Structured payload riding with the text (a comparison table, scored
+    /// options); rendered into the committed memory by [`Thought::rendered`].
+    pub data: Option<serde_json::Value>,
 }
 
 impl Thought {
@@ -48,6 +77,7 @@ impl Thought {
             timestamp: Instant::now(),
             depth,
             source_memory_id: None,
+            data: None,
         }
     }
 
@@ -61,6 +91,21 @@ impl Thought {
         self
     }
 
+    /// Attach a structured payload; `null` counts as none.
+    pub fn with_data(mut self, data: Option<serde_json::Value>) -> Self {
+        self.data = data.filter(|d| !d.is_null());
+        self
+    }
+
+    /// The content followed by the payload rendered as text, as a commit
+    /// stores it.
+    pub fn rendered(&self) -> String {
+        match &self.data {
+            Some(data) => format!("{}\n{}", self.content, render_data(data)),
+            None => self.content.clone(),
+        }
+    }
+
     pub fn is_conclusion(&self) -> bool {
         self.thought_type == ThoughtType::Conclusion
     }
@@ -68,6 +113,57 @@ impl Thought {
     pub fn is_recall(&self) -> bool {
         self.thought_type == ThoughtType::Recall
     }
+
+    pub fn is_decision(&self) -> bool {
+        self.thought_type == ThoughtType::Decision
+    }
+}
+
+/// Plain-text rendering of a thought payload: an array of objects becomes
+/// a `|`-separated table (columns in first-seen key order), an object one
+/// `key: value` line per field, an array one `- item` line per element.
+/// Anything nested deeper is written as compact JSON.
+pub fn render_data(data: &serde_json::Value) -> String {
+    use serde_json::Value;
+    fn cell(v: &Value) -> String {
+        match v {
+            Value::String(s) => s.clone(),
+            Value::Null => String::new(),
+            other => other.to_string(),
+        }
+    }
+    match data {
+        Value::Array(rows) if !rows.is_empty() && rows.iter().all(Value::is_object) => {
+            let mut columns: Vec<&str> = Vec::new();
+            for row in rows.iter().filter_map(Value::as_object) {
+                for key in row.keys() {
+                    if !columns.contains(&key.as_str()) {
+                        columns.push(key);
+                    }
+                }
+            }
+            let mut lines = vec![format!("| {} |", columns.join(" | "))];
+            for row in rows.iter().filter_map(Value::as_object) {
+                let cells: Vec<String> = columns
+                    .iter()
+                    .map(|c| row.get(*c).map(cell).unwrap_or_default())
+                    .collect();
+                lines.push(format!("| {} |", cells.join(" | ")));
+            }
+            lines.join("\n")
+        }
+        Value::Array(items) => items
+            .iter()
+            .map(|v| format!("- {}", cell(v)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Value::Object(fields) => fields
+            .iter()
+            .map(|(k, v)| format!("{k}: {}", cell(v)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        other => cell(other),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -97,6 +193,95 @@ impl std::fmt::Display for ThoughtEdge {
     }
 }
 
+/// Weight an edge carries unless the agent says otherwise: full strength.
+pub const DEFAULT_EDGE_WEIGHT: f32 = 1.0;
+
+/// A typed edge of the thought graph plus how strongly it holds (0.0-1.0),
+/// e.g. a strong vs. a weak implication. `kind` keeps the `Display` name the
+/// tools already speak.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedEdge {
+    pub kind: ThoughtEdge,
+    pub weight: f32,
+}
+
+/// The optional parts of a thought added through
+/// [`super::FastThinkManager::add_thought`]; the default is a plain child
+/// of its parent at the type's default certainty.
+#[derive(Debug, Clone, Default)]
+pub struct ThoughtOptions {
+    /// Typed, weighted edge from the parent.
+    pub edge: Option<WeightedEdge>,
+    /// Overrides the default certainty; clamped to 0..=1.
+    pub certainty: Option<f32>,
+    /// Structured payload kept beside the prose (a JSON `null` is dropped).
+    pub data: Option<serde_json::Value>,
+}
+
+impl WeightedEdge {
+    pub fn new(kind: ThoughtEdge) -> Self {
+        Self {
+            kind,
+            weight: DEFAULT_EDGE_WEIGHT,
+        }
+    }
+
+    /// `weight` clamped to 0.0-1.0; a NaN reads as the default.
+    pub fn with_weight(kind: ThoughtEdge, weight: f32) -> Self {
+        Self {
+            kind,
+            weight: clamp_edge_weight(weight),
+        }
+    }
+}
+
+pub(super) fn clamp_edge_weight(weight: f32) -> f32 {
+    if weight.is_nan() {
+        DEFAULT_EDGE_WEIGHT
+    } else {
+        weight.clamp(0.0, 1.0)
+    }
+}
+
+impl ThoughtEdge {
+    /// Every edge kind, in declaration order.
+    pub const ALL: [ThoughtEdge; 8] = [
+        ThoughtEdge::LeadsTo,
+        ThoughtEdge::Recalled,
+        ThoughtEdge::Supports,
+        ThoughtEdge::Contradicts,
+        ThoughtEdge::Implies,
+        ThoughtEdge::Because,
+        ThoughtEdge::Refines,
+        ThoughtEdge::Questions,
+    ];
+
+    /// Parse the `Display` name (case-insensitive; `-`/space read as `_`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized = name.trim().to_lowercase().replace(['-', ' '], "_");
+        Self::ALL
+            .into_iter()
+            .find(|edge| edge.to_string() == normalized)
+    }
+
+    /// The main-memory relation this edge becomes in a chain commit, and
+    /// whether it runs backwards (child to parent). `leads_to` is the
+    /// default step, read as a weak `IMPLIES` through its weight; a recall
+    /// SUPPORTS the thought it hangs under; `refines` only relates.
+    /// `questions` makes no claim and is not carried over.
+    pub fn reasoning_relation(&self) -> Option<(ReasoningType, bool)> {
+        match self {
+            ThoughtEdge::LeadsTo | ThoughtEdge::Implies => Some((ReasoningType::Implies, false)),
+            ThoughtEdge::Because => Some((ReasoningType::Because, false)),
+            ThoughtEdge::Supports => Some((ReasoningType::Supports, false)),
+            ThoughtEdge::Contradicts => Some((ReasoningType::Contradicts, false)),
+            ThoughtEdge::Refines => Some((ReasoningType::RelatesTo, false)),
+            ThoughtEdge::Recalled => Some((ReasoningType::Supports, true)),
+            ThoughtEdge::Questions => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ScratchEntityType {
     Person,
@@ -126,6 +311,46 @@ impl std::fmt::Display for ScratchEntityType {
     }
 }
 
+impl ScratchEntityType {
+    /// Every variant, in declaration order.
+    pub const ALL: [ScratchEntityType; 9] = [
+        ScratchEntityType::Person,
+        ScratchEntityType::Organization,
+        ScratchEntityType::Location,
+        ScratchEntityType::Concept,
+        ScratchEntityType::Object,
+        ScratchEntityType::Action,
+        ScratchEntityType::Event,
+        ScratchEntityType::Technology,
+        ScratchEntityType::Other,
+    ];
+
+    /// Parse the `Display` name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized = name.trim().to_lowercase();
+        Self::ALL.into_iter().find(|v| v.to_string() == normalized)
+    }
+
+    /// Map a free-form extracted type onto a variant: exact names first,
+    /// then common synonyms (the extraction prompt's `system` is
+    /// Technology); anything else is `Other`.
+    pub fn classify(name: &str) -> Self {
+        if let Some(t) = Self::from_name(name) {
+            return t;
+        }
+        match name.trim().to_lowercase().as_str() {
+            "people" | "user" | "individual" => Self::Person,
+            "org" | "company" | "team" | "organisation" => Self::Organization,
+            "place" | "city" | "country" => Self::Location,
+            "idea" | "topic" => Self::Concept,
+            "activity" | "task" => Self::Action,
+            "system" | "tool" | "product" | "software" | "service" | "library" | "framework"
+            | "component" => Self::Technology,
+            _ => Self::Other,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ScratchEntity {
     pub id: String,
@@ -146,6 +371,20 @@ impl ScratchEntity {
         }
     }
 
+    /// Key of an entity in a session's entity map: the lowercased name, or
+    /// `type:name` when the type is part of its identity.
+    pub fn key(
+        name: &str,
+        entity_type: &ScratchEntityType,
+        identity: super::limits::EntityIdentity,
+    ) -> String {
+        let name = name.to_lowercase();
+        match identity {
+            super::limits::EntityIdentity::Name => name,
+            super::limits::EntityIdentity::NameAndType => format!("{entity_type}:{name}"),
+        }
+    }
+
     pub fn add_mention(&mut self, thought_idx: NodeIndex) {
         if !self.mentions.contains(&thought_idx) {
             self.mentions.push(thought_idx);
@@ -211,6 +450,25 @@ impl std::fmt::Display for SessionStatus {
     }
 }
 
+impl SessionStatus {
+    /// Every variant, in declaration order.
+    pub const ALL: [SessionStatus; 7] = [
+        SessionStatus::Thinking,
+        SessionStatus::NeedsRecall,
+        SessionStatus::Decided,
+        SessionStatus::TimedOut,
+        SessionStatus::Overflow,
+        SessionStatus::Committed,
+        SessionStatus::Discarded,
+    ];
+
+    /// Parse the `Display` name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized = name.trim().to_lowercase();
+        Self::ALL.into_iter().find(|v| v.to_string() == normalized)
+    }
+}
+
 #[derive(Debug)]
 pub enum FastThinkError {
     SessionNotFound,
@@ -219,13 +477,47 @@ pub enum FastThinkError {
     TooManyThoughts,
     TooManyEntities,
     TooManyConcepts,
-    TooDeep,
+    /// The new thought would sit below `max_depth`: its parent is already at
+    /// `parent_depth`.
+    TooDeep {
+        max_depth: usize,
+        parent_depth: usize,
+    },
     NoConclusion,
+    /// Conclude refused: the session has `steps` reasoning steps and
+    /// `min_thoughts_before_conclude` asks for `required`.
+    TooFewThoughts {
+        steps: usize,
+        required: usize,
+    },
+    /// Discard refused: the session holds a conclusion that was never
+    /// committed. Carries a preview of it.
+    UnsavedConclusion(String),
     InvalidState(String),
     RecallFailed(String),
     CommitFailed(String),
     ThoughtNotFound,
     EntityNotFound,
+    /// No memory has this id (continuing or importing an incomplete one).
+    MemoryNotFound(String),
+    /// No annotation at `note_idx`; the session holds `count`.
+    NoteNotFound {
+        note_idx: usize,
+        count: usize,
+    },
+    /// A `think_recall` on the session is in flight; retry once it returns.
+    SessionBusy,
+    /// Uncommit refused: the session has no retained commit to take back.
+    NotRetained,
+    /// Uncommit refused: the retained commit's undo window has passed.
+    UndoWindowPassed,
+    /// Uncommit refused without `confirm`; it would delete `memories`.
+    ConfirmRequired {
+        memories: usize,
+    },
+    /// The memory exists but has no `incomplete_thought` tag, so there is
+    /// no interrupted session in it to continue or import.
+    NotIncomplete(String),
 }
 
 impl std::fmt::Display for FastThinkError {
@@ -244,15 +536,153 @@ impl std::fmt::Display for FastThinkError {
             ),
             FastThinkError::TooManyEntities => write!(f, "Too many entities extracted"),
             FastThinkError::TooManyConcepts => write!(f, "Too many concepts mapped"),
-            FastThinkError::TooDeep => write!(f, "Thought chain too deep"),
+            FastThinkError::TooDeep {
+                max_depth,
+                parent_depth,
+            } => write!(
+                f,
+                "Thought chain too deep: the parent is at depth {parent_depth} and max_depth is {max_depth}"
+            ),
             FastThinkError::NoConclusion => write!(f, "No conclusion reached"),
+            FastThinkError::TooFewThoughts { steps, required } => write!(
+                f,
+                "too few thoughts to conclude: {steps} of {required} — reason further with \
+                 think_add or think_recall first"
+            ),
+            FastThinkError::UnsavedConclusion(preview) => {
+                write!(f, "Session has an uncommitted conclusion: \"{preview}\"")
+            }
             FastThinkError::InvalidState(s) => write!(f, "Invalid state: {}", s),
             FastThinkError::RecallFailed(s) => write!(f, "Recall failed: {}", s),
             FastThinkError::CommitFailed(s) => write!(f, "Commit failed: {}", s),
             FastThinkError::ThoughtNotFound => write!(f, "Thought not found"),
             FastThinkError::EntityNotFound => write!(f, "Entity not found"),
+            FastThinkError::MemoryNotFound(id) => write!(f, "memory {id} not found"),
+            FastThinkError::NoteNotFound { note_idx, count } => write!(
+                f,
+                "no note at index {note_idx}: the session holds {count} (think_note lists them)"
+            ),
+            FastThinkError::SessionBusy => write!(
+                f,
+                "session busy: a think_recall on it is still running — retry once it returns"
+            ),
+            FastThinkError::NotRetained => write!(
+                f,
+                "no retained commit for this session: think_commit it with retain=true"
+            ),
+            FastThinkError::UndoWindowPassed => {
+                write!(
+                    f,
+                    "the undo window for this session has passed; the commit stands"
+                )
+            }
+            FastThinkError::ConfirmRequired { memories } => write!(
+                f,
+                "uncommit deletes {memories} committed memor{}: pass confirm=true",
+                if *memories == 1 { "y" } else { "ies" }
+            ),
+            FastThinkError::NotIncomplete(id) => write!(
+                f,
+                "memory {id} is not an incomplete thought (no {} tag) — use think_recall to \
+                 build on it",
+                super::INCOMPLETE_THOUGHT_TAG
+            ),
         }
     }
 }
 
 impl std::error::Error for FastThinkError {}
+
+impl FastThinkError {
+    /// Stable snake_case name of the variant, reported as `data.error` on
+    /// MCP errors.
+    pub fn code(&self) -> &'static str {
+        match self {
+            FastThinkError::SessionNotFound => "session_not_found",
+            FastThinkError::SessionAlreadyExists => "session_already_exists",
+            FastThinkError::Timeout => "timeout",
+            FastThinkError::TooManyThoughts => "too_many_thoughts",
+            FastThinkError::TooManyEntities => "too_many_entities",
+            FastThinkError::TooManyConcepts => "too_many_concepts",
+            FastThinkError::TooDeep { .. } => "too_deep",
+            FastThinkError::NoConclusion => "no_conclusion",
+            FastThinkError::TooFewThoughts { .. } => "too_few_thoughts",
+            FastThinkError::UnsavedConclusion(_) => "unsaved_conclusion",
+            FastThinkError::InvalidState(_) => "invalid_state",
+            FastThinkError::RecallFailed(_) => "recall_failed",
+            FastThinkError::CommitFailed(_) => "commit_failed",
+            FastThinkError::ThoughtNotFound => "thought_not_found",
+            FastThinkError::EntityNotFound => "entity_not_found",
+            FastThinkError::MemoryNotFound(_) => "memory_not_found",
+            FastThinkError::NoteNotFound { .. } => "note_not_found",
+            FastThinkError::SessionBusy => "session_busy",
+            FastThinkError::NotRetained => "not_retained",
+            FastThinkError::UndoWindowPassed => "undo_window_passed",
+            FastThinkError::ConfirmRequired { .. } => "confirm_required",
+            FastThinkError::NotIncomplete(_) => "not_incomplete",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ThoughtEdge, WeightedEdge, render_data};
+
+    #[test]
+    fn thought_edge_names_round_trip() {
+        for edge in ThoughtEdge::ALL {
+            assert_eq!(ThoughtEdge::from_name(&edge.to_string()), Some(edge));
+        }
+        assert_eq!(
+            ThoughtEdge::from_name(" Leads-To "),
+            Some(ThoughtEdge::LeadsTo)
+        );
+        assert_eq!(
+            ThoughtEdge::from_name("IMPLIES"),
+            Some(ThoughtEdge::Implies)
+        );
+        assert_eq!(ThoughtEdge::from_name("causes"), None);
+    }
+
+    #[test]
+    fn edge_weights_default_to_full_and_clamp() {
+        assert_eq!(WeightedEdge::new(ThoughtEdge::Implies).weight, 1.0);
+        assert_eq!(
+            WeightedEdge::with_weight(ThoughtEdge::Implies, 0.3).weight,
+            0.3
+        );
+        assert_eq!(
+            WeightedEdge::with_weight(ThoughtEdge::Implies, 7.0).weight,
+            1.0
+        );
+        assert_eq!(
+            WeightedEdge::with_weight(ThoughtEdge::Implies, -1.0).weight,
+            0.0
+        );
+        assert_eq!(
+            WeightedEdge::with_weight(ThoughtEdge::Implies, f32::NAN).weight,
+            1.0
+        );
+    }
+
+    #[test]
+    fn render_data_tables_objects_and_lists() {
+        let table = serde_json::json!([
+            {"option": "redis", "score": 7},
+            {"option": "kafka", "score": 9, "note": "ops cost"},
+        ]);
+        assert_eq!(
+            render_data(&table),
+            "| option | score | note |\n| redis | 7 |  |\n| kafka | 9 | ops cost |"
+        );
+        assert_eq!(
+            render_data(&serde_json::json!({"winner": "kafka", "margin": 2})),
+            "margin: 2\nwinner: kafka"
+        );
+        assert_eq!(
+            render_data(&serde_json::json!(["a", {"b": 1}])),
+            "- a\n- {\"b\":1}"
+        );
+        assert_eq!(render_data(&serde_json::json!(3.5)), "3.5");
+    }
+}
@@ -1,15 +1,55 @@
 use petgraph::Direction;
 use petgraph::stable_graph::{EdgeIndex, NodeIndex, StableDiGraph};
 use petgraph::visit::EdgeRef;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
-use super::limits::FastThinkLimits;
+use super::limits::{FastThinkLimits, ThinkTimeoutMode};
 use super::models::*;
+use crate::toolkit::mind_toolbox::reasoning::ReasoningType;
+
+/// A thought [`ThinkingSession::chain_plan`] carries into main memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainThought {
+    pub idx: NodeIndex,
+    /// Rendered content to store; empty for a recall.
+    pub text: String,
+    pub memory_type: &'static str,
+    pub certainty: f32,
+    /// The memory a recall came from; the chain reuses it.
+    pub source_memory_id: Option<String>,
+}
+
+/// A thought edge as the reasoning relation a chain commit writes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainLink {
+    pub from: NodeIndex,
+    pub to: NodeIndex,
+    pub relation: ReasoningType,
+    pub weight: f32,
+}
+
+/// How far a conclusion can be trusted, from
+/// [`ThinkingSession::conclusion_confidence`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ConclusionConfidence {
+    /// 0.0-1.0, two decimals.
+    pub confidence: f32,
+    /// What set it, one line per factor ("raised by ...", "lowered by ...").
+    pub rationale: Vec<String>,
+}
+
+/// Share of the confidence the recalled evidence carries when the support
+/// also holds the agent's own thoughts.
+const EVIDENCE_WEIGHT: f32 = 0.3;
+/// Factor for a conclusion with no recalled evidence behind it.
+const NO_EVIDENCE_FACTOR: f32 = 0.85;
+/// Factor per contradicting thought on the support.
+const CONTRADICTION_FACTOR: f32 = 0.8;
 
 pub struct ThinkingSession {
     pub id: String,
-    pub graph: StableDiGraph<Thought, ThoughtEdge>,
+    pub graph: StableDiGraph<Thought, WeightedEdge>,
     pub entities: HashMap<String, ScratchEntity>,
     pub concepts: HashMap<String, ScratchConcept>,
     pub thought_to_concepts: HashMap<NodeIndex, Vec<String>>,
@@ -21,7 +61,19 @@ pub struct ThinkingSession {
     /// Last user_id seen by think_recall/commit on this session — the best
     /// guess of the owner when a shutdown auto-save must pick one.
     pub owner_hint: Option<String>,
+    /// Meta-notes about the process ("verify with user") — not thoughts: they
+    /// are never conclusion content, evidence, or committed memory.
+    pub annotations: Vec<String>,
+    /// The [INCOMPLETE] memory this session continues (`continue_incomplete`);
+    /// a commit without an explicit `supersede_memory_id` replaces it.
+    pub continues_memory_id: Option<String>,
+    /// What `think_add` records when the caller names no `thought_type`
+    /// (`think_start`'s `default_thought_type`); `Reasoning` unless set.
+    pub default_thought_type: ThoughtType,
     root_thought: Option<NodeIndex>,
+    /// `Thought::id` → node: the stable handle clients hold instead of a
+    /// raw petgraph index.
+    thought_ids: HashMap<String, NodeIndex>,
 }
 
 impl ThinkingSession {
@@ -38,7 +90,11 @@ impl ThinkingSession {
             current_depth: 0,
             status: SessionStatus::Thinking,
             owner_hint: None,
+            annotations: Vec::new(),
+            continues_memory_id: None,
+            default_thought_type: ThoughtType::Reasoning,
             root_thought: None,
+            thought_ids: HashMap::new(),
         }
     }
 
@@ -50,7 +106,7 @@ impl ThinkingSession {
         edge_type: Option<ThoughtEdge>,
         limits: &FastThinkLimits,
     ) -> Result<NodeIndex, FastThinkError> {
-        if self.started_at.elapsed() > limits.thinking_timeout {
+        if self.timed_out(limits) {
             self.status = SessionStatus::TimedOut;
             return Err(FastThinkError::Timeout);
         }
@@ -65,21 +121,32 @@ impl ThinkingSession {
             return Err(FastThinkError::TooManyThoughts);
         }
 
-        let depth = parent
-            .and_then(|p| self.graph.node_weight(p))
-            .map(|t| t.depth + 1)
-            .unwrap_or(0);
+        // An index a client made up (or kept from another session) would
+        // otherwise reach `add_edge`, which panics on a missing node.
+        let depth = match parent {
+            Some(p) => {
+                self.graph
+                    .node_weight(p)
+                    .ok_or(FastThinkError::ThoughtNotFound)?
+                    .depth
+                    + 1
+            }
+            None => 0,
+        };
 
         if depth > limits.max_depth {
-            return Err(FastThinkError::TooDeep);
+            return Err(FastThinkError::TooDeep {
+                max_depth: limits.max_depth,
+                parent_depth: depth - 1,
+            });
         }
 
-        let thought = Thought::new(content, thought_type, depth);
-        let node = self.graph.add_node(thought);
+        let node = self.insert_thought(Thought::new(content, thought_type, depth));
 
         if let Some(parent_idx) = parent {
             let edge = edge_type.unwrap_or(ThoughtEdge::LeadsTo);
-            self.graph.add_edge(parent_idx, node, edge);
+            self.graph
+                .add_edge(parent_idx, node, WeightedEdge::new(edge));
         }
 
         if self.root_thought.is_none() {
@@ -122,6 +189,37 @@ impl ThinkingSession {
         supporting_thoughts: &[NodeIndex],
         limits: &FastThinkLimits,
     ) -> Result<NodeIndex, FastThinkError> {
+        self.add_related_conclusion(content, supporting_thoughts, None, limits)
+    }
+
+    /// [`Self::add_conclusion`], optionally qualifying an earlier
+    /// conclusion: `relation` is `(prior, Refines | Contradicts)`, stored as
+    /// an edge from the new conclusion to `prior`. The relation is checked
+    /// before anything is added, and so is
+    /// `limits.min_thoughts_before_conclude`.
+    pub fn add_related_conclusion(
+        &mut self,
+        content: &str,
+        supporting_thoughts: &[NodeIndex],
+        relation: Option<(NodeIndex, ThoughtEdge)>,
+        limits: &FastThinkLimits,
+    ) -> Result<NodeIndex, FastThinkError> {
+        let steps = self.reasoning_step_count();
+        if steps < limits.min_thoughts_before_conclude {
+            return Err(FastThinkError::TooFewThoughts {
+                steps,
+                required: limits.min_thoughts_before_conclude,
+            });
+        }
+        if let Some((prior, kind)) = &relation {
+            self.check_conclusion_relation(*prior, kind)?;
+        }
+        if supporting_thoughts
+            .iter()
+            .any(|&s| self.graph.node_weight(s).is_none())
+        {
+            return Err(FastThinkError::ThoughtNotFound);
+        }
         let parent = supporting_thoughts.first().copied();
 
         let node = self.add_thought(
@@ -133,18 +231,80 @@ impl ThinkingSession {
         )?;
 
         for &supporting in supporting_thoughts.iter().skip(1) {
-            self.graph.add_edge(supporting, node, ThoughtEdge::Supports);
+            self.graph
+                .add_edge(supporting, node, WeightedEdge::new(ThoughtEdge::Supports));
+        }
+        if let Some((prior, kind)) = relation {
+            self.graph.add_edge(node, prior, WeightedEdge::new(kind));
         }
 
         self.status = SessionStatus::Decided;
         Ok(node)
     }
 
+    /// A new conclusion may qualify `target` with `kind` only when `target`
+    /// is an existing conclusion and `kind` is `Refines` or `Contradicts`.
+    fn check_conclusion_relation(
+        &self,
+        target: NodeIndex,
+        kind: &ThoughtEdge,
+    ) -> Result<(), FastThinkError> {
+        if !matches!(kind, ThoughtEdge::Refines | ThoughtEdge::Contradicts) {
+            return Err(FastThinkError::InvalidState(format!(
+                "a conclusion relates to another by refines or contradicts, not {kind}"
+            )));
+        }
+        match self.graph.node_weight(target) {
+            None => Err(FastThinkError::InvalidState(format!(
+                "relation_to {} is not a thought in this session",
+                target.index()
+            ))),
+            Some(t) if !t.is_conclusion() => Err(FastThinkError::InvalidState(format!(
+                "relation_to {} is not a conclusion",
+                target.index()
+            ))),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// The earlier conclusion `idx` refines or contradicts, if any.
+    fn qualified_conclusion(&self, idx: NodeIndex) -> Option<(NodeIndex, &ThoughtEdge)> {
+        self.graph
+            .edges_directed(idx, Direction::Outgoing)
+            .filter(|e| {
+                matches!(
+                    e.weight().kind,
+                    ThoughtEdge::Refines | ThoughtEdge::Contradicts
+                )
+            })
+            .find(|e| {
+                self.graph
+                    .node_weight(e.target())
+                    .is_some_and(Thought::is_conclusion)
+            })
+            .map(|e| (e.target(), &e.weight().kind))
+    }
+
+    /// The top-level conclusion `idx` nests under, following refinements
+    /// back; `None` when the chain loops (edges added through `think_link`),
+    /// in which case `idx` is rendered as its own root.
+    fn conclusion_root(&self, idx: NodeIndex) -> Option<NodeIndex> {
+        let mut walked = HashSet::from([idx]);
+        let mut cursor = idx;
+        while let Some((parent, _)) = self.qualified_conclusion(cursor) {
+            if !walked.insert(parent) {
+                return None;
+            }
+            cursor = parent;
+        }
+        Some(cursor)
+    }
+
     pub fn link_thoughts(
         &mut self,
         from: NodeIndex,
         to: NodeIndex,
-        edge_type: ThoughtEdge,
+        edge: WeightedEdge,
     ) -> Result<EdgeIndex, FastThinkError> {
         if self.graph.node_weight(from).is_none() {
             return Err(FastThinkError::ThoughtNotFound);
@@ -153,7 +313,53 @@ impl ThinkingSession {
             return Err(FastThinkError::ThoughtNotFound);
         }
 
-        Ok(self.graph.add_edge(from, to, edge_type))
+        self.last_activity = Instant::now();
+        Ok(self.graph.add_edge(from, to, edge))
+    }
+
+    /// Move `idx`'s certainty by `delta`, clamped to 0.0-1.0 — "this got a
+    /// bit more likely" without restating an absolute value. Returns the
+    /// certainty before and after. A non-finite delta is `InvalidState`.
+    pub fn adjust_certainty(
+        &mut self,
+        idx: NodeIndex,
+        delta: f32,
+        limits: &FastThinkLimits,
+    ) -> Result<(f32, f32), FastThinkError> {
+        if !delta.is_finite() {
+            return Err(FastThinkError::InvalidState(format!(
+                "certainty delta must be a finite number, got {delta}"
+            )));
+        }
+        if self.timed_out(limits) {
+            self.status = SessionStatus::TimedOut;
+            return Err(FastThinkError::Timeout);
+        }
+        let thought = self
+            .graph
+            .node_weight_mut(idx)
+            .ok_or(FastThinkError::ThoughtNotFound)?;
+        let before = thought.certainty;
+        thought.certainty = (before + delta).clamp(0.0, 1.0);
+        let after = thought.certainty;
+        self.last_activity = Instant::now();
+        Ok((before, after))
+    }
+
+    /// Re-weight the edge `from -> to` (clamped to 0.0-1.0).
+    pub fn set_edge_weight(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: f32,
+    ) -> Result<(), FastThinkError> {
+        let edge = self
+            .graph
+            .find_edge(from, to)
+            .and_then(|e| self.graph.edge_weight_mut(e))
+            .ok_or(FastThinkError::ThoughtNotFound)?;
+        edge.weight = clamp_edge_weight(weight);
+        Ok(())
     }
 
     pub fn extract_entity(
@@ -167,9 +373,10 @@ impl ThinkingSession {
             return Err(FastThinkError::ThoughtNotFound);
         }
 
-        let normalized_name = name.to_lowercase();
+        let normalized_name = ScratchEntity::key(name, &entity_type, limits.entity_identity);
 
         if let Some(entity) = self.entities.get_mut(&normalized_name) {
+            self.last_activity = Instant::now();
             entity.add_mention(thought_idx);
 
             self.thought_to_entities
@@ -184,6 +391,7 @@ impl ThinkingSession {
             return Err(FastThinkError::TooManyEntities);
         }
 
+        self.last_activity = Instant::now();
         let mut entity = ScratchEntity::new(name, entity_type);
         entity.add_mention(thought_idx);
         let entity_id = entity.id.clone();
@@ -211,6 +419,7 @@ impl ThinkingSession {
         let normalized_name = concept_name.to_lowercase();
 
         if let Some(concept) = self.concepts.get_mut(&normalized_name) {
+            self.last_activity = Instant::now();
             concept.link_thought(thought_idx);
 
             self.thought_to_concepts
@@ -225,6 +434,7 @@ impl ThinkingSession {
             return Err(FastThinkError::TooManyConcepts);
         }
 
+        self.last_activity = Instant::now();
         let mut concept = ScratchConcept::new(concept_name, parent_concept);
         concept.link_thought(thought_idx);
         let concept_id = concept.id.clone();
@@ -238,6 +448,27 @@ impl ThinkingSession {
         Ok(concept_id)
     }
 
+    /// Add `thought` as a node and index it by its id.
+    pub(super) fn insert_thought(&mut self, thought: Thought) -> NodeIndex {
+        let id = thought.id.clone();
+        let node = self.graph.add_node(thought);
+        self.thought_ids.insert(id, node);
+        node
+    }
+
+    /// The node of the thought whose [`Thought::id`] is `id`.
+    pub fn resolve_thought_id(&self, id: &str) -> Option<NodeIndex> {
+        self.thought_ids
+            .get(id)
+            .copied()
+            .filter(|&idx| self.graph.node_weight(idx).is_some())
+    }
+
+    /// The stable id of the thought at `idx`.
+    pub fn thought_id(&self, idx: NodeIndex) -> Option<&str> {
+        self.graph.node_weight(idx).map(|t| t.id.as_str())
+    }
+
     pub fn get_thought(&self, idx: NodeIndex) -> Option<&Thought> {
         self.graph.node_weight(idx)
     }
@@ -261,36 +492,126 @@ impl ThinkingSession {
             .collect()
     }
 
-    pub fn get_children(&self, idx: NodeIndex) -> Vec<(NodeIndex, &ThoughtEdge)> {
+    pub fn get_children(&self, idx: NodeIndex) -> Vec<(NodeIndex, &WeightedEdge)> {
         self.graph
             .edges_directed(idx, Direction::Outgoing)
             .map(|e| (e.target(), e.weight()))
             .collect()
     }
 
-    pub fn get_parents(&self, idx: NodeIndex) -> Vec<(NodeIndex, &ThoughtEdge)> {
+    pub fn get_parents(&self, idx: NodeIndex) -> Vec<(NodeIndex, &WeightedEdge)> {
         self.graph
             .edges_directed(idx, Direction::Incoming)
             .map(|e| (e.source(), e.weight()))
             .collect()
     }
 
+    /// Path from the root to `idx` along each thought's tree parent: the
+    /// edge it was created with, not later links such as a supporter of a
+    /// conclusion or an auto-linked recall.
     pub fn get_chain_to_root(&self, idx: NodeIndex) -> Vec<NodeIndex> {
         let mut chain = vec![idx];
         let mut current = idx;
 
-        while let Some((parent, _)) = self.get_parents(current).first() {
-            if chain.contains(parent) {
+        while let Some(parent) = self
+            .graph
+            .edges_directed(current, Direction::Incoming)
+            .min_by_key(|e| e.id())
+            .map(|e| e.source())
+        {
+            if chain.contains(&parent) {
                 break;
             }
-            chain.push(*parent);
-            current = *parent;
+            chain.push(parent);
+            current = parent;
         }
 
         chain.reverse();
         chain
     }
 
+    /// A new session `new_id` holding the chain from the root to `from`
+    /// ([`get_chain_to_root`](Self::get_chain_to_root)) under fresh indices
+    /// and thought ids, the edges among those thoughts, and the entities and
+    /// concepts that mention them. Owner, default thought type and
+    /// annotations carry over; timing starts now and the fork is `Thinking`.
+    /// Returns the fork and its copy of `from`.
+    pub fn fork(
+        &self,
+        new_id: &str,
+        from: NodeIndex,
+    ) -> Result<(ThinkingSession, NodeIndex), FastThinkError> {
+        if self.graph.node_weight(from).is_none() {
+            return Err(FastThinkError::ThoughtNotFound);
+        }
+        let chain = self.get_chain_to_root(from);
+        let mut fork = ThinkingSession::new(new_id);
+        let mut nodes: HashMap<NodeIndex, NodeIndex> = HashMap::with_capacity(chain.len());
+        for &idx in &chain {
+            let thought = &self.graph[idx];
+            fork.current_depth = fork.current_depth.max(thought.depth);
+            let copy = Thought {
+                id: uuid::Uuid::new_v4().to_string(),
+                ..thought.clone()
+            };
+            nodes.insert(idx, fork.insert_thought(copy));
+        }
+        for e in self.graph.edge_indices() {
+            let Some((source, target)) = self.graph.edge_endpoints(e) else {
+                continue;
+            };
+            if let (Some(&a), Some(&b)) = (nodes.get(&source), nodes.get(&target)) {
+                fork.graph.add_edge(a, b, self.graph[e].clone());
+            }
+        }
+
+        for (key, entity) in &self.entities {
+            let mentions: Vec<NodeIndex> = entity
+                .mentions
+                .iter()
+                .filter_map(|m| nodes.get(m).copied())
+                .collect();
+            if mentions.is_empty() {
+                continue;
+            }
+            for &m in &mentions {
+                fork.thought_to_entities
+                    .entry(m)
+                    .or_default()
+                    .push(key.clone());
+            }
+            let mut copy = ScratchEntity::new(&entity.name, entity.entity_type.clone());
+            copy.mentions = mentions;
+            copy.attributes = entity.attributes.clone();
+            fork.entities.insert(key.clone(), copy);
+        }
+        for (key, concept) in &self.concepts {
+            let related: Vec<NodeIndex> = concept
+                .related_thoughts
+                .iter()
+                .filter_map(|t| nodes.get(t).copied())
+                .collect();
+            if related.is_empty() {
+                continue;
+            }
+            for &t in &related {
+                fork.thought_to_concepts
+                    .entry(t)
+                    .or_default()
+                    .push(key.clone());
+            }
+            let mut copy = ScratchConcept::new(&concept.name, concept.parent.as_deref());
+            copy.related_thoughts = related;
+            fork.concepts.insert(key.clone(), copy);
+        }
+
+        fork.set_root(Some(nodes[&chain[0]]));
+        fork.owner_hint = self.owner_hint.clone();
+        fork.default_thought_type = self.default_thought_type.clone();
+        fork.annotations = self.annotations.clone();
+        Ok((fork, nodes[&from]))
+    }
+
     pub fn get_entities_for_thought(&self, idx: NodeIndex) -> Vec<&ScratchEntity> {
         self.thought_to_entities
             .get(&idx)
@@ -319,6 +640,14 @@ impl ThinkingSession {
         self.graph.node_count()
     }
 
+    /// Thoughts other than the root question and conclusions.
+    pub fn reasoning_step_count(&self) -> usize {
+        self.graph
+            .node_weights()
+            .filter(|t| t.thought_type != ThoughtType::Initial && !t.is_conclusion())
+            .count()
+    }
+
     pub fn entity_count(&self) -> usize {
         self.entities.len()
     }
@@ -331,6 +660,35 @@ impl ThinkingSession {
         self.started_at.elapsed()
     }
 
+    /// What `thinking_timeout` counts from: the start or, in
+    /// [`ThinkTimeoutMode::Idle`], the last mutation.
+    fn timeout_anchor(&self, limits: &FastThinkLimits) -> Instant {
+        match limits.timeout_mode {
+            ThinkTimeoutMode::Absolute => self.started_at,
+            ThinkTimeoutMode::Idle => self.last_activity,
+        }
+    }
+
+    /// Past `thinking_timeout` (see [`Self::timeout_anchor`]).
+    pub fn timed_out(&self, limits: &FastThinkLimits) -> bool {
+        self.timeout_anchor(limits).elapsed() > limits.thinking_timeout
+    }
+
+    /// Thinking time left before [`Self::timed_out`]; zero once it has.
+    pub fn time_remaining(&self, limits: &FastThinkLimits) -> std::time::Duration {
+        limits
+            .thinking_timeout
+            .saturating_sub(self.timeout_anchor(limits).elapsed())
+    }
+
+    /// Time left before an untouched session is dropped (`session_ttl`
+    /// from the last mutation); zero once it is due.
+    pub fn ttl_remaining(&self, limits: &FastThinkLimits) -> std::time::Duration {
+        limits
+            .session_ttl
+            .saturating_sub(self.last_activity.elapsed())
+    }
+
     pub fn is_active(&self) -> bool {
         matches!(
             self.status,
@@ -342,18 +700,249 @@ impl ThinkingSession {
         self.root_thought
     }
 
-    pub fn build_conclusion_content(&self) -> String {
-        let conclusions = self.get_conclusions();
+    /// Restore path only: a rebuilt graph names its root explicitly rather
+    /// than through the first `add_thought`.
+    pub(super) fn set_root(&mut self, root: Option<NodeIndex>) {
+        self.root_thought = root;
+    }
 
-        if conclusions.is_empty() {
-            return String::new();
+    /// Append a process note; returns the session's annotation count.
+    pub fn annotate(&mut self, note: &str) -> usize {
+        self.annotations.push(note.trim().to_string());
+        self.last_activity = Instant::now();
+        self.annotations.len()
+    }
+
+    /// Whether the agent's confidence has settled: the last
+    /// `convergence_window` thoughts it wrote itself (recalls carry retrieval
+    /// scores, not confidence; the root and conclusions are not steps) all
+    /// sit at or above `convergence_min_certainty` with variance at most
+    /// `convergence_max_variance`.
+    pub fn certainty_converged(&self, limits: &FastThinkLimits) -> bool {
+        let k = limits.convergence_window;
+        if k == 0 {
+            return false;
+        }
+        let recent: Vec<f32> = self
+            .graph
+            .node_indices()
+            .rev()
+            .filter_map(|idx| self.graph.node_weight(idx))
+            .filter(|t| {
+                !matches!(
+                    t.thought_type,
+                    ThoughtType::Initial | ThoughtType::Recall | ThoughtType::Conclusion
+                )
+            })
+            .take(k)
+            .map(|t| t.certainty)
+            .collect();
+        if recent.len() < k || recent.iter().any(|c| *c < limits.convergence_min_certainty) {
+            return false;
         }
+        let mean = recent.iter().sum::<f32>() / k as f32;
+        let variance = recent.iter().map(|c| (c - mean).powi(2)).sum::<f32>() / k as f32;
+        variance <= limits.convergence_max_variance
+    }
 
-        conclusions
+    /// Calibrated confidence in `conclusion`: the mean certainty of the
+    /// thoughts it rests on (its LeadsTo/Supports parents), blended with the
+    /// retrieval strength of the recalls among or under them, and lowered
+    /// for every Contradicts edge touching that support. No recalled
+    /// evidence lowers it too; no support at all starts from a neutral 0.5.
+    pub fn conclusion_confidence(
+        &self,
+        conclusion: NodeIndex,
+    ) -> Result<ConclusionConfidence, FastThinkError> {
+        if self.graph.node_weight(conclusion).is_none() {
+            return Err(FastThinkError::ThoughtNotFound);
+        }
+        let support: HashSet<NodeIndex> = self
+            .graph
+            .edges_directed(conclusion, Direction::Incoming)
+            .filter(|e| {
+                matches!(
+                    e.weight().kind,
+                    ThoughtEdge::LeadsTo | ThoughtEdge::Supports
+                )
+            })
+            .map(|e| e.source())
+            .collect();
+        let is_recall = |idx: &NodeIndex| {
+            self.graph
+                .node_weight(*idx)
+                .is_some_and(|t| t.thought_type == ThoughtType::Recall)
+        };
+        let reasoning: Vec<f32> = support
+            .iter()
+            .filter(|idx| !is_recall(idx))
+            .filter_map(|&idx| self.graph.node_weight(idx))
+            .map(|t| t.certainty)
+            .collect();
+        let recalls: HashSet<NodeIndex> = support
             .iter()
-            .map(|(_, t)| t.content.clone())
-            .collect::<Vec<_>>()
-            .join("\n")
+            .copied()
+            .filter(is_recall)
+            .chain(support.iter().flat_map(|&idx| {
+                self.graph
+                    .edges_directed(idx, Direction::Outgoing)
+                    .filter(|e| e.weight().kind == ThoughtEdge::Recalled)
+                    .map(|e| e.target())
+            }))
+            .filter(is_recall)
+            .collect();
+        let evidence: Vec<f32> = recalls
+            .iter()
+            .filter_map(|&idx| self.graph.node_weight(idx))
+            .map(|t| t.certainty.clamp(0.0, 1.0))
+            .collect();
+        let mean = |xs: &[f32]| xs.iter().sum::<f32>() / xs.len() as f32;
+
+        let mut rationale = Vec::new();
+        let mut confidence = if !reasoning.is_empty() {
+            let base = mean(&reasoning);
+            rationale.push(format!(
+                "supporting thoughts average certainty {base:.2} ({})",
+                reasoning.len()
+            ));
+            base
+        } else if !evidence.is_empty() {
+            rationale.push("rests on recalled memories only".to_string());
+            mean(&evidence)
+        } else {
+            rationale.push("no supporting thoughts; starts from a neutral 0.5".to_string());
+            0.5
+        };
+        if evidence.is_empty() {
+            confidence *= NO_EVIDENCE_FACTOR;
+            rationale.push("lowered by no recalled evidence".to_string());
+        } else {
+            let strength = mean(&evidence);
+            if !reasoning.is_empty() {
+                let blended = (1.0 - EVIDENCE_WEIGHT) * confidence + EVIDENCE_WEIGHT * strength;
+                let direction = if blended >= confidence {
+                    "raised"
+                } else {
+                    "lowered"
+                };
+                confidence = blended;
+                rationale.push(format!(
+                    "{direction} by {} recalled memories (mean strength {strength:.2})",
+                    evidence.len()
+                ));
+            } else {
+                rationale.push(format!(
+                    "{} recalled memories (mean strength {strength:.2})",
+                    evidence.len()
+                ));
+            }
+        }
+
+        let contradictions = self
+            .graph
+            .edge_indices()
+            .filter(|&e| self.graph[e].kind == ThoughtEdge::Contradicts)
+            .filter_map(|e| self.graph.edge_endpoints(e))
+            .filter(|(from, to)| support.contains(from) || support.contains(to))
+            .count();
+        if contradictions > 0 {
+            confidence *= CONTRADICTION_FACTOR.powi(contradictions as i32);
+            rationale.push(format!(
+                "lowered by {contradictions} contradicting thought{} present",
+                if contradictions == 1 { "" } else { "s" }
+            ));
+        }
+
+        Ok(ConclusionConfidence {
+            confidence: (confidence.clamp(0.0, 1.0) * 100.0).round() / 100.0,
+            rationale,
+        })
+    }
+
+    pub fn get_decisions(&self) -> Vec<&Thought> {
+        self.graph
+            .node_weights()
+            .filter(|t| t.is_decision())
+            .collect()
+    }
+
+    /// The texts a commit stores: one per top-level conclusion, with the
+    /// conclusions that refine or contradict it nested underneath
+    /// (`(refined by: ...)`), and the session's decisions appended to the
+    /// last one as their own section. Empty when there is no conclusion —
+    /// decisions alone do not make a commit.
+    pub fn conclusion_atoms(&self) -> Vec<String> {
+        let conclusions = self.get_conclusions();
+        let mut rendered = HashSet::new();
+        let mut atoms: Vec<String> = Vec::new();
+        for &(idx, _) in &conclusions {
+            if rendered.contains(&idx) {
+                continue;
+            }
+            // Nested conclusions are rendered with their root.
+            if self.conclusion_root(idx).is_some_and(|root| root != idx) {
+                continue;
+            }
+            let mut atom = String::new();
+            self.render_conclusion(idx, &conclusions, 0, &mut rendered, &mut atom);
+            atoms.push(atom);
+        }
+        let decisions = self.get_decisions();
+        if let Some(last) = atoms.last_mut() {
+            if !decisions.is_empty() {
+                last.push_str("\n\nDecisions:");
+                for d in decisions {
+                    last.push_str("\n- ");
+                    last.push_str(&d.rendered().replace('\n', "\n  "));
+                }
+            }
+        }
+        atoms
+    }
+
+    /// `idx` at `depth` levels of indentation, then the conclusions that
+    /// qualify it, one level deeper.
+    fn render_conclusion(
+        &self,
+        idx: NodeIndex,
+        conclusions: &[(NodeIndex, &Thought)],
+        depth: usize,
+        rendered: &mut HashSet<NodeIndex>,
+        out: &mut String,
+    ) {
+        if !rendered.insert(idx) {
+            return;
+        }
+        let Some(thought) = self.graph.node_weight(idx) else {
+            return;
+        };
+        let indent = "  ".repeat(depth);
+        let text = thought.rendered().replace('\n', &format!("\n{indent}"));
+        if depth > 0 {
+            out.push('\n');
+        }
+        out.push_str(&indent);
+        if depth == 0 {
+            out.push_str(&text);
+        } else {
+            let label = match self.qualified_conclusion(idx) {
+                Some((_, ThoughtEdge::Contradicts)) => "contradicted by",
+                _ => "refined by",
+            };
+            out.push_str(&format!("({label}: {text})"));
+        }
+        for &(child, _) in conclusions {
+            if self
+                .qualified_conclusion(child)
+                .is_some_and(|(parent, _)| parent == idx)
+            {
+                self.render_conclusion(child, conclusions, depth + 1, rendered, out);
+            }
+        }
+    }
+
+    pub fn build_conclusion_content(&self) -> String {
+        self.conclusion_atoms().join("\n")
     }
 
     pub fn get_supporting_evidence(&self) -> Vec<String> {
@@ -420,6 +1009,68 @@ impl ThinkingSession {
         evidence
     }
 
+    /// What a chain commit carries over: every thought except the framing
+    /// root and open questions, in insertion order, and the typed edges
+    /// among them as reasoning relations
+    /// ([`ThoughtEdge::reasoning_relation`]). A recall keeps its source
+    /// memory instead of a text to store.
+    pub fn chain_plan(&self) -> (Vec<ChainThought>, Vec<ChainLink>) {
+        let thoughts: Vec<ChainThought> = self
+            .graph
+            .node_indices()
+            .filter_map(|idx| {
+                let t = self.graph.node_weight(idx)?;
+                let memory_type = match t.thought_type {
+                    ThoughtType::Initial | ThoughtType::Question => return None,
+                    ThoughtType::Recall => {
+                        return t.source_memory_id.clone().map(|id| ChainThought {
+                            idx,
+                            text: String::new(),
+                            memory_type: "fact",
+                            certainty: t.certainty,
+                            source_memory_id: Some(id),
+                        });
+                    }
+                    ThoughtType::Hypothesis => "opinion",
+                    _ => "fact",
+                };
+                let text = t.rendered();
+                (!text.trim().is_empty()).then_some(ChainThought {
+                    idx,
+                    text,
+                    memory_type,
+                    certainty: t.certainty,
+                    source_memory_id: None,
+                })
+            })
+            .collect();
+        let kept: HashSet<NodeIndex> = thoughts.iter().map(|t| t.idx).collect();
+        let links = self
+            .graph
+            .edge_indices()
+            .filter_map(|e| {
+                let (source, target) = self.graph.edge_endpoints(e)?;
+                if !kept.contains(&source) || !kept.contains(&target) {
+                    return None;
+                }
+                let edge = self.graph.edge_weight(e)?;
+                let (relation, backwards) = edge.kind.reasoning_relation()?;
+                let (from, to) = if backwards {
+                    (target, source)
+                } else {
+                    (source, target)
+                };
+                Some(ChainLink {
+                    from,
+                    to,
+                    relation,
+                    weight: edge.weight,
+                })
+            })
+            .collect();
+        (thoughts, links)
+    }
+
     pub fn get_supporting_memory_ids(&self) -> Vec<String> {
         self.graph
             .node_indices()
@@ -433,17 +1084,222 @@ impl ThinkingSession {
             })
             .collect()
     }
+
+    /// Fold `other` into this session. Thoughts are matched by
+    /// [`Thought::id`]; the ones this session lacks are appended under new
+    /// indices, and edges, entity mentions and concept links follow them
+    /// onto the matched or appended nodes. What both sides have — a thought,
+    /// an edge between the same pair, an entity attribute — keeps this
+    /// session's version. Annotations are unioned in order; status, timing
+    /// and the root stay this session's (the root is taken from `other`
+    /// only when this one has none). Like a template, a merge that would
+    /// take the session past `max_thoughts` or `max_depth` is refused
+    /// before anything changes. Returns how many thoughts were added.
+    pub fn merge_from(
+        &mut self,
+        other: &ThinkingSession,
+        limits: &FastThinkLimits,
+    ) -> Result<usize, FastThinkError> {
+        self.check_merge(other, limits)?;
+        let identity = limits.entity_identity;
+        let mut nodes: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut added = 0;
+        for idx in other.graph.node_indices() {
+            let Some(thought) = other.graph.node_weight(idx) else {
+                continue;
+            };
+            let node = match self.resolve_thought_id(&thought.id) {
+                Some(node) => node,
+                None => {
+                    added += 1;
+                    self.current_depth = self.current_depth.max(thought.depth);
+                    self.insert_thought(thought.clone())
+                }
+            };
+            nodes.insert(idx, node);
+        }
+
+        for e in other.graph.edge_indices() {
+            let (Some((from, to)), Some(edge)) =
+                (other.graph.edge_endpoints(e), other.graph.edge_weight(e))
+            else {
+                continue;
+            };
+            let (from, to) = (nodes[&from], nodes[&to]);
+            if self.graph.find_edge(from, to).is_none() {
+                self.graph.add_edge(from, to, edge.clone());
+            }
+        }
+        for entity in other.entities.values() {
+            let key = ScratchEntity::key(&entity.name, &entity.entity_type, identity);
+            let slot = self
+                .entities
+                .entry(key.clone())
+                .or_insert_with(|| ScratchEntity {
+                    mentions: Vec::new(),
+                    attributes: Default::default(),
+                    ..entity.clone()
+                });
+            for (k, v) in &entity.attributes {
+                slot.attributes
+                    .entry(k.clone())
+                    .or_insert_with(|| v.clone());
+            }
+            for m in &entity.mentions {
+                let node = nodes[m];
+                slot.add_mention(node);
+                let keys = self.thought_to_entities.entry(node).or_default();
+                if !keys.contains(&key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+        for (key, concept) in &other.concepts {
+            let slot = self
+                .concepts
+                .entry(key.clone())
+                .or_insert_with(|| ScratchConcept {
+                    related_thoughts: Vec::new(),
+                    ..concept.clone()
+                });
+            for t in &concept.related_thoughts {
+                let node = nodes[t];
+                slot.link_thought(node);
+                let keys = self.thought_to_concepts.entry(node).or_default();
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+        for note in &other.annotations {
+            if !self.annotations.contains(note) {
+                self.annotations.push(note.clone());
+            }
+        }
+        if self.root().is_none() {
+            self.set_root(other.root().map(|r| nodes[&r]));
+        }
+        Ok(added)
+    }
+
+    /// Whether [`Self::merge_from`] of `other` fits `limits`: the thoughts
+    /// this session lacks must not push it past `max_thoughts`, nor sit
+    /// deeper than `max_depth`.
+    pub fn check_merge(
+        &self,
+        other: &ThinkingSession,
+        limits: &FastThinkLimits,
+    ) -> Result<(), FastThinkError> {
+        let missing: Vec<&Thought> = other
+            .graph
+            .node_weights()
+            .filter(|t| self.resolve_thought_id(&t.id).is_none())
+            .collect();
+        if self.thought_count() + missing.len() > limits.max_thoughts {
+            return Err(FastThinkError::TooManyThoughts);
+        }
+        if let Some(deep) = missing.iter().find(|t| t.depth > limits.max_depth) {
+            return Err(FastThinkError::TooDeep {
+                max_depth: limits.max_depth,
+                parent_depth: deep.depth.saturating_sub(1),
+            });
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod evidence_tests {
     use super::*;
-    use crate::toolkit::fast_think::limits::FastThinkLimits;
+    use crate::toolkit::fast_think::limits::{EntityIdentity, FastThinkLimits};
     use crate::toolkit::fast_think::models::{ThoughtEdge, ThoughtType};
+    use std::time::Duration;
 
     /// Only recalls in the conclusion's supporting subtree are evidence — a
     /// broad exploratory recall on an unrelated branch must NOT become
     /// SUPPORTS provenance (the inflation observed live: ~105 edges).
+    #[test]
+    fn chain_to_root_ignores_links_added_later() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("why is it slow", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let hypothesis = s
+            .add_thought("cache", ThoughtType::Hypothesis, Some(root), None, &limits)
+            .unwrap();
+        let side = s
+            .add_thought("disk", ThoughtType::Question, Some(root), None, &limits)
+            .unwrap();
+        let recall = s
+            .add_recalled_thought("miss rate is high", "mem_1", 0.8, side, &limits)
+            .unwrap();
+        s.link_thoughts(recall, hypothesis, WeightedEdge::new(ThoughtEdge::Supports))
+            .unwrap();
+
+        assert_eq!(s.get_chain_to_root(hypothesis), vec![root, hypothesis]);
+    }
+
+    #[test]
+    fn a_chain_plan_keeps_claims_and_maps_their_edges() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("why is it slow", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let cause = s
+            .add_thought(
+                "cache misses",
+                ThoughtType::Hypothesis,
+                Some(root),
+                None,
+                &limits,
+            )
+            .unwrap();
+        let recall = s
+            .add_recalled_thought("miss rate is high", "mem_1", 0.8, cause, &limits)
+            .unwrap();
+        let open = s
+            .add_thought(
+                "disk too?",
+                ThoughtType::Question,
+                Some(cause),
+                Some(ThoughtEdge::Questions),
+                &limits,
+            )
+            .unwrap();
+        let effect = s
+            .add_thought(
+                "p99 doubles",
+                ThoughtType::Reasoning,
+                Some(cause),
+                Some(ThoughtEdge::Implies),
+                &limits,
+            )
+            .unwrap();
+        let conclusion = s
+            .add_conclusion("warm the cache", &[effect], &limits)
+            .unwrap();
+
+        let (thoughts, links) = s.chain_plan();
+        let kept: Vec<_> = thoughts.iter().map(|t| t.idx).collect();
+        assert_eq!(kept, [cause, recall, effect, conclusion]);
+        assert!(!kept.contains(&root) && !kept.contains(&open));
+        assert_eq!(thoughts[0].memory_type, "opinion");
+        assert_eq!(thoughts[1].source_memory_id.as_deref(), Some("mem_1"));
+        assert!(thoughts[1].text.is_empty());
+
+        let edges: Vec<_> = links.iter().map(|l| (l.from, l.to, l.relation)).collect();
+        assert_eq!(
+            edges,
+            [
+                (recall, cause, ReasoningType::Supports),
+                (cause, effect, ReasoningType::Implies),
+                (effect, conclusion, ReasoningType::Implies),
+            ]
+        );
+    }
+
     #[test]
     fn evidence_excludes_unrelated_recalls() {
         let limits = FastThinkLimits::default();
@@ -490,4 +1346,481 @@ mod evidence_tests {
             "unrelated recall must be excluded: {ev:?}"
         );
     }
+
+    #[test]
+    fn decisions_get_their_own_section_of_the_conclusion() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("pick a policy", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        s.add_thought(
+            "use the managed queue",
+            ThoughtType::Decision,
+            Some(root),
+            None,
+            &limits,
+        )
+        .unwrap();
+        assert!(
+            s.conclusion_atoms().is_empty(),
+            "a decision is not a conclusion"
+        );
+
+        s.add_conclusion("backoff with jitter", &[root], &limits)
+            .unwrap();
+        assert_eq!(
+            s.build_conclusion_content(),
+            "backoff with jitter\n\nDecisions:\n- use the managed queue"
+        );
+    }
+
+    #[test]
+    fn too_deep_reports_the_limit_and_the_parent_depth() {
+        let limits = FastThinkLimits::default().with_max_depth(1);
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("q", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let child = s
+            .add_thought("a", ThoughtType::Reasoning, Some(root), None, &limits)
+            .unwrap();
+        let err = s
+            .add_thought("b", ThoughtType::Reasoning, Some(child), None, &limits)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            FastThinkError::TooDeep {
+                max_depth: 1,
+                parent_depth: 1
+            }
+        ));
+        assert!(err.to_string().contains("max_depth is 1"), "{err}");
+        // A branch from the root still fits.
+        s.add_thought("c", ThoughtType::Reasoning, Some(root), None, &limits)
+            .unwrap();
+    }
+
+    #[test]
+    fn conclude_waits_for_the_minimum_reasoning_steps() {
+        let limits = FastThinkLimits {
+            min_thoughts_before_conclude: 2,
+            ..FastThinkLimits::default()
+        };
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("where to cache?", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let step = s
+            .add_thought(
+                "reads dominate",
+                ThoughtType::Reasoning,
+                Some(root),
+                None,
+                &limits,
+            )
+            .unwrap();
+
+        let err = s
+            .add_conclusion("cache in redis", &[step], &limits)
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                FastThinkError::TooFewThoughts {
+                    steps: 1,
+                    required: 2
+                }
+            ),
+            "{err}"
+        );
+        assert!(err.to_string().contains("1 of 2"), "{err}");
+        assert_eq!(s.thought_count(), 2, "nothing was added");
+
+        s.add_thought(
+            "redis is already deployed",
+            ThoughtType::Observation,
+            Some(step),
+            None,
+            &limits,
+        )
+        .unwrap();
+        s.add_conclusion("cache in redis", &[step], &limits)
+            .unwrap();
+        assert_eq!(s.reasoning_step_count(), 2);
+    }
+
+    #[test]
+    fn related_conclusions_nest_under_the_one_they_qualify() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("where to cache?", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let first = s
+            .add_conclusion("cache in redis", &[root], &limits)
+            .unwrap();
+        let other = s
+            .add_conclusion("tune the queries", &[root], &limits)
+            .unwrap();
+        // Only refines/contradicts, and only onto a conclusion.
+        for bad in [
+            (first, ThoughtEdge::Supports),
+            (root, ThoughtEdge::Refines),
+            (NodeIndex::new(99), ThoughtEdge::Refines),
+        ] {
+            let before = s.thought_count();
+            assert!(matches!(
+                s.add_related_conclusion("x", &[root], Some(bad), &limits),
+                Err(FastThinkError::InvalidState(_))
+            ));
+            assert_eq!(s.thought_count(), before, "rejected before adding");
+        }
+
+        let refine = s
+            .add_related_conclusion(
+                "with a 5 minute TTL",
+                &[root],
+                Some((first, ThoughtEdge::Refines)),
+                &limits,
+            )
+            .unwrap();
+        s.add_related_conclusion(
+            "not for session data",
+            &[root],
+            Some((refine, ThoughtEdge::Contradicts)),
+            &limits,
+        )
+        .unwrap();
+        assert_eq!(
+            s.conclusion_atoms(),
+            vec![
+                "cache in redis\n  (refined by: with a 5 minute TTL)\n    (contradicted by: not for session data)",
+                "tune the queries",
+            ]
+        );
+
+        // A loop made through link_thoughts still renders every conclusion once.
+        s.link_thoughts(first, other, WeightedEdge::new(ThoughtEdge::Refines))
+            .unwrap();
+        s.link_thoughts(other, first, WeightedEdge::new(ThoughtEdge::Refines))
+            .unwrap();
+        let content = s.build_conclusion_content();
+        for text in ["cache in redis", "tune the queries", "with a 5 minute TTL"] {
+            assert_eq!(content.matches(text).count(), 1, "{content}");
+        }
+    }
+
+    #[test]
+    fn thoughts_resolve_by_id_and_unknown_references_are_rejected() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("refs");
+        let root = s
+            .add_thought("which db?", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let id = s.thought_id(root).unwrap().to_string();
+        assert_eq!(s.resolve_thought_id(&id), Some(root));
+        assert_eq!(s.resolve_thought_id("no-such-thought"), None);
+
+        // A made-up index used to reach add_edge and panic.
+        let ghost = NodeIndex::new(42);
+        assert!(matches!(
+            s.add_thought("child", ThoughtType::Reasoning, Some(ghost), None, &limits),
+            Err(FastThinkError::ThoughtNotFound)
+        ));
+        assert!(matches!(
+            s.add_conclusion("done", &[root, ghost], &limits),
+            Err(FastThinkError::ThoughtNotFound)
+        ));
+        assert_eq!(s.thought_count(), 1);
+    }
+
+    #[test]
+    fn structured_data_is_rendered_into_the_commit_content() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("which broker?", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let pick = s
+            .add_thought(
+                "go with kafka",
+                ThoughtType::Decision,
+                Some(root),
+                None,
+                &limits,
+            )
+            .unwrap();
+        s.get_thought_mut(pick).unwrap().data = Some(serde_json::json!([
+            {"option": "kafka", "score": 9},
+            {"option": "redis", "score": 7},
+        ]));
+        let end = s
+            .add_conclusion("kafka for the event bus", &[pick], &limits)
+            .unwrap();
+        s.get_thought_mut(end).unwrap().data = Some(serde_json::json!({"owner": "infra"}));
+
+        assert_eq!(
+            s.build_conclusion_content(),
+            "kafka for the event bus\nowner: infra\n\nDecisions:\n- go with kafka\n  \
+             | option | score |\n  | kafka | 9 |\n  | redis | 7 |"
+        );
+    }
+
+    #[test]
+    fn annotations_stay_out_of_thoughts_and_commit_content() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("pick a policy", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        assert_eq!(s.annotate("  need to verify with user "), 1);
+        s.add_conclusion("backoff with jitter", &[root], &limits)
+            .unwrap();
+
+        assert_eq!(s.annotations, vec!["need to verify with user".to_string()]);
+        assert_eq!(s.thought_count(), 2);
+        assert_eq!(s.build_conclusion_content(), "backoff with jitter");
+        assert!(s.get_supporting_evidence().is_empty());
+    }
+
+    #[test]
+    fn convergence_needs_k_high_and_steady_agent_thoughts() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought(
+                "is the cache the bottleneck?",
+                ThoughtType::Initial,
+                None,
+                None,
+                &limits,
+            )
+            .unwrap();
+        let step = |s: &mut ThinkingSession, certainty: f32, kind: ThoughtType| {
+            let idx = s
+                .add_thought("step", kind, Some(root), None, &limits)
+                .unwrap();
+            s.get_thought_mut(idx).unwrap().certainty = certainty;
+        };
+
+        step(&mut s, 0.85, ThoughtType::Reasoning);
+        step(&mut s, 0.9, ThoughtType::Hypothesis);
+        assert!(!s.certainty_converged(&limits), "window not yet filled");
+        // A high-scoring recall is evidence, not the agent's confidence.
+        step(&mut s, 0.99, ThoughtType::Recall);
+        assert!(!s.certainty_converged(&limits));
+        step(&mut s, 0.88, ThoughtType::Reasoning);
+        assert!(s.certainty_converged(&limits));
+
+        step(&mut s, 0.4, ThoughtType::Question);
+        assert!(!s.certainty_converged(&limits), "a doubt resets it");
+
+        let disabled = FastThinkLimits {
+            convergence_window: 0,
+            ..FastThinkLimits::default()
+        };
+        assert!(!s.certainty_converged(&disabled));
+    }
+
+    #[test]
+    fn conclusion_confidence_weighs_certainty_evidence_and_contradictions() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("which queue?", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let step = |s: &mut ThinkingSession, certainty: f32| {
+            let idx = s
+                .add_thought("step", ThoughtType::Reasoning, Some(root), None, &limits)
+                .unwrap();
+            s.get_thought_mut(idx).unwrap().certainty = certainty;
+            idx
+        };
+        let a = step(&mut s, 0.9);
+        let b = step(&mut s, 0.7);
+
+        // Own thoughts only: their mean, lowered for the missing evidence.
+        let bare = s.add_conclusion("use kafka", &[a, b], &limits).unwrap();
+        let c = s.conclusion_confidence(bare).unwrap();
+        assert!((c.confidence - 0.68).abs() < 1e-3, "{c:?}");
+        assert!(
+            c.rationale
+                .iter()
+                .any(|r| r.contains("no recalled evidence"))
+        );
+
+        // Strong recalls under the support raise it.
+        s.add_recalled_thought("kafka handles 1M msg/s", "mem_1", 0.9, a, &limits)
+            .unwrap();
+        let backed = s.add_conclusion("use kafka", &[a, b], &limits).unwrap();
+        let c = s.conclusion_confidence(backed).unwrap();
+        assert!((c.confidence - 0.83).abs() < 1e-3, "{c:?}");
+        assert!(
+            c.rationale
+                .iter()
+                .any(|r| r.starts_with("raised by 1 recalled"))
+        );
+
+        // Contradictions against the support lower it, once per edge.
+        let doubt = step(&mut s, 0.6);
+        let doubt2 = step(&mut s, 0.6);
+        for d in [doubt, doubt2] {
+            s.link_thoughts(d, a, WeightedEdge::new(ThoughtEdge::Contradicts))
+                .unwrap();
+        }
+        let c = s.conclusion_confidence(backed).unwrap();
+        assert!((c.confidence - 0.53).abs() < 1e-3, "{c:?}");
+        assert!(
+            c.rationale
+                .contains(&"lowered by 2 contradicting thoughts present".to_string())
+        );
+
+        let unsupported = s.add_conclusion("maybe", &[], &limits).unwrap();
+        assert!(s.conclusion_confidence(unsupported).unwrap().confidence < 0.5);
+        assert!(s.conclusion_confidence(NodeIndex::new(999)).is_err());
+    }
+
+    #[test]
+    fn idle_timeout_counts_from_the_last_mutation() {
+        let absolute = FastThinkLimits::default().with_timeout(Duration::from_secs(30));
+        let idle = absolute.clone().with_timeout_mode(ThinkTimeoutMode::Idle);
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought(
+                "plan the migration",
+                ThoughtType::Initial,
+                None,
+                None,
+                &idle,
+            )
+            .unwrap();
+
+        // Started a minute ago, last touched just now.
+        s.started_at = Instant::now() - Duration::from_secs(60);
+        s.extract_entity(root, "postgres", ScratchEntityType::Technology, &idle)
+            .unwrap();
+        assert!(!s.timed_out(&idle));
+        assert!(s.timed_out(&absolute));
+        s.add_thought(
+            "copy in batches",
+            ThoughtType::Reasoning,
+            Some(root),
+            None,
+            &idle,
+        )
+        .expect("an active session keeps going in idle mode");
+
+        let err = s
+            .add_thought(
+                "and then?",
+                ThoughtType::Reasoning,
+                Some(root),
+                None,
+                &absolute,
+            )
+            .unwrap_err();
+        assert!(matches!(err, FastThinkError::Timeout));
+
+        // Abandoned: idle mode times out too.
+        s.status = SessionStatus::Thinking;
+        s.last_activity = Instant::now() - Duration::from_secs(31);
+        assert!(s.timed_out(&idle));
+    }
+
+    #[test]
+    fn time_remaining_follows_the_timeout_mode_and_stops_at_zero() {
+        let absolute = FastThinkLimits::default().with_timeout(Duration::from_secs(30));
+        let idle = absolute.clone().with_timeout_mode(ThinkTimeoutMode::Idle);
+        let mut s = ThinkingSession::new("t");
+        s.started_at = Instant::now() - Duration::from_secs(20);
+        s.last_activity = Instant::now() - Duration::from_secs(5);
+
+        let left = |d: Duration| d.as_secs_f64().round() as u64;
+        assert_eq!(left(s.time_remaining(&absolute)), 10);
+        assert_eq!(left(s.time_remaining(&idle)), 25);
+        assert_eq!(left(s.ttl_remaining(&absolute)), 295);
+
+        s.started_at = Instant::now() - Duration::from_secs(60);
+        s.last_activity = Instant::now() - Duration::from_secs(600);
+        assert_eq!(s.time_remaining(&absolute), Duration::ZERO);
+        assert_eq!(s.time_remaining(&idle), Duration::ZERO);
+        assert_eq!(s.ttl_remaining(&absolute), Duration::ZERO);
+    }
+
+    #[test]
+    fn certainty_moves_by_the_delta_and_stays_in_range() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("ship on friday?", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let hyp = s
+            .add_thought(
+                "tests are green",
+                ThoughtType::Hypothesis,
+                Some(root),
+                None,
+                &limits,
+            )
+            .unwrap();
+        s.get_thought_mut(hyp).unwrap().certainty = 0.5;
+
+        let (before, after) = s.adjust_certainty(hyp, 0.2, &limits).unwrap();
+        assert!((before - 0.5).abs() < 1e-6);
+        assert!((after - 0.7).abs() < 1e-6);
+        assert_eq!(s.adjust_certainty(hyp, 0.9, &limits).unwrap().1, 1.0);
+        assert_eq!(s.adjust_certainty(hyp, -3.0, &limits).unwrap().1, 0.0);
+        assert!(matches!(
+            s.adjust_certainty(hyp, f32::NAN, &limits),
+            Err(FastThinkError::InvalidState(_))
+        ));
+        assert!(matches!(
+            s.adjust_certainty(NodeIndex::new(42), 0.1, &limits),
+            Err(FastThinkError::ThoughtNotFound)
+        ));
+    }
+
+    #[test]
+    fn typed_identity_keeps_same_named_entities_of_different_types_apart() {
+        let typed = FastThinkLimits::default().with_entity_identity(EntityIdentity::NameAndType);
+        let by_name = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("t");
+        let root = s
+            .add_thought("which python?", ThoughtType::Initial, None, None, &typed)
+            .unwrap();
+        let language = s
+            .extract_entity(root, "Python", ScratchEntityType::Technology, &typed)
+            .unwrap();
+        let snake = s
+            .extract_entity(root, "python", ScratchEntityType::Object, &typed)
+            .unwrap();
+        assert_ne!(language, snake);
+        assert_eq!(
+            s.extract_entity(root, "PYTHON", ScratchEntityType::Technology, &typed)
+                .unwrap(),
+            language
+        );
+        assert_eq!(s.entities.len(), 2);
+
+        // Restoring under name-only identity merges them; typed keeps both.
+        let merged = ThinkingSession::from_snapshot(&s.snapshot(), EntityIdentity::Name).unwrap();
+        assert_eq!(merged.entities.len(), 1);
+        assert_eq!(merged.get_entities_for_thought(root).len(), 1);
+        let kept =
+            ThinkingSession::from_snapshot(&s.snapshot(), EntityIdentity::NameAndType).unwrap();
+        assert_eq!(kept.entities.len(), 2);
+
+        let mut plain = ThinkingSession::new("p");
+        let root = plain
+            .add_thought("which python?", ThoughtType::Initial, None, None, &by_name)
+            .unwrap();
+        let first = plain
+            .extract_entity(root, "Python", ScratchEntityType::Technology, &by_name)
+            .unwrap();
+        let second = plain
+            .extract_entity(root, "python", ScratchEntityType::Object, &by_name)
+            .unwrap();
+        assert_eq!(first, second);
+    }
 }
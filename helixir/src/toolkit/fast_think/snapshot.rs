@@ -0,0 +1,549 @@
+//! Serializable view of a whole FastThink session — every thought, edge,
+//! scratch entity and concept — for clients that rebuild their picture of a
+//! session (e.g. after a reconnect) rather than just reading its counts.
+//! Enum-valued fields carry their `Display` names, the same strings the MCP
+//! tools accept.
+//!
+//! [`ManagerSnapshot`] wraps every live session with the timing the graph
+//! alone does not carry, so a new process can take over in-progress sessions
+//! from an old one (blue/green hand-off) without a DB round trip.
+
+use chrono::{DateTime, Utc};
+use petgraph::stable_graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use super::limits::EntityIdentity;
+use super::models::*;
+use super::session::ThinkingSession;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub session_id: String,
+    pub status: String,
+    pub root_idx: Option<usize>,
+    pub thoughts: Vec<ThoughtSnapshot>,
+    pub edges: Vec<EdgeSnapshot>,
+    pub entities: Vec<EntitySnapshot>,
+    pub concepts: Vec<ConceptSnapshot>,
+    pub annotations: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThoughtSnapshot {
+    pub idx: usize,
+    /// The thought's stable id; empty in snapshots taken before ids were
+    /// part of the API (a fresh one is generated on restore).
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    pub content: String,
+    pub thought_type: String,
+    pub certainty: f32,
+    pub depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_memory_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeSnapshot {
+    pub from: usize,
+    pub to: usize,
+    pub edge_type: String,
+    /// Absent in snapshots taken before edges were weighted.
+    #[serde(default = "default_edge_weight")]
+    pub weight: f32,
+}
+
+fn default_edge_weight() -> f32 {
+    DEFAULT_EDGE_WEIGHT
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntitySnapshot {
+    pub name: String,
+    pub entity_type: String,
+    pub mentions: Vec<usize>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub attributes: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConceptSnapshot {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    pub related_thoughts: Vec<usize>,
+}
+
+impl ThinkingSession {
+    /// Everything in the session, in node-index order (which is insertion
+    /// order). Entities and concepts are sorted by name so two snapshots of
+    /// the same session compare equal.
+    pub fn snapshot(&self) -> SessionSnapshot {
+        let thoughts = self
+            .graph
+            .node_indices()
+            .filter_map(|idx| {
+                self.graph.node_weight(idx).map(|t| ThoughtSnapshot {
+                    idx: idx.index(),
+                    id: t.id.clone(),
+                    content: t.content.clone(),
+                    thought_type: t.thought_type.to_string(),
+                    certainty: t.certainty,
+                    depth: t.depth,
+                    source_memory_id: t.source_memory_id.clone(),
+                    data: t.data.clone(),
+                })
+            })
+            .collect();
+        let edges = self
+            .graph
+            .edge_indices()
+            .filter_map(|e| {
+                let (from, to) = self.graph.edge_endpoints(e)?;
+                let edge = self.graph.edge_weight(e)?;
+                Some(EdgeSnapshot {
+                    from: from.index(),
+                    to: to.index(),
+                    edge_type: edge.kind.to_string(),
+                    weight: edge.weight,
+                })
+            })
+            .collect();
+
+        let mut entities: Vec<EntitySnapshot> = self
+            .entities
+            .values()
+            .map(|e| EntitySnapshot {
+                name: e.name.clone(),
+                entity_type: e.entity_type.to_string(),
+                mentions: e.mentions.iter().map(|m| m.index()).collect(),
+                attributes: e
+                    .attributes
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+            })
+            .collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut concepts: Vec<ConceptSnapshot> = self
+            .concepts
+            .values()
+            .map(|c| ConceptSnapshot {
+                name: c.name.clone(),
+                parent: c.parent.clone(),
+                related_thoughts: c.related_thoughts.iter().map(|t| t.index()).collect(),
+            })
+            .collect();
+        concepts.sort_by(|a, b| a.name.cmp(&b.name));
+
+        SessionSnapshot {
+            session_id: self.id.clone(),
+            status: self.status.to_string(),
+            root_idx: self.root().map(|r| r.index()),
+            thoughts,
+            edges,
+            entities,
+            concepts,
+            annotations: self.annotations.clone(),
+        }
+    }
+
+    /// Rebuild a session from [`snapshot`](Self::snapshot) output. Thought
+    /// indices must be `0..thoughts.len()`, each once — what `snapshot`
+    /// emits — and are preserved exactly, so `thought_idx` values a client
+    /// already holds stay valid, and so do
+    /// thought ids (a snapshot without them gets fresh ones). Entity and
+    /// concept ids are regenerated; timing starts from now unless
+    /// the caller resets it. A name or index that does not resolve is
+    /// `InvalidState`. Entities are keyed by `identity`; restoring a typed
+    /// session under name-only identity merges same-named entities (the
+    /// first type wins).
+    pub fn from_snapshot(
+        snapshot: &SessionSnapshot,
+        identity: EntityIdentity,
+    ) -> Result<Self, FastThinkError> {
+        let invalid = |what: String| FastThinkError::InvalidState(format!("snapshot: {what}"));
+        let mut session = ThinkingSession::new(&snapshot.session_id);
+        session.status = SessionStatus::from_name(&snapshot.status)
+            .ok_or_else(|| invalid(format!("unknown status '{}'", snapshot.status)))?;
+
+        // Indices come from the caller: bound them by the thought count
+        // before they size anything. Unique and in range, they fill every
+        // slot, so the rebuilt graph has the same indices.
+        let count = snapshot.thoughts.len();
+        let mut by_idx: Vec<Option<&ThoughtSnapshot>> = vec![None; count];
+        for t in &snapshot.thoughts {
+            let slot = by_idx.get_mut(t.idx).ok_or_else(|| {
+                invalid(format!(
+                    "thought idx {} out of range for {count} thoughts",
+                    t.idx
+                ))
+            })?;
+            if slot.replace(t).is_some() {
+                return Err(invalid(format!("duplicate thought idx {}", t.idx)));
+            }
+        }
+        for t in by_idx.into_iter().flatten() {
+            let thought_type = ThoughtType::from_name(&t.thought_type)
+                .ok_or_else(|| invalid(format!("unknown thought_type '{}'", t.thought_type)))?;
+            let mut thought = Thought::new(&t.content, thought_type, t.depth)
+                .with_certainty(t.certainty)
+                .with_data(t.data.clone());
+            thought.source_memory_id = t.source_memory_id.clone();
+            if !t.id.is_empty() {
+                if session.resolve_thought_id(&t.id).is_some() {
+                    return Err(invalid(format!("duplicate thought id {}", t.id)));
+                }
+                thought.id = t.id.clone();
+            }
+            session.current_depth = session.current_depth.max(t.depth);
+            session.insert_thought(thought);
+        }
+        let node = |idx: usize| {
+            let node = NodeIndex::new(idx);
+            session
+                .graph
+                .contains_node(node)
+                .then_some(node)
+                .ok_or_else(|| invalid(format!("thought idx {idx} does not exist")))
+        };
+
+        let mut edges = Vec::with_capacity(snapshot.edges.len());
+        for e in &snapshot.edges {
+            let edge = ThoughtEdge::from_name(&e.edge_type)
+                .ok_or_else(|| invalid(format!("unknown edge_type '{}'", e.edge_type)))?;
+            edges.push((
+                node(e.from)?,
+                node(e.to)?,
+                WeightedEdge::with_weight(edge, e.weight),
+            ));
+        }
+        let mut entities = Vec::with_capacity(snapshot.entities.len());
+        for e in &snapshot.entities {
+            let entity_type = ScratchEntityType::from_name(&e.entity_type)
+                .ok_or_else(|| invalid(format!("unknown entity_type '{}'", e.entity_type)))?;
+            let mut entity = ScratchEntity::new(&e.name, entity_type);
+            for &m in &e.mentions {
+                entity.add_mention(node(m)?);
+            }
+            for (k, v) in &e.attributes {
+                entity.set_attribute(k, v);
+            }
+            entities.push(entity);
+        }
+        let mut concepts = Vec::with_capacity(snapshot.concepts.len());
+        for c in &snapshot.concepts {
+            let mut concept = ScratchConcept::new(&c.name, c.parent.as_deref());
+            for &t in &c.related_thoughts {
+                concept.link_thought(node(t)?);
+            }
+            concepts.push(concept);
+        }
+        let root = snapshot.root_idx.map(node).transpose()?;
+
+        for (from, to, edge) in edges {
+            session.graph.add_edge(from, to, edge);
+        }
+        for entity in entities {
+            let key = ScratchEntity::key(&entity.name, &entity.entity_type, identity);
+            let slot = session
+                .entities
+                .entry(key.clone())
+                .or_insert_with(|| ScratchEntity {
+                    mentions: Vec::new(),
+                    ..entity.clone()
+                });
+            for &m in &entity.mentions {
+                slot.add_mention(m);
+            }
+            for (k, v) in &entity.attributes {
+                slot.attributes
+                    .entry(k.clone())
+                    .or_insert_with(|| v.clone());
+            }
+            for &m in &entity.mentions {
+                let keys = session.thought_to_entities.entry(m).or_default();
+                if !keys.contains(&key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+        for concept in concepts {
+            let key = concept.name.to_lowercase();
+            for &t in &concept.related_thoughts {
+                session
+                    .thought_to_concepts
+                    .entry(t)
+                    .or_default()
+                    .push(key.clone());
+            }
+            session.concepts.insert(key, concept);
+        }
+        session.set_root(root);
+        session.annotations = snapshot.annotations.clone();
+        Ok(session)
+    }
+}
+
+/// Every live session of a [`FastThinkManager`](super::FastThinkManager),
+/// as `think_snapshot_all` returns it and `think_restore_all` accepts it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManagerSnapshot {
+    pub taken_at: DateTime<Utc>,
+    pub sessions: Vec<ManagedSessionSnapshot>,
+}
+
+/// One session plus the metadata that lives outside its graph. Wall-clock
+/// times stand in for the process-local `Instant`s, so the session's
+/// timeout and TTL keep counting from when it really started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManagedSessionSnapshot {
+    pub started_at: DateTime<Utc>,
+    pub last_activity: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_hint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub continues_memory_id: Option<String>,
+    /// Set only when the session's default differs from `reasoning`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_thought_type: Option<String>,
+    pub session: SessionSnapshot,
+}
+
+impl ManagedSessionSnapshot {
+    pub(super) fn of(session: &ThinkingSession, now: DateTime<Utc>) -> Self {
+        let wall = |at: Instant| {
+            now - chrono::Duration::from_std(at.elapsed()).unwrap_or(chrono::TimeDelta::zero())
+        };
+        Self {
+            started_at: wall(session.started_at),
+            last_activity: wall(session.last_activity),
+            owner_hint: session.owner_hint.clone(),
+            continues_memory_id: session.continues_memory_id.clone(),
+            default_thought_type: (session.default_thought_type != ThoughtType::Reasoning)
+                .then(|| session.default_thought_type.to_string()),
+            session: session.snapshot(),
+        }
+    }
+
+    pub(super) fn restore(
+        &self,
+        identity: EntityIdentity,
+    ) -> Result<ThinkingSession, FastThinkError> {
+        let mut session = ThinkingSession::from_snapshot(&self.session, identity)?;
+        let now = Utc::now();
+        let instant = |at: DateTime<Utc>| {
+            (now - at)
+                .to_std()
+                .ok()
+                .and_then(|ago| Instant::now().checked_sub(ago))
+                .unwrap_or_else(Instant::now)
+        };
+        session.started_at = instant(self.started_at);
+        session.last_activity = instant(self.last_activity);
+        session.owner_hint = self.owner_hint.clone();
+        session.continues_memory_id = self.continues_memory_id.clone();
+        if let Some(name) = &self.default_thought_type {
+            session.default_thought_type = ThoughtType::from_name(name).ok_or_else(|| {
+                FastThinkError::InvalidState(format!(
+                    "snapshot: unknown default_thought_type '{name}'"
+                ))
+            })?;
+        }
+        Ok(session)
+    }
+}
+
+/// What `think_restore_all` does with a snapshot session whose id is
+/// already live in this process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnConflict {
+    /// Keep the live session untouched.
+    Skip,
+    /// Replace the live session with the snapshot's.
+    Overwrite,
+    /// Install the snapshot's session under a fresh id next to the live one.
+    Duplicate,
+    /// Fold the snapshot's session into the live one (see
+    /// [`ThinkingSession::merge_from`]).
+    Merge,
+}
+
+impl std::fmt::Display for OnConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OnConflict::Skip => write!(f, "skip"),
+            OnConflict::Overwrite => write!(f, "overwrite"),
+            OnConflict::Duplicate => write!(f, "duplicate"),
+            OnConflict::Merge => write!(f, "merge"),
+        }
+    }
+}
+
+impl OnConflict {
+    /// Every variant, in declaration order.
+    pub const ALL: [OnConflict; 4] = [
+        OnConflict::Skip,
+        OnConflict::Overwrite,
+        OnConflict::Duplicate,
+        OnConflict::Merge,
+    ];
+
+    /// Parse the `Display` name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized = name.trim().to_lowercase();
+        Self::ALL.into_iter().find(|v| v.to_string() == normalized)
+    }
+}
+
+/// Outcome of a restore under an [`OnConflict`] policy, one count per way
+/// a snapshot session landed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RestoreReport {
+    /// Sessions whose id was not live.
+    pub created: usize,
+    pub skipped: usize,
+    pub overwritten: usize,
+    pub merged: usize,
+    /// `duplicate` only: snapshot session id → the id it was installed under.
+    pub duplicated: BTreeMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::toolkit::fast_think::limits::FastThinkLimits;
+
+    #[test]
+    fn snapshot_carries_nodes_edges_and_scratch_state() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("snap");
+        let root = s
+            .add_thought("which queue?", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let hyp = s
+            .add_thought(
+                "kafka handles the fan-out",
+                ThoughtType::Hypothesis,
+                Some(root),
+                Some(ThoughtEdge::Implies),
+                &limits,
+            )
+            .unwrap();
+        s.extract_entity(hyp, "Kafka", ScratchEntityType::Technology, &limits)
+            .unwrap();
+        s.set_edge_weight(root, hyp, 0.4).unwrap();
+        s.annotate("check retention limits");
+
+        let snap = s.snapshot();
+        assert_eq!(snap.root_idx, Some(root.index()));
+        assert_eq!(snap.thoughts.len(), 2);
+        assert_eq!(snap.thoughts[1].thought_type, "hypothesis");
+        assert_eq!(
+            snap.edges,
+            vec![EdgeSnapshot {
+                from: root.index(),
+                to: hyp.index(),
+                edge_type: "implies".to_string(),
+                weight: 0.4,
+            }]
+        );
+        assert_eq!(snap.entities[0].name, "Kafka");
+        assert_eq!(snap.entities[0].mentions, vec![hyp.index()]);
+        assert_eq!(snap.annotations, vec!["check retention limits".to_string()]);
+
+        let round: SessionSnapshot =
+            serde_json::from_value(serde_json::to_value(&snap).unwrap()).unwrap();
+        assert_eq!(round, snap);
+
+        let unweighted: EdgeSnapshot =
+            serde_json::from_value(serde_json::json!({"from": 0, "to": 1, "edge_type": "implies"}))
+                .unwrap();
+        assert_eq!(unweighted.weight, 1.0);
+    }
+
+    #[test]
+    fn restore_rebuilds_an_equal_session_and_keeps_its_age() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("handoff");
+        let root = s
+            .add_thought("cache or not?", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        let obs = s
+            .add_thought(
+                "hit rate is 4%",
+                ThoughtType::Observation,
+                Some(root),
+                None,
+                &limits,
+            )
+            .unwrap();
+        s.get_thought_mut(obs).unwrap().data = Some(serde_json::json!({"hit_rate": 0.04}));
+        s.extract_entity(obs, "Redis", ScratchEntityType::Technology, &limits)
+            .unwrap();
+        s.map_to_concept(obs, "caching", Some("performance"), &limits)
+            .unwrap();
+        s.add_conclusion("drop the cache", &[obs], &limits).unwrap();
+        s.owner_hint = Some("alice".to_string());
+        s.continues_memory_id = Some("mem_partial".to_string());
+        s.default_thought_type = ThoughtType::Observation;
+
+        let entry = ManagedSessionSnapshot::of(&s, Utc::now() - chrono::Duration::seconds(30));
+        let wire: ManagedSessionSnapshot =
+            serde_json::from_str(&serde_json::to_string(&entry).unwrap()).unwrap();
+        let restored = wire.restore(EntityIdentity::Name).unwrap();
+
+        assert_eq!(restored.snapshot(), s.snapshot());
+        assert_eq!(restored.status, SessionStatus::Decided);
+        assert_eq!(restored.owner_hint.as_deref(), Some("alice"));
+        assert_eq!(restored.continues_memory_id.as_deref(), Some("mem_partial"));
+        assert_eq!(restored.default_thought_type, ThoughtType::Observation);
+        assert_eq!(restored.get_entities_for_thought(obs)[0].name, "Redis");
+        let obs_id = s.thought_id(obs).unwrap();
+        assert_eq!(restored.resolve_thought_id(obs_id), Some(obs));
+
+        // Snapshots from before thought ids get fresh, resolvable ones.
+        let mut legacy = s.snapshot();
+        for t in &mut legacy.thoughts {
+            t.id.clear();
+        }
+        let rebuilt = ThinkingSession::from_snapshot(&legacy, EntityIdentity::Name).unwrap();
+        assert_eq!(rebuilt.resolve_thought_id(obs_id), None);
+        let fresh = rebuilt.thought_id(obs).unwrap();
+        assert_eq!(rebuilt.resolve_thought_id(fresh), Some(obs));
+        assert!(restored.elapsed() >= std::time::Duration::from_secs(29));
+
+        let mut broken = s.snapshot();
+        broken.edges[0].to = 99;
+        assert!(matches!(
+            ThinkingSession::from_snapshot(&broken, EntityIdentity::Name),
+            Err(FastThinkError::InvalidState(_))
+        ));
+    }
+
+    #[test]
+    fn out_of_range_thought_indices_are_refused_before_allocating() {
+        let limits = FastThinkLimits::default();
+        let mut s = ThinkingSession::new("hostile");
+        s.add_thought("start", ThoughtType::Initial, None, None, &limits)
+            .unwrap();
+        for idx in [usize::MAX, 1 << 40, 1] {
+            let mut snap = s.snapshot();
+            snap.thoughts[0].idx = idx;
+            assert!(matches!(
+                ThinkingSession::from_snapshot(&snap, EntityIdentity::Name),
+                Err(FastThinkError::InvalidState(m)) if m.contains("out of range")
+            ));
+        }
+        let mut snap = s.snapshot();
+        snap.root_idx = Some(usize::MAX);
+        assert!(matches!(
+            ThinkingSession::from_snapshot(&snap, EntityIdentity::Name),
+            Err(FastThinkError::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,243 @@
+//! Reasoning templates (`HELIXIR_THINK_TEMPLATES_DIR`): a recurring
+//! decision's skeleton — "evaluate a library": what problem, which
+//! alternatives, maintenance risk — stored once as a file and stamped into a
+//! session by `think_template` instead of being rebuilt thought by thought.
+//!
+//! One template per `.json` or `.toml` file:
+//! `{name, description?, thoughts: [{ref?, content, type?, parent_ref?}]}`.
+//! `parent_ref` names an earlier thought's `ref`; without one the thought
+//! hangs under the attach point. A file that does not parse or validate is
+//! logged and skipped, so one bad template never hides the others.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::models::ThoughtType;
+
+fn default_thought_type() -> String {
+    "question".to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateThought {
+    /// Handle later thoughts name as their `parent_ref`.
+    #[serde(default, rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    pub content: String,
+    /// Any thought type but `conclusion` and `recall`; default `question`.
+    #[serde(default = "default_thought_type", rename = "type")]
+    pub thought_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_ref: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReasoningTemplate {
+    /// Falls back to the file stem when the file names none.
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub thoughts: Vec<TemplateThought>,
+}
+
+impl ReasoningTemplate {
+    /// Check what [`crate::toolkit::fast_think::FastThinkManager::apply_template`]
+    /// relies on: a name, at least one thought, known types, unique refs and
+    /// every `parent_ref` naming an EARLIER thought (so the thoughts form a
+    /// tree in file order).
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("template has no name".to_string());
+        }
+        if self.thoughts.is_empty() {
+            return Err(format!("template '{}' has no thoughts", self.name));
+        }
+        let mut refs = HashSet::new();
+        for (i, t) in self.thoughts.iter().enumerate() {
+            if t.content.trim().is_empty() {
+                return Err(format!("thought {i} has no content"));
+            }
+            match ThoughtType::from_name(&t.thought_type) {
+                None => return Err(format!("thought {i}: unknown type '{}'", t.thought_type)),
+                Some(ThoughtType::Conclusion | ThoughtType::Recall) => {
+                    return Err(format!(
+                        "thought {i}: a template cannot hold a '{}' thought",
+                        t.thought_type
+                    ));
+                }
+                Some(_) => {}
+            }
+            if let Some(parent) = &t.parent_ref {
+                if !refs.contains(parent.as_str()) {
+                    return Err(format!(
+                        "thought {i}: parent_ref '{parent}' names no earlier thought"
+                    ));
+                }
+            }
+            if let Some(r) = &t.reference {
+                if !refs.insert(r.as_str()) {
+                    return Err(format!("thought {i}: duplicate ref '{r}'"));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Each thought's parent as a position in [`Self::thoughts`]; `None`
+    /// hangs it under the attach point. Only meaningful once validated.
+    pub fn parent_positions(&self) -> Vec<Option<usize>> {
+        self.thoughts
+            .iter()
+            .map(|t| {
+                t.parent_ref.as_ref().and_then(|parent| {
+                    self.thoughts
+                        .iter()
+                        .position(|o| o.reference.as_ref() == Some(parent))
+                })
+            })
+            .collect()
+    }
+
+    /// Levels below the attach point: 1 for a top-level thought.
+    pub fn depth(&self) -> usize {
+        let parents = self.parent_positions();
+        let mut levels = vec![0usize; parents.len()];
+        for (i, parent) in parents.iter().enumerate() {
+            levels[i] = parent.map_or(1, |p| levels[p] + 1);
+        }
+        levels.into_iter().max().unwrap_or(0)
+    }
+
+    /// Parse one template file; the format follows the extension.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let raw = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        let mut template: Self = match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => toml::from_str(&raw).map_err(|e| e.to_string()),
+            _ => serde_json::from_str(&raw).map_err(|e| e.to_string()),
+        }
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+        if template.name.trim().is_empty() {
+            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                template.name = stem.to_string();
+            }
+        }
+        template
+            .validate()
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(template)
+    }
+}
+
+/// Every valid template in `dir`, sorted by name. A missing directory reads
+/// as none; a bad file or a second template of the same name is skipped
+/// with a warning.
+pub fn load_templates(dir: &Path) -> Vec<ReasoningTemplate> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!("Reasoning templates: cannot read {}: {e}", dir.display());
+            return Vec::new();
+        }
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            matches!(
+                p.extension().and_then(|e| e.to_str()),
+                Some("json" | "toml")
+            )
+        })
+        .collect();
+    paths.sort();
+
+    let mut templates: Vec<ReasoningTemplate> = Vec::new();
+    for path in paths {
+        match ReasoningTemplate::from_file(&path) {
+            Ok(t) if templates.iter().any(|o| o.name == t.name) => warn!(
+                "Reasoning templates: {} repeats the name '{}'; skipped",
+                path.display(),
+                t.name
+            ),
+            Ok(t) => templates.push(t),
+            Err(e) => warn!("Reasoning templates: skipping {e}"),
+        }
+    }
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    info!(
+        "Loaded {} reasoning template(s) from {}",
+        templates.len(),
+        dir.display()
+    );
+    templates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thought(reference: Option<&str>, kind: &str, parent: Option<&str>) -> TemplateThought {
+        TemplateThought {
+            reference: reference.map(str::to_string),
+            content: format!("{kind} step"),
+            thought_type: kind.to_string(),
+            parent_ref: parent.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn validation_wants_earlier_parents_and_plain_types() {
+        let mut t = ReasoningTemplate {
+            name: "evaluate_library".to_string(),
+            description: None,
+            thoughts: vec![
+                thought(Some("need"), "question", None),
+                thought(Some("alt"), "hypothesis", Some("need")),
+                thought(None, "question", Some("alt")),
+            ],
+        };
+        assert_eq!(t.validate(), Ok(()));
+        assert_eq!(t.parent_positions(), [None, Some(0), Some(1)]);
+        assert_eq!(t.depth(), 3);
+
+        t.thoughts[0].parent_ref = Some("alt".to_string());
+        assert!(t.validate().unwrap_err().contains("no earlier thought"));
+        t.thoughts[0].parent_ref = None;
+        t.thoughts[2].thought_type = "conclusion".to_string();
+        assert!(t.validate().is_err());
+        t.thoughts[2].thought_type = "musing".to_string();
+        assert!(t.validate().unwrap_err().contains("unknown type"));
+    }
+
+    #[test]
+    fn a_directory_loads_json_and_toml_and_skips_bad_files() {
+        let dir = std::env::temp_dir().join(format!("helixir-templates-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("evaluate_library.json"),
+            r#"{"thoughts": [
+                {"ref": "need", "content": "What problem must the library solve?"},
+                {"content": "Is it maintained?", "type": "hypothesis", "parent_ref": "need"}
+            ]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("postmortem.toml"),
+            "name = \"incident_review\"\n[[thoughts]]\ncontent = \"What broke first?\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("broken.json"), r#"{"thoughts": []}"#).unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a template").unwrap();
+
+        let loaded = load_templates(&dir);
+        let names: Vec<_> = loaded.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["evaluate_library", "incident_review"]);
+        assert_eq!(loaded[0].thoughts[0].thought_type, "question");
+        assert!(load_templates(&dir.join("missing")).is_empty());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,8 +1,13 @@
 //! [`EntityManager`] — cache-fronted CRUD + linking against HelixDB entities.
 //!
 //! The manager owns two synchronized in-memory maps (`entity_id → Entity` and
-//! `name → entity_id`) and falls back to HelixDB on miss. Eviction is naive
-//! (drop the first entry in iteration order) — see TODO inside [`Self::add_to_cache`].
+//! `identity key → entity_id`) and falls back to HelixDB on miss. Eviction is
+//! naive (drop the first entry in iteration order) — see TODO inside
+//! [`Self::add_to_cache`].
+//!
+//! The identity key is the lowercased name, or `type:name` under
+//! [`EntityIdentity::NameAndType`], so same-named entities of different
+//! types resolve to different nodes.
 
 use parking_lot::RwLock;
 use serde::Deserialize;
@@ -10,7 +15,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
+use crate::core::config::EntityIdentity;
 use crate::db::HelixClient;
+use crate::utils::nullable_string;
 
 use super::error::EntityError;
 use super::types::{Entity, EntityDbResponse, EntityEdgeType, EntityType};
@@ -22,6 +29,7 @@ pub struct EntityManager {
 
     name_to_id: RwLock<HashMap<String, String>>,
     cache_size: usize,
+    identity: EntityIdentity,
 }
 
 impl EntityManager {
@@ -32,6 +40,21 @@ impl EntityManager {
             entity_cache: RwLock::new(HashMap::new()),
             name_to_id: RwLock::new(HashMap::new()),
             cache_size,
+            identity: EntityIdentity::Name,
+        }
+    }
+
+    pub fn with_identity(mut self, identity: EntityIdentity) -> Self {
+        self.identity = identity;
+        self
+    }
+
+    /// Cache key of an entity named `name` of type `entity_type`.
+    fn identity_key(&self, name: &str, entity_type: &EntityType) -> String {
+        let name = name.trim().to_lowercase();
+        match self.identity {
+            EntityIdentity::Name => name,
+            EntityIdentity::NameAndType => format!("{entity_type}:{name}"),
         }
     }
 
@@ -42,14 +65,17 @@ impl EntityManager {
         if cache.len() >= self.cache_size {
             if let Some(oldest_id) = cache.keys().next().cloned() {
                 if let Some(evicted) = cache.remove(&oldest_id) {
-                    name_map.remove(&evicted.name.to_lowercase());
+                    name_map.remove(&self.identity_key(&evicted.name, &evicted.entity_type));
                     debug!("Cache eviction: {} (size: {})", oldest_id, self.cache_size);
                 }
             }
         }
 
         cache.insert(entity.entity_id.clone(), entity.clone());
-        name_map.insert(entity.name.to_lowercase(), entity.entity_id.clone());
+        name_map.insert(
+            self.identity_key(&entity.name, &entity.entity_type),
+            entity.entity_id.clone(),
+        );
     }
 
     pub async fn create_entity(
@@ -151,17 +177,156 @@ impl EntityManager {
         }
     }
 
+    /// Look an entity up by its exact stored name; the cache is keyed
+    /// case-insensitively, so a cached hit also matches other casings.
+    /// Under typed identity the name alone is not a cache key, and the first
+    /// stored entity of that name is returned.
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<Entity>, EntityError> {
+        let name = name.trim();
+        if self.identity == EntityIdentity::Name {
+            let name_map = self.name_to_id.read();
+            if let Some(entity_id) = name_map.get(&name.to_lowercase()) {
+                if let Some(entity) = self.entity_cache.read().get(entity_id) {
+                    return Ok(Some(entity.clone()));
+                }
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct EntityByNameResult {
+            entity: Option<EntityDbResponse>,
+        }
+
+        match self
+            .client
+            .execute_query::<EntityByNameResult, _>(
+                "getEntityByName",
+                &serde_json::json!({"name": name}),
+            )
+            .await
+        {
+            Ok(EntityByNameResult {
+                entity: Some(db_entity),
+            }) => {
+                let entity: Entity = db_entity.into();
+                self.add_to_cache(&entity);
+                Ok(Some(entity))
+            }
+            Ok(_) => Ok(None),
+            // FIRST on no match surfaces as a query error.
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => Ok(None),
+            Err(e) => Err(EntityError::Database(e.to_string())),
+        }
+    }
+
+    /// Set (`Some`) or clear (`None`) one attribute `user_id` keeps on the
+    /// entity. Entities are shared, so each user's attributes live on their
+    /// own `EntityAttributes` node and the entity itself is not written.
+    /// Returns that user's attributes as updated.
+    pub async fn set_property(
+        &self,
+        entity_id: &str,
+        user_id: &str,
+        key: &str,
+        value: Option<serde_json::Value>,
+    ) -> Result<HashMap<String, serde_json::Value>, EntityError> {
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(EntityError::Validation(
+                "Attribute key cannot be empty".into(),
+            ));
+        }
+        let entity = self
+            .get_entity(entity_id)
+            .await?
+            .ok_or_else(|| EntityError::NotFound(entity_id.to_string()))?;
+
+        #[derive(Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            entity_attributes: Option<AttributesNode>,
+        }
+        let params = serde_json::json!({ "entity_id": entity_id, "user_id": user_id });
+        let stored = match self
+            .client
+            .execute_query::<Resp, _>("getEntityAttributes", &params)
+            .await
+        {
+            Ok(resp) => resp.entity_attributes.filter(|n| !n.user_id.is_empty()),
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => None,
+            Err(e) => return Err(EntityError::Database(e.to_string())),
+        };
+        let exists = stored.is_some();
+        let mut attributes = stored.map(AttributesNode::parse).unwrap_or_default();
+        match value {
+            Some(v) => {
+                attributes.insert(key.to_string(), v);
+            }
+            None => {
+                attributes.remove(key);
+            }
+        }
+
+        self.client
+            .execute_query::<serde_json::Value, _>(
+                if exists {
+                    "updateEntityAttributes"
+                } else {
+                    "addEntityAttributes"
+                },
+                &serde_json::json!({
+                    "entity_id": entity_id,
+                    "user_id": user_id,
+                    "attributes": serde_json::to_string(&attributes).unwrap_or_default(),
+                    "updated_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            )
+            .await
+            .map_err(|e| EntityError::Database(e.to_string()))?;
+        debug!(
+            "Entity {} attribute '{}' updated for {}",
+            entity.name, key, user_id
+        );
+        Ok(attributes)
+    }
+
+    /// Every attribute `user_id` set, by entity id.
+    pub async fn user_properties(
+        &self,
+        user_id: &str,
+    ) -> Result<HashMap<String, HashMap<String, serde_json::Value>>, EntityError> {
+        #[derive(Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            entity_attributes: Vec<AttributesNode>,
+        }
+        let resp: Resp = self
+            .client
+            .execute_query(
+                "getUserEntityAttributes",
+                &serde_json::json!({ "user_id": user_id }),
+            )
+            .await
+            .map_err(|e| EntityError::Database(e.to_string()))?;
+        Ok(resp
+            .entity_attributes
+            .into_iter()
+            .map(|n| (n.entity_id.clone(), n.parse()))
+            .collect())
+    }
+
     pub async fn get_or_create_entity(
         &self,
         name: &str,
         entity_type: &str,
         properties: Option<HashMap<String, serde_json::Value>>,
     ) -> Result<Entity, EntityError> {
-        let normalized_name = name.trim().to_lowercase();
+        let typed = EntityType::from(entity_type);
+        let key = self.identity_key(name, &typed);
 
         {
             let name_map = self.name_to_id.read();
-            if let Some(entity_id) = name_map.get(&normalized_name) {
+            if let Some(entity_id) = name_map.get(&key) {
                 let cache = self.entity_cache.read();
                 if let Some(entity) = cache.get(entity_id) {
                     debug!("Entity found in cache: {}", name);
@@ -175,12 +340,16 @@ impl EntityManager {
             entity: Option<EntityDbResponse>,
         }
 
+        let (query, params) = match self.identity {
+            EntityIdentity::Name => ("getEntityByName", serde_json::json!({"name": name})),
+            EntityIdentity::NameAndType => (
+                "getEntityByNameAndType",
+                serde_json::json!({"name": name, "entity_type": typed.to_string()}),
+            ),
+        };
         match self
             .client
-            .execute_query::<EntityByNameResult, _>(
-                "getEntityByName",
-                &serde_json::json!({"name": name}),
-            )
+            .execute_query::<EntityByNameResult, _>(query, &params)
             .await
         {
             Ok(result) => {
@@ -340,3 +509,116 @@ impl std::fmt::Debug for EntityManager {
         )
     }
 }
+
+/// One user's `EntityAttributes` node.
+#[derive(Deserialize)]
+struct AttributesNode {
+    #[serde(default, deserialize_with = "nullable_string")]
+    entity_id: String,
+    #[serde(default, deserialize_with = "nullable_string")]
+    user_id: String,
+    #[serde(default, deserialize_with = "nullable_string")]
+    attributes: String,
+}
+
+impl AttributesNode {
+    fn parse(self) -> HashMap<String, serde_json::Value> {
+        serde_json::from_str(&self.attributes).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::InMemoryStore;
+
+    #[tokio::test]
+    async fn attributes_persist_per_user_and_clear() {
+        let store = Arc::new(InMemoryStore::new());
+        let client = Arc::new(HelixClient::with_store(Arc::clone(&store) as _));
+        let manager = EntityManager::new(Arc::clone(&client), 16);
+        let alice = manager
+            .create_entity("Alice", "person", None)
+            .await
+            .unwrap();
+
+        manager
+            .set_property(&alice.entity_id, "u1", "role", Some("CTO".into()))
+            .await
+            .unwrap();
+        let updated = manager
+            .set_property(&alice.entity_id, "u1", "team", Some("platform".into()))
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 2);
+        manager
+            .set_property(&alice.entity_id, "u2", "role", Some("advisor".into()))
+            .await
+            .unwrap();
+
+        // A fresh manager has no cache: the attributes come from the store.
+        let cold = EntityManager::new(client, 16);
+        let read = cold.find_by_name("Alice").await.unwrap().unwrap();
+        assert!(read.properties.is_empty());
+        let u1 = cold.user_properties("u1").await.unwrap();
+        assert_eq!(u1[&read.entity_id]["role"], "CTO");
+        let u2 = cold.user_properties("u2").await.unwrap();
+        assert_eq!(u2[&read.entity_id]["role"], "advisor");
+        let cleared = cold
+            .set_property(&read.entity_id, "u1", "role", None)
+            .await
+            .unwrap();
+        assert!(!cleared.contains_key("role"));
+        assert_eq!(cleared["team"], "platform");
+        assert!(cold.user_properties("u3").await.unwrap().is_empty());
+
+        assert!(cold.find_by_name("Bob").await.unwrap().is_none());
+        assert!(matches!(
+            cold.set_property(&read.entity_id, "u1", " ", None).await,
+            Err(EntityError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn typed_identity_keeps_same_named_entities_of_different_types_apart() {
+        let store = Arc::new(InMemoryStore::new());
+        let client = Arc::new(HelixClient::with_store(Arc::clone(&store) as _));
+        let typed =
+            EntityManager::new(Arc::clone(&client), 16).with_identity(EntityIdentity::NameAndType);
+        let language = typed
+            .get_or_create_entity("Python", "technology", None)
+            .await
+            .unwrap();
+        let snake = typed
+            .get_or_create_entity("Python", "concept", None)
+            .await
+            .unwrap();
+        assert_ne!(language.entity_id, snake.entity_id);
+        let again = typed
+            .get_or_create_entity("python", "technology", None)
+            .await
+            .unwrap();
+        assert_eq!(again.entity_id, language.entity_id);
+
+        // A cold typed manager resolves each from the store by name and type.
+        let cold =
+            EntityManager::new(Arc::clone(&client), 16).with_identity(EntityIdentity::NameAndType);
+        let read = cold
+            .get_or_create_entity("Python", "concept", None)
+            .await
+            .unwrap();
+        assert_eq!(read.entity_id, snake.entity_id);
+
+        // Name-only identity (the default) merges them.
+        let by_name = EntityManager::new(client, 16);
+        let a = by_name
+            .get_or_create_entity("Kafka", "technology", None)
+            .await
+            .unwrap();
+        let b = by_name
+            .get_or_create_entity("Kafka", "person", None)
+            .await
+            .unwrap();
+        assert_eq!(a.entity_id, b.entity_id);
+    }
+}
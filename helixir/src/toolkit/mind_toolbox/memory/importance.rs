@@ -0,0 +1,281 @@
+//! Save-worthiness heuristics: the `<importance_filter>` of the system
+//! prompt (decisions and outcomes always, tool output never) applied in
+//! code, so `should_remember` advises the same way every time instead of
+//! depending on how closely a model follows the prompt.
+//!
+//! Pure text scoring only. The "does a similar memory exist" step needs a
+//! search and lives with the caller.
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Importance {
+    Low,
+    Medium,
+    High,
+}
+
+impl Importance {
+    fn lower(self) -> Self {
+        match self {
+            Self::High => Self::Medium,
+            _ => Self::Low,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportanceAssessment {
+    pub importance: Importance,
+    pub recommend: bool,
+    pub reason: String,
+    /// Which heuristics fired (`decision`, `outcome`, `technical_noise`, ...).
+    pub signals: Vec<&'static str>,
+}
+
+/// Keyword families of the prompt's ALWAYS SAVE list.
+const HIGH_SIGNALS: &[(&str, &[&str])] = &[
+    (
+        "decision",
+        &[
+            "decided",
+            "decide to",
+            "chose",
+            "chosen",
+            "will use",
+            "selected",
+            "going with",
+            "settled on",
+            "switch to",
+            "switched to",
+        ],
+    ),
+    (
+        "outcome",
+        &[
+            "completed",
+            "works",
+            "worked",
+            "failed",
+            "fixed",
+            "resolved",
+            "broke",
+            "broken",
+            "shipped",
+            "deployed",
+            "root cause",
+        ],
+    ),
+    (
+        "preference",
+        &[
+            "i prefer",
+            "prefers",
+            "i like",
+            "i don't like",
+            "i hate",
+            "always use",
+            "never use",
+            "please always",
+            "please never",
+        ],
+    ),
+    (
+        "architecture",
+        &[
+            "endpoint",
+            "schema",
+            "config",
+            "dependency",
+            "depends on",
+            "version",
+            "architecture",
+            "constraint",
+            "must not",
+        ],
+    ),
+];
+
+/// The MAYBE SAVE list.
+const MEDIUM_SIGNALS: &[(&str, &[&str])] = &[(
+    "tentative",
+    &[
+        "hypothesis",
+        "assume",
+        "assumption",
+        "maybe",
+        "might",
+        "milestone",
+        "alternative",
+        "considered",
+        "plan to",
+        "next step",
+    ],
+)];
+
+/// Marks a fact as short-lived: one importance level down.
+const TEMPORARY: &[&str] = &[
+    "temporary",
+    "temporarily",
+    "for now",
+    "debugging",
+    "debug print",
+    "scratch",
+    "tmp",
+];
+
+/// Share of non-blank lines that must look like tool output for the whole
+/// message to count as technical noise.
+const NOISE_LINE_SHARE: f32 = 0.5;
+
+/// Score `message` against the prompt's importance filter.
+pub fn assess(message: &str) -> ImportanceAssessment {
+    let text = message.trim();
+    if text.split_whitespace().count() < 3 {
+        return verdict(
+            Importance::Low,
+            vec!["too_short"],
+            "too short to carry a durable fact",
+        );
+    }
+    let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+    let noisy = lines.iter().filter(|l| is_noise_line(l)).count();
+    if noisy as f32 >= lines.len() as f32 * NOISE_LINE_SHARE {
+        return verdict(
+            Importance::Low,
+            vec!["technical_noise"],
+            "looks like tool output (search hits, compiler/lint output, traces or diffs); save the conclusion you drew from it instead",
+        );
+    }
+
+    let lower = text.to_lowercase();
+    let mut signals = Vec::new();
+    for (name, phrases) in HIGH_SIGNALS {
+        if phrases.iter().any(|p| has_phrase(&lower, p)) {
+            signals.push(*name);
+        }
+    }
+    let mut importance = if signals.is_empty() {
+        for (name, phrases) in MEDIUM_SIGNALS {
+            if phrases.iter().any(|p| has_phrase(&lower, p)) {
+                signals.push(*name);
+            }
+        }
+        Importance::Medium
+    } else {
+        Importance::High
+    };
+    if TEMPORARY.iter().any(|p| has_phrase(&lower, p)) {
+        signals.push("temporary");
+        importance = importance.lower();
+    }
+
+    let reason = match importance {
+        Importance::High => format!("carries a {}", signals[0]),
+        Importance::Medium if signals.contains(&"temporary") => {
+            format!("carries a {} but is marked temporary", signals[0])
+        }
+        Importance::Medium if signals.is_empty() => {
+            "no decision or outcome; save only if it will still matter in a week".to_string()
+        }
+        Importance::Medium => "tentative; worth keeping if it is validated later".to_string(),
+        Importance::Low => "temporary information; skip unless it outlives the task".to_string(),
+    };
+    verdict(importance, signals, &reason)
+}
+
+fn verdict(
+    importance: Importance,
+    signals: Vec<&'static str>,
+    reason: &str,
+) -> ImportanceAssessment {
+    ImportanceAssessment {
+        importance,
+        recommend: importance > Importance::Low,
+        reason: reason.to_string(),
+        signals,
+    }
+}
+
+/// `phrase` occurs in `text` on word boundaries.
+fn has_phrase(text: &str, phrase: &str) -> bool {
+    text.match_indices(phrase).any(|(at, _)| {
+        let before = text[..at].chars().next_back();
+        let after = text[at + phrase.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// One line of grep hits, compiler or lint output, a stack trace or a diff.
+fn is_noise_line(line: &str) -> bool {
+    let t = line.trim();
+    let grep_hit = t
+        .split(':')
+        .nth(1)
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        && t.split(':')
+            .next()
+            .is_some_and(|p| p.contains('.') || p.contains('/'));
+    let compiler = ["warning:", "error:", "error[", "note:", "help:", "-->"]
+        .iter()
+        .any(|p| t.starts_with(p));
+    let trace = t.starts_with("at ") && t.contains('(')
+        || t.starts_with("Traceback")
+        || t.starts_with("File \"");
+    let diff = t.starts_with("@@") || t.starts_with("+++") || t.starts_with("---");
+    let symbols = t.chars().filter(|c| c.is_ascii_punctuation()).count();
+    let symbol_heavy = t.len() >= 20 && symbols * 3 > t.chars().count();
+    grep_hit || compiler || trace || diff || symbol_heavy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decisions_and_outcomes_are_high() {
+        let a = assess("We decided to use Postgres instead of MySQL for the billing service.");
+        assert_eq!(a.importance, Importance::High);
+        assert!(a.recommend);
+        assert_eq!(a.signals, vec!["decision"]);
+
+        let b = assess("The flaky login test is fixed: the token clock skew was the root cause.");
+        assert_eq!(b.importance, Importance::High);
+        assert!(b.signals.contains(&"outcome"));
+    }
+
+    #[test]
+    fn tool_output_and_fragments_are_low() {
+        let grep = "src/main.rs:12: let x = 1;\nsrc/lib.rs:40: fn run() {}\nsrc/db.rs:7: use sqlx;";
+        let a = assess(grep);
+        assert_eq!(a.importance, Importance::Low);
+        assert!(!a.recommend);
+        assert_eq!(a.signals, vec!["technical_noise"]);
+
+        let lint = "warning: unused variable `x`\n --> src/main.rs:3:9\nhelp: prefix it with an underscore";
+        assert_eq!(assess(lint).signals, vec!["technical_noise"]);
+        assert_eq!(assess("ok thanks").signals, vec!["too_short"]);
+    }
+
+    #[test]
+    fn tentative_and_temporary_lower_the_level() {
+        let a = assess("My assumption is that the cache layer is the bottleneck here.");
+        assert_eq!(a.importance, Importance::Medium);
+        assert_eq!(a.signals, vec!["tentative"]);
+
+        let b = assess("For now I switched to the staging database while debugging the import.");
+        assert_eq!(b.importance, Importance::Medium);
+        assert_eq!(b.signals, vec!["decision", "temporary"]);
+
+        let c = assess("The sandbox lives on the 4th floor rack, temporary setup.");
+        assert_eq!(c.importance, Importance::Low);
+
+        // Word boundaries: "networks" is not "works".
+        assert!(
+            !assess("The office networks are segmented by floor.")
+                .signals
+                .contains(&"outcome")
+        );
+    }
+}
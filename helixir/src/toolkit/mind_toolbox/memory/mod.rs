@@ -1,13 +1,16 @@
 pub mod context;
 pub mod crud;
 pub mod evolution;
+pub mod importance;
 pub mod models;
+pub mod references;
 pub mod retrieval;
 
 pub use context::{ContextDef, ContextError, ContextManager};
 pub use crud::{CrudError, MemoryCrud, NewMemory};
 pub use evolution::{EvolutionError, EvolutionResult, MemoryEvolution};
 pub use models::{Context, Entity, EntityType, Memory, MemoryBuilder, MemoryStats};
+pub use references::MemoryReference;
 pub use retrieval::{RetrievalDepth, RetrievalError, RetrievalManager, RetrievalResult};
 
 use crate::db::HelixClient;
@@ -0,0 +1,101 @@
+//! External references on a memory: the file or document a fact came from
+//! (`{uri, locator, title}`), kept under `references` in the node's JSON
+//! `metadata` string so no schema migration is needed.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryReference {
+    /// Where the artifact lives: a path, URL or any caller-chosen id.
+    pub uri: String,
+    /// Position inside it ("§3", "L120-L140", "page 4").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl MemoryReference {
+    /// Stable id for graph rendering: same uri + locator → same node.
+    pub fn node_id(&self) -> String {
+        match self.locator.as_deref() {
+            Some(loc) => format!("ref:{}#{}", self.uri, loc),
+            None => format!("ref:{}", self.uri),
+        }
+    }
+}
+
+/// References stored in a memory's `metadata`. Anything unparseable
+/// (legacy "", non-JSON, no `references` key) reads as none.
+pub fn references_in(metadata: &str) -> Vec<MemoryReference> {
+    serde_json::from_str::<serde_json::Value>(metadata)
+        .ok()
+        .and_then(|v| v.get("references").cloned())
+        .and_then(|r| serde_json::from_value(r).ok())
+        .unwrap_or_default()
+}
+
+/// `metadata` with `refs` appended to its `references`, skipping entries
+/// whose uri + locator are already present. Other metadata keys survive; a
+/// non-object (or unparseable) metadata string is replaced.
+pub fn merge_references(metadata: &str, refs: &[MemoryReference]) -> String {
+    let mut root = match serde_json::from_str::<serde_json::Value>(metadata) {
+        Ok(v @ serde_json::Value::Object(_)) => v,
+        _ => serde_json::json!({}),
+    };
+    let mut merged = references_in(metadata);
+    for r in refs {
+        if r.uri.trim().is_empty() {
+            continue;
+        }
+        if !merged
+            .iter()
+            .any(|m| m.uri == r.uri && m.locator == r.locator)
+        {
+            merged.push(r.clone());
+        }
+    }
+    root["references"] = serde_json::to_value(&merged).unwrap_or_default();
+    root.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(locator: Option<&str>) -> MemoryReference {
+        MemoryReference {
+            uri: "design_doc.md".to_string(),
+            locator: locator.map(str::to_string),
+            title: Some("Design".to_string()),
+        }
+    }
+
+    #[test]
+    fn merge_keeps_other_keys_and_dedups_by_uri_and_locator() {
+        let meta = r#"{"origin":"import"}"#;
+        let once = merge_references(meta, &[doc(Some("§3"))]);
+        let twice = merge_references(&once, &[doc(Some("§3")), doc(Some("§4"))]);
+
+        let v: serde_json::Value = serde_json::from_str(&twice).unwrap();
+        assert_eq!(v["origin"], "import");
+        assert_eq!(
+            references_in(&twice),
+            vec![doc(Some("§3")), doc(Some("§4"))]
+        );
+    }
+
+    #[test]
+    fn legacy_metadata_reads_as_no_references() {
+        for meta in ["", "{}", "not json", r#"{"references":"x"}"#] {
+            assert!(references_in(meta).is_empty(), "{meta:?}");
+        }
+        assert_eq!(references_in(&merge_references("", &[doc(None)])).len(), 1);
+    }
+
+    #[test]
+    fn node_id_includes_the_locator_when_present() {
+        assert_eq!(doc(None).node_id(), "ref:design_doc.md");
+        assert_eq!(doc(Some("§3")).node_id(), "ref:design_doc.md#§3");
+    }
+}
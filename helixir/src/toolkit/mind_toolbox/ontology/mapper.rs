@@ -21,6 +21,23 @@ pub enum ConceptType {
     Achievement,
 }
 
+impl ConceptType {
+    /// Case-insensitive, as `concept_keywords.toml` names them.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "preference" => Some(Self::Preference),
+            "skill" => Some(Self::Skill),
+            "goal" => Some(Self::Goal),
+            "opinion" => Some(Self::Opinion),
+            "fact" => Some(Self::Fact),
+            "action" => Some(Self::Action),
+            "experience" => Some(Self::Experience),
+            "achievement" => Some(Self::Achievement),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TextConcept {
     pub id: String,
@@ -93,7 +110,12 @@ lazy_static! {
     };
 }
 
-pub struct ConceptMapper;
+/// Keyword mapper; the built-in English keywords plus any a locale adds
+/// (see `core::locale`).
+#[derive(Debug, Clone)]
+pub struct ConceptMapper {
+    extra_keywords: HashMap<ConceptType, Vec<String>>,
+}
 
 impl Default for ConceptMapper {
     fn default() -> Self {
@@ -104,7 +126,26 @@ impl Default for ConceptMapper {
 impl ConceptMapper {
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            extra_keywords: HashMap::new(),
+        }
+    }
+
+    /// Add keywords per concept type name; unknown names are logged and
+    /// skipped.
+    #[must_use]
+    pub fn with_extra_keywords(mut self, extra: &HashMap<String, Vec<String>>) -> Self {
+        for (name, words) in extra {
+            match ConceptType::from_name(name) {
+                Some(concept_type) => self
+                    .extra_keywords
+                    .entry(concept_type)
+                    .or_default()
+                    .extend(words.iter().map(|w| w.to_lowercase())),
+                None => tracing::warn!("Concept keywords: unknown concept type '{name}'"),
+            }
+        }
+        self
     }
 
     #[must_use]
@@ -112,7 +153,16 @@ impl ConceptMapper {
         let text_lower = text.to_lowercase();
         let mut matches: Vec<ConceptMatch> = Vec::new();
 
-        for (concept_type, keywords) in CONCEPT_KEYWORDS.iter() {
+        for (concept_type, builtin) in CONCEPT_KEYWORDS.iter() {
+            let extra = self
+                .extra_keywords
+                .get(concept_type)
+                .map_or(&[][..], Vec::as_slice);
+            let keywords: Vec<&str> = builtin
+                .iter()
+                .copied()
+                .chain(extra.iter().map(String::as_str))
+                .collect();
             let matched: Vec<String> = keywords
                 .iter()
                 .filter(|kw| text_lower.contains(*kw))
@@ -184,4 +234,17 @@ mod tests {
 
         assert_eq!(matches1.len(), matches2.len());
     }
+
+    #[test]
+    fn locale_keywords_extend_the_english_ones() {
+        let extra = HashMap::from([
+            ("goal".to_string(), vec!["Хочу".to_string()]),
+            ("mood".to_string(), vec!["грустно".to_string()]),
+        ]);
+        let mapper = ConceptMapper::new().with_extra_keywords(&extra);
+        let matches = mapper.map_to_concepts("Я ХОЧУ выучить Rust", 3);
+        assert_eq!(matches[0].concept.concept_type, ConceptType::Goal);
+        assert_eq!(matches[0].matched_keywords, ["хочу"]);
+        assert!(!mapper.map_to_concepts("I want tea", 3).is_empty());
+    }
 }
@@ -58,6 +58,14 @@ impl OntologyManager {
         }
     }
 
+    /// Extend the concept mapper's keywords (a locale's
+    /// `concept_keywords.toml`).
+    #[must_use]
+    pub fn with_concept_keywords(mut self, extra: &HashMap<String, Vec<String>>) -> Self {
+        self.mapper = self.mapper.with_extra_keywords(extra);
+        self
+    }
+
     pub async fn load(&mut self) -> Result<(), OntologyError> {
         info!("Loading ontology");
         let (concepts, relations) = self.loader.load_base_ontology().await?;
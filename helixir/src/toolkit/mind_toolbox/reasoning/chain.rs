@@ -10,6 +10,11 @@
 //!   candidate whose content embedding is closest to the query embedding.
 //!   Embeddings come from the (persistent) cache, so this path makes **zero**
 //!   LLM calls and usually zero embedding HTTP calls.
+//!
+//! BECAUSE/IMPLIES can loop, so every memory joins a chain at most once —
+//! a hop back to a node already in the chain is dropped, not re-walked —
+//! and a chain holds at most [`MAX_CHAIN_NODES`] memories. Hitting the cap
+//! sets `truncated` on the result.
 
 use serde::Deserialize;
 use tracing::{debug, warn};
@@ -19,6 +24,10 @@ use super::types::{ReasoningChain, ReasoningError, ReasoningType, project_relati
 use crate::llm::EmbeddingGenerator;
 use crate::toolkit::mind_toolbox::search::smart_traversal::cosine_score;
 
+/// Upper bound on memories (seed included) one chain may collect, so a
+/// dense `deep` walk stays bounded whatever `max_depth` asks for.
+pub const MAX_CHAIN_NODES: usize = 64;
+
 /// Query context for embedding-guided traversal (algo_opt R3).
 pub struct ChainGuidance<'a> {
     pub query_embedding: &'a [f32],
@@ -66,6 +75,10 @@ impl ReasoningEngine {
 
         let mut relations = Vec::new();
         let mut visited = std::collections::HashSet::new();
+        // Everything already in the chain (expanded or queued): a candidate in
+        // here would repeat a node, and on a cycle would walk it again.
+        let mut seen = std::collections::HashSet::from([memory_id.to_string()]);
+        let mut truncated = false;
         // Frontier carries `(memory_id, content, depth)` so projection helpers
         // can pair `to_memory_id` with the matching `to_memory_content` regardless
         // of edge direction. See #17.
@@ -82,6 +95,9 @@ impl ReasoningEngine {
         } else {
             frontier.pop_back()
         } {
+            if truncated {
+                break;
+            }
             if current_depth >= effective_max_depth || visited.contains(&current_id) {
                 continue;
             }
@@ -166,7 +182,7 @@ impl ReasoningEngine {
 
             let mut unvisited: Vec<_> = candidates
                 .into_iter()
-                .filter(|(n, _, _)| !visited.contains(&n.memory_id))
+                .filter(|(n, _, _)| !seen.contains(&n.memory_id))
                 .collect();
 
             if unvisited.is_empty() {
@@ -191,6 +207,16 @@ impl ReasoningEngine {
 
             if is_deep {
                 for (node, relation_type, is_incoming) in &unvisited {
+                    if seen.contains(&node.memory_id) {
+                        // Reached twice from this node (e.g. IMPLIES out and
+                        // BECAUSE in): keep the first hop only.
+                        continue;
+                    }
+                    if seen.len() >= MAX_CHAIN_NODES {
+                        truncated = true;
+                        break;
+                    }
+                    seen.insert(node.memory_id.clone());
                     relations.push(project_relation(
                         &current_id,
                         &current_content,
@@ -277,6 +303,11 @@ impl ReasoningEngine {
                 };
 
                 if let Some((node, relation_type, is_incoming)) = best {
+                    if seen.len() >= MAX_CHAIN_NODES {
+                        truncated = true;
+                        break;
+                    }
+                    seen.insert(node.memory_id.clone());
                     relations.push(project_relation(
                         &current_id,
                         &current_content,
@@ -300,11 +331,12 @@ impl ReasoningEngine {
         let reasoning_trail = self.build_reasoning_trail(&relations);
 
         debug!(
-            "Chain traversal for {}: type={}, relations={}, visited={}",
+            "Chain traversal for {}: type={}, relations={}, visited={}, truncated={}",
             crate::safe_truncate(memory_id, 12),
             chain_type,
             relations.len(),
-            visited.len()
+            visited.len(),
+            truncated
         );
 
         Ok(ReasoningChain {
@@ -313,6 +345,110 @@ impl ReasoningEngine {
             chain_type: chain_type.to_string(),
             depth: max_depth_reached,
             reasoning_trail,
+            truncated,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::db::{HelixClient, InMemoryStore, MemoryStore};
+
+    async fn memory(store: &InMemoryStore, id: &str) {
+        store
+            .query(
+                "addMemory",
+                json!({
+                    "memory_id": id, "user_id": "u", "content": format!("content of {id}"),
+                    "memory_type": "fact", "certainty": 80, "importance": 50,
+                    "created_at": "2026-01-01T00:00:00Z", "updated_at": "2026-01-01T00:00:00Z",
+                    "context_tags": "", "source": "test", "metadata": "{}",
+                }),
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn edge(store: &InMemoryStore, query: &str, from: &str, to: &str) {
+        let weight = if query == "addMemoryImplication" {
+            "probability"
+        } else {
+            "strength"
+        };
+        store
+            .query(
+                query,
+                json!({"from_id": from, "to_id": to, weight: 80, "reasoning_id": ""}),
+            )
+            .await
+            .unwrap();
+    }
+
+    fn engine(store: Arc<InMemoryStore>) -> ReasoningEngine {
+        ReasoningEngine::new(Arc::new(HelixClient::with_store(store)), None, 16)
+    }
+
+    fn peers(chain: &ReasoningChain) -> Vec<&str> {
+        chain
+            .relations
+            .iter()
+            .map(|r| r.peer_memory_id.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn cyclic_graph_terminates_without_repeating_nodes() {
+        let store = Arc::new(InMemoryStore::new());
+        for id in ["mem_a", "mem_b", "mem_c", "mem_d"] {
+            memory(&store, id).await;
+        }
+        // a → b → c → a, plus a second edge a ← b and a c ↔ d loop.
+        edge(&store, "addMemoryImplication", "mem_a", "mem_b").await;
+        edge(&store, "addMemoryImplication", "mem_b", "mem_c").await;
+        edge(&store, "addMemoryImplication", "mem_c", "mem_a").await;
+        edge(&store, "addMemoryCausation", "mem_b", "mem_a").await;
+        edge(&store, "addMemoryCausation", "mem_c", "mem_d").await;
+        edge(&store, "addMemoryCausation", "mem_d", "mem_c").await;
+        let engine = engine(store);
+
+        for mode in ["deep", "both", "causal", "forward"] {
+            let chain = engine
+                .get_chain("mem_a", "content of mem_a", mode, 50, None)
+                .await
+                .unwrap();
+            let ids = peers(&chain);
+            let unique: HashSet<_> = ids.iter().copied().collect();
+            assert_eq!(unique.len(), ids.len(), "{mode}: {ids:?}");
+            assert!(!unique.contains("mem_a"), "{mode}: {ids:?}");
+            assert!(!chain.truncated, "{mode}");
+        }
+        let deep = engine
+            .get_chain("mem_a", "content of mem_a", "deep", 50, None)
+            .await
+            .unwrap();
+        assert_eq!(deep.relations.len(), 3, "{:?}", peers(&deep));
+    }
+
+    #[tokio::test]
+    async fn dense_graph_stops_at_the_node_cap() {
+        let store = Arc::new(InMemoryStore::new());
+        memory(&store, "mem_hub").await;
+        for i in 0..MAX_CHAIN_NODES + 6 {
+            let leaf = format!("mem_leaf{i}");
+            memory(&store, &leaf).await;
+            edge(&store, "addMemoryImplication", "mem_hub", &leaf).await;
+        }
+        let chain = engine(store)
+            .get_chain("mem_hub", "hub", "deep", 5, None)
+            .await
+            .unwrap();
+        assert!(chain.truncated);
+        assert_eq!(chain.relations.len(), MAX_CHAIN_NODES - 1);
+    }
+}
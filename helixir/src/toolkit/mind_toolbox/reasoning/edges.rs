@@ -1,6 +1,9 @@
 //! Reasoning-edge CRUD: [`ReasoningEngine::add_relation`] and the
 //! private [`ReasoningEngine::edge_exists`] dedup check.
 
+/// `created_by` of the relations [`ReasoningEngine::add_relation`] writes.
+const REASONING_ENGINE_AUTHOR: &str = "reasoning_engine";
+
 use serde::Deserialize;
 use tracing::{debug, warn};
 
@@ -15,6 +18,29 @@ impl ReasoningEngine {
         relation_type: ReasoningType,
         strength: i32,
         reasoning_id: Option<&str>,
+    ) -> Result<ReasoningRelation, ReasoningError> {
+        self.add_relation_by(
+            from_id,
+            to_id,
+            relation_type,
+            strength,
+            reasoning_id,
+            REASONING_ENGINE_AUTHOR,
+        )
+        .await
+    }
+
+    /// [`Self::add_relation`] recording `created_by` as the writer, so a
+    /// caller can find its own edges again. Only the MEMORY_RELATION types
+    /// (SUPPORTS, RELATES_TO, PART_OF, IS_A) store it.
+    pub async fn add_relation_by(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        relation_type: ReasoningType,
+        strength: i32,
+        reasoning_id: Option<&str>,
+        created_by: &str,
     ) -> Result<ReasoningRelation, ReasoningError> {
         let strength = strength.clamp(0, 100);
 
@@ -177,7 +203,7 @@ impl ReasoningEngine {
                             "strength": strength as i64,
                             "confidence": 80i64,
                             "explanation": "",
-                            "created_by": "reasoning_engine",
+                            "created_by": created_by,
                             "created_at": now,
                         }),
                     )
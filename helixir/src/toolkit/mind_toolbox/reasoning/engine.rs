@@ -11,6 +11,7 @@ use serde::Deserialize;
 use tracing::{debug, info};
 
 use crate::db::HelixClient;
+use crate::llm::prompt_template::{DEFAULT_RELATION_TEMPLATE, PromptTemplate};
 use crate::llm::providers::base::LlmProvider;
 
 use super::types::{CacheStats, ReasoningError, ReasoningRelation, ReasoningType};
@@ -18,6 +19,8 @@ use super::types::{CacheStats, ReasoningError, ReasoningRelation, ReasoningType}
 pub struct ReasoningEngine {
     pub(super) client: Arc<HelixClient>,
     pub(super) llm_provider: Option<Arc<dyn LlmProvider>>,
+    /// User prompt around the NEW/EXISTING listing sent for inference.
+    pub(super) relation_template: PromptTemplate,
     pub(super) relation_cache: parking_lot::Mutex<LruCache<String, ReasoningRelation>>,
     pub(super) cache_size: usize,
     pub(super) is_warmed_up: AtomicBool,
@@ -47,12 +50,21 @@ impl ReasoningEngine {
         Self {
             client,
             llm_provider,
+            relation_template: PromptTemplate::builtin(DEFAULT_RELATION_TEMPLATE),
             relation_cache: parking_lot::Mutex::new(cache),
             cache_size,
             is_warmed_up: AtomicBool::new(false),
         }
     }
 
+    /// Wrap relation-inference input in `template` (see
+    /// [`crate::llm::prompt_template`]).
+    #[must_use]
+    pub fn with_relation_template(mut self, template: PromptTemplate) -> Self {
+        self.relation_template = template;
+        self
+    }
+
     pub async fn warm_up_cache(
         &self,
         memory_id: Option<&str>,
@@ -99,6 +111,14 @@ impl ReasoningEngine {
         }
     }
 
+    /// Whether relation inference has a model behind it. Without one
+    /// [`Self::infer_relations`] returns nothing, which a rebuild must not
+    /// read as "every edge is stale".
+    #[must_use]
+    pub fn can_infer(&self) -> bool {
+        self.llm_provider.is_some()
+    }
+
     #[must_use]
     pub fn get_cache_stats(&self) -> CacheStats {
         use std::sync::atomic::Ordering;
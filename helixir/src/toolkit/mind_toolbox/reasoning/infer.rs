@@ -53,10 +53,10 @@ Rules:
             .collect::<Vec<_>>()
             .join("\n");
 
-        let user_prompt = format!(
+        let user_prompt = self.relation_template.render(&format!(
             "NEW memory: {}\n\nEXISTING memories:\n{}",
             new_memory_content, context_str
-        );
+        ));
 
         match llm
             .generate(system_prompt, &user_prompt, Some("json_object"))
@@ -144,7 +144,7 @@ Rules:
         const BATCH_MAX: usize = 12;
         let mut out: Vec<ReasoningRelation> = Vec::new();
         for chunk in active.chunks(BATCH_MAX) {
-            out.extend(infer_relations_chunk(&**llm, chunk).await);
+            out.extend(infer_relations_chunk(&**llm, &self.relation_template, chunk).await);
         }
         Ok(out)
     }
@@ -238,6 +238,7 @@ fn extract_json_array(response: &str) -> Option<Vec<serde_json::Value>> {
 /// OWN candidate list. Backs [`ReasoningEngine::infer_relations_batch`].
 async fn infer_relations_chunk(
     llm: &dyn crate::llm::providers::base::LlmProvider,
+    template: &crate::llm::prompt_template::PromptTemplate,
     chunk: &[&(String, String, Vec<(String, String)>)],
 ) -> Vec<ReasoningRelation> {
     let system_prompt = r#"You connect NEW memories to their candidate existing memories with typed logical edges.
@@ -260,7 +261,7 @@ Output ONLY a JSON object: {"relations":[{"atom":0,"candidate":0,"type":"SUPPORT
             ctx.push_str(&format!("   candidate {ci}: {cc}\n"));
         }
     }
-    let user_prompt = format!("NEW atoms and their candidates:\n{ctx}");
+    let user_prompt = template.render(&format!("NEW atoms and their candidates:\n{ctx}"));
 
     let build = |arr: Vec<serde_json::Value>| -> Vec<ReasoningRelation> {
         arr.iter()
@@ -28,6 +28,17 @@ pub enum ReasoningType {
 }
 
 impl ReasoningType {
+    /// Every relation type, in declaration order.
+    pub const ALL: [ReasoningType; 7] = [
+        ReasoningType::Implies,
+        ReasoningType::Because,
+        ReasoningType::Contradicts,
+        ReasoningType::Supports,
+        ReasoningType::RelatesTo,
+        ReasoningType::PartOf,
+        ReasoningType::IsA,
+    ];
+
     #[must_use]
     pub fn edge_name(&self) -> &'static str {
         match self {
@@ -152,6 +163,10 @@ pub struct ReasoningChain {
     pub depth: usize,
 
     pub reasoning_trail: String,
+
+    /// The walk stopped at [`super::chain::MAX_CHAIN_NODES`] with hops left.
+    #[serde(default)]
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone)]
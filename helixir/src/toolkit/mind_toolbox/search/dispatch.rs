@@ -2,6 +2,7 @@
 //! [`SearchEngine::search`] (mode-driven user query) and
 //! [`SearchEngine::search_for_dedup`] (lightweight cross-user dedup probe).
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use serde_json::json;
@@ -9,10 +10,16 @@ use tracing::{debug, info};
 
 use crate::core::TimeWindow;
 use crate::core::search_modes::SearchMode;
+use crate::toolkit::mind_toolbox::memory::references::references_in;
 
 use super::engine::{SearchEngine, embedding_cache_key};
+use super::smart_traversal::SearchConfig;
 use super::types::{SearchError, UnifiedSearchResult};
 
+/// SUPERSEDES `reason` written by `correct_memory`. A row superseded for
+/// this reason is wrong, not merely older, and leaves search results.
+pub const CORRECTED_REASON: &str = "corrected";
+
 /// #87: split one deduped, score-ordered result stream into the honest
 /// window (`limit` in-window rows) plus the flashback allowance (at most
 /// `flashback_max` out-of-window rows the graph pulled back in, appended
@@ -22,10 +29,23 @@ fn clamp_with_flashbacks(
     limit: usize,
     flashback_max: usize,
 ) -> Vec<UnifiedSearchResult> {
+    // A memory reached both directly and through the graph is as close as
+    // its nearest path; the surviving row reports that.
+    let mut nearest: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    for r in &results {
+        nearest
+            .entry(r.memory_id.clone())
+            .and_modify(|h| *h = (*h).min(r.hops))
+            .or_insert(r.hops);
+    }
     let mut seen = std::collections::HashSet::new();
     let (flashbacks, in_window): (Vec<_>, Vec<_>) = results
         .into_iter()
         .filter(|r| seen.insert(r.memory_id.clone()))
+        .map(|mut r| {
+            r.hops = nearest[&r.memory_id];
+            r
+        })
         .partition(|r| {
             r.metadata
                 .get("flashback")
@@ -37,6 +57,39 @@ fn clamp_with_flashbacks(
     out
 }
 
+/// The source each memory was extracted from, out of a
+/// `getMemorySourcesBatch` reply: EXTRACTED_FROM edges (internal
+/// `from_node` → `to_node`) joined to the memory and source nodes. Maps the
+/// atom's `memory_id` to the source's `(memory_id, content)`.
+fn extraction_sources(batch: &serde_json::Value) -> HashMap<String, (String, String)> {
+    let rows = |key: &str| batch[key].as_array().cloned().unwrap_or_default();
+    let sources: HashMap<String, (String, String)> = rows("sources")
+        .iter()
+        .filter_map(|n| {
+            Some((
+                n["id"].as_str()?.to_string(),
+                (
+                    n["memory_id"].as_str()?.to_string(),
+                    n["content"].as_str().unwrap_or_default().to_string(),
+                ),
+            ))
+        })
+        .collect();
+    let source_of: HashMap<&str, &str> = batch["source_edges"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|e| Some((e["from_node"].as_str()?, e["to_node"].as_str()?)))
+        .collect();
+    rows("memories")
+        .iter()
+        .filter_map(|m| {
+            let source = sources.get(*source_of.get(m["id"].as_str()?)?)?;
+            Some((m["memory_id"].as_str()?.to_string(), source.clone()))
+        })
+        .collect()
+}
+
 impl SearchEngine {
     /// Mode-driven search. #87: an active `opts.window` bounds seeds by
     /// EVENT time and wins over `temporal_days`; when the window is inactive,
@@ -57,6 +110,8 @@ impl SearchEngine {
             graph_depth,
             scope,
             window,
+            hybrid,
+            windows,
         } = opts;
         let (mode, scope) = (mode.as_str(), scope.as_str());
         let query_preview: String = query.chars().take(30).collect();
@@ -64,15 +119,19 @@ impl SearchEngine {
         let search_mode = SearchMode::parse_mode(mode);
         let mode_defaults = self.config.retrieval.search_modes.for_mode(search_mode);
         let effective_temporal_days = temporal_days.or(mode_defaults.temporal_days);
-
-        let window = if window.is_active() {
-            window
-        } else {
-            match effective_temporal_days {
-                Some(days) => TimeWindow::last_days(days, chrono::Utc::now()),
-                None => TimeWindow::default(),
-            }
-        };
+        // The mode's configured window sets how fast freshness decays; it
+        // never filters (#31).
+        let decay_days = windows
+            .as_ref()
+            .unwrap_or(&self.config.windows)
+            .horizon_days(search_mode)
+            .unwrap_or(self.config.search_thresholds.default_temporal_days);
+
+        let (window, _) = crate::core::time_window::effective_window(
+            window,
+            effective_temporal_days,
+            chrono::Utc::now(),
+        );
         let flashback_max = if window.is_active() {
             self.config.retrieval.flashback_max
         } else {
@@ -107,17 +166,21 @@ impl SearchEngine {
                         "Using SmartTraversalV2 for mode={}, window={:?}..{:?}, scope={}",
                         mode, window.from, window.to, scope
                     );
-                    let config = self.make_search_config(
-                        limit,
-                        // #8: explicit graph_depth overrides the mode default
-                        // (capped at 4 — the full-mode maximum).
-                        graph_depth
-                            .map(|d| d.clamp(1, 4))
-                            .unwrap_or(if mode == "recent" { 1 } else { 2 }),
-                        mode_defaults.min_vector_score,
-                        mode_defaults.min_combined_score,
-                        mode_defaults.temporal_weight,
-                    );
+                    let config = SearchConfig {
+                        hybrid,
+                        ..self.make_search_config(
+                            limit,
+                            // #8: explicit graph_depth overrides the mode default
+                            // (capped at 4 — the full-mode maximum).
+                            graph_depth
+                                .map(|d| d.clamp(1, 4))
+                                .unwrap_or(if mode == "recent" { 1 } else { 2 }),
+                            mode_defaults.min_vector_score,
+                            mode_defaults.min_combined_score,
+                            mode_defaults.temporal_weight,
+                            decay_days,
+                        )
+                    };
                     let traversal_results = traversal
                         .search(query, query_embedding, effective_user_id, config, window)
                         .await
@@ -142,6 +205,7 @@ impl SearchEngine {
                             created_at: r.created_at.unwrap_or_default(),
                             user_count: None,
                             controversy: None,
+                            hops: r.depth as usize,
                         })
                         .collect();
                     mapped
@@ -156,13 +220,17 @@ impl SearchEngine {
                         "Using SmartTraversalV2 for deep search, window={:?}..{:?}, scope={}",
                         window.from, window.to, scope
                     );
-                    let config = self.make_search_config(
-                        limit * 2,
-                        graph_depth.map(|d| d.clamp(1, 4)).unwrap_or(3),
-                        self.config.search_thresholds.min_vector_score,
-                        mode_defaults.min_combined_score,
-                        mode_defaults.temporal_weight,
-                    );
+                    let config = SearchConfig {
+                        hybrid,
+                        ..self.make_search_config(
+                            limit * 2,
+                            graph_depth.map(|d| d.clamp(1, 4)).unwrap_or(3),
+                            self.config.search_thresholds.min_vector_score,
+                            mode_defaults.min_combined_score,
+                            mode_defaults.temporal_weight,
+                            decay_days,
+                        )
+                    };
                     let traversal_results = traversal
                         .search(query, query_embedding, effective_user_id, config, window)
                         .await
@@ -181,6 +249,7 @@ impl SearchEngine {
                             created_at: r.created_at.unwrap_or_default(),
                             user_count: None,
                             controversy: None,
+                            hops: r.depth as usize,
                         })
                         .collect();
                     mapped
@@ -199,13 +268,17 @@ impl SearchEngine {
                         "Using SmartTraversalV2 for full mode, window={:?}..{:?}, scope={}",
                         window.from, window.to, scope
                     );
-                    let config = self.make_search_config(
-                        limit * 2,
-                        graph_depth.map(|d| d.clamp(1, 4)).unwrap_or(4),
-                        self.config.search_thresholds.min_vector_score,
-                        self.config.search_thresholds.min_combined_score,
-                        mode_defaults.temporal_weight,
-                    );
+                    let config = SearchConfig {
+                        hybrid,
+                        ..self.make_search_config(
+                            limit * 2,
+                            graph_depth.map(|d| d.clamp(1, 4)).unwrap_or(4),
+                            self.config.search_thresholds.min_vector_score,
+                            self.config.search_thresholds.min_combined_score,
+                            mode_defaults.temporal_weight,
+                            decay_days,
+                        )
+                    };
                     let traversal_results = traversal
                         .search(query, query_embedding, effective_user_id, config, window)
                         .await
@@ -225,6 +298,7 @@ impl SearchEngine {
                             created_at: r.created_at.unwrap_or_default(),
                             user_count: None,
                             controversy: None,
+                            hops: r.depth as usize,
                         })
                         .collect();
                     mapped
@@ -420,6 +494,89 @@ impl SearchEngine {
         }
     }
 
+    /// Source excerpts: an atom row with an EXTRACTED_FROM edge to a raw
+    /// source (`add_memory` with `store_source`, or the automatic long-input
+    /// preservation) gets `source_id` + a `source_excerpt` of the original
+    /// message, so the caller can see what the fact was extracted from
+    /// without a second round trip. One `getMemorySourcesBatch` query for
+    /// the whole page, best-effort. `retrieval.source_excerpt_chars = 0`
+    /// disables the pass.
+    pub async fn attach_source_excerpts(&self, results: &mut [UnifiedSearchResult]) {
+        let max_chars = self.config.retrieval.source_excerpt_chars;
+        if max_chars == 0 {
+            return;
+        }
+        let memory_ids: Vec<&str> = results
+            .iter()
+            .filter(|r| !r.memory_id.starts_with("raw_"))
+            .map(|r| r.memory_id.as_str())
+            .collect();
+        if memory_ids.is_empty() {
+            return;
+        }
+        let sources = match self
+            .client
+            .execute_query::<serde_json::Value, _>(
+                "getMemorySourcesBatch",
+                &json!({ "memory_ids": memory_ids }),
+            )
+            .await
+        {
+            Ok(batch) => extraction_sources(&batch),
+            Err(e) => {
+                debug!("source excerpt lookup skipped: {}", e);
+                return;
+            }
+        };
+        for row in results.iter_mut() {
+            let Some((source_id, source_content)) = sources.get(&row.memory_id) else {
+                continue;
+            };
+            row.metadata
+                .insert("source_id".to_string(), json!(source_id));
+            row.metadata.insert(
+                "source_excerpt".to_string(),
+                json!(crate::safe_truncate_ellipsis(source_content, max_chars)),
+            );
+        }
+    }
+
+    /// File/document references (`add_memory` with `references`): rows whose
+    /// node metadata carries any get them as `metadata.references`, so the
+    /// caller can cite the artifact a fact came from. One
+    /// `getMemoriesBatch` query for the whole page, best-effort.
+    pub async fn attach_references(&self, results: &mut [UnifiedSearchResult]) {
+        if results.is_empty() {
+            return;
+        }
+        let memory_ids: Vec<&str> = results.iter().map(|r| r.memory_id.as_str()).collect();
+        let batch: serde_json::Value = match self
+            .client
+            .execute_query("getMemoriesBatch", &json!({ "memory_ids": memory_ids }))
+            .await
+        {
+            Ok(batch) => batch,
+            Err(e) => {
+                debug!("reference lookup skipped: {}", e);
+                return;
+            }
+        };
+        let references: HashMap<&str, _> = batch["memories"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|m| {
+                let refs = references_in(m["metadata"].as_str()?);
+                (!refs.is_empty()).then_some((m["memory_id"].as_str()?, refs))
+            })
+            .collect();
+        for row in results.iter_mut() {
+            if let Some(refs) = references.get(row.memory_id.as_str()) {
+                row.metadata.insert("references".to_string(), json!(refs));
+            }
+        }
+    }
+
     /// #92: superseded rows lose ranking priority. A densely-linked stale
     /// hub carries PPR mass its own corrections cannot beat (observed live:
     /// stale fact at 0.926/ppr=1.0 above two explicit corrections) — so a
@@ -429,6 +586,11 @@ impl SearchEngine {
     /// returns, ranked below its successor. Checked only for the top window
     /// (a stale hub is by definition ranked high); best-effort — a DB error
     /// leaves ranking as-is.
+    ///
+    /// A row superseded with reason [`CORRECTED_REASON`] (`correct_memory`)
+    /// is dropped instead: the user said it was wrong. Its successor, when
+    /// in the window, names it under `corrects`. A penalty of 1.0 turns
+    /// both off.
     async fn demote_superseded(&self, results: &mut Vec<UnifiedSearchResult>, limit: usize) {
         let penalty = self.config.retrieval.superseded_penalty;
         if penalty >= 1.0 || results.is_empty() {
@@ -453,6 +615,8 @@ impl SearchEngine {
             from_node: String,
             #[serde(default, deserialize_with = "crate::utils::nullable_string")]
             to_node: String,
+            #[serde(default, deserialize_with = "crate::utils::nullable_string")]
+            reason: String,
         }
         #[derive(serde::Deserialize, Default)]
         struct Resp {
@@ -488,11 +652,16 @@ impl SearchEngine {
         }
 
         let mut demoted = 0usize;
+        let mut corrected: Vec<(&str, Option<&str>)> = Vec::new();
         for edge in &resp.superseded_edges {
             let Some(stale_mid) = uuid_to_mid.get(edge.to_node.as_str()) else {
                 continue;
             };
             let successor_mid = uuid_to_mid.get(edge.from_node.as_str()).copied();
+            if edge.reason == CORRECTED_REASON {
+                corrected.push((stale_mid, successor_mid));
+                continue;
+            }
             if let Some(row) = results[..window]
                 .iter_mut()
                 .find(|r| r.memory_id == *stale_mid)
@@ -513,6 +682,26 @@ impl SearchEngine {
                 demoted += 1;
             }
         }
+        if !corrected.is_empty() {
+            let before = results.len();
+            results.retain(|r| !corrected.iter().any(|(stale, _)| r.memory_id == *stale));
+            for (stale, successor) in &corrected {
+                let Some(succ) = successor else { continue };
+                if let Some(row) = results.iter_mut().find(|r| r.memory_id == *succ) {
+                    let corrects = row
+                        .metadata
+                        .entry("corrects".to_string())
+                        .or_insert_with(|| json!([]));
+                    if let Some(ids) = corrects.as_array_mut() {
+                        ids.push(json!(stale));
+                    }
+                }
+            }
+            debug!(
+                "Corrected rows dropped: {}",
+                before.saturating_sub(results.len())
+            );
+        }
         if demoted > 0 {
             info!("Superseded demotion (#92): {demoted} stale row(s) penalized x{penalty}");
             results.sort_by(|a, b| {
@@ -543,6 +732,7 @@ impl SearchEngine {
                 self.config.search_thresholds.min_vector_score,
                 self.config.search_thresholds.min_combined_score,
                 self.config.search_thresholds.temporal_weight,
+                self.config.search_thresholds.default_temporal_days,
             );
             let results = traversal
                 .search(query, query_embedding, None, config, TimeWindow::default())
@@ -561,6 +751,7 @@ impl SearchEngine {
                     created_at: r.created_at.unwrap_or_default(),
                     user_count: None,
                     controversy: None,
+                    hops: r.depth as usize,
                 })
                 .collect())
         } else {
@@ -587,6 +778,7 @@ mod tests {
             created_at: String::new(),
             user_count: None,
             controversy: None,
+            hops: 0,
         }
     }
 
@@ -624,4 +816,37 @@ mod tests {
         assert_eq!(out[0].memory_id, "dup");
         assert_eq!(out[1].memory_id, "b");
     }
+
+    #[test]
+    fn a_duplicate_reports_its_nearest_hop_count() {
+        // The expansion copy outscored the seed copy; the row still counts
+        // as a direct match.
+        let mut expanded = row("dup", 0.9, false);
+        expanded.hops = 2;
+        let mut far = row("far", 0.8, false);
+        far.hops = 1;
+        let input = vec![expanded, row("dup", 0.7, false), far];
+        let out = clamp_with_flashbacks(input, 5, 0);
+        let hops: Vec<_> = out.iter().map(|r| (r.memory_id.as_str(), r.hops)).collect();
+        assert_eq!(hops, [("dup", 0), ("far", 1)]);
+    }
+
+    #[test]
+    fn extraction_sources_join_the_edge_to_both_nodes() {
+        let batch = json!({
+            "memories": [
+                {"id": "n1", "memory_id": "mem_a"},
+                {"id": "n2", "memory_id": "mem_b"},
+            ],
+            "source_edges": [{"from_node": "n1", "to_node": "n9"}],
+            "sources": [{"id": "n9", "memory_id": "raw_c", "content": "the original message"}],
+        });
+        let sources = extraction_sources(&batch);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(
+            sources["mem_a"],
+            ("raw_c".to_string(), "the original message".to_string())
+        );
+        assert!(extraction_sources(&json!({})).is_empty());
+    }
 }
@@ -82,6 +82,7 @@ impl SearchEngine {
         min_vector_score: f64,
         min_combined_score: f64,
         temporal_weight: f64,
+        temporal_decay_days: f64,
     ) -> SearchConfig {
         let t = &self.config.search_thresholds;
         let _ = t.temporal_weight; // superseded by the per-mode weight (#31)
@@ -101,7 +102,7 @@ impl SearchEngine {
             graph_semantic_weight: t.graph_semantic_weight,
             graph_graph_weight: t.graph_graph_weight,
             graph_temporal_weight: t.graph_temporal_weight,
-            temporal_decay_days: t.default_temporal_days,
+            temporal_decay_days,
             ppr_alpha: self.config.retrieval.ppr.alpha,
             ppr_iterations: self.config.retrieval.ppr.max_iterations,
             rank_base: self.config.retrieval.rank_base,
@@ -109,6 +110,7 @@ impl SearchEngine {
             edge_weights: self.config.retrieval.graph.edge_weights,
             edge_damping: self.config.retrieval.graph.edge_damping,
             rerank_max_rows: self.config.retrieval.rerank_max_rows,
+            hybrid: None,
         }
     }
 
@@ -131,6 +133,7 @@ impl SearchEngine {
                 created_at: r.created_at,
                 user_count: None,
                 controversy: None,
+                hops: 0,
             })
             .collect())
     }
@@ -167,11 +170,15 @@ impl SearchEngine {
         self.hybrid.search(query, user_id, documents, limit).await
     }
 
+    /// The vector-search result cache; the other strategies do not cache.
     pub fn cache_stats(&self) -> super::cache::CacheStats {
-        super::cache::CacheStats::default()
+        self.vector.cache_stats()
     }
 
-    pub fn clear_cache(&self) {
-        debug!("SearchEngine.clear_cache: noop (caches are TTL-managed)");
+    /// Empty the vector-search result cache; returns how many entries it held.
+    pub fn clear_cache(&self) -> usize {
+        let removed = self.vector.clear_cache();
+        debug!("SearchEngine.clear_cache: {removed} entries");
+        removed
     }
 }
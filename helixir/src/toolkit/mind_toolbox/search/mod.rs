@@ -58,6 +58,7 @@ pub use smart_traversal::{
 
 pub use query_processor::{EnhancedQuery, QueryIntent, QueryProcessor};
 
+pub use dispatch::CORRECTED_REASON;
 pub use engine::SearchEngine;
 pub use types::{
     ControversyInfo, SearchEngineConfig, SearchError, SearchOptions, UnifiedSearchResult,
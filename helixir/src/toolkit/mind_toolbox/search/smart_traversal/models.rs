@@ -193,6 +193,10 @@ pub struct SearchConfig {
     /// placeholder. Bounds embedding cost on dense graphs — a full-mode
     /// search once expanded 9 seeds into 1709 rows and embedded them all.
     pub rerank_max_rows: usize,
+
+    /// Per-call override of the Phase 1 BM25 + vector RRF fusion. `None`
+    /// follows the retrieval profile (`native_hybrid_bm25`).
+    pub hybrid: Option<bool>,
 }
 
 impl Default for SearchConfig {
@@ -221,6 +225,7 @@ impl Default for SearchConfig {
             edge_weights: crate::core::config::EdgeWeights::default(),
             edge_damping: crate::core::config::EdgeDamping::default(),
             rerank_max_rows: 128,
+            hybrid: None,
         }
     }
 }
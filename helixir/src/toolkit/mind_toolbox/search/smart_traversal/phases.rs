@@ -136,8 +136,12 @@ pub async fn vector_search_phase(
             .map_err(|e| TraversalError::Database(e.to_string()))?
     };
 
+    // A per-call `hybrid` wins over the profile default either way.
+    let hybrid = config
+        .hybrid
+        .unwrap_or_else(|| profile.native_hybrid_bm25());
     let bm25_limit = fetch_limit.saturating_mul(2).max(fetch_limit);
-    let bm25_memories: Option<Vec<VectorMemory>> = if profile.native_hybrid_bm25() {
+    let bm25_memories: Option<Vec<VectorMemory>> = if hybrid {
         match fetch_bm25_memories(&client, query_text, bm25_limit).await {
             Ok(rows) if !rows.is_empty() => Some(rows),
             Ok(_) => {
@@ -156,18 +160,21 @@ pub async fn vector_search_phase(
         None
     };
 
-    let visit_order: Vec<String> = if let Some(ref bm25_rows) = bm25_memories {
-        let v_ids: Vec<String> = vector_response
-            .memories
-            .iter()
-            .filter(|m| !m.memory_id.is_empty())
-            .map(|m| m.memory_id.clone())
-            .collect();
-        let b_ids: Vec<String> = bm25_rows
-            .iter()
-            .filter(|m| !m.memory_id.is_empty())
-            .map(|m| m.memory_id.clone())
-            .collect();
+    let v_ids: Vec<String> = vector_response
+        .memories
+        .iter()
+        .filter(|m| !m.memory_id.is_empty())
+        .map(|m| m.memory_id.clone())
+        .collect();
+    let b_ids: Vec<String> = bm25_memories
+        .iter()
+        .flatten()
+        .filter(|m| !m.memory_id.is_empty())
+        .map(|m| m.memory_id.clone())
+        .collect();
+    let (vector_ranks, bm25_ranks) = (rrf::rank_positions(&v_ids), rrf::rank_positions(&b_ids));
+
+    let visit_order: Vec<String> = if bm25_memories.is_some() {
         info!(
             "Phase 1 hybrid (RRF k=60): merging {} vector + {} BM25 hits",
             v_ids.len(),
@@ -175,12 +182,7 @@ pub async fn vector_search_phase(
         );
         rrf::fused_memory_order(&v_ids, &b_ids)
     } else {
-        vector_response
-            .memories
-            .iter()
-            .filter(|m| !m.memory_id.is_empty())
-            .map(|m| m.memory_id.clone())
-            .collect()
+        v_ids.clone()
     };
 
     let mut memory_by_id: HashMap<String, VectorMemory> = HashMap::new();
@@ -268,11 +270,26 @@ pub async fn vector_search_phase(
                 serde_json::Value::String(memory.memory_type.clone()),
             );
         }
-        if profile.native_hybrid_bm25() {
+        if hybrid {
             meta.insert(
                 "phase1_hybrid".to_string(),
                 serde_json::Value::String("vector_rrf_bm25".to_string()),
             );
+            // Per-arm sub-scores: each list's RRF contribution (a missing
+            // arm means the row was not in that list at all — e.g. an exact
+            // identifier only BM25 found).
+            let vector_rank = vector_ranks.get(memory.memory_id.as_str()).copied();
+            let bm25_rank = bm25_ranks.get(memory.memory_id.as_str()).copied();
+            meta.insert(
+                "vector_rrf".to_string(),
+                serde_json::json!(vector_rank.map(rrf::rrf_component)),
+            );
+            meta.insert(
+                "bm25_rrf".to_string(),
+                serde_json::json!(bm25_rank.map(rrf::rrf_component)),
+            );
+            meta.insert("vector_rank".to_string(), serde_json::json!(vector_rank));
+            meta.insert("bm25_rank".to_string(), serde_json::json!(bm25_rank));
         }
         if profile.result_provenance() {
             meta.insert(
@@ -46,6 +46,23 @@ pub(crate) fn fused_memory_order_with_k(
     pairs.into_iter().map(|(id, _)| id.to_string()).collect()
 }
 
+/// One list's RRF contribution at 1-based `rank` (the per-arm sub-score
+/// reported next to a fused result).
+pub fn rrf_component(rank: usize) -> f64 {
+    1.0 / (DEFAULT_RRF_K + rank as f64)
+}
+
+/// 1-based rank of every id in a ranked list (first occurrence wins).
+pub fn rank_positions(list: &[String]) -> std::collections::HashMap<&str, usize> {
+    let mut ranks = std::collections::HashMap::new();
+    for (i, id) in list.iter().enumerate() {
+        if !id.is_empty() {
+            ranks.entry(id.as_str()).or_insert(i + 1);
+        }
+    }
+    ranks
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +86,15 @@ mod tests {
         let fused = fused_memory_order(&v, &b);
         assert_eq!(fused, vec!["x", "y"]);
     }
+
+    #[test]
+    fn rank_positions_are_one_based_and_first_wins() {
+        let v: Vec<String> = vec!["a".into(), "".into(), "b".into(), "a".into()];
+        let ranks = rank_positions(&v);
+        assert_eq!(ranks.get("a"), Some(&1));
+        assert_eq!(ranks.get("b"), Some(&3));
+        assert_eq!(ranks.len(), 2);
+        assert!(rrf_component(1) > rrf_component(2));
+        assert_eq!(rrf_component(1), 1.0 / 61.0);
+    }
 }
@@ -374,7 +374,9 @@ impl SmartTraversalV2 {
     ) -> String {
         let mut hasher = Sha256::new();
 
-        if self.profile.cache_includes_query_text() {
+        // A per-call BM25 override makes the result depend on the query
+        // text even on profiles whose default is vector-only.
+        if self.profile.cache_includes_query_text() || config.hybrid == Some(true) {
             hasher.update(query.as_bytes());
         }
 
@@ -390,6 +392,12 @@ impl SmartTraversalV2 {
         hasher.update(config.graph_depth.to_le_bytes());
         hasher.update(config.min_vector_score.to_le_bytes());
         hasher.update(config.min_combined_score.to_le_bytes());
+        hasher.update(config.temporal_decay_days.to_le_bytes());
+        hasher.update([match config.hybrid {
+            None => 0u8,
+            Some(false) => 1,
+            Some(true) => 2,
+        }]);
 
         if let Some(edge_types) = &config.edge_types {
             for edge_type in edge_types {
@@ -4,7 +4,7 @@
 use std::collections::HashMap;
 
 use crate::core::TimeWindow;
-use crate::core::config::{RetrievalConfig, SearchThresholds};
+use crate::core::config::{RetrievalConfig, SearchThresholds, SearchWindowsConfig};
 
 use super::hybrid::HybridSearchError;
 use super::vector::VectorSearchError;
@@ -30,6 +30,12 @@ pub struct SearchOptions {
     pub graph_depth: Option<u32>,
     pub scope: String,
     pub window: TimeWindow,
+    /// Force (`Some(true)`) or suppress (`Some(false)`) BM25 keyword fusion;
+    /// `None` follows the retrieval profile.
+    pub hybrid: Option<bool>,
+    /// Mode windows to decay by instead of the engine's (a user's
+    /// overrides); `None` keeps the engine's.
+    pub windows: Option<crate::core::config::SearchWindowsConfig>,
 }
 
 impl SearchOptions {
@@ -43,6 +49,8 @@ impl SearchOptions {
             graph_depth: None,
             scope: "personal".to_string(),
             window: TimeWindow::default(),
+            hybrid: None,
+            windows: None,
         }
     }
 }
@@ -56,6 +64,7 @@ pub struct SearchEngineConfig {
     pub bm25_weight: f64,
     pub search_thresholds: SearchThresholds,
     pub retrieval: RetrievalConfig,
+    pub windows: SearchWindowsConfig,
 }
 
 impl Default for SearchEngineConfig {
@@ -68,6 +77,7 @@ impl Default for SearchEngineConfig {
             bm25_weight: 0.4,
             search_thresholds: SearchThresholds::default(),
             retrieval: RetrievalConfig::default(),
+            windows: SearchWindowsConfig::default(),
         }
     }
 }
@@ -90,4 +100,6 @@ pub struct UnifiedSearchResult {
     pub created_at: String,
     pub user_count: Option<u32>,
     pub controversy: Option<ControversyInfo>,
+    /// Relation hops from the nearest semantic seed: 0 for a direct match.
+    pub hops: usize,
 }
@@ -66,6 +66,17 @@ impl VectorSearch {
         }
     }
 
+    pub fn cache_stats(&self) -> super::cache::CacheStats {
+        self.cache.stats()
+    }
+
+    /// Empty the result cache; returns how many entries it held.
+    pub fn clear_cache(&self) -> usize {
+        let removed = self.cache.stats().size;
+        self.cache.clear();
+        removed
+    }
+
     fn make_cache_key(
         &self,
         query: &str,
@@ -0,0 +1,264 @@
+//! Access tracking: which memories the agent actually gets back from
+//! search. Every row a client search returns bumps the memory's
+//! `access_count` and `last_accessed_at`, so a search can rank by the working
+//! set (`sort = recently_accessed | frequently_used`) instead of only by
+//! semantic closeness.
+//!
+//! Writes are best-effort and off the response path: hits accumulate in
+//! process and a memory is flushed at most once per
+//! `retrieval.access_throttle_secs`. Once per throttle window the tracker
+//! also sweeps itself: entries whose window has passed flush what they still
+//! hold and are dropped, and past [`MAX_TRACKED`] entries the ones flushed
+//! longest ago go early, so the map holds the working set, not every memory
+//! ever returned. Counts are a read-then-write on the node, so two processes
+//! flushing the same memory at once can lose an increment — fine for a
+//! usage signal.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use tracing::debug;
+
+use super::ToolingManager;
+
+/// Memories the tracker holds at most between sweeps.
+const MAX_TRACKED: usize = 10_000;
+
+/// Usage of one memory: the stored counters plus hits not yet flushed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccessStats {
+    pub access_count: u64,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default)]
+struct AccessEntry {
+    last_flush: Option<Instant>,
+    pending: u64,
+    last_seen: Option<DateTime<Utc>>,
+}
+
+/// A memory's hits handed over for writing, with when it was last seen.
+type Flush = (String, u64, DateTime<Utc>);
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    entries: HashMap<String, AccessEntry>,
+    last_sweep: Option<Instant>,
+}
+
+/// In-process side of access tracking: pending hit counts and the flush
+/// throttle.
+#[derive(Debug)]
+pub(crate) struct AccessTracker {
+    throttle: Duration,
+    max_entries: usize,
+    state: parking_lot::Mutex<TrackerState>,
+}
+
+impl AccessTracker {
+    pub(crate) fn new(throttle: Duration) -> Self {
+        Self {
+            throttle,
+            max_entries: MAX_TRACKED,
+            state: parking_lot::Mutex::new(TrackerState::default()),
+        }
+    }
+
+    /// Count one hit on each id; returns the memories due a flush with the
+    /// hits they carry (their pending count is handed over and reset),
+    /// including whatever a sweep evicts.
+    fn note(&self, ids: &[String], now: DateTime<Utc>) -> Vec<Flush> {
+        let mut state = self.state.lock();
+        let mut due = Vec::new();
+        for id in ids {
+            let entry = state.entries.entry(id.clone()).or_default();
+            entry.pending += 1;
+            entry.last_seen = Some(now);
+            let throttled = entry
+                .last_flush
+                .is_some_and(|at| at.elapsed() < self.throttle);
+            if !throttled {
+                entry.last_flush = Some(Instant::now());
+                due.push((id.clone(), std::mem::take(&mut entry.pending), now));
+            }
+        }
+        let sweep_due = state
+            .last_sweep
+            .map_or(true, |at| at.elapsed() >= self.throttle);
+        if sweep_due || state.entries.len() > self.max_entries {
+            due.extend(self.sweep(&mut state.entries));
+            state.last_sweep = Some(Instant::now());
+        }
+        due
+    }
+
+    /// Drop the entries whose throttle window has passed, then the ones
+    /// flushed longest ago until at most `max_entries` remain. Returns the
+    /// pending hits the dropped entries still held.
+    fn sweep(&self, entries: &mut HashMap<String, AccessEntry>) -> Vec<Flush> {
+        let expired = |e: &AccessEntry| {
+            e.last_flush
+                .map_or(true, |at| at.elapsed() >= self.throttle)
+        };
+        let mut evict: Vec<String> = entries
+            .iter()
+            .filter(|(_, e)| expired(e))
+            .map(|(id, _)| id.clone())
+            .collect();
+        let over = entries.len().saturating_sub(evict.len() + self.max_entries);
+        if over > 0 {
+            let mut live: Vec<(&String, Option<Instant>)> = entries
+                .iter()
+                .filter(|(_, e)| !expired(e))
+                .map(|(id, e)| (id, e.last_flush))
+                .collect();
+            live.sort_by_key(|(_, at)| *at);
+            evict.extend(live.into_iter().take(over).map(|(id, _)| id.clone()));
+        }
+        evict
+            .into_iter()
+            .filter_map(|id| {
+                let entry = entries.remove(&id)?;
+                let seen = entry.last_seen?;
+                (entry.pending > 0).then_some((id, entry.pending, seen))
+            })
+            .collect()
+    }
+
+    /// Hits on `id` not yet written, and when it was last seen here.
+    fn unflushed(&self, id: &str) -> (u64, Option<DateTime<Utc>>) {
+        self.state
+            .lock()
+            .entries
+            .get(id)
+            .map(|e| (e.pending, e.last_seen))
+            .unwrap_or_default()
+    }
+}
+
+impl ToolingManager {
+    /// Record that `memory_ids` were just returned to a client. Never
+    /// blocks: due flushes run on a spawned task and failures only log.
+    pub fn record_access(&self, memory_ids: &[String]) {
+        if memory_ids.is_empty() {
+            return;
+        }
+        let now = Utc::now();
+        let due = self.access.note(memory_ids, now);
+        if due.is_empty() {
+            return;
+        }
+        let db = Arc::clone(&self.db);
+        tokio::spawn(async move {
+            for (memory_id, hits, seen) in due {
+                let stored = match db
+                    .execute_query::<serde_json::Value, _>(
+                        "getMemory",
+                        &json!({ "memory_id": &memory_id }),
+                    )
+                    .await
+                {
+                    Ok(resp) => resp["memory"]["access_count"].as_u64().unwrap_or(0),
+                    Err(e) => {
+                        debug!("access flush: read {memory_id} failed: {e}");
+                        continue;
+                    }
+                };
+                if let Err(e) = db
+                    .execute_query::<serde_json::Value, _>(
+                        "recordMemoryAccess",
+                        &json!({
+                            "memory_id": &memory_id,
+                            "last_accessed_at": seen.to_rfc3339(),
+                            "access_count": (stored + hits) as i64,
+                        }),
+                    )
+                    .await
+                {
+                    debug!("access flush: write {memory_id} failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Usage stats for `memory_ids` (stored counters merged with hits still
+    /// pending in this process). A memory that cannot be read reads as never
+    /// accessed.
+    pub async fn access_stats(&self, memory_ids: &[String]) -> HashMap<String, AccessStats> {
+        let lookups = memory_ids.iter().map(|memory_id| async move {
+            let resp: serde_json::Value = self
+                .db
+                .execute_query("getMemory", &json!({ "memory_id": memory_id }))
+                .await
+                .unwrap_or_default();
+            let node = &resp["memory"];
+            let stored_at = node["last_accessed_at"]
+                .as_str()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map(|t| t.with_timezone(&Utc));
+            let (pending, seen_at) = self.access.unflushed(memory_id);
+            let stats = AccessStats {
+                access_count: node["access_count"].as_u64().unwrap_or(0) + pending,
+                last_accessed_at: stored_at.max(seen_at),
+            };
+            (memory_id.clone(), stats)
+        });
+        futures::future::join_all(lookups)
+            .await
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hits_accumulate_while_throttled_and_flush_together() {
+        let tracker = AccessTracker::new(Duration::from_secs(3600));
+        let ids = vec!["m1".to_string()];
+        let now = Utc::now();
+
+        assert_eq!(tracker.note(&ids, now), vec![("m1".to_string(), 1, now)]);
+        assert!(tracker.note(&ids, now).is_empty());
+        assert!(tracker.note(&ids, now).is_empty());
+        assert_eq!(tracker.unflushed("m1"), (2, Some(now)));
+
+        let eager = AccessTracker::new(Duration::ZERO);
+        assert_eq!(eager.note(&ids, now), vec![("m1".to_string(), 1, now)]);
+        assert_eq!(eager.note(&ids, now), vec![("m1".to_string(), 1, now)]);
+        assert_eq!(eager.unflushed("m1").0, 0);
+    }
+
+    #[test]
+    fn sweeps_flush_what_expired_entries_hold_and_bound_the_map() {
+        let tracker = AccessTracker::new(Duration::from_millis(20));
+        let now = Utc::now();
+        let m1 = vec!["m1".to_string()];
+        tracker.note(&m1, now);
+        tracker.note(&m1, now);
+        std::thread::sleep(Duration::from_millis(30));
+
+        // m1's window has passed: the sweep hands over its held hit.
+        let due = tracker.note(&["m2".to_string()], now);
+        assert!(due.contains(&("m1".to_string(), 1, now)), "{due:?}");
+        assert!(!tracker.state.lock().entries.contains_key("m1"));
+
+        let capped = AccessTracker {
+            max_entries: 2,
+            ..AccessTracker::new(Duration::from_secs(3600))
+        };
+        for id in ["a", "b", "c"] {
+            capped.note(&[id.to_string()], now);
+        }
+        let held = capped.note(&["c".to_string()], now);
+        assert_eq!(capped.state.lock().entries.len(), 2);
+        assert!(held.is_empty(), "{held:?}");
+        assert!(!capped.state.lock().entries.contains_key("a"));
+    }
+}
@@ -55,6 +55,8 @@ impl ToolingManager {
                     // #44: surface the existing memory the write deduped to, so the
                     // agent sees "linked to X" rather than an empty/silent result.
                     deduped_ids.push(target_id.clone());
+                    // Saying it again reinforces it: restarts certainty decay.
+                    self.reinforce_memory(target_id).await;
                     self.emit_memory_deduplicated(target_id, user_id).await;
                 }
                 return Ok(None);
@@ -14,6 +14,7 @@ use crate::toolkit::mind_toolbox::reasoning::ReasoningType;
 
 use super::super::ToolingManager;
 use super::super::types::ToolingError;
+use super::entity_links::retain_allowed_entities;
 use crate::safe_truncate;
 
 impl ToolingManager {
@@ -154,13 +155,20 @@ impl ToolingManager {
         user_id: &str,
         memory_ids: &[String],
     ) -> usize {
-        let extraction = match self.extractor.extract(text, user_id, true, false).await {
+        let mut extraction = match self.extractor.extract(text, user_id, true, false).await {
             Ok(e) => e,
             Err(e) => {
                 warn!("Deferred entity enrichment: extraction failed: {e}");
                 return 0;
             }
         };
+        let discarded =
+            retain_allowed_entities(&mut extraction.entities, &self.config.entity_types);
+        if discarded > 0 {
+            info!(
+                "Deferred entity enrichment: {discarded} entities outside entity_types discarded"
+            );
+        }
 
         let mut linked = 0usize;
         for entity in &extraction.entities {
@@ -1,14 +1,45 @@
 //! Entity → entity relation persistence: given two `ExtractedEntity`s and the
 //! LLM-suggested relationship_type/strength, resolve both sides into DB
-//! entities and write the `addEntityRelation` edge.
+//! entities and write the `addEntityRelation` edge. Also the
+//! `entity_types` allowlist applied to every extraction before linking.
 
 use serde::Serialize;
 use tracing::{info, warn};
 
 use crate::llm::extractor::ExtractedEntity;
+use crate::toolkit::fast_think::ScratchEntityType;
 
 use super::super::ToolingManager;
 
+/// Drop the entities whose type ([`ScratchEntityType::classify`]) is not in
+/// `allowed`; returns how many were dropped. Memories and relations that
+/// name a dropped entity simply find nothing to link. An empty allowlist,
+/// or one with no recognised type name, keeps everything.
+pub(super) fn retain_allowed_entities(
+    entities: &mut Vec<ExtractedEntity>,
+    allowed: &[String],
+) -> usize {
+    if allowed.is_empty() {
+        return 0;
+    }
+    let allowed: Vec<ScratchEntityType> = allowed
+        .iter()
+        .filter_map(|name| {
+            let t = ScratchEntityType::from_name(name);
+            if t.is_none() {
+                warn!("entity_types: unknown type '{name}' ignored");
+            }
+            t
+        })
+        .collect();
+    if allowed.is_empty() {
+        return 0;
+    }
+    let before = entities.len();
+    entities.retain(|e| allowed.contains(&ScratchEntityType::classify(&e.entity_type)));
+    before - entities.len()
+}
+
 impl ToolingManager {
     pub(super) async fn persist_entity_relation(
         &self,
@@ -95,3 +126,38 @@ impl ToolingManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(id: &str, entity_type: &str) -> ExtractedEntity {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "type": entity_type,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn only_allowed_types_survive_and_synonyms_count() {
+        let mut entities = vec![
+            entity("alice", "person"),
+            entity("kafka", "system"),
+            entity("acme", "Organization"),
+            entity("speed", "concept"),
+        ];
+        let allowed = ["person".to_string(), "technology".to_string()];
+        assert_eq!(retain_allowed_entities(&mut entities, &allowed), 2);
+        let ids: Vec<_> = entities.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["alice", "kafka"]);
+
+        assert_eq!(retain_allowed_entities(&mut entities, &[]), 0);
+        assert_eq!(
+            retain_allowed_entities(&mut entities, &["gadget".to_string()]),
+            0
+        );
+        assert_eq!(entities.len(), 2);
+    }
+}
@@ -10,7 +10,10 @@ use crate::llm::decision::{MemoryOperation, SimilarMemory};
 use crate::llm::extractor::{ExtractedEntity, ExtractedMemory, ExtractedRelation};
 
 use super::super::ToolingManager;
-use super::super::types::{AddMemoryResult, ToolingError};
+use super::super::in_flight::add_key;
+use super::super::types::{AddMemoryResult, DuplicateHit, ToolingError};
+use super::entity_links::retain_allowed_entities;
+use crate::core::config::{DedupOnAddPolicy, EmptyExtractionPolicy, OnEmbedFail};
 use crate::safe_truncate;
 
 impl ToolingManager {
@@ -21,6 +24,50 @@ impl ToolingManager {
         agent_id: Option<&str>,
         _metadata: Option<HashMap<String, serde_json::Value>>,
         context_tags: Option<&str>,
+    ) -> Result<AddMemoryResult, ToolingError> {
+        self.extract_and_add(message, user_id, agent_id, context_tags, false)
+            .await
+    }
+
+    /// `add_memory` that always keeps the original message as a `raw_`
+    /// source node, whatever its length or atom count. Every extracted atom
+    /// gets the usual PART_OF family link and an EXTRACTED_FROM edge to it,
+    /// so search can hand back the source excerpt next to the fact.
+    pub async fn add_memory_with_source(
+        &self,
+        message: &str,
+        user_id: &str,
+        agent_id: Option<&str>,
+        context_tags: Option<&str>,
+    ) -> Result<AddMemoryResult, ToolingError> {
+        self.extract_and_add(message, user_id, agent_id, context_tags, true)
+            .await
+    }
+
+    /// One add per identical request at a time (`write.concurrent_add_window_secs`).
+    async fn extract_and_add(
+        &self,
+        message: &str,
+        user_id: &str,
+        agent_id: Option<&str>,
+        context_tags: Option<&str>,
+        store_source: bool,
+    ) -> Result<AddMemoryResult, ToolingError> {
+        let key = add_key(message, user_id, agent_id, context_tags, store_source);
+        self.in_flight_adds
+            .run(key, || {
+                self.run_extract_and_add(message, user_id, agent_id, context_tags, store_source)
+            })
+            .await
+    }
+
+    async fn run_extract_and_add(
+        &self,
+        message: &str,
+        user_id: &str,
+        agent_id: Option<&str>,
+        context_tags: Option<&str>,
+        store_source: bool,
     ) -> Result<AddMemoryResult, ToolingError> {
         let preview: String = message.chars().take(50).collect();
         let tags = context_tags.unwrap_or("");
@@ -34,7 +81,7 @@ impl ToolingManager {
         // to atomize — extraction would rephrase it and the rule tag (stamped
         // by prefix in decide.rs) would never land. Deterministic single-atom
         // path, no LLM.
-        let extraction = if message.trim_start().starts_with("Charter rule [") {
+        let mut extraction = if message.trim_start().starts_with("Charter rule [") {
             info!("charter-rule write: verbatim single-atom path (no extraction)");
             crate::llm::extractor::ExtractionResult {
                 memories: vec![crate::llm::extractor::ExtractedMemory {
@@ -61,6 +108,11 @@ impl ToolingManager {
             extraction.entities.len(),
             extraction.relations.len()
         );
+        let entities_discarded =
+            retain_allowed_entities(&mut extraction.entities, &self.config.entity_types);
+        if entities_discarded > 0 {
+            info!("entity_types: {entities_discarded} extracted entities discarded");
+        }
 
         // #79: example-leak firewall — drop atoms that resemble a prompt's
         // worked example while being ungrounded in the user's actual message
@@ -97,17 +149,59 @@ impl ToolingManager {
             })
             .collect();
 
+        let no_facts = kept.is_empty();
+        if no_facts {
+            match self.config.write.on_empty_extraction {
+                EmptyExtractionPolicy::StoreRaw => {
+                    info!("No facts extracted: storing the message as one fallback memory")
+                }
+                EmptyExtractionPolicy::Skip => {
+                    info!("No facts extracted: nothing stored (on_empty_extraction=skip)");
+                    return Ok(AddMemoryResult {
+                        added: vec![],
+                        updated: vec![],
+                        deleted: vec![],
+                        deduped: vec![],
+                        skipped: 0,
+                        entities_extracted: 0,
+                        reasoning_relations_created: 0,
+                        chunks_created: 0,
+                        metadata: HashMap::new(),
+                        needs_clarification: vec![],
+                        no_facts_extracted: true,
+                        duplicates: vec![],
+                        needs_embedding: vec![],
+                    });
+                }
+                EmptyExtractionPolicy::Error => {
+                    return Err(ToolingError::Extraction(
+                        "no_facts_extracted: the message yielded no atomic facts; rephrase it as explicit statements".to_string(),
+                    ));
+                }
+            }
+        }
+
         let memories_to_store = self.prepare_memories_for_storage(kept, message);
-        self.run_add_pipeline(
-            memories_to_store,
-            &extraction.entities,
-            &relations,
-            Some(message),
-            user_id,
-            agent_id,
-            tags,
-        )
-        .await
+        let mut result = self
+            .run_add_pipeline(
+                memories_to_store,
+                &extraction.entities,
+                &relations,
+                Some(message),
+                store_source,
+                user_id,
+                agent_id,
+                tags,
+            )
+            .await?;
+        result.no_facts_extracted = no_facts;
+        if !self.config.entity_types.is_empty() {
+            result.metadata.insert(
+                "entities_discarded".to_string(),
+                serde_json::json!(entities_discarded),
+            );
+        }
+        Ok(result)
     }
 
     /// LLM-free entry for callers that ALREADY hold structured atoms (FastThink
@@ -132,6 +226,7 @@ impl ToolingManager {
             &[],
             &[],
             None,
+            false,
             user_id,
             agent_id,
             context_tags.unwrap_or(""),
@@ -139,8 +234,66 @@ impl ToolingManager {
         .await
     }
 
+    /// Apply `write.dedup_on_add` to the recalled neighbours of each fact:
+    /// every fact whose closest memory reaches the threshold is reported,
+    /// and under `skip` its `(existing id, similarity)` is returned in the
+    /// second vector so it is not stored. Protected types are only warned
+    /// about (charter C3).
+    fn check_duplicates_on_add(
+        &self,
+        memories: &[ExtractedMemory],
+        recall: &[Vec<SimilarMemory>],
+    ) -> (Vec<DuplicateHit>, Vec<Option<(String, f64)>>) {
+        let policy = self.config.write.dedup_on_add;
+        let threshold = self.config.write.dedup_on_add_threshold;
+        let mut hits = Vec::new();
+        let mut skipped = vec![None; memories.len()];
+        if policy == DedupOnAddPolicy::Off {
+            return (hits, skipped);
+        }
+        for (i, (memory, similar)) in memories.iter().zip(recall).enumerate() {
+            let Some(top) = similar
+                .iter()
+                .filter(|m| m.score >= threshold)
+                .max_by(|a, b| a.score.total_cmp(&b.score))
+            else {
+                continue;
+            };
+            let protected =
+                crate::core::charter::PROTECTED_TYPES.contains(&memory.memory_type.as_str());
+            let skip = policy == DedupOnAddPolicy::Skip && !protected;
+            info!(
+                "dedup_on_add: fact {i} is {:.3} similar to {} — {}",
+                top.score,
+                top.id,
+                if skip { "skipped" } else { "warned" }
+            );
+            hits.push(DuplicateHit {
+                new_content: memory.text.clone(),
+                existing_memory_id: top.id.clone(),
+                existing_content: top.content.clone(),
+                similarity: top.score,
+                action: if skip { "skipped" } else { "warned" }.to_string(),
+                suggestion: if skip {
+                    format!("Already stored as {}; nothing new was written.", top.id)
+                } else {
+                    format!(
+                        "{} already says nearly the same; update_memory or append_memory on it keeps one copy.",
+                        top.id
+                    )
+                },
+            });
+            if skip {
+                skipped[i] = Some((top.id.clone(), top.score));
+            }
+        }
+        (hits, skipped)
+    }
+
     /// The shared post-extraction pipeline: embed → recall → decide → execute
     /// → cross-memory relations → optional raw-source preservation.
+    /// `force_raw_source` keeps `raw_message` even below the length/atom-count
+    /// gate (the caller asked for it via `store_source`).
     #[allow(clippy::too_many_arguments)]
     async fn run_add_pipeline(
         &self,
@@ -148,6 +301,7 @@ impl ToolingManager {
         extracted_entities: &[ExtractedEntity],
         extracted_relations: &[ExtractedRelation],
         raw_message: Option<&str>,
+        force_raw_source: bool,
         user_id: &str,
         agent_id: Option<&str>,
         tags: &str,
@@ -170,16 +324,28 @@ impl ToolingManager {
             memories_to_store.len()
         );
         let memory_texts: Vec<&str> = memories_to_store.iter().map(|m| m.text.as_str()).collect();
-        let all_embeddings = self
-            .embedder
-            .generate_batch(&memory_texts, true)
-            .await
-            .map_err(|e| ToolingError::Embedding(e.to_string()))?;
+        // `None` = embedding failed under on_embed_fail=defer: nothing to
+        // recall against, so every fact is stored as a new, unembedded memory.
+        let all_embeddings = match self.embedder.generate_batch(&memory_texts, true).await {
+            Ok(vectors) => Some(vectors),
+            Err(e) if self.config.write.on_embed_fail == OnEmbedFail::Defer => {
+                warn!(
+                    "Embedding failed, storing {} memories without vectors (on_embed_fail=defer): {}",
+                    memories_to_store.len(),
+                    e
+                );
+                None
+            }
+            Err(e) => return Err(ToolingError::Embedding(e.to_string())),
+        };
 
         // Phase A: recall similar memories for every fact.
         let mut recall: Vec<Vec<SimilarMemory>> = Vec::with_capacity(memories_to_store.len());
         for (i, memory) in memories_to_store.iter().enumerate() {
-            let vector = &all_embeddings[i];
+            let Some(vector) = all_embeddings.as_ref().map(|v| &v[i]) else {
+                recall.push(Vec::new());
+                continue;
+            };
             let similar_results = self
                 .search_engine
                 .search(
@@ -229,6 +395,11 @@ impl ToolingManager {
             recall.push(similar_memories);
         }
 
+        // Write-time duplicate check (write.dedup_on_add): a skipped fact
+        // never reaches the decision engine; a warned one goes on as usual.
+        let (duplicates, skipped_as_duplicate) =
+            self.check_duplicates_on_add(&memories_to_store, &recall);
+
         // Phase B: decisions. Under algo_opt all gray-zone facts are judged
         // in ONE LLM call (W1, #32); deterministic gates never reach the
         // model either way. Legacy keeps the per-fact loop.
@@ -239,25 +410,50 @@ impl ToolingManager {
             .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
             .unwrap_or(false);
 
-        let decisions: Vec<crate::llm::decision::MemoryDecision> =
-            if batch_enabled && memories_to_store.len() > 1 {
-                let items: Vec<(String, String, Vec<SimilarMemory>)> = memories_to_store
+        let pending: Vec<usize> = (0..memories_to_store.len())
+            .filter(|i| skipped_as_duplicate[*i].is_none())
+            .collect();
+        let judged: Vec<crate::llm::decision::MemoryDecision> =
+            if batch_enabled && pending.len() > 1 {
+                let items: Vec<(String, String, Vec<SimilarMemory>)> = pending
                     .iter()
-                    .zip(recall.iter())
-                    .map(|(m, sims)| (m.text.clone(), m.memory_type.clone(), sims.clone()))
+                    .map(|&i| {
+                        let m = &memories_to_store[i];
+                        (m.text.clone(), m.memory_type.clone(), recall[i].clone())
+                    })
                     .collect();
                 self.decision_engine.decide_batch(&items, user_id).await
             } else {
-                let mut out = Vec::with_capacity(memories_to_store.len());
-                for (memory, sims) in memories_to_store.iter().zip(recall.iter()) {
+                let mut out = Vec::with_capacity(pending.len());
+                for &i in &pending {
+                    let memory = &memories_to_store[i];
                     out.push(
                         self.decision_engine
-                            .decide(&memory.text, &memory.memory_type, sims, user_id)
+                            .decide(&memory.text, &memory.memory_type, &recall[i], user_id)
                             .await,
                     );
                 }
                 out
             };
+        let mut judged = judged.into_iter();
+        let decisions: Vec<crate::llm::decision::MemoryDecision> = skipped_as_duplicate
+            .iter()
+            .map(|dup| match dup {
+                Some((target, similarity)) => crate::llm::decision::MemoryDecision {
+                    target_memory_id: Some(target.clone()),
+                    ..crate::llm::decision::MemoryDecision::noop(
+                        95,
+                        format!(
+                            "dedup_on_add: {similarity:.3} >= {}",
+                            self.config.write.dedup_on_add_threshold
+                        ),
+                    )
+                },
+                None => judged.next().unwrap_or_else(|| {
+                    crate::llm::decision::MemoryDecision::add(50, "no decision returned")
+                }),
+            })
+            .collect();
 
         // Phase C: execute.
         for (i, memory) in memories_to_store.iter().enumerate() {
@@ -267,7 +463,7 @@ impl ToolingManager {
                 memories_to_store.len(),
                 safe_truncate(&memory.text, 30)
             );
-            let vector = &all_embeddings[i];
+            let vector: &[f32] = all_embeddings.as_ref().map_or(&[], |v| &v[i]);
             let similar_memories = &recall[i];
             let mut decision = decisions[i].clone();
 
@@ -583,9 +779,13 @@ impl ToolingManager {
             }
         }
 
-        if let Some(message) = raw_message
-            .filter(|m| m.len() > self.config.write.raw_source_min_chars && added_ids.len() > 1)
-        {
+        if let Some(message) = raw_message.filter(|m| {
+            if force_raw_source {
+                !added_ids.is_empty()
+            } else {
+                m.len() > self.config.write.raw_source_min_chars && added_ids.len() > 1
+            }
+        }) {
             let raw_mem = ExtractedMemory {
                 text: message.to_string(),
                 memory_type: "fact".to_string(),
@@ -622,6 +822,25 @@ impl ToolingManager {
                                         atom_id, raw_id, e
                                     );
                                 }
+                                // Provenance proper: what search reads the
+                                // source excerpt from, in one batch.
+                                if let Err(e) = self
+                                    .db
+                                    .execute_query::<serde_json::Value, _>(
+                                        "linkExtractedFrom",
+                                        &serde_json::json!({
+                                            "atom_id": atom_id,
+                                            "source_id": raw_id,
+                                            "created_at": chrono::Utc::now().to_rfc3339(),
+                                        }),
+                                    )
+                                    .await
+                                {
+                                    warn!(
+                                        "EXTRACTED_FROM link {} -> {} failed: {}",
+                                        atom_id, raw_id, e
+                                    );
+                                }
                             }
                         }
                         Err(e) => warn!("Failed to store raw source: {}", e),
@@ -654,6 +873,11 @@ impl ToolingManager {
             serde_json::Value::String(user_id.to_string()),
         );
 
+        let needs_embedding = if all_embeddings.is_none() {
+            added_ids.clone()
+        } else {
+            Vec::new()
+        };
         Ok(AddMemoryResult {
             added: added_ids,
             updated: updated_ids,
@@ -665,6 +889,9 @@ impl ToolingManager {
             chunks_created,
             metadata,
             needs_clarification: clarifications,
+            no_facts_extracted: false,
+            duplicates,
+            needs_embedding,
         })
     }
 }
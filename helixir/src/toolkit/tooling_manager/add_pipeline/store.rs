@@ -2,7 +2,9 @@
 //!
 //! - [`ToolingManager::store_new_memory`] is the canonical write: creates the
 //!   `Memory` node, attaches the embedding, links the user, optionally chunks
-//!   long content, and ties the memory to its context tag.
+//!   long content, and ties the memory to its context tag. An empty vector
+//!   (embedding deferred) skips the embedding and flags the node
+//!   `needs_embedding` for `reindex_embeddings`.
 //! - [`ToolingManager::store_raw_source`] preserves the original long input
 //!   alongside the atomized facts (source="raw_input").
 
@@ -12,6 +14,7 @@ use tracing::{debug, info, warn};
 
 use crate::llm::extractor::ExtractedMemory;
 
+use super::super::reindex::NEEDS_EMBEDDING_KEY;
 use super::super::{ToolingError, ToolingManager};
 
 /// Deterministic content fingerprint for cross-user grouping (#43). Identical
@@ -91,7 +94,11 @@ impl ToolingManager {
             valid_from: now.clone(),
             context_tags: context_tags.to_string(),
             source: "llm_extraction".to_string(),
-            metadata: "{}".to_string(),
+            metadata: if vector.is_empty() {
+                serde_json::json!({ NEEDS_EMBEDDING_KEY: true }).to_string()
+            } else {
+                "{}".to_string()
+            },
         };
 
         #[derive(serde::Deserialize)]
@@ -127,7 +134,9 @@ impl ToolingManager {
             created_at: now.clone(),
         };
 
-        if let Err(e) = self
+        if vector.is_empty() {
+            debug!("Embedding deferred for {}", memory_id);
+        } else if let Err(e) = self
             .db
             .execute_query::<serde_json::Value, _>("addMemoryEmbedding", &embed_input)
             .await
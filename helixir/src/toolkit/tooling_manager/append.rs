@@ -0,0 +1,153 @@
+//! `append_memory`: grow a stored memory instead of replacing it. The
+//! original text is kept verbatim as the prefix, the combined text is
+//! re-embedded and re-extracted, entities it names are linked, and its
+//! reasoning edges are re-judged (additions only).
+
+use std::collections::HashSet;
+
+use tracing::{info, warn};
+
+use super::ToolingManager;
+use super::types::{AppendOutcome, ToolingError};
+use crate::toolkit::mind_toolbox::entity::EntityEdgeType;
+
+/// Lowercased words of three or more characters.
+fn content_words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// An extracted fact is new when fewer than half of its words already
+/// occur in the original content — a restatement of what was there scores
+/// as known even when the extractor rewords it.
+fn is_new_fact(fact: &str, original: &HashSet<String>) -> bool {
+    let words = content_words(fact);
+    if words.is_empty() {
+        return false;
+    }
+    let known = words.iter().filter(|w| original.contains(*w)).count();
+    known * 2 < words.len()
+}
+
+impl ToolingManager {
+    /// Append `additional` to `memory_id` on a new line. Extraction or
+    /// linking failures are logged and leave the counts at zero; the
+    /// content update itself must succeed.
+    pub async fn append_memory(
+        &self,
+        memory_id: &str,
+        additional: &str,
+        user_id: &str,
+    ) -> Result<AppendOutcome, ToolingError> {
+        let record = self
+            .get_memory_record(memory_id)
+            .await?
+            .ok_or_else(|| ToolingError::Memory(format!("Memory {memory_id} not found")))?;
+        let original = record.node["content"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        let combined = format!("{}\n{}", original.trim_end(), additional.trim());
+        // Growing a memory does not re-score it: keep what the node holds
+        // rather than `update_memory`'s defaults.
+        let internal_id = record.node["id"].as_str().unwrap_or_default();
+        let score = |key: &str, default: u8| {
+            record.node[key]
+                .as_i64()
+                .map_or(default as i64, |v| v.clamp(0, 100))
+        };
+        self.rewrite_memory(
+            internal_id,
+            &combined,
+            score("certainty", self.config.default_certainty),
+            score("importance", self.config.default_importance),
+        )
+        .await?;
+
+        let mut outcome = AppendOutcome {
+            content: combined.clone(),
+            ..AppendOutcome::default()
+        };
+        let extraction = match self
+            .extractor
+            .extract(&combined, user_id, true, false)
+            .await
+        {
+            Ok(extraction) => extraction,
+            Err(e) => {
+                warn!("append_memory: extraction for {memory_id} failed: {e}");
+                return Ok(outcome);
+            }
+        };
+
+        let original_words = content_words(&original);
+        outcome.new_facts_added = extraction
+            .memories
+            .iter()
+            .filter(|m| is_new_fact(&m.text, &original_words))
+            .count();
+
+        let mut linked: HashSet<String> =
+            record.entities.iter().map(|n| n.to_lowercase()).collect();
+        for entity in &extraction.entities {
+            if !linked.insert(entity.name.trim().to_lowercase()) {
+                continue;
+            }
+            let stored = match self
+                .entity_manager
+                .get_or_create_entity(&entity.name, &entity.entity_type, None)
+                .await
+            {
+                Ok(stored) => stored,
+                Err(e) => {
+                    warn!("append_memory: entity '{}' failed: {e}", entity.name);
+                    continue;
+                }
+            };
+            match self
+                .entity_manager
+                .link_to_memory(
+                    &stored.entity_id,
+                    memory_id,
+                    EntityEdgeType::ExtractedEntity,
+                    self.config.write.entity_link_strength as i32,
+                    self.config.write.entity_link_confidence as i32,
+                    "neutral",
+                )
+                .await
+            {
+                Ok(()) => outcome.entities_linked += 1,
+                Err(e) => warn!("append_memory: linking '{}' failed: {e}", entity.name),
+            }
+        }
+
+        outcome.relations_added = self.rejudge_memory_relations(memory_id, &combined).await;
+        info!(
+            "append_memory {memory_id}: {} new facts, {} entities, {} relations",
+            outcome.new_facts_added, outcome.entities_linked, outcome.relations_added
+        );
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restated_facts_are_not_new() {
+        let original = content_words("Alice owns the billing service.");
+        assert!(!is_new_fact("Alice owns the billing service", &original));
+        assert!(!is_new_fact(
+            "The billing service is owned by Alice",
+            &original
+        ));
+        assert!(is_new_fact(
+            "The team decided to use Postgres for billing",
+            &original
+        ));
+        assert!(!is_new_fact("ok", &original));
+    }
+}
@@ -0,0 +1,507 @@
+//! `cluster_memories`: a topic map of a user's memory. The newest
+//! `cluster.max_memories` live memories are grouped by k-means on cosine
+//! similarity of their stored vectors, read back in one batch; only a
+//! memory with no stored vector (or one of another dimension, mid-reindex)
+//! is embedded again. Without a `k`, every k from 2 to `cluster.max_k` is
+//! tried and the best mean silhouette wins.
+//!
+//! Each cluster reports the member closest to its centroid as its
+//! representative and, on request, an LLM-written label. Seeding is
+//! deterministic — the most central memory, then repeatedly the one least
+//! like every seed so far — so the same memories give the same map.
+//! Summaries and superseded memories restate others and are left out.
+//! Nothing is written.
+
+use std::collections::HashMap;
+
+use tracing::{debug, info, warn};
+
+use super::ToolingManager;
+use super::summarize::{SCAN_CHUNK, is_summary};
+use super::types::{ClusterOutcome, MemoryCluster, ToolingError};
+
+const LABEL_SYSTEM_PROMPT: &str = "You name the common topic of a group of memories. \
+Reply with a label of two to five words in the language of the memories, and nothing else.";
+
+/// Members shown to the LLM per label, closest to the centroid first.
+const LABEL_SAMPLE: usize = 5;
+
+/// Lloyd iterations per k-means run at most.
+const MAX_ITERATIONS: usize = 50;
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn normalized(v: &[f32]) -> Vec<f32> {
+    let norm = dot(v, v).sqrt();
+    if norm == 0.0 {
+        v.to_vec()
+    } else {
+        v.iter().map(|x| x / norm).collect()
+    }
+}
+
+/// Unit-length mean of `members`.
+fn centroid(vectors: &[Vec<f32>], members: impl IntoIterator<Item = usize>) -> Vec<f32> {
+    let mut sum = vec![0.0f32; vectors.first().map_or(0, Vec::len)];
+    for i in members {
+        for (s, x) in sum.iter_mut().zip(&vectors[i]) {
+            *s += x;
+        }
+    }
+    normalized(&sum)
+}
+
+/// Index of the centroid most similar to `v`; the first on a tie.
+fn nearest(v: &[f32], centroids: &[Vec<f32>]) -> usize {
+    let mut best = (0, f32::MIN);
+    for (c, center) in centroids.iter().enumerate() {
+        let sim = dot(v, center);
+        if sim > best.1 {
+            best = (c, sim);
+        }
+    }
+    best.0
+}
+
+/// Cluster index per unit vector, for at most `k` clusters (fewer when
+/// seeds coincide).
+fn kmeans(vectors: &[Vec<f32>], k: usize) -> Vec<usize> {
+    let n = vectors.len();
+    let mean = centroid(vectors, 0..n);
+    let first = (0..n)
+        .min_by(|&a, &b| dot(&vectors[b], &mean).total_cmp(&dot(&vectors[a], &mean)))
+        .unwrap_or(0);
+    let mut centroids = vec![vectors[first].clone()];
+    while centroids.len() < k.min(n) {
+        let closest_seed = |i: usize| {
+            centroids
+                .iter()
+                .map(|c| dot(&vectors[i], c))
+                .fold(f32::MIN, f32::max)
+        };
+        let next = (0..n)
+            .min_by(|&a, &b| closest_seed(a).total_cmp(&closest_seed(b)))
+            .unwrap_or(0);
+        centroids.push(vectors[next].clone());
+    }
+
+    let mut assignment = vec![usize::MAX; n];
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (i, v) in vectors.iter().enumerate() {
+            let c = nearest(v, &centroids);
+            if assignment[i] != c {
+                assignment[i] = c;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+        for (c, center) in centroids.iter_mut().enumerate() {
+            let members: Vec<usize> = (0..n).filter(|&i| assignment[i] == c).collect();
+            if !members.is_empty() {
+                *center = centroid(vectors, members);
+            }
+        }
+    }
+    assignment
+}
+
+/// Mean silhouette of `assignment` under cosine distance, from the n×n
+/// similarity matrix `sims`. A member of a singleton cluster scores 0.
+fn silhouette(sims: &[f32], assignment: &[usize], k: usize) -> f64 {
+    let n = assignment.len();
+    let mut total = 0.0;
+    for i in 0..n {
+        let mut sum = vec![0.0f64; k];
+        let mut count = vec![0usize; k];
+        for j in (0..n).filter(|&j| j != i) {
+            sum[assignment[j]] += 1.0 - f64::from(sims[i * n + j]);
+            count[assignment[j]] += 1;
+        }
+        let own = assignment[i];
+        if count[own] == 0 {
+            continue;
+        }
+        let a = sum[own] / count[own] as f64;
+        let b = (0..k)
+            .filter(|&c| c != own && count[c] > 0)
+            .map(|c| sum[c] / count[c] as f64)
+            .fold(f64::INFINITY, f64::min);
+        if b.is_finite() && a.max(b) > 0.0 {
+            total += (b - a) / a.max(b);
+        }
+    }
+    if n == 0 { 0.0 } else { total / n as f64 }
+}
+
+/// Assignment for `k` clusters, or — `None` — for the k in `2..=max_k`
+/// with the best silhouette (the smaller k on a tie). Returns the
+/// assignment and its silhouette (`None` for a single cluster).
+fn choose_clustering(
+    vectors: &[Vec<f32>],
+    k: Option<usize>,
+    max_k: usize,
+) -> (Vec<usize>, Option<f64>) {
+    let n = vectors.len();
+    let mut sims = vec![0.0f32; n * n];
+    for i in 0..n {
+        for j in i..n {
+            let s = dot(&vectors[i], &vectors[j]);
+            sims[i * n + j] = s;
+            sims[j * n + i] = s;
+        }
+    }
+    let score = |assignment: &[usize], k: usize| (k > 1).then(|| silhouette(&sims, assignment, k));
+
+    if let Some(k) = k {
+        let k = k.clamp(1, n.max(1));
+        let assignment = kmeans(vectors, k);
+        let s = score(&assignment, k);
+        return (assignment, s);
+    }
+    let mut best: Option<(Vec<usize>, f64)> = None;
+    for k in 2..=max_k.min(n.saturating_sub(1)) {
+        let assignment = kmeans(vectors, k);
+        let s = silhouette(&sims, &assignment, k);
+        if best.as_ref().map_or(true, |(_, top)| s > *top) {
+            best = Some((assignment, s));
+        }
+    }
+    match best {
+        Some((assignment, s)) => (assignment, Some(s)),
+        None => (vec![0; n], None),
+    }
+}
+
+/// The clusters of `assignment`, members ordered by similarity to their
+/// centroid, largest cluster first.
+fn build_clusters(
+    ids: &[String],
+    contents: &[String],
+    vectors: &[Vec<f32>],
+    assignment: &[usize],
+) -> Vec<(MemoryCluster, Vec<usize>)> {
+    let k = assignment.iter().copied().max().map_or(0, |m| m + 1);
+    let mut clusters: Vec<(MemoryCluster, Vec<usize>)> = (0..k)
+        .filter_map(|c| {
+            let members: Vec<usize> = (0..assignment.len())
+                .filter(|&i| assignment[i] == c)
+                .collect();
+            if members.is_empty() {
+                return None;
+            }
+            let center = centroid(vectors, members.iter().copied());
+            let mut ranked: Vec<(usize, f32)> = members
+                .iter()
+                .map(|&i| (i, dot(&vectors[i], &center)))
+                .collect();
+            ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+            let cohesion =
+                ranked.iter().map(|(_, s)| f64::from(*s)).sum::<f64>() / ranked.len() as f64;
+            let order: Vec<usize> = ranked.iter().map(|(i, _)| *i).collect();
+            Some((
+                MemoryCluster {
+                    representative_id: ids[order[0]].clone(),
+                    representative_content: contents[order[0]].clone(),
+                    size: order.len(),
+                    memory_ids: order.iter().map(|&i| ids[i].clone()).collect(),
+                    cohesion,
+                    label: None,
+                },
+                order,
+            ))
+        })
+        .collect();
+    clusters.sort_by(|a, b| {
+        b.0.size
+            .cmp(&a.0.size)
+            .then(b.0.cohesion.total_cmp(&a.0.cohesion))
+    });
+    clusters
+}
+
+/// Stored vector per `memory_id` in a `getMemoryEmbeddingsBatch` reply.
+fn stored_vectors(batch: &serde_json::Value) -> HashMap<String, Vec<f32>> {
+    let rows = |key: &str| batch[key].as_array().cloned().unwrap_or_default();
+    let data: HashMap<String, Vec<f32>> = rows("embeddings")
+        .iter()
+        .filter_map(|v| {
+            let id = v["id"].as_str()?;
+            let values = v["data"].as_array()?;
+            let vector: Vec<f32> = values
+                .iter()
+                .filter_map(|x| x.as_f64().map(|x| x as f32))
+                .collect();
+            (!vector.is_empty()).then(|| (id.to_string(), vector))
+        })
+        .collect();
+    let by_node: HashMap<String, String> = rows("embedding_edges")
+        .iter()
+        .filter_map(|e| {
+            Some((
+                e["from_node"].as_str()?.to_string(),
+                e["to_node"].as_str()?.to_string(),
+            ))
+        })
+        .collect();
+    rows("memories")
+        .iter()
+        .filter_map(|m| {
+            let vector = data.get(by_node.get(m["id"].as_str()?)?)?;
+            Some((m["memory_id"].as_str()?.to_string(), vector.clone()))
+        })
+        .collect()
+}
+
+/// The most common length among `vectors`, the longer one on a tie.
+fn common_dimension(vectors: &HashMap<String, Vec<f32>>) -> Option<usize> {
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for v in vectors.values() {
+        *counts.entry(v.len()).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(len, n)| (n, len))
+        .map(|(len, _)| len)
+}
+
+fn label_prompt(contents: &[String], order: &[usize]) -> String {
+    let mut prompt = String::from("Memories:\n");
+    for &i in order.iter().take(LABEL_SAMPLE) {
+        prompt.push_str(&format!("- {}\n", contents[i]));
+    }
+    prompt
+}
+
+impl ToolingManager {
+    /// Group `user_id`'s memories into topics (see the module docs): `k`
+    /// clusters when given, else the silhouette-best k up to
+    /// `cluster.max_k`. `label` spends one LLM call per cluster; a failed
+    /// call leaves that label empty.
+    pub async fn cluster_memories(
+        &self,
+        user_id: &str,
+        k: Option<usize>,
+        label: bool,
+    ) -> Result<ClusterOutcome, ToolingError> {
+        let cfg = &self.config.cluster;
+        let rows = self
+            .newest_clusterable(user_id, cfg.max_memories.max(1))
+            .await?;
+        if rows.is_empty() {
+            return Ok(ClusterOutcome::default());
+        }
+
+        let (ids, contents): (Vec<String>, Vec<String>) = rows.into_iter().unzip();
+        let vectors: Vec<Vec<f32>> = self
+            .cluster_vectors(&ids, &contents)
+            .await?
+            .iter()
+            .map(|v| normalized(v))
+            .collect();
+
+        let (assignment, silhouette) = choose_clustering(&vectors, k, cfg.max_k.max(2));
+        let mut clusters = build_clusters(&ids, &contents, &vectors, &assignment);
+        if label {
+            for (cluster, order) in &mut clusters {
+                match self
+                    .llm_provider
+                    .generate(LABEL_SYSTEM_PROMPT, &label_prompt(&contents, order), None)
+                    .await
+                {
+                    Ok((text, _)) => {
+                        let text = text.trim().trim_matches('"').trim();
+                        cluster.label = (!text.is_empty()).then(|| text.to_string());
+                    }
+                    Err(e) => warn!(
+                        "cluster_memories: labelling {} failed: {e}",
+                        cluster.representative_id
+                    ),
+                }
+            }
+        }
+
+        let outcome = ClusterOutcome {
+            k: clusters.len(),
+            auto_k: k.is_none(),
+            silhouette,
+            memories_clustered: ids.len(),
+            clusters: clusters.into_iter().map(|(c, _)| c).collect(),
+        };
+        info!(
+            "cluster_memories {user_id}: {} memories in {} clusters (auto_k={}, silhouette={:?})",
+            outcome.memories_clustered, outcome.k, outcome.auto_k, outcome.silhouette
+        );
+        Ok(outcome)
+    }
+
+    /// `(memory_id, content)` of the user's newest memories, at most `max`,
+    /// leaving out deleted, empty, summary and superseded rows. Pages back
+    /// from the newest until `max` are found or the rows run out.
+    async fn newest_clusterable(
+        &self,
+        user_id: &str,
+        max: usize,
+    ) -> Result<Vec<(String, String)>, ToolingError> {
+        let mut rows = Vec::new();
+        let mut start = 0;
+        while rows.len() < max {
+            let chunk = self
+                .user_memories_newest(user_id, start, start + SCAN_CHUNK)
+                .await?;
+            start += chunk.len();
+            let superseded = self
+                .superseded_among(
+                    chunk
+                        .iter()
+                        .filter_map(|m| m["memory_id"].as_str())
+                        .collect(),
+                )
+                .await;
+            rows.extend(chunk.iter().filter_map(|m| {
+                let memory_id = m["memory_id"].as_str()?;
+                let content = m["content"].as_str().unwrap_or_default().trim();
+                (m["is_deleted"].as_i64().unwrap_or(0) == 0
+                    && !is_summary(m)
+                    && !content.is_empty()
+                    && !superseded.contains(memory_id))
+                .then(|| (memory_id.to_string(), content.to_string()))
+            }));
+            if chunk.len() < SCAN_CHUNK {
+                break;
+            }
+        }
+        rows.truncate(max);
+        Ok(rows)
+    }
+
+    /// A vector per memory: the stored one where it has the embedder's
+    /// dimension (the most common stored one before the embedder has
+    /// answered), else `contents[i]` embedded again. An unreadable batch
+    /// re-embeds everything.
+    async fn cluster_vectors(
+        &self,
+        ids: &[String],
+        contents: &[String],
+    ) -> Result<Vec<Vec<f32>>, ToolingError> {
+        let mut stored = match self
+            .db
+            .execute_query::<serde_json::Value, _>(
+                "getMemoryEmbeddingsBatch",
+                &serde_json::json!({ "memory_ids": ids }),
+            )
+            .await
+        {
+            Ok(batch) => stored_vectors(&batch),
+            Err(e) => {
+                debug!("cluster_memories: stored vectors unreadable ({e})");
+                HashMap::new()
+            }
+        };
+        let dim = self
+            .embedder
+            .dimension()
+            .or_else(|| common_dimension(&stored));
+        stored.retain(|_, v| dim.map_or(true, |d| v.len() == d));
+
+        let missing: Vec<usize> = (0..ids.len())
+            .filter(|&i| !stored.contains_key(&ids[i]))
+            .collect();
+        if !missing.is_empty() {
+            debug!(
+                "cluster_memories: re-embedding {} of {} memories",
+                missing.len(),
+                ids.len()
+            );
+            let texts: Vec<&str> = missing.iter().map(|&i| contents[i].as_str()).collect();
+            let fresh = self
+                .embedder
+                .generate_batch(&texts, true)
+                .await
+                .map_err(|e| ToolingError::Embedding(e.to_string()))?;
+            for (&i, vector) in missing.iter().zip(fresh) {
+                stored.insert(ids[i].clone(), vector);
+            }
+        }
+        Ok(ids
+            .iter()
+            .map(|id| stored.remove(id).unwrap_or_default())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two tight groups around orthogonal axes.
+    fn two_topics() -> Vec<Vec<f32>> {
+        [
+            [1.0, 0.1, 0.0],
+            [0.9, 0.0, 0.1],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.1],
+            [0.1, 0.9, 0.0],
+        ]
+        .iter()
+        .map(|v| normalized(v))
+        .collect()
+    }
+
+    #[test]
+    fn silhouette_picks_the_natural_cluster_count() {
+        let vectors = two_topics();
+        let (assignment, silhouette) = choose_clustering(&vectors, None, 4);
+        assert_eq!(assignment[0], assignment[1]);
+        assert_eq!(assignment[1], assignment[2]);
+        assert_eq!(assignment[3], assignment[4]);
+        assert_ne!(assignment[0], assignment[3]);
+        assert!(silhouette.unwrap() > 0.8, "{silhouette:?}");
+
+        let (one, none) = choose_clustering(&vectors, Some(1), 4);
+        assert_eq!(one, vec![0; 5]);
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn stored_vectors_follow_the_embedding_edges() {
+        let batch = serde_json::json!({
+            "memories": [
+                {"id": "n1", "memory_id": "m1"},
+                {"id": "n2", "memory_id": "m2"},
+            ],
+            "embedding_edges": [{"from_node": "n1", "to_node": "v1"}],
+            "embeddings": [{"id": "v1", "data": [0.5, 1.0]}],
+        });
+        let vectors = stored_vectors(&batch);
+        assert_eq!(vectors.len(), 1);
+        assert_eq!(vectors["m1"], vec![0.5, 1.0]);
+
+        let mixed = HashMap::from([
+            ("a".to_string(), vec![0.0; 3]),
+            ("b".to_string(), vec![0.0; 3]),
+            ("c".to_string(), vec![0.0; 2]),
+        ]);
+        assert_eq!(common_dimension(&mixed), Some(3));
+    }
+
+    #[test]
+    fn clusters_are_largest_first_with_the_central_member_as_representative() {
+        let vectors = two_topics();
+        let ids: Vec<String> = (0..5).map(|i| format!("m{i}")).collect();
+        let contents: Vec<String> = (0..5).map(|i| format!("memory {i}")).collect();
+        let (assignment, _) = choose_clustering(&vectors, Some(2), 4);
+        let clusters = build_clusters(&ids, &contents, &vectors, &assignment);
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].0.size, 3);
+        assert_eq!(clusters[0].0.representative_id, "m2");
+        assert_eq!(clusters[0].0.memory_ids[0], "m2");
+        assert_eq!(clusters[1].0.size, 2);
+        assert!(clusters[0].0.cohesion > 0.9);
+    }
+}
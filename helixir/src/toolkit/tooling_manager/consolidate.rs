@@ -78,51 +78,11 @@ impl ToolingManager {
             }
             stats.memories_scanned += 1;
 
-            // Entities this memory extracted.
-            let ents: EntitiesResp = match self
-                .db
-                .execute_query(
-                    "getMemoryEntities",
-                    &serde_json::json!({ "memory_id": m.memory_id }),
-                )
-                .await
-            {
-                Ok(e) => e,
-                Err(e) => {
-                    warn!(
-                        "consolidate: getMemoryEntities failed for {}: {}",
-                        m.memory_id, e
-                    );
-                    continue;
-                }
-            };
-
-            // Memories sharing any of those entities (cross-domain candidates).
-            let mut seen = std::collections::HashSet::new();
-            seen.insert(m.memory_id.clone());
-            let mut candidates: Vec<(String, String)> = Vec::new();
-            for ent in &ents.entities {
-                if ent.entity_id.is_empty() {
-                    continue;
-                }
-                match self
-                    .fetch_memories_by_entity(&ent.entity_id, &m.memory_id, per_memory)
-                    .await
-                {
-                    Ok(co) => {
-                        for (id, content) in co {
-                            if seen.insert(id.clone()) {
-                                candidates.push((id, content));
-                            }
-                        }
-                    }
-                    Err(e) => warn!("consolidate: getMemoriesByEntity failed: {}", e),
-                }
-            }
+            // Memories sharing any of its entities (cross-domain candidates).
+            let candidates = self.entity_candidates(&m.memory_id, per_memory).await;
             if candidates.is_empty() {
                 continue;
             }
-            candidates.truncate(per_memory.max(0) as usize);
 
             // Infer typed relations between this memory and its cross-domain
             // candidates, then persist them.
@@ -163,6 +123,57 @@ impl ToolingManager {
         Ok(stats)
     }
 
+    /// Up to `per_memory` memories sharing an entity with `memory_id`, as
+    /// `(id, content)` pairs. Lookup failures are logged and skipped — a
+    /// background pass works with whatever candidates it can reach.
+    pub(super) async fn entity_candidates(
+        &self,
+        memory_id: &str,
+        per_memory: i64,
+    ) -> Vec<(String, String)> {
+        let ents: EntitiesResp = match self
+            .db
+            .execute_query(
+                "getMemoryEntities",
+                &serde_json::json!({ "memory_id": memory_id }),
+            )
+            .await
+        {
+            Ok(e) => e,
+            Err(e) => {
+                warn!(
+                    "consolidate: getMemoryEntities failed for {}: {}",
+                    memory_id, e
+                );
+                return Vec::new();
+            }
+        };
+
+        let mut seen = std::collections::HashSet::new();
+        seen.insert(memory_id.to_string());
+        let mut candidates: Vec<(String, String)> = Vec::new();
+        for ent in &ents.entities {
+            if ent.entity_id.is_empty() {
+                continue;
+            }
+            match self
+                .fetch_memories_by_entity(&ent.entity_id, memory_id, per_memory)
+                .await
+            {
+                Ok(co) => {
+                    for (id, content) in co {
+                        if seen.insert(id.clone()) {
+                            candidates.push((id, content));
+                        }
+                    }
+                }
+                Err(e) => warn!("consolidate: getMemoriesByEntity failed: {}", e),
+            }
+        }
+        candidates.truncate(per_memory.max(0) as usize);
+        candidates
+    }
+
     async fn fetch_memories_by_entity(
         &self,
         entity_id: &str,
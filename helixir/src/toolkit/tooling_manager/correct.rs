@@ -0,0 +1,151 @@
+//! `correct_memory`: replace a wrong memory without losing it. The
+//! correction is stored as a NEW memory (same type, importance, certainty
+//! and tags) that SUPERSEDES the old one with reason
+//! [`CORRECTED_REASON`] — default search drops the corrected row, while
+//! `get_memory` and `list_memories` (archived) still read it, and the
+//! correction's search rows name it under `corrects`.
+//!
+//! Unlike `add_memory`, the correction skips the decision engine: it must
+//! not be merged into, or deduped against, the memory it corrects. Every
+//! other user holding the corrected memory is linked to the correction.
+
+use tracing::{info, warn};
+
+use super::ToolingManager;
+use super::types::{CorrectionOutcome, ToolingError};
+use crate::llm::extractor::ExtractedMemory;
+use crate::toolkit::mind_toolbox::search::CORRECTED_REASON;
+
+impl ToolingManager {
+    /// Store `corrected` as the successor of `memory_id`. The new memory
+    /// and its SUPERSEDES edge must both be written (a failed edge drops
+    /// the new memory again); linking the old memory's other holders,
+    /// history and the `memory.superseded` event are best-effort.
+    pub async fn correct_memory(
+        &self,
+        memory_id: &str,
+        corrected: &str,
+        user_id: &str,
+    ) -> Result<CorrectionOutcome, ToolingError> {
+        let record = self
+            .get_memory_record(memory_id)
+            .await?
+            .ok_or_else(|| ToolingError::Memory(format!("Memory {memory_id} not found")))?;
+        let old = &record.node;
+        let score = |key: &str, default: u8| {
+            old[key]
+                .as_i64()
+                .map_or(default as i32, |v| v.clamp(0, 100) as i32)
+        };
+        let memory = ExtractedMemory {
+            text: corrected.trim().to_string(),
+            memory_type: old["memory_type"]
+                .as_str()
+                .filter(|t| !t.is_empty())
+                .unwrap_or("fact")
+                .to_string(),
+            certainty: score("certainty", self.config.default_certainty),
+            importance: score("importance", self.config.default_importance),
+            entities: Vec::new(),
+            context: None,
+        };
+
+        let vector = self
+            .embedder
+            .generate(&memory.text, true)
+            .await
+            .map_err(|e| ToolingError::Embedding(e.to_string()))?;
+        let tags = old["context_tags"].as_str().unwrap_or_default();
+        let (new_id, _) = self
+            .store_new_memory(&memory, user_id, &vector, tags)
+            .await?;
+        if let Err(e) = self
+            .record_supersession(&new_id, memory_id, CORRECTED_REASON)
+            .await
+        {
+            // Without the edge the correction would sit next to the wrong
+            // memory as an unrelated peer.
+            if let Err(drop) = self.delete_memory(&new_id).await {
+                warn!("correct_memory: rollback of {new_id} failed: {drop}");
+            }
+            return Err(e);
+        }
+        // Search drops the corrected row for everyone who holds it, so they
+        // all get the correction.
+        let others: Vec<&str> = record
+            .users
+            .iter()
+            .map(String::as_str)
+            .filter(|u| *u != user_id)
+            .collect();
+        if !others.is_empty() {
+            self.share_correction(&new_id, &others, memory.certainty as i64)
+                .await;
+        }
+
+        let previous = old["content"].as_str().unwrap_or_default();
+        if let Err(e) = self
+            .add_memory_history_event(memory_id, "CORRECT", previous, &memory.text, user_id)
+            .await
+        {
+            warn!("correct_memory: history for {memory_id} failed: {e}");
+        }
+        self.emit_memory_superseded(&new_id, memory_id, user_id)
+            .await;
+        info!("correct_memory {user_id}: {new_id} corrects {memory_id}");
+
+        Ok(CorrectionOutcome {
+            memory_id: new_id,
+            corrected_id: memory_id.to_string(),
+            previous_content: previous.to_string(),
+            content: memory.text,
+        })
+    }
+
+    /// Link `users` to the correction `new_id` with the neutral `knows`
+    /// stance and recount its holders. Best-effort per link.
+    async fn share_correction(&self, new_id: &str, users: &[&str], certainty: i64) {
+        let linked_at = chrono::Utc::now().to_rfc3339();
+        for user in users {
+            if let Err(e) = self
+                .db
+                .execute_query::<serde_json::Value, _>(
+                    "linkUserToMemoryWithStance",
+                    &serde_json::json!({
+                        "user_id": user,
+                        "memory_id": new_id,
+                        "context": "correction",
+                        "stance": "knows",
+                        "certainty": certainty,
+                        "linked_at": linked_at,
+                    }),
+                )
+                .await
+            {
+                warn!("correct_memory: linking {user} to {new_id} failed: {e}");
+            }
+        }
+        let count = self
+            .db
+            .execute_query::<serde_json::Value, _>(
+                "getMemoryUserCount",
+                &serde_json::json!({ "memory_id": new_id }),
+            )
+            .await
+            .ok()
+            .and_then(|v| v["count"].as_i64());
+        if let Some(count) = count {
+            let _ = self
+                .db
+                .execute_query::<serde_json::Value, _>(
+                    "updateMemoryUserCount",
+                    &serde_json::json!({
+                        "memory_id": new_id,
+                        "user_count": count,
+                        "updated_at": linked_at,
+                    }),
+                )
+                .await;
+        }
+    }
+}
@@ -1,8 +1,13 @@
+use std::collections::{HashMap, HashSet};
+
 use serde::Serialize;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use super::ToolingManager;
-use super::types::ToolingError;
+use super::types::{MemoryRecord, PurgeStats, ToolingError};
+use crate::core::config::UserConfigOverrides;
+use crate::toolkit::mind_toolbox::memory::MemoryReference;
+use crate::toolkit::mind_toolbox::memory::references::merge_references;
 use crate::utils::nullable_string;
 
 impl ToolingManager {
@@ -14,14 +19,6 @@ impl ToolingManager {
     ) -> Result<bool, ToolingError> {
         info!("Updating memory: {}", memory_id);
 
-        let vector = self
-            .embedder
-            .generate(new_content, true)
-            .await
-            .map_err(|e| ToolingError::Embedding(e.to_string()))?;
-
-        let now = chrono::Utc::now().to_rfc3339();
-
         #[derive(serde::Deserialize)]
         struct GetMemResult {
             #[serde(default)]
@@ -49,22 +46,55 @@ impl ToolingManager {
             }
         };
 
+        // Read the configured defaults (was hardcoded 80/50 — a latent bug:
+        // it diverged silently from config.default_* if those changed).
+        self.rewrite_memory(
+            &internal_id,
+            new_content,
+            self.config.default_certainty as i64,
+            self.config.default_importance as i64,
+        )
+        .await?;
+
+        debug!(
+            "Memory {} (id={}) updated successfully",
+            memory_id, internal_id
+        );
+        Ok(true)
+    }
+
+    /// Store `content` with `certainty` / `importance` on the memory node
+    /// `internal_id` and re-embed it. Embedding the new text must succeed;
+    /// writing the vector afterwards is best effort.
+    pub(super) async fn rewrite_memory(
+        &self,
+        internal_id: &str,
+        content: &str,
+        certainty: i64,
+        importance: i64,
+    ) -> Result<(), ToolingError> {
+        let vector = self
+            .embedder
+            .generate(content, true)
+            .await
+            .map_err(|e| ToolingError::Embedding(e.to_string()))?;
+
+        let now = chrono::Utc::now().to_rfc3339();
+
         #[derive(Serialize)]
-        struct UpdateByIdParams {
-            id: String,
-            content: String,
+        struct UpdateByIdParams<'a> {
+            id: &'a str,
+            content: &'a str,
             certainty: i64,
             importance: i64,
             updated_at: String,
         }
 
         let params = UpdateByIdParams {
-            id: internal_id.clone(),
-            content: new_content.to_string(),
-            // Read the configured defaults (was hardcoded 80/50 — a latent bug:
-            // it diverged silently from config.default_* if those changed).
-            certainty: self.config.default_certainty as i64,
-            importance: self.config.default_importance as i64,
+            id: internal_id,
+            content,
+            certainty,
+            importance,
             updated_at: now.clone(),
         };
 
@@ -74,55 +104,162 @@ impl ToolingManager {
             .await
             .map_err(|e| ToolingError::Database(e.to_string()))?;
 
-        debug!(
-            "Memory {} (id={}) updated successfully",
-            memory_id, internal_id
-        );
-
         #[derive(serde::Deserialize)]
-        struct MemoryResult {
+        #[allow(dead_code)] // HelixDB ack envelope; `embedding` mirrored for schema-error visibility.
+        struct EmbeddingResult {
             #[serde(default)]
-            memory: Option<MemoryData>,
-        }
-        #[derive(serde::Deserialize)]
-        struct MemoryData {
-            #[serde(default, deserialize_with = "nullable_string")]
-            id: String,
+            embedding: serde_json::Value,
         }
 
-        if let Ok(result) = self
+        let _ = self
             .db
-            .execute_query::<MemoryResult, _>(
-                "getMemory",
-                &serde_json::json!({"memory_id": memory_id}),
+            .execute_query::<EmbeddingResult, _>(
+                "addMemoryEmbedding",
+                &serde_json::json!({
+                    "memory_id": internal_id,
+                    "vector_data": vector.iter().map(|&x| x as f64).collect::<Vec<f64>>(),
+                    "embedding_model": self.embedder.model(),
+                    "created_at": now,
+                }),
             )
+            .await;
+
+        Ok(())
+    }
+
+    /// The stored node of one memory with its users, concepts, entities and
+    /// memory-to-memory edges. `None` when no memory has that id; a failed
+    /// neighbourhood read leaves that part empty.
+    pub async fn get_memory_record(
+        &self,
+        memory_id: &str,
+    ) -> Result<Option<MemoryRecord>, ToolingError> {
+        let resp: serde_json::Value = match self
+            .db
+            .execute_query("getMemory", &serde_json::json!({ "memory_id": memory_id }))
             .await
         {
-            if let Some(mem) = result.memory {
-                if !mem.id.is_empty() {
-                    #[derive(serde::Deserialize)]
-                    #[allow(dead_code)] // HelixDB ack envelope; `embedding` mirrored for schema-error visibility.
-                    struct EmbeddingResult {
-                        #[serde(default)]
-                        embedding: serde_json::Value,
-                    }
+            Ok(resp) => resp,
+            // FIRST over no match reads as a graph error, not a null.
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => return Ok(None),
+            Err(e) => return Err(ToolingError::Database(e.to_string())),
+        };
+        let node = resp["memory"].clone();
+        if node["content"].as_str().is_none() {
+            return Ok(None);
+        }
+
+        let logical = self
+            .memory_bucket_query("getMemoryLogicalConnections", memory_id)
+            .await;
+        let graph = self
+            .memory_bucket_query("getMemoryGraphStats", memory_id)
+            .await;
+        let users = self.memory_bucket_query("getMemoryUsers", memory_id).await;
 
-                    let _ = self.db.execute_query::<EmbeddingResult, _>(
-                        "addMemoryEmbedding",
-                        &serde_json::json!({
-                            "memory_id": mem.id,
-                            "vector_data": vector.iter().map(|&x| x as f64).collect::<Vec<f64>>(),
-                            "embedding_model": self.embedder.model(),
-                            "created_at": now,
-                        }),
-                    ).await;
+        let names = |buckets: &[&str], id_key: &str| {
+            let mut out: Vec<String> = Vec::new();
+            for bucket in buckets {
+                for n in graph[*bucket].as_array().into_iter().flatten() {
+                    let name = n["name"]
+                        .as_str()
+                        .filter(|s| !s.is_empty())
+                        .or_else(|| n[id_key].as_str());
+                    if let Some(name) = name {
+                        if !out.iter().any(|o| o == name) {
+                            out.push(name.to_string());
+                        }
+                    }
                 }
             }
+            out
+        };
+        Ok(Some(MemoryRecord {
+            users: users["users"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|u| u["user_id"].as_str().map(str::to_string))
+                .collect(),
+            concepts: names(&["concepts"], "concept_id"),
+            entities: names(&["entities", "mentions"], "entity_id"),
+            // An empty graph projection keeps only the memory buckets.
+            relations: incident_edges(memory_id, &logical, &serde_json::json!({})),
+            node,
+        }))
+    }
+
+    /// Drop `tag` from a memory's `context_tags`. Returns false when the
+    /// memory does not exist or never carried the tag (nothing written).
+    pub async fn remove_context_tag(
+        &self,
+        memory_id: &str,
+        tag: &str,
+    ) -> Result<bool, ToolingError> {
+        let resp: serde_json::Value = self
+            .db
+            .execute_query("getMemory", &serde_json::json!({ "memory_id": memory_id }))
+            .await
+            .map_err(|e| ToolingError::Database(e.to_string()))?;
+        let Some(current) = resp["memory"]["context_tags"].as_str() else {
+            return Ok(false);
+        };
+        let remaining = without_tag(current, tag);
+        if remaining == current {
+            return Ok(false);
         }
 
+        self.db
+            .execute_query::<serde_json::Value, _>(
+                "setMemoryContextTags",
+                &serde_json::json!({ "memory_id": memory_id, "context_tags": remaining }),
+            )
+            .await
+            .map_err(|e| ToolingError::Database(e.to_string()))?;
+        debug!("Removed tag '{}' from {}", tag, memory_id);
         Ok(true)
     }
 
+    /// Attach file/document references to `memory_ids`, merged into each
+    /// node's metadata (an identical uri + locator is not added twice).
+    /// The nodes are read in one batch; each write is best-effort. Returns
+    /// how many memories were written.
+    pub async fn attach_references(
+        &self,
+        memory_ids: &[String],
+        references: &[MemoryReference],
+    ) -> Result<usize, ToolingError> {
+        if references.is_empty() {
+            return Ok(0);
+        }
+        let ids: Vec<&str> = memory_ids.iter().map(String::as_str).collect();
+        let mut attached = 0usize;
+        for node in self.memories_by_ids(&ids).await? {
+            let Some(memory_id) = node["memory_id"].as_str() else {
+                continue;
+            };
+            let current = node["metadata"].as_str().unwrap_or("{}");
+            let metadata = merge_references(current, references);
+            match self
+                .db
+                .execute_query::<serde_json::Value, _>(
+                    "setMemoryMetadata",
+                    &serde_json::json!({ "memory_id": memory_id, "metadata": metadata }),
+                )
+                .await
+            {
+                Ok(_) => attached += 1,
+                Err(e) => warn!("Failed to attach references to {}: {}", memory_id, e),
+            }
+        }
+        debug!(
+            "Attached {} reference(s) to {} memories",
+            references.len(),
+            attached
+        );
+        Ok(attached)
+    }
+
     /// OPERATOR repair path — not exposed over MCP (the memory has no delete
     /// tool by design; supersede is the agent-facing way to retire a fact).
     /// This exists for purging DEBUG ARTIFACTS (e.g. the 2026-07-02 insight
@@ -156,4 +293,343 @@ impl ToolingManager {
 
         Ok(true)
     }
+
+    /// OPERATOR erasure path behind the confirmation-gated `purge_user` tool:
+    /// drops the user's HAS_MEMORY link to every memory they hold and
+    /// recomputes its `user_count`. A memory left with no knowers is
+    /// cascade-dropped (with embedding and chunks); one another user still
+    /// holds stays, whoever authored it. Then any entity no remaining memory
+    /// references goes, then the User node. Best-effort per node: one failed
+    /// drop is logged and the sweep continues, so a re-run picks up whatever
+    /// was left.
+    pub async fn purge_user(&self, user_id: &str) -> Result<PurgeStats, ToolingError> {
+        warn!(
+            "Purging all graph data for user (operator erasure): {}",
+            user_id
+        );
+
+        let mut stats = PurgeStats::default();
+        let mut edges: HashSet<(String, String, String)> = HashSet::new();
+        let mut entities: HashMap<String, String> = HashMap::new();
+        let mut seen: HashSet<String> = HashSet::new();
+
+        // Every handled memory leaves the user's HAS_MEMORY range, so the
+        // first page is always the unhandled rest. A page of memories seen
+        // before means their unlink failed — stop rather than spin.
+        loop {
+            let page = self.user_memory_page(user_id, PURGE_PAGE as i64).await?;
+            let fresh: Vec<_> = page
+                .into_iter()
+                .filter(|m| !m.memory_id.is_empty() && seen.insert(m.memory_id.clone()))
+                .collect();
+            if fresh.is_empty() {
+                break;
+            }
+
+            for memory in fresh {
+                if let Err(e) = self
+                    .db
+                    .execute_query::<serde_json::Value, _>(
+                        "unlinkUserFromMemory",
+                        &serde_json::json!({ "user_id": user_id, "memory_id": memory.memory_id }),
+                    )
+                    .await
+                {
+                    warn!("purge_user: failed to unlink {}: {}", memory.memory_id, e);
+                    continue;
+                }
+
+                // Unknown counts keep the memory, like entities below.
+                let knowers = self
+                    .db
+                    .execute_query::<serde_json::Value, _>(
+                        "getMemoryUserCount",
+                        &serde_json::json!({ "memory_id": memory.memory_id }),
+                    )
+                    .await
+                    .ok()
+                    .and_then(|v| v["count"].as_i64());
+                if knowers != Some(0) {
+                    if let Some(count) = knowers {
+                        let _ = self
+                            .db
+                            .execute_query::<serde_json::Value, _>(
+                                "updateMemoryUserCount",
+                                &serde_json::json!({
+                                    "memory_id": memory.memory_id,
+                                    "user_count": count,
+                                    "updated_at": chrono::Utc::now().to_rfc3339(),
+                                }),
+                            )
+                            .await;
+                    }
+                    stats.memories_unlinked += 1;
+                    continue;
+                }
+
+                let logical = self
+                    .memory_bucket_query("getMemoryLogicalConnections", &memory.memory_id)
+                    .await;
+                let graph = self
+                    .memory_bucket_query("getMemoryGraphStats", &memory.memory_id)
+                    .await;
+                edges.extend(incident_edges(&memory.memory_id, &logical, &graph));
+                for bucket in ["entities", "mentions"] {
+                    for node in graph[bucket].as_array().into_iter().flatten() {
+                        if let (Some(eid), Some(internal)) =
+                            (node["entity_id"].as_str(), node["id"].as_str())
+                        {
+                            entities.insert(eid.to_string(), internal.to_string());
+                        }
+                    }
+                }
+
+                let has_embedding = self
+                    .db
+                    .execute_query::<serde_json::Value, _>(
+                        "getMemoryEmbedding",
+                        &serde_json::json!({ "memory_id": memory.id }),
+                    )
+                    .await
+                    .is_ok_and(|v| !v["embedding"].is_null());
+                let chunks = self
+                    .memory_bucket_query("getMemoryChunks", &memory.memory_id)
+                    .await["chunks"]
+                    .as_array()
+                    .map_or(0, Vec::len);
+
+                match self
+                    .db
+                    .execute_query::<serde_json::Value, _>(
+                        "dropMemoryCascadeByInternalId",
+                        &serde_json::json!({ "memory_internal_id": memory.id }),
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        stats.memories += 1;
+                        stats.embeddings += usize::from(has_embedding);
+                        stats.chunks += chunks;
+                    }
+                    Err(e) => warn!("purge_user: failed to drop {}: {}", memory.memory_id, e),
+                }
+            }
+        }
+        stats.relations = edges.len();
+
+        for (entity_id, internal) in entities {
+            let links = self
+                .db
+                .execute_query::<serde_json::Value, _>(
+                    "countEntityMemoryLinks",
+                    &serde_json::json!({ "entity_id": entity_id }),
+                )
+                .await;
+            // Unknown counts keep the entity: shared state is only dropped
+            // when it is provably orphaned.
+            let orphaned = links.is_ok_and(|v| {
+                v["extracted"].as_u64() == Some(0) && v["mentioned"].as_u64() == Some(0)
+            });
+            if !orphaned {
+                continue;
+            }
+            match self
+                .db
+                .execute_query::<serde_json::Value, _>(
+                    "dropEntityCascadeByInternalId",
+                    &serde_json::json!({ "entity_internal_id": internal }),
+                )
+                .await
+            {
+                Ok(_) => stats.entities += 1,
+                Err(e) => warn!("purge_user: failed to drop entity {}: {}", entity_id, e),
+            }
+        }
+
+        match self
+            .db
+            .execute_query::<serde_json::Value, _>(
+                "dropUserByUserId",
+                &serde_json::json!({ "user_id": user_id }),
+            )
+            .await
+        {
+            Ok(_) => stats.user_node_removed = true,
+            Err(e) => warn!("purge_user: failed to drop user node {}: {}", user_id, e),
+        }
+        if let Err(e) = self
+            .db
+            .execute_query::<serde_json::Value, _>(
+                "dropUserEntityAttributes",
+                &serde_json::json!({ "user_id": user_id }),
+            )
+            .await
+        {
+            warn!(
+                "purge_user: failed to drop entity attributes of {}: {}",
+                user_id, e
+            );
+        }
+        if let Err(e) = self
+            .save_user_config(user_id, &UserConfigOverrides::default())
+            .await
+        {
+            warn!("purge_user: failed to drop config of {}: {}", user_id, e);
+        }
+
+        info!(
+            "Purged user {}: {} memories, {} entities, {} relations, {} embeddings",
+            user_id, stats.memories, stats.entities, stats.relations, stats.embeddings
+        );
+        Ok(stats)
+    }
+
+    async fn user_memory_page(
+        &self,
+        user_id: &str,
+        limit: i64,
+    ) -> Result<Vec<PurgeMemoryNode>, ToolingError> {
+        #[derive(serde::Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            memories: Vec<PurgeMemoryNode>,
+        }
+        match self
+            .db
+            .execute_query::<Resp, _>(
+                "getUserMemories",
+                &serde_json::json!({ "user_id": user_id, "limit": limit }),
+            )
+            .await
+        {
+            Ok(r) => Ok(r.memories),
+            // #19: a user with no HAS_MEMORY edges (or no User node) reads back
+            // as `No value found` — nothing to purge, not a failure.
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => Ok(Vec::new()),
+            Err(e) => Err(ToolingError::Database(e.to_string())),
+        }
+    }
+
+    /// Bucketed per-memory projection; a failed read counts as empty.
+    async fn memory_bucket_query(&self, query: &str, memory_id: &str) -> serde_json::Value {
+        self.db
+            .execute_query::<serde_json::Value, _>(
+                query,
+                &serde_json::json!({ "memory_id": memory_id }),
+            )
+            .await
+            .unwrap_or_default()
+    }
+}
+
+/// Page size for the purge sweep over a user's HAS_MEMORY range.
+const PURGE_PAGE: usize = 200;
+
+#[derive(serde::Deserialize)]
+struct PurgeMemoryNode {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    memory_id: String,
+}
+
+/// Distinct edges around `memory_id` as `(kind, from, to)`, from the
+/// getMemoryLogicalConnections and getMemoryGraphStats projections. Keyed by
+/// endpoints so an edge between two purged memories is counted once.
+fn incident_edges(
+    memory_id: &str,
+    logical: &serde_json::Value,
+    graph: &serde_json::Value,
+) -> Vec<(String, String, String)> {
+    const MEMORY_BUCKETS: [(&str, &str, bool); 8] = [
+        ("implies_out", "IMPLIES", true),
+        ("implies_in", "IMPLIES", false),
+        ("because_out", "BECAUSE", true),
+        ("because_in", "BECAUSE", false),
+        ("contradicts_out", "CONTRADICTS", true),
+        ("contradicts_in", "CONTRADICTS", false),
+        ("relation_out", "MEMORY_RELATION", true),
+        ("relation_in", "MEMORY_RELATION", false),
+    ];
+    const NODE_BUCKETS: [(&str, &str, &str); 4] = [
+        ("entities", "EXTRACTED_ENTITY", "entity_id"),
+        ("mentions", "MENTIONS", "entity_id"),
+        ("concepts", "INSTANCE_OF", "concept_id"),
+        ("categories", "TAGGED_AS", "category_id"),
+    ];
+
+    let mut out = Vec::new();
+    for (bucket, kind, outgoing) in MEMORY_BUCKETS {
+        for node in logical[bucket].as_array().into_iter().flatten() {
+            let Some(other) = node["memory_id"].as_str() else {
+                continue;
+            };
+            let (from, to) = if outgoing {
+                (memory_id, other)
+            } else {
+                (other, memory_id)
+            };
+            out.push((kind.to_string(), from.to_string(), to.to_string()));
+        }
+    }
+    for (bucket, kind, key) in NODE_BUCKETS {
+        for node in graph[bucket].as_array().into_iter().flatten() {
+            let Some(target) = node[key].as_str().or_else(|| node["id"].as_str()) else {
+                continue;
+            };
+            out.push((kind.to_string(), memory_id.to_string(), target.to_string()));
+        }
+    }
+    out
+}
+
+/// `context_tags` minus one tag. The field is usually a single tag (the
+/// tag search matches it whole); a comma-separated list is filtered entry
+/// by entry.
+fn without_tag(tags: &str, tag: &str) -> String {
+    tags.split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty() && *t != tag)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{incident_edges, without_tag};
+    use serde_json::json;
+
+    #[test]
+    fn incident_edges_orients_and_dedups_across_endpoints() {
+        let a_logical = json!({
+            "implies_out": [{ "memory_id": "mem_b" }],
+            "contradicts_in": [{ "memory_id": "mem_c" }],
+        });
+        let a_graph = json!({
+            "entities": [{ "entity_id": "ent_1", "id": "x" }],
+            "concepts": [{ "concept_id": "Preference" }],
+        });
+        let b_logical = json!({ "implies_in": [{ "memory_id": "mem_a" }] });
+
+        let a = incident_edges("mem_a", &a_logical, &a_graph);
+        assert!(a.contains(&("IMPLIES".into(), "mem_a".into(), "mem_b".into())));
+        assert!(a.contains(&("CONTRADICTS".into(), "mem_c".into(), "mem_a".into())));
+        assert!(a.contains(&("EXTRACTED_ENTITY".into(), "mem_a".into(), "ent_1".into())));
+        assert!(a.contains(&("INSTANCE_OF".into(), "mem_a".into(), "Preference".into())));
+
+        // The same IMPLIES edge seen from its other endpoint keys identically.
+        let b = incident_edges("mem_b", &b_logical, &json!({}));
+        let all: std::collections::HashSet<_> = a.into_iter().chain(b).collect();
+        assert_eq!(all.len(), 4);
+    }
+
+    #[test]
+    fn without_tag_clears_single_tag_and_filters_lists() {
+        assert_eq!(without_tag("incomplete_thought", "incomplete_thought"), "");
+        assert_eq!(
+            without_tag("project_x, incomplete_thought", "incomplete_thought"),
+            "project_x"
+        );
+        assert_eq!(without_tag("project_x", "incomplete_thought"), "project_x");
+    }
 }
@@ -0,0 +1,154 @@
+//! Certainty decay (`confidence_decay`): confidence in a memory nobody has
+//! touched for a while halves every `confidence_decay` days, so stale
+//! beliefs stop dominating recall.
+//!
+//! The clock restarts whenever the memory is reinforced: written or updated
+//! (`updated_at`) or re-added as a duplicate (`reinforced_at`). Being
+//! returned by a search does not count — `last_accessed_at` is a usage
+//! signal for `sort`, and letting it reset decay would keep whatever ranks
+//! well ranking well. Pinned memories — `immutable`, or carrying the
+//! [`PINNED_TAG`] context tag — never decay.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use tracing::debug;
+
+use super::ToolingManager;
+
+/// Context tag that exempts a memory from decay.
+pub const PINNED_TAG: &str = "pinned";
+
+/// What decay needs to know about one memory.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DecayInputs {
+    /// Stored certainty, 0-100.
+    pub certainty: i64,
+    /// Latest of creation, update and reinforcement; `None` when none
+    /// parses.
+    pub reinforced_at: Option<DateTime<Utc>>,
+    pub pinned: bool,
+}
+
+impl DecayInputs {
+    /// Multiplier in `(0, 1]` for a memory last reinforced at
+    /// `reinforced_at`, with a `half_life_days` half-life. Pinned memories,
+    /// unknown ages and a non-positive half-life do not decay.
+    pub fn factor(&self, half_life_days: f64, now: DateTime<Utc>) -> f64 {
+        let Some(at) = self.reinforced_at else {
+            return 1.0;
+        };
+        if self.pinned || half_life_days <= 0.0 {
+            return 1.0;
+        }
+        let age_days = (now - at).num_seconds().max(0) as f64 / 86_400.0;
+        0.5f64.powf(age_days / half_life_days)
+    }
+}
+
+fn parse_time(value: &serde_json::Value) -> Option<DateTime<Utc>> {
+    value
+        .as_str()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// `immutable`, or tagged [`PINNED_TAG`].
+pub(super) fn is_pinned(node: &serde_json::Value) -> bool {
+    node["immutable"].as_i64().unwrap_or(0) != 0
+        || node["context_tags"]
+            .as_str()
+            .unwrap_or_default()
+            .split(',')
+            .any(|t| t.trim().eq_ignore_ascii_case(PINNED_TAG))
+}
+
+/// Decay inputs of one stored memory node.
+fn inputs_of(node: &serde_json::Value) -> DecayInputs {
+    let reinforced_at = ["created_at", "updated_at", "reinforced_at"]
+        .iter()
+        .filter_map(|k| parse_time(&node[*k]))
+        .max();
+    DecayInputs {
+        certainty: node["certainty"].as_i64().unwrap_or(100),
+        reinforced_at,
+        pinned: is_pinned(node),
+    }
+}
+
+impl ToolingManager {
+    /// Decay inputs for `memory_ids`, read in one query. A memory that
+    /// cannot be read is left out (and so does not decay).
+    pub async fn decay_inputs(&self, memory_ids: &[String]) -> HashMap<String, DecayInputs> {
+        let ids: Vec<&str> = memory_ids.iter().map(String::as_str).collect();
+        let nodes = match self.memories_by_ids(&ids).await {
+            Ok(nodes) => nodes,
+            Err(e) => {
+                debug!("decay_inputs: batch read failed, no decay applied: {e}");
+                return HashMap::new();
+            }
+        };
+        nodes
+            .iter()
+            .filter_map(|node| Some((node["memory_id"].as_str()?.to_string(), inputs_of(node))))
+            .collect()
+    }
+
+    /// Restart `memory_id`'s decay clock: it was just stated again.
+    /// Best-effort — a failed write only logs.
+    pub(super) async fn reinforce_memory(&self, memory_id: &str) {
+        if let Err(e) = self
+            .db
+            .execute_query::<serde_json::Value, _>(
+                "reinforceMemory",
+                &json!({ "memory_id": memory_id, "reinforced_at": Utc::now().to_rfc3339() }),
+            )
+            .await
+        {
+            debug!("reinforce {memory_id} failed: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halves_per_half_life_from_the_latest_reinforcement() {
+        let now = Utc::now();
+        let days_ago = |d: i64| (now - chrono::Duration::days(d)).to_rfc3339();
+        let node = json!({
+            "certainty": 80,
+            "created_at": days_ago(60),
+            "updated_at": days_ago(20),
+            "reinforced_at": "",
+            "context_tags": "work",
+            "immutable": 0,
+        });
+        let inputs = inputs_of(&node);
+        assert_eq!(inputs.certainty, 80);
+        assert!((inputs.factor(20.0, now) - 0.5).abs() < 1e-3);
+        assert!((inputs.factor(10.0, now) - 0.25).abs() < 1e-3);
+
+        // A fresh re-add restarts the clock; a fresh search hit does not.
+        let mut restated = node.clone();
+        restated["reinforced_at"] = json!(now.to_rfc3339());
+        assert!((inputs_of(&restated).factor(20.0, now) - 1.0).abs() < 1e-6);
+        let mut searched = node.clone();
+        searched["last_accessed_at"] = json!(now.to_rfc3339());
+        assert!((inputs_of(&searched).factor(20.0, now) - 0.5).abs() < 1e-3);
+
+        // Pinned by tag or by the immutable flag.
+        let mut pinned = node.clone();
+        pinned["context_tags"] = json!("work, Pinned");
+        assert_eq!(inputs_of(&pinned).factor(1.0, now), 1.0);
+        pinned["context_tags"] = json!("");
+        pinned["immutable"] = json!(1);
+        assert_eq!(inputs_of(&pinned).factor(1.0, now), 1.0);
+
+        // No parseable timestamp: no decay.
+        assert_eq!(inputs_of(&json!({"certainty": 50})).factor(1.0, now), 1.0);
+    }
+}
@@ -0,0 +1,136 @@
+//! Entity reads and attribute edits for `get_entity` / `set_entity_attribute`,
+//! plus the per-memory attribute lookup behind search's entity filter.
+
+use std::collections::HashMap;
+
+use super::ToolingManager;
+use super::types::{EntityRecord, ToolingError};
+
+impl ToolingManager {
+    /// The entity named `name` and every memory linked to it. `Ok(None)`
+    /// when no entity has that name.
+    pub async fn get_entity_record(
+        &self,
+        name: &str,
+    ) -> Result<Option<EntityRecord>, ToolingError> {
+        let Some(entity) = self.entity_manager.find_by_name(name).await? else {
+            return Ok(None);
+        };
+        let linked: serde_json::Value = self
+            .db
+            .execute_query(
+                "getEntityMemories",
+                &serde_json::json!({ "entity_id": entity.entity_id }),
+            )
+            .await
+            .map_err(|e| ToolingError::Database(e.to_string()))?;
+
+        // Internal ids of knowers and memories, for pairing the link edges.
+        let mut users: HashMap<&str, &str> = HashMap::new();
+        for bucket in ["extracted_knowers", "mentioned_knowers"] {
+            for u in linked[bucket].as_array().into_iter().flatten() {
+                if let (Some(id), Some(user_id)) = (u["id"].as_str(), u["user_id"].as_str()) {
+                    users.insert(id, user_id);
+                }
+            }
+        }
+        let mut memories: Vec<(String, Vec<String>)> = Vec::new();
+        let mut by_internal: HashMap<&str, usize> = HashMap::new();
+        for bucket in ["extracted", "mentioned"] {
+            for m in linked[bucket].as_array().into_iter().flatten() {
+                let Some(id) = m["memory_id"].as_str() else {
+                    continue;
+                };
+                let slot = match memories.iter().position(|(seen, _)| seen == id) {
+                    Some(slot) => slot,
+                    None => {
+                        memories.push((id.to_string(), Vec::new()));
+                        memories.len() - 1
+                    }
+                };
+                if let Some(internal) = m["id"].as_str() {
+                    by_internal.insert(internal, slot);
+                }
+            }
+        }
+        for bucket in ["extracted_links", "mentioned_links"] {
+            for link in linked[bucket].as_array().into_iter().flatten() {
+                let slot = link["to_node"].as_str().and_then(|to| by_internal.get(to));
+                let user = link["from_node"].as_str().and_then(|from| users.get(from));
+                if let (Some(&slot), Some(&user)) = (slot, user) {
+                    let knowers = &mut memories[slot].1;
+                    if !knowers.iter().any(|k| k == user) {
+                        knowers.push(user.to_string());
+                    }
+                }
+            }
+        }
+        Ok(Some(EntityRecord { entity, memories }))
+    }
+
+    /// Set (`Some`) or clear (`None`) one attribute `user_id` keeps on an
+    /// entity. Returns that user's attributes on it.
+    pub async fn set_entity_attribute(
+        &self,
+        entity_id: &str,
+        user_id: &str,
+        key: &str,
+        value: Option<serde_json::Value>,
+    ) -> Result<HashMap<String, serde_json::Value>, ToolingError> {
+        Ok(self
+            .entity_manager
+            .set_property(entity_id, user_id, key, value)
+            .await?)
+    }
+
+    /// Every attribute `user_id` set, by entity id; a failed read counts as
+    /// none.
+    pub async fn user_entity_attributes(
+        &self,
+        user_id: &str,
+    ) -> HashMap<String, HashMap<String, serde_json::Value>> {
+        self.entity_manager
+            .user_properties(user_id)
+            .await
+            .unwrap_or_default()
+    }
+
+    /// Attributes of every entity each of `memory_ids` extracts or
+    /// mentions, by memory id, one map per entity: its stored properties
+    /// with `overlay` (one user's attributes, by entity id) on top. The
+    /// reads run concurrently; a failed read counts as no entities.
+    pub async fn memories_entity_attributes(
+        &self,
+        memory_ids: &[String],
+        overlay: &HashMap<String, HashMap<String, serde_json::Value>>,
+    ) -> HashMap<String, Vec<HashMap<String, serde_json::Value>>> {
+        let lookups = memory_ids.iter().map(|memory_id| async move {
+            let graph: serde_json::Value = self
+                .db
+                .execute_query(
+                    "getMemoryGraphStats",
+                    &serde_json::json!({ "memory_id": memory_id }),
+                )
+                .await
+                .unwrap_or_default();
+            let entities = ["entities", "mentions"]
+                .iter()
+                .flat_map(|bucket| graph[*bucket].as_array().cloned().unwrap_or_default())
+                .map(|e| {
+                    let mut attrs: HashMap<String, serde_json::Value> =
+                        serde_json::from_str(e["properties"].as_str().unwrap_or_default())
+                            .unwrap_or_default();
+                    if let Some(own) = e["entity_id"].as_str().and_then(|id| overlay.get(id)) {
+                        attrs.extend(own.clone());
+                    }
+                    attrs
+                })
+                .collect();
+            (memory_id.clone(), entities)
+        });
+        futures::future::join_all(lookups)
+            .await
+            .into_iter()
+            .collect()
+    }
+}
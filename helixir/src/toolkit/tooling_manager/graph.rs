@@ -1,16 +1,21 @@
-use tracing::info;
+use tracing::{info, warn};
 
 use super::ToolingManager;
-use super::types::ToolingError;
+use super::types::{MemoryGraph, ToolingError};
+use crate::toolkit::mind_toolbox::memory::references::references_in;
 use crate::utils::nullable_string;
 
 impl ToolingManager {
+    /// Breadth-first graph view around `memory_id` (or the user's newest
+    /// memories). A node that fails to load — missing, or a record that no
+    /// longer deserializes — is skipped with a warning instead of failing
+    /// the view; a failed relation read keeps the node and warns.
     pub async fn get_memory_graph(
         &self,
         user_id: &str,
         memory_id: Option<&str>,
         depth: usize,
-    ) -> Result<(Vec<serde_json::Value>, Vec<serde_json::Value>), ToolingError> {
+    ) -> Result<MemoryGraph, ToolingError> {
         info!(
             "Getting memory graph for user={}, memory={:?}, depth={}",
             user_id, memory_id, depth
@@ -24,6 +29,9 @@ impl ToolingManager {
         // See issue #18.
         let mut emitted_edges: std::collections::HashSet<(String, String, &'static str)> =
             std::collections::HashSet::new();
+        let mut emitted_refs: std::collections::HashSet<String> = std::collections::HashSet::new();
+        let mut warnings: Vec<(String, String)> = Vec::new();
+        let mut skipped: std::collections::HashSet<String> = std::collections::HashSet::new();
 
         let start_ids: Vec<String> = if let Some(mid) = memory_id {
             vec![mid.to_string()]
@@ -56,7 +64,7 @@ impl ToolingManager {
         };
 
         if start_ids.is_empty() {
-            return Ok((nodes, edges));
+            return Ok(MemoryGraph::default());
         }
 
         let mut current_ids = start_ids;
@@ -84,23 +92,61 @@ impl ToolingManager {
                     content: String,
                     #[serde(default, deserialize_with = "nullable_string")]
                     memory_type: String,
+                    #[serde(default, deserialize_with = "nullable_string")]
+                    metadata: String,
                 }
 
-                if let Ok(result) = self
+                let loaded = self
                     .db
                     .execute_query::<MemoryResult, _>(
                         "getMemory",
                         &serde_json::json!({"memory_id": mid}),
                     )
-                    .await
-                {
-                    if let Some(mem) = result.memory {
+                    .await;
+                match loaded {
+                    Ok(MemoryResult { memory: Some(mem) }) => {
+                        // File/document references render as their own nodes,
+                        // shared across the memories citing the same spot.
+                        for r in references_in(&mem.metadata) {
+                            let ref_id = r.node_id();
+                            if emitted_refs.insert(ref_id.clone()) {
+                                nodes.push(serde_json::json!({
+                                    "id": ref_id,
+                                    "content": r.title.as_deref().unwrap_or(&r.uri),
+                                    "type": "reference",
+                                    "uri": r.uri,
+                                    "locator": r.locator,
+                                }));
+                            }
+                            edges.push(serde_json::json!({
+                                "source": mem.memory_id,
+                                "target": ref_id,
+                                "type": "REFERENCES",
+                                "weight": 1.0,
+                            }));
+                        }
                         nodes.push(serde_json::json!({
                             "id": mem.memory_id,
                             "content": mem.content,
                             "type": mem.memory_type,
                         }));
                     }
+                    Ok(MemoryResult { memory: None }) => {
+                        warnings.push((mid.clone(), "memory not found".to_string()));
+                        skipped.insert(mid.clone());
+                        continue;
+                    }
+                    Err(e) => {
+                        let reason = if e.to_string().to_lowercase().contains("no value found") {
+                            "memory not found".to_string()
+                        } else {
+                            format!("failed to load: {e}")
+                        };
+                        warn!("get_memory_graph: skipping {mid}: {reason}");
+                        warnings.push((mid.clone(), reason));
+                        skipped.insert(mid.clone());
+                        continue;
+                    }
                 }
 
                 #[derive(serde::Deserialize, Default)]
@@ -124,16 +170,15 @@ impl ToolingManager {
                     #[serde(default)]
                     relation_in: Vec<ConnectedMemory>,
                 }
+                // Only the id: a neighbour's body is read (and may fail) when
+                // the traversal reaches it, not while listing this node's edges.
                 #[derive(serde::Deserialize)]
-                #[allow(dead_code)] // `content` reflected for diagnostics on schema drift.
                 struct ConnectedMemory {
                     #[serde(default, deserialize_with = "nullable_string")]
                     memory_id: String,
-                    #[serde(default, deserialize_with = "nullable_string")]
-                    content: String,
                 }
 
-                if let Ok(conns) = self
+                let conns = match self
                     .db
                     .execute_query::<ConnectionsResult, _>(
                         "getMemoryLogicalConnections",
@@ -141,38 +186,44 @@ impl ToolingManager {
                     )
                     .await
                 {
-                    let edge_groups: &[(&Vec<ConnectedMemory>, &'static str, bool)] = &[
-                        (&conns.implies_out, "IMPLIES", true),
-                        (&conns.implies_in, "IMPLIES", false),
-                        (&conns.because_out, "BECAUSE", true),
-                        (&conns.because_in, "BECAUSE", false),
-                        (&conns.contradicts_out, "CONTRADICTS", true),
-                        (&conns.contradicts_in, "CONTRADICTS", false),
-                        (&conns.relation_out, "SUPPORTS", true),
-                        (&conns.relation_in, "SUPPORTS", false),
-                    ];
-
-                    for (group, edge_type, is_outgoing) in edge_groups {
-                        for conn in group.iter() {
-                            if conn.memory_id.is_empty() {
-                                continue;
-                            }
-                            let (source, target) = if *is_outgoing {
-                                (mid.to_string(), conn.memory_id.clone())
-                            } else {
-                                (conn.memory_id.clone(), mid.to_string())
-                            };
-                            let key = (source.clone(), target.clone(), *edge_type);
-                            if emitted_edges.insert(key) {
-                                edges.push(serde_json::json!({
-                                    "source": source,
-                                    "target": target,
-                                    "type": edge_type,
-                                    "weight": 1.0,
-                                }));
-                            }
-                            next_ids.push(conn.memory_id.clone());
+                    Ok(conns) => conns,
+                    Err(e) => {
+                        warnings.push((mid.clone(), format!("relations failed to load: {e}")));
+                        continue;
+                    }
+                };
+
+                let edge_groups: &[(&Vec<ConnectedMemory>, &'static str, bool)] = &[
+                    (&conns.implies_out, "IMPLIES", true),
+                    (&conns.implies_in, "IMPLIES", false),
+                    (&conns.because_out, "BECAUSE", true),
+                    (&conns.because_in, "BECAUSE", false),
+                    (&conns.contradicts_out, "CONTRADICTS", true),
+                    (&conns.contradicts_in, "CONTRADICTS", false),
+                    (&conns.relation_out, "SUPPORTS", true),
+                    (&conns.relation_in, "SUPPORTS", false),
+                ];
+
+                for (group, edge_type, is_outgoing) in edge_groups {
+                    for conn in group.iter() {
+                        if conn.memory_id.is_empty() {
+                            continue;
+                        }
+                        let (source, target) = if *is_outgoing {
+                            (mid.to_string(), conn.memory_id.clone())
+                        } else {
+                            (conn.memory_id.clone(), mid.to_string())
+                        };
+                        let key = (source.clone(), target.clone(), *edge_type);
+                        if emitted_edges.insert(key) {
+                            edges.push(serde_json::json!({
+                                "source": source,
+                                "target": target,
+                                "type": edge_type,
+                                "weight": 1.0,
+                            }));
                         }
+                        next_ids.push(conn.memory_id.clone());
                     }
                 }
             }
@@ -181,7 +232,22 @@ impl ToolingManager {
             current_depth += 1;
         }
 
-        info!("Graph built: {} nodes, {} edges", nodes.len(), edges.len());
-        Ok((nodes, edges))
+        // Edges recorded from a neighbour before the skipped node was reached
+        // would dangle.
+        edges.retain(|e| {
+            let touches = |k: &str| e[k].as_str().is_some_and(|id| skipped.contains(id));
+            !touches("source") && !touches("target")
+        });
+        info!(
+            "Graph built: {} nodes, {} edges, {} warnings",
+            nodes.len(),
+            edges.len(),
+            warnings.len()
+        );
+        Ok(MemoryGraph {
+            nodes,
+            edges,
+            warnings,
+        })
     }
 }
@@ -1,4 +1,7 @@
-//! Memory ↔ concept linking on the live add path.
+//! Memory ↔ concept linking on the live add path, plus the opt-in LLM
+//! classification FastThink commits use to land in `search_by_concept`.
+
+use std::collections::HashSet;
 
 use tracing::debug;
 
@@ -33,4 +36,191 @@ impl ToolingManager {
         debug!("Linked memory {} to concept {}", memory_id, concept_id);
         Ok(())
     }
+
+    /// Ask the LLM which ontology type a conclusion is (one of
+    /// [`MEMORY_TYPES`]) and how sure it is, 0-100. `Ok(None)` when the answer
+    /// names no known type — the caller stores the memory unclassified.
+    pub(crate) async fn classify_memory_type(
+        &self,
+        text: &str,
+    ) -> Result<Option<(&'static str, i32)>, ToolingError> {
+        const SYS: &str = "You classify one statement from a memory graph into exactly one \
+            ontology type: skill (an ability), preference (a like/dislike or chosen default), \
+            goal (something wanted or planned), fact (a plain statement about the world), \
+            opinion (a judgement), experience (something that happened to someone), \
+            achievement (an accomplished result), action (something done or to do). A decision \
+            reached by reasoning is usually a preference, goal or action — use fact only when \
+            nothing else fits. Respond with JSON only: {\"memory_type\": \"<type>\", \
+            \"confidence\": <0-100>}.";
+
+        let (raw, _meta) = self
+            .llm_provider
+            .generate(SYS, text, Some("json_object"))
+            .await
+            .map_err(|e| ToolingError::Extraction(e.to_string()))?;
+        Ok(parse_classification(&raw))
+    }
+
+    /// Link `memory_id` to the ontology concept of `memory_type` unless it is
+    /// already an INSTANCE_OF it (the add pipeline may have linked it from
+    /// the stored type). Returns whether a new edge was written.
+    pub(crate) async fn ensure_type_concept(
+        &self,
+        memory_id: &str,
+        memory_type: &str,
+        confidence: i32,
+    ) -> Result<bool, ToolingError> {
+        let concept_id = type_concept_id(memory_type);
+        let existing: serde_json::Value = self
+            .db
+            .execute_query(
+                "getMemoryConcepts",
+                &serde_json::json!({ "memory_id": memory_id }),
+            )
+            .await
+            .unwrap_or_default();
+        let linked = existing["instance_of"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .any(|c| c["concept_id"].as_str() == Some(concept_id.as_str()));
+        if linked {
+            return Ok(false);
+        }
+        self.link_memory_to_concept(memory_id, &concept_id, confidence)
+            .await?;
+        Ok(true)
+    }
+
+    /// Which of `memory_ids` are of ontology type `memory_type`: stored as
+    /// that type or an INSTANCE_OF its concept, the test
+    /// `search_by_concept` applies. One query for the whole batch; a batch
+    /// that cannot be read matches nothing.
+    pub(crate) async fn memories_of_type(
+        &self,
+        memory_ids: &[String],
+        memory_type: &str,
+    ) -> HashSet<String> {
+        if memory_ids.is_empty() {
+            return HashSet::new();
+        }
+        match self
+            .db
+            .execute_query::<serde_json::Value, _>(
+                "getMemoryConceptsBatch",
+                &serde_json::json!({ "memory_ids": memory_ids }),
+            )
+            .await
+        {
+            Ok(batch) => typed_among(&batch, memory_type),
+            Err(e) => {
+                debug!("concept boost: type lookup skipped ({e})");
+                HashSet::new()
+            }
+        }
+    }
+}
+
+/// The `memory_id`s in a `getMemoryConceptsBatch` reply whose stored type
+/// is `memory_type` or that have an INSTANCE_OF edge to its concept.
+fn typed_among(batch: &serde_json::Value, memory_type: &str) -> HashSet<String> {
+    let rows = |key: &str| batch[key].as_array().cloned().unwrap_or_default();
+    let concept_id = type_concept_id(memory_type);
+    let type_concepts: HashSet<String> = rows("concepts")
+        .iter()
+        .filter(|c| c["concept_id"].as_str() == Some(concept_id.as_str()))
+        .filter_map(|c| c["id"].as_str().map(str::to_string))
+        .collect();
+    let linked: HashSet<String> = rows("instance_edges")
+        .iter()
+        .filter(|e| {
+            e["to_node"]
+                .as_str()
+                .is_some_and(|to| type_concepts.contains(to))
+        })
+        .filter_map(|e| e["from_node"].as_str().map(str::to_string))
+        .collect();
+    rows("memories")
+        .iter()
+        .filter(|m| {
+            m["memory_type"]
+                .as_str()
+                .is_some_and(|t| t.eq_ignore_ascii_case(memory_type))
+                || m["id"].as_str().is_some_and(|id| linked.contains(id))
+        })
+        .filter_map(|m| m["memory_id"].as_str().map(str::to_string))
+        .collect()
+}
+
+/// The eight ontology types a memory can be (`OntologyType` on the MCP side).
+pub(crate) const MEMORY_TYPES: [&str; 8] = [
+    "skill",
+    "preference",
+    "goal",
+    "fact",
+    "opinion",
+    "experience",
+    "achievement",
+    "action",
+];
+
+/// Ontology concept ids are the capitalised type names ("Preference").
+fn type_concept_id(memory_type: &str) -> String {
+    let mut chars = memory_type.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn parse_classification(raw: &str) -> Option<(&'static str, i32)> {
+    let v: serde_json::Value = serde_json::from_str(raw.trim()).ok()?;
+    let name = v["memory_type"].as_str()?.trim().to_lowercase();
+    let memory_type = MEMORY_TYPES.into_iter().find(|t| *t == name)?;
+    let confidence = v["confidence"].as_i64().unwrap_or(70).clamp(0, 100) as i32;
+    Some((memory_type, confidence))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_classification, type_concept_id, typed_among};
+    use serde_json::json;
+
+    #[test]
+    fn a_type_matches_by_stored_type_or_concept_link() {
+        let batch = json!({
+            "memories": [
+                {"id": "n1", "memory_id": "m_typed", "memory_type": "preference"},
+                {"id": "n2", "memory_id": "m_linked", "memory_type": "fact"},
+                {"id": "n3", "memory_id": "m_other", "memory_type": "fact"},
+            ],
+            "instance_edges": [
+                {"from_node": "n2", "to_node": "c_pref"},
+                {"from_node": "n3", "to_node": "c_fact"},
+            ],
+            "concepts": [
+                {"id": "c_pref", "concept_id": "Preference"},
+                {"id": "c_fact", "concept_id": "Fact"},
+            ],
+        });
+        let mut ids: Vec<_> = typed_among(&batch, "preference").into_iter().collect();
+        ids.sort();
+        assert_eq!(ids, ["m_linked", "m_typed"]);
+        assert!(typed_among(&json!({}), "preference").is_empty());
+    }
+
+    #[test]
+    fn parse_classification_accepts_only_ontology_types() {
+        assert_eq!(
+            parse_classification(r#"{"memory_type": "Preference", "confidence": 88}"#),
+            Some(("preference", 88))
+        );
+        assert_eq!(
+            parse_classification(r#"{"memory_type": "goal"}"#),
+            Some(("goal", 70))
+        );
+        assert_eq!(parse_classification(r#"{"memory_type": "decision"}"#), None);
+        assert_eq!(parse_classification("not json"), None);
+        assert_eq!(type_concept_id("achievement"), "Achievement");
+    }
 }
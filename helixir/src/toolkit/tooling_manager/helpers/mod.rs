@@ -22,3 +22,5 @@ mod history;
 mod queries;
 mod reserved;
 mod users;
+
+pub(crate) use concepts::MEMORY_TYPES;
@@ -0,0 +1,170 @@
+//! Batch-import checkpoints: a progress marker per import so a batch that
+//! dies midway (network, restart) resumes at the first message it did not
+//! finish instead of starting over and re-ingesting what already landed.
+//!
+//! An import is identified by a fingerprint of its user and messages, so the
+//! same batch resent after a crash finds its own checkpoint even when the
+//! caller never saw a resume token. The token itself is
+//! `<import_id>:<next_index>` and carries the progress too, which keeps a
+//! resume working when the checkpoint write was the thing that failed.
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use super::ToolingManager;
+use super::types::ToolingError;
+
+/// Stable id of a batch: sha256 over the user and every message, in order.
+pub fn import_fingerprint(user_id: &str, messages: &[String]) -> String {
+    let mut hasher = Sha256::new();
+    // Length-prefixed, so ["ab", "c"] and ["a", "bc"] differ.
+    for part in std::iter::once(user_id).chain(messages.iter().map(String::as_str)) {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())[..32].to_string()
+}
+
+/// Where a batch stopped, as handed to the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResumeToken {
+    pub import_id: String,
+    pub next_index: usize,
+}
+
+impl ResumeToken {
+    pub fn encode(&self) -> String {
+        format!("{}:{}", self.import_id, self.next_index)
+    }
+
+    pub fn parse(token: &str) -> Option<Self> {
+        let (import_id, next_index) = token.trim().rsplit_once(':')?;
+        if import_id.is_empty() {
+            return None;
+        }
+        Some(Self {
+            import_id: import_id.to_string(),
+            next_index: next_index.parse().ok()?,
+        })
+    }
+}
+
+/// The persisted progress of one import.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportCheckpoint {
+    pub import_id: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub total: i64,
+    #[serde(default)]
+    pub next_index: i64,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+impl ToolingManager {
+    pub async fn get_import_checkpoint(
+        &self,
+        import_id: &str,
+    ) -> Result<Option<ImportCheckpoint>, ToolingError> {
+        #[derive(Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            checkpoint: Option<ImportCheckpoint>,
+        }
+        let resp: Resp = match self
+            .db
+            .execute_query(
+                "getImportCheckpoint",
+                &serde_json::json!({ "import_id": import_id }),
+            )
+            .await
+        {
+            Ok(resp) => resp,
+            // A batch's first call has no checkpoint yet: FIRST over nothing.
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => return Ok(None),
+            Err(e) => return Err(ToolingError::Database(e.to_string())),
+        };
+        Ok(resp.checkpoint.filter(|c| !c.import_id.is_empty()))
+    }
+
+    /// Record that messages before `next_index` are done. `exists` says
+    /// whether a checkpoint node was already written for this import.
+    pub async fn save_import_checkpoint(
+        &self,
+        import_id: &str,
+        user_id: &str,
+        total: usize,
+        next_index: usize,
+        exists: bool,
+    ) -> Result<(), ToolingError> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let (query, params) = if exists {
+            (
+                "updateImportCheckpoint",
+                serde_json::json!({
+                    "import_id": import_id,
+                    "next_index": next_index as i64,
+                    "updated_at": now,
+                }),
+            )
+        } else {
+            (
+                "addImportCheckpoint",
+                serde_json::json!({
+                    "import_id": import_id,
+                    "user_id": user_id,
+                    "total": total as i64,
+                    "next_index": next_index as i64,
+                    "updated_at": now,
+                }),
+            )
+        };
+        self.db
+            .execute_query::<serde_json::Value, _>(query, &params)
+            .await
+            .map(|_| ())
+            .map_err(|e| ToolingError::Database(e.to_string()))
+    }
+
+    /// Forget an import once every message is in (or on an explicit restart).
+    pub async fn delete_import_checkpoint(&self, import_id: &str) -> Result<(), ToolingError> {
+        self.db
+            .execute_query::<serde_json::Value, _>(
+                "deleteImportCheckpoint",
+                &serde_json::json!({ "import_id": import_id }),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| ToolingError::Database(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_tracks_user_order_and_boundaries() {
+        let batch = |xs: &[&str]| xs.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let id = import_fingerprint("u", &batch(&["ab", "c"]));
+        assert_eq!(id, import_fingerprint("u", &batch(&["ab", "c"])));
+        assert_eq!(id.len(), 32);
+        assert_ne!(id, import_fingerprint("u", &batch(&["a", "bc"])));
+        assert_ne!(id, import_fingerprint("u", &batch(&["c", "ab"])));
+        assert_ne!(id, import_fingerprint("v", &batch(&["ab", "c"])));
+    }
+
+    #[test]
+    fn resume_token_round_trips_and_rejects_garbage() {
+        let token = ResumeToken {
+            import_id: "abc123".to_string(),
+            next_index: 42,
+        };
+        assert_eq!(ResumeToken::parse(&token.encode()), Some(token));
+        assert_eq!(ResumeToken::parse("abc123"), None);
+        assert_eq!(ResumeToken::parse(":7"), None);
+        assert_eq!(ResumeToken::parse("abc:-1"), None);
+    }
+}
@@ -0,0 +1,250 @@
+//! In-flight dedup for `add_memory`: two identical adds for the same user
+//! arriving together (a double-click, a client retry racing its original)
+//! would both run extraction and store the same facts twice. While one is
+//! running, an identical one waits for it and returns its result, which
+//! stays shareable for `write.concurrent_add_window_secs` after it lands.
+//!
+//! Identical means same user, agent, context tags, raw-source flag and
+//! trimmed message. A failed or cancelled add shares nothing: whoever waited
+//! on it runs its own. In process only — two server processes still both
+//! write, and the decision engine's dedup then applies as before.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use sha2::{Digest, Sha256};
+use tokio::sync::watch;
+use tracing::info;
+
+use super::types::{AddMemoryResult, ToolingError};
+
+/// Marker a shared result carries in its `metadata`.
+pub const SHARED_RESULT_KEY: &str = "concurrent_add_shared";
+
+/// Dedup key of one add request.
+pub(crate) fn add_key(
+    message: &str,
+    user_id: &str,
+    agent_id: Option<&str>,
+    context_tags: Option<&str>,
+    store_source: bool,
+) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        user_id,
+        agent_id.unwrap_or(""),
+        context_tags.unwrap_or(""),
+        if store_source { "source" } else { "" },
+        message.trim(),
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update([0u8]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+enum Entry {
+    Running(watch::Receiver<Option<AddMemoryResult>>),
+    Done(Instant, Box<AddMemoryResult>),
+}
+
+enum Claim<'a> {
+    Lead(Lease<'a>),
+    Wait(watch::Receiver<Option<AddMemoryResult>>),
+    Shared(Box<AddMemoryResult>),
+}
+
+/// The running add for one key; removes its entry unless it finished with
+/// a result to share.
+struct Lease<'a> {
+    adds: &'a InFlightAdds,
+    key: String,
+    tx: Option<watch::Sender<Option<AddMemoryResult>>>,
+}
+
+impl Lease<'_> {
+    fn finish(mut self, outcome: &Result<AddMemoryResult, ToolingError>) {
+        let Ok(result) = outcome else {
+            return;
+        };
+        let Some(tx) = self.tx.take() else {
+            return;
+        };
+        self.adds.entries.lock().insert(
+            self.key.clone(),
+            Entry::Done(Instant::now(), Box::new(result.clone())),
+        );
+        let _ = tx.send(Some(result.clone()));
+    }
+}
+
+impl Drop for Lease<'_> {
+    fn drop(&mut self) {
+        // Failed or cancelled: dropping the sender wakes the waiters, which
+        // claim again and one of them runs.
+        if self.tx.is_some() {
+            self.adds.entries.lock().remove(&self.key);
+        }
+    }
+}
+
+/// Adds running in this process, and those finished within the window.
+pub(crate) struct InFlightAdds {
+    window: Duration,
+    entries: parking_lot::Mutex<HashMap<String, Entry>>,
+}
+
+impl InFlightAdds {
+    /// `window` of zero turns the dedup off.
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            entries: parking_lot::Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn claim(&self, key: &str) -> Claim<'_> {
+        let mut entries = self.entries.lock();
+        entries.retain(|_, e| !matches!(e, Entry::Done(at, _) if at.elapsed() >= self.window));
+        match entries.get(key) {
+            Some(Entry::Running(rx)) => Claim::Wait(rx.clone()),
+            Some(Entry::Done(_, result)) => Claim::Shared(Box::new(result.as_ref().clone())),
+            None => {
+                let (tx, rx) = watch::channel(None);
+                entries.insert(key.to_string(), Entry::Running(rx));
+                Claim::Lead(Lease {
+                    adds: self,
+                    key: key.to_string(),
+                    tx: Some(tx),
+                })
+            }
+        }
+    }
+
+    /// Run `add` unless an identical add (`key`) is running or just ran, in
+    /// which case its result is returned, marked [`SHARED_RESULT_KEY`].
+    pub(crate) async fn run<F, Fut>(
+        &self,
+        key: String,
+        add: F,
+    ) -> Result<AddMemoryResult, ToolingError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<AddMemoryResult, ToolingError>>,
+    {
+        if self.window.is_zero() {
+            return add().await;
+        }
+        loop {
+            let mut shared = match self.claim(&key) {
+                Claim::Lead(lease) => {
+                    let outcome = add().await;
+                    lease.finish(&outcome);
+                    return outcome;
+                }
+                Claim::Shared(result) => *result,
+                Claim::Wait(mut rx) => match rx.wait_for(Option::is_some).await {
+                    Ok(result) => match result.clone() {
+                        Some(result) => result,
+                        None => continue,
+                    },
+                    Err(_) => continue,
+                },
+            };
+            info!(
+                "identical add already in flight; returning its result ({} added)",
+                shared.added.len()
+            );
+            shared
+                .metadata
+                .insert(SHARED_RESULT_KEY.to_string(), serde_json::Value::Bool(true));
+            return Ok(shared);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn result(id: &str) -> AddMemoryResult {
+        AddMemoryResult {
+            added: vec![id.to_string()],
+            updated: vec![],
+            deleted: vec![],
+            deduped: vec![],
+            skipped: 0,
+            entities_extracted: 0,
+            reasoning_relations_created: 0,
+            chunks_created: 0,
+            metadata: HashMap::new(),
+            needs_clarification: vec![],
+            no_facts_extracted: false,
+            duplicates: vec![],
+            needs_embedding: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn an_identical_add_waits_for_the_running_one() {
+        let adds = InFlightAdds::new(Duration::from_secs(10));
+        let runs = AtomicUsize::new(0);
+        let add = || async {
+            let n = runs.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(result(&format!("mem_{n}")))
+        };
+        let key = add_key("Billing runs on Postgres", "alice", None, None, false);
+
+        let (first, second) = tokio::join!(adds.run(key.clone(), add), adds.run(key.clone(), add));
+        let (first, second) = (first.unwrap(), second.unwrap());
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(first.added, second.added);
+        assert!(!first.metadata.contains_key(SHARED_RESULT_KEY));
+        assert_eq!(second.metadata[SHARED_RESULT_KEY], true);
+
+        // Within the window a finished add is still shared; another user or
+        // another message runs its own.
+        adds.run(key, add).await.unwrap();
+        let other_user = add_key("Billing runs on Postgres", "bob", None, None, false);
+        adds.run(other_user, add).await.unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn a_failed_add_is_not_shared() {
+        let adds = InFlightAdds::new(Duration::from_secs(10));
+        let runs = AtomicUsize::new(0);
+        let add = || async {
+            let n = runs.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            if n == 0 {
+                Err(ToolingError::Extraction("llm down".to_string()))
+            } else {
+                Ok(result("mem_retry"))
+            }
+        };
+        let key = add_key("Billing runs on Postgres", "alice", None, None, false);
+
+        let (first, second) = tokio::join!(adds.run(key.clone(), add), adds.run(key.clone(), add));
+        assert!(first.is_err());
+        assert_eq!(second.unwrap().added, ["mem_retry"]);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn a_zero_window_runs_every_add() {
+        let adds = InFlightAdds::new(Duration::ZERO);
+        let runs = AtomicUsize::new(0);
+        let add = || async {
+            runs.fetch_add(1, Ordering::SeqCst);
+            Ok(result("mem"))
+        };
+        let key = add_key("Billing runs on Postgres", "alice", None, None, false);
+        let _ = tokio::join!(adds.run(key.clone(), add), adds.run(key, add));
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+}
@@ -27,8 +27,9 @@ use tokio::sync::broadcast;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
-use super::ToolingManager;
 use super::types::ToolingError;
+use super::{SHARED_RESULT_KEY, ToolingManager};
+use crate::core::helixir_client::WebhookEvent;
 
 /// A completed (or failed) buffered write, broadcast for best-effort MCP
 /// push (#25 phase 2). The MCP layer subscribes in `on_initialized` and
@@ -377,10 +378,19 @@ impl ToolingManager {
                     "entities_extracted": result.entities_extracted,
                     "relations_created": result.reasoning_relations_created,
                     "needs_clarification": result.needs_clarification,
+                    "no_facts_extracted": result.no_facts_extracted,
+                    "duplicate": result.is_duplicate(),
+                    "duplicates": result.duplicates,
+                    "needs_embedding": result.needs_embedding,
                 });
                 let _ = self
                     .set_pending_status(&node.pending_id, STATUS_DONE, &payload.to_string(), "")
                     .await;
+                // A result shared from an identical add in flight was
+                // announced by that add already.
+                if !result.metadata.contains_key(SHARED_RESULT_KEY) {
+                    self.notify_webhook(WebhookEvent::Add, &result.added, &node.user_id);
+                }
                 // Outbox (прихожая): deliver the outcome to the user's queue,
                 // which the agent drains at session start. The payload doubles
                 // as the add_result and carries any charter escalations.
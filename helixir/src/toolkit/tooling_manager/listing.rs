@@ -0,0 +1,261 @@
+//! `list_memories`: a user's memories by filter, newest first, a page at a
+//! time — no query and no embedding, so a management UI can browse the
+//! store the way `search_memory` never will.
+//!
+//! Paging happens in the database: `getUserMemoriesNewest` hands back the
+//! user's rows by `created_at` descending, a chunk at a time, and filters
+//! (tags, ontology type, a `created_at` window, pinned, archived) apply to
+//! each chunk until the page is full, so a page is short only when the
+//! user's rows ran out. The cursor is a row position, not a match count.
+//! Deleted rows are never listed.
+
+use std::collections::HashSet;
+
+use super::ToolingManager;
+use super::decay::is_pinned;
+use super::types::{MemoryListPage, ToolingError};
+use crate::core::TimeWindow;
+
+/// What [`ToolingManager::list_memories`] keeps. Every field is optional;
+/// the default lists everything.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryListFilter {
+    /// Context tags the memory must carry, all of them (case-insensitive).
+    pub tags: Vec<String>,
+    /// Ontology type (`fact`, `preference`, ...).
+    pub memory_type: Option<String>,
+    /// Bounds on when the memory was stored (`created_at`).
+    pub window: TimeWindow,
+    /// `Some(true)` keeps only pinned memories (`immutable` or tagged
+    /// `pinned`), `Some(false)` only unpinned ones.
+    pub pinned: Option<bool>,
+    /// `Some(true)` keeps only archived memories — ones a newer memory
+    /// supersedes, e.g. the sources `summarize_recent` archived —
+    /// `Some(false)` only current ones.
+    pub archived: Option<bool>,
+}
+
+impl MemoryListFilter {
+    fn keeps(&self, node: &serde_json::Value, archived: &HashSet<String>) -> bool {
+        if node["is_deleted"].as_i64().unwrap_or(0) != 0 {
+            return false;
+        }
+        let Some(memory_id) = node["memory_id"].as_str() else {
+            return false;
+        };
+        if let Some(ref t) = self.memory_type {
+            if node["memory_type"].as_str() != Some(t.as_str()) {
+                return false;
+            }
+        }
+        let tags: Vec<&str> = node["context_tags"]
+            .as_str()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .collect();
+        if !self
+            .tags
+            .iter()
+            .all(|want| tags.iter().any(|t| t.eq_ignore_ascii_case(want.trim())))
+        {
+            return false;
+        }
+        if self.window.is_active()
+            && !self
+                .window
+                .contains_rfc3339(node["created_at"].as_str().unwrap_or_default())
+        {
+            return false;
+        }
+        if self.pinned.is_some_and(|p| p != is_pinned(node)) {
+            return false;
+        }
+        !self
+            .archived
+            .is_some_and(|a| a != archived.contains(memory_id))
+    }
+}
+
+/// DB rows fetched per round trip while filling a page.
+const LIST_CHUNK: usize = 200;
+
+/// The rows of `chunk` (already newest first) that `filter` keeps, at most
+/// `room` of them, each with its index in `chunk`, and how many rows of
+/// `chunk` were consumed to find them — the next page resumes right after
+/// the last row looked at.
+fn take_matching(
+    chunk: Vec<serde_json::Value>,
+    filter: &MemoryListFilter,
+    archived: &HashSet<String>,
+    room: usize,
+) -> (Vec<(usize, serde_json::Value)>, usize) {
+    let mut kept = Vec::new();
+    let mut used = 0;
+    for row in chunk {
+        if kept.len() >= room {
+            break;
+        }
+        if filter.keeps(&row, archived) {
+            kept.push((used, row));
+        }
+        used += 1;
+    }
+    (kept, used)
+}
+
+impl ToolingManager {
+    /// One page of `user_id`'s memories matching `filter`, newest first.
+    /// `offset` is a row position in the user's newest-first HAS_MEMORY
+    /// list; pass the page's `next_offset` back as `offset` for the next one.
+    pub async fn list_memories(
+        &self,
+        user_id: &str,
+        filter: &MemoryListFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<MemoryListPage, ToolingError> {
+        let limit = limit.max(1);
+        let total_rows = self.count_user_memories(user_id).await?;
+        let chunk = limit.max(LIST_CHUNK);
+
+        let mut memories = Vec::new();
+        let mut positions = Vec::new();
+        let mut pos = offset;
+        while memories.len() < limit && pos < total_rows {
+            let rows = self.user_memories_newest(user_id, pos, pos + chunk).await?;
+            let fetched = rows.len();
+            // Supersession costs a query; only the archived filter needs it.
+            let archived = if filter.archived.is_some() {
+                self.superseded_among(
+                    rows.iter()
+                        .filter_map(|m| m["memory_id"].as_str())
+                        .collect(),
+                )
+                .await
+            } else {
+                HashSet::new()
+            };
+            let (kept, used) = take_matching(rows, filter, &archived, limit - memories.len());
+            for (at, row) in kept {
+                positions.push(pos + at);
+                memories.push(row);
+            }
+            pos += used;
+            if fetched < chunk {
+                break;
+            }
+        }
+        Ok(MemoryListPage {
+            next_offset: (pos < total_rows).then_some(pos),
+            memories,
+            positions,
+            total_rows,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn node(id: &str, at: &str, memory_type: &str, tags: &str) -> serde_json::Value {
+        json!({
+            "memory_id": id,
+            "content": id,
+            "memory_type": memory_type,
+            "context_tags": tags,
+            "created_at": at,
+            "immutable": 0,
+            "is_deleted": 0,
+        })
+    }
+
+    /// Newest first, the order `getUserMemoriesNewest` returns.
+    fn corpus() -> Vec<serde_json::Value> {
+        let mut gone = node("m_gone", "2026-03-05T00:00:00+00:00", "fact", "");
+        gone["is_deleted"] = json!(1);
+        vec![
+            node("m_apr", "2026-04-10T00:00:00+00:00", "fact", "work"),
+            node("m_mar", "2026-03-10T00:00:00+00:00", "fact", "home"),
+            gone,
+            node(
+                "m_feb",
+                "2026-02-10T00:00:00+00:00",
+                "preference",
+                "Work, pinned",
+            ),
+            node("m_jan", "2026-01-10T00:00:00+00:00", "fact", "work"),
+        ]
+    }
+
+    fn ids(rows: &[(usize, serde_json::Value)]) -> Vec<&str> {
+        rows.iter()
+            .filter_map(|(_, m)| m["memory_id"].as_str())
+            .collect()
+    }
+
+    fn keep(filter: &MemoryListFilter, archived: &HashSet<String>) -> Vec<String> {
+        let (rows, _) = take_matching(corpus(), filter, archived, 10);
+        ids(&rows).into_iter().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn a_full_page_stops_at_the_last_row_it_kept() {
+        let all = MemoryListFilter::default();
+        let none = HashSet::new();
+        let (first, used) = take_matching(corpus(), &all, &none, 3);
+        assert_eq!(ids(&first), ["m_apr", "m_mar", "m_feb"]);
+        // The deleted row was looked at, so the cursor lands past it.
+        assert_eq!(used, 4);
+
+        let rest: Vec<_> = corpus().into_iter().skip(used).collect();
+        let (last, used) = take_matching(rest, &all, &none, 3);
+        assert_eq!(ids(&last), ["m_jan"]);
+        assert_eq!(used, 1);
+    }
+
+    #[test]
+    fn filters_apply_before_paging() {
+        let none = HashSet::new();
+        let tagged = MemoryListFilter {
+            tags: vec!["work".to_string()],
+            ..MemoryListFilter::default()
+        };
+        let (rows, used) = take_matching(corpus(), &tagged, &none, 2);
+        assert_eq!(ids(&rows), ["m_apr", "m_feb"]);
+        assert_eq!(rows.iter().map(|(at, _)| *at).collect::<Vec<_>>(), [0, 3]);
+        assert_eq!(used, 4);
+
+        let typed = MemoryListFilter {
+            memory_type: Some("fact".to_string()),
+            window: TimeWindow {
+                from: chrono::DateTime::parse_from_rfc3339("2026-02-01T00:00:00+00:00")
+                    .ok()
+                    .map(|t| t.to_utc()),
+                to: None,
+            },
+            ..MemoryListFilter::default()
+        };
+        assert_eq!(keep(&typed, &none), ["m_apr", "m_mar"]);
+
+        let pinned = MemoryListFilter {
+            pinned: Some(true),
+            ..MemoryListFilter::default()
+        };
+        assert_eq!(keep(&pinned, &none), ["m_feb"]);
+
+        let archived = HashSet::from(["m_jan".to_string()]);
+        let current = MemoryListFilter {
+            archived: Some(false),
+            ..MemoryListFilter::default()
+        };
+        assert_eq!(keep(&current, &archived), ["m_apr", "m_mar", "m_feb"]);
+        let only_archived = MemoryListFilter {
+            archived: Some(true),
+            ..MemoryListFilter::default()
+        };
+        assert_eq!(keep(&only_archived, &archived), ["m_jan"]);
+    }
+}
@@ -1,20 +1,39 @@
+mod access;
+pub use access::AccessStats;
 mod add_pipeline;
+mod append;
 pub mod categories;
 pub mod charter_rules;
+pub mod cluster;
 pub mod consolidate;
 pub mod content_key;
 pub mod contradictions;
+mod correct;
 mod crud;
+pub mod decay;
+pub use decay::DecayInputs;
+mod entities;
 mod events;
 mod graph;
+mod in_flight;
+pub use in_flight::SHARED_RESULT_KEY;
 pub(crate) mod helpers;
+pub mod import;
 pub mod ingest_buffer;
+pub mod listing;
+pub use listing::MemoryListFilter;
 mod reasoning;
+pub use reasoning::THINK_COMMIT_AUTHOR;
+pub mod rebuild;
+pub mod reindex;
 mod search;
-pub use search::MemorySearchOptions;
+pub use search::{MAX_WITHIN_IDS, MemorySearchOptions};
 pub mod seeds;
+pub mod stale;
+pub mod summarize;
 pub mod swarm;
 pub mod types;
+mod user_config;
 
 pub use types::*;
 
@@ -24,10 +43,15 @@ use tracing::{info, warn};
 
 use crate::core::config::HelixirConfig;
 use crate::core::events::EventBus;
+use crate::core::helixir_client::Webhook;
+use crate::core::locale::Locale;
 use crate::db::HelixClient;
 use crate::llm::EmbeddingGenerator;
 use crate::llm::decision::LLMDecisionEngine;
 use crate::llm::extractor::LlmExtractor;
+use crate::llm::prompt_template::{
+    DEFAULT_EXTRACTION_TEMPLATE, DEFAULT_RELATION_TEMPLATE, PromptTemplate,
+};
 use crate::llm::providers::base::LlmProvider;
 use crate::toolkit::mind_toolbox::chunking::ChunkingManager;
 use crate::toolkit::mind_toolbox::entity::EntityManager;
@@ -48,6 +72,11 @@ pub struct ToolingManager {
     pub(crate) search_engine: SearchEngine,
     pub(crate) config: HelixirConfig,
     pub(crate) event_bus: Arc<EventBus>,
+    pub(crate) access: access::AccessTracker,
+    pub(crate) in_flight_adds: in_flight::InFlightAdds,
+    pub(crate) locale: Locale,
+    /// Post-commit webhook endpoint; `None` without `webhook.url`.
+    pub(crate) webhook: Option<Arc<Webhook>>,
 }
 
 impl ToolingManager {
@@ -56,12 +85,19 @@ impl ToolingManager {
         embedder: Arc<EmbeddingGenerator>,
         llm_provider: Arc<dyn LlmProvider>,
         config: &HelixirConfig,
-    ) -> Self {
+    ) -> Result<Self, ToolingError> {
         info!("ToolingManager initialized with full pipeline");
 
         let thresholds = &config.search_thresholds;
 
-        let extractor = LlmExtractor::new(Arc::clone(&llm_provider));
+        let extractor = LlmExtractor::new(Arc::clone(&llm_provider)).with_template(
+            PromptTemplate::load(
+                config.prompts.extraction_path.as_deref(),
+                DEFAULT_EXTRACTION_TEMPLATE,
+                "extraction",
+            )
+            .map_err(ToolingError::Config)?,
+        );
         let decision_engine = LLMDecisionEngine::with_thresholds(
             Arc::clone(&llm_provider),
             thresholds.similarity_threshold,
@@ -72,12 +108,24 @@ impl ToolingManager {
             config.chunking.threshold,
             config.chunking.chunk_size,
         );
-        let entity_manager = EntityManager::new(Arc::clone(&db), config.entity_cache_size);
-        let ontology_manager = parking_lot::RwLock::new(OntologyManager::new(Arc::clone(&db)));
+        let entity_manager = EntityManager::new(Arc::clone(&db), config.entity_cache_size)
+            .with_identity(config.entity_identity);
+        let locale = Locale::load(&config.prompts);
+        let ontology_manager = parking_lot::RwLock::new(
+            OntologyManager::new(Arc::clone(&db)).with_concept_keywords(locale.concept_keywords()),
+        );
         let reasoning_engine = ReasoningEngine::new(
             Arc::clone(&db),
             Some(Arc::clone(&llm_provider)),
             config.reasoning_context_limit,
+        )
+        .with_relation_template(
+            PromptTemplate::load(
+                config.prompts.relation_path.as_deref(),
+                DEFAULT_RELATION_TEMPLATE,
+                "relation",
+            )
+            .map_err(ToolingError::Config)?,
         );
         let search_engine = SearchEngine::new(
             Arc::clone(&db),
@@ -85,12 +133,13 @@ impl ToolingManager {
             SearchEngineConfig {
                 search_thresholds: config.search_thresholds.clone(),
                 retrieval: config.retrieval.clone(),
+                windows: config.windows.clone(),
                 ..SearchEngineConfig::default()
             },
         );
         let event_bus = Arc::new(EventBus::new());
 
-        Self {
+        Ok(Self {
             db,
             embedder,
             llm_provider,
@@ -103,7 +152,20 @@ impl ToolingManager {
             search_engine,
             config: config.clone(),
             event_bus,
-        }
+            access: access::AccessTracker::new(std::time::Duration::from_secs(
+                config.retrieval.access_throttle_secs,
+            )),
+            in_flight_adds: in_flight::InFlightAdds::new(std::time::Duration::from_secs(
+                config.write.concurrent_add_window_secs,
+            )),
+            locale,
+            webhook: Webhook::from_config(&config.webhook),
+        })
+    }
+
+    /// Language of the prompts and concept keywords (`HELIXIR_LANG`).
+    pub fn locale(&self) -> &Locale {
+        &self.locale
     }
 
     pub async fn initialize(&self) -> Result<(), ToolingError> {
@@ -116,7 +178,8 @@ impl ToolingManager {
 
         if needs_load {
             let db = Arc::clone(&self.db);
-            let mut ontology_manager = OntologyManager::new(db);
+            let mut ontology_manager =
+                OntologyManager::new(db).with_concept_keywords(self.locale.concept_keywords());
             ontology_manager.load().await.map_err(|e| {
                 warn!("Failed to load ontology: {}", e);
                 ToolingError::from(e)
@@ -1,12 +1,83 @@
+use std::collections::{BTreeSet, HashSet};
+
+use futures::StreamExt;
 use tracing::{debug, info};
 
 use super::ToolingManager;
 use super::types::{
-    ChainNode, ReasoningChainSearchResult, SearchMemoryResult, ToolingError, ToolingReasoningChain,
+    ChainNode, ReasoningChainSearchResult, SearchMemoryResult, SupportingSource, ToolingError,
+    ToolingReasoningChain,
 };
 use crate::safe_truncate;
+use crate::toolkit::mind_toolbox::reasoning::ReasoningChain;
 use crate::toolkit::mind_toolbox::search::SearchOptions;
 
+/// `created_by` of the SUPPORTS edges `think_commit` writes from recalled
+/// evidence to the committed conclusion.
+pub const THINK_COMMIT_AUTHOR: &str = "think_commit";
+
+/// The SUPPORTS edges into a memory (`getMemoryIncomingRelations`) joined
+/// to the memories they come from (`getMemoryLogicalConnections`): commit
+/// evidence first, then strongest first.
+fn supporting_sources(
+    incoming: &serde_json::Value,
+    logical: &serde_json::Value,
+) -> Vec<SupportingSource> {
+    let nodes: std::collections::HashMap<&str, &serde_json::Value> = logical["relation_in"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|n| Some((n["id"].as_str()?, n)))
+        .collect();
+    let mut seen = std::collections::HashSet::new();
+    let mut sources: Vec<SupportingSource> = incoming["relations_in"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|e| e["relation_type"].as_str() == Some("SUPPORTS"))
+        .filter_map(|e| {
+            let node = nodes.get(e["from_node"].as_str()?)?;
+            let memory_id = node["memory_id"].as_str()?;
+            seen.insert(memory_id).then(|| SupportingSource {
+                memory_id: memory_id.to_string(),
+                content: node["content"].as_str().unwrap_or_default().to_string(),
+                strength: e["strength"].as_i64().unwrap_or(0),
+                from_commit: e["created_by"].as_str() == Some(THINK_COMMIT_AUTHOR),
+            })
+        })
+        .collect();
+    sources.sort_by(|a, b| {
+        b.from_commit
+            .cmp(&a.from_commit)
+            .then(b.strength.cmp(&a.strength))
+    });
+    sources
+}
+
+/// A walked chain's hops as [`ChainNode`]s. GH#23: each names the PEER
+/// (the other end of the hop), not the to-endpoint — for incoming edges
+/// `to` is the current node.
+fn chain_nodes(chain: &ReasoningChain) -> Vec<ChainNode> {
+    chain
+        .relations
+        .iter()
+        .map(|r| ChainNode {
+            memory_id: if r.peer_memory_id.is_empty() {
+                r.to_memory_id.clone()
+            } else {
+                r.peer_memory_id.clone()
+            },
+            content: if r.peer_memory_id.is_empty() {
+                r.to_memory_content.clone()
+            } else {
+                r.peer_memory_content.clone()
+            },
+            relation: r.relation_type.edge_name().to_string(),
+            depth: 0,
+        })
+        .collect()
+}
+
 /// True if a `connect_memories` / `route` anchor argument is itself a memory id
 /// (`mem_…` / `raw_…`) rather than a free-text query — in which case it anchors
 /// directly instead of going through best-effort search (#59).
@@ -31,6 +102,26 @@ impl ToolingManager {
             .map_err(|e| ToolingError::Database(e.to_string()))
     }
 
+    /// The memories with a SUPPORTS edge into `memory_id` — for a committed
+    /// conclusion, the recalled evidence it rests on (`from_commit`).
+    pub async fn supporting_sources(
+        &self,
+        memory_id: &str,
+    ) -> Result<Vec<SupportingSource>, ToolingError> {
+        let params = serde_json::json!({ "memory_id": memory_id });
+        let incoming: serde_json::Value = self
+            .db
+            .execute_query("getMemoryIncomingRelations", &params)
+            .await
+            .map_err(|e| ToolingError::Database(e.to_string()))?;
+        let logical: serde_json::Value = self
+            .db
+            .execute_query("getMemoryLogicalConnections", &params)
+            .await
+            .map_err(|e| ToolingError::Database(e.to_string()))?;
+        Ok(supporting_sources(&incoming, &logical))
+    }
+
     /// Does this memory carry any typed causal edge (BECAUSE/IMPLIES, either
     /// direction)? Used to aim chain seeds at causal-bearing candidates.
     async fn has_causal_edges(&self, memory_id: &str) -> bool {
@@ -49,6 +140,23 @@ impl ToolingManager {
         }
     }
 
+    /// The chain walked from one memory the caller already holds, with no
+    /// seed search: `get_goals` starts from each goal's own id, so what it
+    /// reaches does not depend on how the goal is worded.
+    pub async fn chain_from_memory(
+        &self,
+        memory_id: &str,
+        content: &str,
+        chain_mode: &str,
+        max_depth: usize,
+    ) -> Result<Vec<ChainNode>, ToolingError> {
+        let chain = self
+            .reasoning_engine
+            .get_chain(memory_id, content, chain_mode, max_depth, None)
+            .await?;
+        Ok(chain_nodes(&chain))
+    }
+
     pub async fn search_reasoning_chain(
         &self,
         query: &str,
@@ -130,73 +238,91 @@ impl ToolingManager {
                 chains: Vec::new(),
                 total_memories: 0,
                 deepest_chain: 0,
+                truncated: false,
             });
         }
 
-        let mut all_chains = Vec::new();
-        let mut max_chain_depth = 0;
-        let mut total_memories = 0;
-
         // algo_opt R3: hand the query embedding to the chain walker so hop
         // selection runs on cosine similarity instead of an LLM call per hop.
         let guided = crate::core::RetrievalProfile::cached().embedding_guided_chains();
 
-        for seed in &seed_results {
-            let guidance = guided.then(|| crate::toolkit::mind_toolbox::reasoning::ChainGuidance {
-                query_embedding: &query_embedding,
-                embedder: &self.embedder,
-            });
-            match self
-                .reasoning_engine
-                .get_chain(
-                    &seed.memory_id,
-                    &seed.content,
-                    chain_mode,
-                    max_depth,
-                    guidance,
-                )
-                .await
-            {
-                Ok(chain) => {
-                    if !chain.relations.is_empty() {
-                        let chain_depth = chain.depth;
-                        max_chain_depth = max_chain_depth.max(chain_depth);
-                        total_memories += chain.relations.len();
+        // Seeds expand concurrently, at most `chain_seed_concurrency` at a
+        // time (their queries still share the DB pool); `buffered` keeps
+        // seed order, so the result does not depend on which finishes first.
+        let concurrency = self.config.retrieval.chain_seed_concurrency.max(1);
+        let expansions: Vec<_> = seed_results
+            .iter()
+            .map(|seed| {
+                let guidance =
+                    guided.then(|| crate::toolkit::mind_toolbox::reasoning::ChainGuidance {
+                        query_embedding: &query_embedding,
+                        embedder: &self.embedder,
+                    });
+                async move {
+                    let chain = self
+                        .reasoning_engine
+                        .get_chain(
+                            &seed.memory_id,
+                            &seed.content,
+                            chain_mode,
+                            max_depth,
+                            guidance,
+                        )
+                        .await;
+                    (seed, chain)
+                }
+            })
+            .collect();
+        let expansions: Vec<_> = futures::stream::iter(expansions)
+            .buffered(concurrency)
+            .collect()
+            .await;
 
-                        all_chains.push(ToolingReasoningChain {
-                            seed: SearchMemoryResult {
-                                memory_id: seed.memory_id.clone(),
-                                content: seed.content.clone(),
-                                score: seed.score as f64,
-                                method: seed.method.clone(),
-                                metadata: seed.metadata.clone(),
-                                created_at: seed.created_at.clone(),
-                            },
-                            nodes: chain
-                                .relations
-                                .iter()
-                                .map(|r| ChainNode {
-                                    // GH#23: expose the PEER (the other end of
-                                    // the hop), not the to-endpoint — for
-                                    // incoming edges `to` is the current node.
-                                    memory_id: if r.peer_memory_id.is_empty() {
-                                        r.to_memory_id.clone()
-                                    } else {
-                                        r.peer_memory_id.clone()
-                                    },
-                                    content: if r.peer_memory_id.is_empty() {
-                                        r.to_memory_content.clone()
-                                    } else {
-                                        r.peer_memory_content.clone()
-                                    },
-                                    relation: r.relation_type.edge_name().to_string(),
-                                    depth: 0,
-                                })
-                                .collect(),
-                            chain_type: chain.chain_type.clone(),
-                            reasoning_trail: chain.reasoning_trail.clone(),
-                        });
+        let mut all_chains: Vec<ToolingReasoningChain> = Vec::new();
+        let mut max_chain_depth = 0;
+        let mut total_memories = 0;
+        let mut explored = 0usize;
+        // Two seeds on one chain walk it from both ends: keep the first.
+        let mut walked: HashSet<BTreeSet<String>> = HashSet::new();
+
+        for (seed, chain) in expansions {
+            match chain {
+                Ok(chain) => {
+                    if chain.relations.is_empty() {
+                        continue;
                     }
+                    explored += 1;
+                    let nodes = chain_nodes(&chain);
+                    let members: BTreeSet<String> = nodes
+                        .iter()
+                        .map(|n| n.memory_id.clone())
+                        .chain(std::iter::once(seed.memory_id.clone()))
+                        .collect();
+                    if !walked.insert(members) {
+                        debug!(
+                            "Chain from {} already found from another seed",
+                            seed.memory_id
+                        );
+                        continue;
+                    }
+
+                    max_chain_depth = max_chain_depth.max(chain.depth);
+                    total_memories += chain.relations.len();
+                    all_chains.push(ToolingReasoningChain {
+                        seed: SearchMemoryResult {
+                            memory_id: seed.memory_id.clone(),
+                            content: seed.content.clone(),
+                            score: seed.score as f64,
+                            method: seed.method.clone(),
+                            metadata: seed.metadata.clone(),
+                            created_at: seed.created_at.clone(),
+                            hops: seed.hops,
+                        },
+                        nodes,
+                        chain_type: chain.chain_type,
+                        reasoning_trail: chain.reasoning_trail,
+                        truncated: chain.truncated,
+                    });
                 }
                 Err(e) => {
                     debug!("Failed to get chain for {}: {}", seed.memory_id, e);
@@ -205,16 +331,21 @@ impl ToolingManager {
         }
 
         info!(
-            "Found {} chains, max_depth={}, total_memories={}",
+            "Expanded {} seeds (concurrency {}): {} chains explored, {} kept, max_depth={}, total_memories={}",
+            seed_results.len(),
+            concurrency,
+            explored,
             all_chains.len(),
             max_chain_depth,
             total_memories
         );
 
+        let truncated = all_chains.iter().any(|c| c.truncated);
         Ok(ReasoningChainSearchResult {
             chains: all_chains,
             total_memories,
             deepest_chain: max_chain_depth,
+            truncated,
         })
     }
 
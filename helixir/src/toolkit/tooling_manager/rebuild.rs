@@ -0,0 +1,552 @@
+//! Relation rebuild: re-run relation inference over memories that are
+//! already stored and bring their reasoning edges in line with what the
+//! current model and prompts say.
+//!
+//! Per memory, the pair set that gets re-judged is its existing reasoning
+//! neighbours plus the entity-sharing candidates [`super::consolidate`]
+//! uses. Inferred edges that are missing get added; existing edges inside
+//! that pair set that inference no longer proposes are "stale" and are
+//! dropped only when pruning is asked for. CONTRADICTS edges are never
+//! dropped — they carry dispute state a re-inference cannot restore.
+//!
+//! The pass walks users (sorted by id) and their memories in `HAS_MEMORY`
+//! order, a batch at a time; the returned cursor resumes where the batch
+//! stopped, so a large store is rebuilt over several calls. A batch holds
+//! at most [`MAX_REBUILD_BATCH`] memories and one memory's re-judged pair
+//! set at most [`MAX_REBUILD_CANDIDATES`] peers, whatever the caller asks.
+
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use super::ToolingManager;
+use super::types::ToolingError;
+use crate::toolkit::mind_toolbox::reasoning::{ReasoningError, ReasoningType};
+use crate::utils::nullable_string;
+
+/// Most memories one call re-judges.
+pub const MAX_REBUILD_BATCH: usize = 200;
+
+/// Most peers one memory is re-judged against: existing neighbours first,
+/// then entity-sharing candidates. Edges to peers past the cap are left
+/// alone, never counted stale.
+pub const MAX_REBUILD_CANDIDATES: usize = 32;
+
+#[derive(Debug, Clone)]
+pub struct RebuildOptions {
+    /// Compute the diff without writing anything.
+    pub dry_run: bool,
+    /// Drop stale edges. Off by default: additions are safe to repeat,
+    /// deletions trust a single inference run.
+    pub prune: bool,
+    /// Resume point from a previous [`RebuildStats::next_cursor`].
+    pub cursor: Option<String>,
+    /// Memories to re-judge in this call, clamped to 1..=[`MAX_REBUILD_BATCH`].
+    pub batch_size: usize,
+    /// Entity-sharing candidates per memory (bounds the LLM cost); the
+    /// whole pair set is capped at [`MAX_REBUILD_CANDIDATES`].
+    pub per_memory: i64,
+}
+
+impl Default for RebuildOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            prune: false,
+            cursor: None,
+            batch_size: 50,
+            per_memory: 8,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RebuildStats {
+    pub memories_scanned: usize,
+    pub relations_added: usize,
+    /// Edges inference no longer proposes, pruned or not.
+    pub relations_stale: usize,
+    /// Stale edges dropped (or, in a dry run, that pruning would drop).
+    pub relations_removed: usize,
+    pub relations_unchanged: usize,
+    /// Memories whose inference or edge writes failed; re-run from the
+    /// same cursor to retry them.
+    pub failures: usize,
+    /// `None` once every memory in scope has been visited.
+    pub next_cursor: Option<String>,
+}
+
+/// Which edge family a relation persists as — the granularity
+/// `getMemoryLogicalConnections` reports and the drop queries work at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum EdgeFamily {
+    Implies,
+    Because,
+    Contradicts,
+    Relation,
+}
+
+impl EdgeFamily {
+    fn of(relation_type: ReasoningType) -> Self {
+        match relation_type {
+            ReasoningType::Implies => Self::Implies,
+            ReasoningType::Because => Self::Because,
+            ReasoningType::Contradicts => Self::Contradicts,
+            ReasoningType::Supports
+            | ReasoningType::RelatesTo
+            | ReasoningType::PartOf
+            | ReasoningType::IsA => Self::Relation,
+        }
+    }
+
+    fn drop_query(self) -> Option<&'static str> {
+        match self {
+            Self::Implies => Some("dropMemoryImplication"),
+            Self::Because => Some("dropMemoryCausation"),
+            Self::Relation => Some("dropMemoryRelation"),
+            Self::Contradicts => None,
+        }
+    }
+}
+
+/// A directed edge `(family, from, to)`.
+type EdgeKey = (EdgeFamily, String, String);
+
+/// Position of a batched walk over users' memories (rebuild, reindex): the
+/// user being walked and the offset into their memories, rendered
+/// `"<offset>:<user_id>"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct UserCursor {
+    pub(super) user_id: String,
+    pub(super) offset: usize,
+}
+
+impl UserCursor {
+    pub(super) fn parse(raw: &str) -> Result<Self, ToolingError> {
+        let bad = || ToolingError::Memory(format!("invalid cursor '{raw}'"));
+        let (offset, user_id) = raw.split_once(':').ok_or_else(bad)?;
+        if user_id.is_empty() {
+            return Err(bad());
+        }
+        Ok(Self {
+            user_id: user_id.to_string(),
+            offset: offset.parse().map_err(|_| bad())?,
+        })
+    }
+
+    pub(super) fn render(&self) -> String {
+        format!("{}:{}", self.offset, self.user_id)
+    }
+}
+
+/// The edge diff for one re-judged memory. `existing` are the edges touching
+/// it, `inferred` what inference proposes now, `judged` the peers the model
+/// was shown. Returns (edges to add, stale edges). Stale edges are only
+/// outgoing ones whose peer was judged, and never CONTRADICTS.
+fn diff_edges(
+    memory_id: &str,
+    existing: &HashSet<EdgeKey>,
+    inferred: &[EdgeKey],
+    judged: &HashSet<String>,
+) -> (Vec<EdgeKey>, Vec<EdgeKey>) {
+    let proposed: HashSet<&EdgeKey> = inferred.iter().collect();
+    let mut add: Vec<EdgeKey> = Vec::new();
+    for key in inferred {
+        if key.1 != key.2 && !existing.contains(key) && !add.contains(key) {
+            add.push(key.clone());
+        }
+    }
+    let mut stale: Vec<EdgeKey> = existing
+        .iter()
+        .filter(|key| {
+            key.0 != EdgeFamily::Contradicts
+                && key.1 == memory_id
+                && judged.contains(&key.2)
+                && !proposed.contains(key)
+        })
+        .cloned()
+        .collect();
+    stale.sort_by(|a, b| a.2.cmp(&b.2));
+    (add, stale)
+}
+
+#[derive(Deserialize, Default)]
+struct MemoriesResp {
+    #[serde(default)]
+    memories: Vec<MemRow>,
+}
+
+#[derive(Deserialize, Clone)]
+struct MemRow {
+    #[serde(default, deserialize_with = "nullable_string")]
+    memory_id: String,
+    #[serde(default, deserialize_with = "nullable_string")]
+    content: String,
+}
+
+#[derive(Deserialize, Default)]
+struct UsersResp {
+    #[serde(default)]
+    users: Vec<UserRow>,
+}
+
+#[derive(Deserialize)]
+struct UserRow {
+    #[serde(default, deserialize_with = "nullable_string")]
+    user_id: String,
+}
+
+#[derive(Deserialize, Default)]
+struct ConnectionsResp {
+    #[serde(default)]
+    implies_out: Vec<MemRow>,
+    #[serde(default)]
+    implies_in: Vec<MemRow>,
+    #[serde(default)]
+    because_out: Vec<MemRow>,
+    #[serde(default)]
+    because_in: Vec<MemRow>,
+    #[serde(default)]
+    contradicts_out: Vec<MemRow>,
+    #[serde(default)]
+    contradicts_in: Vec<MemRow>,
+    #[serde(default)]
+    relation_out: Vec<MemRow>,
+    #[serde(default)]
+    relation_in: Vec<MemRow>,
+}
+
+impl ToolingManager {
+    /// Re-judge stored memories' reasoning edges (see the module docs).
+    /// `user_id: None` walks every user. Fails up front when no LLM is
+    /// configured, since an empty inference would flag every edge stale.
+    pub async fn rebuild_relations(
+        &self,
+        user_id: Option<&str>,
+        opts: &RebuildOptions,
+    ) -> Result<RebuildStats, ToolingError> {
+        if !self.reasoning_engine.can_infer() {
+            return Err(ToolingError::Reasoning(ReasoningError::Invalid(
+                "rebuild_relations needs an LLM provider for relation inference".to_string(),
+            )));
+        }
+        let mut cursor = opts.cursor.as_deref().map(UserCursor::parse).transpose()?;
+
+        let users: Vec<String> = match user_id {
+            Some(u) => {
+                if cursor.as_ref().is_some_and(|c| c.user_id != u) {
+                    return Err(ToolingError::Memory(format!(
+                        "rebuild cursor belongs to another user than '{u}'"
+                    )));
+                }
+                vec![u.to_string()]
+            }
+            None => self.rebuild_user_ids().await?,
+        };
+
+        let batch = opts.batch_size.clamp(1, MAX_REBUILD_BATCH);
+        let mut stats = RebuildStats::default();
+
+        for user in users {
+            let mut offset = match &cursor {
+                Some(c) if c.user_id > user => continue,
+                Some(c) if c.user_id == user => c.offset,
+                _ => 0,
+            };
+            cursor = None;
+
+            let remaining = batch - stats.memories_scanned;
+            let page: MemoriesResp = match self
+                .db
+                .execute_query(
+                    "getUserMemoriesPage",
+                    &serde_json::json!({
+                        "user_id": user,
+                        "start": offset as i64,
+                        "end": (offset + remaining) as i64,
+                    }),
+                )
+                .await
+            {
+                Ok(p) => p,
+                // A user with zero HAS_MEMORY edges reads as a graph error;
+                // there is simply nothing to rebuild.
+                Err(e) => {
+                    warn!("rebuild: no memories page for {user} at {offset}: {e}");
+                    MemoriesResp::default()
+                }
+            };
+            let fetched = page.memories.len();
+            for m in page.memories.iter().filter(|m| !m.memory_id.is_empty()) {
+                self.rebuild_memory_relations(m, opts, &mut stats).await;
+            }
+            offset += fetched;
+            stats.memories_scanned += fetched;
+
+            if stats.memories_scanned >= batch {
+                stats.next_cursor = Some(
+                    UserCursor {
+                        user_id: user,
+                        offset,
+                    }
+                    .render(),
+                );
+                return Ok(self.log_rebuild(stats, opts));
+            }
+        }
+        Ok(self.log_rebuild(stats, opts))
+    }
+
+    /// Re-judge one memory whose content just changed, additions only.
+    /// Returns the edges added; 0 without an LLM provider.
+    pub(super) async fn rejudge_memory_relations(&self, memory_id: &str, content: &str) -> usize {
+        if !self.reasoning_engine.can_infer() {
+            return 0;
+        }
+        let row = MemRow {
+            memory_id: memory_id.to_string(),
+            content: content.to_string(),
+        };
+        let mut stats = RebuildStats::default();
+        self.rebuild_memory_relations(&row, &RebuildOptions::default(), &mut stats)
+            .await;
+        stats.relations_added
+    }
+
+    fn log_rebuild(&self, stats: RebuildStats, opts: &RebuildOptions) -> RebuildStats {
+        info!(
+            "rebuild_relations(dry_run={}, prune={}): scanned={}, added={}, stale={}, removed={}, unchanged={}, failures={}, done={}",
+            opts.dry_run,
+            opts.prune,
+            stats.memories_scanned,
+            stats.relations_added,
+            stats.relations_stale,
+            stats.relations_removed,
+            stats.relations_unchanged,
+            stats.failures,
+            stats.next_cursor.is_none()
+        );
+        stats
+    }
+
+    pub(super) async fn rebuild_user_ids(&self) -> Result<Vec<String>, ToolingError> {
+        let resp: UsersResp = self
+            .db
+            .execute_query("getAllUsers", &serde_json::json!({}))
+            .await
+            .map_err(|e| ToolingError::Database(e.to_string()))?;
+        let mut ids: Vec<String> = resp
+            .users
+            .into_iter()
+            .map(|u| u.user_id)
+            .filter(|id| !id.is_empty())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        Ok(ids)
+    }
+
+    async fn rebuild_memory_relations(
+        &self,
+        memory: &MemRow,
+        opts: &RebuildOptions,
+        stats: &mut RebuildStats,
+    ) {
+        let id = &memory.memory_id;
+        let conns: ConnectionsResp = match self
+            .db
+            .execute_query(
+                "getMemoryLogicalConnections",
+                &serde_json::json!({ "memory_id": id }),
+            )
+            .await
+        {
+            Ok(c) => c,
+            Err(e) => {
+                warn!("rebuild: getMemoryLogicalConnections failed for {id}: {e}");
+                stats.failures += 1;
+                return;
+            }
+        };
+
+        let mut existing: HashSet<EdgeKey> = HashSet::new();
+        let mut candidates: Vec<(String, String)> = Vec::new();
+        let mut judged: HashSet<String> = HashSet::new();
+        let mut consider = |family: EdgeFamily, peers: &[MemRow], outgoing: bool| {
+            for peer in peers.iter().filter(|p| !p.memory_id.is_empty()) {
+                let (from, to) = if outgoing {
+                    (id.clone(), peer.memory_id.clone())
+                } else {
+                    (peer.memory_id.clone(), id.clone())
+                };
+                existing.insert((family, from, to));
+                if peer.memory_id != *id && judged.insert(peer.memory_id.clone()) {
+                    candidates.push((peer.memory_id.clone(), peer.content.clone()));
+                }
+            }
+        };
+        consider(EdgeFamily::Implies, &conns.implies_out, true);
+        consider(EdgeFamily::Implies, &conns.implies_in, false);
+        consider(EdgeFamily::Because, &conns.because_out, true);
+        consider(EdgeFamily::Because, &conns.because_in, false);
+        consider(EdgeFamily::Contradicts, &conns.contradicts_out, true);
+        consider(EdgeFamily::Contradicts, &conns.contradicts_in, false);
+        consider(EdgeFamily::Relation, &conns.relation_out, true);
+        consider(EdgeFamily::Relation, &conns.relation_in, false);
+
+        let room = MAX_REBUILD_CANDIDATES.saturating_sub(candidates.len()) as i64;
+        if room > 0 {
+            for (peer, content) in self.entity_candidates(id, opts.per_memory.min(room)).await {
+                if judged.insert(peer.clone()) {
+                    candidates.push((peer, content));
+                }
+            }
+        }
+        if candidates.len() > MAX_REBUILD_CANDIDATES {
+            candidates.truncate(MAX_REBUILD_CANDIDATES);
+            judged = candidates.iter().map(|(peer, _)| peer.clone()).collect();
+        }
+        if candidates.is_empty() {
+            return;
+        }
+
+        let inferred = match self
+            .reasoning_engine
+            .infer_relations(id, &memory.content, &candidates)
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                warn!("rebuild: infer_relations failed for {id}: {e}");
+                stats.failures += 1;
+                return;
+            }
+        };
+        let keys: Vec<EdgeKey> = inferred
+            .iter()
+            .map(|r| {
+                (
+                    EdgeFamily::of(r.relation_type),
+                    r.from_memory_id.clone(),
+                    r.to_memory_id.clone(),
+                )
+            })
+            .collect();
+        let (add, stale) = diff_edges(id, &existing, &keys, &judged);
+        stats.relations_unchanged += keys
+            .iter()
+            .filter(|k| existing.contains(*k))
+            .collect::<HashSet<_>>()
+            .len();
+        stats.relations_stale += stale.len();
+
+        for key in &add {
+            if opts.dry_run {
+                stats.relations_added += 1;
+                continue;
+            }
+            let Some(rel) = inferred.iter().find(|r| {
+                EdgeFamily::of(r.relation_type) == key.0
+                    && r.from_memory_id == key.1
+                    && r.to_memory_id == key.2
+            }) else {
+                continue;
+            };
+            match self
+                .reasoning_engine
+                .add_relation(
+                    &rel.from_memory_id,
+                    &rel.to_memory_id,
+                    rel.relation_type,
+                    rel.strength,
+                    rel.reasoning_id.as_deref(),
+                )
+                .await
+            {
+                Ok(_) => stats.relations_added += 1,
+                Err(e) => {
+                    warn!("rebuild: add_relation failed for {id}: {e}");
+                    stats.failures += 1;
+                }
+            }
+        }
+
+        if !opts.prune {
+            return;
+        }
+        for (family, from, to) in &stale {
+            let Some(query) = family.drop_query() else {
+                continue;
+            };
+            if opts.dry_run {
+                stats.relations_removed += 1;
+                continue;
+            }
+            match self
+                .db
+                .execute_query::<serde_json::Value, _>(
+                    query,
+                    &serde_json::json!({ "from_id": from, "to_id": to }),
+                )
+                .await
+            {
+                Ok(_) => stats.relations_removed += 1,
+                Err(e) => {
+                    warn!("rebuild: {query} {from} -> {to} failed: {e}");
+                    stats.failures += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(family: EdgeFamily, from: &str, to: &str) -> EdgeKey {
+        (family, from.to_string(), to.to_string())
+    }
+
+    #[test]
+    fn cursor_round_trips_and_rejects_garbage() {
+        let c = UserCursor {
+            user_id: "team:alpha".to_string(),
+            offset: 150,
+        };
+        assert_eq!(c.render(), "150:team:alpha");
+        assert_eq!(UserCursor::parse(&c.render()).unwrap(), c);
+        for raw in ["", "abc", "12:", "x:user"] {
+            assert!(UserCursor::parse(raw).is_err(), "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn diff_adds_missing_edges_and_flags_only_judged_outgoing_ones_stale() {
+        let existing: HashSet<EdgeKey> = [
+            key(EdgeFamily::Implies, "m", "a"),
+            key(EdgeFamily::Because, "m", "b"),
+            key(EdgeFamily::Contradicts, "m", "c"),
+            key(EdgeFamily::Relation, "d", "m"),
+            key(EdgeFamily::Relation, "m", "unjudged"),
+        ]
+        .into_iter()
+        .collect();
+        let judged: HashSet<String> = ["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let inferred = vec![
+            key(EdgeFamily::Implies, "m", "a"),
+            key(EdgeFamily::Relation, "m", "e"),
+            key(EdgeFamily::Relation, "m", "e"),
+            key(EdgeFamily::Implies, "m", "m"),
+        ];
+
+        let (add, stale) = diff_edges("m", &existing, &inferred, &judged);
+        assert_eq!(add, vec![key(EdgeFamily::Relation, "m", "e")]);
+        // CONTRADICTS, incoming and un-judged edges are left alone.
+        assert_eq!(stale, vec![key(EdgeFamily::Because, "m", "b")]);
+    }
+}
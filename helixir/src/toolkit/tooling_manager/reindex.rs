@@ -0,0 +1,254 @@
+//! Embedding backfill: give a vector to memories stored without one.
+//!
+//! Under `write.on_embed_fail = defer` an `add` whose embedding call fails
+//! still stores its facts, with `needs_embedding: true` in the node's
+//! metadata and no `HAS_EMBEDDING` edge. Vector search cannot see them
+//! until [`ToolingManager::reindex_embeddings`] embeds them and clears the
+//! flag. A memory whose embedding fails again keeps its flag, so the next
+//! pass retries it.
+//!
+//! Like the relation rebuild, the pass walks users (sorted by id) and their
+//! memories in `HAS_MEMORY` order; when `limit` flagged memories are found
+//! the returned cursor resumes at the first one left over, so a large store
+//! is scanned once over several calls rather than from the top each time.
+
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use super::ToolingManager;
+use super::rebuild::UserCursor;
+use super::types::ToolingError;
+use crate::utils::nullable_string;
+
+/// Metadata key marking a memory stored without an embedding.
+pub const NEEDS_EMBEDDING_KEY: &str = "needs_embedding";
+
+/// Memories read per `getUserMemoriesPage` call while scanning.
+const SCAN_PAGE: usize = 200;
+
+#[derive(Debug, Default)]
+pub struct ReindexStats {
+    pub memories_scanned: usize,
+    /// Memories that got their embedding in this call.
+    pub reindexed: Vec<String>,
+    /// Flagged memories whose embedding or write failed; still flagged.
+    pub failures: usize,
+    /// Where the next call resumes; `None` once the scan is through.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct MemoriesResp {
+    #[serde(default)]
+    memories: Vec<MemRow>,
+}
+
+#[derive(Deserialize)]
+struct MemRow {
+    /// Internal node id — what `addMemoryEmbedding` links from.
+    #[serde(default, deserialize_with = "nullable_string")]
+    id: String,
+    #[serde(default, deserialize_with = "nullable_string")]
+    memory_id: String,
+    #[serde(default, deserialize_with = "nullable_string")]
+    content: String,
+    #[serde(default, deserialize_with = "nullable_string")]
+    metadata: String,
+}
+
+/// Whether a node's metadata JSON carries `needs_embedding: true`.
+fn needs_embedding(metadata: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(metadata)
+        .ok()
+        .and_then(|m| m.get(NEEDS_EMBEDDING_KEY)?.as_bool())
+        .unwrap_or(false)
+}
+
+/// `metadata` with the flag removed; other keys are kept.
+fn without_flag(metadata: &str) -> String {
+    let mut value = serde_json::from_str::<serde_json::Value>(metadata)
+        .unwrap_or_else(|_| serde_json::json!({}));
+    if let Some(map) = value.as_object_mut() {
+        map.remove(NEEDS_EMBEDDING_KEY);
+    }
+    value.to_string()
+}
+
+impl ToolingManager {
+    /// Embed up to `limit` memories flagged `needs_embedding` and clear
+    /// their flag, resuming at `cursor` (a previous
+    /// [`ReindexStats::next_cursor`]). `user_id: None` walks every user.
+    pub async fn reindex_embeddings(
+        &self,
+        user_id: Option<&str>,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<ReindexStats, ToolingError> {
+        let mut cursor = cursor.map(UserCursor::parse).transpose()?;
+        let users: Vec<String> = match user_id {
+            Some(u) => {
+                if cursor.as_ref().is_some_and(|c| c.user_id != u) {
+                    return Err(ToolingError::Memory(format!(
+                        "reindex cursor belongs to another user than '{u}'"
+                    )));
+                }
+                vec![u.to_string()]
+            }
+            None => self.rebuild_user_ids().await?,
+        };
+        let limit = limit.max(1);
+        let mut stats = ReindexStats::default();
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut pending: Vec<MemRow> = Vec::new();
+
+        'users: for user in users {
+            let mut offset = match &cursor {
+                Some(c) if c.user_id > user => continue,
+                Some(c) if c.user_id == user => c.offset,
+                _ => 0,
+            };
+            cursor = None;
+            loop {
+                let page = self.memories_page(&user, offset).await?;
+                let fetched = page.len();
+                for m in page {
+                    if m.memory_id.is_empty()
+                        || !needs_embedding(&m.metadata)
+                        || !seen.insert(m.memory_id.clone())
+                    {
+                        offset += 1;
+                        stats.memories_scanned += 1;
+                        continue;
+                    }
+                    if pending.len() == limit {
+                        stats.next_cursor = Some(
+                            UserCursor {
+                                user_id: user,
+                                offset,
+                            }
+                            .render(),
+                        );
+                        break 'users;
+                    }
+                    pending.push(m);
+                    offset += 1;
+                    stats.memories_scanned += 1;
+                }
+                if fetched < SCAN_PAGE {
+                    break;
+                }
+            }
+        }
+
+        if !pending.is_empty() {
+            let texts: Vec<&str> = pending.iter().map(|m| m.content.as_str()).collect();
+            match self.embedder.generate_batch(&texts, true).await {
+                Ok(vectors) => {
+                    for (m, vector) in pending.iter().zip(vectors) {
+                        match self.attach_deferred_embedding(m, &vector).await {
+                            Ok(()) => stats.reindexed.push(m.memory_id.clone()),
+                            Err(e) => {
+                                warn!("reindex: {} failed: {}", m.memory_id, e);
+                                stats.failures += 1;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        "reindex: embedding {} memories failed: {}",
+                        pending.len(),
+                        e
+                    );
+                    stats.failures = pending.len();
+                }
+            }
+        }
+
+        info!(
+            "reindex_embeddings: scanned={}, reindexed={}, failures={}, done={}",
+            stats.memories_scanned,
+            stats.reindexed.len(),
+            stats.failures,
+            stats.next_cursor.is_none()
+        );
+        Ok(stats)
+    }
+
+    /// `SCAN_PAGE` of `user_id`'s memories from `offset`, in `HAS_MEMORY`
+    /// order. A user with no memories yet reads as an empty page.
+    async fn memories_page(
+        &self,
+        user_id: &str,
+        offset: usize,
+    ) -> Result<Vec<MemRow>, ToolingError> {
+        match self
+            .db
+            .execute_query::<MemoriesResp, _>(
+                "getUserMemoriesPage",
+                &serde_json::json!({
+                    "user_id": user_id,
+                    "start": offset as i64,
+                    "end": (offset + SCAN_PAGE) as i64,
+                }),
+            )
+            .await
+        {
+            Ok(page) => Ok(page.memories),
+            // #19: no HAS_MEMORY edges yet reads back as `No value found`.
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => Ok(Vec::new()),
+            Err(e) => Err(ToolingError::Database(e.to_string())),
+        }
+    }
+
+    /// Link `vector` to the memory, then drop its flag. The flag stays when
+    /// the embedding write fails, so the memory is retried.
+    async fn attach_deferred_embedding(
+        &self,
+        memory: &MemRow,
+        vector: &[f32],
+    ) -> Result<(), ToolingError> {
+        self.db
+            .execute_query::<serde_json::Value, _>(
+                "addMemoryEmbedding",
+                &serde_json::json!({
+                    "memory_id": memory.id,
+                    "vector_data": vector.iter().map(|&x| x as f64).collect::<Vec<f64>>(),
+                    "embedding_model": self.embedder.model(),
+                    "created_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            )
+            .await
+            .map_err(|e| ToolingError::Database(e.to_string()))?;
+        self.db
+            .execute_query::<serde_json::Value, _>(
+                "setMemoryMetadata",
+                &serde_json::json!({
+                    "memory_id": memory.memory_id,
+                    "metadata": without_flag(&memory.metadata),
+                }),
+            )
+            .await
+            .map_err(|e| ToolingError::Database(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_flag_is_read_and_cleared_without_touching_other_keys() {
+        assert!(needs_embedding(r#"{"needs_embedding":true}"#));
+        assert!(!needs_embedding(r#"{"needs_embedding":false}"#));
+        assert!(!needs_embedding("{}"));
+        assert!(!needs_embedding(""));
+        assert_eq!(
+            without_flag(r#"{"needs_embedding":true,"references":[]}"#),
+            r#"{"references":[]}"#
+        );
+    }
+}
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
+use futures::StreamExt;
 use tracing::{debug, info};
 
 use super::ToolingManager;
 use super::add_pipeline::store::content_key;
-use super::types::{SearchMemoryResult, ToolingError};
+use super::types::{SearchMemoryResult, ToolingError, WindowCounts};
+use crate::core::TimeWindow;
 use crate::safe_truncate;
 use crate::utils::nullable_string;
 
@@ -51,6 +53,86 @@ fn collapse_collective_duplicates(results: Vec<SearchMemoryResult>) -> Vec<Searc
     out
 }
 
+/// Upper bound on rows [`ToolingManager::find_stale`] reviews.
+pub(super) const BROWSE_SCAN_CAP: i64 = 1000;
+
+/// Rows read per round trip by [`ToolingManager::window_counts`].
+const WINDOW_COUNT_CHUNK: usize = 500;
+
+/// Most ids [`ToolingManager::search_within`] ranks in one call.
+pub const MAX_WITHIN_IDS: usize = 200;
+
+/// `rows` (each with its content embedding) ranked by cosine score against
+/// `query`, best first, cut to `limit`.
+fn rank_within(
+    query: &[f32],
+    rows: Vec<(SearchMemoryResult, Vec<f32>)>,
+    limit: usize,
+) -> Vec<SearchMemoryResult> {
+    let mut ranked: Vec<SearchMemoryResult> = rows
+        .into_iter()
+        .map(|(mut row, vector)| {
+            row.score =
+                crate::toolkit::mind_toolbox::search::smart_traversal::scoring::cosine_score(
+                    query, &vector,
+                );
+            row
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked.truncate(limit);
+    ranked
+}
+
+/// Memory nodes → browse rows, newest `created_at` first (RFC3339 strings in
+/// one offset sort chronologically). Pure so it is unit-tested directly.
+fn newest_first(memories: Vec<serde_json::Value>, limit: usize) -> Vec<SearchMemoryResult> {
+    let mut rows: Vec<SearchMemoryResult> = memories
+        .into_iter()
+        .filter_map(|m| {
+            let memory_id = m["memory_id"].as_str()?.to_string();
+            let mut metadata = HashMap::new();
+            metadata.insert("browse".to_string(), serde_json::json!("recent"));
+            for key in ["memory_type", "certainty", "importance"] {
+                if !m[key].is_null() {
+                    metadata.insert(key.to_string(), m[key].clone());
+                }
+            }
+            Some(SearchMemoryResult {
+                memory_id,
+                content: m["content"].as_str().unwrap_or_default().to_string(),
+                score: 0.0,
+                method: "recent".to_string(),
+                metadata,
+                created_at: m["created_at"].as_str().unwrap_or_default().to_string(),
+                hops: 0,
+            })
+        })
+        .collect();
+    rows.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    rows.truncate(limit);
+    rows
+}
+
+/// Memories whose EVENT time (`valid_from`, else `created_at`) falls in
+/// `window` — the same test the search seed filter applies.
+fn count_in_window(memories: &[serde_json::Value], window: &TimeWindow) -> usize {
+    if !window.is_active() {
+        return memories.len();
+    }
+    memories
+        .iter()
+        .filter(|m| {
+            window.contains_rfc3339(
+                crate::toolkit::mind_toolbox::search::smart_traversal::scoring::event_time(
+                    m["valid_from"].as_str().unwrap_or_default(),
+                    m["created_at"].as_str().unwrap_or_default(),
+                ),
+            )
+        })
+        .count()
+}
+
 /// Tooling-level search request (#9): `mode` and `scope` arrive resolved by
 /// the caller (the client layer); `limit` stays optional so the configured
 /// default applies. #87: an active `window` hard-filters seeds by EVENT time;
@@ -64,6 +146,10 @@ pub struct MemorySearchOptions {
     pub graph_depth: Option<usize>,
     pub scope: String,
     pub window: crate::core::TimeWindow,
+    /// BM25 keyword fusion override; `None` follows the retrieval profile.
+    pub hybrid: Option<bool>,
+    /// Per-user mode windows; `None` follows the config.
+    pub windows: Option<crate::core::config::SearchWindowsConfig>,
 }
 
 impl MemorySearchOptions {
@@ -77,6 +163,8 @@ impl MemorySearchOptions {
             graph_depth: None,
             scope: "personal".to_string(),
             window: crate::core::TimeWindow::default(),
+            hybrid: None,
+            windows: None,
         }
     }
 }
@@ -95,6 +183,8 @@ impl ToolingManager {
             graph_depth,
             scope,
             window,
+            hybrid,
+            windows,
         } = opts;
         let (mode, scope) = (mode.as_str(), scope.as_str());
         info!(
@@ -115,7 +205,12 @@ impl ToolingManager {
             .map_err(|e| ToolingError::Embedding(e.to_string()))?;
 
         let graph_depth = graph_depth.map(|d| d as u32);
-        let effective_limit = limit.unwrap_or(self.config.default_search_limit);
+        let effective_limit = limit.unwrap_or_else(|| {
+            self.config
+                .retrieval
+                .search_modes
+                .limit_for(crate::core::search_modes::SearchMode::parse_mode(mode))
+        });
 
         let effective_scope = match scope {
             "collective" | "all" => scope,
@@ -134,6 +229,8 @@ impl ToolingManager {
                     graph_depth,
                     scope: effective_scope.to_string(),
                     window,
+                    hybrid,
+                    windows,
                 },
             )
             .await?;
@@ -144,6 +241,10 @@ impl ToolingManager {
         // (the write path's dedup recall) keep seeing raw candidates.
         let mut results = results;
         self.search_engine.collapse_raw_families(&mut results).await;
+        self.search_engine
+            .attach_source_excerpts(&mut results)
+            .await;
+        self.search_engine.attach_references(&mut results).await;
 
         self.emit_search_executed(user_id, mode, results.len())
             .await;
@@ -165,6 +266,7 @@ impl ToolingManager {
                     method: r.method,
                     metadata: r.metadata,
                     created_at: r.created_at,
+                    hops: r.hops,
                 };
                 if let Some(uc) = r.user_count {
                     result.metadata.insert(
@@ -182,6 +284,11 @@ impl ToolingManager {
             })
             .collect();
 
+        let boost = self.config.summary.recent_boost;
+        if mode == "recent" && boost != 1.0 {
+            self.prefer_summaries(&mut search_results, boost).await;
+        }
+
         if scope == "collective" || scope == "all" {
             // #3a: fold same-fact-across-users into one row BEFORE ranking, so
             // the boost-sort operates on distinct knowledge, not duplicates.
@@ -217,6 +324,25 @@ impl ToolingManager {
         Ok(search_results)
     }
 
+    /// `recent` mode: multiply the score of stored summaries (see
+    /// `summarize_recent`) by `boost`, mark them `summary: true` and
+    /// re-sort, so one summary outranks the granular rows it covers.
+    async fn prefer_summaries(&self, results: &mut [SearchMemoryResult], boost: f64) {
+        let ids: Vec<&str> = results.iter().map(|r| r.memory_id.as_str()).collect();
+        let summaries = self.summary_ids(&ids).await;
+        if summaries.is_empty() {
+            return;
+        }
+        for row in results.iter_mut() {
+            if summaries.contains(&row.memory_id) {
+                row.score *= boost;
+                row.metadata
+                    .insert("summary".to_string(), serde_json::Value::Bool(true));
+            }
+        }
+        results.sort_by(|a, b| crate::toolkit::mind_toolbox::ranking::desc(&a.score, &b.score));
+    }
+
     pub async fn search_by_tag(
         &self,
         tag: &str,
@@ -270,10 +396,242 @@ impl ToolingManager {
                 method: "tag_search".to_string(),
                 metadata: HashMap::new(),
                 created_at: m.created_at,
+                hops: 0,
             })
             .collect())
     }
 
+    /// Re-rank exactly `memory_ids` against `query` (`within_ids`): no
+    /// vector index, time window or graph expansion — each memory's content
+    /// is embedded (cache first, so a set from an earlier search is cheap)
+    /// and scored by cosine. Ids that do not exist or that `user_id` does
+    /// not hold are dropped; duplicates count once. Callers keep the set to
+    /// [`MAX_WITHIN_IDS`]: every id costs a record read. The reads run at
+    /// most half the DB pool at a time, so a concurrent request still gets
+    /// connections, and one that fails drops only its id.
+    pub async fn search_within(
+        &self,
+        query: &str,
+        user_id: &str,
+        memory_ids: &[String],
+        limit: usize,
+    ) -> Result<Vec<SearchMemoryResult>, ToolingError> {
+        let mut seen = std::collections::HashSet::new();
+        let unique: Vec<&String> = memory_ids.iter().filter(|id| seen.insert(*id)).collect();
+        let concurrency = (self.config.db_pool.size / 2).max(1);
+        let reads: Vec<_> = unique
+            .iter()
+            .map(|id| async move { self.get_memory_record(id).await })
+            .collect();
+        let records: Vec<_> = futures::stream::iter(reads)
+            .buffered(concurrency)
+            .collect()
+            .await;
+        let mut rows = Vec::new();
+        for (id, record) in unique.into_iter().zip(records) {
+            let record = match record {
+                Ok(Some(record)) => record,
+                Ok(None) => continue,
+                Err(e) => {
+                    debug!("within_ids: reading {id} failed, dropping it: {e}");
+                    continue;
+                }
+            };
+            if !record.users.iter().any(|u| u == user_id) {
+                continue;
+            }
+            let mut metadata = HashMap::new();
+            metadata.insert("origin".to_string(), serde_json::json!("within_ids"));
+            rows.push(SearchMemoryResult {
+                memory_id: id.clone(),
+                content: record.node["content"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                score: 0.0,
+                method: "within_ids".to_string(),
+                metadata,
+                created_at: record.node["created_at"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                hops: 0,
+            });
+        }
+        debug!(
+            "within_ids: {} of {} ids held by {}",
+            rows.len(),
+            memory_ids.len(),
+            user_id
+        );
+        if rows.is_empty() {
+            return Ok(rows);
+        }
+
+        let query_embedding = self
+            .embedder
+            .generate(query, true)
+            .await
+            .map_err(|e| ToolingError::Embedding(e.to_string()))?;
+        let texts: Vec<&str> = rows.iter().map(|r| r.content.as_str()).collect();
+        let vectors = self
+            .embedder
+            .generate_batch(&texts, true)
+            .await
+            .map_err(|e| ToolingError::Embedding(e.to_string()))?;
+        Ok(rank_within(
+            &query_embedding,
+            rows.into_iter().zip(vectors).collect(),
+            limit,
+        ))
+    }
+
+    /// "Browse recent" — the user's newest memories, unranked (no embedding,
+    /// no query). Backs a blank query when `empty_query_browse` is on. The
+    /// query orders by `created_at`, so only the `limit` rows returned are
+    /// read however many the user holds.
+    pub async fn recent_memories(
+        &self,
+        user_id: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchMemoryResult>, ToolingError> {
+        let memories = self.user_memories_newest(user_id, 0, limit).await?;
+        Ok(newest_first(memories, limit))
+    }
+
+    /// How many of the user's memories fall in `window` by event time, and
+    /// how many there are at all — "nothing in the window" vs "nothing
+    /// stored". The total is one `countUserMemories` aggregate; an active
+    /// window also reads every row (event time is `valid_from` or
+    /// `created_at`, which no single index orders), so callers ask for this
+    /// only when they need it.
+    pub async fn window_counts(
+        &self,
+        user_id: &str,
+        window: &TimeWindow,
+    ) -> Result<WindowCounts, ToolingError> {
+        let total_overall = self.count_user_memories(user_id).await?;
+        if !window.is_active() {
+            return Ok(WindowCounts {
+                total_overall,
+                total_in_window: total_overall,
+            });
+        }
+        let mut total_in_window = 0;
+        let mut start = 0;
+        while start < total_overall {
+            let chunk = self
+                .user_memories_newest(user_id, start, start + WINDOW_COUNT_CHUNK)
+                .await?;
+            if chunk.is_empty() {
+                break;
+            }
+            start += chunk.len();
+            total_in_window += count_in_window(&chunk, window);
+        }
+        Ok(WindowCounts {
+            total_overall,
+            total_in_window,
+        })
+    }
+
+    /// Up to [`BROWSE_SCAN_CAP`] of the user's memory nodes, unordered.
+    pub(super) async fn scan_user_memories(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<serde_json::Value>, ToolingError> {
+        #[derive(serde::Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            memories: Vec<serde_json::Value>,
+        }
+        match self
+            .db
+            .execute_query::<Resp, _>(
+                "getUserMemories",
+                &serde_json::json!({ "user_id": user_id, "limit": BROWSE_SCAN_CAP }),
+            )
+            .await
+        {
+            Ok(r) => Ok(r.memories),
+            // #19: no HAS_MEMORY edges yet reads back as `No value found`.
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => Ok(Vec::new()),
+            Err(e) => Err(ToolingError::Database(e.to_string())),
+        }
+    }
+
+    /// Rows `start..end` of the user's memory nodes, newest first by
+    /// `created_at`.
+    pub(super) async fn user_memories_newest(
+        &self,
+        user_id: &str,
+        start: usize,
+        end: usize,
+    ) -> Result<Vec<serde_json::Value>, ToolingError> {
+        #[derive(serde::Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            memories: Vec<serde_json::Value>,
+        }
+        match self
+            .db
+            .execute_query::<Resp, _>(
+                "getUserMemoriesNewest",
+                &serde_json::json!({ "user_id": user_id, "start": start, "end": end }),
+            )
+            .await
+        {
+            Ok(r) => Ok(r.memories),
+            // #19: no HAS_MEMORY edges yet reads back as `No value found`.
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => Ok(Vec::new()),
+            Err(e) => Err(ToolingError::Database(e.to_string())),
+        }
+    }
+
+    /// The stored nodes of `memory_ids` in one query, in no particular
+    /// order; unknown ids are simply absent.
+    pub(super) async fn memories_by_ids(
+        &self,
+        memory_ids: &[&str],
+    ) -> Result<Vec<serde_json::Value>, ToolingError> {
+        #[derive(serde::Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            memories: Vec<serde_json::Value>,
+        }
+        if memory_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        match self
+            .db
+            .execute_query::<Resp, _>(
+                "getMemoriesBatch",
+                &serde_json::json!({ "memory_ids": memory_ids }),
+            )
+            .await
+        {
+            Ok(r) => Ok(r.memories),
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => Ok(Vec::new()),
+            Err(e) => Err(ToolingError::Database(e.to_string())),
+        }
+    }
+
+    /// How many memories the user holds (their HAS_MEMORY edges).
+    pub(super) async fn count_user_memories(&self, user_id: &str) -> Result<usize, ToolingError> {
+        match self
+            .db
+            .execute_query::<serde_json::Value, _>(
+                "countUserMemories",
+                &serde_json::json!({ "user_id": user_id }),
+            )
+            .await
+        {
+            Ok(r) => Ok(r["count"].as_u64().unwrap_or(0) as usize),
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => Ok(0),
+            Err(e) => Err(ToolingError::Database(e.to_string())),
+        }
+    }
+
     pub async fn search_by_concept(
         &self,
         query: &str,
@@ -392,6 +750,7 @@ impl ToolingManager {
                             method: format!("concept_search_{}", mode),
                             metadata: candidate.metadata.clone(),
                             created_at: candidate.created_at.clone(),
+                            hops: candidate.hops,
                         });
 
                         if results.len() >= limit {
@@ -471,6 +830,7 @@ impl ToolingManager {
                                 method: "concept_search_db_fallback".to_string(),
                                 metadata: HashMap::new(),
                                 created_at: mem.created_at,
+                                hops: 0,
                             });
 
                             if results.len() >= limit {
@@ -547,10 +907,55 @@ fn concept_fallback_score(
 
 #[cfg(test)]
 mod tests {
-    use super::SearchMemoryResult;
-    use super::{collapse_collective_duplicates, concept_fallback_score};
+    use super::{SearchMemoryResult, TimeWindow};
+    use super::{
+        collapse_collective_duplicates, concept_fallback_score, count_in_window, newest_first,
+    };
     use std::collections::HashMap;
 
+    #[test]
+    fn newest_first_orders_by_created_at_and_truncates() {
+        let node = |id: &str, at: &str| {
+            serde_json::json!({
+                "memory_id": id,
+                "content": id,
+                "memory_type": "fact",
+                "created_at": at,
+            })
+        };
+        let rows = newest_first(
+            vec![
+                node("mem_old", "2026-01-01T00:00:00+00:00"),
+                node("mem_new", "2026-03-01T00:00:00+00:00"),
+                node("mem_mid", "2026-02-01T00:00:00+00:00"),
+                serde_json::json!({ "content": "no id" }),
+            ],
+            2,
+        );
+        let ids: Vec<_> = rows.iter().map(|r| r.memory_id.as_str()).collect();
+        assert_eq!(ids, ["mem_new", "mem_mid"]);
+        assert_eq!(rows[0].method, "recent");
+        assert_eq!(rows[0].metadata["browse"], "recent");
+    }
+
+    #[test]
+    fn window_counts_go_by_event_time() {
+        let memories = vec![
+            serde_json::json!({ "created_at": "2026-07-04T10:00:00+00:00" }),
+            serde_json::json!({
+                "valid_from": "2025-01-01T00:00:00+00:00",
+                "created_at": "2026-07-04T11:00:00+00:00",
+            }),
+            serde_json::json!({ "created_at": "2026-05-01T00:00:00+00:00" }),
+        ];
+        let since_july = TimeWindow {
+            from: Some("2026-07-01T00:00:00Z".parse().unwrap()),
+            to: None,
+        };
+        assert_eq!(count_in_window(&memories, &since_july), 1);
+        assert_eq!(count_in_window(&memories, &TimeWindow::default()), 3);
+    }
+
     fn res(memory_id: &str, content: &str, mtype: &str, score: f64) -> SearchMemoryResult {
         let mut metadata = HashMap::new();
         if !mtype.is_empty() {
@@ -563,6 +968,7 @@ mod tests {
             method: "test".to_string(),
             metadata,
             created_at: String::new(),
+            hops: 0,
         }
     }
 
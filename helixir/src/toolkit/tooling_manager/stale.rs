@@ -0,0 +1,337 @@
+//! `find_stale`: memories that were true when written but probably are not
+//! any more — "currently on v1.2" three months on — so the agent can offer
+//! an update before relying on them. Two signals, each reported as a
+//! reason:
+//!
+//! - wording: present-tense or time-relative phrases ("currently", "right
+//!   now", "this quarter") or a version number, in a memory older than
+//!   `stale.min_age_days` (age counts from its last update);
+//! - contradiction: an open CONTRADICTS edge to a NEWER memory, whatever
+//!   the age — the add pipeline's contradiction check already judged them
+//!   incompatible, and the newer one most likely wins.
+//!
+//! Superseded memories already have their successor and are skipped, so
+//! are summaries and deleted rows. Nothing is changed: the review is a
+//! worklist for `update_memory`.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use tracing::debug;
+
+use super::ToolingManager;
+use super::summarize::SUMMARY_TAG;
+use super::types::{StaleCandidate, ToolingError};
+
+/// Phrases that only hold at the time they were said, matched on word
+/// boundaries in the lowercased content.
+const TEMPORAL_PHRASES: &[&str] = &[
+    "currently",
+    "right now",
+    "at the moment",
+    "at present",
+    "for now",
+    "these days",
+    "nowadays",
+    "today",
+    "tomorrow",
+    "this week",
+    "this month",
+    "this quarter",
+    "this year",
+    "next week",
+    "next month",
+    "latest",
+    "recently",
+    "upcoming",
+    "still",
+    "is using",
+    "am using",
+    "are using",
+    "is working on",
+    "am working on",
+];
+
+lazy_static! {
+    static ref TEMPORAL_REGEX: Regex = Regex::new(&format!(
+        r"\b(?:{})\b",
+        TEMPORAL_PHRASES.join("|").replace(' ', r"\s+")
+    ))
+    .unwrap();
+    /// `v1.2`, `1.2.3`, `version 14`.
+    static ref VERSION_REGEX: Regex =
+        Regex::new(r"\b(?:v\d+(?:\.\d+)*|\d+\.\d+(?:\.\d+)+|version\s+\d+(?:\.\d+)*)\b").unwrap();
+}
+
+/// The time-bound phrases and version numbers in `content`, deduplicated,
+/// in order of appearance.
+fn temporal_markers(content: &str) -> Vec<String> {
+    let lower = content.to_lowercase();
+    let mut seen = HashSet::new();
+    TEMPORAL_REGEX
+        .find_iter(&lower)
+        .chain(VERSION_REGEX.find_iter(&lower))
+        .map(|m| m.as_str().split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|m| seen.insert(m.clone()))
+        .collect()
+}
+
+/// A scanned memory and when it was last written.
+struct Row<'a> {
+    memory_id: &'a str,
+    content: &'a str,
+    created_at: &'a str,
+    written_at: DateTime<Utc>,
+}
+
+fn parse_time(value: &serde_json::Value) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.as_str()?)
+        .ok()
+        .map(|t| t.to_utc())
+}
+
+/// Live, non-summary memories with a parseable `created_at`; `written_at`
+/// is the later of `created_at` and `updated_at`.
+fn live_rows<'a>(memories: &'a [serde_json::Value], skip: &HashSet<String>) -> Vec<Row<'a>> {
+    memories
+        .iter()
+        .filter(|m| m["is_deleted"].as_i64().unwrap_or(0) == 0)
+        .filter(|m| m["context_tags"].as_str() != Some(SUMMARY_TAG))
+        .filter_map(|m| {
+            let memory_id = m["memory_id"].as_str()?;
+            let created = parse_time(&m["created_at"])?;
+            let written_at = parse_time(&m["updated_at"]).map_or(created, |u| u.max(created));
+            (!skip.contains(memory_id)).then(|| Row {
+                memory_id,
+                content: m["content"].as_str().unwrap_or_default(),
+                created_at: m["created_at"].as_str().unwrap_or_default(),
+                written_at,
+            })
+        })
+        .collect()
+}
+
+/// Flag `rows` by wording (older than `min_age_days`) and by open
+/// contradictions (`pairs` of memory ids, either direction) with a newer
+/// row. Most reasons first, then oldest first.
+fn assess(
+    rows: &[Row<'_>],
+    pairs: &[(String, String)],
+    min_age_days: u64,
+    now: DateTime<Utc>,
+) -> Vec<StaleCandidate> {
+    let by_id: HashMap<&str, &Row<'_>> = rows.iter().map(|r| (r.memory_id, r)).collect();
+    let mut newer: HashMap<&str, Vec<String>> = HashMap::new();
+    for (a, b) in pairs {
+        let (Some(ra), Some(rb)) = (by_id.get(a.as_str()), by_id.get(b.as_str())) else {
+            continue;
+        };
+        let (old, new) = if ra.written_at < rb.written_at {
+            (ra, rb)
+        } else if rb.written_at < ra.written_at {
+            (rb, ra)
+        } else {
+            continue;
+        };
+        let list = newer.entry(old.memory_id).or_default();
+        if !list.iter().any(|id| id == new.memory_id) {
+            list.push(new.memory_id.to_string());
+        }
+    }
+
+    let mut out: Vec<StaleCandidate> = rows
+        .iter()
+        .filter_map(|r| {
+            let age_days = (now - r.written_at).num_days().max(0) as u64;
+            let mut reasons = Vec::new();
+            let markers = if age_days >= min_age_days {
+                temporal_markers(r.content)
+            } else {
+                Vec::new()
+            };
+            if !markers.is_empty() {
+                reasons.push(format!(
+                    "time-bound wording ({}) written {age_days} days ago",
+                    markers.join(", ")
+                ));
+            }
+            let contradicted_by = newer.remove(r.memory_id).unwrap_or_default();
+            if !contradicted_by.is_empty() {
+                reasons.push(format!(
+                    "contradicted by newer {}",
+                    contradicted_by.join(", ")
+                ));
+            }
+            (!reasons.is_empty()).then(|| StaleCandidate {
+                memory_id: r.memory_id.to_string(),
+                content: r.content.to_string(),
+                created_at: r.created_at.to_string(),
+                age_days,
+                reasons,
+                markers,
+                contradicted_by,
+            })
+        })
+        .collect();
+    out.sort_by(|a, b| {
+        b.reasons
+            .len()
+            .cmp(&a.reasons.len())
+            .then(b.age_days.cmp(&a.age_days))
+    });
+    out
+}
+
+/// Open contradictions out of a `getMemoryContradictionsBatch` reply:
+/// unresolved CONTRADICTS edges (internal `from_node` → `to_node`) mapped
+/// back to `memory_id`s through the memory and target nodes.
+fn open_contradiction_pairs(batch: &serde_json::Value) -> Vec<(String, String)> {
+    let nodes = |key: &str| batch[key].as_array().cloned().unwrap_or_default();
+    let memory_ids: HashMap<String, String> = nodes("memories")
+        .into_iter()
+        .chain(nodes("targets"))
+        .filter_map(|n| {
+            Some((
+                n["id"].as_str()?.to_string(),
+                n["memory_id"].as_str()?.to_string(),
+            ))
+        })
+        .collect();
+    nodes("contradiction_edges")
+        .iter()
+        .filter(|e| e["resolved"].as_i64().unwrap_or(0) == 0)
+        .filter_map(|e| {
+            Some((
+                memory_ids.get(e["from_node"].as_str()?)?.clone(),
+                memory_ids.get(e["to_node"].as_str()?)?.clone(),
+            ))
+        })
+        .collect()
+}
+
+impl ToolingManager {
+    /// Review `user_id`'s memories (up to the browse scan cap) for ones that
+    /// likely need updating. `min_age_days` defaults to
+    /// `stale.min_age_days`, `limit` to `stale.max_results`.
+    pub async fn find_stale(
+        &self,
+        user_id: &str,
+        min_age_days: Option<u64>,
+        limit: Option<usize>,
+    ) -> Result<Vec<StaleCandidate>, ToolingError> {
+        let cfg = &self.config.stale;
+        let memories = self.scan_user_memories(user_id).await?;
+        let ids: Vec<&str> = memories
+            .iter()
+            .filter_map(|m| m["memory_id"].as_str())
+            .collect();
+        let superseded = self.superseded_among(ids).await;
+        let rows = live_rows(&memories, &superseded);
+
+        let pairs = self
+            .open_contradictions(rows.iter().map(|r| r.memory_id).collect())
+            .await;
+
+        let mut out = assess(
+            &rows,
+            &pairs,
+            min_age_days.unwrap_or(cfg.min_age_days),
+            Utc::now(),
+        );
+        out.truncate(limit.unwrap_or(cfg.max_results).max(1));
+        Ok(out)
+    }
+
+    /// `(from, to)` memory ids of the unresolved CONTRADICTS edges leaving
+    /// any of `memory_ids`, in one `getMemoryContradictionsBatch` query.
+    /// Best-effort: a DB error reads as none.
+    async fn open_contradictions(&self, memory_ids: Vec<&str>) -> Vec<(String, String)> {
+        if memory_ids.is_empty() {
+            return Vec::new();
+        }
+        match self
+            .db
+            .execute_query::<serde_json::Value, _>(
+                "getMemoryContradictionsBatch",
+                &serde_json::json!({ "memory_ids": memory_ids }),
+            )
+            .await
+        {
+            Ok(batch) => open_contradiction_pairs(&batch),
+            Err(e) => {
+                debug!("find_stale: contradiction check skipped ({e})");
+                Vec::new()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use serde_json::json;
+
+    #[test]
+    fn markers_catch_time_bound_phrases_and_versions() {
+        assert_eq!(
+            temporal_markers("Currently using Postgres v14.2; right  now the API is version 3."),
+            ["currently", "right now", "v14.2", "version 3"]
+        );
+        assert!(temporal_markers("Prefers dark mode in every editor.").is_empty());
+        // Word boundaries: "stillness" is not "still".
+        assert!(temporal_markers("Values stillness at work.").is_empty());
+    }
+
+    #[test]
+    fn old_wording_and_newer_contradictions_are_flagged() {
+        let now = Utc::now();
+        let at = |days: i64| (now - Duration::days(days)).to_rfc3339();
+        let memories = vec![
+            json!({ "memory_id": "m_old", "content": "Currently on v1.2", "created_at": at(90) }),
+            json!({ "memory_id": "m_fresh", "content": "Currently on v2.0", "created_at": at(2) }),
+            json!({ "memory_id": "m_plain", "content": "Billing runs on Postgres", "created_at": at(60) }),
+            json!({ "memory_id": "m_new", "content": "Billing moved to MySQL", "created_at": at(1) }),
+            json!({ "memory_id": "m_touched", "content": "Currently on call", "created_at": at(90), "updated_at": at(3) }),
+            json!({ "memory_id": "m_sum", "content": "Currently busy", "created_at": at(90), "context_tags": SUMMARY_TAG }),
+            json!({ "memory_id": "m_done", "content": "Currently on v0.9", "created_at": at(99) }),
+        ];
+        let skip = HashSet::from(["m_done".to_string()]);
+        let rows = live_rows(&memories, &skip);
+        let pairs = vec![("m_new".to_string(), "m_plain".to_string())];
+
+        let stale = assess(&rows, &pairs, 30, now);
+        let ids: Vec<_> = stale.iter().map(|s| s.memory_id.as_str()).collect();
+        assert_eq!(ids, ["m_old", "m_plain"]);
+        assert_eq!(stale[0].markers, ["currently", "v1.2"]);
+        assert!(stale[0].reasons[0].contains("90 days ago"), "{stale:?}");
+        assert_eq!(stale[1].contradicted_by, ["m_new"]);
+        assert!(stale[1].markers.is_empty());
+
+        // With no age floor the fresh wording counts too.
+        let all = assess(&rows, &pairs, 0, now);
+        assert_eq!(all.len(), 4, "{all:?}");
+    }
+
+    #[test]
+    fn only_unresolved_edges_become_pairs() {
+        let batch = json!({
+            "memories": [
+                { "id": "n1", "memory_id": "m_a" },
+                { "id": "n2", "memory_id": "m_b" },
+            ],
+            "contradiction_edges": [
+                { "from_node": "n1", "to_node": "n3", "resolved": 0 },
+                { "from_node": "n2", "to_node": "n1", "resolved": 1 },
+                { "from_node": "n2", "to_node": "n9", "resolved": 0 },
+            ],
+            "targets": [{ "id": "n3", "memory_id": "m_c" }, { "id": "n1", "memory_id": "m_a" }],
+        });
+        assert_eq!(
+            open_contradiction_pairs(&batch),
+            [("m_a".to_string(), "m_c".to_string())]
+        );
+    }
+}
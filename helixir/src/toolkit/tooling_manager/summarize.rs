@@ -0,0 +1,342 @@
+//! `summarize_recent`: progressive summarization. A very active user's
+//! recent memories are folded into one LLM-written summary, stored tagged
+//! [`SUMMARY_TAG`] with a SUPPORTS edge from every source, so `recent`
+//! searches can surface one row instead of fifty granular ones.
+//!
+//! With `summary.archive_sources` the summary also SUPERSEDES its sources:
+//! they stay reachable (nothing is deleted) but rank below it, and the next
+//! run skips them — each memory is summarized once. A supersession is seen
+//! by every holder of the memory, so a source another user also holds is
+//! only linked, never archived: one user's summary must not demote what
+//! the others still read as current.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use tracing::{debug, info, warn};
+
+use super::ToolingManager;
+use super::types::{SummaryOutcome, ToolingError};
+use crate::llm::extractor::ExtractedMemory;
+use crate::toolkit::mind_toolbox::reasoning::ReasoningType;
+use crate::utils::nullable_string;
+
+/// `context_tags` value of a stored summary.
+pub const SUMMARY_TAG: &str = "summary";
+
+const SUMMARY_SYSTEM_PROMPT: &str = "You consolidate a user's recent memories into one summary. \
+Keep every decision, outcome, preference and open question; merge repetitions; drop chatter. \
+Write plain prose in the language of the memories, at most 200 words. \
+Reply with the summary text only.";
+
+/// DB rows read per round trip while walking back to the window start.
+pub(super) const SCAN_CHUNK: usize = 200;
+
+/// One memory a summary may cover.
+#[derive(Debug, Clone, PartialEq)]
+struct Source {
+    memory_id: String,
+    content: String,
+    created_at: String,
+    /// Other users hold it too (`user_count` above one).
+    shared: bool,
+}
+
+/// Whether `node` is a stored summary (carries [`SUMMARY_TAG`]).
+pub(super) fn is_summary(node: &serde_json::Value) -> bool {
+    node["context_tags"]
+        .as_str()
+        .unwrap_or_default()
+        .split(',')
+        .any(|t| t.trim() == SUMMARY_TAG)
+}
+
+/// Memories created at or after `cutoff` that are not summaries, deleted
+/// or in `superseded`, newest first, at most `max`.
+fn pick_sources(
+    memories: &[serde_json::Value],
+    cutoff: DateTime<Utc>,
+    superseded: &HashSet<String>,
+    max: usize,
+) -> Vec<Source> {
+    let mut sources: Vec<(DateTime<Utc>, Source)> = memories
+        .iter()
+        .filter(|m| !is_summary(m))
+        .filter(|m| m["is_deleted"].as_i64().unwrap_or(0) == 0)
+        .filter_map(|m| {
+            let memory_id = m["memory_id"].as_str()?;
+            let created_at = m["created_at"].as_str()?;
+            let at = DateTime::parse_from_rfc3339(created_at).ok()?.to_utc();
+            let content = m["content"].as_str().unwrap_or_default().trim();
+            (at >= cutoff && !content.is_empty() && !superseded.contains(memory_id)).then(|| {
+                (
+                    at,
+                    Source {
+                        memory_id: memory_id.to_string(),
+                        content: content.to_string(),
+                        created_at: created_at.to_string(),
+                        shared: m["user_count"].as_i64().unwrap_or(1) > 1,
+                    },
+                )
+            })
+        })
+        .collect();
+    sources.sort_by_key(|s| std::cmp::Reverse(s.0));
+    sources.truncate(max);
+    sources.into_iter().map(|(_, s)| s).collect()
+}
+
+/// Sources oldest first, one per line, dated.
+fn summary_prompt(sources: &[Source]) -> String {
+    let mut prompt = String::from("Memories, oldest first:\n");
+    for s in sources.iter().rev() {
+        let day = s.created_at.get(..10).unwrap_or(&s.created_at);
+        prompt.push_str(&format!("- ({day}) {}\n", s.content));
+    }
+    prompt
+}
+
+impl ToolingManager {
+    /// Summarize `user_id`'s memories from the last `window_hours`
+    /// (default `summary.window_hours`), archiving the sources when
+    /// `archive` (default `summary.archive_sources`; sources other users
+    /// also hold are never archived). Fewer eligible
+    /// memories than `summary.min_sources` writes nothing and returns an
+    /// outcome without `summary_id`. Edge and archive failures are logged;
+    /// the summary itself must be stored.
+    pub async fn summarize_recent(
+        &self,
+        user_id: &str,
+        window_hours: Option<u64>,
+        archive: Option<bool>,
+    ) -> Result<SummaryOutcome, ToolingError> {
+        let cfg = &self.config.summary;
+        let window_hours = window_hours.unwrap_or(cfg.window_hours).max(1);
+        let archive = archive.unwrap_or(cfg.archive_sources);
+        let cutoff = Utc::now() - Duration::hours(window_hours as i64);
+
+        let memories = self.memories_since(user_id, cutoff).await?;
+        let candidates = pick_sources(&memories, cutoff, &HashSet::new(), usize::MAX);
+        let superseded = self
+            .superseded_among(candidates.iter().map(|s| s.memory_id.as_str()).collect())
+            .await;
+        let sources = pick_sources(&memories, cutoff, &superseded, cfg.max_sources.max(1));
+
+        let mut outcome = SummaryOutcome {
+            source_ids: sources.iter().map(|s| s.memory_id.clone()).collect(),
+            window_hours,
+            ..SummaryOutcome::default()
+        };
+        if sources.len() < cfg.min_sources.max(1) {
+            info!(
+                "summarize_recent {user_id}: {} memories in the last {window_hours}h, below {}; nothing written",
+                sources.len(),
+                cfg.min_sources
+            );
+            return Ok(outcome);
+        }
+
+        let (text, _) = self
+            .llm_provider
+            .generate(SUMMARY_SYSTEM_PROMPT, &summary_prompt(&sources), None)
+            .await
+            .map_err(|e| ToolingError::Memory(format!("summary generation failed: {e}")))?;
+        let text = text.trim().to_string();
+        if text.is_empty() {
+            return Err(ToolingError::Memory(
+                "summary generation returned no text".to_string(),
+            ));
+        }
+
+        let stored = self
+            .add_prepared_memories(
+                vec![ExtractedMemory {
+                    text: text.clone(),
+                    memory_type: "fact".to_string(),
+                    certainty: 90,
+                    importance: 70,
+                    entities: Vec::new(),
+                    context: None,
+                }],
+                user_id,
+                None,
+                Some(SUMMARY_TAG),
+            )
+            .await?;
+        let summary_id = stored
+            .added
+            .first()
+            .or(stored.deduped.first())
+            .cloned()
+            .ok_or_else(|| ToolingError::Memory("summary was not stored".to_string()))?;
+
+        for source in &sources {
+            let source_id = &source.memory_id;
+            match self
+                .reasoning_engine
+                .add_relation(
+                    source_id,
+                    &summary_id,
+                    ReasoningType::Supports,
+                    cfg.support_strength as i32,
+                    None,
+                )
+                .await
+            {
+                Ok(_) => outcome.sources_linked += 1,
+                Err(e) => {
+                    warn!("summarize_recent: SUPPORTS {source_id} -> {summary_id} failed: {e}")
+                }
+            }
+            if archive && !source.shared {
+                match self
+                    .record_supersession(&summary_id, source_id, "summarized")
+                    .await
+                {
+                    Ok(()) => outcome.archived += 1,
+                    Err(e) => warn!("summarize_recent: archiving {source_id} failed: {e}"),
+                }
+            }
+        }
+
+        info!(
+            "summarize_recent {user_id}: {summary_id} covers {} memories ({} archived)",
+            outcome.source_ids.len(),
+            outcome.archived
+        );
+        outcome.summary_id = Some(summary_id);
+        outcome.content = text;
+        Ok(outcome)
+    }
+
+    /// `user_id`'s memory nodes created at or after `cutoff`, read newest
+    /// first until a chunk reaches past it.
+    async fn memories_since(
+        &self,
+        user_id: &str,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Vec<serde_json::Value>, ToolingError> {
+        let mut memories = Vec::new();
+        loop {
+            let start = memories.len();
+            let chunk = self
+                .user_memories_newest(user_id, start, start + SCAN_CHUNK)
+                .await?;
+            let fetched = chunk.len();
+            let reached_cutoff = chunk.last().map_or(true, |m| {
+                m["created_at"]
+                    .as_str()
+                    .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+                    .map_or(true, |at| at.to_utc() < cutoff)
+            });
+            memories.extend(chunk);
+            if fetched < SCAN_CHUNK || reached_cutoff {
+                return Ok(memories);
+            }
+        }
+    }
+
+    /// Which of `memory_ids` already have a successor. Best-effort: a DB
+    /// error reads as none.
+    pub(super) async fn superseded_among(&self, memory_ids: Vec<&str>) -> HashSet<String> {
+        #[derive(Deserialize)]
+        struct Node {
+            #[serde(default, deserialize_with = "nullable_string")]
+            id: String,
+            #[serde(default, deserialize_with = "nullable_string")]
+            memory_id: String,
+        }
+        #[derive(Deserialize)]
+        struct Edge {
+            #[serde(default, deserialize_with = "nullable_string")]
+            to_node: String,
+        }
+        #[derive(Deserialize, Default)]
+        struct Resp {
+            #[serde(default)]
+            memories: Vec<Node>,
+            #[serde(default)]
+            superseded_edges: Vec<Edge>,
+        }
+
+        if memory_ids.is_empty() {
+            return HashSet::new();
+        }
+        let resp: Resp = match self
+            .db
+            .execute_query(
+                "getSupersededBatch",
+                &serde_json::json!({ "memory_ids": memory_ids }),
+            )
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                debug!("summarize_recent: superseded check skipped ({e})");
+                return HashSet::new();
+            }
+        };
+        let stale: HashSet<&str> = resp
+            .superseded_edges
+            .iter()
+            .map(|e| e.to_node.as_str())
+            .collect();
+        resp.memories
+            .into_iter()
+            .filter(|n| stale.contains(n.id.as_str()))
+            .map(|n| n.memory_id)
+            .collect()
+    }
+
+    /// Ids among `memory_ids` that are stored summaries; the `recent`
+    /// search uses it to prefer them. Reads only those nodes. Best-effort:
+    /// a DB error reads as none.
+    pub(crate) async fn summary_ids(&self, memory_ids: &[&str]) -> HashSet<String> {
+        match self.memories_by_ids(memory_ids).await {
+            Ok(rows) => rows
+                .iter()
+                .filter(|m| is_summary(m))
+                .filter_map(|m| m["memory_id"].as_str().map(str::to_string))
+                .collect(),
+            Err(e) => {
+                debug!("summary lookup skipped ({e})");
+                HashSet::new()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sources_are_recent_plain_and_unarchived_newest_first() {
+        let now = Utc::now();
+        let at = |hours: i64| (now - Duration::hours(hours)).to_rfc3339();
+        let memories = vec![
+            json!({ "memory_id": "m_old", "content": "Last month", "created_at": at(24 * 30) }),
+            json!({ "memory_id": "m_1", "content": "Chose Postgres", "created_at": at(5) }),
+            json!({ "memory_id": "m_2", "content": "Set up CI", "created_at": at(1), "user_count": 2 }),
+            json!({ "memory_id": "m_sum", "content": "Earlier summary", "created_at": at(2), "context_tags": SUMMARY_TAG }),
+            json!({ "memory_id": "m_gone", "content": "Deleted", "created_at": at(2), "is_deleted": 1 }),
+            json!({ "memory_id": "m_arch", "content": "Archived", "created_at": at(3) }),
+        ];
+        let superseded = HashSet::from(["m_arch".to_string()]);
+
+        let sources = pick_sources(&memories, now - Duration::hours(24), &superseded, 10);
+        let ids: Vec<_> = sources.iter().map(|s| s.memory_id.as_str()).collect();
+        assert_eq!(ids, ["m_2", "m_1"]);
+        assert!(sources[0].shared && !sources[1].shared);
+        assert_eq!(
+            pick_sources(&memories, now - Duration::hours(24), &superseded, 1).len(),
+            1
+        );
+
+        let prompt = summary_prompt(&sources);
+        let postgres = prompt.find("Chose Postgres").unwrap();
+        assert!(postgres < prompt.find("Set up CI").unwrap(), "{prompt}");
+    }
+}
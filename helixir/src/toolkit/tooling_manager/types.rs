@@ -27,6 +27,42 @@ pub struct AddMemoryResult {
     /// executed; the agent decides whether to ask the human.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub needs_clarification: Vec<Clarification>,
+    /// Extraction found no atomic facts in the message; what happened next
+    /// is `write.on_empty_extraction` (raw fallback stored, or nothing).
+    #[serde(default)]
+    pub no_facts_extracted: bool,
+    /// Near-duplicates found by the write-time check (`write.dedup_on_add`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<DuplicateHit>,
+    /// Memories stored without a vector because embedding failed under
+    /// `write.on_embed_fail = defer`; `reindex_embeddings` fills them in.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub needs_embedding: Vec<String>,
+}
+
+impl AddMemoryResult {
+    /// Nothing was written because every fact was already stored: the
+    /// write-time check skipped at least one and nothing else was added or
+    /// updated.
+    pub fn is_duplicate(&self) -> bool {
+        self.added.is_empty()
+            && self.updated.is_empty()
+            && self.duplicates.iter().any(|d| d.action == "skipped")
+    }
+}
+
+/// A fact that `write.dedup_on_add` found already stored, at or above
+/// `dedup_on_add_threshold`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateHit {
+    pub new_content: String,
+    pub existing_memory_id: String,
+    pub existing_content: String,
+    pub similarity: f64,
+    /// `skipped` (nothing stored, the existing memory stands in for it) or
+    /// `warned` (stored anyway).
+    pub action: String,
+    pub suggestion: String,
 }
 
 /// One write-path conflict surfaced to the agent per the memory charter.
@@ -62,6 +98,10 @@ pub struct SearchMemoryResult {
     pub method: String,
     pub metadata: HashMap<String, serde_json::Value>,
     pub created_at: String,
+    /// 0 for a direct semantic match, N for a row graph expansion reached
+    /// N relation hops from its seed.
+    #[serde(default)]
+    pub hops: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +109,8 @@ pub struct ReasoningChainSearchResult {
     pub chains: Vec<ToolingReasoningChain>,
     pub total_memories: usize,
     pub deepest_chain: usize,
+    /// At least one chain stopped at the node cap.
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,6 +119,7 @@ pub struct ToolingReasoningChain {
     pub nodes: Vec<ChainNode>,
     pub chain_type: String,
     pub reasoning_trail: String,
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,6 +130,175 @@ pub struct ChainNode {
     pub depth: usize,
 }
 
+/// What [`super::ToolingManager::purge_user`] removed from the graph.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PurgeStats {
+    pub memories: usize,
+    /// Memories other users still hold: only this user's HAS_MEMORY link is
+    /// dropped and `user_count` recomputed, the memory stays.
+    pub memories_unlinked: usize,
+    /// Entities left with no memory referencing them after the purge.
+    pub entities: usize,
+    /// Distinct edges incident to the dropped memories.
+    pub relations: usize,
+    pub embeddings: usize,
+    pub chunks: usize,
+    pub user_node_removed: bool,
+}
+
+/// One memory as stored, for [`super::ToolingManager::get_memory_record`]:
+/// the raw node plus who links it and what it links to.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryRecord {
+    pub node: serde_json::Value,
+    /// Every user with a HAS_MEMORY link to it.
+    pub users: Vec<String>,
+    /// INSTANCE_OF targets, by name.
+    pub concepts: Vec<String>,
+    /// Extracted and mentioned entities, by name, deduped.
+    pub entities: Vec<String>,
+    /// Memory-to-memory edges as `(kind, from, to)`.
+    pub relations: Vec<(String, String, String)>,
+}
+
+/// What [`super::ToolingManager::append_memory`] did.
+#[derive(Debug, Clone, Default)]
+pub struct AppendOutcome {
+    /// The stored content after the append.
+    pub content: String,
+    /// Facts extracted from the combined text that the original did not
+    /// already state.
+    pub new_facts_added: usize,
+    pub entities_linked: usize,
+    pub relations_added: usize,
+}
+
+/// What [`super::ToolingManager::correct_memory`] stored.
+#[derive(Debug, Clone, Default)]
+pub struct CorrectionOutcome {
+    /// The new memory holding the correction.
+    pub memory_id: String,
+    /// The memory it supersedes, kept for history.
+    pub corrected_id: String,
+    pub previous_content: String,
+    pub content: String,
+}
+
+/// What [`super::ToolingManager::summarize_recent`] did.
+#[derive(Debug, Clone, Default)]
+pub struct SummaryOutcome {
+    /// The stored summary; `None` when too few memories were eligible.
+    pub summary_id: Option<String>,
+    pub content: String,
+    /// The memories the summary covers, newest first (the eligible ones
+    /// when nothing was written).
+    pub source_ids: Vec<String>,
+    pub window_hours: u64,
+    /// Sources with a SUPPORTS edge to the summary.
+    pub sources_linked: usize,
+    /// Sources the summary now supersedes.
+    pub archived: usize,
+}
+
+/// One page from [`super::ToolingManager::list_memories`].
+#[derive(Debug, Clone, Default)]
+pub struct MemoryListPage {
+    /// Stored memory nodes, newest first.
+    pub memories: Vec<serde_json::Value>,
+    /// Offset of the next page; `None` on the last one.
+    pub next_offset: Option<usize>,
+    /// Row position of each memory: passing `positions[i]` as `offset`
+    /// lists `memories[i]` first.
+    pub positions: Vec<usize>,
+    /// HAS_MEMORY rows the user holds before any filter — an upper bound
+    /// on the matches across all pages.
+    pub total_rows: usize,
+}
+
+/// One memory [`super::ToolingManager::find_stale`] thinks needs review.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StaleCandidate {
+    pub memory_id: String,
+    pub content: String,
+    pub created_at: String,
+    /// Days since the memory was last written (created or updated).
+    pub age_days: u64,
+    /// Why it was flagged, one line per signal.
+    pub reasons: Vec<String>,
+    /// Time-bound phrases and version numbers found in the content; empty
+    /// when the memory is younger than the age floor.
+    pub markers: Vec<String>,
+    /// Newer memories with an open contradiction against this one.
+    pub contradicted_by: Vec<String>,
+}
+
+/// One topic from [`super::ToolingManager::cluster_memories`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoryCluster {
+    /// The member closest to the cluster's centroid.
+    pub representative_id: String,
+    pub representative_content: String,
+    pub size: usize,
+    /// Members, closest to the centroid first.
+    pub memory_ids: Vec<String>,
+    /// Mean cosine similarity of the members to the centroid.
+    pub cohesion: f64,
+    /// LLM-written topic name, when asked for and the call succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// What [`super::ToolingManager::cluster_memories`] found.
+#[derive(Debug, Clone, Default)]
+pub struct ClusterOutcome {
+    /// Largest first.
+    pub clusters: Vec<MemoryCluster>,
+    pub k: usize,
+    /// `k` was chosen by silhouette score rather than given.
+    pub auto_k: bool,
+    /// Mean silhouette of the chosen clustering; `None` for one cluster.
+    pub silhouette: Option<f64>,
+    pub memories_clustered: usize,
+}
+
+/// A memory supporting another, from
+/// [`super::ToolingManager::supporting_sources`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupportingSource {
+    pub memory_id: String,
+    pub content: String,
+    /// Edge strength, 0-100.
+    pub strength: i64,
+    /// The edge was written by `think_commit` from the session's evidence.
+    pub from_commit: bool,
+}
+
+/// Event-time census from [`super::ToolingManager::window_counts`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WindowCounts {
+    pub total_overall: usize,
+    pub total_in_window: usize,
+}
+
+/// Graph view from [`super::ToolingManager::get_memory_graph`]. Nodes that
+/// failed to load are left out (with the edges touching them) and named in
+/// `warnings` as `(node_id, reason)`.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryGraph {
+    pub nodes: Vec<serde_json::Value>,
+    pub edges: Vec<serde_json::Value>,
+    pub warnings: Vec<(String, String)>,
+}
+
+/// One entity as stored, for [`super::ToolingManager::get_entity_record`]:
+/// the entity plus the memories that extract or mention it.
+#[derive(Debug, Clone)]
+pub struct EntityRecord {
+    pub entity: crate::toolkit::mind_toolbox::entity::Entity,
+    /// Linked memories as `(memory_id, user_ids holding it)`, deduped.
+    pub memories: Vec<(String, Vec<String>)>,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ToolingError {
     #[error("Embedding failed: {0}")]
@@ -107,6 +319,8 @@ pub enum ToolingError {
     Search(#[from] SearchError),
     #[error("Database error: {0}")]
     Database(String),
+    #[error("Configuration error: {0}")]
+    Config(String),
 }
 
 #[cfg(test)]
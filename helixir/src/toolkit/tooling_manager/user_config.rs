@@ -0,0 +1,94 @@
+//! Per-user config overrides, one `UserConfig` node per user holding the
+//! JSON of [`UserConfigOverrides`]. The merge over the global config is
+//! [`crate::core::config::HelixirConfig::for_user`].
+
+use serde::Deserialize;
+
+use super::ToolingManager;
+use super::types::ToolingError;
+use crate::core::config::UserConfigOverrides;
+use crate::utils::nullable_string;
+
+#[derive(Deserialize, Default)]
+struct UserConfigNode {
+    #[serde(default, deserialize_with = "nullable_string")]
+    user_id: String,
+    #[serde(default, deserialize_with = "nullable_string")]
+    overrides: String,
+}
+
+impl ToolingManager {
+    /// The stored node's overrides, `None` when the user has none.
+    async fn stored_user_config(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<UserConfigOverrides>, ToolingError> {
+        #[derive(Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            user_config: Option<UserConfigNode>,
+        }
+        let resp: Resp = match self
+            .db
+            .execute_query("getUserConfig", &serde_json::json!({ "user_id": user_id }))
+            .await
+        {
+            Ok(resp) => resp,
+            // No node reads back as `No value found` on HelixDB.
+            Err(e) if e.to_string().to_lowercase().contains("no value found") => return Ok(None),
+            Err(e) => return Err(ToolingError::Database(e.to_string())),
+        };
+        let Some(node) = resp.user_config.filter(|n| !n.user_id.is_empty()) else {
+            return Ok(None);
+        };
+        if node.overrides.trim().is_empty() {
+            return Ok(Some(UserConfigOverrides::default()));
+        }
+        serde_json::from_str(&node.overrides)
+            .map(Some)
+            .map_err(|e| ToolingError::Database(format!("user config of {user_id}: {e}")))
+    }
+
+    /// `user_id`'s overrides; empty when none are stored.
+    pub async fn get_user_config(
+        &self,
+        user_id: &str,
+    ) -> Result<UserConfigOverrides, ToolingError> {
+        Ok(self.stored_user_config(user_id).await?.unwrap_or_default())
+    }
+
+    /// Store `overrides` as `user_id`'s whole override set (the caller has
+    /// merged and validated it). An empty set removes the node.
+    pub async fn save_user_config(
+        &self,
+        user_id: &str,
+        overrides: &UserConfigOverrides,
+    ) -> Result<(), ToolingError> {
+        let exists = self.stored_user_config(user_id).await?.is_some();
+        let (query, params) = if overrides.is_empty() {
+            if !exists {
+                return Ok(());
+            }
+            ("dropUserConfig", serde_json::json!({ "user_id": user_id }))
+        } else {
+            (
+                if exists {
+                    "updateUserConfig"
+                } else {
+                    "addUserConfig"
+                },
+                serde_json::json!({
+                    "user_id": user_id,
+                    "overrides": serde_json::to_string(overrides)
+                        .map_err(|e| ToolingError::Database(e.to_string()))?,
+                    "updated_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            )
+        };
+        self.db
+            .execute_query::<serde_json::Value, _>(query, &params)
+            .await
+            .map(|_| ())
+            .map_err(|e| ToolingError::Database(e.to_string()))
+    }
+}
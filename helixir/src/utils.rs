@@ -18,10 +18,48 @@ pub fn safe_truncate_ellipsis(s: &str, max_chars: usize) -> String {
     }
 }
 
+/// Cosine similarity of two equal-length vectors, in `[-1, 1]`; `0.0` on
+/// length mismatch or a zero-norm vector. The one copy every embedding
+/// comparison uses (search scoring keeps its own `[0, 1]` `cosine_score`).
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let (mut dot, mut na, mut nb) = (0f64, 0f64, 0f64);
+    for (x, y) in a.iter().zip(b.iter()) {
+        let (x, y) = (*x as f64, *y as f64);
+        dot += x * y;
+        na += x * x;
+        nb += y * y;
+    }
+    if na == 0.0 || nb == 0.0 {
+        return 0.0;
+    }
+    dot / (na.sqrt() * nb.sqrt())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn cosine_identical_is_one() {
+        let v = [0.3f32, 0.4, 0.5];
+        assert!((cosine_similarity(&v, &v) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cosine_orthogonal_is_zero() {
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cosine_degenerate_is_zero() {
+        assert_eq!(cosine_similarity(&[1.0, 2.0], &[1.0]), 0.0); // length mismatch
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), 0.0); // zero-norm vector
+        assert_eq!(cosine_similarity(&[], &[]), 0.0); // empty
+    }
+
     #[test]
     fn test_safe_truncate_ascii() {
         assert_eq!(safe_truncate("hello world", 5), "hello");
@@ -0,0 +1,113 @@
+//! In-process harness: a [`HelixirClient`] over an [`InMemoryStore`] that
+//! serves the real `queries.hx`, the `hash` embedder in place of a model and
+//! a scripted [`LlmProvider`] that answers every call with an empty
+//! extraction. Needs no services, so suites built on it run on plain
+//! `cargo test`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use helixir::core::config::HelixirConfig;
+use helixir::core::helixir_client::HelixirClient;
+use helixir::db::{HelixClient, InMemoryStore};
+use helixir::llm::extractor::ExtractedMemory;
+use helixir::llm::providers::base::{LlmMetadata, LlmProvider, LlmProviderError};
+use helixir::llm::{EmbeddingConfig, EmbeddingGenerator};
+use serde_json::json;
+
+/// Answers every prompt with an extraction that found nothing.
+pub struct ScriptedLlm;
+
+#[async_trait]
+impl LlmProvider for ScriptedLlm {
+    async fn generate(
+        &self,
+        _system_prompt: &str,
+        _user_prompt: &str,
+        _response_format: Option<&str>,
+    ) -> Result<(String, LlmMetadata), LlmProviderError> {
+        let body = json!({"memories": [], "entities": [], "relations": []});
+        Ok((body.to_string(), LlmMetadata::default()))
+    }
+
+    fn provider_name(&self) -> &str {
+        "scripted"
+    }
+
+    fn model_name(&self) -> &str {
+        "scripted"
+    }
+}
+
+pub fn client(store: Arc<InMemoryStore>) -> Arc<HelixirClient> {
+    client_with(store, HelixirConfig::default())
+}
+
+/// [`client`] with `config` (its embedding provider is forced to `hash`).
+pub fn client_with(store: Arc<InMemoryStore>, config: HelixirConfig) -> Arc<HelixirClient> {
+    client_with_llm(store, config, Arc::new(ScriptedLlm))
+}
+
+/// [`client_with`] answering LLM calls with `llm`.
+pub fn client_with_llm(
+    store: Arc<InMemoryStore>,
+    config: HelixirConfig,
+    llm: Arc<dyn LlmProvider>,
+) -> Arc<HelixirClient> {
+    build(store, config, llm, "hash")
+}
+
+/// [`client_with`] whose embedder fails every call, as during an
+/// embedding-service outage.
+pub fn client_without_embeddings(
+    store: Arc<InMemoryStore>,
+    config: HelixirConfig,
+) -> Arc<HelixirClient> {
+    build(store, config, Arc::new(ScriptedLlm), "offline")
+}
+
+fn build(
+    store: Arc<InMemoryStore>,
+    config: HelixirConfig,
+    llm: Arc<dyn LlmProvider>,
+    embedding_provider: &str,
+) -> Arc<HelixirClient> {
+    let config = HelixirConfig {
+        embedding_provider: "hash".to_string(),
+        ..config
+    };
+    let embedder = EmbeddingGenerator::new(EmbeddingConfig {
+        provider: embedding_provider.to_string(),
+        base_url: String::new(),
+        model: "hash".to_string(),
+        api_key: None,
+        timeout_secs: 1,
+        cache_size: 64,
+        cache_ttl: 60,
+        fallback_enabled: false,
+        fallback_url: String::new(),
+        fallback_model: String::new(),
+        expected_dim: Some(128),
+        batch_size: helixir::DEFAULT_EMBEDDING_BATCH_SIZE,
+    });
+    Arc::new(
+        HelixirClient::with_backends(
+            config,
+            Arc::new(HelixClient::with_store(store)),
+            Arc::new(embedder),
+            llm,
+        )
+        .expect("client"),
+    )
+}
+
+pub fn fact(text: &str) -> ExtractedMemory {
+    ExtractedMemory {
+        text: text.to_string(),
+        memory_type: "fact".to_string(),
+        certainty: 90,
+        importance: 70,
+        entities: vec![],
+        context: None,
+    }
+}
@@ -4,6 +4,7 @@
 #![allow(dead_code)] // each test crate uses a subset
 
 pub mod golden;
+pub mod in_memory;
 
 use std::io::{BufRead, BufReader, Write};
 use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
@@ -0,0 +1,136 @@
+//! `HelixirClient::append` on the [`common::in_memory`] harness: the
+//! original content survives, the addition is searchable and counted, and
+//! a memory the caller does not hold is refused; the memory keeps its
+//! scores and the combined text is extracted once.
+
+mod common;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use common::in_memory::{client, client_with_llm, fact};
+use helixir::core::config::HelixirConfig;
+use helixir::core::helixir_client::{HelixirClientError, SearchParams};
+use helixir::db::{InMemoryStore, MemoryStore};
+use helixir::llm::providers::base::{LlmMetadata, LlmProvider, LlmProviderError};
+use serde_json::json;
+
+const ADDITION: &str = "The canary stage was dropped from the release checklist.";
+
+/// Extracts one fact and counts the prompts that carry [`ADDITION`].
+#[derive(Default)]
+struct CountingLlm {
+    extractions: AtomicUsize,
+}
+
+#[async_trait]
+impl LlmProvider for CountingLlm {
+    async fn generate(
+        &self,
+        _system_prompt: &str,
+        user_prompt: &str,
+        _response_format: Option<&str>,
+    ) -> Result<(String, LlmMetadata), LlmProviderError> {
+        if user_prompt.contains(ADDITION) {
+            self.extractions.fetch_add(1, Ordering::SeqCst);
+        }
+        let body = json!({
+            "memories": [{"text": ADDITION, "memory_type": "fact"}],
+            "entities": [],
+            "relations": [],
+        });
+        Ok((body.to_string(), LlmMetadata::default()))
+    }
+
+    fn provider_name(&self) -> &str {
+        "counting"
+    }
+
+    fn model_name(&self) -> &str {
+        "counting"
+    }
+}
+
+#[tokio::test]
+async fn append_keeps_the_original_and_indexes_the_addition() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let seeded = client
+        .add_prepared(vec![fact("Deploys run on Fridays.")], "alice", None, None)
+        .await
+        .expect("seed write");
+    let id = seeded.memory_ids[0].clone();
+
+    let appended = client
+        .append(
+            &id,
+            "Also, the team decided to move releases to Tuesday mornings after the outage review.",
+            "alice",
+        )
+        .await
+        .expect("append");
+    assert!(
+        appended.content.starts_with("Deploys run on Fridays.\n"),
+        "{appended:?}"
+    );
+    // The scripted LLM extracts nothing, so the combined text is one fact —
+    // and it is mostly new words.
+    assert_eq!(appended.new_facts_added, 1);
+
+    let stored = client.get_memory(&id, "alice").await.unwrap().unwrap();
+    assert_eq!(stored.content, appended.content);
+
+    let hits = client
+        .search(
+            "releases moved to Tuesday mornings",
+            "alice",
+            SearchParams::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(hits.first().map(|h| h.id.as_str()), Some(id.as_str()));
+
+    for (memory_id, user) in [(id.as_str(), "bob"), ("mem_nowhere", "alice")] {
+        let err = client.append(memory_id, "more", user).await.unwrap_err();
+        assert!(matches!(err, HelixirClientError::Config(_)), "{err}");
+    }
+    let err = client.append(&id, "  ", "alice").await.unwrap_err();
+    assert!(matches!(err, HelixirClientError::Config(_)), "{err}");
+}
+
+#[tokio::test]
+async fn append_keeps_the_scores_and_extracts_once() {
+    let store = Arc::new(InMemoryStore::new());
+    let llm = Arc::new(CountingLlm::default());
+    let client = client_with_llm(
+        Arc::clone(&store),
+        HelixirConfig::default(),
+        Arc::clone(&llm) as Arc<dyn LlmProvider>,
+    );
+
+    // `fact` stores certainty 90 / importance 70, not the config defaults.
+    let id = client
+        .add_prepared(
+            vec![fact("Releases go through a canary stage.")],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write")
+        .memory_ids[0]
+        .clone();
+    client.append(&id, ADDITION, "alice").await.expect("append");
+
+    assert_eq!(llm.extractions.load(Ordering::SeqCst), 1);
+    let node = store
+        .query("getMemory", json!({ "memory_id": id }))
+        .await
+        .unwrap()["memory"]
+        .clone();
+    assert_eq!(node["certainty"], 90, "{node}");
+    assert_eq!(node["importance"], 70, "{node}");
+    assert!(node["content"].as_str().unwrap().ends_with(ADDITION));
+}
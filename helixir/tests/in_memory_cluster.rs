@@ -0,0 +1,137 @@
+//! `cluster_memories` on the [`common::in_memory`] harness: two unrelated
+//! topics come back as two clusters, whether k is fixed or chosen by
+//! silhouette, and `label` names each cluster through the LLM. Stored
+//! vectors are clustered without the embedder, newest memories first.
+
+mod common;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common::in_memory::{client, client_with_llm, client_without_embeddings, fact};
+use helixir::core::config::HelixirConfig;
+use helixir::db::InMemoryStore;
+use helixir::llm::providers::base::{LlmMetadata, LlmProvider, LlmProviderError};
+
+const DATABASE: [&str; 3] = [
+    "The postgres database replica lags behind the primary database",
+    "Nightly postgres database backups run on the replica",
+    "The primary postgres database needs a bigger disk",
+];
+
+const PIANO: [&str; 3] = [
+    "Alice takes piano lessons every Tuesday evening",
+    "Alice practices piano scales before her lessons",
+    "Alice wants a new piano teacher for her lessons",
+];
+
+/// Names every cluster the same, in quotes the tool should strip.
+struct LabelLlm;
+
+#[async_trait]
+impl LlmProvider for LabelLlm {
+    async fn generate(
+        &self,
+        _system_prompt: &str,
+        _user_prompt: &str,
+        _response_format: Option<&str>,
+    ) -> Result<(String, LlmMetadata), LlmProviderError> {
+        Ok(("\"Shared topic\"\n".to_string(), LlmMetadata::default()))
+    }
+
+    fn provider_name(&self) -> &str {
+        "label"
+    }
+
+    fn model_name(&self) -> &str {
+        "label"
+    }
+}
+
+fn cluster_of(result: &helixir::core::helixir_client::ClusterResult, memory_id: &str) -> usize {
+    result
+        .clusters
+        .iter()
+        .position(|c| c.memory_ids.iter().any(|id| id == memory_id))
+        .unwrap_or_else(|| panic!("{memory_id} in no cluster: {result:?}"))
+}
+
+#[tokio::test]
+async fn two_topics_come_back_as_two_clusters() {
+    let store = Arc::new(InMemoryStore::new());
+    let helixir = client(Arc::clone(&store));
+    let mut ids = Vec::new();
+    for text in DATABASE.iter().chain(&PIANO) {
+        let added = helixir
+            .add_prepared(vec![fact(text)], "alice", None, None)
+            .await
+            .unwrap();
+        ids.push(added.memory_ids[0].clone());
+    }
+
+    let auto = helixir
+        .cluster_memories("alice", None, false)
+        .await
+        .unwrap();
+    assert!(auto.auto_k);
+    assert_eq!((auto.k, auto.memories_clustered), (2, 6), "{auto:?}");
+    assert!(auto.silhouette.unwrap() > 0.0);
+    for group in [&ids[..3], &ids[3..]] {
+        let home = cluster_of(&auto, &group[0]);
+        assert!(group.iter().all(|id| cluster_of(&auto, id) == home));
+        let cluster = &auto.clusters[home];
+        assert_eq!(cluster.size, 3);
+        assert!(group.contains(&cluster.representative_id));
+        assert_eq!(cluster.label, None);
+    }
+
+    let labelled = client_with_llm(store, HelixirConfig::default(), Arc::new(LabelLlm))
+        .cluster_memories("alice", Some(2), true)
+        .await
+        .unwrap();
+    assert!(!labelled.auto_k);
+    assert_eq!(labelled.k, 2);
+    assert!(
+        labelled
+            .clusters
+            .iter()
+            .all(|c| c.label.as_deref() == Some("Shared topic"))
+    );
+
+    assert!(
+        helixir
+            .cluster_memories("alice", Some(0), false)
+            .await
+            .is_err()
+    );
+    let empty = helixir.cluster_memories("bob", None, false).await.unwrap();
+    assert_eq!((empty.k, empty.clusters.len()), (0, 0));
+}
+
+#[tokio::test]
+async fn stored_vectors_of_the_newest_memories_need_no_embedder() {
+    let store = Arc::new(InMemoryStore::new());
+    let helixir = client(Arc::clone(&store));
+    let mut ids = Vec::new();
+    for text in DATABASE.iter().chain(&PIANO) {
+        let added = helixir
+            .add_prepared(vec![fact(text)], "alice", None, None)
+            .await
+            .unwrap();
+        ids.push(added.memory_ids[0].clone());
+    }
+
+    let mut config = HelixirConfig::default();
+    config.cluster.max_memories = 3;
+    let offline = client_without_embeddings(store, config);
+    let result = offline
+        .cluster_memories("alice", Some(1), false)
+        .await
+        .unwrap();
+    assert_eq!(result.memories_clustered, 3, "{result:?}");
+    let mut clustered = result.clusters[0].memory_ids.clone();
+    clustered.sort();
+    let mut newest = ids[3..].to_vec();
+    newest.sort();
+    assert_eq!(clustered, newest);
+}
@@ -0,0 +1,57 @@
+//! `HelixirClient::compare_search_modes` on the [`common::in_memory`]
+//! harness: every mode is reported, in order, with its count and top hit,
+//! and a blank query is refused.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::core::helixir_client::HelixirClientError;
+use helixir::db::InMemoryStore;
+
+#[tokio::test]
+async fn every_mode_reports_its_count_and_top_hit() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let seeded = client
+        .add_prepared(
+            vec![fact("The billing service pages the on-call rota")],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write");
+    let id = seeded.memory_ids[0].clone();
+
+    let modes = client
+        .compare_search_modes("who does billing page", "alice", Some(500))
+        .await
+        .expect("compare");
+    let names: Vec<&str> = modes.iter().map(|m| m.mode).collect();
+    assert_eq!(names, ["recent", "contextual", "deep", "full"]);
+    for mode in &modes {
+        assert!(mode.error.is_none(), "{mode:?}");
+        assert_eq!(mode.result_count, 1, "{mode:?}");
+        assert!(!mode.capped);
+        assert_eq!(mode.top.as_ref().map(|t| t.id.as_str()), Some(id.as_str()));
+    }
+
+    let nobody = client
+        .compare_search_modes("who does billing page", "bob", None)
+        .await
+        .unwrap();
+    assert!(
+        nobody
+            .iter()
+            .all(|m| m.result_count == 0 && m.top.is_none())
+    );
+
+    let err = client
+        .compare_search_modes("  ", "alice", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HelixirClientError::Config(_)), "{err}");
+}
@@ -0,0 +1,141 @@
+//! `HelixirClient::correct_memory` on the [`common::in_memory`] harness:
+//! the correction is a new memory, the corrected one leaves search but
+//! stays readable and listed as archived, and a memory the caller does not
+//! hold is refused.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::core::helixir_client::{HelixirClientError, SearchParams};
+use helixir::db::{InMemoryStore, MemoryStore};
+use helixir::toolkit::tooling_manager::MemoryListFilter;
+
+#[tokio::test]
+async fn a_correction_replaces_the_old_memory_in_search_and_keeps_it() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let seeded = client
+        .add_prepared(
+            vec![fact("The product launch is set for March 5.")],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write");
+    let old_id = seeded.memory_ids[0].clone();
+
+    let corrected = client
+        .correct_memory(&old_id, "The product launch is set for March 12.", "alice")
+        .await
+        .expect("correct");
+    assert_ne!(corrected.memory_id, old_id);
+    assert_eq!(corrected.corrected_id, old_id);
+    assert_eq!(
+        corrected.previous_content,
+        "The product launch is set for March 5."
+    );
+
+    let hits = client
+        .search("product launch date", "alice", SearchParams::default())
+        .await
+        .unwrap();
+    assert!(hits.iter().all(|h| h.id != old_id), "{hits:?}");
+    let first = hits.first().expect("the correction is found");
+    assert_eq!(first.id, corrected.memory_id);
+    assert_eq!(first.metadata["corrects"], serde_json::json!([old_id]));
+
+    let old = client.get_memory(&old_id, "alice").await.unwrap();
+    assert_eq!(
+        old.expect("corrected memory is kept").content,
+        "The product launch is set for March 5."
+    );
+    let archived = client
+        .list_memories(
+            "alice",
+            &MemoryListFilter {
+                archived: Some(true),
+                ..MemoryListFilter::default()
+            },
+            0,
+            10,
+        )
+        .await
+        .unwrap();
+    let archived_ids: Vec<&str> = archived
+        .memories
+        .iter()
+        .filter_map(|m| m["memory_id"].as_str())
+        .collect();
+    assert_eq!(archived_ids, [old_id.as_str()]);
+
+    for (memory_id, user) in [(old_id.as_str(), "bob"), ("mem_nowhere", "alice")] {
+        let err = client
+            .correct_memory(memory_id, "anything", user)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, HelixirClientError::Config(_)), "{err}");
+    }
+    let err = client
+        .correct_memory(&old_id, " ", "alice")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HelixirClientError::Config(_)), "{err}");
+}
+
+#[tokio::test]
+async fn a_correction_reaches_every_holder_of_the_old_memory() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let old_id = client
+        .add_prepared(vec![fact("The offsite is in Lisbon.")], "alice", None, None)
+        .await
+        .unwrap()
+        .memory_ids[0]
+        .clone();
+    store
+        .query(
+            "addUser",
+            serde_json::json!({"user_id": "bob", "name": "bob"}),
+        )
+        .await
+        .expect("add bob");
+    store
+        .query(
+            "linkUserToMemoryWithStance",
+            serde_json::json!({
+                "user_id": "bob",
+                "memory_id": old_id,
+                "context": "shared",
+                "stance": "asserts",
+                "certainty": 80,
+                "linked_at": "2026-01-01T00:00:00Z",
+            }),
+        )
+        .await
+        .expect("link bob");
+
+    let corrected = client
+        .correct_memory(&old_id, "The offsite is in Porto.", "alice")
+        .await
+        .expect("correct");
+    let seen = client
+        .get_memory(&corrected.memory_id, "bob")
+        .await
+        .unwrap()
+        .expect("bob holds the correction");
+    assert_eq!(seen.content, "The offsite is in Porto.");
+    let count = store
+        .query(
+            "getMemoryUserCount",
+            serde_json::json!({ "memory_id": corrected.memory_id }),
+        )
+        .await
+        .unwrap()["count"]
+        .as_i64();
+    assert_eq!(count, Some(2));
+}
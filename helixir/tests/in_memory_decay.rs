@@ -0,0 +1,104 @@
+//! Certainty-decay inputs on the [`common::in_memory`] harness: a deduped
+//! re-add restarts a memory's clock, a search hit does not, and every row's
+//! inputs come from one batch read.
+
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use common::in_memory::{client, fact};
+use helixir::db::{InMemoryStore, MemoryStore};
+use serde_json::json;
+
+const LONG_AGO: &str = "2025-01-01T00:00:00+00:00";
+
+#[tokio::test]
+async fn a_search_hit_does_not_restart_the_clock_but_a_re_add_does() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    store
+        .query("addUser", json!({"user_id": "gina", "name": "gina"}))
+        .await
+        .unwrap();
+    for (id, content) in [
+        ("mem_rota", "Gina keeps the on-call rota."),
+        ("mem_restore", "The staging database is restored nightly."),
+    ] {
+        store
+            .query(
+                "addMemory",
+                json!({
+                    "memory_id": id,
+                    "user_id": "gina",
+                    "content": content,
+                    "memory_type": "fact",
+                    "certainty": 80,
+                    "importance": 50,
+                    "created_at": LONG_AGO,
+                    "updated_at": LONG_AGO,
+                    "context_tags": "",
+                    "source": "test",
+                    "metadata": "{}",
+                }),
+            )
+            .await
+            .unwrap();
+    }
+    let now = Utc::now().to_rfc3339();
+    store
+        .query(
+            "recordMemoryAccess",
+            json!({"memory_id": "mem_rota", "last_accessed_at": now, "access_count": 5}),
+        )
+        .await
+        .unwrap();
+    store
+        .query(
+            "reinforceMemory",
+            json!({"memory_id": "mem_restore", "reinforced_at": now}),
+        )
+        .await
+        .unwrap();
+
+    let ids = vec![
+        "mem_rota".to_string(),
+        "mem_restore".to_string(),
+        "mem_nowhere".to_string(),
+    ];
+    let inputs = client.tooling().decay_inputs(&ids).await;
+    assert_eq!(inputs.len(), 2, "{inputs:?}");
+    let long_ago = DateTime::parse_from_rfc3339(LONG_AGO).unwrap().to_utc();
+    assert_eq!(inputs["mem_rota"].reinforced_at, Some(long_ago));
+    assert!(inputs["mem_restore"].reinforced_at > Some(long_ago));
+}
+
+#[tokio::test]
+async fn a_deduped_re_add_stamps_reinforced_at() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let said = fact("Gina keeps the on-call rota.");
+    let id = client
+        .add_prepared(vec![said.clone()], "gina", None, None)
+        .await
+        .unwrap()
+        .memory_ids[0]
+        .clone();
+    let again = client
+        .add_prepared(vec![said], "gina", None, None)
+        .await
+        .unwrap();
+    assert_eq!(again.deduped, vec![id.clone()], "{again:?}");
+
+    let node = store
+        .query("getMemory", json!({ "memory_id": id }))
+        .await
+        .unwrap()["memory"]
+        .clone();
+    assert!(
+        DateTime::parse_from_rfc3339(node["reinforced_at"].as_str().unwrap_or_default()).is_ok(),
+        "{node}"
+    );
+}
@@ -0,0 +1,70 @@
+//! `HELIXIR_DEDUP_ON_ADD` on the [`common::in_memory`] harness: `skip`
+//! returns the existing memory instead of storing a near-copy, `warn`
+//! stores it and says so, and a protected type is never skipped.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client_with, fact};
+use helixir::core::config::{DedupOnAddPolicy, HelixirConfig, WriteConfig};
+use helixir::db::InMemoryStore;
+
+fn config(policy: DedupOnAddPolicy) -> HelixirConfig {
+    HelixirConfig {
+        write: WriteConfig {
+            dedup_on_add: policy,
+            // The hash embedder scores rewordings lower than a model would.
+            dedup_on_add_threshold: 0.5,
+            ..WriteConfig::default()
+        },
+        ..HelixirConfig::default()
+    }
+}
+
+const ORIGINAL: &str = "The nightly deploy pipeline runs on the staging cluster";
+const REWORDED: &str = "The nightly deploy pipeline runs on the staging cluster now";
+const RESTATED: &str = "The nightly deploy pipeline runs on the production cluster";
+
+#[tokio::test]
+async fn skip_returns_the_existing_memory_and_warn_stores_anyway() {
+    let store = Arc::new(InMemoryStore::new());
+    let skipping = client_with(Arc::clone(&store), config(DedupOnAddPolicy::Skip));
+    let seeded = skipping
+        .add_prepared(vec![fact(ORIGINAL)], "alice", None, None)
+        .await
+        .unwrap();
+    assert!(!seeded.duplicate && seeded.duplicates.is_empty());
+    let existing = seeded.memory_ids[0].clone();
+
+    let again = skipping
+        .add_prepared(vec![fact(REWORDED)], "alice", None, None)
+        .await
+        .unwrap();
+    assert!(again.duplicate, "{again:?}");
+    assert!(again.memory_ids.is_empty());
+    assert_eq!(again.deduped, vec![existing.clone()]);
+    assert_eq!(again.duplicates[0].existing_memory_id, existing);
+    assert_eq!(again.duplicates[0].action, "skipped");
+
+    // A preference is a protected type: warned about, still stored.
+    let mut preference = fact(REWORDED);
+    preference.memory_type = "preference".to_string();
+    let kept = skipping
+        .add_prepared(vec![preference], "alice", None, None)
+        .await
+        .unwrap();
+    assert!(!kept.duplicate);
+    assert_eq!(kept.memories_added, 1, "{kept:?}");
+    assert_eq!(kept.duplicates[0].action, "warned");
+
+    let warning = client_with(Arc::clone(&store), config(DedupOnAddPolicy::Warn));
+    let warned = warning
+        .add_prepared(vec![fact(RESTATED)], "alice", None, None)
+        .await
+        .unwrap();
+    assert!(!warned.duplicate);
+    assert_eq!(warned.memories_added, 1, "{warned:?}");
+    assert_eq!(warned.duplicates[0].action, "warned");
+    assert!(warned.duplicates[0].suggestion.contains("update_memory"));
+}
@@ -0,0 +1,125 @@
+//! `HELIXIR_ON_EMBED_FAIL` on the [`common::in_memory`] harness: during an
+//! embedding outage `defer` stores the fact flagged `needs_embedding` where
+//! `fail` stores nothing, and `reindex_embeddings` fills the vector in once
+//! the embedder is back, a `limit` at a time from a resumable cursor.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, client_without_embeddings, fact};
+use helixir::core::config::{HelixirConfig, OnEmbedFail};
+use helixir::core::helixir_client::SearchParams;
+use helixir::db::{InMemoryStore, MemoryStore};
+use serde_json::json;
+
+async fn flagged(store: &InMemoryStore, memory_id: &str) -> bool {
+    let row = store
+        .query("getMemory", json!({ "memory_id": memory_id }))
+        .await
+        .unwrap();
+    let metadata = row["memory"]["metadata"].as_str().unwrap_or("{}");
+    serde_json::from_str::<serde_json::Value>(metadata).unwrap()["needs_embedding"] == json!(true)
+}
+
+#[tokio::test]
+async fn a_deferred_memory_is_stored_and_reindexed_later() {
+    let store = Arc::new(InMemoryStore::new());
+    let text = "The ledger service keeps its snapshots in the cold bucket";
+
+    let failing = client_without_embeddings(Arc::clone(&store), HelixirConfig::default());
+    assert!(
+        failing
+            .add_prepared(vec![fact(text)], "alice", None, None)
+            .await
+            .is_err(),
+        "fail is the default"
+    );
+
+    let mut config = HelixirConfig::default();
+    config.write.on_embed_fail = OnEmbedFail::Defer;
+    let deferring = client_without_embeddings(Arc::clone(&store), config);
+    let added = deferring
+        .add_prepared(vec![fact(text)], "alice", None, None)
+        .await
+        .expect("deferred add");
+    assert_eq!(added.memories_added, 1, "{added:?}");
+    assert_eq!(added.needs_embedding, added.memory_ids);
+    let memory_id = added.memory_ids[0].clone();
+    assert!(flagged(&store, &memory_id).await);
+
+    let still_down = deferring
+        .reindex_embeddings(Some("alice"), 10, None)
+        .await
+        .unwrap();
+    assert_eq!((still_down.reindexed, still_down.failures), (0, 1));
+    assert!(
+        flagged(&store, &memory_id).await,
+        "a failed retry keeps the flag"
+    );
+
+    let healthy = client(Arc::clone(&store));
+    let report = healthy.reindex_embeddings(None, 10, None).await.unwrap();
+    assert_eq!(report.memory_ids, vec![memory_id.clone()]);
+    assert_eq!(report.next_cursor, None);
+    assert!(!flagged(&store, &memory_id).await);
+    assert_eq!(
+        healthy
+            .reindex_embeddings(None, 10, None)
+            .await
+            .unwrap()
+            .reindexed,
+        0
+    );
+
+    let found = healthy
+        .search(text, "alice", SearchParams::default())
+        .await
+        .unwrap();
+    assert!(found.iter().any(|r| r.id == memory_id), "{found:?}");
+}
+
+#[tokio::test]
+async fn reindex_resumes_from_its_cursor() {
+    let store = Arc::new(InMemoryStore::new());
+    let mut config = HelixirConfig::default();
+    config.write.on_embed_fail = OnEmbedFail::Defer;
+    let deferring = client_without_embeddings(Arc::clone(&store), config);
+    let mut deferred = Vec::new();
+    for text in [
+        "The billing job runs at midnight",
+        "Grafana alerts page the on-call engineer",
+        "The staging cluster lives in eu-west",
+    ] {
+        let added = deferring
+            .add_prepared(vec![fact(text)], "alice", None, None)
+            .await
+            .expect("deferred add");
+        deferred.extend(added.memory_ids);
+    }
+
+    let healthy = client(Arc::clone(&store));
+    let first = healthy
+        .reindex_embeddings(Some("alice"), 2, None)
+        .await
+        .unwrap();
+    assert_eq!(first.reindexed, 2);
+    let cursor = first.next_cursor.expect("one memory left");
+    let second = healthy
+        .reindex_embeddings(Some("alice"), 2, Some(&cursor))
+        .await
+        .unwrap();
+    assert_eq!(second.reindexed, 1);
+    assert_eq!(second.next_cursor, None);
+    assert!(second.memories_scanned < 3, "{second:?}");
+    for id in &deferred {
+        assert!(!flagged(&store, id).await, "{id} still flagged");
+    }
+    assert!(
+        healthy
+            .reindex_embeddings(Some("bob"), 2, Some(&cursor))
+            .await
+            .is_err(),
+        "another user's cursor"
+    );
+}
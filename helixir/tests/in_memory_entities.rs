@@ -0,0 +1,92 @@
+//! `get_entity` / `set_entity_attribute` on the [`common::in_memory`]
+//! harness: access follows HAS_MEMORY links rather than the author field,
+//! and an attribute one user sets is invisible to the others.
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::core::helixir_client::SearchParams;
+use helixir::db::{InMemoryStore, MemoryStore};
+use serde_json::json;
+
+#[tokio::test]
+async fn attributes_are_per_user_and_access_follows_links() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let id = client
+        .add_prepared(
+            vec![fact("Dana runs the platform team.")],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+        .memory_ids[0]
+        .clone();
+    for (query, params) in [
+        (
+            "createEntity",
+            json!({"entity_id": "ent_dana", "name": "Dana", "entity_type": "person", "properties": "{}", "aliases": "[]"}),
+        ),
+        (
+            "linkExtractedEntity",
+            json!({"memory_id": id, "entity_id": "ent_dana", "confidence": 90, "method": "test"}),
+        ),
+        ("addUser", json!({"user_id": "bob", "name": "bob"})),
+        (
+            "linkUserToMemoryWithStance",
+            json!({"user_id": "bob", "memory_id": id, "context": "shared", "stance": "asserts", "certainty": 80, "linked_at": "2026-01-01T00:00:00Z"}),
+        ),
+    ] {
+        store.query(query, params).await.expect(query);
+    }
+
+    // bob holds alice's memory without authoring it; carol holds nothing.
+    let seen = client
+        .get_entity("bob", "Dana")
+        .await
+        .unwrap()
+        .expect("bob");
+    assert_eq!(seen.memory_ids, vec![id.clone()]);
+    assert!(client.get_entity("carol", "Dana").await.unwrap().is_none());
+    assert!(
+        client
+            .set_entity_attribute("carol", "Dana", "role", "CTO")
+            .await
+            .is_err()
+    );
+
+    let set = client
+        .set_entity_attribute("alice", "Dana", "role", "CTO")
+        .await
+        .unwrap();
+    assert_eq!(set.attributes["role"], "CTO");
+    let bobs = client.get_entity("bob", "Dana").await.unwrap().unwrap();
+    assert!(!bobs.attributes.contains_key("role"), "{bobs:?}");
+    client
+        .set_entity_attribute("bob", "Dana", "role", "advisor")
+        .await
+        .unwrap();
+    let alices = client.get_entity("alice", "Dana").await.unwrap().unwrap();
+    assert_eq!(alices.attributes["role"], "CTO");
+
+    let filtered = |role: &str| SearchParams {
+        entity_attributes: HashMap::from([("role".to_string(), role.to_string())]),
+        ..SearchParams::default()
+    };
+    let hits = client
+        .search("platform team", "alice", filtered("CTO"))
+        .await
+        .unwrap();
+    assert_eq!(hits.first().map(|h| h.id.as_str()), Some(id.as_str()));
+    let hits = client
+        .search("platform team", "alice", filtered("advisor"))
+        .await
+        .unwrap();
+    assert!(hits.is_empty(), "{hits:?}");
+}
@@ -0,0 +1,623 @@
+//! FastThink start → add → recall → conclude → commit, entirely in process
+//! on the [`common::in_memory`] harness. Runs on plain `cargo test`, no
+//! services.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::core::helixir_client::SearchParams;
+use helixir::db::{InMemoryStore, MemoryStore};
+use helixir::toolkit::fast_think::{
+    CommittedEntity, FastThinkError, FastThinkManager, ScratchEntityType, SessionStatus,
+    ThoughtEdge, ThoughtOptions, ThoughtSearchMode, ThoughtType, WeightedEdge,
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn think_session_commits_against_the_in_memory_store() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let seeded = client
+        .add_prepared(
+            vec![
+                fact("The aurora ingestion service reads from the kappa queue"),
+                fact("The cafeteria serves lunch at noon on Fridays"),
+            ],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write");
+    assert_eq!(seeded.memory_ids.len(), 2, "{seeded:?}");
+    let evidence_id = seeded.memory_ids[0].clone();
+
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+    let root = manager
+        .start_thinking("s1", "Which queue does the aurora service consume?")
+        .unwrap();
+    let step = manager
+        .add_thought(
+            "s1",
+            "Check what memory says about aurora ingestion",
+            ThoughtType::Reasoning,
+            Some(root),
+            ThoughtOptions::default(),
+        )
+        .unwrap();
+
+    let (parent, recalled, _) = manager
+        .recall(
+            "s1",
+            "aurora ingestion service kappa queue",
+            Some(step),
+            "alice",
+        )
+        .await
+        .expect("recall");
+    assert_eq!(parent, step);
+    assert!(!recalled.is_empty(), "recall found nothing");
+    manager
+        .extract_entity("s1", step, "aurora", ScratchEntityType::Technology)
+        .unwrap();
+    manager
+        .map_to_concept("s1", step, "ingestion", None)
+        .unwrap();
+
+    manager
+        .conclude(
+            "s1",
+            "The aurora service consumes the kappa queue",
+            &recalled,
+        )
+        .unwrap();
+    let committed = manager
+        .commit("s1", "alice", None, Some(false), None, false)
+        .await
+        .expect("commit");
+    assert!(!committed.memory_id.is_empty());
+    assert_ne!(committed.memory_id, evidence_id);
+    // The session's structure is reported even though the session is gone.
+    assert_eq!(
+        committed.entities,
+        vec![CommittedEntity {
+            name: "aurora".to_string(),
+            entity_type: ScratchEntityType::Technology.to_string(),
+        }]
+    );
+    assert_eq!(committed.concepts, vec!["ingestion".to_string()]);
+
+    // The conclusion is stored, linked to the recalled evidence...
+    let stored = client
+        .get_memory(&committed.memory_id, "alice")
+        .await
+        .unwrap()
+        .expect("committed memory is readable");
+    assert_eq!(
+        stored.content,
+        "The aurora service consumes the kappa queue"
+    );
+    assert!(
+        stored
+            .relations
+            .related
+            .iter()
+            .any(|r| r.memory_id == evidence_id && r.direction == "in"),
+        "{:?}",
+        stored.relations
+    );
+    // ...and the link is a SUPPORTS edge, not "[Evidence: ...]" text.
+    let incoming = store
+        .query(
+            "getMemoryIncomingRelations",
+            json!({"memory_id": committed.memory_id}),
+        )
+        .await
+        .unwrap();
+    let supports = incoming["relations_in"].as_array().unwrap();
+    assert!(
+        !supports.is_empty() && supports.iter().all(|e| e["relation_type"] == "SUPPORTS"),
+        "{incoming}"
+    );
+    assert!(!stored.content.contains("[Evidence"));
+    // The edges read back as the conclusion's provenance.
+    let sources = client
+        .get_conclusion_sources(&committed.memory_id, "alice")
+        .await
+        .unwrap()
+        .expect("sources of a held memory");
+    assert!(
+        sources
+            .iter()
+            .any(|s| s.memory_id == evidence_id && s.from_commit),
+        "{sources:?}"
+    );
+    assert!(
+        client
+            .get_conclusion_sources(&committed.memory_id, "bob")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    // Ranking runs on the same store: the off-topic seed ranks last.
+    let hits = client
+        .search("kappa queue", "alice", SearchParams::default())
+        .await
+        .unwrap();
+    let rank = |id: &str| hits.iter().position(|h| h.id == id).unwrap_or(usize::MAX);
+    assert!(rank(&evidence_id) < rank(&seeded.memory_ids[1]), "{hits:?}");
+}
+
+#[tokio::test]
+async fn reconciled_commit_counts_come_from_the_stored_graph() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+
+    for (session, reconcile) in [("scratch", false), ("graph", true)] {
+        let root = manager
+            .start_thinking(session, "Which broker does billing use?")
+            .unwrap();
+        manager
+            .extract_entity(session, root, "billing", ScratchEntityType::Technology)
+            .unwrap();
+        manager
+            .conclude(session, "Billing publishes to the orders broker", &[root])
+            .unwrap();
+        let committed = manager
+            .commit(session, "alice", None, Some(false), Some(reconcile), false)
+            .await
+            .expect("commit");
+        assert_eq!(committed.counts_reconciled, reconcile);
+        // The scripted LLM extracts nothing, so main memory linked no
+        // entity — the scratch count is the only thing that says one.
+        let expected = if reconcile { 0 } else { 1 };
+        assert_eq!(committed.entities_extracted, expected, "{session}");
+        assert_eq!(committed.entities.len(), 1, "{session}");
+    }
+}
+
+#[tokio::test]
+async fn semantic_thought_search_ranks_by_similarity() {
+    let client = client(Arc::new(InMemoryStore::new()));
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+    let root = manager
+        .start_thinking("s", "Plan the billing rollout")
+        .unwrap();
+    for thought in [
+        "Invoices are generated nightly by the ledger job",
+        "The response cache tradeoff is staleness versus database load",
+        "Marketing wants the launch before the holidays",
+    ] {
+        manager
+            .add_thought(
+                "s",
+                thought,
+                ThoughtType::Reasoning,
+                Some(root),
+                ThoughtOptions::default(),
+            )
+            .unwrap();
+    }
+
+    for _ in 0..2 {
+        // The second pass is served from the session's embedding cache.
+        let hits = manager
+            .search_thoughts(
+                "s",
+                "cache tradeoff staleness",
+                ThoughtSearchMode::Semantic,
+                2,
+            )
+            .await
+            .expect("semantic search");
+        assert_eq!(hits.len(), 2);
+        assert!(hits[0].content.contains("cache tradeoff"), "{hits:?}");
+        assert!(hits[0].score >= hits[1].score);
+    }
+}
+
+#[tokio::test]
+async fn a_note_promoted_to_memory_is_stored_and_leaves_the_session() {
+    let client = client(Arc::new(InMemoryStore::new()));
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+    manager
+        .start_thinking("s", "Why is checkout slow?")
+        .unwrap();
+    manager
+        .annotate(
+            "s",
+            "The payments sandbox rate-limits to five requests per second",
+        )
+        .unwrap();
+
+    let ids = manager
+        .promote_note_to_memory("s", 0, "alice")
+        .await
+        .expect("note stored");
+    let stored = client
+        .get_memory(&ids[0], "alice")
+        .await
+        .unwrap()
+        .expect("promoted memory is readable");
+    assert!(stored.content.contains("rate-limits"), "{}", stored.content);
+    assert!(manager.annotations("s").unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn a_retained_commit_can_be_undone_and_recommitted() {
+    let client = client(Arc::new(InMemoryStore::new()));
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+    let root = manager
+        .start_thinking("s", "Which region hosts billing?")
+        .unwrap();
+    manager
+        .conclude("s", "Billing runs in the moon region", &[root])
+        .unwrap();
+
+    let committed = manager
+        .commit("s", "alice", None, Some(false), None, true)
+        .await
+        .expect("commit");
+    assert!(committed.retained);
+    assert!(
+        manager.get_session_status("s").is_err(),
+        "the session ended"
+    );
+
+    let refused = manager.uncommit("s", false).await.unwrap_err();
+    assert!(
+        matches!(refused, FastThinkError::ConfirmRequired { memories: 1 }),
+        "{refused}"
+    );
+    assert_eq!(refused.code(), "confirm_required");
+    assert!(
+        client
+            .get_memory(&committed.memory_id, "alice")
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    let undone = manager.uncommit("s", true).await.expect("uncommit");
+    assert_eq!(undone.deleted_memory_ids, vec![committed.memory_id.clone()]);
+    assert!(
+        client
+            .get_memory(&committed.memory_id, "alice")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let info = manager.get_session_status("s").expect("session is back");
+    assert_eq!(info.status, SessionStatus::Decided);
+    assert!(
+        matches!(
+            manager.uncommit("s", true).await,
+            Err(FastThinkError::NotRetained)
+        ),
+        "undo happens once"
+    );
+
+    manager
+        .conclude("s", "Billing runs in the eu-west region", &[root])
+        .unwrap();
+    let again = manager
+        .commit("s", "alice", None, Some(false), None, false)
+        .await
+        .expect("recommit");
+    assert!(!again.retained);
+    assert!(matches!(
+        manager.uncommit("s", true).await,
+        Err(FastThinkError::NotRetained)
+    ));
+}
+
+#[tokio::test]
+async fn an_incomplete_memory_is_imported_into_a_live_session() {
+    let client = client(Arc::new(InMemoryStore::new()));
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+    let root = manager.start_thinking("old", "Which cache fits?").unwrap();
+    let hunch = manager
+        .add_thought(
+            "old",
+            "Redis already runs in prod",
+            ThoughtType::Observation,
+            Some(root),
+            ThoughtOptions::default(),
+        )
+        .unwrap();
+    manager
+        .add_thought(
+            "old",
+            "Probably Redis",
+            ThoughtType::Conclusion,
+            Some(hunch),
+            ThoughtOptions::default(),
+        )
+        .unwrap();
+    let partial = manager
+        .commit_partial("old", "alice", "timeout")
+        .await
+        .expect("partial commit");
+
+    let live_root = manager
+        .start_thinking("new", "Plan the cache rollout")
+        .unwrap();
+    assert!(
+        matches!(
+            manager
+                .import_incomplete("new", &partial.memory_id, "bob", Some(live_root))
+                .await,
+            Err(FastThinkError::MemoryNotFound(_))
+        ),
+        "bob does not hold alice's memory"
+    );
+    assert!(matches!(
+        manager
+            .continue_incomplete("bob-s", &partial.memory_id, "bob")
+            .await,
+        Err(FastThinkError::MemoryNotFound(_))
+    ));
+    let imported = manager
+        .import_incomplete("new", &partial.memory_id, "alice", Some(live_root))
+        .await
+        .expect("import");
+    assert_eq!(imported.len(), 3);
+
+    let snapshot = manager.snapshot("new").unwrap();
+    let types: Vec<(&str, &str)> = imported
+        .iter()
+        .map(|n| {
+            let t = &snapshot.thoughts[n.index()];
+            (t.thought_type.as_str(), t.content.as_str())
+        })
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            ("question", "Which cache fits?"),
+            ("observation", "Redis already runs in prod"),
+            ("hypothesis", "Probably Redis"),
+        ]
+    );
+    let parents: Vec<usize> = imported
+        .iter()
+        .map(|n| {
+            snapshot
+                .edges
+                .iter()
+                .find(|e| e.to == n.index())
+                .expect("attached")
+                .from
+        })
+        .collect();
+    let head = imported[0].index();
+    assert_eq!(parents, vec![live_root.index(), head, head]);
+    assert_eq!(
+        manager.get_session_status("new").unwrap().status,
+        SessionStatus::Thinking,
+        "an imported conclusion does not conclude the session"
+    );
+
+    let plain = client
+        .add_prepared(vec![fact("Redis runs in prod")], "alice", None, None)
+        .await
+        .unwrap();
+    assert!(
+        matches!(
+            manager
+                .import_incomplete("new", &plain.memory_ids[0], "alice", None)
+                .await,
+            Err(FastThinkError::NotIncomplete(ref id)) if *id == plain.memory_ids[0]
+        ),
+        "only incomplete_thought memories import"
+    );
+    assert!(matches!(
+        manager
+            .import_incomplete("new", "mem_missing", "alice", None)
+            .await,
+        Err(FastThinkError::MemoryNotFound(_))
+    ));
+    assert!(matches!(
+        manager
+            .continue_incomplete("other", "mem_missing", "alice")
+            .await,
+        Err(FastThinkError::MemoryNotFound(_))
+    ));
+    assert!(matches!(
+        manager
+            .continue_incomplete("other", &plain.memory_ids[0], "alice")
+            .await,
+        Err(FastThinkError::NotIncomplete(_))
+    ));
+}
+
+#[tokio::test]
+async fn why_memory_walks_the_evidence_and_finds_the_retained_session() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+    let seeded = client
+        .add_prepared(
+            vec![fact(
+                "The aurora ingestion service reads from the kappa queue",
+            )],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write");
+    let evidence_id = &seeded.memory_ids[0];
+
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+    let root = manager
+        .start_thinking("s", "Which queue does the aurora service consume?")
+        .unwrap();
+    let (_, recalled, _) = manager
+        .recall(
+            "s",
+            "aurora ingestion service kappa queue",
+            Some(root),
+            "alice",
+        )
+        .await
+        .expect("recall");
+    manager
+        .conclude(
+            "s",
+            "The aurora service consumes the kappa queue",
+            &recalled,
+        )
+        .unwrap();
+    let committed = manager
+        .commit("s", "alice", None, Some(false), None, true)
+        .await
+        .expect("commit");
+
+    // Support for the evidence, written after the session recalled it.
+    let background = client
+        .add_prepared(
+            vec![fact("The platform team owns every queue consumer")],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("background write");
+    let background_id = &background.memory_ids[0];
+    store
+        .query(
+            "addMemoryRelation",
+            json!({
+                "source_id": background_id,
+                "target_id": evidence_id,
+                "relation_type": "SUPPORTS",
+                "strength": 70,
+                "created_at": "2026-01-01T00:00:00Z",
+                "metadata": "",
+            }),
+        )
+        .await
+        .unwrap();
+
+    let why = client
+        .why_memory(&committed.memory_id, "alice")
+        .await
+        .unwrap()
+        .expect("evidence of a held memory");
+    assert_eq!(why.memory.memory_id, committed.memory_id);
+    let evidence = why.evidence;
+    let link = |id: &str| evidence.iter().find(|l| l.memory_id == id);
+    let direct = link(evidence_id).expect("recalled evidence");
+    assert_eq!(
+        (direct.depth, direct.supports.as_str(), direct.from_commit),
+        (1, committed.memory_id.as_str(), true)
+    );
+    let indirect = link(background_id).expect("evidence of the evidence");
+    assert_eq!(
+        (indirect.depth, indirect.supports.as_str()),
+        (2, evidence_id.as_str())
+    );
+    assert!(
+        client
+            .why_memory(&committed.memory_id, "bob")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let (session_id, trace) = manager
+        .retained_trace(&committed.memory_id)
+        .expect("the commit was retained");
+    assert_eq!(session_id, "s");
+    assert!(
+        trace
+            .thoughts
+            .iter()
+            .any(|t| t.content == "The aurora service consumes the kappa queue"),
+        "{trace:?}"
+    );
+    assert!(manager.retained_trace(evidence_id).is_none());
+    manager.uncommit("s", true).await.expect("uncommit");
+    assert!(manager.retained_trace(&committed.memory_id).is_none());
+}
+
+#[tokio::test]
+async fn a_chain_commit_stores_each_thought_and_links_them() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+    let root = manager
+        .start_thinking("s", "Why did the nightly export fail?")
+        .unwrap();
+    let cause = manager
+        .add_thought(
+            "s",
+            "The export bucket ran out of quota",
+            ThoughtType::Observation,
+            Some(root),
+            ThoughtOptions::default(),
+        )
+        .unwrap();
+    let effect = manager
+        .add_thought(
+            "s",
+            "Writes to the bucket were rejected",
+            ThoughtType::Reasoning,
+            Some(cause),
+            ThoughtOptions {
+                edge: Some(WeightedEdge::new(ThoughtEdge::Implies)),
+                ..ThoughtOptions::default()
+            },
+        )
+        .unwrap();
+    manager
+        .conclude("s", "The export failed on a full bucket", &[effect])
+        .unwrap();
+
+    let chain = manager.commit_chain("s", "alice").await.expect("chain");
+    assert_eq!(chain.memory_ids.len(), 3, "{chain:?}");
+    assert_eq!(chain.conclusion_memory_ids.len(), 1);
+    assert!(chain.reused_memory_ids.is_empty());
+    assert_eq!(chain.relations_failed, 0);
+    assert!(
+        manager.get_session_status("s").is_err(),
+        "the session ended"
+    );
+
+    let conclusion = &chain.conclusion_memory_ids[0];
+    assert!(
+        chain
+            .relations
+            .iter()
+            .any(|r| &r.to_memory_id == conclusion && r.relation == "IMPLIES"),
+        "{:?}",
+        chain.relations
+    );
+    let links = store
+        .query(
+            "getMemoryLogicalConnections",
+            json!({ "memory_id": conclusion }),
+        )
+        .await
+        .expect("connections");
+    let implied_by: Vec<_> = links["implies_in"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|m| m["memory_id"].as_str())
+        .collect();
+    assert_eq!(implied_by.len(), 1, "{links}");
+    assert!(chain.memory_ids.iter().any(|id| id == implied_by[0]));
+
+    let stored = client
+        .get_memory(conclusion, "alice")
+        .await
+        .unwrap()
+        .expect("conclusion readable");
+    assert_eq!(stored.content, "The export failed on a full bucket");
+}
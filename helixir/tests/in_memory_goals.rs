@@ -0,0 +1,73 @@
+//! `get_goals` on the [`common::in_memory`] harness: goals are found by
+//! their memory type however they are worded, and each one's outcomes come
+//! from the chain walked from the goal itself.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::core::helixir_client::GoalProgress;
+use helixir::db::{InMemoryStore, MemoryStore};
+use helixir::llm::extractor::ExtractedMemory;
+use serde_json::json;
+
+fn goal(text: &str) -> ExtractedMemory {
+    ExtractedMemory {
+        memory_type: "goal".to_string(),
+        ..fact(text)
+    }
+}
+
+#[tokio::test]
+async fn goals_are_listed_by_type_and_walked_from_their_own_id() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let written = client
+        .add_prepared(
+            vec![
+                goal("Run the Berlin marathon under four hours"),
+                goal("Read every Le Guin novel"),
+                fact("Frank's bike is green"),
+                fact("Frank finished the Berlin marathon in 3:52, goal completed"),
+            ],
+            "frank",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write");
+    let [marathon, reading, _bike, finish] = &written.memory_ids[..] else {
+        panic!("{written:?}");
+    };
+    store
+        .query(
+            "addMemoryCausation",
+            json!({
+                "from_id": finish,
+                "to_id": marathon,
+                "strength": 80,
+                "reasoning_id": "",
+            }),
+        )
+        .await
+        .unwrap();
+
+    let goals = client.get_goals("frank", None, None).await.unwrap();
+    let ids: Vec<&str> = goals.iter().map(|g| g.goal.id.as_str()).collect();
+    assert_eq!(ids, vec![reading.as_str(), marathon.as_str()], "{goals:?}");
+    assert_eq!(goals[0].inferred_status, GoalProgress::Open);
+    assert_eq!(goals[1].inferred_status, GoalProgress::Completed);
+    assert_eq!(goals[1].related_outcomes[0].memory_id, *finish);
+
+    let one = client.get_goals("frank", None, Some(1)).await.unwrap();
+    assert_eq!(one.len(), 1, "{one:?}");
+    assert!(
+        client
+            .get_goals("gina", None, None)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
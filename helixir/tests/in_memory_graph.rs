@@ -0,0 +1,87 @@
+//! `get_memory_graph` over a store holding a record that no longer
+//! deserializes: the bad node is skipped and reported, the rest of the
+//! traversal comes back. Runs on the [`common::in_memory`] harness.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::db::{InMemoryStore, MemoryStore};
+use serde_json::json;
+
+#[tokio::test]
+async fn corrupt_and_missing_nodes_become_warnings() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let seeded = client
+        .add_prepared(
+            vec![fact("The billing service pages the on-call rota")],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write");
+    let good = seeded.memory_ids[0].clone();
+
+    // A record whose `content` is not a string fails to deserialize.
+    store
+        .query(
+            "addMemory",
+            json!({
+                "memory_id": "mem_corrupt",
+                "user_id": "alice",
+                "content": 42,
+                "memory_type": "fact",
+                "certainty": 80,
+                "importance": 50,
+                "created_at": "2026-01-01T00:00:00Z",
+                "updated_at": "2026-01-01T00:00:00Z",
+                "context_tags": "",
+                "source": "test",
+                "metadata": "{}",
+            }),
+        )
+        .await
+        .expect("corrupt write");
+    store
+        .query(
+            "addMemoryImplication",
+            json!({
+                "from_id": good,
+                "to_id": "mem_corrupt",
+                "probability": 80,
+                "reasoning_id": "r1",
+            }),
+        )
+        .await
+        .expect("edge write");
+
+    let graph = client
+        .get_graph("alice", Some(&good), Some(3))
+        .await
+        .expect("a bad neighbour must not fail the view");
+    assert!(graph.nodes.iter().any(|n| n.id == good), "{graph:?}");
+    assert!(graph.nodes.iter().all(|n| n.id != "mem_corrupt"));
+    assert!(
+        graph
+            .edges
+            .iter()
+            .all(|e| e.source != "mem_corrupt" && e.target != "mem_corrupt"),
+        "{:?}",
+        graph.edges
+    );
+    assert_eq!(graph.warnings.len(), 1, "{:?}", graph.warnings);
+    assert_eq!(graph.warnings[0].node_id, "mem_corrupt");
+    assert!(graph.warnings[0].reason.starts_with("failed to load"));
+
+    let missing = client
+        .get_graph("alice", Some("mem_nowhere"), Some(2))
+        .await
+        .unwrap();
+    assert!(missing.nodes.is_empty());
+    assert_eq!(missing.warnings[0].node_id, "mem_nowhere");
+    assert_eq!(missing.warnings[0].reason, "memory not found");
+}
@@ -0,0 +1,43 @@
+//! `HelixirClient::import_messages` on the [`common::in_memory`] harness:
+//! the first call of a batch finds no checkpoint and starts at 0, and a
+//! second call resumes from the checkpoint it wrote.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::client;
+use helixir::core::helixir_client::ImportOptions;
+use helixir::db::InMemoryStore;
+
+#[tokio::test]
+async fn first_call_starts_without_a_checkpoint_and_the_next_resumes() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+    let messages = vec![
+        "The build cache lives on the shared volume.".to_string(),
+        "Release notes are drafted on Thursdays.".to_string(),
+    ];
+    let opts = ImportOptions {
+        max_items: 1,
+        ..ImportOptions::default()
+    };
+
+    let first = client
+        .import_messages(&messages, "alice", None, opts.clone())
+        .await
+        .expect("first call with no checkpoint");
+    assert_eq!((first.start_index, first.next_index), (0, 1), "{first:?}");
+    assert!(!first.done);
+
+    let second = client
+        .import_messages(&messages, "alice", None, opts)
+        .await
+        .expect("resume from the checkpoint");
+    assert_eq!(
+        (second.start_index, second.next_index),
+        (1, 2),
+        "{second:?}"
+    );
+    assert!(second.done && second.resume_token.is_none());
+}
@@ -0,0 +1,81 @@
+//! `HelixirClient::list_memories` on the [`common::in_memory`] harness:
+//! pages come out of the database newest first and the cursor walks every
+//! row without repeating one.
+
+mod common;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::db::InMemoryStore;
+use helixir::toolkit::tooling_manager::MemoryListFilter;
+
+#[tokio::test]
+async fn the_cursor_walks_every_memory_newest_first() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let facts = (0..250)
+        .map(|i| fact(&format!("Dana logged support ticket number {i}.")))
+        .collect();
+    client
+        .add_prepared(facts, "dana", None, None)
+        .await
+        .unwrap();
+
+    let all = MemoryListFilter::default();
+    let mut seen = HashSet::new();
+    let mut stamps = Vec::new();
+    let mut offset = Some(0);
+    while let Some(at) = offset {
+        let page = client.list_memories("dana", &all, at, 100).await.unwrap();
+        assert_eq!(page.total, 250);
+        for m in &page.memories {
+            assert!(seen.insert(m["memory_id"].as_str().unwrap().to_string()));
+            stamps.push(m["created_at"].as_str().unwrap().to_string());
+        }
+        offset = page.next_cursor;
+    }
+    assert_eq!(seen.len(), 250);
+    assert!(stamps.windows(2).all(|w| w[0] >= w[1]), "not newest first");
+
+    let tagged = MemoryListFilter {
+        tags: vec!["nonexistent".to_string()],
+        ..MemoryListFilter::default()
+    };
+    let page = client.list_memories("dana", &tagged, 0, 10).await.unwrap();
+    assert!(page.memories.is_empty());
+    assert_eq!(page.next_cursor, None);
+}
+
+#[tokio::test]
+async fn a_truncated_page_resumes_at_the_first_row_it_dropped() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let facts = (0..12)
+        .map(|i| fact(&format!("Erin reviewed pull request number {i}.")))
+        .collect();
+    client
+        .add_prepared(facts, "erin", None, None)
+        .await
+        .unwrap();
+
+    let all = MemoryListFilter::default();
+    let whole = client.list_memories("erin", &all, 2, 6).await.unwrap();
+    assert_eq!(whole.positions, (2..8).collect::<Vec<_>>());
+
+    let mut cut = whole.clone();
+    cut.truncate(4);
+    assert_eq!(cut.memories.len(), 4);
+    assert_eq!(cut.next_cursor, Some(6));
+    let next = client
+        .list_memories("erin", &all, cut.next_cursor.unwrap(), 6)
+        .await
+        .unwrap();
+    assert_eq!(
+        next.memories[0]["memory_id"],
+        whole.memories[4]["memory_id"]
+    );
+}
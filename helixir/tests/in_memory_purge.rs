@@ -0,0 +1,101 @@
+//! `HelixirClient::purge_user` on the [`common::in_memory`] harness: a
+//! memory another user still holds keeps its node and loses only the purged
+//! user's link, and the sweep reaches past the first page.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::db::{InMemoryStore, MemoryStore};
+use serde_json::json;
+
+#[tokio::test]
+async fn purge_keeps_memories_another_user_still_holds() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let shared = client
+        .add_prepared(vec![fact("The office closes at six.")], "alice", None, None)
+        .await
+        .unwrap()
+        .memory_ids[0]
+        .clone();
+    let private = client
+        .add_prepared(vec![fact("Alice parks on level two.")], "alice", None, None)
+        .await
+        .unwrap()
+        .memory_ids[0]
+        .clone();
+    client
+        .add_prepared(vec![fact("Bob rides a bike to work.")], "bob", None, None)
+        .await
+        .unwrap();
+    store
+        .query(
+            "linkUserToMemoryWithStance",
+            json!({
+                "user_id": "bob",
+                "memory_id": shared,
+                "context": "shared",
+                "stance": "asserts",
+                "certainty": 80,
+                "linked_at": "2026-01-01T00:00:00Z",
+            }),
+        )
+        .await
+        .expect("link bob");
+
+    let report = client.purge_user("alice", "alice").await.expect("purge");
+    assert_eq!(report.memories, 1, "{report:?}");
+    assert_eq!(report.memories_unlinked, 1, "{report:?}");
+
+    assert!(client.get_memory(&shared, "bob").await.unwrap().is_some());
+    let knowers = store
+        .query("getMemoryUsers", json!({ "memory_id": shared }))
+        .await
+        .unwrap()["users"]
+        .as_array()
+        .map(|users| {
+            users
+                .iter()
+                .map(|u| u["user_id"].clone())
+                .collect::<Vec<_>>()
+        });
+    assert_eq!(knowers, Some(vec![json!("bob")]));
+    let count = store
+        .query("getMemory", json!({ "memory_id": shared }))
+        .await
+        .unwrap()["memory"]["user_count"]
+        .as_i64();
+    assert_eq!(count, Some(1));
+    assert!(client.get_memory(&private, "bob").await.unwrap().is_none());
+    assert!(
+        store
+            .query("getMemory", json!({ "memory_id": private }))
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn purge_sweeps_every_page() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    // More than one purge page (200) of memories.
+    let facts = (0..230)
+        .map(|i| fact(&format!("Carol filed expense report number {i}.")))
+        .collect();
+    client
+        .add_prepared(facts, "carol", None, None)
+        .await
+        .unwrap();
+
+    let report = client.purge_user("carol", "carol").await.expect("purge");
+    assert_eq!(report.memories, 230, "{report:?}");
+    let left = store.query("getAllMemories", json!({})).await.unwrap()["memories"]
+        .as_array()
+        .map_or(0, Vec::len);
+    assert_eq!(left, 0);
+}
@@ -0,0 +1,69 @@
+//! `search_reasoning_chain` on the [`common::in_memory`] harness: seeds
+//! expand concurrently, and a chain two seeds reach from either end is
+//! returned once.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client_with, fact};
+use helixir::core::config::HelixirConfig;
+use helixir::db::{InMemoryStore, MemoryStore};
+use serde_json::json;
+
+#[tokio::test]
+async fn a_chain_reached_from_two_seeds_is_returned_once() {
+    let store = Arc::new(InMemoryStore::new());
+    let mut config = HelixirConfig::default();
+    config.retrieval.chain_seed_concurrency = 2;
+    let client = client_with(Arc::clone(&store), config);
+
+    let seeded = client
+        .add_prepared(
+            vec![
+                fact("The nightly export failed on the billing database"),
+                fact("The billing database ran out of disk during the export"),
+            ],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write");
+    let (effect, cause) = (&seeded.memory_ids[0], &seeded.memory_ids[1]);
+    store
+        .query(
+            "addMemoryCausation",
+            json!({
+                "from_id": effect,
+                "to_id": cause,
+                "strength": 80,
+                "reasoning_id": "",
+            }),
+        )
+        .await
+        .unwrap();
+
+    let result = client
+        .search_reasoning_chain(
+            "billing database export disk",
+            "alice",
+            Some("both"),
+            Some(3),
+            Some(5),
+        )
+        .await
+        .expect("chain search");
+    assert_eq!(result.chains.len(), 1, "{result:?}");
+    let chain = &result.chains[0];
+    let mut members: Vec<&str> = chain
+        .nodes
+        .iter()
+        .map(|n| n.memory_id.as_str())
+        .chain([chain.seed.id.as_str()])
+        .collect();
+    members.sort_unstable();
+    let mut expected = [effect.as_str(), cause.as_str()];
+    expected.sort_unstable();
+    assert_eq!(members, expected);
+}
@@ -0,0 +1,58 @@
+//! `HelixirClient::recent` on the [`common::in_memory`] harness: browse
+//! recent returns the newest memories even when the user holds more rows
+//! than one scan would read.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::client;
+use helixir::db::{InMemoryStore, MemoryStore};
+use serde_json::json;
+
+#[tokio::test]
+async fn recent_returns_the_newest_past_a_thousand_rows() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    store
+        .query("addUser", json!({"user_id": "frank", "name": "frank"}))
+        .await
+        .unwrap();
+    // Stored oldest first, so the newest rows are the last ones inserted.
+    for i in 0..1003 {
+        let at = chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:00+00:00").unwrap()
+            + chrono::Duration::minutes(i);
+        let memory_id = format!("mem_{i:04}");
+        store
+            .query(
+                "addMemory",
+                json!({
+                    "memory_id": memory_id,
+                    "user_id": "frank",
+                    "content": format!("Frank archived invoice number {i}."),
+                    "memory_type": "fact",
+                    "certainty": 80,
+                    "importance": 50,
+                    "created_at": at.to_rfc3339(),
+                    "updated_at": at.to_rfc3339(),
+                    "context_tags": "",
+                    "source": "test",
+                    "metadata": "{}",
+                }),
+            )
+            .await
+            .unwrap();
+        store
+            .query(
+                "linkUserToMemory",
+                json!({"user_id": "frank", "memory_id": memory_id, "context": ""}),
+            )
+            .await
+            .unwrap();
+    }
+
+    let recent = client.recent("frank", Some(3)).await.unwrap();
+    let ids: Vec<_> = recent.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, ["mem_1002", "mem_1001", "mem_1000"]);
+}
@@ -0,0 +1,66 @@
+//! File/document references on the [`common::in_memory`] harness: attached
+//! once, not twice, and handed back on every search row they belong to.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::core::helixir_client::SearchParams;
+use helixir::db::InMemoryStore;
+use helixir::toolkit::mind_toolbox::memory::references::MemoryReference;
+use serde_json::json;
+
+#[tokio::test]
+async fn references_come_back_on_the_rows_that_carry_them() {
+    let store = Arc::new(InMemoryStore::new());
+    let helixir = client(store);
+
+    let cited = helixir
+        .add_prepared(
+            vec![fact(
+                "The retry budget for the payments API is three attempts",
+            )],
+            "dana",
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+        .memory_ids;
+    helixir
+        .add_prepared(
+            vec![fact("The payments API team meets on Thursdays")],
+            "dana",
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let spec = MemoryReference {
+        uri: "docs/payments.md".to_string(),
+        locator: Some("§3".to_string()),
+        title: None,
+    };
+    let refs = [spec];
+    assert_eq!(helixir.attach_references(&cited, &refs).await.unwrap(), 1);
+    assert_eq!(helixir.attach_references(&cited, &refs).await.unwrap(), 1);
+
+    let hits = helixir
+        .search("payments API", "dana", SearchParams::default())
+        .await
+        .unwrap();
+    assert_eq!(hits.len(), 2, "{hits:?}");
+    for hit in &hits {
+        let expected = if cited.contains(&hit.id) {
+            json!([{"uri": "docs/payments.md", "locator": "§3"}])
+        } else {
+            serde_json::Value::Null
+        };
+        assert_eq!(
+            hit.metadata.get("references").cloned().unwrap_or_default(),
+            expected,
+            "{hit:?}"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! `SearchParams::within_ids` on the [`common::in_memory`] harness: only the
+//! listed memories come back, ranked against the new query, and ids the
+//! caller does not hold are dropped.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::core::helixir_client::{HelixirClientError, SearchParams};
+use helixir::db::InMemoryStore;
+
+#[tokio::test]
+async fn within_ids_ranks_only_the_given_memories() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let mut ids = Vec::new();
+    for text in [
+        "The billing service runs on Postgres",
+        "Alice prefers tea over coffee in the morning",
+        "The billing service pages the on-call rota at night",
+    ] {
+        let added = client
+            .add_prepared(vec![fact(text)], "alice", None, None)
+            .await
+            .expect("seed write");
+        ids.push(added.memory_ids[0].clone());
+    }
+    let bobs = client
+        .add_prepared(
+            vec![fact("The billing service invoices Bob monthly")],
+            "bob",
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+        .memory_ids[0]
+        .clone();
+
+    let within = |set: Vec<String>| SearchParams {
+        within_ids: Some(set),
+        ..SearchParams::default()
+    };
+    let hits = client
+        .search(
+            "billing service on-call pages",
+            "alice",
+            within(vec![
+                ids[1].clone(),
+                ids[2].clone(),
+                ids[2].clone(),
+                bobs,
+                "mem_nowhere".to_string(),
+            ]),
+        )
+        .await
+        .unwrap();
+    let got: Vec<&str> = hits.iter().map(|h| h.id.as_str()).collect();
+    // ids[0] matches too, but is outside the set; bob's row is not alice's.
+    assert_eq!(got, vec![ids[2].as_str(), ids[1].as_str()], "{hits:?}");
+    assert_eq!(hits[0].metadata["origin"], "within_ids");
+
+    assert!(
+        client
+            .search("billing", "alice", within(vec![]))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let too_many = (0..=helixir::toolkit::tooling_manager::MAX_WITHIN_IDS)
+        .map(|i| format!("mem_{i}"))
+        .collect();
+    let err = client
+        .search("billing", "alice", within(too_many))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HelixirClientError::Config(_)), "{err}");
+}
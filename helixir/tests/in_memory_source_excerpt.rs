@@ -0,0 +1,87 @@
+//! `add_with_source` on the [`common::in_memory`] harness: every extracted
+//! fact links back to the stored message over EXTRACTED_FROM, and one
+//! `getMemorySourcesBatch` query — what search reads `source_excerpt`
+//! from — finds the message for all of them.
+
+mod common;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common::in_memory::client_with_llm;
+use helixir::core::config::HelixirConfig;
+use helixir::db::{InMemoryStore, MemoryStore};
+use helixir::llm::providers::base::{LlmMetadata, LlmProvider, LlmProviderError};
+use serde_json::json;
+
+const MESSAGE: &str = "Notes from the quarterly offsite, which covered hiring plans, budgets, \
+roadmap themes and the new office lease: Dana moved the billing job to midnight, and Erik \
+owns the staging cluster.";
+
+/// Extracts the two facts of [`MESSAGE`]; every other call finds nothing.
+struct TwoFacts;
+
+#[async_trait]
+impl LlmProvider for TwoFacts {
+    async fn generate(
+        &self,
+        _system_prompt: &str,
+        user_prompt: &str,
+        _response_format: Option<&str>,
+    ) -> Result<(String, LlmMetadata), LlmProviderError> {
+        let memories = if user_prompt.contains(MESSAGE) {
+            json!([
+                {"text": "Dana moved the billing job to midnight", "memory_type": "fact"},
+                {"text": "Erik owns the staging cluster", "memory_type": "fact"},
+            ])
+        } else {
+            json!([])
+        };
+        let body = json!({"memories": memories, "entities": [], "relations": []});
+        Ok((body.to_string(), LlmMetadata::default()))
+    }
+
+    fn provider_name(&self) -> &str {
+        "two-facts"
+    }
+
+    fn model_name(&self) -> &str {
+        "two-facts"
+    }
+}
+
+#[tokio::test]
+async fn every_fact_links_back_to_the_message_it_came_from() {
+    let store = Arc::new(InMemoryStore::new());
+    let helixir = client_with_llm(
+        Arc::clone(&store),
+        HelixirConfig::default(),
+        Arc::new(TwoFacts),
+    );
+
+    let added = helixir
+        .add_with_source(MESSAGE, "dana", None, None)
+        .await
+        .unwrap();
+    assert_eq!(added.memories_added, 2, "{added:?}");
+
+    let batch = store
+        .query(
+            "getMemorySourcesBatch",
+            json!({ "memory_ids": added.memory_ids }),
+        )
+        .await
+        .unwrap();
+    let edges = batch["source_edges"].as_array().expect("source edges");
+    assert_eq!(edges.len(), 2, "{batch}");
+    let sources = batch["sources"].as_array().expect("sources");
+    assert!(
+        sources.iter().all(|s| {
+            s["memory_id"]
+                .as_str()
+                .is_some_and(|id| id.starts_with("raw_"))
+                && s["content"] == json!(MESSAGE)
+        }),
+        "{batch}"
+    );
+}
@@ -0,0 +1,74 @@
+//! `HelixirClient::find_stale` on the [`common::in_memory`] harness: a
+//! memory contradicted by a newer one is flagged at any age, time-bound
+//! wording only past the age floor.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::db::{HelixClient, InMemoryStore};
+use serde_json::json;
+
+#[tokio::test]
+async fn newer_contradictions_and_old_present_tense_are_flagged() {
+    let store = Arc::new(InMemoryStore::new());
+    let client = client(Arc::clone(&store));
+
+    let old = client
+        .add_prepared(
+            vec![fact("Billing is currently using Postgres v14.")],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("first write")
+        .memory_ids[0]
+        .clone();
+    let new = client
+        .add_prepared(
+            vec![fact("Billing moved to MySQL after the migration.")],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("second write")
+        .memory_ids[0]
+        .clone();
+    let _: serde_json::Value = HelixClient::with_store(store.clone())
+        .execute_query(
+            "addMemoryContradiction",
+            &json!({
+                "from_id": new,
+                "to_id": old,
+                "resolution": "",
+                "resolved": 0,
+                "resolution_strategy": "pending",
+            }),
+        )
+        .await
+        .expect("contradiction edge");
+
+    // Fresh memories: only the contradiction counts.
+    let stale = client.find_stale("alice", None, None).await.unwrap();
+    assert_eq!(stale.len(), 1, "{stale:?}");
+    assert_eq!(stale[0].memory_id, old);
+    assert_eq!(stale[0].contradicted_by, vec![new.clone()]);
+    assert!(stale[0].markers.is_empty());
+
+    // No age floor: the wording is reported as well.
+    let stale = client.find_stale("alice", Some(0), None).await.unwrap();
+    assert_eq!(stale.len(), 1, "{stale:?}");
+    assert_eq!(stale[0].markers, ["currently", "v14"]);
+    assert_eq!(stale[0].reasons.len(), 2);
+
+    assert!(
+        client
+            .find_stale("bob", None, None)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -0,0 +1,145 @@
+//! `HelixirClient::summarize_recent` on the [`common::in_memory`] harness:
+//! the summary is stored tagged and supported by its sources, archiving
+//! supersedes them so a second run has nothing left, `recent` searches
+//! rank the summary first, and a source another user holds is linked but
+//! never archived.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client_with, fact};
+use helixir::core::config::HelixirConfig;
+use helixir::core::helixir_client::SearchParams;
+use helixir::db::{InMemoryStore, MemoryStore};
+use serde_json::json;
+
+#[tokio::test]
+async fn a_summary_supersedes_its_sources_and_leads_recent_searches() {
+    let store = Arc::new(InMemoryStore::new());
+    let mut config = HelixirConfig::default();
+    config.summary.min_sources = 2;
+    config.summary.recent_boost = 1_000.0;
+    let client = client_with(Arc::clone(&store), config);
+
+    let seeded = client
+        .add_prepared(
+            vec![
+                fact("Picked Postgres for the billing service."),
+                fact("CI now runs on every pull request."),
+                fact("The on-call rotation moved to weekly shifts."),
+            ],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write");
+    assert_eq!(seeded.memory_ids.len(), 3);
+
+    let summary = client
+        .summarize_recent("alice", Some(1), Some(true))
+        .await
+        .expect("summarize");
+    let summary_id = summary.summary_id.clone().expect("a summary is written");
+    let mut sources = summary.source_ids.clone();
+    sources.sort();
+    let mut seeded_ids = seeded.memory_ids.clone();
+    seeded_ids.sort();
+    assert_eq!(sources, seeded_ids);
+    assert_eq!((summary.sources_linked, summary.archived), (3, 3));
+
+    let stored = client
+        .get_memory(&summary_id, "alice")
+        .await
+        .unwrap()
+        .expect("summary readable");
+    assert_eq!(stored.content, summary.content);
+
+    // Archived sources and the summary itself are not summarized again.
+    let again = client
+        .summarize_recent("alice", Some(1), None)
+        .await
+        .expect("second run");
+    assert!(again.summary_id.is_none(), "{again:?}");
+    assert!(again.source_ids.is_empty(), "{again:?}");
+
+    let hits = client
+        .search(
+            "billing service database",
+            "alice",
+            SearchParams {
+                search_mode: Some("recent".to_string()),
+                ..SearchParams::default()
+            },
+        )
+        .await
+        .unwrap();
+    let first = hits.first().expect("recent hits");
+    assert_eq!(first.id, summary_id);
+    assert_eq!(first.metadata["summary"], true);
+}
+
+#[tokio::test]
+async fn a_shared_source_is_linked_but_not_archived() {
+    let store = Arc::new(InMemoryStore::new());
+    let mut config = HelixirConfig::default();
+    config.summary.min_sources = 2;
+    let client = client_with(Arc::clone(&store), config);
+
+    let seeded = client
+        .add_prepared(
+            vec![
+                fact("The staging cluster was rebuilt on Monday."),
+                fact("Deploy freezes start every Thursday evening."),
+            ],
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .expect("seed write")
+        .memory_ids;
+    let shared = &seeded[0];
+    for (query, params) in [
+        ("addUser", json!({"user_id": "bob", "name": "bob"})),
+        (
+            "linkUserToMemoryWithStance",
+            json!({"user_id": "bob", "memory_id": shared, "context": "shared", "stance": "asserts", "certainty": 80, "linked_at": "2026-01-01T00:00:00Z"}),
+        ),
+        (
+            "updateMemoryUserCount",
+            json!({"memory_id": shared, "user_count": 2, "updated_at": "2026-01-01T00:00:00Z"}),
+        ),
+    ] {
+        store.query(query, params).await.expect(query);
+    }
+
+    let summary = client
+        .summarize_recent("alice", Some(1), Some(true))
+        .await
+        .expect("summarize");
+    assert!(summary.summary_id.is_some(), "{summary:?}");
+    assert_eq!((summary.sources_linked, summary.archived), (2, 1));
+
+    let superseded = store
+        .query("getSupersededBatch", json!({ "memory_ids": seeded }))
+        .await
+        .unwrap();
+    let archived: Vec<_> = superseded["superseded_edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["to_node"].clone())
+        .collect();
+    let ids_of = |id: &str| {
+        superseded["memories"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|m| m["memory_id"] == id)
+            .map(|m| m["id"].clone())
+            .unwrap()
+    };
+    assert_eq!(archived, vec![ids_of(&seeded[1])]);
+}
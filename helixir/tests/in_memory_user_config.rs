@@ -0,0 +1,145 @@
+//! Per-user config overrides on the [`common::in_memory`] harness: a patch
+//! keeps earlier keys, the effective config layers them over the global
+//! one, a user's search follows their own limit, and purge drops them —
+//! from HelixDB and from the client's cache of them.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::in_memory::{client, fact};
+use helixir::core::config::UserConfigOverrides;
+use helixir::core::helixir_client::SearchParams;
+use helixir::db::InMemoryStore;
+
+#[tokio::test]
+async fn overrides_apply_to_their_user_only() {
+    let client = client(Arc::new(InMemoryStore::new()));
+    assert!(client.user_config("alice").await.unwrap().is_empty());
+    assert_eq!(
+        client.effective_config("alice").await.windows.recent_hours,
+        client.config().windows.recent_hours,
+        "a reset reaches the cached overrides"
+    );
+
+    client
+        .set_user_config(
+            "alice",
+            UserConfigOverrides {
+                recent_hours: Some(8.0),
+                ..UserConfigOverrides::default()
+            },
+            false,
+        )
+        .await
+        .expect("first patch");
+    let stored = client
+        .set_user_config(
+            "alice",
+            UserConfigOverrides {
+                search_limit: Some(1),
+                ..UserConfigOverrides::default()
+            },
+            false,
+        )
+        .await
+        .expect("second patch");
+    assert_eq!(
+        (stored.recent_hours, stored.search_limit),
+        (Some(8.0), Some(1))
+    );
+    assert_eq!(client.user_config("alice").await.unwrap(), stored);
+
+    let effective = client.effective_config("alice").await;
+    assert_eq!(effective.windows.recent_hours, Some(8.0));
+    assert_eq!(
+        client.effective_config("bob").await.windows.recent_hours,
+        client.config().windows.recent_hours
+    );
+
+    let rejected = client
+        .set_user_config(
+            "alice",
+            UserConfigOverrides {
+                deep_days: Some(-1.0),
+                ..UserConfigOverrides::default()
+            },
+            false,
+        )
+        .await;
+    assert!(rejected.is_err());
+    assert_eq!(client.user_config("alice").await.unwrap(), stored);
+
+    for user in ["alice", "bob"] {
+        client
+            .add_prepared(
+                vec![
+                    fact("The billing service runs on Postgres."),
+                    fact("The billing service deploys every Friday."),
+                    fact("The billing service pages the payments team."),
+                ],
+                user,
+                None,
+                None,
+            )
+            .await
+            .expect("seed write");
+    }
+    let search = |user: &'static str| {
+        let client = Arc::clone(&client);
+        async move {
+            client
+                .search("billing service", user, SearchParams::default())
+                .await
+                .unwrap()
+        }
+    };
+    assert_eq!(search("alice").await.len(), 1);
+    assert!(search("bob").await.len() > 1);
+
+    let cleared = client
+        .set_user_config("alice", UserConfigOverrides::default(), true)
+        .await
+        .expect("reset");
+    assert!(cleared.is_empty());
+    assert!(client.user_config("alice").await.unwrap().is_empty());
+    assert_eq!(
+        client.effective_config("alice").await.windows.recent_hours,
+        client.config().windows.recent_hours,
+        "a reset reaches the cached overrides"
+    );
+
+    client
+        .set_user_config(
+            "bob",
+            UserConfigOverrides {
+                search_limit: Some(2),
+                ..UserConfigOverrides::default()
+            },
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        client
+            .effective_config("bob")
+            .await
+            .retrieval
+            .search_modes
+            .full
+            .max_results,
+        2
+    );
+    client.purge_user("bob", "bob").await.expect("purge");
+    assert!(client.user_config("bob").await.unwrap().is_empty());
+    assert_eq!(
+        client
+            .effective_config("bob")
+            .await
+            .retrieval
+            .search_modes
+            .full
+            .max_results,
+        client.config().retrieval.search_modes.full.max_results
+    );
+}
@@ -0,0 +1,66 @@
+//! `llm_runtime.warm_up` on the [`common::in_memory`] harness:
+//! `initialize` makes one LLM call when warm-up is on, none when it is
+//! off, and a failing warm-up call does not fail startup.
+
+mod common;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use common::in_memory::client_with_llm;
+use helixir::core::config::HelixirConfig;
+use helixir::db::InMemoryStore;
+use helixir::llm::providers::base::{LlmMetadata, LlmProvider, LlmProviderError};
+
+/// Counts calls; fails every one when `fail` is set.
+#[derive(Default)]
+struct CountingLlm {
+    calls: AtomicUsize,
+    fail: bool,
+}
+
+#[async_trait]
+impl LlmProvider for CountingLlm {
+    async fn generate(
+        &self,
+        _system_prompt: &str,
+        _user_prompt: &str,
+        _response_format: Option<&str>,
+    ) -> Result<(String, LlmMetadata), LlmProviderError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.fail {
+            return Err(LlmProviderError::Provider("unreachable".to_string()));
+        }
+        Ok(("OK".to_string(), LlmMetadata::default()))
+    }
+
+    fn provider_name(&self) -> &str {
+        "counting"
+    }
+
+    fn model_name(&self) -> &str {
+        "counting"
+    }
+}
+
+#[tokio::test]
+async fn initialize_warms_the_llm_only_when_enabled() {
+    for (warm_up, fail, expected_calls) in [(false, false, 0), (true, false, 1), (true, true, 1)] {
+        let llm = Arc::new(CountingLlm {
+            fail,
+            ..CountingLlm::default()
+        });
+        let mut config = HelixirConfig::default();
+        config.llm_runtime.warm_up = warm_up;
+        let client = client_with_llm(Arc::new(InMemoryStore::new()), config, llm.clone());
+
+        client.initialize().await.expect("initialize");
+        client.initialize().await.expect("initialize is idempotent");
+        assert_eq!(
+            llm.calls.load(Ordering::SeqCst),
+            expected_calls,
+            "warm_up={warm_up} fail={fail}"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! Post-commit webhook on the [`common::in_memory`] harness: writes through
+//! the client API and the FastThink manager are announced without any MCP
+//! handler in between, and a commit is announced once, as a `commit`.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::in_memory::{client_with, fact};
+use helixir::core::config::{HelixirConfig, WebhookConfig};
+use helixir::db::InMemoryStore;
+use helixir::toolkit::fast_think::FastThinkManager;
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+/// A local endpoint that answers 200 and forwards each POSTed body.
+async fn receiver() -> (String, mpsc::UnboundedReceiver<Value>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let mut raw = Vec::new();
+                let mut buf = [0u8; 4096];
+                let body = loop {
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    if n == 0 {
+                        return;
+                    }
+                    raw.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&raw);
+                    let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                        continue;
+                    };
+                    let len = head
+                        .lines()
+                        .find_map(|l| {
+                            l.to_ascii_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|v| v.trim().parse::<usize>().unwrap_or(0))
+                        })
+                        .unwrap_or(0);
+                    if body.len() >= len {
+                        break body.to_string();
+                    }
+                };
+                let _ = socket
+                    .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                    .await;
+                if let Ok(v) = serde_json::from_str(&body) {
+                    let _ = tx.send(v);
+                }
+            });
+        }
+    });
+    (url, rx)
+}
+
+async fn next(rx: &mut mpsc::UnboundedReceiver<Value>) -> Value {
+    tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("webhook delivery")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn client_adds_and_think_commits_are_announced_once() {
+    let (url, mut rx) = receiver().await;
+    let store = Arc::new(InMemoryStore::new());
+    let client = client_with(
+        store,
+        HelixirConfig {
+            webhook: WebhookConfig {
+                url: Some(url),
+                ..WebhookConfig::default()
+            },
+            ..HelixirConfig::default()
+        },
+    );
+
+    let added = client
+        .add_prepared(
+            vec![fact("Hana's build server runs on port 8090")],
+            "hana",
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let hook = next(&mut rx).await;
+    assert_eq!(hook["event"], "add");
+    assert_eq!(hook["memory_id"], added.memory_ids[0].as_str());
+    assert_eq!(hook["user_id"], "hana");
+
+    let manager = FastThinkManager::with_default_limits(Arc::clone(&client));
+    manager
+        .start_thinking("s1", "Where does the nightly job run?")
+        .unwrap();
+    manager
+        .conclude("s1", "The nightly job runs on the build server", &[])
+        .unwrap();
+    let committed = manager
+        .commit("s1", "hana", None, Some(false), None, false)
+        .await
+        .unwrap();
+    let hook = next(&mut rx).await;
+    assert_eq!(hook["event"], "commit");
+    assert_eq!(hook["memory_id"], committed.memory_id.as_str());
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert!(rx.try_recv().is_err(), "a commit was announced twice");
+}